@@ -1,12 +1,24 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-pub use glide_core::client::{GlideRt, get_or_init_runtime};
+pub use glide_core::client::{
+    ClusterFailoverMode, ClusterFailoverOptions, ClusterFailoverProgress, ClusterFailoverStage,
+    FailoverOptions, GlideRt, NodeAddress, RequestPriority, SlotMigrationOptions,
+    SlotMigrationProgress, get_or_init_runtime,
+};
 
 use crate::connection_request::ConnectionRequest;
 use redis::{Pipeline, PipelineRetryStrategy, ScanStateRC, Cmd, PushInfo, Value, ClusterScanArgs, RoutingInfo, RedisResult};
 
 pub struct ConnectionError;
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum FlushMode {
+    Default,
+    Sync,
+    Async,
+}
+
 use std::fmt;
 impl fmt::Display for ConnectionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -29,6 +41,14 @@ impl Client {
         })
     }
 
+    pub async fn new_with_connection_events(
+        request: ConnectionRequest,
+        push_sender: Option<tokio::sync::mpsc::UnboundedSender<PushInfo>>,
+        _connection_event_listener: std::sync::Arc<dyn redis::ConnectionEventListener>,
+    ) -> Result<Self, ConnectionError> {
+        Self::new(request, push_sender).await
+    }
+
     pub fn send_pipeline<'a>(
         &'a mut self,
         _pipeline: &'a Pipeline,
@@ -60,6 +80,33 @@ impl Client {
         todo!()
     }
 
+    pub async fn invoke_script_inline(
+        &mut self,
+        _source: &[u8],
+        _keys: &Vec<&[u8]>,
+        _args: &Vec<&[u8]>,
+        _routing: Option<RoutingInfo>,
+    ) -> redis::RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn invoke_script_ro<'a>(
+        &'a mut self,
+        _hash: &'a str,
+        _keys: &Vec<&[u8]>,
+        _args: &Vec<&[u8]>,
+        _routing: Option<RoutingInfo>,
+    ) -> redis::RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn set_address_translation(
+        &self,
+        _table: std::collections::HashMap<String, String>,
+    ) -> redis::RedisResult<()> {
+        Ok(())
+    }
+
     pub async fn update_connection_password(
         &mut self,
         _password: Option<String>,
@@ -76,6 +123,14 @@ impl Client {
         todo!()
     }
 
+    pub async fn send_blocking_command(
+        &mut self,
+        _cmd: &mut Cmd,
+        _routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
     pub async fn cluster_scan<'a>(
         &'a mut self,
         _scan_state_cursor: &'a ScanStateRC,
@@ -84,10 +139,272 @@ impl Client {
         todo!()
     }
 
+    pub async fn cluster_scan_with_progress<'a>(
+        &'a mut self,
+        _scan_state_cursor: &'a ScanStateRC,
+        _cluster_scan_args: ClusterScanArgs,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
     pub async fn refresh_iam_token(&mut self) -> RedisResult<()> {
         todo!()
     }
 
+    pub async fn ping_all_nodes(&mut self) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn describe_key(&mut self, _key: &[u8]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn get_node_for_key(&mut self, _key: &[u8]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn node_address_for_route(&mut self, _routing: &RoutingInfo) -> Option<String> {
+        None
+    }
+
+    pub async fn refresh_topology(&mut self) -> RedisResult<bool> {
+        todo!()
+    }
+
+    pub async fn dump_key(&mut self, _key: &[u8]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn restore_key(
+        &mut self,
+        _key: &[u8],
+        _ttl: u64,
+        _serialized_value: &[u8],
+        _replace: bool,
+        _absttl: bool,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn script_exists_all_nodes(&mut self, _hashes: &[&[u8]]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn script_flush(
+        &mut self,
+        _mode: FlushMode,
+        _routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn flush_cluster(&mut self, _flush_all: bool, _mode: FlushMode) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn config_get_all_nodes(&mut self, _parameters: &[&[u8]]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn config_set_all_nodes(
+        &mut self,
+        _parameters: &[(&[u8], &[u8])],
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn acl_getuser(&mut self, _username: &[u8]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn acl_whoami(&mut self) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn acl_setuser_all_nodes(
+        &mut self,
+        _username: &[u8],
+        _rules: &[&[u8]],
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn server_capabilities(&mut self, _refresh: bool) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn get_info(
+        &mut self,
+        _sections: &[&[u8]],
+        _routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn list_clients(
+        &mut self,
+        _filters: &crate::client_list::ClientListFilters,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn rate_limit_acquire(
+        &mut self,
+        _bucket_key: &[u8],
+        _tokens: u64,
+        _config: &crate::rate_limiter::RateLimitConfig,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn stop_lock_extensions(&self) {}
+
+    pub async fn acquire_lock(
+        &mut self,
+        _key: &[u8],
+        _ttl: std::time::Duration,
+        _auto_extend: bool,
+        _listener: Option<crate::distributed_lock::LockExtensionListener>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn release_lock(&mut self, _key: &[u8], _token: &[u8]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn extend_lock(
+        &mut self,
+        _key: &[u8],
+        _token: &[u8],
+        _ttl: std::time::Duration,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn scan_database(
+        &mut self,
+        _match_pattern: Option<&[u8]>,
+        _count: Option<u32>,
+        _object_type: Option<&str>,
+        _on_page: impl FnMut(Vec<Value>) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn wait_for_replication(
+        &mut self,
+        _numreplicas: u32,
+        _timeout_ms: u64,
+        _key: Option<&[u8]>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn failover(&mut self, _options: &FailoverOptions) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn watch(&mut self, _keys: &[Vec<u8>]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn unwatch(&mut self, _token: u64) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn execute_transaction_with_watch(
+        &mut self,
+        _token: Option<u64>,
+        _keys: &[Vec<u8>],
+        _pipeline: &Pipeline,
+        _routing: Option<RoutingInfo>,
+        _transaction_timeout: Option<u32>,
+        _raise_on_error: bool,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn analyze_keyspace(
+        &mut self,
+        _sample_size: usize,
+        _pattern: Option<&[u8]>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn circuit_breaker_states(&mut self) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn migrate_slot(
+        &mut self,
+        _slot: u16,
+        _target: NodeAddress,
+        _options: SlotMigrationOptions,
+        _on_progress: impl FnMut(SlotMigrationProgress) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn trigger_failover(
+        &mut self,
+        _target: NodeAddress,
+        _options: ClusterFailoverOptions,
+        _on_progress: impl FnMut(ClusterFailoverProgress) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn copy_key_cross_slot(
+        &mut self,
+        _source: &[u8],
+        _destination: &[u8],
+        _replace: bool,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn pfmerge_across_slots(
+        &mut self,
+        _destination: &[u8],
+        _sources: &[Vec<u8>],
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn served_read_nodes(&self, _count: usize) -> Vec<String> {
+        todo!()
+    }
+
+    pub async fn statistics(&self) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn node_latencies(&self) -> Value {
+        todo!()
+    }
+
+    pub fn effective_config(&self) -> Value {
+        todo!()
+    }
+
+    pub async fn publish_many(&mut self, _messages: &[(&[u8], &[u8], bool)]) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn get_subscriptions(&self) -> Value {
+        todo!()
+    }
+
+    pub async fn subscribe_keyspace_events(
+        &mut self,
+        _key_patterns: &[Vec<u8>],
+        _event_mask: Option<&str>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
     /// Mock compression_manager method for Miri tests
     pub fn compression_manager(&self) -> Option<std::sync::Arc<crate::compression::CompressionManager>> {
         None
@@ -98,8 +415,133 @@ impl Client {
         true // Always allow in mock
     }
 
+    /// Mock reserve_inflight_request_with_priority method for Miri tests
+    pub fn reserve_inflight_request_with_priority(&self, _priority: RequestPriority) -> bool {
+        true // Always allow in mock
+    }
+
     /// Mock release_inflight_request method for Miri tests
     pub fn release_inflight_request(&self) -> isize {
         0 // No-op in mock
     }
 }
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CoalescingConfig {
+    pub window: std::time::Duration,
+    pub max_batch_size: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CoalescingStatistics {
+    pub batches_count: u64,
+    pub commands_count: u64,
+    pub max_batch_size: u64,
+}
+
+pub fn deadline_from_unix_millis(_deadline_unix_ms: u64) -> std::time::Instant {
+    std::time::Instant::now()
+}
+
+pub const DEFAULT_COALESCING_WINDOW: std::time::Duration = std::time::Duration::from_micros(100);
+pub const DEFAULT_COALESCING_MAX_BATCH_SIZE: usize = 64;
+
+pub struct CommandCoalescer;
+
+impl CommandCoalescer {
+    pub fn new(_client: Client, _config: CoalescingConfig) -> Self {
+        CommandCoalescer
+    }
+
+    pub fn with_client(&self, _client: Client) -> Self {
+        CommandCoalescer
+    }
+
+    pub fn can_coalesce(&self, _cmd: &Cmd, _routing: Option<&RoutingInfo>) -> bool {
+        false
+    }
+
+    pub async fn send_command(&self, _cmd: Cmd) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn statistics(&self) -> CoalescingStatistics {
+        CoalescingStatistics::default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeduplicationStatistics {
+    pub requests_count: u64,
+    pub hits_count: u64,
+}
+
+#[derive(Default)]
+pub struct RequestDeduplicator;
+
+impl RequestDeduplicator {
+    pub fn new() -> Self {
+        RequestDeduplicator
+    }
+
+    pub fn can_deduplicate(_cmd: &Cmd, _routing: Option<&RoutingInfo>) -> bool {
+        false
+    }
+
+    pub async fn deduplicate<F>(&self, _cmd: &Cmd, request: F) -> RedisResult<Value>
+    where
+        F: std::future::Future<Output = RedisResult<Value>>,
+    {
+        request.await
+    }
+
+    pub fn statistics(&self) -> DeduplicationStatistics {
+        DeduplicationStatistics::default()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SlowCommand {
+    pub id: u64,
+    pub command_name: String,
+    pub args_digest: u64,
+    pub duration: std::time::Duration,
+    pub node_address: Option<String>,
+}
+
+pub fn is_multi_key_split_command(_cmd: &Cmd) -> bool {
+    true
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandSignature;
+
+impl CommandSignature {
+    pub fn new(_cmd: &Cmd) -> Self {
+        CommandSignature
+    }
+}
+
+pub struct SlowCommandLog;
+
+impl SlowCommandLog {
+    pub fn new(_threshold: std::time::Duration, _capacity: usize) -> Self {
+        SlowCommandLog
+    }
+
+    pub fn is_slow(&self, _duration: std::time::Duration) -> bool {
+        false
+    }
+
+    pub fn record(&self, _signature: CommandSignature, _duration: std::time::Duration, _node_address: Option<String>) {}
+
+    pub fn latency_percentiles(&self) -> crate::command_latency::LatencyPercentiles {
+        crate::command_latency::LatencyPercentiles::default()
+    }
+
+    pub fn entries(&self, _max_entries: usize) -> Vec<SlowCommand> {
+        Vec::new()
+    }
+
+    pub fn clear(&self) {}
+}
@@ -8,3 +8,11 @@ pub fn get_cluster_scan_cursor(_id: String) -> RedisResult<ScanStateRC> {
 
 pub fn remove_scan_state_cursor(_id: String) {
 }
+
+pub fn cancel_cluster_scan(_id: &str) -> bool {
+    todo!()
+}
+
+pub fn set_cluster_scan_cursor_ttl(_ttl: Option<std::time::Duration>) {
+    todo!()
+}
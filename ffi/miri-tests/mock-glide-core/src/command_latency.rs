@@ -0,0 +1,22 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::request_type::RequestType;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    pub count: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+}
+
+pub fn set_enabled(_enabled: bool) {}
+
+pub fn record(_request_type: RequestType, _latency: Duration) {}
+
+pub fn percentiles(_request_type: RequestType) -> LatencyPercentiles {
+    LatencyPercentiles::default()
+}
+
+pub fn reset() {}
@@ -7,6 +7,9 @@ use crate::request_type::RequestType;
 /// Re-export the minimum compressed size constant from glide-core
 pub use glide_core::compression::MIN_COMPRESSED_SIZE;
 
+/// Re-export the decompression of wrapper payloads from glide-core
+pub use glide_core::compression::decompress_payload;
+
 /// Mock compression manager
 #[derive(Debug)]
 pub struct CompressionManager;
@@ -26,6 +29,26 @@ impl CompressionManager {
     pub fn is_enabled(&self) -> bool {
         false
     }
+
+    /// Mock statistics method - always returns empty statistics
+    pub fn statistics(&self) -> CompressionStatistics {
+        CompressionStatistics::default()
+    }
+}
+
+/// Mock per-client compression statistics
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressionStatistics {
+    pub values_compressed: u64,
+    pub values_decompressed: u64,
+    pub original_bytes: u64,
+    pub compressed_bytes: u64,
+}
+
+impl CompressionStatistics {
+    pub fn bytes_saved(&self) -> u64 {
+        self.original_bytes.saturating_sub(self.compressed_bytes)
+    }
 }
 
 /// Mock function to process command args for compression
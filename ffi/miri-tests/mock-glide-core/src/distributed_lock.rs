@@ -0,0 +1,3 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+pub use glide_core::distributed_lock::LockExtensionListener;
@@ -8,6 +8,10 @@ pub enum RequestErrorType {
     ExecAbort = 1,
     Timeout = 2,
     Disconnect = 3,
+    WatchedKeyModified = 4,
+    CircuitOpen = 5,
+    ResponseSchemaMismatch = 6,
+    DeadlineExceededBeforeSend = 7,
 }
 
 pub fn error_type(_error: &RedisError) -> RequestErrorType {
@@ -17,3 +21,14 @@ pub fn error_type(_error: &RedisError) -> RequestErrorType {
 pub fn error_message(_error: &RedisError) -> String {
     "".to_string()
 }
+
+#[derive(Default)]
+pub struct ErrorDetails {
+    pub code: Option<String>,
+    pub retryable: bool,
+    pub node_address: Option<String>,
+}
+
+pub fn error_details(_error: &RedisError) -> ErrorDetails {
+    ErrorDetails::default()
+}
@@ -0,0 +1,10 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Mock geo search module for Miri tests
+
+use redis::{Cmd, RedisResult, Value};
+
+/// Mock convert_geo_search_response function for Miri tests
+pub fn convert_geo_search_response(_cmd: &Cmd, value: Value) -> RedisResult<Value> {
+    Ok(value) // Responses are returned as is in mock
+}
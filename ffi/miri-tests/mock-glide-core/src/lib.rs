@@ -1,12 +1,24 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 pub mod client;
+pub mod client_list;
 pub mod cluster_scan_container;
+pub mod cluster_topology;
+pub mod command_latency;
 pub mod command_request;
 pub mod compression;
 pub mod connection_request;
+pub mod distributed_lock;
 pub mod errors;
+pub mod geo_search;
+pub mod mock_server;
+pub mod pubsub;
+pub mod rate_limiter;
 pub mod request_type;
+pub mod response_encoding;
+pub mod response_filter;
+pub mod response_schema;
+pub mod routed_multi_client;
 pub mod scripts_container;
 
 pub use client::*;
@@ -0,0 +1,6 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Mock server module for Miri tests
+
+/// The mock server doesn't use the redis types, so the real one is used
+pub use glide_core::mock_server::*;
@@ -0,0 +1,35 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Mock pubsub module for Miri tests
+
+/// The keyspace notification helpers are pure functions, so the real ones are used
+pub use glide_core::pubsub::keyspace_events;
+
+/// Mock pubsub buffer module for Miri tests
+pub mod buffer {
+    pub use glide_core::pubsub::buffer::{
+        DEFAULT_PUBSUB_BUFFER_CAPACITY, PubSubBufferConfig, PubSubBufferStatistics,
+        PubSubOverflowPolicy,
+    };
+    use redis::PushInfo;
+
+    pub struct PubSubBuffer;
+
+    impl PubSubBuffer {
+        pub fn new(_config: PubSubBufferConfig) -> Self {
+            PubSubBuffer
+        }
+
+        pub fn push(&self, _message: PushInfo) {}
+
+        pub fn pop(&self) -> Option<PushInfo> {
+            None // Nothing is buffered in mock
+        }
+
+        pub fn close(&self) {}
+
+        pub fn statistics(&self) -> PubSubBufferStatistics {
+            PubSubBufferStatistics::default()
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use redis::Cmd;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub enum RequestType {
     /// Invalid request type
@@ -11,12 +11,30 @@ pub enum RequestType {
     // Basic string commands for testing
     Get = 1504,
     Set = 1517,
+    Append = 1501,
+    GetDel = 1505,
+    GetEx = 1506,
+    GetRange = 1507,
+    GetSet = 1508,
+    MGet = 1513,
+    MSet = 1514,
+    MSetNX = 1515,
+    PSetEx = 1516,
+    SetEx = 1518,
+    SetNX = 1519,
     Del = 402,
     Exists = 404,
 
     // Hash commands for testing
     HGet = 603,
     HSet = 613,
+    HGetAll = 604,
+    HMGet = 609,
+    HMSet = 610,
+    HSetNX = 614,
+    HVals = 616,
+    HSetEx = 617,
+    HGetEx = 618,
     HDel = 601,
 
     // List commands for testing
@@ -28,6 +46,10 @@ pub enum RequestType {
     // Other common commands
     Expire = 405,
     TTL = 428,
+
+    // PubSub commands
+    Publish = 902,
+    SPublish = 909,
 }
 
 impl RequestType {
@@ -37,4 +59,12 @@ impl RequestType {
             _ => Some(Cmd::default()),
         }
     }
+
+    pub fn validate_arg_count(&self, _arg_count: usize) -> Result<(), String> {
+        Ok(())
+    }
+
+    pub fn validate_args(&self, _args: &[&[u8]]) -> Result<(), String> {
+        Ok(())
+    }
 }
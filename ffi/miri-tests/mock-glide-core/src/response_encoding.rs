@@ -0,0 +1,7 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::{RedisResult, Value};
+
+pub fn encode_response(_result: RedisResult<Value>) -> Vec<u8> {
+    Vec::new()
+}
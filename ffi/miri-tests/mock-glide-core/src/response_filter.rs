@@ -0,0 +1,6 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Mock response filter module for Miri tests
+
+/// The projection is a pure function, so the real one is used
+pub use glide_core::response_filter::*;
@@ -0,0 +1,36 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::client::Client;
+use redis::cluster_routing::RoutingInfo;
+use redis::{Cmd, RedisResult, Value};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPrefixRoute {
+    pub prefix: Vec<u8>,
+    pub cluster: String,
+}
+
+pub struct RoutedMultiClient;
+
+impl RoutedMultiClient {
+    pub fn new(
+        _clients: HashMap<String, Client>,
+        _routes: Vec<KeyPrefixRoute>,
+        _default_cluster: Option<String>,
+    ) -> RedisResult<Self> {
+        todo!()
+    }
+
+    pub fn set_routes(&self, _routes: Vec<KeyPrefixRoute>) -> RedisResult<()> {
+        todo!()
+    }
+
+    pub async fn send_command(
+        &self,
+        _cmd: &mut Cmd,
+        _routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+}
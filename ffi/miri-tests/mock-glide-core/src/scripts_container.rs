@@ -6,3 +6,18 @@ pub fn add_script(_script: &[u8]) -> String {
 
 pub fn remove_script(_hash: &str) {}
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptStatistics {
+    pub invocations: u64,
+    pub eval_fallbacks: u64,
+    pub errors: u64,
+    pub total_latency: std::time::Duration,
+    pub max_latency: std::time::Duration,
+}
+
+pub fn script_statistics(_hash: &str) -> Option<ScriptStatistics> {
+    None
+}
+
+pub fn reset_script_statistics() {}
+
@@ -7,6 +7,7 @@ pub trait Routable {
 }
 
 #[allow(unused)]
+#[derive(Clone)]
 pub struct Route(u16, SlotAddr);
 
 impl Route {
@@ -15,6 +16,7 @@ impl Route {
     }
 }
 
+#[derive(Clone)]
 pub enum SlotAddr {
     Master,
     ReplicaOptional,
@@ -22,11 +24,13 @@ pub enum SlotAddr {
 }   
 
 
+#[derive(Clone)]
 pub enum RoutingInfo {
     SingleNode(SingleNodeRoutingInfo),
     MultiNode((MultipleNodeRoutingInfo, Option<ResponsePolicy>)),
 }
 
+#[derive(Clone)]
 pub enum SingleNodeRoutingInfo {
     Random,
     RandomPrimary,
@@ -35,23 +39,31 @@ pub enum SingleNodeRoutingInfo {
         host: String,
         port: u16,
     },
+    ReplicaInAZ {
+        slot: u16,
+        az: String,
+    },
 }
 
+#[derive(Clone)]
 pub enum MultipleNodeRoutingInfo {
     AllNodes,
     AllMasters,
     MultiSlot((Vec<(Route, Vec<usize>)>, MultiSlotArgPattern)),
 }
 
+#[derive(Clone)]
 pub enum LogicalAggregateOp {
     And,    
 }           
 
+#[derive(Clone)]
 pub enum AggregateOp {
     Min,
     Sum,
 }
 
+#[derive(Clone)]
 pub enum ResponsePolicy {
     OneSucceeded,
     FirstSucceededNonEmptyOrAllEmpty,
@@ -63,12 +75,19 @@ pub enum ResponsePolicy {
     CombineMaps,
 }
 
+impl RoutingInfo {
+    pub fn for_routable<R: Routable + ?Sized>(_r: &R) -> Option<RoutingInfo> {
+        None
+    }
+}
+
 impl ResponsePolicy {
     pub fn for_command(_cmd: &[u8]) -> Option<ResponsePolicy> {
         Some(ResponsePolicy::OneSucceeded)
     }
 }
 
+#[derive(Clone)]
 pub enum MultiSlotArgPattern {
     KeysOnly,
     KeyValuePairs,
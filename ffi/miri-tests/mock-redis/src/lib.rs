@@ -1,6 +1,9 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-pub use redis::{ErrorKind, ObjectType, PushKind, RedisError, RedisFuture, RedisResult, Value};
+pub use redis::{
+    CommandTrace, ConnectionEvent, ConnectionEventListener, ErrorKind, ObjectType, PushKind,
+    RedisError, RedisFuture, RedisResult, Value, parse_redis_value,
+};
 use telemetrylib::GlideSpan;
 
 pub mod cluster_routing;
@@ -11,6 +14,7 @@ pub use cluster_topology::*;
 
 pub use redis::ToRedisArgs;
 
+#[derive(Clone)]
 pub struct Cmd {
     command_bytes: Vec<u8>,
 }
@@ -38,6 +42,14 @@ impl Cmd {
         None
     }
 
+    pub fn set_deadline(&mut self, _deadline: Option<std::time::Instant>) -> &mut Cmd {
+        self
+    }
+
+    pub fn set_trace(&mut self, _trace: Option<std::sync::Arc<CommandTrace>>) -> &mut Cmd {
+        self
+    }
+
     pub fn command(&self) -> Option<Vec<u8>> {
         Some(self.command_bytes.clone())
     }
@@ -75,8 +87,14 @@ impl ScanStateRC {
     pub fn new() -> Self {
         ScanStateRC
     }
+
+    pub fn from_progress(_progress: ScanProgress) -> Self {
+        ScanStateRC
+    }
 }
 
+pub use redis::ScanProgress;
+
 pub struct ClusterScanArgs;
 
 impl ClusterScanArgs {
@@ -121,10 +139,18 @@ pub struct PushInfo {
     pub data: Vec<redis::Value>,
 }
 
-pub struct PipelineRetryStrategy;
+pub struct PipelineRetryStrategy {
+    pub retry_server_error: bool,
+    pub retry_connection_error: bool,
+    pub follow_ask_redirects: bool,
+}
 
 impl PipelineRetryStrategy {
-    pub fn new(_retry_server_error: bool, _retry_connection_error: bool) -> Self {
-        PipelineRetryStrategy
+    pub fn new(retry_server_error: bool, retry_connection_error: bool) -> Self {
+        PipelineRetryStrategy {
+            retry_server_error,
+            retry_connection_error,
+            follow_ask_redirects: true,
+        }
     }
 }
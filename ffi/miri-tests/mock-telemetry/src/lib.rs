@@ -128,6 +128,20 @@ impl Telemetry {
     pub fn subscription_out_of_sync_count() -> usize { 0 }
     pub fn update_subscription_last_sync_timestamp(_timestamp: u64) -> u64 { 0 }
     pub fn subscription_last_sync_timestamp() -> u64 { 0 }
+    pub fn incr_primary_fallback_reads() -> usize { 0 }
+    pub fn primary_fallback_reads_count() -> usize { 0 }
+    pub fn incr_circuit_breaker_opened() -> usize { 0 }
+    pub fn circuit_breaker_opened_count() -> usize { 0 }
+    pub fn incr_circuit_breaker_rejected() -> usize { 0 }
+    pub fn circuit_breaker_rejected_count() -> usize { 0 }
+    pub fn incr_pubsub_messages_dropped() -> usize { 0 }
+    pub fn pubsub_messages_dropped_count() -> usize { 0 }
+    pub fn incr_cluster_scan_cursors_reaped(_count: usize) -> usize { 0 }
+    pub fn cluster_scan_cursors_reaped_count() -> usize { 0 }
+    pub fn incr_connections_recycled_max_age() -> usize { 0 }
+    pub fn connections_recycled_max_age_count() -> usize { 0 }
+    pub fn incr_connections_recycled_idle() -> usize { 0 }
+    pub fn connections_recycled_idle_count() -> usize { 0 }
     pub fn reset() {}
 }
 
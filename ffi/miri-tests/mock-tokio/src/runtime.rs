@@ -8,7 +8,28 @@ use crate::task::JoinHandle;
 
 pub struct Runtime;
 
+#[derive(Clone)]
+pub struct Handle;
+
+impl Handle {
+    pub fn spawn<F>(&self, _future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        JoinHandle {
+            _p: std::marker::PhantomData::<F::Output>
+        }
+    }
+}
+
 impl Runtime {
+    pub fn handle(&self) -> &Handle {
+        &Handle
+    }
+
+    pub fn shutdown_background(self) {}
+
     pub fn block_on<F: Future>(&self, future: F) -> F::Output {
         let waker = std::task::Waker::noop();
         let context = &mut Context::from_waker(&waker);
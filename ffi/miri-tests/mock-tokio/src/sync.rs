@@ -5,3 +5,4 @@ pub mod mpsc {
 }
 
 pub use mpsc::*;
+pub use tokio::sync::Notify;
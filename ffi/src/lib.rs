@@ -2,13 +2,32 @@
 
 use glide_core::ConnectionRequest;
 use glide_core::client::Client as GlideClient;
+use glide_core::client::{
+    ClusterFailoverMode, ClusterFailoverOptions, ClusterFailoverStage, CoalescingConfig,
+    CommandCoalescer, CommandSignature, DEFAULT_COALESCING_MAX_BATCH_SIZE,
+    DEFAULT_COALESCING_WINDOW, NodeAddress, RequestDeduplicator, RequestPriority,
+    SlotMigrationOptions, SlowCommandLog, is_multi_key_split_command,
+};
+use glide_core::client::{FailoverOptions, FlushMode};
+use glide_core::client_list::{ClientConnectionType, ClientListFilters};
 use glide_core::cluster_scan_container::get_cluster_scan_cursor;
 use glide_core::command_request::SimpleRoutes;
 use glide_core::command_request::{Routes, SlotTypes};
 use glide_core::connection_request;
 use glide_core::errors::RequestErrorType;
 use glide_core::errors::{self, error_message};
+#[cfg(feature = "mock-server")]
+use glide_core::mock_server::MockServer;
+use glide_core::pubsub::buffer::{
+    DEFAULT_PUBSUB_BUFFER_CAPACITY, PubSubBuffer, PubSubBufferConfig, PubSubOverflowPolicy,
+};
+use glide_core::pubsub::keyspace_events;
+use glide_core::rate_limiter::{RateLimitAlgorithm, RateLimitConfig};
 use glide_core::request_type::RequestType;
+use glide_core::response_encoding::encode_response;
+use glide_core::response_filter::ResponseFilter;
+use glide_core::response_schema::{ExpectedResponseType, validate_batch_response};
+use glide_core::routed_multi_client::{KeyPrefixRoute, RoutedMultiClient};
 use glide_core::scripts_container;
 use glide_core::{
     DEFAULT_FLUSH_SIGNAL_INTERVAL_MS, GlideOpenTelemetry, GlideOpenTelemetryConfigBuilder,
@@ -23,8 +42,9 @@ use redis::cluster_routing::Routable;
 use redis::cluster_routing::{
     MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
 };
-use redis::{ClusterScanArgs, RedisError};
-use redis::{Cmd, Pipeline, PipelineRetryStrategy, RedisResult, Value};
+use redis::{ClusterScanArgs, RedisError, ScanProgress};
+use redis::{Cmd, CommandTrace, Pipeline, PipelineRetryStrategy, RedisResult, Value};
+use std::collections::HashSet;
 use std::ffi::CStr;
 use std::future::Future;
 use std::mem::ManuallyDrop;
@@ -32,6 +52,7 @@ use std::slice::from_raw_parts;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{
     ffi::{CString, c_void},
     mem,
@@ -167,6 +188,7 @@ pub struct CommandResponse {
     /// Below two values represent the Map structure inside CommandResponse.
     /// The map is transformed into an array of (map_key: CommandResponse, map_value: CommandResponse) and passed to the foreign language.
     /// These are represented as pointers as the map can be null (optionally present).
+    /// Integer and float keys also hold their text in `string_value`, for the languages that key maps by strings.
     pub map_key: *mut CommandResponse,
     pub map_value: *mut CommandResponse,
 
@@ -210,6 +232,9 @@ pub enum ResponseType {
     Sets = 7,
     Ok = 8,
     Error = 9,
+    /// A large string that is read in chunks with [`read_response_chunk`].
+    /// `int_value` holds the handle of the response, and `string_value_len` its total length.
+    StreamedString = 10,
 }
 
 /// A Send-safe wrapper around a raw buffer pointer and length.
@@ -245,6 +270,103 @@ pub type FailureCallback = unsafe extern "C-unwind" fn(
     error_type: RequestErrorType,
 ) -> ();
 
+/// Error details callback that is called right before the failure callback of an async client, when set with
+/// [`set_error_details_callback`].
+///
+/// # Parameters
+/// * `index_ptr`: The same baton-pass as the failure callback that follows.
+/// * `error_code`: The error code, such as `MOVED`, `CLUSTERDOWN` or `OOM`, or null if the error has none.
+/// * `retryable`: Whether the request may succeed when it's retried.
+/// * `node_address`: The address of the node the error originated from, or null if it isn't known.
+///
+/// # Safety
+/// The pointers are only valid during the callback execution and will be freed
+/// automatically when the callback returns. Any data needed beyond the callback's
+/// execution must be copied.
+pub type ErrorDetailsCallback = unsafe extern "C-unwind" fn(
+    index_ptr: usize,
+    error_code: *const c_char,
+    retryable: bool,
+    node_address: *const c_char,
+) -> ();
+
+/// The metadata of a successful command of an async client, reported to the [`CommandMetadataCallback`].
+#[repr(C)]
+pub struct CommandMetadata {
+    /// The number of attempts to send the command, including the retries and redirections. 0 if the command wasn't
+    /// sent on its own, such as when it was coalesced into a pipeline or its response was shared by a deduplicated
+    /// request.
+    pub attempts: u32,
+    /// The address of the node that served the command, or null if it isn't known.
+    pub node_address: *const c_char,
+    /// How long the command waited before it was first sent, in microseconds, such as for a reconnection.
+    pub queue_time_us: u64,
+    /// The time the server reports it spent on the command, in microseconds, from the `server-time` RESP3 attribute
+    /// of the response, or -1 if the response doesn't have it.
+    pub server_time_us: i64,
+}
+
+/// Command metadata callback that is called right before the success callback of an async client, when set with
+/// [`set_command_metadata_callback`].
+///
+/// # Parameters
+/// * `index_ptr`: The same baton-pass as the success callback that follows.
+/// * `metadata`: The metadata of the command.
+///
+/// # Safety
+/// `metadata` and its `node_address` are only valid during the callback execution and will be freed automatically
+/// when the callback returns. Any data needed beyond the callback's execution must be copied.
+pub type CommandMetadataCallback =
+    unsafe extern "C-unwind" fn(index_ptr: usize, metadata: *const CommandMetadata) -> ();
+
+/// The RESP3 attribute in which servers and proxies may report the time they spent on a command, in microseconds.
+const SERVER_TIME_ATTRIBUTE: &[u8] = b"server-time";
+
+/// Value codec callback that encodes or decodes a value of a command, when set with [`set_value_codec`].
+///
+/// # Parameters
+/// * `context`: The baton-pass given to [`set_value_codec`].
+/// * `request_type`: The command the value belongs to, such as `Set` or `HGet`.
+/// * `value`: A pointer to the value bytes.
+/// * `value_len`: The length of the value in bytes.
+/// * `output`: The sink the encoded or decoded value is written to with [`write_value_codec_output`].
+///
+/// # Returns
+/// `true` if the value was written to `output`, or `false` if it couldn't be encoded or decoded, which fails the
+/// command.
+///
+/// # Safety
+/// `value` and `output` are only valid during the callback execution. Values are encoded on the thread that sends the
+/// command, and decoded on the client's thread pool, so the callback must not block.
+pub type ValueCodecCallback = unsafe extern "C-unwind" fn(
+    context: usize,
+    request_type: RequestType,
+    value: *const u8,
+    value_len: usize,
+    output: *mut c_void,
+) -> bool;
+
+/// Lock lost callback that is called when a lock acquired with [`acquire_lock`] with automatic extension is lost.
+///
+/// # Parameters
+/// * `context`: The baton-pass given to [`acquire_lock`].
+/// * `key`: A pointer to the key of the lock.
+/// * `key_len`: The length of the key in bytes.
+/// * `error`: A pointer to the reason the lock was lost, such as the lock having expired or being held by another
+///   owner.
+/// * `error_len`: The length of the reason in bytes.
+///
+/// # Safety
+/// The pointers are only valid during the callback execution. The callback is called on the client's thread pool, so
+/// it must not block.
+pub type LockLostCallback = unsafe extern "C-unwind" fn(
+    context: usize,
+    key: *const u8,
+    key_len: usize,
+    error: *const u8,
+    error_len: usize,
+) -> ();
+
 /// PubSub callback that is called when a push notification is received.
 ///
 /// The PubSub callback needs to handle the push notification synchronously, since the data will be dropped by Rust once the callback returns.
@@ -275,6 +397,114 @@ pub type PubSubCallback = unsafe extern "C-unwind" fn(
     pattern_len: i64,
 ) -> ();
 
+/// Keyspace event callback that is called for each keyspace notification of the subscriptions made with
+/// [`subscribe_keyspace_events`], instead of the pubsub callback.
+///
+/// # Parameters
+/// * `client_ptr`: A baton-pass back to the caller language to uniquely identify the client.
+/// * `event`: A pointer to the event type, such as `set`, `del` or `expired`.
+/// * `event_len`: The length of the event type in bytes.
+/// * `key`: A pointer to the key the event refers to.
+/// * `key_len`: The length of the key in bytes.
+/// * `db`: The database of the key.
+///
+/// # Safety
+/// The pointers are only valid during the callback execution and will be freed
+/// automatically when the callback returns. Any data needed beyond the callback's
+/// execution must be copied.
+pub type KeyspaceEventCallback = unsafe extern "C-unwind" fn(
+    client_ptr: usize,
+    event: *const u8,
+    event_len: i64,
+    key: *const u8,
+    key_len: i64,
+    db: i64,
+) -> ();
+
+/// Scan page callback that is called by [`scan_database`] for each page of keys returned by the server.
+///
+/// # Parameters
+/// * `request_id`: The request ID that was passed to [`scan_database`].
+/// * `keys`: A pointer to an array [`CommandResponse`] with the keys of the page.
+///
+/// # Safety
+/// The pointer is only valid during the callback execution and will be freed
+/// automatically when the callback returns. Any data needed beyond the callback's
+/// execution must be copied.
+pub type ScanPageCallback =
+    unsafe extern "C-unwind" fn(request_id: usize, keys: *const CommandResponse) -> ();
+
+/// Slot migration progress callback that is called by [`migrate_slot`] after each batch of migrated keys.
+///
+/// # Parameters
+/// * `request_id`: The request ID that was passed to [`migrate_slot`].
+/// * `migrated_keys`: The number of keys moved to the target so far.
+/// * `total_keys`: The number of keys that were in the slot when the migration started.
+pub type SlotMigrationProgressCallback =
+    unsafe extern "C-unwind" fn(request_id: usize, migrated_keys: u64, total_keys: u64) -> ();
+
+/// Failover progress callback that is called by [`trigger_failover`] after each completed step of the failover.
+///
+/// # Parameters
+/// * `request_id`: The request ID that was passed to [`trigger_failover`].
+/// * `stage`: The completed step.
+/// * `replica_offset`: The latest replication offset of the target.
+/// * `primary_offset`: The replication offset of the primary that the target catches up with, or 0 if the catch-up
+///   isn't awaited.
+pub type ClusterFailoverProgressCallback = unsafe extern "C-unwind" fn(
+    request_id: usize,
+    stage: ClusterFailoverStage,
+    replica_offset: i64,
+    primary_offset: i64,
+) -> ();
+
+/// Drain callback that is called by [`drain_client`] once the client was drained, right before it is released.
+///
+/// # Parameters
+/// * `client_ptr`: The client that was drained, which must not be used anymore.
+/// * `pending_requests`: The number of commands that didn't complete before the timeout. 0 if the client was fully
+///   drained.
+pub type DrainCallback =
+    unsafe extern "C-unwind" fn(client_ptr: usize, pending_requests: u64) -> ();
+
+/// Client creation callback that is called by [`create_client_async`] once the client is connected, or failed to connect.
+///
+/// The callback is called from the client's thread pool, so it should return quickly.
+///
+/// # Parameters
+/// * `request_id`: The request ID that was passed to [`create_client_async`].
+/// * `response`: The [`ConnectionResponse`] of the client, which must be freed with [`free_connection_response`].
+pub type ClientCreationCallback =
+    unsafe extern "C-unwind" fn(request_id: usize, response: *const ConnectionResponse) -> ();
+
+/// Connection event callback that is called when the state of a connection changes.
+///
+/// The callback is called from the client's thread pool, so it should return quickly.
+/// Events raised while the client is being created are reported before [`create_client_with_connection_events`] returns,
+/// with a `client_ptr` of 0.
+///
+/// # Parameters
+/// * `client_ptr`: A baton-pass back to the caller language to uniquely identify the client.
+/// * `event`: The [`ConnectionEvent`] that occurred.
+/// * `address`: A pointer to the address of the node the event refers to. Empty for topology changes and
+///   authentication refresh failures.
+/// * `address_len`: The length of the address in bytes.
+/// * `error`: A pointer to the reason of a disconnection or a failure (null if unknown).
+/// * `error_len`: The length of the error in bytes (0 if unknown).
+///
+/// # Safety
+/// The pointers are only valid during the callback execution and will be freed
+/// automatically when the callback returns. Any data needed beyond the callback's
+/// execution must be copied.
+pub type ConnectionEventCallback = unsafe extern "C-unwind" fn(
+    client_ptr: usize,
+    event: ConnectionEvent,
+    address: *const u8,
+    address_len: i64,
+    error: *const u8,
+    error_len: i64,
+) -> ();
+
 /// The connection response.
 ///
 /// It contains either a connection or an error. It is represented as a struct instead of a union for ease of use in the wrapper language.
@@ -295,16 +525,24 @@ pub struct ConnectionResponse {
 ///
 /// - `command_error_message`: A null-terminated C string describing the error.
 /// - `command_error_type`: An enum identifying the type of error. See [`RequestErrorType`] for details.
+/// - `command_error_code`: A null-terminated C string with the error code, such as `MOVED`, `CLUSTERDOWN` or `OOM`,
+///   or null if the error has none.
+/// - `command_error_retryable`: Whether the request may succeed when it's retried.
+/// - `command_error_node_address`: A null-terminated C string with the address of the node the error originated from,
+///   or null if it isn't known.
 ///
 /// # Safety
 ///
-/// The pointer `command_error_message` must remain valid and not be freed until after
-/// [`free_command_result`] is called.
+/// The pointers `command_error_message`, `command_error_code` and `command_error_node_address` must remain valid and
+/// not be freed until after [`free_command_result`] is called.
 ///
 #[repr(C)]
 pub struct CommandError {
     pub command_error_message: *const c_char,
     pub command_error_type: RequestErrorType,
+    pub command_error_code: *const c_char,
+    pub command_error_retryable: bool,
+    pub command_error_node_address: *const c_char,
 }
 
 /// Represents the result of a logging operation.
@@ -395,6 +633,12 @@ pub unsafe extern "C" fn free_command_result(command_result_ptr: *mut CommandRes
             if !command_error.command_error_message.is_null() {
                 _ = CString::from_raw(command_error.command_error_message as *mut c_char);
             }
+            if !command_error.command_error_code.is_null() {
+                _ = CString::from_raw(command_error.command_error_code as *mut c_char);
+            }
+            if !command_error.command_error_node_address.is_null() {
+                _ = CString::from_raw(command_error.command_error_node_address as *mut c_char);
+            }
         }
     }
 }
@@ -425,11 +669,316 @@ pub struct ClientAdapter {
     runtime: Runtime,
     core: Arc<CommandExecutionCore>,
     pubsub_callback: Arc<std::sync::RwLock<Option<PubSubCallback>>>,
+    keyspace_event_callback: Arc<std::sync::RwLock<Option<KeyspaceEventCallback>>>,
+    // Buffers the push notifications for a delivery thread, when enabled with `set_pubsub_buffer`.
+    pubsub_buffer: Arc<std::sync::RwLock<Option<Arc<PubSubBuffer>>>>,
+    // The server that a client created with `create_mock_client` is connected to.
+    #[cfg(feature = "mock-server")]
+    mock_server: Option<MockServer>,
+    // Injects the faults of `inject_fault` into the commands of the client.
+    #[cfg(feature = "fault-injection")]
+    fault_injector: Arc<redis::FaultInjector>,
+}
+
+impl Drop for ClientAdapter {
+    fn drop(&mut self) {
+        // Stops the delivery thread, so it doesn't call the callbacks of a closed client.
+        if let Ok(guard) = self.pubsub_buffer.read()
+            && let Some(buffer) = guard.as_ref()
+        {
+            buffer.close();
+        }
+        // The lock extension tasks hold a clone of the client, which would keep its connections open.
+        self.core.client().stop_lock_extensions();
+    }
 }
 
 struct CommandExecutionCore {
-    client: GlideClient,
+    // Replaced by `reconfigure_client`. Requests clone the client, so in-flight requests complete on the previous one.
+    client: std::sync::RwLock<GlideClient>,
     client_type: ClientType,
+    // The push notifications sender and connection events forwarder of the client, reused by `reconfigure_client`.
+    push_sender: tokio::sync::mpsc::UnboundedSender<redis::PushInfo>,
+    connection_event_forwarder: Option<Arc<ConnectionEventForwarder>>,
+    // String responses of at least this many bytes are streamed with `read_response_chunk`. 0 disables streaming.
+    response_streaming_threshold: std::sync::atomic::AtomicUsize,
+    // Coalesces the commands of async clients into pipelines, when enabled with `set_command_coalescing`.
+    coalescer: std::sync::RwLock<Option<Arc<CommandCoalescer>>>,
+    // Collapses concurrent identical read commands, when enabled with `set_request_deduplication`.
+    deduplicator: std::sync::RwLock<Option<Arc<RequestDeduplicator>>>,
+    // Set by `drain_client`, after which new commands are rejected.
+    draining: std::sync::atomic::AtomicBool,
+    // The number of commands that were accepted and didn't complete yet.
+    inflight_requests: std::sync::atomic::AtomicUsize,
+    // Notified whenever a command completes.
+    request_completed: tokio::sync::Notify,
+    // Receives the details of the errors of async clients, when set with `set_error_details_callback`.
+    error_details_callback: std::sync::RwLock<Option<ErrorDetailsCallback>>,
+    // Receives the metadata of the commands of async clients, when set with `set_command_metadata_callback`.
+    command_metadata_callback: std::sync::RwLock<Option<CommandMetadataCallback>>,
+    // Records the commands slower than its threshold, when enabled with `set_slow_command_log`.
+    slow_command_log: std::sync::RwLock<Option<Arc<SlowCommandLog>>>,
+    // Encodes and decodes the values of the configured commands, when set with `set_value_codec`.
+    value_codec: std::sync::RwLock<Option<Arc<ValueCodec>>>,
+}
+
+impl CommandExecutionCore {
+    fn client(&self) -> GlideClient {
+        self.client
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn complete_request(&self) {
+        self.inflight_requests
+            .fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+        self.request_completed.notify_waiters();
+    }
+
+    /// Reports the details of `err` to the error details callback, if one is set.
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`ErrorDetailsCallback`].
+    unsafe fn report_error_details(&self, err: &RedisError, request_id: usize) {
+        let Some(callback) = self
+            .error_details_callback
+            .read()
+            .ok()
+            .and_then(|guard| *guard)
+        else {
+            return;
+        };
+        let details = errors::error_details(err);
+        let error_code = to_c_string_or_null(details.code);
+        let node_address = to_c_string_or_null(details.node_address);
+        unsafe { callback(request_id, error_code, details.retryable, node_address) };
+        for ptr in [error_code, node_address] {
+            if !ptr.is_null() {
+                _ = unsafe { CString::from_raw(ptr as *mut c_char) };
+            }
+        }
+    }
+
+    async fn wait_for_inflight_requests(&self) {
+        loop {
+            // Registered before checking the count, so a request completing in between isn't missed.
+            let request_completed = self.request_completed.notified();
+            if self
+                .inflight_requests
+                .load(std::sync::atomic::Ordering::SeqCst)
+                == 0
+            {
+                return;
+            }
+            request_completed.await;
+        }
+    }
+}
+
+/// The callbacks of [`set_value_codec`], and the commands whose values they encode and decode.
+struct ValueCodec {
+    encode: ValueCodecCallback,
+    decode: ValueCodecCallback,
+    context: usize,
+    // The commands whose values are encoded or decoded, out of the commands `is_supported` accepts.
+    request_types: HashSet<RequestType>,
+}
+
+impl ValueCodec {
+    /// Returns the codec of the values of `request_types`, or `None` if one of them isn't supported.
+    fn new(
+        encode: ValueCodecCallback,
+        decode: ValueCodecCallback,
+        context: usize,
+        request_types: &[RequestType],
+    ) -> Option<Self> {
+        request_types
+            .iter()
+            .all(|&request_type| Self::is_supported(request_type))
+            .then(|| Self {
+                encode,
+                decode,
+                context,
+                request_types: request_types.iter().copied().collect(),
+            })
+    }
+
+    /// Returns whether the values of `request_type` can be encoded or decoded. The commands that read or write a part
+    /// of a value, such as `APPEND` and `GETRANGE`, aren't supported, since an encoded value can't be split or
+    /// concatenated.
+    fn is_supported(request_type: RequestType) -> bool {
+        matches!(
+            request_type,
+            RequestType::Set
+                | RequestType::SetNX
+                | RequestType::SetEx
+                | RequestType::PSetEx
+                | RequestType::GetSet
+                | RequestType::MSet
+                | RequestType::MSetNX
+                | RequestType::HSet
+                | RequestType::HSetNX
+                | RequestType::HMSet
+                | RequestType::HSetEx
+                | RequestType::Get
+                | RequestType::GetDel
+                | RequestType::GetEx
+                | RequestType::MGet
+                | RequestType::HGet
+                | RequestType::HMGet
+                | RequestType::HGetAll
+                | RequestType::HVals
+                | RequestType::HGetEx
+        )
+    }
+
+    /// Returns whether the values of `request_type` are encoded or decoded.
+    fn handles(&self, request_type: RequestType) -> bool {
+        self.request_types.contains(&request_type)
+    }
+
+    /// Encodes the values in the arguments of `request_type`, such as the value of `SET key value` and the values of
+    /// `MSET key value [key value ...]` and `HSET key field value [field value ...]`.
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`ValueCodecCallback`].
+    unsafe fn encode_args(
+        &self,
+        request_type: RequestType,
+        args: &mut [Vec<u8>],
+    ) -> RedisResult<()> {
+        if !self.handles(request_type) {
+            return Ok(());
+        }
+        let value_indices = match request_type {
+            RequestType::Set | RequestType::SetNX | RequestType::GetSet => 1..args.len().min(2),
+            // SETEX key seconds value
+            RequestType::SetEx | RequestType::PSetEx => 2..args.len().min(3),
+            RequestType::MSet | RequestType::MSetNX => 1..args.len(),
+            RequestType::HSet | RequestType::HSetNX | RequestType::HMSet => 2..args.len(),
+            // HSETEX key [options] FIELDS numfields field value [field value ...]
+            RequestType::HSetEx => match args
+                .iter()
+                .skip(1)
+                .position(|arg| arg.eq_ignore_ascii_case(b"FIELDS"))
+            {
+                Some(position) => position + 4..args.len(),
+                None => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+        for index in value_indices.step_by(2) {
+            args[index] = unsafe { self.transform(self.encode, request_type, &args[index]) }?;
+        }
+        Ok(())
+    }
+
+    /// Decodes the values returned by `request_type`, such as the value of `GET`, the values of `MGET` and `HGETALL`,
+    /// and the previous value returned by `SET` with the `GET` option. Nils are returned as is.
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`ValueCodecCallback`].
+    unsafe fn decode_response(
+        &self,
+        request_type: RequestType,
+        value: Value,
+    ) -> RedisResult<Value> {
+        if !self.handles(request_type) {
+            return Ok(value);
+        }
+        match (request_type, value) {
+            // SET replies with a bulk string only with the `GET` option, and otherwise with `OK` or nil.
+            (
+                RequestType::Set
+                | RequestType::Get
+                | RequestType::GetDel
+                | RequestType::GetEx
+                | RequestType::GetSet
+                | RequestType::HGet,
+                value,
+            ) => unsafe { self.decode_value(request_type, value) },
+            (
+                RequestType::MGet | RequestType::HMGet | RequestType::HVals | RequestType::HGetEx,
+                Value::Array(values),
+            ) => values
+                .into_iter()
+                .map(|value| unsafe { self.decode_value(request_type, value) })
+                .collect::<RedisResult<Vec<_>>>()
+                .map(Value::Array),
+            (RequestType::HGetAll, Value::Map(entries)) => entries
+                .into_iter()
+                .map(|(field, value)| {
+                    Ok((field, unsafe { self.decode_value(request_type, value) }?))
+                })
+                .collect::<RedisResult<Vec<_>>>()
+                .map(Value::Map),
+            (_, value) => Ok(value),
+        }
+    }
+
+    /// Decodes a single value returned by `request_type`, if it's a bulk string.
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`ValueCodecCallback`].
+    unsafe fn decode_value(&self, request_type: RequestType, value: Value) -> RedisResult<Value> {
+        match value {
+            Value::BulkString(bytes) => Ok(Value::BulkString(unsafe {
+                self.transform(self.decode, request_type, &bytes)
+            }?)),
+            value => Ok(value),
+        }
+    }
+
+    /// Decodes the responses of a batch of `request_types`, in the order of the commands. The nil response of an
+    /// aborted transaction and the errors of failed commands are returned as is.
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`ValueCodecCallback`].
+    unsafe fn decode_batch_response(
+        &self,
+        request_types: &[RequestType],
+        value: Value,
+    ) -> RedisResult<Value> {
+        match value {
+            Value::Array(responses) => responses
+                .into_iter()
+                .enumerate()
+                .map(|(index, response)| match request_types.get(index) {
+                    Some(&request_type) => unsafe { self.decode_response(request_type, response) },
+                    None => Ok(response),
+                })
+                .collect::<RedisResult<Vec<_>>>()
+                .map(Value::Array),
+            value => Ok(value),
+        }
+    }
+
+    unsafe fn transform(
+        &self,
+        callback: ValueCodecCallback,
+        request_type: RequestType,
+        value: &[u8],
+    ) -> RedisResult<Vec<u8>> {
+        let mut output: Option<Vec<u8>> = None;
+        let written = unsafe {
+            callback(
+                self.context,
+                request_type,
+                value.as_ptr(),
+                value.len(),
+                &mut output as *mut Option<Vec<u8>> as *mut c_void,
+            )
+        };
+        match output {
+            Some(output) if written => Ok(output),
+            _ => Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Value codec failed",
+                format!("The value of {request_type:?} couldn't be encoded or decoded"),
+            ))),
+        }
+    }
 }
 
 impl ClientAdapter {
@@ -454,28 +1003,101 @@ impl ClientAdapter {
     where
         Fut: Future<Output = RedisResult<Value>> + Send + 'static,
     {
-        match self.core.client_type {
+        let streaming_threshold = self
+            .core
+            .response_streaming_threshold
+            .load(std::sync::atomic::Ordering::Relaxed);
+        // The request is counted before the draining flag is checked, so `drain_client` either waits for the request
+        // or the request is rejected.
+        self.core
+            .inflight_requests
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if self.core.draining.load(std::sync::atomic::Ordering::SeqCst) {
+            self.core.complete_request();
+            let err = RedisError::from((
+                ErrorKind::ClientError,
+                "The client is draining and doesn't accept new commands",
+            ));
+            return unsafe { self.handle_redis_error(err, request_id) };
+        }
+        Self::run_request(
+            &self.runtime,
+            &self.core.client_type,
+            Some(self.core.clone()),
+            request_id,
+            request_future,
+            response_buf,
+            streaming_threshold,
+        )
+    }
+
+    /// Runs `request_future` on `runtime` and routes its result based on `client_type`, like [`Self::execute_request`].
+    ///
+    /// The errors of async clients are reported to the error details callback of `core`, and the request is completed on
+    /// `core` once its result was handled. Clients without a `core` only route the result.
+    #[must_use]
+    fn run_request<Fut>(
+        runtime: &Runtime,
+        client_type: &ClientType,
+        core: Option<Arc<CommandExecutionCore>>,
+        request_id: usize,
+        request_future: Fut,
+        response_buf: Option<ResponseBuffer>,
+        streaming_threshold: usize,
+    ) -> *mut CommandResult
+    where
+        Fut: Future<Output = RedisResult<Value>> + Send + 'static,
+    {
+        match *client_type {
             ClientType::AsyncClient {
                 success_callback,
                 failure_callback,
             } => {
                 // Spawn the request for async client
-                self.runtime.spawn(async move {
+                runtime.spawn(async move {
                     let result = request_future.await;
+                    if let (Err(err), Some(core)) = (&result, &core) {
+                        unsafe { core.report_error_details(err, request_id) };
+                    }
                     let _ = Self::handle_result(
                         result,
                         Some(success_callback),
                         Some(failure_callback),
                         request_id,
                         response_buf,
+                        streaming_threshold,
                     );
+                    if let Some(core) = core {
+                        core.complete_request();
+                    }
                 });
                 std::ptr::null_mut()
             }
             ClientType::SyncClient => {
-                // Block on the request for sync client
-                let result = self.runtime.block_on(request_future);
-                Self::handle_result(result, None, None, request_id, response_buf)
+                // The request runs on the runtime, while the calling thread waits for its result. Blocking the calling
+                // thread on the future instead could deadlock, if the future needs the single runtime worker.
+                let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+                runtime.spawn(async move {
+                    let _ = sender.send(request_future.await);
+                });
+                let result = receiver.recv().unwrap_or_else(|_| {
+                    Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "The request was dropped before completing",
+                    )))
+                });
+                let command_result = Self::handle_result(
+                    result,
+                    None,
+                    None,
+                    request_id,
+                    response_buf,
+                    streaming_threshold,
+                );
+                if let Some(core) = core {
+                    core.complete_request();
+                }
+                command_result
             }
         }
     }
@@ -492,11 +1114,22 @@ impl ClientAdapter {
         failure_callback: Option<FailureCallback>,
         request_id: usize,
         response_buf: Option<ResponseBuffer>,
+        streaming_threshold: usize,
     ) -> *mut CommandResult {
         match result {
             Ok(value) => {
                 let buf = response_buf.map(|rb| (rb.0, rb.1));
-                match valkey_value_to_command_response(value, buf) {
+                let command_response = match value {
+                    Value::BulkString(data)
+                        if buf.is_none()
+                            && streaming_threshold > 0
+                            && data.len() >= streaming_threshold =>
+                    {
+                        Ok(streamed_command_response(data))
+                    }
+                    value => valkey_value_to_command_response(value, buf),
+                };
+                match command_response {
                     Ok(command_response) => {
                         if let Some(success_callback) = success_callback {
                             unsafe {
@@ -553,9 +1186,18 @@ impl ClientAdapter {
     /// Unsafe, because calls to an FFI function. See the safety documentation of [`Self::handle_custom_error`].
     #[must_use]
     unsafe fn handle_redis_error(&self, err: RedisError, request_id: usize) -> *mut CommandResult {
-        let error_string = errors::error_message(&err);
-        let error_type = errors::error_type(&err);
-        unsafe { Self::handle_custom_error(self, error_string, error_type, request_id) }
+        match self.core.client_type {
+            ClientType::AsyncClient {
+                failure_callback, ..
+            } => {
+                unsafe {
+                    self.core.report_error_details(&err, request_id);
+                    Self::send_async_redis_error(failure_callback, err, request_id);
+                }
+                std::ptr::null_mut()
+            }
+            ClientType::SyncClient => create_error_result_with_redis_error(err),
+        }
     }
 
     /// Handles a Redis error by either invoking the failure callback (for async clients)
@@ -666,6 +1308,7 @@ pub enum PushKind {
     PushSubscribe,
     PushPSubscribe,
     PushSSubscribe,
+    PushResubscribed,
 }
 
 impl From<redis::PushKind> for PushKind {
@@ -683,6 +1326,69 @@ impl From<redis::PushKind> for PushKind {
             redis::PushKind::Subscribe => PushKind::PushSubscribe,
             redis::PushKind::PSubscribe => PushKind::PushPSubscribe,
             redis::PushKind::SSubscribe => PushKind::PushSSubscribe,
+            redis::PushKind::Resubscribed => PushKind::PushResubscribed,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum ConnectionEvent {
+    Connected,
+    Disconnected,
+    Reconnecting,
+    TopologyChanged,
+    AuthenticationRefreshFailed,
+    Redirected,
+    ProtocolDowngraded,
+    Recycled,
+}
+
+impl From<redis::ConnectionEvent> for ConnectionEvent {
+    fn from(value: redis::ConnectionEvent) -> Self {
+        match value {
+            redis::ConnectionEvent::Connected => ConnectionEvent::Connected,
+            redis::ConnectionEvent::Disconnected => ConnectionEvent::Disconnected,
+            redis::ConnectionEvent::Reconnecting => ConnectionEvent::Reconnecting,
+            redis::ConnectionEvent::TopologyChanged => ConnectionEvent::TopologyChanged,
+            redis::ConnectionEvent::AuthenticationRefreshFailed => {
+                ConnectionEvent::AuthenticationRefreshFailed
+            }
+            redis::ConnectionEvent::Redirected => ConnectionEvent::Redirected,
+            redis::ConnectionEvent::ProtocolDowngraded => ConnectionEvent::ProtocolDowngraded,
+            redis::ConnectionEvent::Recycled => ConnectionEvent::Recycled,
+        }
+    }
+}
+
+/// Forwards the connection events of a client to a [`ConnectionEventCallback`].
+struct ConnectionEventForwarder {
+    callback: ConnectionEventCallback,
+    // Set once the client adapter is created.
+    client_adapter_ptr: std::sync::atomic::AtomicUsize,
+}
+
+impl redis::ConnectionEventListener for ConnectionEventForwarder {
+    fn on_connection_event(
+        &self,
+        event: redis::ConnectionEvent,
+        address: &str,
+        error: Option<&str>,
+    ) {
+        let (error_ptr, error_len) = match error {
+            Some(error) => (error.as_ptr(), error.len() as i64),
+            None => (std::ptr::null(), 0),
+        };
+        unsafe {
+            (self.callback)(
+                self.client_adapter_ptr
+                    .load(std::sync::atomic::Ordering::Acquire),
+                event.into(),
+                address.as_ptr(),
+                address.len() as i64,
+                error_ptr,
+                error_len,
+            );
         }
     }
 }
@@ -758,86 +1464,299 @@ unsafe fn process_push_notification(
     }
 }
 
-fn create_client_internal(
-    connection_request_bytes: &[u8],
-    client_type: ClientType,
-    pubsub_callback: Option<PubSubCallback>,
-) -> Result<*const ClientAdapter, String> {
-    let request = connection_request::ConnectionRequest::parse_from_bytes(connection_request_bytes)
-        .map_err(|err| err.to_string())?;
-    // TODO: optimize this using multiple threads instead of a single worker thread (e.g. by pinning each go thread to a rust thread)
-    let runtime = Builder::new_multi_thread()
-        .enable_all()
-        .worker_threads(1)
-        .thread_name("Valkey-GLIDE thread")
-        .build()
-        .map_err(|err| {
-            let redis_error = err.into();
-            errors::error_message(&redis_error)
-        })?;
-
-    // Always create push channels to support dynamic pubsub
-    let (push_tx, mut push_rx) = tokio::sync::mpsc::unbounded_channel();
-
-    let client = runtime
-        .block_on(GlideClient::new(
-            ConnectionRequest::from(request),
-            Some(push_tx),
-        ))
-        .map_err(|err| err.to_string())?;
-
-    // Create the client adapter that will be returned and used as conn_ptr
-    let core = Arc::new(CommandExecutionCore {
-        client,
-        client_type,
-    });
-    let pubsub_callback_store = Arc::new(std::sync::RwLock::new(pubsub_callback));
-    let client_adapter = Arc::new(ClientAdapter {
-        runtime,
-        core,
-        pubsub_callback: pubsub_callback_store.clone(),
-    });
-    let client_adapter_ptr = Arc::as_ptr(&client_adapter).addr();
-
-    // Always spawn push handler to support dynamic pubsub
-    let callback_store = pubsub_callback_store.clone();
-    client_adapter.runtime.spawn(async move {
-        while let Some(push_msg) = push_rx.recv().await {
-            if (push_msg.kind == redis::PushKind::Message
-                || push_msg.kind == redis::PushKind::PMessage
-                || push_msg.kind == redis::PushKind::SMessage)
-                && let Ok(guard) = callback_store.read()
-                && let Some(callback) = *guard
-            {
-                unsafe {
-                    process_push_notification(push_msg, callback, client_adapter_ptr);
-                }
-            }
-        }
-    });
-
-    Ok(Arc::into_raw(client_adapter))
+/// Converts a resubscription push into the channel and message layout expected by [`process_push_notification`].
+///
+/// The callback receives the time in milliseconds since the disconnection as the `message`, and the restored
+/// channels and patterns as the `channel`, one entry after the other. Each entry is the kind of the subscription in a
+/// single byte (0 for exact channels, 1 for patterns and 2 for sharded channels), the length of the name as a 4 bytes
+/// big-endian integer, and the name itself, so names that contain any byte are delivered as is.
+fn resubscription_to_notification(push_msg: redis::PushInfo) -> redis::PushInfo {
+    let mut data = push_msg.data.into_iter();
+    let gap = data
+        .next()
+        .unwrap_or_else(|| Value::BulkString(b"0".to_vec()));
+    let mut subscriptions = Vec::new();
+    for entry in data {
+        let Value::Array(entry) = entry else {
+            continue;
+        };
+        let [Value::Int(kind), Value::BulkString(name)] = entry.as_slice() else {
+            continue;
+        };
+        subscriptions.push(*kind as u8);
+        subscriptions.extend_from_slice(&(name.len() as u32).to_be_bytes());
+        subscriptions.extend_from_slice(name);
+    }
+    redis::PushInfo {
+        kind: push_msg.kind,
+        data: vec![Value::BulkString(subscriptions), gap],
+    }
 }
 
-/// Creates a new `ClientAdapter` with a new `GlideClient` configured using a Protobuf `ConnectionRequest`.
-///
-/// The returned `ConnectionResponse` will only be freed by calling [`free_connection_response`].
-///
-/// `connection_request_bytes` is an array of bytes that will be parsed into a Protobuf `ConnectionRequest` object.
-/// `connection_request_len` is the number of bytes in `connection_request_bytes`.
-/// `success_callback` is the callback that will be called when a command succeeds.
-/// `failure_callback` is the callback that will be called when a command fails.
-/// `pubsub_callback` is an optional callback for pubsub messages. Pass 0 (null) to create a client without pubsub support.
+/// Delivers a push notification to the keyspace event callback or the pubsub callback of a client, if registered.
 ///
 /// # Safety
 ///
-/// * `connection_request_bytes` must point to `connection_request_len` consecutive properly initialized bytes. It must be a well-formed Protobuf `ConnectionRequest` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `connection_request_len` must not be greater than the length of the connection request bytes array. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * The `conn_ptr` pointer in the returned `ConnectionResponse` must live while the client is open/active and must be explicitly freed by calling [`close_client``].
-/// * The `connection_error_message` pointer in the returned `ConnectionResponse` must live until the returned `ConnectionResponse` pointer is passed to [`free_connection_response``].
+/// * The registered callbacks must be valid function pointers.
+/// * `client_adapter_ptr` must be the address of the client adapter that the callbacks were registered on.
+unsafe fn deliver_push_notification(
+    push_msg: redis::PushInfo,
+    pubsub_callback_store: &std::sync::RwLock<Option<PubSubCallback>>,
+    keyspace_event_callback_store: &std::sync::RwLock<Option<KeyspaceEventCallback>>,
+    client_adapter_ptr: usize,
+) {
+    let push_msg = if push_msg.kind == redis::PushKind::Resubscribed {
+        resubscription_to_notification(push_msg)
+    } else {
+        push_msg
+    };
+    if push_msg.kind == redis::PushKind::PMessage
+        && let Ok(guard) = keyspace_event_callback_store.read()
+        && let Some(callback) = *guard
+        && let [_, Value::BulkString(channel), Value::BulkString(message)] =
+            push_msg.data.as_slice()
+        && let Some(event) = keyspace_events::parse_keyspace_event(channel, message)
+    {
+        unsafe {
+            callback(
+                client_adapter_ptr,
+                event.event.as_ptr(),
+                event.event.len() as i64,
+                event.key.as_ptr(),
+                event.key.len() as i64,
+                event.db,
+            );
+        }
+        return;
+    }
+    if (push_msg.kind == redis::PushKind::Message
+        || push_msg.kind == redis::PushKind::PMessage
+        || push_msg.kind == redis::PushKind::SMessage
+        || push_msg.kind == redis::PushKind::Resubscribed)
+        && let Ok(guard) = pubsub_callback_store.read()
+        && let Some(callback) = *guard
+    {
+        unsafe {
+            process_push_notification(push_msg, callback, client_adapter_ptr);
+        }
+    }
+}
+
+async fn new_glide_client(
+    request: ConnectionRequest,
+    push_sender: tokio::sync::mpsc::UnboundedSender<redis::PushInfo>,
+    connection_event_forwarder: Option<&Arc<ConnectionEventForwarder>>,
+) -> Result<GlideClient, glide_core::client::ConnectionError> {
+    match connection_event_forwarder {
+        Some(forwarder) => {
+            GlideClient::new_with_connection_events(request, Some(push_sender), forwarder.clone())
+                .await
+        }
+        None => GlideClient::new(request, Some(push_sender)).await,
+    }
+}
+
+/// The state needed to connect a client and wrap it in a `ClientAdapter`.
+struct PendingClient {
+    runtime: Runtime,
+    request: ConnectionRequest,
+    client_type: ClientType,
+    pubsub_callback: Option<PubSubCallback>,
+    #[cfg(feature = "mock-server")]
+    mock_server: Option<MockServer>,
+    push_tx: tokio::sync::mpsc::UnboundedSender<redis::PushInfo>,
+    push_rx: tokio::sync::mpsc::UnboundedReceiver<redis::PushInfo>,
+    connection_event_forwarder: Option<Arc<ConnectionEventForwarder>>,
+    #[cfg(feature = "fault-injection")]
+    fault_injector: Arc<redis::FaultInjector>,
+}
+
+impl PendingClient {
+    fn new(
+        request: ConnectionRequest,
+        client_type: ClientType,
+        pubsub_callback: Option<PubSubCallback>,
+        connection_event_callback: Option<ConnectionEventCallback>,
+    ) -> Result<Self, String> {
+        // TODO: optimize this using multiple threads instead of a single worker thread (e.g. by pinning each go thread to a rust thread)
+        let runtime = Builder::new_multi_thread()
+            .enable_all()
+            .worker_threads(1)
+            .thread_name("Valkey-GLIDE thread")
+            .build()
+            .map_err(|err| {
+                let redis_error = err.into();
+                errors::error_message(&redis_error)
+            })?;
+
+        #[cfg(feature = "fault-injection")]
+        let fault_injector = Arc::new(redis::FaultInjector::default());
+        #[cfg(feature = "fault-injection")]
+        let request = ConnectionRequest {
+            fault_injector: Some(fault_injector.clone()),
+            ..request
+        };
+
+        // Always create push channels to support dynamic pubsub
+        let (push_tx, push_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let connection_event_forwarder = connection_event_callback.map(|callback| {
+            Arc::new(ConnectionEventForwarder {
+                callback,
+                client_adapter_ptr: std::sync::atomic::AtomicUsize::new(0),
+            })
+        });
+        Ok(Self {
+            runtime,
+            request,
+            client_type,
+            pubsub_callback,
+            #[cfg(feature = "mock-server")]
+            mock_server: None,
+            push_tx,
+            push_rx,
+            connection_event_forwarder,
+            #[cfg(feature = "fault-injection")]
+            fault_injector,
+        })
+    }
+
+    async fn connect(&self) -> Result<GlideClient, String> {
+        new_glide_client(
+            self.request.clone(),
+            self.push_tx.clone(),
+            self.connection_event_forwarder.as_ref(),
+        )
+        .await
+        .map_err(|err| err.to_string())
+    }
+
+    /// Wraps the connected `client` in a `ClientAdapter`, and starts delivering its push notifications.
+    fn into_client_adapter(self, client: GlideClient) -> *const ClientAdapter {
+        let PendingClient {
+            runtime,
+            client_type,
+            pubsub_callback,
+            #[cfg(feature = "mock-server")]
+            mock_server,
+            push_tx,
+            mut push_rx,
+            connection_event_forwarder,
+            #[cfg(feature = "fault-injection")]
+            fault_injector,
+            ..
+        } = self;
+
+        // Create the client adapter that will be returned and used as conn_ptr
+        let core = Arc::new(CommandExecutionCore {
+            client: std::sync::RwLock::new(client),
+            client_type,
+            push_sender: push_tx,
+            connection_event_forwarder: connection_event_forwarder.clone(),
+            response_streaming_threshold: std::sync::atomic::AtomicUsize::new(0),
+            coalescer: std::sync::RwLock::new(None),
+            deduplicator: std::sync::RwLock::new(None),
+            draining: std::sync::atomic::AtomicBool::new(false),
+            inflight_requests: std::sync::atomic::AtomicUsize::new(0),
+            request_completed: tokio::sync::Notify::new(),
+            error_details_callback: std::sync::RwLock::new(None),
+            command_metadata_callback: std::sync::RwLock::new(None),
+            slow_command_log: std::sync::RwLock::new(None),
+            value_codec: std::sync::RwLock::new(None),
+        });
+        let pubsub_callback_store = Arc::new(std::sync::RwLock::new(pubsub_callback));
+        let keyspace_event_callback_store = Arc::new(std::sync::RwLock::new(None));
+        let client_adapter = Arc::new(ClientAdapter {
+            runtime,
+            core,
+            pubsub_callback: pubsub_callback_store.clone(),
+            keyspace_event_callback: keyspace_event_callback_store.clone(),
+            pubsub_buffer: Arc::new(std::sync::RwLock::new(None)),
+            #[cfg(feature = "mock-server")]
+            mock_server,
+            #[cfg(feature = "fault-injection")]
+            fault_injector,
+        });
+        let client_adapter_ptr = Arc::as_ptr(&client_adapter).addr();
+        if let Some(forwarder) = &connection_event_forwarder {
+            forwarder
+                .client_adapter_ptr
+                .store(client_adapter_ptr, std::sync::atomic::Ordering::Release);
+        }
+
+        // Always spawn push handler to support dynamic pubsub
+        let pubsub_buffer_store = client_adapter.pubsub_buffer.clone();
+        client_adapter.runtime.spawn(async move {
+            while let Some(push_msg) = push_rx.recv().await {
+                let buffer = pubsub_buffer_store
+                    .read()
+                    .ok()
+                    .and_then(|buffer| buffer.clone());
+                match buffer {
+                    Some(buffer) => buffer.push(push_msg),
+                    None => unsafe {
+                        deliver_push_notification(
+                            push_msg,
+                            &pubsub_callback_store,
+                            &keyspace_event_callback_store,
+                            client_adapter_ptr,
+                        )
+                    },
+                }
+            }
+        });
+
+        Arc::into_raw(client_adapter)
+    }
+}
+
+fn create_client_internal(
+    request: ConnectionRequest,
+    client_type: ClientType,
+    pubsub_callback: Option<PubSubCallback>,
+    connection_event_callback: Option<ConnectionEventCallback>,
+) -> Result<*const ClientAdapter, String> {
+    let pending = PendingClient::new(
+        request,
+        client_type,
+        pubsub_callback,
+        connection_event_callback,
+    )?;
+    let client = pending.runtime.block_on(pending.connect())?;
+    Ok(pending.into_client_adapter(client))
+}
+
+/// Parses a Protobuf `ConnectionRequest`, which may be compressed with LZ4 as described in
+/// [`glide_core::compression::decompress_payload`].
+fn parse_connection_request(
+    request_bytes: &[u8],
+) -> Result<connection_request::ConnectionRequest, String> {
+    let request_bytes = glide_core::compression::decompress_payload(request_bytes)
+        .map_err(|err| err.to_string())?;
+    connection_request::ConnectionRequest::parse_from_bytes(&request_bytes)
+        .map_err(|err| err.to_string())
+}
+
+/// Creates a new `ClientAdapter` with a new `GlideClient` configured using a Protobuf `ConnectionRequest`.
+///
+/// The `ConnectionRequest` may be compressed with LZ4, to reduce the cost of copying a large request.
+///
+/// The returned `ConnectionResponse` will only be freed by calling [`free_connection_response`].
+///
+/// `connection_request_bytes` is an array of bytes that will be parsed into a Protobuf `ConnectionRequest` object.
+/// `connection_request_len` is the number of bytes in `connection_request_bytes`.
+/// `success_callback` is the callback that will be called when a command succeeds.
+/// `failure_callback` is the callback that will be called when a command fails.
+/// `pubsub_callback` is an optional callback for pubsub messages. Pass 0 (null) to create a client without pubsub support.
+///
+/// # Safety
+///
+/// * `connection_request_bytes` must point to `connection_request_len` consecutive properly initialized bytes. It must be a well-formed Protobuf `ConnectionRequest` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `connection_request_len` must not be greater than the length of the connection request bytes array. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * The `conn_ptr` pointer in the returned `ConnectionResponse` must live while the client is open/active and must be explicitly freed by calling [`close_client``].
+/// * The `connection_error_message` pointer in the returned `ConnectionResponse` must live until the returned `ConnectionResponse` pointer is passed to [`free_connection_response``].
 /// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
 /// * If `pubsub_callback` is non-zero, it must be a valid function pointer that lives while the client is open/active.
-// TODO: Consider making this async
 #[unsafe(no_mangle)]
 pub unsafe extern "C-unwind" fn create_client(
     connection_request_bytes: *const u8,
@@ -845,6 +1764,87 @@ pub unsafe extern "C-unwind" fn create_client(
     client_type: *const ClientType,
     pubsub_callback: PubSubCallback,
 ) -> *const ConnectionResponse {
+    unsafe {
+        create_client_with_connection_events(
+            connection_request_bytes,
+            connection_request_len,
+            client_type,
+            pubsub_callback,
+            None,
+        )
+    }
+}
+
+/// Creates a new `ClientAdapter` like [`create_client`], and reports the connection events of the client to `connection_event_callback`.
+///
+/// `connection_event_callback` is an optional callback for connection events. Pass 0 (null) to create a client without connection events.
+///
+/// # Safety
+///
+/// * The safety requirements of [`create_client`] apply.
+/// * If `connection_event_callback` is non-null, it must be a valid function pointer that lives while the client is open/active.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn create_client_with_connection_events(
+    connection_request_bytes: *const u8,
+    connection_request_len: usize,
+    client_type: *const ClientType,
+    pubsub_callback: PubSubCallback,
+    connection_event_callback: Option<ConnectionEventCallback>,
+) -> *const ConnectionResponse {
+    assert!(!connection_request_bytes.is_null());
+    let request_bytes =
+        unsafe { std::slice::from_raw_parts(connection_request_bytes, connection_request_len) };
+    let client_type = unsafe { &*client_type };
+
+    // Convert callback pointer to Option - 0 means no callback
+    let callback_opt = if pubsub_callback as usize == 0 {
+        None
+    } else {
+        Some(pubsub_callback)
+    };
+
+    let result = parse_connection_request(request_bytes).and_then(|request| {
+        create_client_internal(
+            ConnectionRequest::from(request),
+            client_type.clone(),
+            callback_opt,
+            connection_event_callback,
+        )
+    });
+    into_connection_response(result)
+}
+
+/// Creates a new `ClientAdapter` like [`create_client_with_connection_events`], without blocking the calling thread
+/// while the client connects.
+///
+/// The function returns immediately, and `creation_callback` is called with `request_id` and the `ConnectionResponse`
+/// once the client is connected, or failed to connect. If `connection_request_bytes` can't be parsed, the callback is
+/// called before this function returns.
+///
+/// # Parameters
+///
+/// * `connection_request_bytes`: Pointer to the bytes of a Protobuf `ConnectionRequest`.
+/// * `connection_request_len`: The number of bytes in `connection_request_bytes`.
+/// * `client_type`: Pointer to the `ClientType` of the client.
+/// * `pubsub_callback`: An optional callback for pubsub messages. Pass 0 (null) to create a client without pubsub support.
+/// * `connection_event_callback`: An optional callback for connection events. Pass 0 (null) to create a client without connection events.
+/// * `request_id`: Unique identifier that is passed back to `creation_callback`.
+/// * `creation_callback`: The callback that receives the `ConnectionResponse`.
+///
+/// # Safety
+///
+/// * The safety requirements of [`create_client`] and [`create_client_with_connection_events`] apply.
+/// * `creation_callback` must be a valid function pointer that lives until it's called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn create_client_async(
+    connection_request_bytes: *const u8,
+    connection_request_len: usize,
+    client_type: *const ClientType,
+    pubsub_callback: PubSubCallback,
+    connection_event_callback: Option<ConnectionEventCallback>,
+    request_id: usize,
+    creation_callback: ClientCreationCallback,
+) {
     assert!(!connection_request_bytes.is_null());
     let request_bytes =
         unsafe { std::slice::from_raw_parts(connection_request_bytes, connection_request_len) };
@@ -857,7 +1857,42 @@ pub unsafe extern "C-unwind" fn create_client(
         Some(pubsub_callback)
     };
 
-    let response = match create_client_internal(request_bytes, client_type.clone(), callback_opt) {
+    let pending = parse_connection_request(request_bytes).and_then(|request| {
+        PendingClient::new(
+            ConnectionRequest::from(request),
+            client_type.clone(),
+            callback_opt,
+            connection_event_callback,
+        )
+    });
+    let pending = match pending {
+        Ok(pending) => pending,
+        Err(err) => {
+            let response = into_connection_response::<ClientAdapter>(Err(err));
+            unsafe { creation_callback(request_id, response) };
+            return;
+        }
+    };
+
+    let handle = pending.runtime.handle().clone();
+    handle.spawn(async move {
+        match pending.connect().await {
+            Ok(client) => {
+                let response = into_connection_response(Ok(pending.into_client_adapter(client)));
+                unsafe { creation_callback(request_id, response) };
+            }
+            Err(err) => {
+                let response = into_connection_response::<ClientAdapter>(Err(err));
+                unsafe { creation_callback(request_id, response) };
+                // The runtime is owned by this task, so it can't wait for its own shutdown.
+                pending.runtime.shutdown_background();
+            }
+        }
+    });
+}
+
+fn into_connection_response<T>(result: Result<*const T, String>) -> *const ConnectionResponse {
+    let response = match result {
         Err(err) => ConnectionResponse {
             conn_ptr: std::ptr::null(),
             connection_error_message: CString::into_raw(
@@ -872,467 +1907,5721 @@ pub unsafe extern "C-unwind" fn create_client(
     Box::into_raw(Box::new(response))
 }
 
-/// Closes the given `GlideClient`, freeing it from the heap.
+/// Creates a new `ClientAdapter` with a standalone `GlideClient` connected to an in-process mock server, instead of a
+/// Valkey server, so the unit tests of the wrappers can run without a server.
 ///
-/// `client_adapter_ptr` is a pointer to a valid `GlideClient` returned in the `ConnectionResponse` from [`create_client`].
+/// The mock server answers the commands from a table of canned responses set with [`mock_set_response`], and fails
+/// them with the errors injected with [`mock_inject_error`]. Commands without a canned response fail with an
+/// `unknown command` error, except for the commands sent while connecting and `PING`. The client uses RESP3, and the
+/// mock server stops when the client is closed.
 ///
-/// # Panics
+/// Only available when the library is built with the `mock-server` feature, which is meant for tests.
 ///
-/// This function panics when called with a null `client_adapter_ptr`.
+/// The returned `ConnectionResponse` will only be freed by calling [`free_connection_response`].
 ///
 /// # Safety
 ///
-/// * `close_client` can only be called once per client. Calling it twice is undefined behavior, since the address will be freed twice.
-/// * `close_client` must be called after `free_connection_response` has been called to avoid creating a dangling pointer in the `ConnectionResponse`.
-/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be valid until `close_client` is called.
+/// * `client_type` must be a valid pointer to a `ClientType`.
+/// * The safety requirements of [`create_client`] for the returned `ConnectionResponse` and the callbacks apply.
+#[cfg(feature = "mock-server")]
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn close_client(client_adapter_ptr: *const c_void) {
+pub unsafe extern "C-unwind" fn create_mock_client(
+    client_type: *const ClientType,
+    pubsub_callback: PubSubCallback,
+) -> *const ConnectionResponse {
+    let client_type = unsafe { &*client_type };
+    let callback_opt = if pubsub_callback as usize == 0 {
+        None
+    } else {
+        Some(pubsub_callback)
+    };
+
+    let result = MockServer::start()
+        .map_err(|err| err.to_string())
+        .and_then(|mock_server| {
+            // The default protocol of the request is RESP3.
+            let mut request = connection_request::ConnectionRequest::new();
+            request.addresses.push(connection_request::NodeAddress {
+                host: mock_server.address().host.clone().into(),
+                port: mock_server.address().port as u32,
+                ..Default::default()
+            });
+            let mut pending = PendingClient::new(
+                ConnectionRequest::from(request),
+                client_type.clone(),
+                callback_opt,
+                None,
+            )?;
+            pending.mock_server = Some(mock_server);
+            let client = pending.runtime.block_on(pending.connect())?;
+            Ok(pending.into_client_adapter(client))
+        });
+    into_connection_response(result)
+}
+
+/// Runs `action` with the mock server of a client created by [`create_mock_client`].
+/// Returns a C string with the error message, or `null` on success.
+#[cfg(feature = "mock-server")]
+unsafe fn with_mock_server(
+    client_adapter_ptr: *const c_void,
+    action: impl FnOnce(&MockServer) -> RedisResult<()>,
+) -> *const c_char {
     assert!(!client_adapter_ptr.is_null());
-    // This will bring the strong count down to 0 once all client requests are done.
-    unsafe { Arc::decrement_strong_count(client_adapter_ptr as *const ClientAdapter) };
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *const ClientAdapter)
+    };
+    let result = match &client_adapter.mock_server {
+        Some(mock_server) => action(mock_server).map_err(|err| error_message(&err)),
+        None => Err("The client wasn't created by create_mock_client".to_string()),
+    };
+    match result {
+        Ok(()) => std::ptr::null(),
+        Err(err) => CString::new(err)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw(),
+    }
 }
 
-/// Deallocates a `ConnectionResponse`.
+/// Answers the commands of a mock client that start with the given arguments with a canned response.
 ///
-/// This function also frees the contained error. If the contained error is a null pointer, the function returns and only the `ConnectionResponse` is freed.
+/// When several canned responses match a command, the one with the most arguments is used. The command name is matched
+/// case-insensitively, and the other arguments exactly. Setting a response again for the same arguments replaces it.
 ///
-/// # Panics
+/// # Parameters
 ///
-/// This function panics when called with a null `ConnectionResponse` pointer.
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_mock_client`].
+/// * `arg_count`: The number of arguments the commands start with, including the command name.
+/// * `args`: Pointer to an array of pointers to the arguments.
+/// * `args_len`: Pointer to an array of the lengths of the arguments.
+/// * `response`: Pointer to the response, encoded in RESP, such as `+OK\r\n` or `:1\r\n`.
+/// * `response_len`: The length of the response.
+///
+/// # Returns
+///
+/// `null` on success, or an error message if the client isn't a mock client or the response isn't valid RESP.
+/// The error message must be freed with [`free_c_string`].
 ///
 /// # Safety
 ///
-/// * `free_connection_response` can only be called once per `ConnectionResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
-/// * `connection_response_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `connection_response_ptr` must be valid until `free_connection_response` is called.
-/// * The contained `connection_error_message` must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * The contained `connection_error_message` must be valid until `free_connection_response` is called and it must outlive the `ConnectionResponse` that contains it.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_mock_client`], before [`close_client`] was called with the pointer.
+/// * `args` and `args_len` must point to arrays of `arg_count` elements, and each argument must point to as many bytes as its length.
+/// * `response` must point to `response_len` consecutive properly initialized bytes.
+#[cfg(feature = "mock-server")]
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn free_connection_response(
-    connection_response_ptr: *mut ConnectionResponse,
-) {
-    assert!(!connection_response_ptr.is_null());
-    let connection_response = unsafe { Box::from_raw(connection_response_ptr) };
-    let connection_error_message = connection_response.connection_error_message;
-    drop(connection_response);
-    if !connection_error_message.is_null() {
-        drop(unsafe { CString::from_raw(connection_error_message as *mut c_char) });
+pub unsafe extern "C" fn mock_set_response(
+    client_adapter_ptr: *const c_void,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    response: *const u8,
+    response_len: usize,
+) -> *const c_char {
+    let prefix = unsafe { mock_command_prefix(arg_count, args, args_len) };
+    let response = unsafe { from_raw_parts(response, response_len) }.to_vec();
+    unsafe {
+        with_mock_server(client_adapter_ptr, |mock_server| {
+            mock_server.set_response(prefix, response)
+        })
     }
 }
 
-/// Provides the string mapping for the ResponseType enum.
+/// Fails the next commands of a mock client that start with the given arguments with an error.
+/// Injected errors take precedence over canned responses, and commands are matched as in [`mock_set_response`].
 ///
-/// Important: the returned pointer is a pointer to a constant string and should not be freed.
-#[unsafe(no_mangle)]
-pub extern "C" fn get_response_type_string(response_type: ResponseType) -> *const c_char {
-    let c_str = match response_type {
-        ResponseType::Null => c"Null",
-        ResponseType::Int => c"Int",
-        ResponseType::Float => c"Float",
-        ResponseType::Bool => c"Bool",
-        ResponseType::String => c"String",
-        ResponseType::Array => c"Array",
-        ResponseType::Map => c"Map",
-        ResponseType::Sets => c"Sets",
-        ResponseType::Ok => c"Ok",
-        ResponseType::Error => c"Error",
-    };
-    c_str.as_ptr()
-}
-
-/// Deallocates a `CommandResponse`.
+/// # Parameters
 ///
-/// This function also frees the contained string_value and array_value. If the string_value and array_value are null pointers, the function returns and only the `CommandResponse` is freed.
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_mock_client`].
+/// * `arg_count`: The number of arguments the commands start with, including the command name.
+/// * `args`: Pointer to an array of pointers to the arguments.
+/// * `args_len`: Pointer to an array of the lengths of the arguments.
+/// * `error_message`: The error returned by the server, starting with its error code, such as `ERR failure` or `MOVED 3999 127.0.0.1:6381`.
+/// * `count`: The number of commands that fail. Pass 0 to fail every matching command.
+///
+/// # Returns
+///
+/// `null` on success, or an error message if the client isn't a mock client or `error_message` contains line breaks.
+/// The error message must be freed with [`free_c_string`].
 ///
 /// # Safety
 ///
-/// * `free_command_response` can only be called once per `CommandResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
-/// * `command_response_ptr` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
-/// * `command_response_ptr` must be valid until `free_command_response` is called.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_mock_client`], before [`close_client`] was called with the pointer.
+/// * `args` and `args_len` must point to arrays of `arg_count` elements, and each argument must point to as many bytes as its length.
+/// * `error_message` must be a valid null-terminated C string.
+#[cfg(feature = "mock-server")]
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn free_command_response(command_response_ptr: *mut CommandResponse) {
-    if !command_response_ptr.is_null() {
-        let command_response = unsafe { Box::from_raw(command_response_ptr) };
-        unsafe { free_command_response_elements(*command_response) };
+pub unsafe extern "C" fn mock_inject_error(
+    client_adapter_ptr: *const c_void,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    error_message: *const c_char,
+    count: u32,
+) -> *const c_char {
+    let prefix = unsafe { mock_command_prefix(arg_count, args, args_len) };
+    let message = unsafe { CStr::from_ptr(error_message) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe {
+        with_mock_server(client_adapter_ptr, |mock_server| {
+            mock_server.inject_error(prefix, message, count)
+        })
     }
 }
 
-/// Frees the nested elements of `CommandResponse`.
-/// TODO: Add a test case to check for memory leak.
+/// Removes the canned responses and the injected errors of a mock client.
 ///
 /// # Safety
 ///
-/// * `free_command_response_elements` can only be called once per `CommandResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
-/// * The contained `string_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
-/// * The contained `string_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
-/// * The contained `array_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
-/// * The contained `array_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
-/// * The contained `map_key` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
-/// * The contained `map_key` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
-/// * The contained `map_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
-/// * The contained `map_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
-unsafe fn free_command_response_elements(command_response: CommandResponse) {
-    let string_value = command_response.string_value;
-    let string_value_len = command_response.string_value_len;
-    let array_value = command_response.array_value;
-    let array_value_len = command_response.array_value_len;
-    let map_key = command_response.map_key;
-    let map_value = command_response.map_value;
-    let sets_value = command_response.sets_value;
-    let sets_value_len = command_response.sets_value_len;
-    if !string_value.is_null() {
-        let len = string_value_len as usize;
-        unsafe { Vec::from_raw_parts(string_value, len, len) };
-    }
-    if !array_value.is_null() {
-        let len = array_value_len as usize;
-        let vec = unsafe { Vec::from_raw_parts(array_value, len, len) };
-        for element in vec.into_iter() {
-            unsafe { free_command_response_elements(element) };
-        }
-    }
-    if !map_key.is_null() {
-        unsafe { free_command_response(map_key) };
-    }
-    if !map_value.is_null() {
-        unsafe { free_command_response(map_value) };
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_mock_client`], before [`close_client`] was called with the pointer.
+#[cfg(feature = "mock-server")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mock_reset(client_adapter_ptr: *const c_void) -> *const c_char {
+    unsafe {
+        with_mock_server(client_adapter_ptr, |mock_server| {
+            mock_server.reset();
+            Ok(())
+        })
     }
-    if !sets_value.is_null() {
-        let len = sets_value_len as usize;
-        let vec = unsafe { Vec::from_raw_parts(sets_value, len, len) };
-        for element in vec.into_iter() {
-            unsafe { free_command_response_elements(element) };
-        }
+}
+
+#[cfg(feature = "mock-server")]
+unsafe fn mock_command_prefix(
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+) -> Vec<Vec<u8>> {
+    if args.is_null() || args_len.is_null() {
+        return Vec::new();
     }
+    unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
+        .into_iter()
+        .map(<[u8]>::to_vec)
+        .collect()
 }
 
-/// Converts a double pointer to a vec.
+/// The type of a fault injected with [`inject_fault`].
+#[cfg(feature = "fault-injection")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum FaultType {
+    /// Delays the commands by `latency_ms` before sending them.
+    FaultLatency,
+    /// Fails the commands with the server error `message`, such as `ERR injected failure`.
+    FaultError,
+    /// Fails the commands with a `MOVED` redirection of `slot` to the node at `message`, given as `host:port`.
+    FaultMoved,
+    /// Fails the commands as if the connection to the node was dropped, which makes the client reconnect to the node.
+    FaultDisconnect,
+}
+
+/// A fault injected with [`inject_fault`], and the commands it is injected into.
+#[cfg(feature = "fault-injection")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FaultSpec {
+    pub fault_type: FaultType,
+    /// The address of the targeted node, given as `host:port`. `null` targets every node.
+    pub node: *const c_char,
+    /// The name of the targeted command, matched case-insensitively. `null` targets every command.
+    pub command: *const c_char,
+    /// The delay of a `FaultLatency` fault, in milliseconds.
+    pub latency_ms: u64,
+    /// The error of a `FaultError` fault, or the redirection address of a `FaultMoved` fault.
+    pub message: *const c_char,
+    /// The redirected slot of a `FaultMoved` fault.
+    pub slot: u16,
+    /// The number of commands the fault is injected into. Pass 0 to inject the fault until [`clear_faults`] is called.
+    pub count: u32,
+}
+
+/// Injects a fault into the commands a client sends to the nodes, so integration tests can deterministically
+/// reproduce slow nodes, redirections and dropped connections. Each command gets the first matching fault, in the
+/// order the faults were injected. Faults are injected into single-node commands of cluster clients, and into all the
+/// commands of standalone clients.
+///
+/// Only available when the library is built with the `fault-injection` feature.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `fault_spec`: Pointer to the injected fault.
+///
+/// # Returns
+///
+/// `null` on success, or an error message if one of the strings of `fault_spec` isn't valid UTF-8, or the message of
+/// a `FaultError` or `FaultMoved` fault is missing. The error message must be freed with [`free_c_string`].
 ///
 /// # Safety
 ///
-/// `convert_double_pointer_to_vec` returns a `Vec` of u8 slice which holds pointers of `go`
-/// strings. The returned `Vec<&'a [u8]>` is meant to be copied into Rust code. Storing them
-/// for later use will cause the program to crash as the pointers will be freed by go's gc
-unsafe fn convert_double_pointer_to_vec<'a>(
-    data: *const *const c_void,
-    len: c_ulong,
-    data_len: *const c_ulong,
-) -> Vec<&'a [u8]> {
-    let string_ptrs = unsafe { from_raw_parts(data, len as usize) };
-    let string_lengths = unsafe { from_raw_parts(data_len, len as usize) };
-    let mut result = Vec::<&[u8]>::with_capacity(string_ptrs.len());
-    for (i, &str_ptr) in string_ptrs.iter().enumerate() {
-        let slice = unsafe { from_raw_parts(str_ptr as *const u8, string_lengths[i] as usize) };
-        result.push(slice);
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`], before [`close_client`] was called with the pointer.
+/// * `fault_spec` must be a valid pointer to a `FaultSpec`, whose strings are `null` or valid null-terminated C strings.
+#[cfg(feature = "fault-injection")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn inject_fault(
+    client_adapter_ptr: *const c_void,
+    fault_spec: *const FaultSpec,
+) -> *const c_char {
+    assert!(!client_adapter_ptr.is_null());
+    assert!(!fault_spec.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *const ClientAdapter)
+    };
+    match unsafe { convert_fault_spec(&*fault_spec) } {
+        Ok(fault) => {
+            client_adapter.fault_injector.add(fault);
+            std::ptr::null()
+        }
+        Err(err) => CString::new(err)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw(),
     }
-    result
-}
-
-fn convert_vec_to_pointer<T>(mut vec: Vec<T>) -> (*mut T, c_long) {
-    vec.shrink_to_fit();
-    let vec_ptr = vec.as_mut_ptr();
-    let len = vec.len() as c_long;
-    mem::forget(vec);
-    (vec_ptr, len)
 }
 
-fn valkey_value_to_command_response(
-    value: Value,
-    response_buf: Option<(*mut u8, usize)>,
-) -> RedisResult<CommandResponse> {
-    let mut command_response = CommandResponse::default();
-    let result: RedisResult<CommandResponse> = match value {
-        Value::Nil => Ok(command_response),
-        Value::SimpleString(text) => {
-            let vec: Vec<u8> = text.into_bytes();
-            let (vec_ptr, len) = convert_vec_to_pointer(vec);
-            command_response.string_value = vec_ptr as *mut c_char;
-            command_response.string_value_len = len;
-            command_response.response_type = ResponseType::String;
-            Ok(command_response)
-        }
-        Value::BulkString(data) => {
-            let data = if let Some((buf, buf_len)) = response_buf {
-                if data.len() > buf_len {
-                    return Err(RedisError::from((
-                        ErrorKind::ClientError,
-                        "Value size exceeds buffer capacity",
-                        format!(
-                            "value is {} bytes but buffer is {} bytes",
-                            data.len(),
-                            buf_len
-                        ),
-                    )));
-                }
-                // Copy data directly into the caller's buffer; the command response
-                // will carry the number of bytes written instead of the data itself.
-                unsafe {
-                    std::ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
-                }
-                data.len().to_string().into_bytes()
-            } else {
-                data
-            };
-            let (vec_ptr, len) = convert_vec_to_pointer(data);
-            command_response.string_value = vec_ptr as *mut c_char;
-            command_response.string_value_len = len;
-            command_response.response_type = ResponseType::String;
-            Ok(command_response)
-        }
-        Value::VerbatimString { format: _, text } => {
-            let vec: Vec<u8> = text.into_bytes();
-            let (vec_ptr, len) = convert_vec_to_pointer(vec);
-            command_response.string_value = vec_ptr as *mut c_char;
-            command_response.string_value_len = len;
-            command_response.response_type = ResponseType::String;
-            Ok(command_response)
-        }
-        Value::Okay => {
-            command_response.response_type = ResponseType::Ok;
-            Ok(command_response)
-        }
-        Value::Int(num) => {
-            command_response.int_value = num;
-            command_response.response_type = ResponseType::Int;
-            Ok(command_response)
-        }
-        Value::Double(num) => {
-            command_response.float_value = num;
-            command_response.response_type = ResponseType::Float;
-            Ok(command_response)
-        }
-        Value::Boolean(boolean) => {
-            command_response.bool_value = boolean;
-            command_response.response_type = ResponseType::Bool;
-            Ok(command_response)
+/// Converts a [`FaultSpec`] to the fault injected by the client.
+///
+/// # Safety
+///
+/// * The strings of `fault_spec` must be `null` or valid null-terminated C strings.
+#[cfg(feature = "fault-injection")]
+unsafe fn convert_fault_spec(fault_spec: &FaultSpec) -> Result<redis::FaultSpec, String> {
+    let to_string = |ptr: *const c_char| -> Result<Option<String>, String> {
+        if ptr.is_null() {
+            return Ok(None);
         }
-        Value::Array(array) => {
-            let vec: Result<Vec<CommandResponse>, RedisError> = array
-                .into_iter()
-                .map(|v| valkey_value_to_command_response(v, None))
-                .collect();
-            let (vec_ptr, len) = convert_vec_to_pointer(vec?);
-            command_response.array_value = vec_ptr;
-            command_response.array_value_len = len;
-            command_response.response_type = ResponseType::Array;
-            Ok(command_response)
+        unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .map(|value| Some(value.to_string()))
+            .map_err(|err| err.to_string())
+    };
+    let message = to_string(fault_spec.message)?;
+    let required_message = || {
+        message
+            .clone()
+            .ok_or_else(|| format!("{:?} requires a message", fault_spec.fault_type))
+    };
+    let kind = match fault_spec.fault_type {
+        FaultType::FaultLatency => {
+            redis::FaultKind::Latency(Duration::from_millis(fault_spec.latency_ms))
         }
-        Value::Map(map) => {
-            let result: Result<Vec<CommandResponse>, RedisError> = map
-                .into_iter()
-                .map(|(key, val)| {
-                    let mut map_response = CommandResponse::default();
-
-                    let map_key = match valkey_value_to_command_response(key, None) {
-                        Ok(map_key) => map_key,
-                        Err(err) => return Err(err),
-                    };
-                    map_response.map_key = Box::into_raw(Box::new(map_key));
+        FaultType::FaultError => redis::FaultKind::Error(required_message()?),
+        FaultType::FaultMoved => redis::FaultKind::Moved {
+            slot: fault_spec.slot,
+            address: required_message()?,
+        },
+        FaultType::FaultDisconnect => redis::FaultKind::Disconnect,
+    };
+    Ok(redis::FaultSpec {
+        kind,
+        node: to_string(fault_spec.node)?,
+        command: to_string(fault_spec.command)?,
+        count: fault_spec.count,
+    })
+}
 
-                    let map_val = match valkey_value_to_command_response(val, None) {
-                        Ok(map_val) => map_val,
-                        Err(err) => return Err(err),
-                    };
-                    map_response.map_value = Box::into_raw(Box::new(map_val));
+/// Removes the faults injected with [`inject_fault`].
+///
+/// Only available when the library is built with the `fault-injection` feature.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`], before [`close_client`] was called with the pointer.
+#[cfg(feature = "fault-injection")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn clear_faults(client_adapter_ptr: *const c_void) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *const ClientAdapter)
+    };
+    client_adapter.fault_injector.clear();
+}
 
-                    Ok(map_response)
-                })
-                .collect::<Result<Vec<CommandResponse>, RedisError>>();
+/// Closes the given `GlideClient`, freeing it from the heap.
+///
+/// `client_adapter_ptr` is a pointer to a valid `GlideClient` returned in the `ConnectionResponse` from [`create_client`].
+///
+/// # Panics
+///
+/// This function panics when called with a null `client_adapter_ptr`.
+///
+/// # Safety
+///
+/// * `close_client` can only be called once per client. Calling it twice is undefined behavior, since the address will be freed twice.
+/// * `close_client` must be called after `free_connection_response` has been called to avoid creating a dangling pointer in the `ConnectionResponse`.
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be valid until `close_client` is called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn close_client(client_adapter_ptr: *const c_void) {
+    assert!(!client_adapter_ptr.is_null());
+    // This will bring the strong count down to 0 once all client requests are done.
+    unsafe { Arc::decrement_strong_count(client_adapter_ptr as *const ClientAdapter) };
+}
 
-            let (vec_ptr, len) = convert_vec_to_pointer(result?);
-            command_response.array_value = vec_ptr;
-            command_response.array_value_len = len;
-            command_response.response_type = ResponseType::Map;
-            Ok(command_response)
-        }
-        Value::Set(array) => {
-            let vec: Result<Vec<CommandResponse>, RedisError> = array
-                .into_iter()
-                .map(|v| valkey_value_to_command_response(v, None))
-                .collect();
-            let (vec_ptr, len) = convert_vec_to_pointer(vec?);
-            command_response.sets_value = vec_ptr;
-            command_response.sets_value_len = len;
-            command_response.response_type = ResponseType::Sets;
-            Ok(command_response)
+/// Gracefully closes the given `GlideClient`, as an alternative to [`close_client`].
+///
+/// The client stops accepting new commands, which fail with a `ClientError`, and waits up to `timeout_ms` for the
+/// commands in flight to complete and their callbacks to return. The pubsub messages that were buffered with
+/// [`set_pubsub_buffer`] are then delivered within the remaining time. Finally, `callback` is called and the client is
+/// released. Returns immediately, while the drain runs on a dedicated thread.
+///
+/// # Parameters
+/// * `client_adapter_ptr`: Pointer to a valid `GlideClient` returned in the `ConnectionResponse` from [`create_client`].
+/// * `timeout_ms`: The maximal time to wait for the commands in flight and the buffered pubsub messages.
+/// * `callback`: Called once the client was drained, with the number of commands that didn't complete in time.
+///
+/// # Safety
+///
+/// * `drain_client` replaces [`close_client`], and can only be called once per client. Calling [`close_client`] after it
+///   is undefined behavior, since the address will be freed twice.
+/// * `drain_client` must be called after `free_connection_response` has been called to avoid creating a dangling pointer in the `ConnectionResponse`.
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `callback` must be a valid function pointer that lives until it is called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn drain_client(
+    client_adapter_ptr: *const c_void,
+    timeout_ms: u64,
+    callback: DrainCallback,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    // Takes over the reference of the caller, which is released once the client was drained.
+    let client_adapter = unsafe { Arc::from_raw(client_adapter_ptr as *const ClientAdapter) };
+    client_adapter
+        .core
+        .draining
+        .store(true, std::sync::atomic::Ordering::SeqCst);
+    let client_ptr = client_adapter_ptr.addr();
+    let drain = move || {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        let _ = client_adapter.runtime.block_on(tokio::time::timeout_at(
+            deadline.into(),
+            client_adapter.core.wait_for_inflight_requests(),
+        ));
+        let buffer = client_adapter
+            .pubsub_buffer
+            .read()
+            .ok()
+            .and_then(|buffer| buffer.clone());
+        if let Some(buffer) = buffer {
+            buffer.wait_until_empty(deadline.saturating_duration_since(Instant::now()));
         }
-        Value::ServerError(server_error) => {
-            let error_message: String = error_message(&server_error.into());
-            // Convert the formatted string to bytes
-            let bytes = error_message.into_bytes();
-            // Process the bytes as before
-            let (vec_ptr, len) = convert_vec_to_pointer(bytes);
-            command_response.string_value = vec_ptr as *mut c_char;
-            command_response.string_value_len = len;
-            command_response.response_type = ResponseType::Error;
+        let pending_requests = client_adapter
+            .core
+            .inflight_requests
+            .load(std::sync::atomic::Ordering::SeqCst);
+        unsafe { callback(client_ptr, pending_requests as u64) };
+        // The client is released on this thread, since its runtime can't be dropped from within the runtime.
+        drop(client_adapter);
+    };
+    if let Err(err) = std::thread::Builder::new()
+        .name("Valkey-GLIDE drain".to_string())
+        .spawn(drain)
+    {
+        logger_core::log_error(
+            "ffi_drain",
+            format!("Failed to start the drain thread: {err}"),
+        );
+    }
+}
+
+/// Applies a new configuration to a live client, such as new timeouts, read strategy, inflight requests limit or seed
+/// addresses, without dropping its traffic.
+///
+/// A new client is connected in the background with the given connection request, and then atomically replaces the
+/// current client. Commands sent before the replacement complete on the previous client, whose connections are closed
+/// once they did. If the new client fails to connect, the current client is kept and the error is returned.
+/// The settings of the adapter are kept: command coalescing, enabled with [`set_command_coalescing`], moves to the new
+/// client with its configuration and statistics, and request deduplication, the slow command log, the value codec and
+/// the callbacks keep applying to the commands.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `connection_request_bytes`: A Protobuf `ConnectionRequest` with the full new configuration of the client.
+/// * `connection_request_len`: The number of bytes in `connection_request_bytes`.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing `OK` once the new configuration is in use.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `connection_request_bytes` must point to `connection_request_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn reconfigure_client(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    connection_request_bytes: *const u8,
+    connection_request_len: usize,
+) -> *mut CommandResult {
+    assert!(!connection_request_bytes.is_null());
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let request_bytes =
+        unsafe { std::slice::from_raw_parts(connection_request_bytes, connection_request_len) };
+    let request = match parse_connection_request(request_bytes) {
+        Ok(request) => ConnectionRequest::from(request),
+        Err(err) => {
+            let err = RedisError::from((
+                ErrorKind::ClientError,
+                "Decoding connection request failed",
+                err.to_string(),
+            ));
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+    };
+    #[cfg(feature = "fault-injection")]
+    let request = ConnectionRequest {
+        fault_injector: Some(client_adapter.fault_injector.clone()),
+        ..request
+    };
+
+    let core = client_adapter.core.clone();
+    client_adapter.execute_request(request_id, async move {
+        let client = new_glide_client(
+            request,
+            core.push_sender.clone(),
+            core.connection_event_forwarder.as_ref(),
+        )
+        .await
+        .map_err(|err| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "Reconfiguring the client failed",
+                err.to_string(),
+            ))
+        })?;
+        // The coalescer sends the commands through the client it was created with, so it's replaced by a coalescer
+        // of the new client.
+        let mut client_guard = core
+            .client
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Ok(mut guard) = core.coalescer.write()
+            && let Some(coalescer) = guard.as_ref()
+        {
+            *guard = Some(Arc::new(coalescer.with_client(client.clone())));
+        }
+        *client_guard = client;
+        Ok(Value::Okay)
+    })
+}
+
+/// Deallocates a `ConnectionResponse`.
+///
+/// This function also frees the contained error. If the contained error is a null pointer, the function returns and only the `ConnectionResponse` is freed.
+///
+/// # Panics
+///
+/// This function panics when called with a null `ConnectionResponse` pointer.
+///
+/// # Safety
+///
+/// * `free_connection_response` can only be called once per `ConnectionResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
+/// * `connection_response_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `connection_response_ptr` must be valid until `free_connection_response` is called.
+/// * The contained `connection_error_message` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * The contained `connection_error_message` must be valid until `free_connection_response` is called and it must outlive the `ConnectionResponse` that contains it.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn free_connection_response(
+    connection_response_ptr: *mut ConnectionResponse,
+) {
+    assert!(!connection_response_ptr.is_null());
+    let connection_response = unsafe { Box::from_raw(connection_response_ptr) };
+    let connection_error_message = connection_response.connection_error_message;
+    drop(connection_response);
+    if !connection_error_message.is_null() {
+        drop(unsafe { CString::from_raw(connection_error_message as *mut c_char) });
+    }
+}
+
+/// A `RoutedMultiClient` adapter, dispatching each command to one of several clusters by the prefix of its keys.
+pub struct RoutedClientAdapter {
+    runtime: Runtime,
+    client: Arc<RoutedMultiClient>,
+    client_type: ClientType,
+}
+
+impl RoutedClientAdapter {
+    /// Executes a command and routes the result based on client type, like [`ClientAdapter::execute_request`].
+    #[must_use]
+    fn execute_request<Fut>(&self, request_id: usize, request_future: Fut) -> *mut CommandResult
+    where
+        Fut: Future<Output = RedisResult<Value>> + Send + 'static,
+    {
+        ClientAdapter::run_request(
+            &self.runtime,
+            &self.client_type,
+            None,
+            request_id,
+            request_future,
+            None,
+            0,
+        )
+    }
+
+    /// Reports `err` like [`ClientAdapter::handle_redis_error`].
+    ///
+    /// # Safety
+    /// Unsafe, because calls to an FFI function. See the safety documentation of [`FailureCallback`].
+    #[must_use]
+    unsafe fn handle_redis_error(&self, err: RedisError, request_id: usize) -> *mut CommandResult {
+        match self.client_type {
+            ClientType::AsyncClient {
+                failure_callback, ..
+            } => {
+                unsafe { ClientAdapter::send_async_redis_error(failure_callback, err, request_id) };
+                std::ptr::null_mut()
+            }
+            ClientType::SyncClient => create_error_result_with_redis_error(err),
+        }
+    }
+}
+
+/// Reads a routing table given as parallel arrays of key prefixes and cluster names.
+///
+/// # Safety
+///
+/// * `route_prefixes`, `route_prefixes_len`, `route_clusters` and `route_clusters_len` must either be null with a
+///   `route_count` of 0, or point to arrays of `route_count` elements, each argument pointing to as many bytes as its length.
+unsafe fn routes_from_raw_parts(
+    route_count: c_ulong,
+    route_prefixes: *const usize,
+    route_prefixes_len: *const c_ulong,
+    route_clusters: *const usize,
+    route_clusters_len: *const c_ulong,
+) -> Vec<KeyPrefixRoute> {
+    if route_count == 0 {
+        return Vec::new();
+    }
+    let prefixes = unsafe {
+        convert_double_pointer_to_vec(
+            route_prefixes as *const *const c_void,
+            route_count,
+            route_prefixes_len,
+        )
+    };
+    let clusters = unsafe {
+        convert_double_pointer_to_vec(
+            route_clusters as *const *const c_void,
+            route_count,
+            route_clusters_len,
+        )
+    };
+    prefixes
+        .into_iter()
+        .zip(clusters)
+        .map(|(prefix, cluster)| KeyPrefixRoute {
+            prefix: prefix.to_vec(),
+            cluster: String::from_utf8_lossy(cluster).into_owned(),
+        })
+        .collect()
+}
+
+fn create_routed_client_internal(
+    names: Vec<&[u8]>,
+    requests: Vec<&[u8]>,
+    routes: Vec<KeyPrefixRoute>,
+    default_cluster: Option<String>,
+    client_type: ClientType,
+) -> Result<*const RoutedClientAdapter, String> {
+    let runtime = Builder::new_multi_thread()
+        .enable_all()
+        .worker_threads(1)
+        .thread_name("Valkey-GLIDE thread")
+        .build()
+        .map_err(|err| errors::error_message(&err.into()))?;
+    let mut clients = std::collections::HashMap::with_capacity(names.len());
+    for (name, request_bytes) in names.into_iter().zip(requests) {
+        let request = parse_connection_request(request_bytes)?;
+        let client = runtime
+            .block_on(GlideClient::new(ConnectionRequest::from(request), None))
+            .map_err(|err| err.to_string())?;
+        clients.insert(String::from_utf8_lossy(name).into_owned(), client);
+    }
+    let client = RoutedMultiClient::new(clients, routes, default_cluster)
+        .map_err(|err| error_message(&err))?;
+    Ok(Arc::into_raw(Arc::new(RoutedClientAdapter {
+        runtime,
+        client: Arc::new(client),
+        client_type,
+    })))
+}
+
+/// Creates a `RoutedMultiClient`, which wraps a client per cluster and dispatches each command to the cluster its keys
+/// are routed to. A key is routed to the cluster of the route with the longest prefix of the key, or to the default
+/// cluster if no route matches it. Commands without keys are sent to the default cluster, and commands whose keys are
+/// routed to different clusters fail.
+///
+/// The returned `ConnectionResponse` will only be freed by calling [`free_connection_response`], and its `conn_ptr`
+/// must be closed with [`close_routed_client`].
+///
+/// # Parameters
+///
+/// * `cluster_count`: The number of clusters.
+/// * `cluster_names`, `cluster_names_len`: The names of the clusters, used by the routes.
+/// * `connection_requests`, `connection_requests_len`: The Protobuf `ConnectionRequest` of each cluster, in the order of the names.
+/// * `route_count`: The number of routes.
+/// * `route_prefixes`, `route_prefixes_len`: The key prefix of each route.
+/// * `route_clusters`, `route_clusters_len`: The name of the cluster of each route, in the order of the prefixes.
+/// * `default_cluster`: The name of the default cluster, or null for no default cluster.
+/// * `client_type`: The type of the client, with its callbacks for async clients.
+///
+/// # Safety
+///
+/// * `cluster_names`, `cluster_names_len`, `connection_requests` and `connection_requests_len` must point to arrays of
+///   `cluster_count` elements, each argument pointing to as many bytes as its length.
+/// * `route_prefixes`, `route_prefixes_len`, `route_clusters` and `route_clusters_len` must either be null with a
+///   `route_count` of 0, or point to arrays of `route_count` elements, each argument pointing to as many bytes as its length.
+/// * `default_cluster` must be null or a valid null-terminated C string.
+/// * `client_type` must be a valid pointer to a `ClientType`.
+/// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active.
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C-unwind" fn create_routed_client(
+    cluster_count: c_ulong,
+    cluster_names: *const usize,
+    cluster_names_len: *const c_ulong,
+    connection_requests: *const usize,
+    connection_requests_len: *const c_ulong,
+    route_count: c_ulong,
+    route_prefixes: *const usize,
+    route_prefixes_len: *const c_ulong,
+    route_clusters: *const usize,
+    route_clusters_len: *const c_ulong,
+    default_cluster: *const c_char,
+    client_type: *const ClientType,
+) -> *const ConnectionResponse {
+    let client_type = unsafe { &*client_type }.clone();
+    let names = unsafe {
+        convert_double_pointer_to_vec(
+            cluster_names as *const *const c_void,
+            cluster_count,
+            cluster_names_len,
+        )
+    };
+    let requests = unsafe {
+        convert_double_pointer_to_vec(
+            connection_requests as *const *const c_void,
+            cluster_count,
+            connection_requests_len,
+        )
+    };
+    let routes = unsafe {
+        routes_from_raw_parts(
+            route_count,
+            route_prefixes,
+            route_prefixes_len,
+            route_clusters,
+            route_clusters_len,
+        )
+    };
+    let default_cluster = (!default_cluster.is_null()).then(|| {
+        unsafe { CStr::from_ptr(default_cluster) }
+            .to_string_lossy()
+            .into_owned()
+    });
+
+    into_connection_response(create_routed_client_internal(
+        names,
+        requests,
+        routes,
+        default_cluster,
+        client_type,
+    ))
+}
+
+/// Executes a command on the cluster its keys are routed to. See [`create_routed_client`] for the routing of the commands.
+///
+/// # Safety
+///
+/// * `routed_client_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_routed_client`], before [`close_routed_client`] was called with the pointer.
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` and `args_len` must point to arrays of `arg_count` elements, and each argument must point to as many bytes as its length.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object, routing the command within its cluster.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C-unwind" fn routed_command(
+    routed_client_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    let routed_client = unsafe {
+        Arc::increment_strong_count(routed_client_ptr);
+        Arc::from_raw(routed_client_ptr as *mut RoutedClientAdapter)
+    };
+    let arg_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
+    } else {
+        Vec::new()
+    };
+    let Some(mut cmd) = command_type.get_command() else {
+        let err = RedisError::from((ErrorKind::ClientError, "Couldn't fetch command type"));
+        return unsafe { routed_client.handle_redis_error(err, request_id) };
+    };
+    if let Err(msg) = command_type.validate_args(&arg_vec) {
+        let err = RedisError::from((ErrorKind::ClientError, "Invalid command arguments", msg));
+        return unsafe { routed_client.handle_redis_error(err, request_id) };
+    }
+    for command_arg in &arg_vec {
+        cmd.arg(command_arg);
+    }
+    let route = if !route_bytes.is_null() {
+        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
+        match Routes::parse_from_bytes(r_bytes) {
+            Ok(route) => route,
+            Err(err) => {
+                let err = RedisError::from((
+                    ErrorKind::ClientError,
+                    "Decoding route failed",
+                    err.to_string(),
+                ));
+                return unsafe { routed_client.handle_redis_error(err, request_id) };
+            }
+        }
+    } else {
+        Routes::default()
+    };
+
+    let client = routed_client.client.clone();
+    routed_client.execute_request(request_id, async move {
+        let routing_info = get_route(route, Some(&cmd))?;
+        client.send_command(&mut cmd, routing_info).await
+    })
+}
+
+/// Replaces the routes of a `RoutedMultiClient`. The commands that were already dispatched aren't affected.
+///
+/// # Returns
+///
+/// `null` on success, or an error message if a route names an unknown cluster, in which case the routes are unchanged.
+/// The error message must be freed with [`free_c_string`].
+///
+/// # Safety
+///
+/// * `routed_client_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_routed_client`], before [`close_routed_client`] was called with the pointer.
+/// * `route_prefixes`, `route_prefixes_len`, `route_clusters` and `route_clusters_len` must either be null with a
+///   `route_count` of 0, or point to arrays of `route_count` elements, each argument pointing to as many bytes as its length.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_routed_client_routes(
+    routed_client_ptr: *const c_void,
+    route_count: c_ulong,
+    route_prefixes: *const usize,
+    route_prefixes_len: *const c_ulong,
+    route_clusters: *const usize,
+    route_clusters_len: *const c_ulong,
+) -> *const c_char {
+    assert!(!routed_client_ptr.is_null());
+    let routed_client = unsafe {
+        Arc::increment_strong_count(routed_client_ptr);
+        Arc::from_raw(routed_client_ptr as *const RoutedClientAdapter)
+    };
+    let routes = unsafe {
+        routes_from_raw_parts(
+            route_count,
+            route_prefixes,
+            route_prefixes_len,
+            route_clusters,
+            route_clusters_len,
+        )
+    };
+    match routed_client.client.set_routes(routes) {
+        Ok(()) => std::ptr::null(),
+        Err(err) => CString::new(error_message(&err))
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw(),
+    }
+}
+
+/// Closes a `RoutedMultiClient` and its clients, freeing it from the heap.
+///
+/// # Safety
+///
+/// * `close_routed_client` can only be called once per client, after `free_connection_response` was called.
+/// * `routed_client_ptr` must be obtained from the `ConnectionResponse` returned from [`create_routed_client`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn close_routed_client(routed_client_ptr: *const c_void) {
+    assert!(!routed_client_ptr.is_null());
+    // This will bring the strong count down to 0 once all client requests are done.
+    unsafe { Arc::decrement_strong_count(routed_client_ptr as *const RoutedClientAdapter) };
+}
+
+/// Provides the string mapping for the ResponseType enum.
+///
+/// Important: the returned pointer is a pointer to a constant string and should not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_response_type_string(response_type: ResponseType) -> *const c_char {
+    let c_str = match response_type {
+        ResponseType::Null => c"Null",
+        ResponseType::Int => c"Int",
+        ResponseType::Float => c"Float",
+        ResponseType::Bool => c"Bool",
+        ResponseType::String => c"String",
+        ResponseType::Array => c"Array",
+        ResponseType::Map => c"Map",
+        ResponseType::Sets => c"Sets",
+        ResponseType::Ok => c"Ok",
+        ResponseType::Error => c"Error",
+        ResponseType::StreamedString => c"StreamedString",
+    };
+    c_str.as_ptr()
+}
+
+/// Deallocates a `CommandResponse`.
+///
+/// This function also frees the contained string_value and array_value. If the string_value and array_value are null pointers, the function returns and only the `CommandResponse` is freed.
+///
+/// # Safety
+///
+/// * `free_command_response` can only be called once per `CommandResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
+/// * `command_response_ptr` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
+/// * `command_response_ptr` must be valid until `free_command_response` is called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn free_command_response(command_response_ptr: *mut CommandResponse) {
+    if !command_response_ptr.is_null() {
+        let command_response = unsafe { Box::from_raw(command_response_ptr) };
+        unsafe { free_command_response_elements(*command_response) };
+    }
+}
+
+/// Frees the nested elements of `CommandResponse`.
+/// TODO: Add a test case to check for memory leak.
+///
+/// # Safety
+///
+/// * `free_command_response_elements` can only be called once per `CommandResponse`. Calling it twice is undefined behavior, since the address will be freed twice.
+/// * The contained `string_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
+/// * The contained `string_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
+/// * The contained `array_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
+/// * The contained `array_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
+/// * The contained `map_key` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
+/// * The contained `map_key` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
+/// * The contained `map_value` must be obtained from the `CommandResponse` returned in [`SuccessCallback`] from [`command`].
+/// * The contained `map_value` must be valid until `free_command_response` is called and it must outlive the `CommandResponse` that contains it.
+unsafe fn free_command_response_elements(command_response: CommandResponse) {
+    let string_value = command_response.string_value;
+    let string_value_len = command_response.string_value_len;
+    let array_value = command_response.array_value;
+    let array_value_len = command_response.array_value_len;
+    let map_key = command_response.map_key;
+    let map_value = command_response.map_value;
+    let sets_value = command_response.sets_value;
+    let sets_value_len = command_response.sets_value_len;
+    if !string_value.is_null() {
+        let len = string_value_len as usize;
+        unsafe { Vec::from_raw_parts(string_value, len, len) };
+    }
+    if !array_value.is_null() {
+        let len = array_value_len as usize;
+        let vec = unsafe { Vec::from_raw_parts(array_value, len, len) };
+        for element in vec.into_iter() {
+            unsafe { free_command_response_elements(element) };
+        }
+    }
+    if !map_key.is_null() {
+        unsafe { free_command_response(map_key) };
+    }
+    if !map_value.is_null() {
+        unsafe { free_command_response(map_value) };
+    }
+    if !sets_value.is_null() {
+        let len = sets_value_len as usize;
+        let vec = unsafe { Vec::from_raw_parts(sets_value, len, len) };
+        for element in vec.into_iter() {
+            unsafe { free_command_response_elements(element) };
+        }
+    }
+}
+
+struct StreamedResponse {
+    data: Vec<u8>,
+    offset: usize,
+}
+
+static STREAMED_RESPONSES: std::sync::LazyLock<
+    std::sync::Mutex<std::collections::HashMap<i64, StreamedResponse>>,
+> = std::sync::LazyLock::new(Default::default);
+static NEXT_STREAMED_RESPONSE_HANDLE: std::sync::atomic::AtomicI64 =
+    std::sync::atomic::AtomicI64::new(1);
+
+/// Stores a large string response, and returns a `CommandResponse` with the handle to read it with [`read_response_chunk`].
+fn streamed_command_response(data: Vec<u8>) -> CommandResponse {
+    let handle = NEXT_STREAMED_RESPONSE_HANDLE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let len = data.len() as c_long;
+    STREAMED_RESPONSES
+        .lock()
+        .expect("Failed to acquire the streamed responses lock")
+        .insert(handle, StreamedResponse { data, offset: 0 });
+    CommandResponse {
+        response_type: ResponseType::StreamedString,
+        int_value: handle,
+        string_value_len: len,
+        ..Default::default()
+    }
+}
+
+/// Sets the size from which string responses of a client are streamed instead of being copied into the `CommandResponse`.
+///
+/// A streamed response has the `StreamedString` type, with the response handle in `int_value` and the total length in `string_value_len`.
+/// The wrapper reads it in chunks with [`read_response_chunk`], so a multi-megabyte value doesn't require one contiguous
+/// allocation in the host language. Only top-level string responses are streamed, and never into a caller-provided buffer.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `threshold`: The minimal size in bytes of a streamed response. Pass 0 to disable streaming, which is the default.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_response_streaming_threshold(
+    client_adapter_ptr: *const c_void,
+    threshold: usize,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    client_adapter
+        .core
+        .response_streaming_threshold
+        .store(threshold, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Copies the next chunk of a streamed response into `buf`.
+///
+/// The response is released once its last byte is read, and the handle becomes invalid. The wrapper knows it read
+/// the whole response from the total length in the `CommandResponse`. A response that won't be read to the end must be
+/// released with [`free_response_stream`].
+///
+/// # Parameters
+///
+/// * `handle`: The handle of the response, taken from the `int_value` of a `StreamedString` response.
+/// * `buf`: The buffer to copy the chunk into.
+/// * `len`: The size of `buf`.
+///
+/// # Returns
+///
+/// * The number of bytes copied into `buf`.
+/// * -1 if the handle doesn't exist, or the response was already released.
+///
+/// # Safety
+///
+/// * `buf` must point to a writable buffer of at least `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn read_response_chunk(handle: i64, buf: *mut u8, len: usize) -> c_long {
+    let mut responses = STREAMED_RESPONSES
+        .lock()
+        .expect("Failed to acquire the streamed responses lock");
+    let Some(response) = responses.get_mut(&handle) else {
+        return -1;
+    };
+    let chunk_len = len.min(response.data.len() - response.offset);
+    unsafe {
+        std::ptr::copy_nonoverlapping(response.data.as_ptr().add(response.offset), buf, chunk_len);
+    }
+    response.offset += chunk_len;
+    if response.offset == response.data.len() {
+        responses.remove(&handle);
+    }
+    chunk_len as c_long
+}
+
+/// Releases a streamed response before it was read to the end. Releasing an unknown handle has no effect.
+///
+/// # Parameters
+///
+/// * `handle`: The handle of the response, taken from the `int_value` of a `StreamedString` response.
+#[unsafe(no_mangle)]
+pub extern "C" fn free_response_stream(handle: i64) {
+    STREAMED_RESPONSES
+        .lock()
+        .expect("Failed to acquire the streamed responses lock")
+        .remove(&handle);
+}
+
+/// Converts a double pointer to a vec.
+///
+/// # Safety
+///
+/// `convert_double_pointer_to_vec` returns a `Vec` of u8 slice which holds pointers of `go`
+/// strings. The returned `Vec<&'a [u8]>` is meant to be copied into Rust code. Storing them
+/// for later use will cause the program to crash as the pointers will be freed by go's gc
+unsafe fn convert_double_pointer_to_vec<'a>(
+    data: *const *const c_void,
+    len: c_ulong,
+    data_len: *const c_ulong,
+) -> Vec<&'a [u8]> {
+    let string_ptrs = unsafe { from_raw_parts(data, len as usize) };
+    let string_lengths = unsafe { from_raw_parts(data_len, len as usize) };
+    let mut result = Vec::<&[u8]>::with_capacity(string_ptrs.len());
+    for (i, &str_ptr) in string_ptrs.iter().enumerate() {
+        let slice = unsafe { from_raw_parts(str_ptr as *const u8, string_lengths[i] as usize) };
+        result.push(slice);
+    }
+    result
+}
+
+/// Copies `count` byte arrays passed as arrays of pointers and lengths, or returns an empty `Vec` if the arrays are `null`.
+///
+/// # Safety
+///
+/// See the safety documentation of [`convert_double_pointer_to_vec`].
+unsafe fn owned_byte_arrays(
+    count: c_ulong,
+    data: *const usize,
+    data_len: *const c_ulong,
+) -> Vec<Vec<u8>> {
+    if count == 0 || data.is_null() || data_len.is_null() {
+        return Vec::new();
+    }
+    unsafe { convert_double_pointer_to_vec(data as *const *const c_void, count, data_len) }
+        .into_iter()
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+fn convert_vec_to_pointer<T>(mut vec: Vec<T>) -> (*mut T, c_long) {
+    vec.shrink_to_fit();
+    let vec_ptr = vec.as_mut_ptr();
+    let len = vec.len() as c_long;
+    mem::forget(vec);
+    (vec_ptr, len)
+}
+
+/// Converts a key of a map. Integer and double keys keep their types and values, and also hold their text in
+/// `string_value`, such as `12` or `1.5`, for the languages that key maps by strings. Big number keys are returned as
+/// strings of their digits, the same as big number values.
+fn map_key_to_command_response(key: Value) -> RedisResult<CommandResponse> {
+    let text = match &key {
+        Value::Int(num) => Some(num.to_string()),
+        Value::Double(num) => Some(num.to_string()),
+        _ => None,
+    };
+    let mut command_response = valkey_value_to_command_response(key, None)?;
+    if let Some(text) = text {
+        let (vec_ptr, len) = convert_vec_to_pointer(text.into_bytes());
+        command_response.string_value = vec_ptr as *mut c_char;
+        command_response.string_value_len = len;
+    }
+    Ok(command_response)
+}
+
+fn valkey_value_to_command_response(
+    value: Value,
+    response_buf: Option<(*mut u8, usize)>,
+) -> RedisResult<CommandResponse> {
+    let mut command_response = CommandResponse::default();
+    let result: RedisResult<CommandResponse> = match value {
+        Value::Nil => Ok(command_response),
+        Value::SimpleString(text) => {
+            let vec: Vec<u8> = text.into_bytes();
+            let (vec_ptr, len) = convert_vec_to_pointer(vec);
+            command_response.string_value = vec_ptr as *mut c_char;
+            command_response.string_value_len = len;
+            command_response.response_type = ResponseType::String;
+            Ok(command_response)
+        }
+        Value::BulkString(data) => {
+            let data = if let Some((buf, buf_len)) = response_buf {
+                if data.len() > buf_len {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "Value size exceeds buffer capacity",
+                        format!(
+                            "value is {} bytes but buffer is {} bytes",
+                            data.len(),
+                            buf_len
+                        ),
+                    )));
+                }
+                // Copy data directly into the caller's buffer; the command response
+                // will carry the number of bytes written instead of the data itself.
+                unsafe {
+                    std::ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
+                }
+                data.len().to_string().into_bytes()
+            } else {
+                data
+            };
+            let (vec_ptr, len) = convert_vec_to_pointer(data);
+            command_response.string_value = vec_ptr as *mut c_char;
+            command_response.string_value_len = len;
+            command_response.response_type = ResponseType::String;
+            Ok(command_response)
+        }
+        Value::VerbatimString { format: _, text } => {
+            let vec: Vec<u8> = text.into_bytes();
+            let (vec_ptr, len) = convert_vec_to_pointer(vec);
+            command_response.string_value = vec_ptr as *mut c_char;
+            command_response.string_value_len = len;
+            command_response.response_type = ResponseType::String;
+            Ok(command_response)
+        }
+        Value::Okay => {
+            command_response.response_type = ResponseType::Ok;
+            Ok(command_response)
+        }
+        Value::Int(num) => {
+            command_response.int_value = num;
+            command_response.response_type = ResponseType::Int;
+            Ok(command_response)
+        }
+        Value::Double(num) => {
+            command_response.float_value = num;
+            command_response.response_type = ResponseType::Float;
+            Ok(command_response)
+        }
+        Value::Boolean(boolean) => {
+            command_response.bool_value = boolean;
+            command_response.response_type = ResponseType::Bool;
+            Ok(command_response)
+        }
+        // Big numbers don't fit any numeric field, so they're returned as their decimal digits.
+        Value::BigNumber(num) => {
+            let (vec_ptr, len) = convert_vec_to_pointer(num.to_string().into_bytes());
+            command_response.string_value = vec_ptr as *mut c_char;
+            command_response.string_value_len = len;
+            command_response.response_type = ResponseType::String;
+            Ok(command_response)
+        }
+        Value::Array(array) => {
+            let vec: Result<Vec<CommandResponse>, RedisError> = array
+                .into_iter()
+                .map(|v| valkey_value_to_command_response(v, None))
+                .collect();
+            let (vec_ptr, len) = convert_vec_to_pointer(vec?);
+            command_response.array_value = vec_ptr;
+            command_response.array_value_len = len;
+            command_response.response_type = ResponseType::Array;
+            Ok(command_response)
+        }
+        Value::Map(map) => {
+            let result: Result<Vec<CommandResponse>, RedisError> = map
+                .into_iter()
+                .map(|(key, val)| {
+                    let mut map_response = CommandResponse::default();
+
+                    let map_key = match map_key_to_command_response(key) {
+                        Ok(map_key) => map_key,
+                        Err(err) => return Err(err),
+                    };
+                    map_response.map_key = Box::into_raw(Box::new(map_key));
+
+                    let map_val = match valkey_value_to_command_response(val, None) {
+                        Ok(map_val) => map_val,
+                        Err(err) => return Err(err),
+                    };
+                    map_response.map_value = Box::into_raw(Box::new(map_val));
+
+                    Ok(map_response)
+                })
+                .collect::<Result<Vec<CommandResponse>, RedisError>>();
+
+            let (vec_ptr, len) = convert_vec_to_pointer(result?);
+            command_response.array_value = vec_ptr;
+            command_response.array_value_len = len;
+            command_response.response_type = ResponseType::Map;
+            Ok(command_response)
+        }
+        Value::Set(array) => {
+            let vec: Result<Vec<CommandResponse>, RedisError> = array
+                .into_iter()
+                .map(|v| valkey_value_to_command_response(v, None))
+                .collect();
+            let (vec_ptr, len) = convert_vec_to_pointer(vec?);
+            command_response.sets_value = vec_ptr;
+            command_response.sets_value_len = len;
+            command_response.response_type = ResponseType::Sets;
+            Ok(command_response)
+        }
+        Value::ServerError(server_error) => {
+            let error_message: String = error_message(&server_error.into());
+            // Convert the formatted string to bytes
+            let bytes = error_message.into_bytes();
+            // Process the bytes as before
+            let (vec_ptr, len) = convert_vec_to_pointer(bytes);
+            command_response.string_value = vec_ptr as *mut c_char;
+            command_response.string_value_len = len;
+            command_response.response_type = ResponseType::Error;
+
+            // Return as Ok to continue transaction processing
+            Ok(command_response)
+        }
+        Value::Push { kind, data } => {
+            // Create kind entry
+            let mut kind_entry = CommandResponse::default();
+            let map_key =
+                valkey_value_to_command_response(Value::SimpleString("kind".to_string()), None)?;
+            kind_entry.map_key = Box::into_raw(Box::new(map_key));
+            let map_val =
+                valkey_value_to_command_response(Value::SimpleString(format!("{:?}", kind)), None)?;
+            kind_entry.map_value = Box::into_raw(Box::new(map_val));
+
+            // Create values entry
+            let mut values_entry = CommandResponse::default();
+            let map_key =
+                valkey_value_to_command_response(Value::SimpleString("values".to_string()), None)?;
+            values_entry.map_key = Box::into_raw(Box::new(map_key));
+            let map_val = valkey_value_to_command_response(Value::Array(data), None)?;
+            values_entry.map_value = Box::into_raw(Box::new(map_val));
+
+            let (map_ptr, map_len) = convert_vec_to_pointer(vec![kind_entry, values_entry]);
+            command_response.array_value = map_ptr;
+            command_response.array_value_len = map_len;
+            command_response.response_type = ResponseType::Map;
+
+            Ok(command_response)
+        }
+        // TODO: Add support for other return types.
+        _ => todo!(),
+    };
+    result
+}
+
+/// Executes a command.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called should with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    span_ptr: u64,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            span_ptr,
+        )
+    }
+}
+
+/// Executes a command, optionally copying a BulkString response directly into a
+/// caller-provided buffer instead of returning it as a heap-allocated value.
+///
+/// When `response_buf` is null (and `response_buf_len` is 0), behaves identically
+/// to [`command`] — the response flows through the normal `execute_request` path.
+///
+/// When `response_buf` is non-null, the response is written directly into the buffer:
+/// - `response.string_value` = number of bytes written as a string, or Nil response for missing keys.
+/// - Errors if the value exceeds `response_buf_len`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * When non-null, `response_buf` must point to a writable buffer of at least `response_buf_len` bytes.
+/// * `response_buf_len` must be 0 if `response_buf` is null.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_buffer(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    response_buf: *mut u8,
+    response_buf_len: usize,
+    span_ptr: u64,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            response_buf,
+            response_buf_len,
+            span_ptr,
+            RequestPriority::Normal,
+            false,
+            None,
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes a command with the given priority class.
+///
+/// When the client was created with an inflight requests high watermark, normal-priority commands are rejected
+/// once the inflight requests reach the watermark, while high-priority commands may use the remaining slots up to
+/// the inflight requests limit. Otherwise, behaves identically to [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_priority(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    span_ptr: u64,
+    priority: RequestPriority,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            span_ptr,
+            priority,
+            false,
+            None,
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes a command and returns its response serialized as a protobuf `Response` message, for the wrappers that
+/// parse the responses with protobuf instead of walking the returned `CommandResponse` tree.
+///
+/// On success, the `CommandResponse` is a string containing the serialized `Response`, in which the value of the
+/// command is set in `response_value`, or `OK` in `constant_response`, and errors returned for the command are set in
+/// `request_error`. Errors raised before the command is sent, such as an invalid route or reaching the inflight
+/// requests limit, are reported the same as with [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_pb(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    span_ptr: u64,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            span_ptr,
+            RequestPriority::Normal,
+            true,
+            None,
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes a command and applies a projection to its response before it's converted to a `CommandResponse`, so
+/// callers that need only a few fields of a large response, such as the ones of `HGETALL` or `XRANGE`, don't pay for
+/// converting the rest.
+///
+/// The projection keeps only the entries of the given fields, of the response if it's a map of fields to values, and
+/// otherwise of each of the records it holds, and at most `max_entries` entries of the response. Otherwise, behaves
+/// identically to [`command`].
+///
+/// # Parameters
+///
+/// * `field_count`: the number of fields in `fields` and `fields_len`, or 0 to keep all the fields.
+/// * `max_entries`: the maximum number of entries of the response to keep, or 0 to keep all entries.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * `fields` and `fields_len` follow the same requirements as `args` and `args_len`, with `field_count` elements.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_response_filter(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    field_count: c_ulong,
+    fields: *const usize,
+    fields_len: *const c_ulong,
+    max_entries: c_ulong,
+    span_ptr: u64,
+) -> *mut CommandResult {
+    // The fields are copied, since they're only valid until this function returns.
+    let fields = (field_count > 0 && !fields.is_null() && !fields_len.is_null()).then(|| {
+        unsafe {
+            convert_double_pointer_to_vec(fields as *const *const c_void, field_count, fields_len)
+        }
+        .into_iter()
+        .map(|field| field.to_vec())
+        .collect()
+    });
+    let response_filter = ResponseFilter {
+        fields,
+        max_entries: (max_entries > 0).then_some(max_entries as usize),
+    };
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            span_ptr,
+            RequestPriority::Normal,
+            false,
+            Some(ResponseTransform::Filter(response_filter)),
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes a command unless its deadline passed before it could be sent, such as while the client waits for a
+/// reconnection or for a connection of its pool. A command whose deadline passed is dropped without being sent, and
+/// fails with an error of type [`RequestErrorType::DeadlineExceededBeforeSend`]. Once sent, the command isn't
+/// cancelled when its deadline passes, so a response that arrives later is still returned. Otherwise, behaves
+/// identically to [`command`].
+///
+/// # Parameters
+///
+/// * `deadline_unix_ms`: the deadline, in milliseconds since the Unix epoch, or 0 for no deadline.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_send_deadline(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    span_ptr: u64,
+    deadline_unix_ms: u64,
+) -> *mut CommandResult {
+    let send_deadline = (deadline_unix_ms > 0)
+        .then(|| glide_core::client::deadline_from_unix_millis(deadline_unix_ms));
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            span_ptr,
+            RequestPriority::Normal,
+            false,
+            None,
+            send_deadline,
+            None,
+            false,
+        )
+    }
+}
+
+/// A transformation of the response of a command, applied by [`command_with_buffer_and_priority`] before the response
+/// is converted to a `CommandResponse`.
+enum ResponseTransform {
+    /// Projects the response, for [`command_with_response_filter`].
+    Filter(ResponseFilter),
+    /// Normalizes the responses of `CLUSTER SLOTS` and `CLUSTER SHARDS`, for [`command_with_normalized_topology`].
+    NormalizedTopology,
+    /// Converts the responses of the geo searches into maps, for [`command_with_structured_geo_response`].
+    StructuredGeo,
+}
+
+impl ResponseTransform {
+    fn apply(self, cmd: &Cmd, value: Value) -> RedisResult<Value> {
+        match self {
+            ResponseTransform::Filter(response_filter) => Ok(response_filter.apply(value)),
+            ResponseTransform::NormalizedTopology => {
+                glide_core::cluster_topology::normalize_cluster_topology(cmd, value)
+            }
+            ResponseTransform::StructuredGeo => {
+                glide_core::geo_search::convert_geo_search_response(cmd, value)
+            }
+        }
+    }
+}
+
+/// Implements [`command_with_buffer`], [`command_with_priority`], [`command_pb`], [`command_with_response_filter`],
+/// [`command_with_send_deadline`], [`command_with_deadline`], [`command_with_normalized_topology`],
+/// [`command_with_structured_geo_response`] and [`command_blocking`]. See [`command_with_buffer`] for the safety
+/// requirements. Blocking commands are sent through a blocking connection of their own, and are neither coalesced
+/// nor deduplicated.
+#[allow(clippy::too_many_arguments)]
+unsafe fn command_with_buffer_and_priority(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    response_buf: *mut u8,
+    response_buf_len: usize,
+    span_ptr: u64,
+    priority: RequestPriority,
+    protobuf_response: bool,
+    response_transform: Option<ResponseTransform>,
+    send_deadline: Option<Instant>,
+    deadline: Option<Duration>,
+    blocking: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let arg_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
+    } else {
+        Vec::new()
+    };
+
+    // Create the command outside of the task to ensure that the command arguments passed
+    // from the foreign code are still valid
+    let mut cmd = match command_type.get_command() {
+        Some(cmd) => cmd,
+        None => {
+            let err = RedisError::from((ErrorKind::ClientError, "Couldn't fetch command type"));
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+    };
+    if let Err(msg) = command_type.validate_args(&arg_vec) {
+        let err = RedisError::from((ErrorKind::ClientError, "Invalid command arguments", msg));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    // Check if compression is enabled before converting args
+    let compression_manager = client_adapter.core.client().compression_manager();
+    let should_process_compression = compression_manager
+        .as_ref()
+        .map(|cm| cm.is_enabled())
+        .unwrap_or(false);
+    let value_codec = client_adapter
+        .core
+        .value_codec
+        .read()
+        .ok()
+        .and_then(|value_codec| value_codec.clone())
+        .filter(|value_codec| value_codec.handles(command_type));
+
+    if should_process_compression || value_codec.is_some() {
+        // Convert arg_vec to owned Vec<Vec<u8>> for compression processing
+        let mut owned_args: Vec<Vec<u8>> = arg_vec.iter().map(|&arg| arg.to_vec()).collect();
+
+        // Values are encoded before they're compressed, since responses are decompressed before they're decoded.
+        if let Some(value_codec) = &value_codec
+            && let Err(err) = unsafe { value_codec.encode_args(command_type, &mut owned_args) }
+        {
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+
+        // Apply compression to command arguments
+        if let Err(err) = glide_core::compression::process_command_args_for_compression(
+            &mut owned_args,
+            command_type,
+            compression_manager.as_deref(),
+        ) {
+            let err = RedisError::from((
+                ErrorKind::ClientError,
+                "Compression failed",
+                err.to_string(),
+            ));
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+
+        // Use the compressed arguments
+        for command_arg in &owned_args {
+            cmd.arg(command_arg);
+        }
+    } else {
+        // Use the original arguments
+        for command_arg in &arg_vec {
+            cmd.arg(command_arg);
+        }
+    }
+
+    if span_ptr != 0 {
+        cmd.set_span(unsafe { get_unsafe_span_from_ptr(Some(span_ptr)) });
+    }
+    cmd.set_deadline(send_deadline);
+    // The metadata is reported to async clients only, right before their success callback.
+    let command_metadata_callback = match client_adapter.core.client_type {
+        ClientType::AsyncClient { .. } => client_adapter
+            .core
+            .command_metadata_callback
+            .read()
+            .ok()
+            .and_then(|guard| *guard),
+        ClientType::SyncClient => None,
+    };
+    let trace = command_metadata_callback.map(|_| Arc::new(CommandTrace::default()));
+    cmd.set_trace(trace.clone());
+
+    let route = if !route_bytes.is_null() {
+        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
+        match Routes::parse_from_bytes(r_bytes) {
+            Ok(route) => route,
+            Err(err) => {
+                let err = RedisError::from((
+                    ErrorKind::ClientError,
+                    "Decoding route failed",
+                    err.to_string(),
+                ));
+                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            }
+        }
+    } else {
+        Routes::default()
+    };
+
+    // Check inflight request limit
+    let mut client = client_adapter.core.client();
+    if !client.reserve_inflight_request_with_priority(priority) {
+        let err = RedisError::from((ErrorKind::ClientError, "Reached maximum inflight requests"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    let child_span = create_child_span(cmd.span().as_ref(), "send_command");
+    let client_for_release = client.clone();
+    // Compressed values are decompressed by `send_command` only, so these commands aren't coalesced.
+    let coalescer = match client_adapter.core.client_type {
+        ClientType::AsyncClient { .. } if !should_process_compression && !blocking => {
+            client_adapter
+                .core
+                .coalescer
+                .read()
+                .ok()
+                .and_then(|coalescer| coalescer.clone())
+        }
+        _ => None,
+    };
+    let deduplicator = client_adapter
+        .core
+        .deduplicator
+        .read()
+        .ok()
+        .and_then(|deduplicator| deduplicator.clone())
+        .filter(|_| !blocking);
+    let slow_command_log = client_adapter
+        .core
+        .slow_command_log
+        .read()
+        .ok()
+        .and_then(|slow_command_log| slow_command_log.clone());
+
+    // The transformations depend on the arguments of the command, which is consumed when it's sent.
+    let response_transform = response_transform.map(|transform| (transform, cmd.clone()));
+
+    let buf_option = if response_buf.is_null() {
+        None
+    } else {
+        Some(ResponseBuffer(response_buf, response_buf_len))
+    };
+
+    let result = client_adapter.execute_request_with_buffer(
+        request_id,
+        async move {
+            let routing_info = get_route(route, Some(&cmd))?;
+            let start = Instant::now();
+            let deduplication = deduplicator
+                .filter(|_| RequestDeduplicator::can_deduplicate(&cmd, routing_info.as_ref()))
+                .map(|deduplicator| (deduplicator, cmd.clone()));
+            // The command is consumed when it's sent, so only what the log needs is kept. The serving node is
+            // resolved from the routing only for the slow commands.
+            let slow_command = slow_command_log.map(|slow_command_log| {
+                let routing = routing_info
+                    .clone()
+                    .or_else(|| RoutingInfo::for_routable(&cmd));
+                (slow_command_log, CommandSignature::new(&cmd), routing)
+            });
+            let request = async move {
+                match coalescer {
+                    Some(coalescer) if coalescer.can_coalesce(&cmd, routing_info.as_ref()) => {
+                        coalescer.send_command(cmd).await
+                    }
+                    _ if blocking => client.send_blocking_command(&mut cmd, routing_info).await,
+                    _ => client.send_command(&mut cmd, routing_info).await,
+                }
+            };
+            let request = async move {
+                match deduplication {
+                    Some((deduplicator, cmd)) => deduplicator.deduplicate(&cmd, request).await,
+                    None => request.await,
+                }
+            };
+            let result = match deadline {
+                Some(deadline) => tokio::time::timeout(deadline, request)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(std::io::Error::from(std::io::ErrorKind::TimedOut).into())
+                    }),
+                None => request.await,
+            };
+            let duration = start.elapsed();
+            glide_core::command_latency::record(command_type, duration);
+            if let Some((slow_command_log, signature, routing)) = slow_command {
+                let node_address = match result.as_ref().err().and_then(|err| err.node_address()) {
+                    Some(address) => Some(address.to_string()),
+                    None => match routing {
+                        Some(routing) if slow_command_log.is_slow(duration) => {
+                            client_for_release
+                                .clone()
+                                .node_address_for_route(&routing)
+                                .await
+                        }
+                        _ => None,
+                    },
+                };
+                slow_command_log.record(signature, duration, node_address);
+            }
+            client_for_release.release_inflight_request();
+            let mut server_time_us = None;
+            let result = match trace {
+                Some(_) => result.map(|value| {
+                    let (value, server_time) = take_server_time(value);
+                    server_time_us = server_time;
+                    value
+                }),
+                None => result,
+            };
+            let result = match value_codec {
+                Some(value_codec) => result
+                    .and_then(|value| unsafe { value_codec.decode_response(command_type, value) }),
+                None => result,
+            };
+            let result = match response_transform {
+                Some((response_transform, cmd)) => {
+                    result.and_then(|value| response_transform.apply(&cmd, value))
+                }
+                None => result,
+            };
+            if let (Some(callback), Some(trace), Ok(_)) =
+                (command_metadata_callback, &trace, &result)
+            {
+                unsafe { report_command_metadata(callback, request_id, trace, server_time_us) };
+            }
+            if protobuf_response {
+                return Ok(Value::BulkString(encode_response(result)));
+            }
+            result
+        },
+        buf_option,
+    );
+    if let Ok(span) = child_span {
+        span.end();
+    }
+    result
+}
+
+/// Executes a blocking command, such as `BLPOP`, `BRPOP`, `BLMOVE` or `XREAD` with `BLOCK`.
+///
+/// Each blocking command in flight is sent through a connection of its own, reused by the next blocking commands
+/// once it completes, so it doesn't delay the commands multiplexed on the client's main connection, or the other
+/// blocking commands, while it waits on the server.
+/// The request times out after the timeout given in the command, extended by a short grace period.
+/// Commands that aren't blocking are executed the same as with [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_blocking(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            0,
+            RequestPriority::Normal,
+            false,
+            None,
+            None,
+            None,
+            true,
+        )
+    }
+}
+
+/// Executes a command, and fails it with a timeout error if it doesn't complete within `deadline_ms` milliseconds.
+///
+/// With a `SyncClient`, the calling thread waits for the result without blocking the runtime worker, and returns
+/// once the command completes or the deadline expires, regardless of the client's request timeout.
+/// Expiring the deadline only stops waiting for the response: a command that was already sent isn't cancelled, and
+/// may still be executed by the server. A `deadline_ms` of 0 fails with a client error.
+/// Otherwise, behaves identically to [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_deadline(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    deadline_ms: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    if deadline_ms == 0 {
+        let err = RedisError::from((ErrorKind::ClientError, "The deadline must be positive"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            0,
+            RequestPriority::Normal,
+            false,
+            None,
+            None,
+            Some(Duration::from_millis(deadline_ms)),
+            false,
+        )
+    }
+}
+
+/// Executes `CLUSTER SLOTS` or `CLUSTER SHARDS`, and normalizes the response into a list of shards.
+///
+/// Each shard is a map of its `slots`, as an array of `[start, end]` ranges, and its `nodes`, as an array of maps of
+/// `id`, `host`, `port`, `role` (`primary` or `replica`) and `health`, with the primary listed first.
+/// `health` is `null` for `CLUSTER SLOTS`, which doesn't report it. If the command is routed to multiple nodes,
+/// the response is a map from each node address to its normalized response.
+/// Any other command type is executed the same as with [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_normalized_topology(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            0,
+            RequestPriority::Normal,
+            false,
+            Some(ResponseTransform::NormalizedTopology),
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes `GEOSEARCH`, `GEORADIUS`, `GEORADIUS_RO`, `GEORADIUSBYMEMBER` or `GEORADIUSBYMEMBER_RO`, and converts the
+/// positional response into a list of maps.
+///
+/// Each map holds the `member`, its `distance` as a double, its geohash `hash` as an integer, and its `coordinates` as
+/// an array of longitude and latitude doubles. Fields that weren't requested with `WITHDIST`, `WITHHASH` or
+/// `WITHCOORD` are `null`. The count of stored members returned with the `STORE` options is returned as is.
+/// Any other command type is executed the same as with [`command`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_with_structured_geo_response(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    unsafe {
+        command_with_buffer_and_priority(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            std::ptr::null_mut(),
+            0,
+            0,
+            RequestPriority::Normal,
+            false,
+            Some(ResponseTransform::StructuredGeo),
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Executes a multi-key command (`MGET`, `MSET`, `DEL`, `UNLINK`, `EXISTS` or `TOUCH`) without explicit routing.
+///
+/// This function only validates the command type: any other command type fails with a client error. The command
+/// is then sent like [`command`] with no route, and in cluster mode the cluster connection splits it by the slots of
+/// its keys and merges the results back in the order of the original keys.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
+/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `arg_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn command_multi_key_split(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    command_type: RequestType,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+) -> *mut CommandResult {
+    let can_split = command_type
+        .get_command()
+        .is_some_and(|cmd| is_multi_key_split_command(&cmd));
+    if !can_split {
+        let client_adapter = unsafe {
+            // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+            Arc::increment_strong_count(client_adapter_ptr);
+            Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+        };
+        let err = RedisError::from((
+            ErrorKind::ClientError,
+            "Command can't be split by slot",
+            format!("{command_type:?}"),
+        ));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    // Without explicit routing, the command is split by slot in cluster mode.
+    unsafe {
+        command(
+            client_adapter_ptr,
+            request_id,
+            command_type,
+            arg_count,
+            args,
+            args_len,
+            std::ptr::null(),
+            0,
+            0,
+        )
+    }
+}
+
+/// Creates a heap-allocated `CommandResult` containing a `CommandError`.
+///
+/// This function is used to construct an error response when a Valkey command fails,
+/// intended to be returned through FFI to the calling language.
+///
+/// The resulting `CommandResult` contains:
+/// - A null `response` pointer.
+/// - A valid `command_error` pointer with error details (message and type).
+///
+/// # Parameters
+/// - `err`: The `RedisError` to be converted into a `CommandError`.
+///
+/// # Returns
+/// A raw pointer to a `CommandResult`. This must be freed using [`free_command_result`]
+/// to avoid memory leaks.
+///
+/// # Safety
+/// The returned pointer must be passed back to Rust for cleanup. Failing to call
+/// [`free_command_result`] will result in a memory leak.
+fn create_error_result_with_redis_error(err: RedisError) -> *mut CommandResult {
+    let details = errors::error_details(&err);
+    let (c_err_str, error_type) = to_c_error(err);
+    Box::into_raw(Box::new(CommandResult {
+        response: std::ptr::null_mut(),
+        command_error: Box::into_raw(Box::new(CommandError {
+            command_error_message: c_err_str,
+            command_error_type: error_type,
+            command_error_code: to_c_string_or_null(details.code),
+            command_error_retryable: details.retryable,
+            command_error_node_address: to_c_string_or_null(details.node_address),
+        })),
+    }))
+}
+
+/// Creates a heap-allocated `CommandResult` containing a `CommandError`.
+///
+/// This function is used to construct an error response when a Valkey command fails,
+/// intended to be returned through FFI to the calling language.
+///
+/// The resulting `CommandResult` contains:
+/// - A null `response` pointer.
+/// - A valid `command_error` pointer with error details (message and type).
+///
+/// # Parameters
+/// - `error_string`: The error message to be converted into a `CommandError`.
+/// - `error_type`: The error type.
+///
+/// # Returns
+/// A raw pointer to a `CommandResult`. This must be freed using [`free_command_result`]
+/// to avoid memory leaks.
+///
+/// # Safety
+/// The returned pointer must be passed back to Rust for cleanup. Failing to call
+/// [`free_command_result`] will result in a memory leak.
+fn create_error_result_with_custom_error(
+    error_string: String,
+    error_type: RequestErrorType,
+) -> *mut CommandResult {
+    let c_err_str = CString::into_raw(
+        CString::new(error_string).expect("Couldn't convert error message to CString"),
+    );
+    Box::into_raw(Box::new(CommandResult {
+        response: std::ptr::null_mut(),
+        command_error: Box::into_raw(Box::new(CommandError {
+            command_error_message: c_err_str,
+            command_error_type: error_type,
+            command_error_code: std::ptr::null(),
+            command_error_retryable: false,
+            command_error_node_address: std::ptr::null(),
+        })),
+    }))
+}
+
+/// Converts a `RedisError` into a C-compatible error representation.
+///
+/// This helper function extracts the error message and error type,
+/// and returns a raw C string pointer (`*const c_char`) along with
+/// the corresponding [`RequestErrorType`].
+///
+/// # Parameters
+/// - `err`: The `RedisError` to convert.
+///
+/// # Returns
+/// A tuple containing:
+/// - A raw C string (`*const c_char`) containing the error message.
+/// - A `RequestErrorType` representing the kind of error.
+///
+/// # Panics
+/// This function will panic if the error message cannot be converted into a `CString`.
+/// Converts `value` to a C string, or returns null if it's `None` or contains a nul byte.
+/// Removes the RESP3 attributes of a response, and returns the server time they report, in microseconds.
+fn take_server_time(value: Value) -> (Value, Option<i64>) {
+    let Value::Attribute { data, attributes } = value else {
+        return (value, None);
+    };
+    let server_time = attributes.iter().find_map(|(key, value)| {
+        let is_server_time = match key {
+            Value::SimpleString(key) => key.as_bytes() == SERVER_TIME_ATTRIBUTE,
+            Value::BulkString(key) => key == SERVER_TIME_ATTRIBUTE,
+            _ => false,
+        };
+        match value {
+            Value::Int(server_time) if is_server_time => Some(*server_time),
+            Value::Double(server_time) if is_server_time => Some(*server_time as i64),
+            _ => None,
+        }
+    });
+    (*data, server_time)
+}
+
+/// Reports the metadata of a successful command to `callback`.
+///
+/// # Safety
+/// Unsafe, because calls to an FFI function. See the safety documentation of [`CommandMetadataCallback`].
+unsafe fn report_command_metadata(
+    callback: CommandMetadataCallback,
+    request_id: usize,
+    trace: &CommandTrace,
+    server_time_us: Option<i64>,
+) {
+    let node_address = to_c_string_or_null(trace.node());
+    let metadata = CommandMetadata {
+        attempts: trace.attempts(),
+        node_address,
+        queue_time_us: trace
+            .queue_time()
+            .map_or(0, |queue_time| queue_time.as_micros() as u64),
+        server_time_us: server_time_us.unwrap_or(-1),
+    };
+    unsafe { callback(request_id, &metadata) };
+    if !node_address.is_null() {
+        _ = unsafe { CString::from_raw(node_address as *mut c_char) };
+    }
+}
+
+fn to_c_string_or_null(value: Option<String>) -> *const c_char {
+    value
+        .and_then(|value| CString::new(value).ok())
+        .map_or(std::ptr::null(), |value| value.into_raw())
+}
+
+fn to_c_error(err: RedisError) -> (*const c_char, RequestErrorType) {
+    let message = errors::error_message(&err);
+    let error_type = errors::error_type(&err);
+
+    let c_err_str = CString::into_raw(
+        CString::new(message).expect("Couldn't convert error message to CString"),
+    );
+    (c_err_str, error_type)
+}
+
+fn get_route(route: Routes, cmd: Option<&Cmd>) -> RedisResult<Option<RoutingInfo>> {
+    use glide_core::command_request::routes::Value;
+    let route = match route.value {
+        Some(route) => route,
+        None => return Ok(None),
+    };
+    let get_response_policy = |cmd: Option<&Cmd>| {
+        cmd.and_then(|cmd| {
+            cmd.command()
+                .and_then(|cmd| ResponsePolicy::for_command(&cmd))
+        })
+    };
+    match route {
+        Value::SimpleRoutes(simple_route) => {
+            let simple_route = match simple_route.enum_value() {
+                Ok(simple_route) => simple_route,
+                Err(value) => {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "simple_route was not a valid enum variant",
+                        format!("Value: {value}"),
+                    )));
+                }
+            };
+            match simple_route {
+                SimpleRoutes::AllNodes => Ok(Some(RoutingInfo::MultiNode((
+                    MultipleNodeRoutingInfo::AllNodes,
+                    get_response_policy(cmd),
+                )))),
+                SimpleRoutes::AllPrimaries => Ok(Some(RoutingInfo::MultiNode((
+                    MultipleNodeRoutingInfo::AllMasters,
+                    get_response_policy(cmd),
+                )))),
+                SimpleRoutes::Random => {
+                    Ok(Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)))
+                }
+            }
+        }
+        Value::SlotKeyRoute(slot_key_route) => Ok(Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::new(
+                redis::cluster_topology::get_slot(slot_key_route.slot_key.as_bytes()),
+                get_slot_addr(&slot_key_route.slot_type)?,
+            )),
+        ))),
+        Value::SlotIdRoute(slot_id_route) => Ok(Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::new(
+                slot_id_route.slot_id as u16,
+                get_slot_addr(&slot_id_route.slot_type)?,
+            )),
+        ))),
+        Value::ByAddressRoute(by_address_route) => match u16::try_from(by_address_route.port) {
+            Ok(port) => Ok(Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::ByAddress {
+                    host: by_address_route.host.to_string(),
+                    port,
+                },
+            ))),
+            Err(_) => Err(RedisError::from((
+                ErrorKind::ClientError,
+                "by_address_route port could not be converted to u16.",
+                format!("Value: {}", by_address_route.port),
+            ))),
+        },
+        Value::AzAffinityRoute(az_affinity_route) if az_affinity_route.az.is_empty() => {
+            Err(RedisError::from((
+                ErrorKind::ClientError,
+                "AZ affinity routes require an availability zone",
+            )))
+        }
+        Value::AzAffinityRoute(az_affinity_route) => Ok(Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::ReplicaInAZ {
+                slot: redis::cluster_topology::get_slot(az_affinity_route.slot_key.as_bytes()),
+                az: az_affinity_route.az.to_string(),
+            },
+        ))),
+        _ => Err(RedisError::from((
+            ErrorKind::ClientError,
+            "Unknown route type.",
+            format!("Value: {route:?}"),
+        ))),
+    }
+}
+
+fn get_slot_addr(slot_type: &protobuf::EnumOrUnknown<SlotTypes>) -> RedisResult<SlotAddr> {
+    let slot_addr_result = slot_type.enum_value().map(|slot_type| match slot_type {
+        SlotTypes::Primary => SlotAddr::Master,
+        SlotTypes::Replica => SlotAddr::ReplicaRequired,
+    });
+    slot_addr_result.map_err(|_| {
+        RedisError::from((
+            ErrorKind::ClientError,
+            "Received unexpected slot id type",
+            format!("Value: {slot_type:?}"),
+        ))
+    })
+}
+
+/// Allows the client to request a cluster scan command to be executed.
+///
+/// `client_adapter_ptr` is a pointer to a valid `GlideClusterClient` returned in the `ConnectionResponse` from [`create_client`].
+/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
+/// `cursor` is a cursor string.
+/// `arg_count` keeps track of how many option arguments are passed in the client.
+/// `args` is a pointer to C string representation of the string args.
+/// `args_len` is a pointer to the lengths of the C string representation of the string args.
+/// `success_callback` is the callback that will be called when a command succeeds.
+/// `failure_callback` is the callback that will be called when a command fails.
+///
+/// If the `WITH_PROGRESS` argument is passed, the response contains a third element: a progress token that can be
+/// persisted and passed to [`resume_cluster_scan`], or `Nil` if the scan is finished.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be valid until `close_client` is called.
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `cursor` must not be null. It must point to a valid C string ([`CStr`]). See the safety documentation of [`CStr::from_ptr`].
+/// * `cursor` must remain valid until the end of this call. The caller is responsible for freeing the memory allocated for this string.
+/// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn request_cluster_scan(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    cursor: *const c_char,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let cursor_id = unsafe { CStr::from_ptr(cursor) }
+        .to_str()
+        .unwrap_or("0")
+        .to_owned();
+
+    let (cluster_scan_args, with_progress) =
+        match unsafe { parse_cluster_scan_args(arg_count, args, args_len) } {
+            Ok(parsed) => parsed,
+            Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+        };
+
+    let scan_state_cursor = if cursor_id.is_empty() || cursor_id == "0" {
+        ScanStateRC::new()
+    } else {
+        match get_cluster_scan_cursor(cursor_id) {
+            Ok(existing_cursor) => existing_cursor,
+            Err(err) => {
+                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            }
+        }
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        if with_progress {
+            client
+                .cluster_scan_with_progress(&scan_state_cursor, cluster_scan_args)
+                .await
+        } else {
+            client
+                .cluster_scan(&scan_state_cursor, cluster_scan_args)
+                .await
+        }
+    })
+}
+
+/// Resumes a cluster scan from a progress token returned by a previous [`request_cluster_scan`] or [`resume_cluster_scan`] call,
+/// possibly made by another process.
+///
+/// The response has the same format as a [`request_cluster_scan`] call with the `WITH_PROGRESS` argument:
+/// the cursor, the keys, and the updated progress token.
+///
+/// `client_adapter_ptr` is a pointer to a valid `GlideClusterClient` returned in the `ConnectionResponse` from [`create_client`].
+/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
+/// `progress_token` is the progress token.
+/// `arg_count` keeps track of how many option arguments are passed in the client.
+/// `args` is a pointer to C string representation of the string args.
+/// `args_len` is a pointer to the lengths of the C string representation of the string args.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be valid until `close_client` is called.
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `progress_token` must not be null. It must point to a valid C string ([`CStr`]). See the safety documentation of [`CStr::from_ptr`].
+/// * `progress_token` must remain valid until the end of this call. The caller is responsible for freeing the memory allocated for this string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn resume_cluster_scan(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    progress_token: *const c_char,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let scan_state_cursor = match unsafe { CStr::from_ptr(progress_token) }.to_str() {
+        Ok(token) => match ScanProgress::from_token(token) {
+            Ok(progress) => ScanStateRC::from_progress(progress),
+            Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+        },
+        Err(err) => {
+            return unsafe { client_adapter.handle_redis_error(RedisError::from(err), request_id) };
+        }
+    };
+    let (cluster_scan_args, _) = match unsafe { parse_cluster_scan_args(arg_count, args, args_len) }
+    {
+        Ok(parsed) => parsed,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .cluster_scan_with_progress(&scan_state_cursor, cluster_scan_args)
+            .await
+    })
+}
+
+/// Parses the optional arguments of a cluster scan.
+/// Returns the scan arguments, and whether the `WITH_PROGRESS` argument was passed.
+///
+/// # Safety
+///
+/// * `args` and `args_len` must point to `arg_count` consecutive elements, or be null if `arg_count` is 0.
+unsafe fn parse_cluster_scan_args(
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+) -> RedisResult<(ClusterScanArgs, bool)> {
+    if arg_count == 0 {
+        return Ok((ClusterScanArgs::builder().build(), false));
+    }
+    let arg_vec =
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) };
+
+    let mut pattern: &[u8] = &[];
+    let mut object_type: &[u8] = &[];
+    let mut count: &[u8] = &[];
+    let mut allow_non_covered_slots: bool = false;
+    let mut with_progress = false;
+
+    let mut iter = arg_vec.iter().peekable();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            b"MATCH" => match iter.next() {
+                Some(pat) => pattern = pat,
+                None => {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "No argument following MATCH.",
+                    )));
+                }
+            },
+            b"TYPE" => match iter.next() {
+                Some(obj_type) => object_type = obj_type,
+                None => {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "No argument following TYPE.",
+                    )));
+                }
+            },
+            b"COUNT" => match iter.next() {
+                Some(c) => count = c,
+                None => {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "No argument following COUNT.",
+                    )));
+                }
+            },
+            b"ALLOW_NON_COVERED_SLOTS" => {
+                allow_non_covered_slots = true;
+            }
+            b"WITH_PROGRESS" => {
+                with_progress = true;
+            }
+            _ => {
+                // Unknown or unsupported arg — safely skip or log
+                continue;
+            }
+        }
+    }
+
+    // Convert back to proper types
+    let converted_count = if !count.is_empty() {
+        str::parse::<u32>(str::from_utf8(count)?)?
+    } else {
+        10 // default count value
+    };
+    let converted_type = ObjectType::from(str::from_utf8(object_type)?.to_string());
+
+    let mut cluster_scan_args_builder = ClusterScanArgs::builder();
+    if !count.is_empty() {
+        cluster_scan_args_builder = cluster_scan_args_builder.with_count(converted_count);
+    }
+    if !pattern.is_empty() {
+        cluster_scan_args_builder = cluster_scan_args_builder.with_match_pattern(pattern);
+    }
+    if !object_type.is_empty() {
+        cluster_scan_args_builder = cluster_scan_args_builder.with_object_type(converted_type);
+    }
+    cluster_scan_args_builder =
+        cluster_scan_args_builder.allow_non_covered_slots(allow_non_covered_slots);
+    Ok((cluster_scan_args_builder.build(), with_progress))
+}
+
+/// Scans the whole keyspace of a standalone client, and passes each page of keys to `page_callback`,
+/// as a standalone counterpart of [`request_cluster_scan`].
+///
+/// The `SCAN` cursor is managed internally, and the request completes once the full keyspace was covered,
+/// with the total number of keys passed to `page_callback`. A page that can't be converted ends the scan with an error.
+///
+/// `client_adapter_ptr` is a pointer to a valid standalone client returned in the `ConnectionResponse` from [`create_client`].
+/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
+/// `arg_count` keeps track of how many option arguments (`MATCH`, `COUNT` and `TYPE`) are passed in the client.
+/// `args` is a pointer to C string representation of the string args.
+/// `args_len` is a pointer to the lengths of the C string representation of the string args.
+/// `page_callback` is the callback that receives the pages of keys.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be valid until `close_client` is called.
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `args` and `args_len` must point to `arg_count` consecutive elements, or be null if `arg_count` is 0.
+/// * `page_callback` must be a valid function pointer that lives until the request completes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn scan_database(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    page_callback: ScanPageCallback,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let arg_vec = if arg_count > 0 {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
+    } else {
+        Vec::new()
+    };
+    let mut match_pattern: Option<Vec<u8>> = None;
+    let mut count: Option<u32> = None;
+    let mut object_type: Option<String> = None;
+    let mut iter = arg_vec.into_iter();
+    while let Some(arg) = iter.next() {
+        let (option, value) = match arg {
+            b"MATCH" => ("MATCH", iter.next()),
+            b"COUNT" => ("COUNT", iter.next()),
+            b"TYPE" => ("TYPE", iter.next()),
+            // Unknown or unsupported arg — safely skip
+            _ => continue,
+        };
+        let Some(value) = value else {
+            let err = RedisError::from((
+                ErrorKind::ClientError,
+                "Missing scan argument value",
+                format!("No argument following {option}."),
+            ));
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        };
+        let parsed = match option {
+            "MATCH" => {
+                match_pattern = Some(value.to_vec());
+                Ok(())
+            }
+            "COUNT" => str::from_utf8(value)
+                .map_err(RedisError::from)
+                .and_then(|value| value.parse::<u32>().map_err(RedisError::from))
+                .map(|value| count = Some(value)),
+            _ => str::from_utf8(value)
+                .map_err(RedisError::from)
+                .map(|value| object_type = Some(value.to_string())),
+        };
+        if let Err(err) = parsed {
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+    }
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .scan_database(
+                match_pattern.as_deref(),
+                count,
+                object_type.as_deref(),
+                |keys| {
+                    let page = valkey_value_to_command_response(Value::Array(keys), None)?;
+                    unsafe {
+                        page_callback(request_id, &page);
+                        free_command_response_elements(page);
+                    }
+                    Ok(())
+                },
+            )
+            .await
+    })
+}
+
+/// Remove a cluster scan cursor from the container.
+///
+/// `cursor_id` is the cursor ID returned by a previous cluster scan operation.
+///
+/// # Safety
+/// * `cursor_id` must point to a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn remove_cluster_scan_cursor(cursor_id: *const c_char) {
+    if cursor_id.is_null() {
+        return;
+    }
+
+    if let Ok(cursor_str) = unsafe { CStr::from_ptr(cursor_id).to_str() } {
+        glide_core::cluster_scan_container::remove_scan_state_cursor(cursor_str.to_string());
+    }
+}
+
+/// Cancels a cluster scan, removing its cursor from the container, so resuming the scan with the cursor fails.
+///
+/// `cursor_id` is the cursor ID returned by a previous cluster scan operation.
+///
+/// # Returns
+///
+/// `true` if the scan was cancelled, or `false` if the cursor doesn't exist, such as when it was already removed,
+/// cancelled or reaped.
+///
+/// # Safety
+/// * `cursor_id` must point to a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cancel_cluster_scan(cursor_id: *const c_char) -> bool {
+    if cursor_id.is_null() {
+        return false;
+    }
+
+    match unsafe { CStr::from_ptr(cursor_id).to_str() } {
+        Ok(cursor_str) => glide_core::cluster_scan_container::cancel_cluster_scan(cursor_str),
+        Err(_) => false,
+    }
+}
+
+/// Sets how long a cluster scan cursor may stay unused before it's reaped, so the cursors of a wrapper that crashed
+/// mid-scan don't leak. Expired cursors are reaped whenever a cursor is created, and periodically at the interval of the
+/// TTL, up to a minute. The reaped cursors are logged and counted in `cluster_scan_cursors_reaped_count` of the
+/// statistics. The setting is shared by all clients.
+///
+/// # Parameters
+///
+/// * `ttl_ms`: The time in milliseconds a cursor may stay unused, or 0 to keep the cursors until they're removed.
+#[unsafe(no_mangle)]
+pub extern "C" fn set_cluster_scan_cursor_ttl(ttl_ms: u64) {
+    glide_core::cluster_scan_container::set_cluster_scan_cursor_ttl(
+        (ttl_ms > 0).then(|| Duration::from_millis(ttl_ms)),
+    );
+}
+
+/// Allows the client to request an update to the connection password.
+///
+/// `client_adapter_ptr` is a pointer to a valid `GlideClusterClient` returned in the `ConnectionResponse` from [`create_client`].
+/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
+/// `password` is a pointer to C string representation of the password.
+/// `immediate_auth` is a boolean flag to indicate if the password should be updated immediately.
+/// `success_callback` is the callback that will be called when a command succeeds.
+/// `failure_callback` is the callback that will be called when a command fails.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be valid until `close_client` is called.
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn update_connection_password(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    password: *const c_char,
+    immediate_auth: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // argument conversion to be used in the async block
+    let password = match unsafe { CStr::from_ptr(password).to_str() } {
+        Ok(password) => password,
+        Err(e) => {
+            return unsafe { client_adapter.handle_redis_error(RedisError::from(e), request_id) };
+        }
+    };
+    let password_option = if password.is_empty() {
+        None
+    } else {
+        Some(password.to_string())
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .update_connection_password(password_option, immediate_auth)
+            .await
+    })
+}
+
+/// Manually refresh the IAM authentication token.
+///
+/// This function triggers an immediate refresh of the IAM token and updates the connection.
+/// It is only available if the client was created with IAM authentication.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing "OK" on success, or an error if:
+///   - The client is not using IAM authentication
+///   - Token generation fails
+///   - Authentication with the new token fails
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn refresh_iam_token(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.refresh_iam_token().await.map(|_| Value::Okay)
+    })
+}
+
+/// Sends `PING` to every known node concurrently and reports the round-trip latency of each one.
+///
+/// Intended for readiness probes and latency-aware routing decisions in the wrappers.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of node address to either:
+///   - An integer with the round-trip latency in microseconds, if the node responded
+///   - A string with the error message, if the node failed to respond
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn ping_all_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.ping_all_nodes().await })
+}
+
+/// Checks the cluster topology right away, instead of waiting for the next periodic check, and refreshes the slot map
+/// if the topology changed, such as after the application received a signal of a failover. The interval of the periodic
+/// checks and their jitter are configured in the `ConnectionRequest`. Supported in cluster mode only.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a boolean, which is true if the topology changed and the slot map
+///   was refreshed.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn refresh_topology(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.refresh_topology().await.map(Value::Boolean)
+    })
+}
+
+/// Reports which nodes served the latest read commands of the client, so tests can assert how the reads were
+/// distributed among the replicas.
+///
+/// Only the reads of cluster clients, routed by their `ReadFrom` strategy, are recorded. The client remembers the
+/// nodes of its latest 1000 reads.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `count`: The number of latest reads to report.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing an array of the addresses of the nodes, as `host:port` strings, oldest read first.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_served_read_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    count: u32,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        Ok(Value::Array(
+            client
+                .served_read_nodes(count as usize)
+                .into_iter()
+                .map(|address| Value::BulkString(address.into_bytes()))
+                .collect(),
+        ))
+    })
+}
+
+/// Reports the configuration of the client, with the defaults resolved for the options that weren't set, so the
+/// wrappers can implement configuration introspection without duplicating the default values of the core.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of the timeouts, inflight requests limit, protocol, read
+///   strategy, reconnect strategy and TLS mode of the client, as described by `Client::effective_config`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_default_config(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { Ok(client.effective_config()) })
+}
+
+/// Reports the smoothed round-trip latency the client measured to each node, as used by `ReadFrom::LowestLatency`.
+///
+/// Only cluster clients measure latencies: with the reads served by replicas, and with the periodic checks when
+/// reading with `ReadFrom::LowestLatency`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of node address to the latency in microseconds.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_node_latencies(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { Ok(client.node_latencies()) })
+}
+
+/// Describes a key by pipelining `TYPE`, `OBJECT ENCODING`, `TTL` and `MEMORY USAGE` in a single round trip.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key to describe.
+/// * `key_len`: Length of the key.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of:
+///   - `type`: The type of the key, or `none` if the key doesn't exist
+///   - `encoding`: The internal encoding of the value, or `null` if the key doesn't exist
+///   - `ttl`: The remaining time to live in seconds, `-1` if the key has no expiry, or `-2` if the key doesn't exist
+///   - `memory_usage`: The number of bytes used to store the key and its value, or `null` if the key doesn't exist
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must not be `null` and must point to `key_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn describe_key(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the key before spawning the task, since the caller may free it once this function returns
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.describe_key(&key).await })
+}
+
+/// Samples keys by scanning the keyspace, and returns aggregated statistics of their type, TTL and memory usage.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `sample_size`: The maximal number of keys to sample. In cluster mode the keys are sampled from all the nodes.
+/// * `pattern`: Pointer to a glob-style pattern the sampled keys must match, or `null` to sample all keys.
+/// * `pattern_len`: Length of the pattern.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of:
+///   - `sampled_keys`: The number of sampled keys
+///   - `total_memory_usage`: The number of bytes used by the sampled keys
+///   - `keys_with_ttl`: The number of sampled keys that have an expiry
+///   - `types`: A map from each key type to its `keys_count` and `memory_usage`
+///   - `biggest_keys`: The biggest sampled keys, each as a map of `key`, `type`, `ttl_ms` and `memory_usage`
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `pattern` must be `null` or point to `pattern_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn analyze_keyspace(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    sample_size: usize,
+    pattern: *const u8,
+    pattern_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the pattern before spawning the task, since the caller may free it once this function returns
+    let pattern = (!pattern.is_null())
+        .then(|| unsafe { std::slice::from_raw_parts(pattern, pattern_len) }.to_vec());
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .analyze_keyspace(sample_size, pattern.as_deref())
+            .await
+    })
+}
+
+/// Returns the circuit breaker state of each node of the client. Cluster clients report the nodes they sent
+/// commands to.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map from each node address to a map of:
+///   - `state`: `closed`, `open` or `half_open`
+///   - `consecutive_failures`: The number of consecutive connection failures or timeouts of the node
+///
+///   The map is empty if circuit breakers aren't configured.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn circuit_breaker_states(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(
+        request_id,
+        async move { client.circuit_breaker_states().await },
+    )
+}
+
+/// Computes the hash slot of a key, using the same CRC16 and hash tag rules as the server.
+///
+/// # Parameters
+///
+/// * `key`: Pointer to the key.
+/// * `key_len`: Length of the key.
+///
+/// # Returns
+///
+/// * The hash slot of the key, between 0 and 16383.
+///
+/// # Safety
+///
+/// * `key` must not be `null` and must point to `key_len` consecutive properly initialized bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_slot_for_key(key: *const u8, key_len: usize) -> u16 {
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    redis::cluster_topology::get_slot(key)
+}
+
+/// Resolves the address of the primary node that serves a key.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key.
+/// * `key_len`: Length of the key.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing the `host:port` address of the primary node,
+///   or `null` if the key's slot isn't covered by the client's current slot map.
+///   In standalone mode the address of the primary node is returned.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must not be `null` and must point to `key_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_node_for_key(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the key before spawning the task, since the caller may free it once this function returns
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(
+        request_id,
+        async move { client.get_node_for_key(&key).await },
+    )
+}
+
+/// Publishes a message to a channel.
+///
+/// With `sharded`, the message is published with `SPUBLISH`. In cluster mode it's routed to the primary that serves
+/// the slot of the channel, so the wrappers don't need to compute the route themselves. Otherwise `PUBLISH` is used.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `channel`: Pointer to the channel name.
+/// * `channel_len`: Length of the channel name.
+/// * `message`: Pointer to the message.
+/// * `message_len`: Length of the message.
+/// * `sharded`: Whether to publish to a sharded channel.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing the number of clients that received the message.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `channel` must not be `null` and must point to `channel_len` consecutive properly initialized bytes.
+/// * `message` must not be `null` and must point to `message_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn publish(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    channel: *const u8,
+    channel_len: usize,
+    message: *const u8,
+    message_len: usize,
+    sharded: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Build the command before spawning the task, since the caller may free the buffers once this function returns
+    let channel = unsafe { std::slice::from_raw_parts(channel, channel_len) };
+    let message = unsafe { std::slice::from_raw_parts(message, message_len) };
+    let (request_type, routing) = if sharded {
+        let route = Route::new(redis::cluster_topology::get_slot(channel), SlotAddr::Master);
+        (
+            RequestType::SPublish,
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::SpecificNode(route),
+            )),
+        )
+    } else {
+        (RequestType::Publish, None)
+    };
+    let Some(mut cmd) = request_type.get_command() else {
+        let err = RedisError::from((ErrorKind::ClientError, "Couldn't fetch command type"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    };
+    cmd.arg(channel).arg(message);
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.send_command(&mut cmd, routing).await
+    })
+}
+
+/// Publishes many messages in a single pipeline.
+///
+/// Each message is published with `SPUBLISH` if its `sharded` flag is set, and with `PUBLISH` otherwise. In cluster mode
+/// the sharded messages are grouped by the primary that serves the slot of their channel, so each node receives a single
+/// batch instead of a request per message.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `message_count`: The number of messages.
+/// * `channels`: Pointer to an array of channel name pointers.
+/// * `channels_len`: Pointer to an array of the channel name lengths.
+/// * `messages`: Pointer to an array of message pointers.
+/// * `messages_len`: Pointer to an array of the message lengths.
+/// * `sharded`: Pointer to an array of flags, whether to publish each message to a sharded channel.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing an array with the number of clients that received each message, in
+///   the order of the messages. A message that failed to publish is returned as an error in its position of the array.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `channels`, `channels_len`, `messages`, `messages_len` and `sharded` must point to `message_count` consecutive elements.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn publish_many(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    message_count: c_ulong,
+    channels: *const usize,
+    channels_len: *const c_ulong,
+    messages: *const usize,
+    messages_len: *const c_ulong,
+    sharded: *const bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    if message_count == 0 || sharded.is_null() {
+        let err = RedisError::from((ErrorKind::ClientError, "No messages to publish"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    // Copy the messages before spawning the task, since the caller may free the buffers once this function returns
+    let channels = unsafe { owned_byte_arrays(message_count, channels, channels_len) };
+    let messages = unsafe { owned_byte_arrays(message_count, messages, messages_len) };
+    let sharded = unsafe { from_raw_parts(sharded, message_count as usize) }.to_vec();
+    if channels.len() != sharded.len() || messages.len() != sharded.len() {
+        let err = RedisError::from((
+            ErrorKind::ClientError,
+            "Each message must have a channel and a message",
+        ));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let batch: Vec<(&[u8], &[u8], bool)> = channels
+            .iter()
+            .zip(&messages)
+            .zip(&sharded)
+            .map(|((channel, message), sharded)| (channel.as_slice(), message.as_slice(), *sharded))
+            .collect();
+        client.publish_many(&batch).await
+    })
+}
+
+/// Reports the active subscriptions of the client, as tracked by glide-core, so the wrappers don't need to keep
+/// their own bookkeeping of the subscriptions.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of `Exact`, `Pattern` and `Sharded` to a map of:
+///   - `count`: The number of subscriptions of the kind
+///   - `active`: The channels or patterns the client is subscribed to
+///   - `pending`: The channels or patterns that weren't subscribed yet, or are waiting to be restored after a reconnection
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_subscriptions(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { Ok(client.get_subscriptions()) })
+}
+
+/// Subscribes to keyspace notifications, and delivers them to `keyspace_event_callback` parsed into the event type,
+/// key and database, instead of as raw pubsub messages.
+///
+/// The keys that match any of `key_patterns` are subscribed in all databases, or all keys if no pattern is given.
+/// Cluster clients subscribe on every primary, and follow the primaries through topology changes.
+/// If `event_mask` isn't null, `notify-keyspace-events` is set to it on all nodes before subscribing, otherwise
+/// the server must already be configured to publish the notifications.
+/// The callback replaces any callback registered by a previous call, and applies to all keyspace subscriptions of the client.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `pattern_count`: The number of key patterns.
+/// * `key_patterns`: Pointer to an array of key pattern pointers.
+/// * `key_patterns_len`: Pointer to an array of the key pattern lengths.
+/// * `event_mask`: Pointer to a null-terminated `notify-keyspace-events` value, such as `KEA`, or null.
+/// * `keyspace_event_callback`: The callback that receives the notifications.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] with the response of the subscription.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key_patterns` and `key_patterns_len` must point to `pattern_count` consecutive elements, or be null if `pattern_count` is 0.
+/// * `event_mask` must be null or point to a valid null-terminated string.
+/// * `keyspace_event_callback` must be a valid function pointer that lives while the client is open/active.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn subscribe_keyspace_events(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    pattern_count: c_ulong,
+    key_patterns: *const usize,
+    key_patterns_len: *const c_ulong,
+    event_mask: *const c_char,
+    keyspace_event_callback: KeyspaceEventCallback,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the arguments before spawning the task, since the caller may free them once this function returns
+    let key_patterns = unsafe { copy_keys(pattern_count, key_patterns, key_patterns_len) };
+    let event_mask = if event_mask.is_null() {
+        None
+    } else {
+        match unsafe { CStr::from_ptr(event_mask) }.to_str() {
+            Ok(event_mask) => Some(event_mask.to_string()),
+            Err(err) => {
+                let err = RedisError::from((
+                    ErrorKind::ClientError,
+                    "Invalid keyspace events mask",
+                    err.to_string(),
+                ));
+                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            }
+        }
+    };
+
+    if let Ok(mut guard) = client_adapter.keyspace_event_callback.write() {
+        *guard = Some(keyspace_event_callback);
+    }
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .subscribe_keyspace_events(&key_patterns, event_mask.as_deref())
+            .await
+    })
+}
+
+/// Waits until the previous writes are acknowledged by at least `numreplicas` replicas, or until the timeout passed, with `WAIT`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `numreplicas`: The number of replicas to wait for.
+/// * `timeout_ms`: The timeout in milliseconds, or `0` to wait indefinitely.
+/// * `key`: Pointer to a key of the previous write, or `null`. In cluster mode `WAIT` is sent to the primary
+///   that serves the key, or to all primaries if no key is given.
+/// * `key_len`: Length of the key.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map with the `acknowledged` replicas count,
+///   the `requested` replicas count, and whether the request was `satisfied`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must be `null` or point to `key_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn wait_for_replication(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    numreplicas: u32,
+    timeout_ms: u64,
+    key: *const u8,
+    key_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the key before spawning the task, since the caller may free it once this function returns
+    let key =
+        (!key.is_null()).then(|| unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec());
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .wait_for_replication(numreplicas, timeout_ms, key.as_deref())
+            .await
+    })
+}
+
+/// Promotes a replica of a standalone primary with `FAILOVER`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid standalone client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `target_host`: Pointer to a null-terminated host of the replica to promote, or `null` to let the server choose a replica.
+/// * `target_port`: The port of the replica to promote. Ignored if `target_host` is `null`.
+/// * `force`: Promote the target even if it didn't catch up within the timeout. Requires a target and a timeout.
+/// * `abort`: Abort an ongoing failover. Can't be combined with the other options.
+/// * `timeout_ms`: How long the primary waits for the target to catch up in milliseconds, or `0` to wait indefinitely.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing `OK`, or an error if the options are invalid.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `target_host` must be `null` or point to a valid null-terminated string.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn failover(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    target_host: *const c_char,
+    target_port: u16,
+    force: bool,
+    abort: bool,
+    timeout_ms: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let target = if target_host.is_null() {
+        None
+    } else {
+        match unsafe { CStr::from_ptr(target_host) }.to_str() {
+            Ok(host) => Some((host.to_string(), target_port)),
+            Err(err) => {
+                return unsafe {
+                    client_adapter.handle_redis_error(RedisError::from(err), request_id)
+                };
+            }
+        }
+    };
+    let options = FailoverOptions {
+        target,
+        force,
+        abort,
+        timeout_ms: (timeout_ms > 0).then_some(timeout_ms),
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.failover(&options).await })
+}
+
+/// Moves a hash slot of a cluster to another primary with `CLUSTER SETSLOT`, `CLUSTER GETKEYSINSLOT` and `MIGRATE`,
+/// and reports the progress to `progress_callback` after each batch of keys.
+///
+/// A migration that fails midway leaves the slot in the migrating state, and can be resumed by calling this function again.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid cluster client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `slot`: The slot to move.
+/// * `target_host`: Pointer to a null-terminated host of the primary that receives the slot.
+/// * `target_port`: The port of the primary that receives the slot.
+/// * `batch_size`: The number of keys moved by a single `MIGRATE`. Pass 0 to use the default of 100 keys.
+/// * `timeout_ms`: The timeout of a single `MIGRATE` in milliseconds. Pass 0 to use the default of 5 seconds.
+/// * `replace`: Replace keys that already exist on the target, instead of failing the migration.
+/// * `username`: Pointer to a null-terminated username that `MIGRATE` authenticates with, or `null`.
+/// * `password`: Pointer to a null-terminated password that `MIGRATE` authenticates with, or `null` to not authenticate.
+/// * `progress_callback`: The callback that receives the progress of the migration, or `null`.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of `migrated_keys` and `batches`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `target_host` must point to a valid null-terminated string.
+/// * `username` and `password` must be `null` or point to valid null-terminated strings.
+/// * `progress_callback` must be `null` or a valid function pointer that lives until the request completes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C-unwind" fn migrate_slot(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    slot: u16,
+    target_host: *const c_char,
+    target_port: u16,
+    batch_size: u32,
+    timeout_ms: u64,
+    replace: bool,
+    username: *const c_char,
+    password: *const c_char,
+    progress_callback: Option<SlotMigrationProgressCallback>,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let to_string = |ptr: *const c_char| -> Result<Option<String>, str::Utf8Error> {
+        if ptr.is_null() {
+            return Ok(None);
+        }
+        unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .map(|value| Some(value.to_string()))
+    };
+    let (host, username, password) = match (
+        to_string(target_host),
+        to_string(username),
+        to_string(password),
+    ) {
+        (Ok(Some(host)), Ok(username), Ok(password)) => (host, username, password),
+        (Ok(None), _, _) => {
+            let err = RedisError::from((ErrorKind::ClientError, "Missing target host"));
+            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+        }
+        (Err(err), _, _) | (_, Err(err), _) | (_, _, Err(err)) => {
+            return unsafe { client_adapter.handle_redis_error(RedisError::from(err), request_id) };
+        }
+    };
+    let target = NodeAddress {
+        host,
+        port: target_port,
+    };
+    let options = SlotMigrationOptions {
+        batch_size,
+        timeout: Duration::from_millis(timeout_ms),
+        replace,
+        username,
+        password,
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .migrate_slot(slot, target, options, |progress| {
+                if let Some(progress_callback) = progress_callback {
+                    unsafe {
+                        progress_callback(request_id, progress.migrated_keys, progress.total_keys)
+                    };
+                }
+                Ok(())
+            })
+            .await
+    })
+}
+
+/// Fails over a replica of a cluster with `CLUSTER FAILOVER`, after checking that it's a replica and optionally
+/// waiting for its replication offset to catch up with its primary, and waits until the topology of the client lists
+/// it among the primaries. The progress is reported to `progress_callback` after each step.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid cluster client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `target_host`: Pointer to a null-terminated host of the replica to promote.
+/// * `target_port`: The port of the replica to promote.
+/// * `mode`: The mode of `CLUSTER FAILOVER`.
+/// * `catch_up_timeout_ms`: How long to wait for the replica to catch up with its primary before failing over, in
+///   milliseconds. Pass 0 to fail over right away.
+/// * `timeout_ms`: How long to wait for the promotion and the topology update, in milliseconds. Pass 0 to use the
+///   default of 30 seconds.
+/// * `progress_callback`: The callback that receives the progress of the failover, or `null`.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of the promoted `node`, its `previous_primary` and the
+///   `duration_ms` of the failover.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `target_host` must point to a valid null-terminated string.
+/// * `progress_callback` must be `null` or a valid function pointer that lives until the request completes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C-unwind" fn trigger_failover(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    target_host: *const c_char,
+    target_port: u16,
+    mode: ClusterFailoverMode,
+    catch_up_timeout_ms: u64,
+    timeout_ms: u64,
+    progress_callback: Option<ClusterFailoverProgressCallback>,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    if target_host.is_null() {
+        let err = RedisError::from((ErrorKind::ClientError, "Missing target host"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    }
+    let host = match unsafe { CStr::from_ptr(target_host) }.to_str() {
+        Ok(host) => host.to_string(),
+        Err(err) => {
+            return unsafe { client_adapter.handle_redis_error(RedisError::from(err), request_id) };
+        }
+    };
+    let target = NodeAddress {
+        host,
+        port: target_port,
+    };
+    let options = ClusterFailoverOptions {
+        mode,
+        catch_up_timeout: (catch_up_timeout_ms > 0)
+            .then(|| Duration::from_millis(catch_up_timeout_ms)),
+        timeout: Duration::from_millis(timeout_ms),
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .trigger_failover(target, options, |progress| {
+                if let Some(progress_callback) = progress_callback {
+                    unsafe {
+                        progress_callback(
+                            request_id,
+                            progress.stage,
+                            progress.replica_offset,
+                            progress.primary_offset,
+                        )
+                    };
+                }
+                Ok(())
+            })
+            .await
+    })
+}
+
+/// Serializes the value stored at a key with `DUMP`, so it can be recreated with [`restore_key`].
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key.
+/// * `key_len`: Length of the key.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing the serialized value, or `null` if the key doesn't exist.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must not be `null` and must point to `key_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn dump_key(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the key before spawning the task, since the caller may free it once this function returns
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.dump_key(&key).await })
+}
+
+/// Creates a key from a value serialized with [`dump_key`], using `RESTORE`.
+///
+/// The serialized value may be passed as a sequence of chunks, which are concatenated in order, so wrappers
+/// that keep large values in several buffers don't have to merge them first.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key.
+/// * `key_len`: Length of the key.
+/// * `ttl`: Time to live of the key in milliseconds, or `0` for no expiry. A Unix timestamp in milliseconds if `absttl` is set.
+/// * `chunk_count`: Number of chunks of the serialized value.
+/// * `chunks`: Array of pointers to the chunks of the serialized value.
+/// * `chunks_len`: Array of lengths of the chunks.
+/// * `replace`: Whether an existing key should be overwritten (`REPLACE`).
+/// * `absttl`: Whether `ttl` is an absolute Unix timestamp (`ABSTTL`).
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing `OK` if the key was restored.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must not be `null` and must point to `key_len` consecutive properly initialized bytes.
+/// * `chunks` and `chunks_len` must not be `null` and must point to `chunk_count` elements each. Each chunk must point to the number of bytes given by its length.
+/// * The arrays and the chunks must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn restore_key(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+    ttl: u64,
+    chunk_count: c_ulong,
+    chunks: *const usize,
+    chunks_len: *const c_ulong,
+    replace: bool,
+    absttl: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the key and the value before spawning the task, since the caller may free them once this function returns
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let serialized_value = unsafe {
+        convert_double_pointer_to_vec(chunks as *const *const c_void, chunk_count, chunks_len)
+    }
+    .concat();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .restore_key(&key, ttl, &serialized_value, replace, absttl)
+            .await
+    })
+}
+
+/// Copies the value stored at a key to another key, like `COPY`.
+///
+/// In cluster mode, when the keys hash to different slots, the value is copied with `DUMP` and `RESTORE` instead,
+/// keeping the remaining time to live of the source key. This fallback isn't atomic.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `source`: Pointer to the source key.
+/// * `source_len`: Length of the source key.
+/// * `destination`: Pointer to the destination key.
+/// * `destination_len`: Length of the destination key.
+/// * `replace`: Whether an existing destination key should be overwritten.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing `true` if the value was copied, or `false` if the source doesn't
+///   exist, or the destination exists and `replace` isn't set.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `source` must not be `null` and must point to `source_len` consecutive properly initialized bytes.
+/// * `destination` must not be `null` and must point to `destination_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn copy_key_cross_slot(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    source: *const u8,
+    source_len: usize,
+    destination: *const u8,
+    destination_len: usize,
+    replace: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the keys before spawning the task, since the caller may free them once this function returns
+    let source = unsafe { std::slice::from_raw_parts(source, source_len) }.to_vec();
+    let destination = unsafe { std::slice::from_raw_parts(destination, destination_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .copy_key_cross_slot(&source, &destination, replace)
+            .await
+    })
+}
+
+/// Merges HyperLogLogs into a destination key, like `PFMERGE`.
+///
+/// In cluster mode, the sources that hash to other slots than the destination are merged per slot into temporary keys,
+/// which are copied to the destination's slot and merged into the destination. This isn't atomic.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `destination`: Pointer to the destination key.
+/// * `destination_len`: Length of the destination key.
+/// * `source_count`: Number of source keys.
+/// * `sources`: Array of pointers to the source keys.
+/// * `sources_len`: Array of lengths for each source key.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing `OK` once the HyperLogLogs are merged.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `destination` must not be `null` and must point to `destination_len` consecutive properly initialized bytes.
+/// * `sources` and `sources_len` must point to `source_count` consecutive properly initialized values, unless `source_count` is 0.
+/// * Each source pointer must point to as many consecutive properly initialized bytes as its length in `sources_len`.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn pfmerge_across_slots(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    destination: *const u8,
+    destination_len: usize,
+    source_count: c_ulong,
+    sources: *const usize,
+    sources_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the keys before spawning the task, since the caller may free them once this function returns
+    let destination = unsafe { std::slice::from_raw_parts(destination, destination_len) }.to_vec();
+    let sources = unsafe { owned_byte_arrays(source_count, sources, sources_len) };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.pfmerge_across_slots(&destination, &sources).await
+    })
+}
+
+/// Executes a Lua script.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `GlideClusterClient` returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `hash`: SHA1 hash of the script for script caching.
+/// * `keys_count`: Number of keys in the keys array.
+/// * `keys`: Array of keys used by the script.
+/// * `keys_len`: Array of lengths for each key.
+/// * `args_count`: Number of arguments in the args array.
+/// * `args`: Array of arguments to pass to the script.
+/// * `args_len`: Array of lengths for each argument.
+/// * `route_bytes`: Optional array of bytes for routing information.
+/// * `route_bytes_len`: Length of the route_bytes array.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until either `success_callback` or `failure_callback` is finished.
+/// * `hash` must be a valid null-terminated C string.
+/// * `keys` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `keys_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `keys_count` must be 0 if `keys` and `keys_len` are null.
+/// * `keys` and `keys_len` must either be both null or be both not null.
+/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `args_count` must be 0 if `args` and `args_len` are null.
+/// * `args` and `args_len` must either be both null or be both not null.
+/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
+/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
+/// * `route_bytes_len` must be 0 if `route_bytes` is null.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn invoke_script(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    hash: *const c_char,
+    keys_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+    args_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    unsafe {
+        invoke_script_internal(
+            client_adapter_ptr,
+            request_id,
+            hash,
+            keys_count,
+            keys,
+            keys_len,
+            args_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            false,
+        )
+    }
+}
+
+/// Executes a read-only Lua script with `EVALSHA_RO`.
+///
+/// Without a route, the script may be served by a replica according to the client's `ReadFrom` strategy,
+/// so read-only scripts can scale across replicas. The server rejects scripts that modify the dataset.
+/// The parameters are the same as in [`invoke_script`].
+///
+/// # Safety
+///
+/// * The same requirements as in [`invoke_script`] apply.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn invoke_script_ro(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    hash: *const c_char,
+    keys_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+    args_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    unsafe {
+        invoke_script_internal(
+            client_adapter_ptr,
+            request_id,
+            hash,
+            keys_count,
+            keys,
+            keys_len,
+            args_count,
+            args,
+            args_len,
+            route_bytes,
+            route_bytes_len,
+            true,
+        )
+    }
+}
+
+/// Executes a Lua script given by its source.
+///
+/// The script is invoked with `EVALSHA`, and sent with `EVAL` when it isn't cached on the server, so wrappers don't
+/// need to store the script or handle `NOSCRIPT` errors themselves. Each invocation is recorded in the statistics of
+/// the script, see [`get_script_statistics`].
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `source`: The source of the script.
+/// * `source_len`: The length of `source`.
+/// * The other parameters are the same as in [`invoke_script`].
+///
+/// # Safety
+///
+/// * `source` must point to `source_len` consecutive properly initialized bytes, which must be valid until either
+///   `success_callback` or `failure_callback` is finished.
+/// * The other requirements are the same as in [`invoke_script`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn invoke_script_inline(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    source: *const u8,
+    source_len: usize,
+    keys_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+    args_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let source = unsafe { std::slice::from_raw_parts(source, source_len) };
+    let keys_vec: Vec<&[u8]> = if !keys.is_null() && !keys_len.is_null() && keys_count > 0 {
+        unsafe { convert_double_pointer_to_vec(keys as *const *const c_void, keys_count, keys_len) }
+    } else {
+        Vec::new()
+    };
+    let args_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() && args_count > 0 {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, args_count, args_len) }
+    } else {
+        Vec::new()
+    };
+
+    let route = if !route_bytes.is_null() {
+        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
+        match Routes::parse_from_bytes(r_bytes) {
+            Ok(route) => route,
+            Err(err) => {
+                let err = RedisError::from((
+                    ErrorKind::ClientError,
+                    "Decoding route failed",
+                    err.to_string(),
+                ));
+                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            }
+        }
+    } else {
+        Routes::default()
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let routing_info = get_route(route, None)?;
+        client
+            .invoke_script_inline(source, &keys_vec, &args_vec, routing_info)
+            .await
+    })
+}
+
+/// Acquires tokens from a rate limiter bucket, with the script of the algorithm bundled in glide-core, so wrappers
+/// don't need to implement their own rate limiter. The script is cached on the server as in [`invoke_script_inline`].
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `bucket_key`: Pointer to the key of the bucket.
+/// * `bucket_key_len`: Length of the key of the bucket.
+/// * `tokens`: The number of tokens to acquire, between 1 and `limit`.
+/// * `algorithm`: The algorithm of the rate limiter. All the acquisitions from a bucket must use the same algorithm.
+/// * `limit`: The number of tokens that can be acquired in a window.
+/// * `window_ms`: The window of the limit, in milliseconds.
+/// * `deny_cache_ms`: How long a denied acquisition is cached by the client, so acquiring as many tokens or more from
+///   the bucket is denied without reaching the server. `0` sends every acquisition to the server.
+///
+/// # Returns
+///
+/// A map of `allowed` to whether the tokens were acquired, `remaining` to the tokens left in the bucket,
+/// `retry_after_ms` to the time until the tokens may be available, and `cached` to whether the denial was taken from
+/// the client's cache.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `bucket_key` must point to `bucket_key_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[allow(clippy::too_many_arguments)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn rate_limit_acquire(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    bucket_key: *const u8,
+    bucket_key_len: usize,
+    tokens: u64,
+    algorithm: RateLimitAlgorithm,
+    limit: u64,
+    window_ms: u64,
+    deny_cache_ms: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let bucket_key = unsafe { std::slice::from_raw_parts(bucket_key, bucket_key_len) }.to_vec();
+    let config = RateLimitConfig {
+        algorithm,
+        limit,
+        window: Duration::from_millis(window_ms),
+        deny_cache_duration: (deny_cache_ms > 0).then(|| Duration::from_millis(deny_cache_ms)),
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .rate_limit_acquire(&bucket_key, tokens, &config)
+            .await
+    })
+}
+
+/// Acquires a lock with `SET NX PX` and a new random token, so wrappers share the same lock implementation. The lock
+/// is only released or extended with its token, by [`release_lock`] and [`extend_lock`].
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key of the lock.
+/// * `key_len`: Length of the key of the lock.
+/// * `ttl_ms`: The time in milliseconds after which the lock expires, unless it's extended.
+/// * `auto_extend`: Whether the client extends the lock every third of `ttl_ms`, until it's released or lost.
+/// * `lock_lost_callback`: Optional callback called when an automatically extended lock is lost.
+/// * `context`: The baton-pass passed to `lock_lost_callback`.
+///
+/// # Returns
+///
+/// The token of the lock, or nil if the lock is held by another owner.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` must point to `key_len` consecutive properly initialized bytes.
+/// * `lock_lost_callback`, if set, must remain valid until the lock is released or lost.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[allow(clippy::too_many_arguments)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn acquire_lock(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+    ttl_ms: u64,
+    auto_extend: bool,
+    lock_lost_callback: Option<LockLostCallback>,
+    context: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let listener = lock_lost_callback.map(|callback| {
+        Arc::new(move |key: &[u8], error: Option<&str>| {
+            // The listener is also called when the lock is released, which isn't reported.
+            if let Some(error) = error {
+                unsafe {
+                    callback(
+                        context,
+                        key.as_ptr(),
+                        key.len(),
+                        error.as_ptr(),
+                        error.len(),
+                    )
+                };
+            }
+        }) as glide_core::distributed_lock::LockExtensionListener
+    });
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .acquire_lock(&key, Duration::from_millis(ttl_ms), auto_extend, listener)
+            .await
+    })
+}
+
+/// Releases a lock acquired with [`acquire_lock`] if it's still held with the token, and stops its automatic
+/// extension.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key of the lock.
+/// * `key_len`: Length of the key of the lock.
+/// * `token`: Pointer to the token returned by [`acquire_lock`].
+/// * `token_len`: Length of the token.
+///
+/// # Returns
+///
+/// Whether the lock was held with the token and released.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` and `token` must point to `key_len` and `token_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn release_lock(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+    token: *const u8,
+    token_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let token = unsafe { std::slice::from_raw_parts(token, token_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.release_lock(&key, &token).await
+    })
+}
+
+/// Sets the expiration of a lock acquired with [`acquire_lock`] if it's still held with the token.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key`: Pointer to the key of the lock.
+/// * `key_len`: Length of the key of the lock.
+/// * `token`: Pointer to the token returned by [`acquire_lock`].
+/// * `token_len`: Length of the token.
+/// * `ttl_ms`: The time in milliseconds after which the lock expires, unless it's extended again.
+///
+/// # Returns
+///
+/// Whether the lock was held with the token and extended.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `key` and `token` must point to `key_len` and `token_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn extend_lock(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    key: *const u8,
+    key_len: usize,
+    token: *const u8,
+    token_len: usize,
+    ttl_ms: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) }.to_vec();
+    let token = unsafe { std::slice::from_raw_parts(token, token_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client
+            .extend_lock(&key, &token, Duration::from_millis(ttl_ms))
+            .await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn invoke_script_internal(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    hash: *const c_char,
+    keys_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+    args_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+    read_only: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Convert hash to Rust string
+    let hash_str = match unsafe { CStr::from_ptr(hash).to_str() } {
+        Ok(hash) => hash,
+        Err(e) => {
+            return unsafe { client_adapter.handle_redis_error(RedisError::from(e), request_id) };
+        }
+    };
+
+    // Convert keys to Vec<&[u8]>
+    let keys_vec: Vec<&[u8]> = if !keys.is_null() && !keys_len.is_null() && keys_count > 0 {
+        unsafe { convert_double_pointer_to_vec(keys as *const *const c_void, keys_count, keys_len) }
+    } else {
+        Vec::new()
+    };
+
+    // Convert args to Vec<&[u8]>
+    let args_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() && args_count > 0 {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, args_count, args_len) }
+    } else {
+        Vec::new()
+    };
+
+    // Parse routing information if provided
+    let route = if !route_bytes.is_null() {
+        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
+        match Routes::parse_from_bytes(r_bytes) {
+            Ok(route) => route,
+            Err(err) => {
+                let err = RedisError::from((
+                    ErrorKind::ClientError,
+                    "Decoding route failed",
+                    err.to_string(),
+                ));
+                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            }
+        }
+    } else {
+        Routes::default()
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let routing_info = get_route(route, None)?;
+        if read_only {
+            client
+                .invoke_script_ro(hash_str, &keys_vec, &args_vec, routing_info)
+                .await
+        } else {
+            client
+                .invoke_script(hash_str, &keys_vec, &args_vec, routing_info)
+                .await
+        }
+    })
+}
+
+/// Checks whether scripts exist in the server-side script cache of all primaries.
+///
+/// A script is reported as existing only if every primary has it cached, so wrappers don't need to
+/// aggregate the per-node `SCRIPT EXISTS` replies themselves.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `hashes_count`: Number of hashes in the hashes array.
+/// * `hashes`: Array of SHA1 hashes of the scripts to check.
+/// * `hashes_len`: Array of lengths for each hash.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing an array of booleans, one per hash, in the order given. The array
+///   is empty if `hashes_count` is 0, and the command isn't sent.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `hashes` and `hashes_len` must point to `hashes_count` consecutive hash pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn script_exists_all_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    hashes_count: c_ulong,
+    hashes: *const usize,
+    hashes_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let hashes_vec: Vec<&[u8]> = if !hashes.is_null() && !hashes_len.is_null() && hashes_count > 0 {
+        unsafe {
+            convert_double_pointer_to_vec(hashes as *const *const c_void, hashes_count, hashes_len)
+        }
+    } else {
+        Vec::new()
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.script_exists_all_nodes(&hashes_vec).await
+    })
+}
+
+/// Flushes the server-side script cache.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `mode`: Whether the cache should be flushed synchronously or asynchronously. [`FlushMode::Default`] leaves it to the server's configuration.
+/// * `route_bytes`: Optional array of bytes for routing information. Without a route, the cache is flushed on all nodes.
+/// * `route_bytes_len`: Length of the route_bytes array.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing "OK" on success.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `route_bytes` could be `null`, but if it is not `null`, it must point to `route_bytes_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn script_flush(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    mode: FlushMode,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let route = match unsafe { parse_route_bytes(route_bytes, route_bytes_len) } {
+        Ok(route) => route,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let routing_info = get_route(route, None)?;
+        client.script_flush(mode, routing_info).await
+    })
+}
+
+/// Flushes every primary node with `FLUSHALL`, or `FLUSHDB` if `flush_all` is `false`, and reports the result of each node.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `flush_all`: Whether all databases should be flushed (`FLUSHALL`) or only the selected one (`FLUSHDB`).
+/// * `mode`: Whether the nodes should be flushed synchronously or asynchronously. [`FlushMode::Default`] leaves it to the server's configuration.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of:
+///   - `succeeded`: An array of the addresses of the flushed nodes
+///   - `failed`: A map of the addresses of the nodes that failed to the error message
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn flush_cluster(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    flush_all: bool,
+    mode: FlushMode,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.flush_cluster(flush_all, mode).await
+    })
+}
+
+/// Gets configuration parameters with `CONFIG GET` on every node, and checks that the nodes agree on their values.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `parameter_count`: Number of parameters in the parameters array.
+/// * `parameters`: Array of the names or glob patterns of the parameters to get.
+/// * `parameters_len`: Array of lengths for each parameter.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of `values` to a map of each node's address to its parameters,
+///   `disagreeing` to an array of the addresses of the nodes whose parameters differ from the ones of most nodes,
+///   and `failed` to a map of the addresses of the nodes that failed to the error message.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `parameters` and `parameters_len` must point to `parameter_count` consecutive parameter pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn config_get_all_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    parameter_count: c_ulong,
+    parameters: *const usize,
+    parameters_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the parameters before spawning the task, since the caller may free them once this function returns
+    let parameters = unsafe { owned_byte_arrays(parameter_count, parameters, parameters_len) };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let parameters: Vec<&[u8]> = parameters.iter().map(Vec::as_slice).collect();
+        client.config_get_all_nodes(&parameters).await
+    })
+}
+
+/// Sets configuration parameters with `CONFIG SET` on every node, then reads them back with `CONFIG GET` to check
+/// that the nodes applied the same values. The nodes are set separately, so the parameters may be set on some nodes only.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `parameter_count`: Number of parameters in the parameters and values arrays.
+/// * `parameters`: Array of the names of the parameters to set.
+/// * `parameters_len`: Array of lengths for each parameter.
+/// * `values`: Array of the values of the parameters, in the order of `parameters`.
+/// * `values_len`: Array of lengths for each value.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing the report described in [`config_get_all_nodes`]. A node that failed
+///   to set the parameters is reported as failed with the error of `CONFIG SET`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `parameters` and `parameters_len` must point to `parameter_count` consecutive parameter pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * `values` and `values_len` must point to `parameter_count` consecutive value pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn config_set_all_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    parameter_count: c_ulong,
+    parameters: *const usize,
+    parameters_len: *const c_ulong,
+    values: *const usize,
+    values_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the parameters before spawning the task, since the caller may free them once this function returns
+    let parameters = unsafe { owned_byte_arrays(parameter_count, parameters, parameters_len) };
+    let values = unsafe { owned_byte_arrays(parameter_count, values, values_len) };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let parameters: Vec<(&[u8], &[u8])> = parameters
+            .iter()
+            .zip(&values)
+            .map(|(parameter, value)| (parameter.as_slice(), value.as_slice()))
+            .collect();
+        client.config_set_all_nodes(&parameters).await
+    })
+}
+
+/// Gets the ACL rules of a user with `ACL GETUSER`, in a structured form.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `username`: Pointer to the name of the user.
+/// * `username_len`: Length of the name of the user.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of the user's `flags`, `passwords`, `commands`, `keys`,
+///   `channels` and `selectors`, where the rules of `commands`, `keys` and `channels` are split into arrays, or nil
+///   if the user doesn't exist. See [`glide_core::acl::convert_acl_getuser_response`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `username` must point to `username_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn acl_getuser(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    username: *const u8,
+    username_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let username = unsafe { std::slice::from_raw_parts(username, username_len) }.to_vec();
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(
+        request_id,
+        async move { client.acl_getuser(&username).await },
+    )
+}
+
+/// Returns the name of the user the client is authenticated as, with `ACL WHOAMI`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn acl_whoami(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.acl_whoami().await })
+}
+
+/// Sets the ACL rules of a user with `ACL SETUSER` on every node, then reads the user back with `ACL GETUSER` to check
+/// that all the nodes hold the same rules. The rules are validated before they're sent, so an invalid rule fails the
+/// request without changing any node. The nodes are set separately, so the rules may be set on some nodes only.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `username`: Pointer to the name of the user.
+/// * `username_len`: Length of the name of the user.
+/// * `rule_count`: Number of rules in the rules array.
+/// * `rules`: Array of the rules to apply, such as `on`, `>password`, `~keys:*` or `+@read`.
+/// * `rules_len`: Array of lengths for each rule.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing the report described in [`config_get_all_nodes`], where the values
+///   of each node are the user in the form returned by [`acl_getuser`]. A node that failed to set the rules is
+///   reported as failed with the error of `ACL SETUSER`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `username` must point to `username_len` consecutive properly initialized bytes.
+/// * `rules` and `rules_len` must point to `rule_count` consecutive rule pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn acl_setuser_all_nodes(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    username: *const u8,
+    username_len: usize,
+    rule_count: c_ulong,
+    rules: *const usize,
+    rules_len: *const c_ulong,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the rules before spawning the task, since the caller may free them once this function returns
+    let username = unsafe { std::slice::from_raw_parts(username, username_len) }.to_vec();
+    let rules = unsafe { owned_byte_arrays(rule_count, rules, rules_len) };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let rules: Vec<&[u8]> = rules.iter().map(Vec::as_slice).collect();
+        client.acl_setuser_all_nodes(&username, &rules).await
+    })
+}
+
+/// Returns the capabilities of the nodes, recorded with `HELLO` after the client connected, so wrappers can gate
+/// features, such as sharded pubsub and functions, without sending their own `INFO`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `refresh`: Whether to collect the capabilities again instead of returning the recorded ones.
+///
+/// # Returns
+///
+/// A map of `nodes` to a map of the address of each node to its `server`, `version`, negotiated `protocol`,
+/// `modules`, the `sharded_pubsub` and `functions` flags, and whether it was `downgraded` to RESP2 although the client
+/// was configured with RESP3, and of `failed` to a map of the address of each node that failed to its error.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_server_capabilities(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    refresh: bool,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        client.server_capabilities(refresh).await
+    })
+}
+
+/// Lists the connections of all the nodes with `CLIENT LIST`, in a structured form.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `filter_by_type`: Whether to only list the connections of `client_type`.
+/// * `client_type`: The type of the listed connections, ignored unless `filter_by_type` is set.
+/// * `min_age_secs`: Only list the connections open for at least this many seconds. 0 lists all of them.
+/// * `min_idle_secs`: Only list the connections idle for at least this many seconds. 0 lists all of them.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of `clients` to a map of the address of each node to an array
+///   with a map of the fields of each of its connections, such as `id`, `addr`, `name`, `age`, `idle` and `flags`, and
+///   `failed` to a map of the addresses of the nodes that failed to the error message. See
+///   [`glide_core::client_list::client_lists_report`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn list_clients(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    filter_by_type: bool,
+    client_type: ClientConnectionType,
+    min_age_secs: u64,
+    min_idle_secs: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
 
-            // Return as Ok to continue transaction processing
-            Ok(command_response)
+    let filters = ClientListFilters {
+        client_type: filter_by_type.then_some(client_type),
+        min_age: (min_age_secs > 0).then(|| Duration::from_secs(min_age_secs)),
+        min_idle: (min_idle_secs > 0).then(|| Duration::from_secs(min_idle_secs)),
+    };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(
+        request_id,
+        async move { client.list_clients(&filters).await },
+    )
+}
+
+/// Gets sections of `INFO`, parsed into a structured form.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `section_count`: Number of sections in the sections array. 0 gets the default sections.
+/// * `sections`: Array of the names of the sections, such as `server` or `keyspace`.
+/// * `sections_len`: Array of lengths for each section name.
+/// * `route_bytes`: Optional array of bytes for routing information.
+/// * `route_bytes_len`: Length of the route_bytes array.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing a map of the sections to their fields, with the numeric fields as
+///   integers and doubles. With a multi-node route, or without a route for a cluster client, a map of the address of
+///   each node to its sections. See [`glide_core::info::convert_info_response`] and
+///   [`glide_core::info::aggregate_info`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `sections` and `sections_len` must point to `section_count` consecutive pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * `route_bytes` could be `null`, but if it is not `null`, it must point to `route_bytes_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_info(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    section_count: c_ulong,
+    sections: *const usize,
+    sections_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let sections: Vec<Vec<u8>> =
+        if !sections.is_null() && !sections_len.is_null() && section_count > 0 {
+            unsafe {
+                convert_double_pointer_to_vec(
+                    sections as *const *const c_void,
+                    section_count,
+                    sections_len,
+                )
+            }
+            .into_iter()
+            .map(|section| section.to_vec())
+            .collect()
+        } else {
+            Vec::new()
+        };
+
+    let route = match unsafe { parse_route_bytes(route_bytes, route_bytes_len) } {
+        Ok(route) => route,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let routing_info = get_route(route, None)?;
+        let sections: Vec<&[u8]> = sections.iter().map(Vec::as_slice).collect();
+        client.get_info(&sections, routing_info).await
+    })
+}
+
+/// Executes `FT.SEARCH` and returns its reply in a structured form.
+///
+/// Unlike sending [`RequestType::FtSearch`] through [`command`], which returns the documents as nested arrays and maps,
+/// the reply is converted by [`glide_core::search::convert_ft_search_response`] into a map of:
+/// * `total_results`: The number of documents matching the query.
+/// * `documents`: An array of maps, each holding the document's `key`, its `score` if the server returned one, and its `fields`.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `arg_count`: Number of arguments in the args array, starting with the index name and the query.
+/// * `args`: Array of arguments to pass to `FT.SEARCH`.
+/// * `args_len`: Array of lengths for each argument.
+/// * `route_bytes`: Optional array of bytes for routing information.
+/// * `route_bytes_len`: Length of the route_bytes array.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `args` and `args_len` must point to `arg_count` consecutive argument pointers and lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * `route_bytes` could be `null`, but if it is not `null`, it must point to `route_bytes_len` consecutive properly initialized bytes.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[cfg(feature = "search")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn ft_search(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    arg_count: c_ulong,
+    args: *const usize,
+    args_len: *const c_ulong,
+    route_bytes: *const u8,
+    route_bytes_len: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let arg_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() && arg_count > 0 {
+        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
+    } else {
+        Vec::new()
+    };
+
+    let Some(mut cmd) = RequestType::FtSearch.get_command() else {
+        let err = RedisError::from((ErrorKind::ClientError, "Couldn't fetch command type"));
+        return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    };
+    for arg in &arg_vec {
+        cmd.arg(arg);
+    }
+
+    let route = match unsafe { parse_route_bytes(route_bytes, route_bytes_len) } {
+        Ok(route) => route,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, request_id) },
+    };
+
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move {
+        let routing_info = get_route(route, Some(&cmd))?;
+        let value = client.send_command(&mut cmd, routing_info).await?;
+        glide_core::search::convert_ft_search_response(value)
+    })
+}
+
+/// Decodes the optional protobuf-encoded route passed alongside a request.
+///
+/// # Safety
+///
+/// * `route_bytes` could be `null`, but if it is not `null`, it must point to `route_bytes_len` consecutive properly initialized bytes.
+unsafe fn parse_route_bytes(route_bytes: *const u8, route_bytes_len: usize) -> RedisResult<Routes> {
+    if route_bytes.is_null() {
+        return Ok(Routes::default());
+    }
+    let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
+    Routes::parse_from_bytes(r_bytes).map_err(|err| {
+        RedisError::from((
+            ErrorKind::ClientError,
+            "Decoding route failed",
+            err.to_string(),
+        ))
+    })
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum RouteType {
+    AllNodes = 0,
+    AllPrimaries,
+    Random,
+    SlotId,
+    SlotKey,
+    ByAddress,
+    AzAffinity,
+}
+
+/// A mirror of [`SlotAddr`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum SlotType {
+    Primary = 0,
+    Replica,
+}
+
+impl From<&SlotType> for SlotAddr {
+    fn from(val: &SlotType) -> Self {
+        match val {
+            SlotType::Primary => SlotAddr::Master,
+            SlotType::Replica => SlotAddr::ReplicaRequired,
         }
-        Value::Push { kind, data } => {
-            // Create kind entry
-            let mut kind_entry = CommandResponse::default();
-            let map_key =
-                valkey_value_to_command_response(Value::SimpleString("kind".to_string()), None)?;
-            kind_entry.map_key = Box::into_raw(Box::new(map_key));
-            let map_val =
-                valkey_value_to_command_response(Value::SimpleString(format!("{:?}", kind)), None)?;
-            kind_entry.map_value = Box::into_raw(Box::new(map_val));
+    }
+}
 
-            // Create values entry
-            let mut values_entry = CommandResponse::default();
-            let map_key =
-                valkey_value_to_command_response(Value::SimpleString("values".to_string()), None)?;
-            values_entry.map_key = Box::into_raw(Box::new(map_key));
-            let map_val = valkey_value_to_command_response(Value::Array(data), None)?;
-            values_entry.map_value = Box::into_raw(Box::new(map_val));
+/// A structure which represents a route. To avoid extra pointer mandgling, it has fields for all route types.
+/// Depending on [`RouteType`], the struct stores:
+/// * Only `route_type` is filled, if route is a simple route;
+/// * `route_type`, `slot_id` and `slot_type`, if route is a Slot ID route;
+/// * `route_type`, `slot_key` and `slot_type`, if route is a Slot key route;
+/// * `route_type`, `hostname` and `port`, if route is a Address route;
+/// * `route_type`, `slot_key` and `az`, if route is an AZ affinity route;
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RouteInfo {
+    pub route_type: RouteType,
+    pub slot_id: i32,
+    /// zero pointer is valid, means no slot key is given (`None`)
+    pub slot_key: *const c_char,
+    pub slot_type: SlotType,
+    /// zero pointer is valid, means no hostname is given (`None`)
+    pub hostname: *const c_char,
+    pub port: i32,
+    /// zero pointer is valid, means no availability zone is given (`None`)
+    pub az: *const c_char,
+}
 
-            let (map_ptr, map_len) = convert_vec_to_pointer(vec![kind_entry, values_entry]);
-            command_response.array_value = map_ptr;
-            command_response.array_value_len = map_len;
-            command_response.response_type = ResponseType::Map;
+#[repr(C)]
+#[derive(Clone, Debug, Copy)]
+pub struct CmdInfo {
+    pub request_type: RequestType,
+    pub args: *const *const u8,
+    pub arg_count: usize,
+    pub args_len: *const usize,
+}
 
-            Ok(command_response)
+#[repr(C)]
+#[derive(Clone, Debug, Copy)]
+pub struct BatchInfo {
+    pub cmd_count: usize,
+    pub cmds: *const *const CmdInfo,
+    pub is_atomic: bool,
+    /// zero pointer is valid, means the responses aren't validated, and otherwise points to the `cmd_count`
+    /// [`ExpectedResponseType`] discriminants of the responses expected for the commands, in order
+    pub expected_types: *const u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Debug, Copy)]
+pub struct BatchOptionsInfo {
+    // three params from PipelineRetryStrategy
+    pub retry_server_error: bool,
+    pub retry_connection_error: bool,
+    /// Whether commands redirected with ASK are retried on the importing node. Wrappers should default it to `true`.
+    pub follow_ask_redirects: bool,
+    pub has_timeout: bool,
+    pub timeout: u32,
+    pub route_info: *const RouteInfo,
+}
+
+/// Execute a batch.
+///
+/// # Safety
+/// * `client_ptr` must not be `null`.
+/// * `client_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`Box::from_raw`].
+/// * This function should only be called should with a pointer created by [`create_client`], before [`close_client`] was called with the pointer.
+/// * `batch_ptr` must not be `null`.
+/// * `batch_ptr` must be able to be safely casted to a valid [`BatchInfo`]. See the safety documentation of [`create_pipeline`].
+/// * `options_ptr` could be `null`, but if it is not `null`, it must be a valid [`BatchOptionsInfo`] pointer. See the safety documentation of [`get_pipeline_options`].
+#[allow(rustdoc::private_intra_doc_links)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn batch(
+    client_ptr: *const c_void,
+    callback_index: usize,
+    batch_ptr: *const BatchInfo,
+    raise_on_error: bool,
+    options_ptr: *const BatchOptionsInfo,
+    span_ptr: u64,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_ptr);
+        Arc::from_raw(client_ptr as *mut ClientAdapter)
+    };
+    let mut client = client_adapter.core.client();
+
+    // Get compression manager for batch operations
+    let compression_manager = client_adapter.core.client().compression_manager();
+
+    // TODO handle panics
+    let value_codec = client_adapter
+        .core
+        .value_codec
+        .read()
+        .ok()
+        .and_then(|value_codec| value_codec.clone());
+    let mut pipeline = match unsafe {
+        create_pipeline(
+            batch_ptr,
+            compression_manager.as_ref(),
+            value_codec.as_deref(),
+        )
+    } {
+        Ok(pipeline) => pipeline,
+        Err(err) => {
+            return unsafe {
+                client_adapter.handle_custom_error(
+                    err,
+                    RequestErrorType::Unspecified,
+                    callback_index,
+                )
+            };
+        }
+    };
+    if span_ptr != 0 {
+        pipeline.set_pipeline_span(unsafe { get_unsafe_span_from_ptr(Some(span_ptr)) });
+    }
+    let child_span = create_child_span(pipeline.span().as_ref(), "send_batch");
+    let (routing, timeout, pipeline_retry_strategy) =
+        match unsafe { get_pipeline_options(options_ptr) } {
+            Ok(options) => options,
+            Err(err) => return unsafe { client_adapter.handle_redis_error(err, callback_index) },
+        };
+    let expected_types = match unsafe { get_expected_response_types(batch_ptr) } {
+        Ok(expected_types) => expected_types,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, callback_index) },
+    };
+
+    let request_types = unsafe { get_request_types(batch_ptr) };
+
+    let result = client_adapter.execute_request(callback_index, async move {
+        let response = if pipeline.is_atomic() {
+            client
+                .send_transaction(&pipeline, routing, timeout, raise_on_error)
+                .await
+        } else {
+            client
+                .send_pipeline(
+                    &pipeline,
+                    routing,
+                    raise_on_error,
+                    timeout,
+                    pipeline_retry_strategy,
+                )
+                .await
+        }?;
+        validate_batch_response(&expected_types, &response)?;
+        match value_codec {
+            Some(value_codec) => unsafe {
+                value_codec.decode_batch_response(&request_types, response)
+            },
+            None => Ok(response),
         }
-        // TODO: Add support for other return types.
-        _ => todo!(),
-    };
+    });
+
+    if let Ok(span) = child_span {
+        span.end();
+    }
     result
 }
 
-/// Executes a command.
+/// Copies an array of keys passed by the caller.
+///
+/// # Safety
+/// * `keys` and `keys_len` must point to `key_count` consecutive elements, or be null if `key_count` is 0.
+unsafe fn copy_keys(
+    key_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+) -> Vec<Vec<u8>> {
+    if key_count == 0 {
+        return Vec::new();
+    }
+    unsafe { convert_double_pointer_to_vec(keys as *const *const c_void, key_count, keys_len) }
+        .into_iter()
+        .map(|key| key.to_vec())
+        .collect()
+}
+
+/// Watches keys for an optimistic transaction, with `WATCH`.
+///
+/// `WATCH` is sent through a side connection that is reserved until the watched transaction is executed with
+/// [`execute_transaction_with_watch`] or the watch is released with [`unwatch`], so other commands of the client
+/// can't release or trigger the watch. Only one watch is active at a time per client, and a second call waits until
+/// the active watch ends, or until it expires after a minute without `EXEC` or `UNWATCH`.
+///
+/// The response is the token of the watch, an integer that [`unwatch`] and [`execute_transaction_with_watch`] take
+/// to end it.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `key_count`: The number of keys.
+/// * `keys`: Pointer to an array of key pointers.
+/// * `keys_len`: Pointer to an array of the key lengths.
 ///
 /// # Safety
 ///
 /// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
-/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
-/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * `arg_count` must be 0 if `args` and `args_len` are null.
-/// * `args` and `args_len` must either be both null or be both not null.
-/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * `route_bytes_len` must be 0 if `route_bytes` is null.
-/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
-/// * This function should only be called should with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * `keys` and `keys_len` must point to `key_count` consecutive elements.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn command(
+pub unsafe extern "C-unwind" fn watch(
     client_adapter_ptr: *const c_void,
     request_id: usize,
-    command_type: RequestType,
-    arg_count: c_ulong,
-    args: *const usize,
-    args_len: *const c_ulong,
-    route_bytes: *const u8,
-    route_bytes_len: usize,
-    span_ptr: u64,
+    key_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
 ) -> *mut CommandResult {
-    unsafe {
-        command_with_buffer(
-            client_adapter_ptr,
-            request_id,
-            command_type,
-            arg_count,
-            args,
-            args_len,
-            route_bytes,
-            route_bytes_len,
-            std::ptr::null_mut(),
-            0,
-            span_ptr,
-        )
-    }
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    // Copy the keys before spawning the task, since the caller may free them once this function returns
+    let keys = unsafe { copy_keys(key_count, keys, keys_len) };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.watch(&keys).await })
 }
 
-/// Executes a command, optionally copying a BulkString response directly into a
-/// caller-provided buffer instead of returning it as a heap-allocated value.
-///
-/// When `response_buf` is null (and `response_buf_len` is 0), behaves identically
-/// to [`command`] — the response flows through the normal `execute_request` path.
-///
-/// When `response_buf` is non-null, the response is written directly into the buffer:
-/// - `response.string_value` = number of bytes written as a string, or Nil response for missing keys.
-/// - Errors if the value exceeds `response_buf_len`.
+/// Releases the watch identified by `watch_token` with `UNWATCH`. Does nothing if the watch already ended or expired,
+/// so the watch of another caller is never released.
 ///
 /// # Safety
 ///
 /// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`std::sync::Arc::from_raw`].
-/// * `request_id` must be a request ID from the foreign language and must be valid until either `success_callback` or `failure_callback` is finished.
-/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `arg_count` the number of elements in `args` and `args_len`. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * `arg_count` must be 0 if `args` and `args_len` are null.
-/// * `args` and `args_len` must either be both null or be both not null.
-/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * `route_bytes_len` must be 0 if `route_bytes` is null.
-/// * When non-null, `response_buf` must point to a writable buffer of at least `response_buf_len` bytes.
-/// * `response_buf_len` must be 0 if `response_buf` is null.
-/// * `span_ptr` is a valid pointer to [`Arc<GlideSpan>`], a span created by [`create_otel_span`] or `0`. The span must be valid until the command is finished.
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
 /// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn command_with_buffer(
+pub unsafe extern "C-unwind" fn unwatch(
     client_adapter_ptr: *const c_void,
     request_id: usize,
-    command_type: RequestType,
-    arg_count: c_ulong,
-    args: *const usize,
-    args_len: *const c_ulong,
-    route_bytes: *const u8,
-    route_bytes_len: usize,
-    response_buf: *mut u8,
-    response_buf_len: usize,
-    span_ptr: u64,
+    watch_token: u64,
 ) -> *mut CommandResult {
     let client_adapter = unsafe {
         // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
         Arc::increment_strong_count(client_adapter_ptr);
         Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
+    let mut client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.unwatch(watch_token).await })
+}
 
-    let arg_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() {
-        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len) }
-    } else {
-        Vec::new()
+/// Executes a transaction through the connection of a watch, and releases the watch.
+/// If `key_count` isn't 0, the keys are watched first, as with [`watch`], and `watch_token` is ignored. Otherwise the
+/// watch identified by `watch_token` is used, and the request fails if it already ended or expired. If both are 0, the
+/// transaction is executed without a watch.
+///
+/// If the transaction is aborted because a watched key was modified, the request fails with
+/// [`RequestErrorType::WatchedKeyModified`].
+///
+/// # Safety
+/// * `client_ptr` must not be `null`.
+/// * `client_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`Box::from_raw`].
+/// * This function should only be called should with a pointer created by [`create_client`], before [`close_client`] was called with the pointer.
+/// * `keys` and `keys_len` must point to `key_count` consecutive elements, or be null if `key_count` is 0.
+/// * `batch_ptr` must not be `null`.
+/// * `batch_ptr` must be able to be safely casted to a valid [`BatchInfo`]. See the safety documentation of [`create_pipeline`].
+/// * `options_ptr` could be `null`, but if it is not `null`, it must be a valid [`BatchOptionsInfo`] pointer. See the safety documentation of [`get_pipeline_options`].
+#[allow(rustdoc::private_intra_doc_links)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn execute_transaction_with_watch(
+    client_ptr: *const c_void,
+    callback_index: usize,
+    watch_token: u64,
+    key_count: c_ulong,
+    keys: *const usize,
+    keys_len: *const c_ulong,
+    batch_ptr: *const BatchInfo,
+    raise_on_error: bool,
+    options_ptr: *const BatchOptionsInfo,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_ptr);
+        Arc::from_raw(client_ptr as *mut ClientAdapter)
+    };
+    let mut client = client_adapter.core.client();
+    let compression_manager = client_adapter.core.client().compression_manager();
+
+    let value_codec = client_adapter
+        .core
+        .value_codec
+        .read()
+        .ok()
+        .and_then(|value_codec| value_codec.clone());
+    let mut pipeline = match unsafe {
+        create_pipeline(
+            batch_ptr,
+            compression_manager.as_ref(),
+            value_codec.as_deref(),
+        )
+    } {
+        Ok(pipeline) => pipeline,
+        Err(err) => {
+            return unsafe {
+                client_adapter.handle_custom_error(
+                    err,
+                    RequestErrorType::Unspecified,
+                    callback_index,
+                )
+            };
+        }
+    };
+    pipeline.atomic();
+    let (routing, timeout, _) = match unsafe { get_pipeline_options(options_ptr) } {
+        Ok(options) => options,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, callback_index) },
+    };
+    let keys = unsafe { copy_keys(key_count, keys, keys_len) };
+    let expected_types = match unsafe { get_expected_response_types(batch_ptr) } {
+        Ok(expected_types) => expected_types,
+        Err(err) => return unsafe { client_adapter.handle_redis_error(err, callback_index) },
     };
 
-    // Create the command outside of the task to ensure that the command arguments passed
-    // from the foreign code are still valid
-    let mut cmd = match command_type.get_command() {
-        Some(cmd) => cmd,
-        None => {
-            let err = RedisError::from((ErrorKind::ClientError, "Couldn't fetch command type"));
-            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    let request_types = unsafe { get_request_types(batch_ptr) };
+
+    client_adapter.execute_request(callback_index, async move {
+        let response = client
+            .execute_transaction_with_watch(
+                (watch_token != 0).then_some(watch_token),
+                &keys,
+                &pipeline,
+                routing,
+                timeout,
+                raise_on_error,
+            )
+            .await?;
+        validate_batch_response(&expected_types, &response)?;
+        match value_codec {
+            Some(value_codec) => unsafe {
+                value_codec.decode_batch_response(&request_types, response)
+            },
+            None => Ok(response),
+        }
+    })
+}
+
+/// Convert raw C string to a rust string.
+///
+/// # Safety
+///
+/// * `ptr` must be able to be safely casted to a valid [`CStr`] via [`CStr::from_ptr`]. See the safety documentation of [`std::ffi::CStr::from_ptr`].
+unsafe fn ptr_to_str(ptr: *const c_char) -> String {
+    if !ptr.is_null() {
+        unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().into()
+    } else {
+        "".into()
+    }
+}
+
+/// Convert route configuration to a corresponding object.
+///
+/// # Safety
+/// * `route_ptr` could be `null`, but if it is not `null`, it must be a valid pointer to a [`RouteInfo`] struct.
+/// * `slot_key`, `hostname` and `az` in dereferenced [`RouteInfo`] struct must contain valid string pointers when corresponding `route_type` is set.
+///   See description of [`RouteInfo`] and the safety documentation of [`ptr_to_str`].
+///
+/// Returns an error if an AZ affinity route has no availability zone.
+pub(crate) unsafe fn create_route(
+    route_ptr: *const RouteInfo,
+    cmd: Option<&Cmd>,
+) -> RedisResult<Option<RoutingInfo>> {
+    if route_ptr.is_null() {
+        return Ok(None);
+    }
+    let route = unsafe { *route_ptr };
+    let routing = match route.route_type {
+        RouteType::Random => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
+        RouteType::AllNodes => Some(RoutingInfo::MultiNode((
+            MultipleNodeRoutingInfo::AllNodes,
+            cmd.and_then(|c| ResponsePolicy::for_command(&c.command().unwrap())),
+        ))),
+        RouteType::AllPrimaries => Some(RoutingInfo::MultiNode((
+            MultipleNodeRoutingInfo::AllMasters,
+            cmd.and_then(|c| ResponsePolicy::for_command(&c.command().unwrap())),
+        ))),
+        RouteType::SlotId => Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::new(
+                route.slot_id as u16,
+                (&route.slot_type).into(),
+            )),
+        )),
+        RouteType::SlotKey => Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::new(
+                redis::cluster_topology::get_slot(unsafe { ptr_to_str(route.slot_key) }.as_bytes()),
+                (&route.slot_type).into(),
+            )),
+        )),
+        RouteType::ByAddress => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+            host: unsafe { ptr_to_str(route.hostname) },
+            port: route.port as u16,
+        })),
+        RouteType::AzAffinity => {
+            let az = unsafe { ptr_to_str(route.az) };
+            if az.is_empty() {
+                return Err(RedisError::from((
+                    ErrorKind::ClientError,
+                    "AZ affinity routes require an availability zone",
+                )));
+            }
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::ReplicaInAZ {
+                    slot: redis::cluster_topology::get_slot(
+                        unsafe { ptr_to_str(route.slot_key) }.as_bytes(),
+                    ),
+                    az,
+                },
+            ))
         }
     };
+    Ok(routing)
+}
+
+/// Convert [`CmdInfo`] to a [`Cmd`].
+///
+/// # Safety
+/// * `cmd_ptr` must be able to be safely casted to a valid [`CmdInfo`]
+/// * `args` and `args_len` in a referred [`CmdInfo`] structure must not be `null`.
+/// * `data` in a referred [`CmdInfo`] structure must point to `arg_count` consecutive string pointers.
+/// * `args_len` in a referred [`CmdInfo`] structure must point to `arg_count` consecutive string lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
+/// * The callbacks of `value_codec` must be valid. See the safety documentation of [`ValueCodecCallback`].
+pub(crate) unsafe fn create_cmd(
+    ptr: *const CmdInfo,
+    compression_manager: Option<&std::sync::Arc<glide_core::compression::CompressionManager>>,
+    value_codec: Option<&ValueCodec>,
+) -> Result<Cmd, String> {
+    let info = unsafe { *ptr };
+    let arg_vec = unsafe {
+        convert_double_pointer_to_vec(
+            info.args as *const *const c_void,
+            info.arg_count as c_ulong,
+            info.args_len as *const c_ulong,
+        )
+    };
+
+    let Some(mut cmd) = info.request_type.get_command() else {
+        return Err("Couldn't fetch command type".into());
+    };
+    info.request_type.validate_args(&arg_vec)?;
 
     // Check if compression is enabled before converting args
-    let compression_manager = client_adapter.core.client.compression_manager();
     let should_process_compression = compression_manager
         .as_ref()
         .map(|cm| cm.is_enabled())
         .unwrap_or(false);
 
-    if should_process_compression {
+    let value_codec = value_codec.filter(|value_codec| value_codec.handles(info.request_type));
+
+    if should_process_compression || value_codec.is_some() {
         // Convert arg_vec to owned Vec<Vec<u8>> for compression processing
         let mut owned_args: Vec<Vec<u8>> = arg_vec.iter().map(|&arg| arg.to_vec()).collect();
 
+        // Values are encoded before they're compressed, the same as in `command`.
+        if let Some(value_codec) = value_codec {
+            unsafe { value_codec.encode_args(info.request_type, &mut owned_args) }
+                .map_err(|err| err.to_string())?;
+        }
+
         // Apply compression to command arguments
         if let Err(err) = glide_core::compression::process_command_args_for_compression(
             &mut owned_args,
-            command_type,
-            compression_manager.as_deref(),
-        ) {
-            let err = RedisError::from((
-                ErrorKind::ClientError,
-                "Compression failed",
-                err.to_string(),
-            ));
-            return unsafe { client_adapter.handle_redis_error(err, request_id) };
+            info.request_type,
+            compression_manager.map(|m| m.as_ref()),
+        ) {
+            return Err(format!("Compression failed: {}", err));
         }
 
         // Use the compressed arguments
@@ -1346,1770 +7635,1820 @@ pub unsafe extern "C-unwind" fn command_with_buffer(
         }
     }
 
-    if span_ptr != 0 {
-        cmd.set_span(unsafe { get_unsafe_span_from_ptr(Some(span_ptr)) });
-    }
-
-    let route = if !route_bytes.is_null() {
-        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
-        match Routes::parse_from_bytes(r_bytes) {
-            Ok(route) => route,
-            Err(err) => {
-                let err = RedisError::from((
-                    ErrorKind::ClientError,
-                    "Decoding route failed",
-                    err.to_string(),
-                ));
-                return unsafe { client_adapter.handle_redis_error(err, request_id) };
-            }
-        }
-    } else {
-        Routes::default()
-    };
-
-    // Check inflight request limit
-    if !client_adapter.core.client.reserve_inflight_request() {
-        let err = RedisError::from((ErrorKind::ClientError, "Reached maximum inflight requests"));
-        return unsafe { client_adapter.handle_redis_error(err, request_id) };
-    }
-
-    let child_span = create_child_span(cmd.span().as_ref(), "send_command");
-    let mut client = client_adapter.core.client.clone();
-    let client_for_release = client_adapter.core.client.clone();
-
-    let buf_option = if response_buf.is_null() {
-        None
-    } else {
-        Some(ResponseBuffer(response_buf, response_buf_len))
-    };
-
-    let result = client_adapter.execute_request_with_buffer(
-        request_id,
-        async move {
-            let routing_info = get_route(route, Some(&cmd))?;
-            let result = client.send_command(&mut cmd, routing_info).await;
-            client_for_release.release_inflight_request();
-            result
-        },
-        buf_option,
-    );
-    if let Ok(span) = child_span {
-        span.end();
-    }
-    result
+    Ok(cmd)
 }
 
-/// Creates a heap-allocated `CommandResult` containing a `CommandError`.
-///
-/// This function is used to construct an error response when a Valkey command fails,
-/// intended to be returned through FFI to the calling language.
-///
-/// The resulting `CommandResult` contains:
-/// - A null `response` pointer.
-/// - A valid `command_error` pointer with error details (message and type).
-///
-/// # Parameters
-/// - `err`: The `RedisError` to be converted into a `CommandError`.
-///
-/// # Returns
-/// A raw pointer to a `CommandResult`. This must be freed using [`free_command_result`]
-/// to avoid memory leaks.
+/// Convert [`BatchInfo`] to a [`Pipeline`].
 ///
 /// # Safety
-/// The returned pointer must be passed back to Rust for cleanup. Failing to call
-/// [`free_command_result`] will result in a memory leak.
-fn create_error_result_with_redis_error(err: RedisError) -> *mut CommandResult {
-    let (c_err_str, error_type) = to_c_error(err);
-    Box::into_raw(Box::new(CommandResult {
-        response: std::ptr::null_mut(),
-        command_error: Box::into_raw(Box::new(CommandError {
-            command_error_message: c_err_str,
-            command_error_type: error_type,
-        })),
-    }))
-}
+/// * `ptr` must be able to be safely casted to a valid [`BatchInfo`].
+/// * `cmds` in a referred [`BatchInfo`] structure must not be `null`.
+/// * `cmds` in a referred [`BatchInfo`] structure must point to `cmd_count` consecutive [`CmdInfo`] pointers.
+///   They must be able to be safely casted to a valid to a slice of the corresponding type via [`from_raw_parts`]. See the safety documentation of [`from_raw_parts`].
+/// * Every pointer stored in `cmds` must not be `null` and must point to a valid [`CmdInfo`] structure.
+/// * All data in referred [`CmdInfo`] structure(s) should be valid. See the safety documentation of [`create_cmd`].
+pub(crate) unsafe fn create_pipeline(
+    ptr: *const BatchInfo,
+    compression_manager: Option<&std::sync::Arc<glide_core::compression::CompressionManager>>,
+    value_codec: Option<&ValueCodec>,
+) -> Result<Pipeline, String> {
+    let info = unsafe { *ptr };
+    let cmd_pointers = unsafe { from_raw_parts(info.cmds, info.cmd_count) };
+    let mut pipeline = Pipeline::with_capacity(info.cmd_count);
+    for (i, cmd_ptr) in cmd_pointers.iter().enumerate() {
+        match unsafe { create_cmd(*cmd_ptr, compression_manager, value_codec) } {
+            Ok(cmd) => pipeline.add_command(cmd),
+            Err(err) => return Err(format!("Coudln't create {i:?}'th command: {err:?}")),
+        };
+    }
+    if info.is_atomic {
+        pipeline.atomic();
+    }
 
-/// Creates a heap-allocated `CommandResult` containing a `CommandError`.
-///
-/// This function is used to construct an error response when a Valkey command fails,
-/// intended to be returned through FFI to the calling language.
-///
-/// The resulting `CommandResult` contains:
-/// - A null `response` pointer.
-/// - A valid `command_error` pointer with error details (message and type).
-///
-/// # Parameters
-/// - `error_string`: The error message to be converted into a `CommandError`.
-/// - `error_type`: The error type.
-///
-/// # Returns
-/// A raw pointer to a `CommandResult`. This must be freed using [`free_command_result`]
-/// to avoid memory leaks.
-///
-/// # Safety
-/// The returned pointer must be passed back to Rust for cleanup. Failing to call
-/// [`free_command_result`] will result in a memory leak.
-fn create_error_result_with_custom_error(
-    error_string: String,
-    error_type: RequestErrorType,
-) -> *mut CommandResult {
-    let c_err_str = CString::into_raw(
-        CString::new(error_string).expect("Couldn't convert error message to CString"),
-    );
-    Box::into_raw(Box::new(CommandResult {
-        response: std::ptr::null_mut(),
-        command_error: Box::into_raw(Box::new(CommandError {
-            command_error_message: c_err_str,
-            command_error_type: error_type,
-        })),
-    }))
+    Ok(pipeline)
 }
 
-/// Converts a `RedisError` into a C-compatible error representation.
-///
-/// This helper function extracts the error message and error type,
-/// and returns a raw C string pointer (`*const c_char`) along with
-/// the corresponding [`RequestErrorType`].
-///
-/// # Parameters
-/// - `err`: The `RedisError` to convert.
-///
-/// # Returns
-/// A tuple containing:
-/// - A raw C string (`*const c_char`) containing the error message.
-/// - A `RequestErrorType` representing the kind of error.
+/// Returns the request types of the commands of a [`BatchInfo`], in order.
 ///
-/// # Panics
-/// This function will panic if the error message cannot be converted into a `CString`.
-fn to_c_error(err: RedisError) -> (*const c_char, RequestErrorType) {
-    let message = errors::error_message(&err);
-    let error_type = errors::error_type(&err);
-
-    let c_err_str = CString::into_raw(
-        CString::new(message).expect("Couldn't convert error message to CString"),
-    );
-    (c_err_str, error_type)
-}
-
-fn get_route(route: Routes, cmd: Option<&Cmd>) -> RedisResult<Option<RoutingInfo>> {
-    use glide_core::command_request::routes::Value;
-    let route = match route.value {
-        Some(route) => route,
-        None => return Ok(None),
-    };
-    let get_response_policy = |cmd: Option<&Cmd>| {
-        cmd.and_then(|cmd| {
-            cmd.command()
-                .and_then(|cmd| ResponsePolicy::for_command(&cmd))
-        })
-    };
-    match route {
-        Value::SimpleRoutes(simple_route) => {
-            let simple_route = match simple_route.enum_value() {
-                Ok(simple_route) => simple_route,
-                Err(value) => {
-                    return Err(RedisError::from((
-                        ErrorKind::ClientError,
-                        "simple_route was not a valid enum variant",
-                        format!("Value: {value}"),
-                    )));
-                }
-            };
-            match simple_route {
-                SimpleRoutes::AllNodes => Ok(Some(RoutingInfo::MultiNode((
-                    MultipleNodeRoutingInfo::AllNodes,
-                    get_response_policy(cmd),
-                )))),
-                SimpleRoutes::AllPrimaries => Ok(Some(RoutingInfo::MultiNode((
-                    MultipleNodeRoutingInfo::AllMasters,
-                    get_response_policy(cmd),
-                )))),
-                SimpleRoutes::Random => {
-                    Ok(Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)))
-                }
-            }
-        }
-        Value::SlotKeyRoute(slot_key_route) => Ok(Some(RoutingInfo::SingleNode(
-            SingleNodeRoutingInfo::SpecificNode(Route::new(
-                redis::cluster_topology::get_slot(slot_key_route.slot_key.as_bytes()),
-                get_slot_addr(&slot_key_route.slot_type)?,
-            )),
-        ))),
-        Value::SlotIdRoute(slot_id_route) => Ok(Some(RoutingInfo::SingleNode(
-            SingleNodeRoutingInfo::SpecificNode(Route::new(
-                slot_id_route.slot_id as u16,
-                get_slot_addr(&slot_id_route.slot_type)?,
-            )),
-        ))),
-        Value::ByAddressRoute(by_address_route) => match u16::try_from(by_address_route.port) {
-            Ok(port) => Ok(Some(RoutingInfo::SingleNode(
-                SingleNodeRoutingInfo::ByAddress {
-                    host: by_address_route.host.to_string(),
-                    port,
-                },
-            ))),
-            Err(_) => Err(RedisError::from((
-                ErrorKind::ClientError,
-                "by_address_route port could not be converted to u16.",
-                format!("Value: {}", by_address_route.port),
-            ))),
-        },
-        _ => Err(RedisError::from((
-            ErrorKind::ClientError,
-            "Unknown route type.",
-            format!("Value: {route:?}"),
-        ))),
-    }
-}
-
-fn get_slot_addr(slot_type: &protobuf::EnumOrUnknown<SlotTypes>) -> RedisResult<SlotAddr> {
-    let slot_addr_result = slot_type.enum_value().map(|slot_type| match slot_type {
-        SlotTypes::Primary => SlotAddr::Master,
-        SlotTypes::Replica => SlotAddr::ReplicaRequired,
-    });
-    slot_addr_result.map_err(|_| {
-        RedisError::from((
-            ErrorKind::ClientError,
-            "Received unexpected slot id type",
-            format!("Value: {slot_type:?}"),
-        ))
-    })
+/// # Safety
+/// * `ptr` must be able to be safely casted to a valid [`BatchInfo`], whose commands are valid. See the safety
+///   documentation of [`create_pipeline`].
+unsafe fn get_request_types(ptr: *const BatchInfo) -> Vec<RequestType> {
+    let info = unsafe { *ptr };
+    unsafe { from_raw_parts(info.cmds, info.cmd_count) }
+        .iter()
+        .map(|&cmd_ptr| unsafe { *cmd_ptr }.request_type)
+        .collect()
 }
 
-/// Allows the client to request a cluster scan command to be executed.
-///
-/// `client_adapter_ptr` is a pointer to a valid `GlideClusterClient` returned in the `ConnectionResponse` from [`create_client`].
-/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
-/// `cursor` is a cursor string.
-/// `arg_count` keeps track of how many option arguments are passed in the client.
-/// `args` is a pointer to C string representation of the string args.
-/// `args_len` is a pointer to the lengths of the C string representation of the string args.
-/// `success_callback` is the callback that will be called when a command succeeds.
-/// `failure_callback` is the callback that will be called when a command fails.
+/// Converts the response types expected for the commands of a [`BatchInfo`], or returns an empty vector if the
+/// responses aren't validated.
 ///
 /// # Safety
+/// * `ptr` must be able to be safely casted to a valid [`BatchInfo`].
+/// * `expected_types` in a referred [`BatchInfo`] structure must be `null` or point to `cmd_count` consecutive values.
+unsafe fn get_expected_response_types(
+    ptr: *const BatchInfo,
+) -> RedisResult<Vec<ExpectedResponseType>> {
+    let info = unsafe { *ptr };
+    if info.expected_types.is_null() {
+        return Ok(Vec::new());
+    }
+    unsafe { from_raw_parts(info.expected_types, info.cmd_count) }
+        .iter()
+        .map(|&expected_type| ExpectedResponseType::try_from(expected_type))
+        .collect()
+}
+
+/// Convert [`BatchOptionsInfo`] to a tuple of corresponding values.
 ///
-/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be valid until `close_client` is called.
-/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
-/// * `cursor` must not be null. It must point to a valid C string ([`CStr`]). See the safety documentation of [`CStr::from_ptr`].
-/// * `cursor` must remain valid until the end of this call. The caller is responsible for freeing the memory allocated for this string.
-/// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
-#[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn request_cluster_scan(
-    client_adapter_ptr: *const c_void,
-    request_id: usize,
-    cursor: *const c_char,
-    arg_count: c_ulong,
-    args: *const usize,
-    args_len: *const c_ulong,
-) -> *mut CommandResult {
-    let client_adapter = unsafe {
-        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
-        Arc::increment_strong_count(client_adapter_ptr);
-        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+/// # Safety
+/// * `ptr` could be `null`, but if it is not `null`, it must be a valid pointer to a [`BatchOptionsInfo`] struct.
+/// * `route_info` in dereferenced [`BatchOptionsInfo`] struct must contain a [`RouteInfo`] pointer.
+///   See description of [`RouteInfo`] and the safety documentation of [`create_route`].
+///
+/// Returns an error if the route of the options is invalid. See [`create_route`].
+pub(crate) unsafe fn get_pipeline_options(
+    ptr: *const BatchOptionsInfo,
+) -> RedisResult<(Option<RoutingInfo>, Option<u32>, PipelineRetryStrategy)> {
+    if ptr.is_null() {
+        return Ok((None, None, PipelineRetryStrategy::new(false, false)));
+    }
+    let info = unsafe { *ptr };
+    let timeout = if info.has_timeout {
+        Some(info.timeout)
+    } else {
+        None
     };
-    let cursor_id = unsafe { CStr::from_ptr(cursor) }
-        .to_str()
-        .unwrap_or("0")
-        .to_owned();
+    let route = unsafe { create_route(info.route_info, None) }?;
 
-    let cluster_scan_args: ClusterScanArgs = if arg_count > 0 {
-        let arg_vec = unsafe {
-            convert_double_pointer_to_vec(args as *const *const c_void, arg_count, args_len)
-        };
+    Ok((
+        route,
+        timeout,
+        PipelineRetryStrategy {
+            retry_server_error: info.retry_server_error,
+            retry_connection_error: info.retry_connection_error,
+            follow_ask_redirects: info.follow_ask_redirects,
+        },
+    ))
+}
 
-        let mut pattern: &[u8] = &[];
-        let mut object_type: &[u8] = &[];
-        let mut count: &[u8] = &[];
-        let mut allow_non_covered_slots: bool = false;
-
-        let mut iter = arg_vec.iter().peekable();
-        while let Some(arg) = iter.next() {
-            match *arg {
-                b"MATCH" => match iter.next() {
-                    Some(pat) => pattern = pat,
-                    None => {
-                        let err = RedisError::from((
-                            ErrorKind::ClientError,
-                            "No argument following MATCH.",
-                        ));
-                        return unsafe { client_adapter.handle_redis_error(err, request_id) };
-                    }
-                },
-                b"TYPE" => match iter.next() {
-                    Some(obj_type) => object_type = obj_type,
-                    None => {
-                        let err = RedisError::from((
-                            ErrorKind::ClientError,
-                            "No argument following TYPE.",
-                        ));
-                        return unsafe { client_adapter.handle_redis_error(err, request_id) };
-                    }
-                },
-                b"COUNT" => match iter.next() {
-                    Some(c) => count = c,
-                    None => {
-                        let err = RedisError::from((
-                            ErrorKind::ClientError,
-                            "No argument following COUNT.",
-                        ));
-                        return unsafe { client_adapter.handle_redis_error(err, request_id) };
-                    }
-                },
-                b"ALLOW_NON_COVERED_SLOTS" => {
-                    allow_non_covered_slots = true;
-                }
-                _ => {
-                    // Unknown or unsupported arg — safely skip or log
-                    continue;
-                }
-            }
+/// Helper function to extract and validate command name from RequestType.
+/// Returns the command name or None if validation fails.
+/// Falls back to "CustomCommand" for user-defined commands (e.g., EVAL, EVALSHA).
+fn extract_command_name(request_type: RequestType, context: &str) -> Option<String> {
+    // Validate request type and extract command
+    let cmd = match request_type.get_command() {
+        Some(cmd) => cmd,
+        None => {
+            logger_core::log_error(
+                "ffi_otel",
+                format!("{context}: RequestType has no command available"),
+            );
+            return None;
         }
+    };
 
-        // Convert back to proper types
-        let converted_count = match str::from_utf8(count) {
-            Ok(v) => {
-                if !count.is_empty() {
-                    match str::parse::<u32>(v) {
-                        Ok(v) => v,
-                        Err(e) => {
-                            return unsafe {
-                                client_adapter.handle_redis_error(RedisError::from(e), request_id)
-                            };
-                        }
-                    }
-                } else {
-                    10 // default count value
-                }
-            }
+    // Extract command name, falling back to "CustomCommand" for user-defined commands
+    // (e.g. EVAL) where the Cmd struct has no pre-set command bytes.
+    let command_name = match cmd.command() {
+        Some(bytes) => match std::str::from_utf8(bytes.as_slice()) {
+            Ok(name) => name.to_owned(),
             Err(e) => {
-                return unsafe {
-                    client_adapter.handle_redis_error(RedisError::from(e), request_id)
-                };
+                logger_core::log_error(
+                    "ffi_otel",
+                    format!("{context}: Command bytes are not valid UTF-8: {e}"),
+                );
+                return None;
             }
-        };
+        },
+        None => "CustomCommand".to_owned(),
+    };
 
-        let converted_type = match str::from_utf8(object_type) {
-            Ok(v) => ObjectType::from(v.to_string()),
-            Err(e) => {
-                return unsafe {
-                    client_adapter.handle_redis_error(RedisError::from(e), request_id)
-                };
-            }
-        };
+    // Validate command name length (reasonable limit to prevent abuse)
+    if command_name.len() > 256 {
+        logger_core::log_error(
+            "ffi_otel",
+            format!(
+                "{context}: Command name too long ({} chars), max 256",
+                command_name.len()
+            ),
+        );
+        return None;
+    }
 
-        let mut cluster_scan_args_builder = ClusterScanArgs::builder();
-        if !count.is_empty() {
-            cluster_scan_args_builder = cluster_scan_args_builder.with_count(converted_count);
-        }
-        if !pattern.is_empty() {
-            cluster_scan_args_builder = cluster_scan_args_builder.with_match_pattern(pattern);
-        }
-        if !object_type.is_empty() {
-            cluster_scan_args_builder = cluster_scan_args_builder.with_object_type(converted_type);
-        }
-        cluster_scan_args_builder =
-            cluster_scan_args_builder.allow_non_covered_slots(allow_non_covered_slots);
-        cluster_scan_args_builder.build()
-    } else {
-        ClusterScanArgs::builder().build()
-    };
+    Some(command_name)
+}
 
-    let scan_state_cursor = if cursor_id.is_empty() || cursor_id == "0" {
-        ScanStateRC::new()
-    } else {
-        match get_cluster_scan_cursor(cursor_id) {
-            Ok(existing_cursor) => existing_cursor,
-            Err(err) => {
-                return unsafe { client_adapter.handle_redis_error(err, request_id) };
-            }
-        }
+/// Creates an OpenTelemetry span with the given name and returns a pointer to the span as u64.
+#[unsafe(no_mangle)]
+pub extern "C" fn create_otel_span(request_type: RequestType) -> u64 {
+    let command_name = match extract_command_name(request_type, "create_otel_span") {
+        Some(name) => name,
+        None => return 0,
     };
-    let mut client = client_adapter.core.client.clone();
-    client_adapter.execute_request(request_id, async move {
-        client
-            .cluster_scan(&scan_state_cursor, cluster_scan_args)
-            .await
-    })
+
+    // Create span and convert to pointer
+    let span = GlideOpenTelemetry::new_span(&command_name);
+    let arc = Arc::new(span);
+    let ptr = Arc::into_raw(arc);
+    let span_ptr = ptr as u64;
+
+    logger_core::log_debug(
+        "ffi_otel",
+        format!(
+            "create_otel_span: Successfully created span '{command_name}' with pointer 0x{span_ptr:x}",
+        ),
+    );
+    span_ptr
 }
 
-/// Remove a cluster scan cursor from the container.
-///
-/// `cursor_id` is the cursor ID returned by a previous cluster scan operation.
+/// Creates an OpenTelemetry span with a fixed name "batch" and returns a pointer to the span as u64.
 ///
-/// # Safety
-/// * `cursor_id` must point to a valid C string.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn remove_cluster_scan_cursor(cursor_id: *const c_char) {
-    if cursor_id.is_null() {
-        return;
-    }
+pub extern "C" fn create_batch_otel_span() -> u64 {
+    let command_name = "Batch";
 
-    if let Ok(cursor_str) = unsafe { CStr::from_ptr(cursor_id).to_str() } {
-        glide_core::cluster_scan_container::remove_scan_state_cursor(cursor_str.to_string());
-    }
+    // Create span and convert to pointer
+    let span = GlideOpenTelemetry::new_span(command_name);
+    let arc = Arc::new(span);
+    let ptr = Arc::into_raw(arc);
+    let span_ptr = ptr as u64;
+
+    logger_core::log_debug(
+        "ffi_otel",
+        format!(
+            "create_batch_otel_span: Successfully created batch span with pointer 0x{span_ptr:x}",
+        ),
+    );
+    span_ptr
 }
 
-/// Allows the client to request an update to the connection password.
+/// Creates an OpenTelemetry batch span with a parent span and returns a pointer to the span as u64.
+/// This function creates a child span with the name "Batch" under the provided parent span.
+/// Returns 0 on failure.
 ///
-/// `client_adapter_ptr` is a pointer to a valid `GlideClusterClient` returned in the `ConnectionResponse` from [`create_client`].
-/// `request_id` is a unique identifier for a valid payload buffer which is created in the client.
-/// `password` is a pointer to C string representation of the password.
-/// `immediate_auth` is a boolean flag to indicate if the password should be updated immediately.
-/// `success_callback` is the callback that will be called when a command succeeds.
-/// `failure_callback` is the callback that will be called when a command fails.
+/// # Parameters
+/// * `parent_span_ptr`: A u64 pointer to the parent span created by create_otel_span, create_named_otel_span, or create_batch_otel_span
 ///
-/// # Safety
+/// # Returns
+/// * A u64 pointer to the created child batch span, or 0 if creation fails
 ///
-/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be valid until `close_client` is called.
-/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
-/// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
+/// # Safety
+/// * `parent_span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`], [`create_named_otel_span`], or [`create_batch_otel_span`], or 0.
+/// * If `parent_span_ptr` is 0 or invalid, the function will create an independent batch span as fallback.
 #[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn update_connection_password(
-    client_adapter_ptr: *const c_void,
-    request_id: usize,
-    password: *const c_char,
-    immediate_auth: bool,
-) -> *mut CommandResult {
-    let client_adapter = unsafe {
-        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
-        Arc::increment_strong_count(client_adapter_ptr);
-        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
-    };
+pub unsafe extern "C" fn create_batch_otel_span_with_parent(parent_span_ptr: u64) -> u64 {
+    let command_name = "Batch";
 
-    // argument conversion to be used in the async block
-    let password = match unsafe { CStr::from_ptr(password).to_str() } {
-        Ok(password) => password,
+    // Handle parent span pointer validation with graceful fallback
+    if parent_span_ptr == 0 {
+        logger_core::log_warn(
+            "ffi_otel",
+            "create_batch_otel_span_with_parent: parent_span_ptr is null (0), creating independent batch span as fallback",
+        );
+        // Graceful fallback: create independent batch span
+        let span = GlideOpenTelemetry::new_span(command_name);
+        let arc = Arc::new(span);
+        let ptr = Arc::into_raw(arc);
+        let span_ptr = ptr as u64;
+        logger_core::log_debug(
+            "ffi_otel",
+            format!(
+                "create_batch_otel_span_with_parent: Created independent fallback batch span with pointer 0x{span_ptr:x}",
+            ),
+        );
+        return span_ptr;
+    }
+
+    // Convert parent pointer to GlideSpan and use existing add_span method
+    let span = match unsafe { GlideOpenTelemetry::span_from_pointer(parent_span_ptr) } {
+        Ok(parent_span) => {
+            // Use existing add_span method to create child batch span
+            match parent_span.add_span(command_name) {
+                Ok(child_span) => child_span,
+                Err(e) => {
+                    logger_core::log_warn(
+                        "ffi_otel",
+                        format!(
+                            "create_batch_otel_span_with_parent: Failed to create child batch span with parent 0x{parent_span_ptr:x}: {e}. Creating independent batch span as fallback.",
+                        ),
+                    );
+                    // Graceful fallback: create independent batch span
+                    GlideOpenTelemetry::new_span(command_name)
+                }
+            }
+        }
         Err(e) => {
-            return unsafe { client_adapter.handle_redis_error(RedisError::from(e), request_id) };
+            logger_core::log_warn(
+                "ffi_otel",
+                format!(
+                    "create_batch_otel_span_with_parent: Invalid parent span pointer 0x{parent_span_ptr:x}: {e}. Creating independent batch span as fallback.",
+                ),
+            );
+            // Graceful fallback: create independent batch span
+            GlideOpenTelemetry::new_span(command_name)
         }
     };
-    let password_option = if password.is_empty() {
-        None
-    } else {
-        Some(password.to_string())
-    };
-    let mut client = client_adapter.core.client.clone();
-    client_adapter.execute_request(request_id, async move {
-        client
-            .update_connection_password(password_option, immediate_auth)
-            .await
-    })
+
+    // Convert span to pointer and return
+    let arc = Arc::new(span);
+    let ptr = Arc::into_raw(arc);
+    let span_ptr = ptr as u64;
+
+    logger_core::log_debug(
+        "ffi_otel",
+        format!(
+            "create_batch_otel_span_with_parent: Successfully created batch span with parent 0x{parent_span_ptr:x}, child pointer 0x{span_ptr:x}",
+        ),
+    );
+    span_ptr
 }
 
-/// Manually refresh the IAM authentication token.
-///
-/// This function triggers an immediate refresh of the IAM token and updates the connection.
-/// It is only available if the client was created with IAM authentication.
+/// Creates an OpenTelemetry span with a custom name and returns a pointer to the span as u64.
+/// This function is intended for creating parent spans that can be used with create_otel_span_with_parent.
+/// Returns 0 on failure.
 ///
 /// # Parameters
-///
-/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
-/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `span_name`: A null-terminated C string containing the name for the span
 ///
 /// # Returns
-///
-/// * A pointer to a [`CommandResult`] containing "OK" on success, or an error if:
-///   - The client is not using IAM authentication
-///   - Token generation fails
-///   - Authentication with the new token fails
+/// * A u64 pointer to the created span, or 0 if creation fails
 ///
 /// # Safety
-///
-/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
-/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
-/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+/// * `span_name` must be a valid pointer to a null-terminated C string
+/// * The string must be valid UTF-8
+/// * The caller is responsible for eventually calling drop_otel_span with the returned pointer
 #[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn refresh_iam_token(
-    client_adapter_ptr: *const c_void,
-    request_id: usize,
-) -> *mut CommandResult {
-    let client_adapter = unsafe {
-        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
-        Arc::increment_strong_count(client_adapter_ptr);
-        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+pub unsafe extern "C" fn create_named_otel_span(span_name: *const c_char) -> u64 {
+    // Validate input pointer
+    if span_name.is_null() {
+        logger_core::log_error(
+            "ffi_otel",
+            "create_named_otel_span: span_name pointer is null",
+        );
+        return 0;
+    }
+
+    // Convert C string to Rust string with safe error handling
+    let c_str = unsafe { CStr::from_ptr(span_name) };
+
+    let name_str = match c_str.to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            logger_core::log_error(
+                "ffi_otel",
+                format!("create_named_otel_span: span_name is not valid UTF-8: {e}",),
+            );
+            return 0;
+        }
     };
 
-    let mut client = client_adapter.core.client.clone();
-    client_adapter.execute_request(request_id, async move {
-        client.refresh_iam_token().await.map(|_| Value::Okay)
-    })
+    // Validate string length (reasonable limit to prevent abuse)
+    // Note: Empty names are allowed as per test expectations
+    if name_str.len() > 256 {
+        logger_core::log_error(
+            "ffi_otel",
+            format!(
+                "create_named_otel_span: span_name too long ({} chars), max 256",
+                name_str.len()
+            ),
+        );
+        return 0;
+    }
+
+    // Validate string content (basic sanity check for control characters)
+    if name_str
+        .chars()
+        .any(|c| c.is_control() && c != '\t' && c != '\n' && c != '\r')
+    {
+        logger_core::log_error(
+            "ffi_otel",
+            "create_named_otel_span: span_name contains invalid control characters",
+        );
+        return 0;
+    }
+
+    // Create the named span using existing new_span method
+    let span = GlideOpenTelemetry::new_span(name_str);
+    let arc = Arc::new(span);
+    let ptr = Arc::into_raw(arc);
+    let span_ptr = ptr as u64;
+
+    logger_core::log_debug(
+        "ffi_otel",
+        format!(
+            "create_named_otel_span: Successfully created named span '{name_str}' with pointer 0x{span_ptr:x}",
+        ),
+    );
+    span_ptr
 }
 
-/// Executes a Lua script.
+/// Creates an OpenTelemetry span with the given request type as a child of the provided parent span.
+/// Returns a pointer to the child span as u64, or 0 on failure.
 ///
 /// # Parameters
+/// * `request_type`: The type of request to create a span for
+/// * `parent_span_ptr`: A pointer to the parent span (created by create_otel_span or create_named_otel_span)
 ///
-/// * `client_adapter_ptr`: Pointer to a valid `GlideClusterClient` returned from [`create_client`].
-/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
-/// * `hash`: SHA1 hash of the script for script caching.
-/// * `keys_count`: Number of keys in the keys array.
-/// * `keys`: Array of keys used by the script.
-/// * `keys_len`: Array of lengths for each key.
-/// * `args_count`: Number of arguments in the args array.
-/// * `args`: Array of arguments to pass to the script.
-/// * `args_len`: Array of lengths for each argument.
-/// * `route_bytes`: Optional array of bytes for routing information.
-/// * `route_bytes_len`: Length of the route_bytes array.
+/// # Returns
+/// * A u64 pointer to the created child span, or 0 if creation fails
 ///
 /// # Safety
-///
-/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
-/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
-/// * `request_id` must be valid until either `success_callback` or `failure_callback` is finished.
-/// * `hash` must be a valid null-terminated C string.
-/// * `keys` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `keys_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `keys_count` must be 0 if `keys` and `keys_len` are null.
-/// * `keys` and `keys_len` must either be both null or be both not null.
-/// * `args` is an optional bytes pointers array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `args_len` is an optional bytes length array. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `args_count` must be 0 if `args` and `args_len` are null.
-/// * `args` and `args_len` must either be both null or be both not null.
-/// * `route_bytes` is an optional array of bytes that will be parsed into a Protobuf `Routes` object. The array must be allocated by the caller and subsequently freed by the caller after this function returns.
-/// * `route_bytes_len` is the number of bytes in `route_bytes`. It must also not be greater than the max value of a signed pointer-sized integer.
-/// * `route_bytes_len` must be 0 if `route_bytes` is null.
-/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+/// * `parent_span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`], [`create_named_otel_span`], or [`create_batch_otel_span`], or 0.
+/// * If `parent_span_ptr` is 0 or invalid, the function will create an independent span as fallback.
 #[unsafe(no_mangle)]
-pub unsafe extern "C-unwind" fn invoke_script(
-    client_adapter_ptr: *const c_void,
-    request_id: usize,
-    hash: *const c_char,
-    keys_count: c_ulong,
-    keys: *const usize,
-    keys_len: *const c_ulong,
-    args_count: c_ulong,
-    args: *const usize,
-    args_len: *const c_ulong,
-    route_bytes: *const u8,
-    route_bytes_len: usize,
-) -> *mut CommandResult {
-    let client_adapter = unsafe {
-        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
-        Arc::increment_strong_count(client_adapter_ptr);
-        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
-    };
-
-    // Convert hash to Rust string
-    let hash_str = match unsafe { CStr::from_ptr(hash).to_str() } {
-        Ok(hash) => hash,
-        Err(e) => {
-            return unsafe { client_adapter.handle_redis_error(RedisError::from(e), request_id) };
-        }
-    };
-
-    // Convert keys to Vec<&[u8]>
-    let keys_vec: Vec<&[u8]> = if !keys.is_null() && !keys_len.is_null() && keys_count > 0 {
-        unsafe { convert_double_pointer_to_vec(keys as *const *const c_void, keys_count, keys_len) }
-    } else {
-        Vec::new()
+pub unsafe extern "C" fn create_otel_span_with_parent(
+    request_type: RequestType,
+    parent_span_ptr: u64,
+) -> u64 {
+    let command_name = match extract_command_name(request_type, "create_otel_span_with_parent") {
+        Some(name) => name,
+        None => return 0,
     };
 
-    // Convert args to Vec<&[u8]>
-    let args_vec: Vec<&[u8]> = if !args.is_null() && !args_len.is_null() && args_count > 0 {
-        unsafe { convert_double_pointer_to_vec(args as *const *const c_void, args_count, args_len) }
-    } else {
-        Vec::new()
-    };
+    // Handle parent span pointer validation with graceful fallback
+    if parent_span_ptr == 0 {
+        logger_core::log_warn(
+            "ffi_otel",
+            "create_otel_span_with_parent: parent_span_ptr is null (0), creating independent span as fallback",
+        );
+        // Graceful fallback: create independent span
+        let span = GlideOpenTelemetry::new_span(&command_name);
+        let arc = Arc::new(span);
+        let ptr = Arc::into_raw(arc);
+        let span_ptr = ptr as u64;
+        logger_core::log_debug(
+            "ffi_otel",
+            format!(
+                "create_otel_span_with_parent: Created independent fallback span '{command_name}' with pointer 0x{span_ptr:x}",
+            ),
+        );
+        return span_ptr;
+    }
 
-    // Parse routing information if provided
-    let route = if !route_bytes.is_null() {
-        let r_bytes = unsafe { std::slice::from_raw_parts(route_bytes, route_bytes_len) };
-        match Routes::parse_from_bytes(r_bytes) {
-            Ok(route) => route,
-            Err(err) => {
-                let err = RedisError::from((
-                    ErrorKind::ClientError,
-                    "Decoding route failed",
-                    err.to_string(),
-                ));
-                return unsafe { client_adapter.handle_redis_error(err, request_id) };
+    // Convert parent pointer to GlideSpan and use existing add_span method
+    let span = match unsafe { GlideOpenTelemetry::span_from_pointer(parent_span_ptr) } {
+        Ok(parent_span) => {
+            // Use existing add_span method to create child span
+            match parent_span.add_span(&command_name) {
+                Ok(child_span) => child_span,
+                Err(e) => {
+                    logger_core::log_warn(
+                        "ffi_otel",
+                        format!(
+                            "create_otel_span_with_parent: Failed to create child span '{command_name}' with parent 0x{parent_span_ptr:x}: {e}. Creating independent span as fallback.",
+                        ),
+                    );
+                    // Graceful fallback: create independent span
+                    GlideOpenTelemetry::new_span(&command_name)
+                }
             }
         }
-    } else {
-        Routes::default()
-    };
-
-    let mut client = client_adapter.core.client.clone();
-    client_adapter.execute_request(request_id, async move {
-        let routing_info = get_route(route, None)?;
-        client
-            .invoke_script(hash_str, &keys_vec, &args_vec, routing_info)
-            .await
-    })
-}
-
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub enum RouteType {
-    AllNodes = 0,
-    AllPrimaries,
-    Random,
-    SlotId,
-    SlotKey,
-    ByAddress,
-}
-
-/// A mirror of [`SlotAddr`]
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub enum SlotType {
-    Primary = 0,
-    Replica,
-}
-
-impl From<&SlotType> for SlotAddr {
-    fn from(val: &SlotType) -> Self {
-        match val {
-            SlotType::Primary => SlotAddr::Master,
-            SlotType::Replica => SlotAddr::ReplicaRequired,
+        Err(e) => {
+            logger_core::log_warn(
+                "ffi_otel",
+                format!(
+                    "create_otel_span_with_parent: Invalid parent span pointer 0x{parent_span_ptr:x}: {e}. Creating independent span as fallback.",
+                ),
+            );
+            // Graceful fallback: create independent span
+            GlideOpenTelemetry::new_span(&command_name)
         }
-    }
-}
-
-/// A structure which represents a route. To avoid extra pointer mandgling, it has fields for all route types.
-/// Depending on [`RouteType`], the struct stores:
-/// * Only `route_type` is filled, if route is a simple route;
-/// * `route_type`, `slot_id` and `slot_type`, if route is a Slot ID route;
-/// * `route_type`, `slot_key` and `slot_type`, if route is a Slot key route;
-/// * `route_type`, `hostname` and `port`, if route is a Address route;
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct RouteInfo {
-    pub route_type: RouteType,
-    pub slot_id: i32,
-    /// zero pointer is valid, means no slot key is given (`None`)
-    pub slot_key: *const c_char,
-    pub slot_type: SlotType,
-    /// zero pointer is valid, means no hostname is given (`None`)
-    pub hostname: *const c_char,
-    pub port: i32,
-}
-
-#[repr(C)]
-#[derive(Clone, Debug, Copy)]
-pub struct CmdInfo {
-    pub request_type: RequestType,
-    pub args: *const *const u8,
-    pub arg_count: usize,
-    pub args_len: *const usize,
-}
+    };
 
-#[repr(C)]
-#[derive(Clone, Debug, Copy)]
-pub struct BatchInfo {
-    pub cmd_count: usize,
-    pub cmds: *const *const CmdInfo,
-    pub is_atomic: bool,
-}
+    // Convert span to pointer and return
+    let arc = Arc::new(span);
+    let ptr = Arc::into_raw(arc);
+    let span_ptr = ptr as u64;
 
-#[repr(C)]
-#[derive(Clone, Debug, Copy)]
-pub struct BatchOptionsInfo {
-    // two params from PipelineRetryStrategy
-    pub retry_server_error: bool,
-    pub retry_connection_error: bool,
-    pub has_timeout: bool,
-    pub timeout: u32,
-    pub route_info: *const RouteInfo,
+    logger_core::log_debug(
+        "ffi_otel",
+        format!(
+            "create_otel_span_with_parent: Successfully created span '{command_name}' with parent 0x{parent_span_ptr:x}, child pointer 0x{span_ptr:x}",
+        ),
+    );
+    span_ptr
 }
 
-/// Execute a batch.
+/// Drops an OpenTelemetry span given its pointer as u64.
 ///
 /// # Safety
-/// * `client_ptr` must not be `null`.
-/// * `client_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`]. See the safety documentation of [`Box::from_raw`].
-/// * This function should only be called should with a pointer created by [`create_client`], before [`close_client`] was called with the pointer.
-/// * `batch_ptr` must not be `null`.
-/// * `batch_ptr` must be able to be safely casted to a valid [`BatchInfo`]. See the safety documentation of [`create_pipeline`].
-/// * `options_ptr` could be `null`, but if it is not `null`, it must be a valid [`BatchOptionsInfo`] pointer. See the safety documentation of [`get_pipeline_options`].
-#[allow(rustdoc::private_intra_doc_links)]
+/// * `span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`] or `0`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn batch(
-    client_ptr: *const c_void,
-    callback_index: usize,
-    batch_ptr: *const BatchInfo,
-    raise_on_error: bool,
-    options_ptr: *const BatchOptionsInfo,
-    span_ptr: u64,
-) -> *mut CommandResult {
-    let client_adapter = unsafe {
-        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
-        Arc::increment_strong_count(client_ptr);
-        Arc::from_raw(client_ptr as *mut ClientAdapter)
-    };
-    let mut client = client_adapter.core.client.clone();
+pub unsafe extern "C" fn drop_otel_span(span_ptr: u64) {
+    // Validate span pointer
+    if span_ptr == 0 {
+        logger_core::log_debug("ffi_otel", "drop_otel_span: Ignoring null span pointer (0)");
+        return;
+    }
 
-    // Get compression manager for batch operations
-    let compression_manager = client_adapter.core.client.compression_manager();
+    // Validate pointer alignment and bounds (basic safety checks)
+    if !span_ptr.is_multiple_of(8) {
+        logger_core::log_error(
+            "ffi_otel",
+            format!("drop_otel_span: Invalid span pointer - misaligned: 0x{span_ptr:x}",),
+        );
+        return;
+    }
 
-    // TODO handle panics
-    let mut pipeline = match unsafe { create_pipeline(batch_ptr, compression_manager.as_ref()) } {
-        Ok(pipeline) => pipeline,
-        Err(err) => {
-            return unsafe {
-                client_adapter.handle_custom_error(
-                    err,
-                    RequestErrorType::Unspecified,
-                    callback_index,
-                )
-            };
-        }
-    };
-    if span_ptr != 0 {
-        pipeline.set_pipeline_span(unsafe { get_unsafe_span_from_ptr(Some(span_ptr)) });
+    // Check for obviously invalid pointer values
+    const MIN_VALID_ADDRESS: u64 = 0x1000; // 4KB, below this is likely invalid
+    const MAX_VALID_ADDRESS: u64 = 0x7FFF_FFFF_FFFF_FFF8; // Max user space on most 64-bit systems
+
+    if span_ptr < MIN_VALID_ADDRESS {
+        logger_core::log_error(
+            "ffi_otel",
+            format!("drop_otel_span: Invalid span pointer - address too low: 0x{span_ptr:x}",),
+        );
+        return;
     }
-    let child_span = create_child_span(pipeline.span().as_ref(), "send_batch");
-    let (routing, timeout, pipeline_retry_strategy) = unsafe { get_pipeline_options(options_ptr) };
 
-    let result = client_adapter.execute_request(callback_index, async move {
-        if pipeline.is_atomic() {
-            client
-                .send_transaction(&pipeline, routing, timeout, raise_on_error)
-                .await
-        } else {
-            client
-                .send_pipeline(
-                    &pipeline,
-                    routing,
-                    raise_on_error,
-                    timeout,
-                    pipeline_retry_strategy,
-                )
-                .await
-        }
-    });
+    if span_ptr > MAX_VALID_ADDRESS {
+        logger_core::log_error(
+            "ffi_otel",
+            format!("drop_otel_span: Invalid span pointer - address too high: 0x{span_ptr:x}",),
+        );
+        return;
+    }
 
-    if let Ok(span) = child_span {
-        span.end();
+    // Attempt to safely drop the span
+    unsafe {
+        // Use std::panic::catch_unwind to handle potential panics during Arc::from_raw
+        let result = std::panic::catch_unwind(|| {
+            Arc::from_raw(span_ptr as *const GlideSpan);
+        });
+
+        match result {
+            Ok(_) => {
+                logger_core::log_debug(
+                    "ffi_otel",
+                    format!(
+                        "drop_otel_span: Successfully dropped span with pointer 0x{span_ptr:x}",
+                    ),
+                );
+            }
+            Err(_) => {
+                logger_core::log_error(
+                    "ffi_otel",
+                    format!(
+                        "drop_otel_span: Panic occurred while dropping span pointer 0x{span_ptr:x} - likely invalid pointer",
+                    ),
+                );
+            }
+        }
     }
-    result
 }
 
-/// Convert raw C string to a rust string.
+/// Configuration for OpenTelemetry integration in the Node.js client.
 ///
-/// # Safety
+/// This struct allows you to configure how telemetry data (traces and metrics) is exported to an OpenTelemetry collector.
+/// - `traces`: Optional configuration for exporting trace data. If `None`, trace data will not be exported.
+/// - `metrics`: Optional configuration for exporting metrics data. If `None`, metrics data will not be exported.
+/// - `flush_interval_ms`: Optional interval in milliseconds between consecutive exports of telemetry data. If `None`, a default value will be used.
 ///
-/// * `ptr` must be able to be safely casted to a valid [`CStr`] via [`CStr::from_ptr`]. See the safety documentation of [`std::ffi::CStr::from_ptr`].
-unsafe fn ptr_to_str(ptr: *const c_char) -> String {
-    if !ptr.is_null() {
-        unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().into()
-    } else {
-        "".into()
-    }
+/// At least one of traces or metrics must be provided.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct OpenTelemetryConfig {
+    /// Configuration for exporting trace data. Only valid if has_traces is true.
+    pub traces: *const OpenTelemetryTracesConfig,
+    /// Configuration for exporting metrics data. Only valid if has_metrics is true.
+    pub metrics: *const OpenTelemetryMetricsConfig,
+    /// Whether flush interval is specified
+    pub has_flush_interval_ms: bool,
+    /// Interval in milliseconds between consecutive exports of telemetry data. Only valid if has_flush_interval_ms is true.
+    pub flush_interval_ms: i64,
+}
+
+/// Configuration for exporting OpenTelemetry traces.
+///
+/// - `endpoint`: The endpoint to which trace data will be exported. Expected format:
+///   - For gRPC: `grpc://host:port`
+///   - For HTTP: `http://host:port` or `https://host:port`
+///   - For file exporter: `file:///absolute/path/to/folder/file.json`
+/// - `has_sample_percentage`: Whether sample percentage is specified
+/// - `sample_percentage`: The percentage of requests to sample and create a span for, used to measure command duration. Only valid if has_sample_percentage is true.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct OpenTelemetryTracesConfig {
+    /// The endpoint to which trace data will be exported, `null` if not specified.
+    pub endpoint: *const c_char,
+    /// Whether sample percentage is specified
+    pub has_sample_percentage: bool,
+    /// The percentage of requests to sample and create a span for, used to measure command duration. Only valid if has_sample_percentage is true.
+    pub sample_percentage: u32,
 }
 
-/// Convert route configuration to a corresponding object.
+/// Configuration for exporting OpenTelemetry metrics.
 ///
-/// # Safety
-/// * `route_ptr` could be `null`, but if it is not `null`, it must be a valid pointer to a [`RouteInfo`] struct.
-/// * `slot_key` and `hostname` in dereferenced [`RouteInfo`] struct must contain valid string pointers when corresponding `route_type` is set.
-///   See description of [`RouteInfo`] and the safety documentation of [`ptr_to_str`].
-pub(crate) unsafe fn create_route(
-    route_ptr: *const RouteInfo,
-    cmd: Option<&Cmd>,
-) -> Option<RoutingInfo> {
-    if route_ptr.is_null() {
-        return None;
-    }
-    let route = unsafe { *route_ptr };
-    match route.route_type {
-        RouteType::Random => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
-        RouteType::AllNodes => Some(RoutingInfo::MultiNode((
-            MultipleNodeRoutingInfo::AllNodes,
-            cmd.and_then(|c| ResponsePolicy::for_command(&c.command().unwrap())),
-        ))),
-        RouteType::AllPrimaries => Some(RoutingInfo::MultiNode((
-            MultipleNodeRoutingInfo::AllMasters,
-            cmd.and_then(|c| ResponsePolicy::for_command(&c.command().unwrap())),
-        ))),
-        RouteType::SlotId => Some(RoutingInfo::SingleNode(
-            SingleNodeRoutingInfo::SpecificNode(Route::new(
-                route.slot_id as u16,
-                (&route.slot_type).into(),
-            )),
-        )),
-        RouteType::SlotKey => Some(RoutingInfo::SingleNode(
-            SingleNodeRoutingInfo::SpecificNode(Route::new(
-                redis::cluster_topology::get_slot(unsafe { ptr_to_str(route.slot_key) }.as_bytes()),
-                (&route.slot_type).into(),
-            )),
-        )),
-        RouteType::ByAddress => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
-            host: unsafe { ptr_to_str(route.hostname) },
-            port: route.port as u16,
-        })),
-    }
+/// - `endpoint`: The endpoint to which metrics data will be exported. Expected format:
+///   - For gRPC: `grpc://host:port`
+///   - For HTTP: `http://host:port` or `https://host:port`
+///   - For file exporter: `file:///absolute/path/to/folder/file.json`
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct OpenTelemetryMetricsConfig {
+    /// The endpoint to which metrics data will be exported, `null` if not specified.
+    pub endpoint: *const c_char,
 }
 
-/// Convert [`CmdInfo`] to a [`Cmd`].
+/// Initializes OpenTelemetry with the given configuration.
 ///
 /// # Safety
-/// * `cmd_ptr` must be able to be safely casted to a valid [`CmdInfo`]
-/// * `args` and `args_len` in a referred [`CmdInfo`] structure must not be `null`.
-/// * `data` in a referred [`CmdInfo`] structure must point to `arg_count` consecutive string pointers.
-/// * `args_len` in a referred [`CmdInfo`] structure must point to `arg_count` consecutive string lengths. See the safety documentation of [`convert_double_pointer_to_vec`].
-pub(crate) unsafe fn create_cmd(
-    ptr: *const CmdInfo,
-    compression_manager: Option<&std::sync::Arc<glide_core::compression::CompressionManager>>,
-) -> Result<Cmd, String> {
-    let info = unsafe { *ptr };
-    let arg_vec = unsafe {
-        convert_double_pointer_to_vec(
-            info.args as *const *const c_void,
-            info.arg_count as c_ulong,
-            info.args_len as *const c_ulong,
-        )
-    };
-
-    let Some(mut cmd) = info.request_type.get_command() else {
-        return Err("Couldn't fetch command type".into());
-    };
-
-    // Check if compression is enabled before converting args
-    let should_process_compression = compression_manager
-        .as_ref()
-        .map(|cm| cm.is_enabled())
-        .unwrap_or(false);
+/// * `open_telemetry_config` and its underlying traces and metrics pointers must be valid until the function returns.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn init_open_telemetry(
+    open_telemetry_config: *const OpenTelemetryConfig,
+) -> *const c_char {
+    // At least one of traces or metrics must be provided
+    if unsafe { (*open_telemetry_config).traces.is_null() }
+        && unsafe { (*open_telemetry_config).metrics.is_null() }
+    {
+        let error_msg =
+            "At least one of traces or metrics must be provided for OpenTelemetry configuration";
+        return CString::new(error_msg)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw();
+    }
 
-    if should_process_compression {
-        // Convert arg_vec to owned Vec<Vec<u8>> for compression processing
-        let mut owned_args: Vec<Vec<u8>> = arg_vec.iter().map(|&arg| arg.to_vec()).collect();
+    let mut config = GlideOpenTelemetryConfigBuilder::default();
 
-        // Apply compression to command arguments
-        if let Err(err) = glide_core::compression::process_command_args_for_compression(
-            &mut owned_args,
-            info.request_type,
-            compression_manager.map(|m| m.as_ref()),
-        ) {
-            return Err(format!("Compression failed: {}", err));
+    // Initialize open telemetry traces exporter
+    if !unsafe { (*open_telemetry_config).traces.is_null() } {
+        let endpoint = unsafe { CStr::from_ptr((*(*open_telemetry_config).traces).endpoint) }
+            .to_string_lossy()
+            .to_string();
+        match GlideOpenTelemetrySignalsExporter::from_str(&endpoint) {
+            Ok(exporter) => {
+                let sample_percentage =
+                    if unsafe { (*(*open_telemetry_config).traces).has_sample_percentage } {
+                        Some(unsafe { (*(*open_telemetry_config).traces).sample_percentage })
+                    } else {
+                        None
+                    };
+                config = config.with_trace_exporter(exporter, sample_percentage);
+            }
+            Err(e) => {
+                let error_msg = format!("Invalid traces exporter configuration: {e}");
+                return CString::new(error_msg)
+                    .unwrap_or_else(|_| {
+                        CString::new("Couldn't convert error message to C string").unwrap()
+                    })
+                    .into_raw();
+            }
         }
+    }
 
-        // Use the compressed arguments
-        for command_arg in &owned_args {
-            cmd.arg(command_arg);
+    // Initialize open telemetry metrics exporter
+    if !unsafe { (*open_telemetry_config).metrics.is_null() } {
+        let endpoint = unsafe { CStr::from_ptr((*(*open_telemetry_config).metrics).endpoint) }
+            .to_string_lossy()
+            .to_string();
+        match GlideOpenTelemetrySignalsExporter::from_str(&endpoint) {
+            Ok(exporter) => {
+                config = config.with_metrics_exporter(exporter);
+            }
+            Err(e) => {
+                let error_msg = format!("Invalid metrics exporter configuration: {e}");
+                return CString::new(error_msg)
+                    .unwrap_or_else(|_| {
+                        CString::new("Couldn't convert error message to C string").unwrap()
+                    })
+                    .into_raw();
+            }
         }
+    }
+
+    let flush_interval_ms = if unsafe { (*open_telemetry_config).has_flush_interval_ms } {
+        unsafe { (*open_telemetry_config).flush_interval_ms }
     } else {
-        // Use the original arguments
-        for command_arg in &arg_vec {
-            cmd.arg(command_arg);
+        DEFAULT_FLUSH_SIGNAL_INTERVAL_MS as i64
+    };
+
+    if flush_interval_ms <= 0 {
+        let error_msg = format!(
+            "InvalidInput: flushIntervalMs must be a positive integer (got: {flush_interval_ms})"
+        );
+        return CString::new(error_msg)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw();
+    }
+
+    config = config.with_flush_interval(std::time::Duration::from_millis(flush_interval_ms as u64));
+
+    // Initialize OpenTelemetry synchronously
+    match glide_core::client::get_or_init_runtime() {
+        Ok(glide_runtime) => {
+            match glide_runtime
+                .runtime
+                .block_on(async { GlideOpenTelemetry::initialise(config.build()) })
+            {
+                Ok(_) => std::ptr::null(), // Success
+                Err(e) => {
+                    let error_msg = format!("Failed to initialize OpenTelemetry: {e}");
+                    CString::new(error_msg)
+                        .unwrap_or_else(|_| {
+                            CString::new("Couldn't convert error message to C string").unwrap()
+                        })
+                        .into_raw()
+                }
+            }
         }
+        Err(e) => CString::new(e)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw(),
     }
+}
 
-    Ok(cmd)
+/// Frees a C string.
+///
+/// # Safety
+/// * `s` must be a valid pointer to a C string or `null`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn free_c_string(s: *mut c_char) {
+    unsafe {
+        if s.is_null() {
+            return;
+        }
+        drop(CString::from_raw(s));
+    };
 }
 
-/// Convert [`BatchInfo`] to a [`Pipeline`].
+/// This function converts a raw pointer to a GlideSpan into a safe Rust reference.
+/// It handles the unsafe pointer operations internally, incrementing the reference count
+/// to ensure the span remains valid while in use.
 ///
 /// # Safety
-/// * `ptr` must be able to be safely casted to a valid [`BatchInfo`].
-/// * `cmds` in a referred [`BatchInfo`] structure must not be `null`.
-/// * `cmds` in a referred [`BatchInfo`] structure must point to `cmd_count` consecutive [`CmdInfo`] pointers.
-///   They must be able to be safely casted to a valid to a slice of the corresponding type via [`from_raw_parts`]. See the safety documentation of [`from_raw_parts`].
-/// * Every pointer stored in `cmds` must not be `null` and must point to a valid [`CmdInfo`] structure.
-/// * All data in referred [`CmdInfo`] structure(s) should be valid. See the safety documentation of [`create_cmd`].
-pub(crate) unsafe fn create_pipeline(
-    ptr: *const BatchInfo,
-    compression_manager: Option<&std::sync::Arc<glide_core::compression::CompressionManager>>,
-) -> Result<Pipeline, String> {
-    let info = unsafe { *ptr };
-    let cmd_pointers = unsafe { from_raw_parts(info.cmds, info.cmd_count) };
-    let mut pipeline = Pipeline::with_capacity(info.cmd_count);
-    for (i, cmd_ptr) in cmd_pointers.iter().enumerate() {
-        match unsafe { create_cmd(*cmd_ptr, compression_manager) } {
-            Ok(cmd) => pipeline.add_command(cmd),
-            Err(err) => return Err(format!("Coudln't create {i:?}'th command: {err:?}")),
-        };
-    }
-    if info.is_atomic {
-        pipeline.atomic();
+///
+/// This function is marked as unsafe because it dereferences a raw pointer. The caller
+/// must ensure that:
+/// * The pointer is valid and points to a properly allocated GlideSpan
+/// * The pointer is properly aligned
+/// * The data pointed to is not modified while the returned reference is in use
+/// * The pointer is not used after the referenced data is dropped
+///
+/// # Arguments
+///
+/// * `command_span` - An optional raw pointer (as u64) to a GlideSpan
+///
+/// # Returns
+///
+/// * `Some(GlideSpan)` - A cloned GlideSpan if the pointer is valid
+/// * `None` - If the pointer is None
+unsafe fn get_unsafe_span_from_ptr(command_span: Option<u64>) -> Option<GlideSpan> {
+    command_span.map(|command_span| unsafe {
+        Arc::increment_strong_count(command_span as *const GlideSpan);
+        (*Arc::from_raw(command_span as *const GlideSpan)).clone()
+    })
+}
+
+/// Creates a child span for telemetry if telemetry is enabled
+fn create_child_span(span: Option<&GlideSpan>, name: &str) -> Result<GlideSpan, String> {
+    // Early return if no parent span is provided
+    let parent_span = span.ok_or_else(|| "No parent span provided".to_string())?;
+
+    match parent_span.add_span(name) {
+        Ok(child_span) => Ok(child_span),
+        Err(error_msg) => Err(format!(
+            "Opentelemetry failed to create child span with name `{name}`. Error: {error_msg:?}"
+        )),
     }
+}
 
-    Ok(pipeline)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    ERROR = 0,
+    WARN = 1,
+    INFO = 2,
+    DEBUG = 3,
+    TRACE = 4,
+    OFF = 5,
 }
 
-/// Convert [`BatchOptionsInfo`] to a tuple of corresponding values.
-///
-/// # Safety
-/// * `ptr` could be `null`, but if it is not `null`, it must be a valid pointer to a [`BatchOptionsInfo`] struct.
-/// * `route_info` in dereferenced [`BatchOptionsInfo`] struct must contain a [`RouteInfo`] pointer.
-///   See description of [`RouteInfo`] and the safety documentation of [`create_route`].
-pub(crate) unsafe fn get_pipeline_options(
-    ptr: *const BatchOptionsInfo,
-) -> (Option<RoutingInfo>, Option<u32>, PipelineRetryStrategy) {
-    if ptr.is_null() {
-        return (None, None, PipelineRetryStrategy::new(false, false));
+impl From<logger_core::Level> for Level {
+    fn from(level: logger_core::Level) -> Self {
+        match level {
+            logger_core::Level::Error => Level::ERROR,
+            logger_core::Level::Warn => Level::WARN,
+            logger_core::Level::Info => Level::INFO,
+            logger_core::Level::Debug => Level::DEBUG,
+            logger_core::Level::Trace => Level::TRACE,
+            logger_core::Level::Off => Level::OFF,
+        }
     }
-    let info = unsafe { *ptr };
-    let timeout = if info.has_timeout {
-        Some(info.timeout)
-    } else {
-        None
-    };
-    let route = unsafe { create_route(info.route_info, None) };
-
-    (
-        route,
-        timeout,
-        PipelineRetryStrategy::new(info.retry_server_error, info.retry_connection_error),
-    )
 }
 
-/// Helper function to extract and validate command name from RequestType.
-/// Returns the command name or None if validation fails.
-/// Falls back to "CustomCommand" for user-defined commands (e.g., EVAL, EVALSHA).
-fn extract_command_name(request_type: RequestType, context: &str) -> Option<String> {
-    // Validate request type and extract command
-    let cmd = match request_type.get_command() {
-        Some(cmd) => cmd,
-        None => {
-            logger_core::log_error(
-                "ffi_otel",
-                format!("{context}: RequestType has no command available"),
-            );
-            return None;
+impl From<Level> for logger_core::Level {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => logger_core::Level::Error,
+            Level::WARN => logger_core::Level::Warn,
+            Level::INFO => logger_core::Level::Info,
+            Level::DEBUG => logger_core::Level::Debug,
+            Level::TRACE => logger_core::Level::Trace,
+            Level::OFF => logger_core::Level::Off,
         }
-    };
-
-    // Extract command name, falling back to "CustomCommand" for user-defined commands
-    // (e.g. EVAL) where the Cmd struct has no pre-set command bytes.
-    let command_name = match cmd.command() {
-        Some(bytes) => match std::str::from_utf8(bytes.as_slice()) {
-            Ok(name) => name.to_owned(),
-            Err(e) => {
-                logger_core::log_error(
-                    "ffi_otel",
-                    format!("{context}: Command bytes are not valid UTF-8: {e}"),
-                );
-                return None;
-            }
-        },
-        None => "CustomCommand".to_owned(),
-    };
-
-    // Validate command name length (reasonable limit to prevent abuse)
-    if command_name.len() > 256 {
-        logger_core::log_error(
-            "ffi_otel",
-            format!(
-                "{context}: Command name too long ({} chars), max 256",
-                command_name.len()
-            ),
-        );
-        return None;
     }
-
-    Some(command_name)
 }
 
-/// Creates an OpenTelemetry span with the given name and returns a pointer to the span as u64.
+/// Logs a message using the logger backend.
+///
+/// # Parameters
+///
+/// * `level` - The severity level of the current message (e.g., Error, Warn, Info).
+/// * `identifier` - A pointer to a null-terminated C string identifying the source of the log message.
+/// * `message` - A pointer to a null-terminated C string containing the actual log message.
+///
+/// # Safety
+///
+///  The returned pointer must be freed using [`free_log_result`].
+///
+/// * `identifier` must be a valid, non-null pointer to a null-terminated UTF-8 encoded C string.
+/// * `message` must be a valid, non-null pointer to a null-terminated UTF-8 encoded C string.
+///
+/// # Note
+///
+/// The caller (Python Sync wrapper, Go wrapper, etc.) is responsible for filtering log messages according to the logger's current log level.
+/// This function will log any message it receives.
 #[unsafe(no_mangle)]
-pub extern "C" fn create_otel_span(request_type: RequestType) -> u64 {
-    let command_name = match extract_command_name(request_type, "create_otel_span") {
-        Some(name) => name,
-        None => return 0,
+pub unsafe extern "C" fn glide_log(
+    level: Level,
+    identifier: *const c_char,
+    message: *const c_char,
+) -> *mut LogResult {
+    let id_str = match unsafe { CStr::from_ptr(identifier).to_str() } {
+        Ok(s) => s,
+        Err(err) => {
+            let c_err = CString::new(format!("Log identifier contains invalid UTF-8: {err}"))
+                .unwrap_or_default()
+                .into_raw();
+            return Box::into_raw(Box::new(LogResult {
+                log_error: c_err,
+                level: Level::OFF, // Default value, should be ignored when there's an error
+            }));
+        }
     };
 
-    // Create span and convert to pointer
-    let span = GlideOpenTelemetry::new_span(&command_name);
-    let arc = Arc::new(span);
-    let ptr = Arc::into_raw(arc);
-    let span_ptr = ptr as u64;
-
-    logger_core::log_debug(
-        "ffi_otel",
-        format!(
-            "create_otel_span: Successfully created span '{command_name}' with pointer 0x{span_ptr:x}",
-        ),
-    );
-    span_ptr
-}
-
-/// Creates an OpenTelemetry span with a fixed name "batch" and returns a pointer to the span as u64.
-///
-#[unsafe(no_mangle)]
-pub extern "C" fn create_batch_otel_span() -> u64 {
-    let command_name = "Batch";
+    let msg_str = match unsafe { CStr::from_ptr(message).to_str() } {
+        Ok(s) => s,
+        Err(err) => {
+            let c_err = CString::new(format!("Log message contains invalid UTF-8: {err}"))
+                .unwrap_or_default()
+                .into_raw();
+            return Box::into_raw(Box::new(LogResult {
+                log_error: c_err,
+                level: Level::OFF, // Default value, should be ignored when there's an error
+            }));
+        }
+    };
 
-    // Create span and convert to pointer
-    let span = GlideOpenTelemetry::new_span(command_name);
-    let arc = Arc::new(span);
-    let ptr = Arc::into_raw(arc);
-    let span_ptr = ptr as u64;
+    logger_core::log(level.into(), id_str, msg_str);
 
-    logger_core::log_debug(
-        "ffi_otel",
-        format!(
-            "create_batch_otel_span: Successfully created batch span with pointer 0x{span_ptr:x}",
-        ),
-    );
-    span_ptr
+    Box::into_raw(Box::new(LogResult {
+        log_error: std::ptr::null_mut(),
+        level, // Level is not meaningful for log operations, but set a default
+    }))
 }
 
-/// Creates an OpenTelemetry batch span with a parent span and returns a pointer to the span as u64.
-/// This function creates a child span with the name "Batch" under the provided parent span.
-/// Returns 0 on failure.
+/// Initializes the logger with the provided log level and optional log file path.
+///
+/// Success is indicated by a `LogResult` with a null `log_error` field and the actual
+/// log level set in the `level` field. Failure is indicated by a `LogResult` with a non-null
+/// `log_error` field containing an error message, and the `level` field should be ignored.
 ///
 /// # Parameters
-/// * `parent_span_ptr`: A u64 pointer to the parent span created by create_otel_span, create_named_otel_span, or create_batch_otel_span
+///
+/// * `level` - A pointer to a `Level` enum value that sets the maximum log level. If null, a WARN level will be used.
+/// * `file_name` - A pointer to a null-terminated C string representing the desired log file path.
 ///
 /// # Returns
-/// * A u64 pointer to the created child batch span, or 0 if creation fails
+///
+/// A pointer to a `LogResult` struct containing either:
+/// - Success: `log_error` is null, `level` contains the actual log level that was set
+/// - Error: `log_error` contains the error message, `level` should be ignored
+///
 ///
 /// # Safety
-/// * `parent_span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`], [`create_named_otel_span`], or [`create_batch_otel_span`], or 0.
-/// * If `parent_span_ptr` is 0 or invalid, the function will create an independent batch span as fallback.
+///
+/// The returned pointer must be freed using [`free_log_result`].
+///
+/// * `level` may be null. If not null, it must point to a valid instance of the `Level` enum.
+/// * `file_name` may be null. If not null, it must point to a valid, null-terminated C string.
+///   If the string contains invalid UTF-8, an error will be returned instead of panicking.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn create_batch_otel_span_with_parent(parent_span_ptr: u64) -> u64 {
-    let command_name = "Batch";
-
-    // Handle parent span pointer validation with graceful fallback
-    if parent_span_ptr == 0 {
-        logger_core::log_warn(
-            "ffi_otel",
-            "create_batch_otel_span_with_parent: parent_span_ptr is null (0), creating independent batch span as fallback",
-        );
-        // Graceful fallback: create independent batch span
-        let span = GlideOpenTelemetry::new_span(command_name);
-        let arc = Arc::new(span);
-        let ptr = Arc::into_raw(arc);
-        let span_ptr = ptr as u64;
-        logger_core::log_debug(
-            "ffi_otel",
-            format!(
-                "create_batch_otel_span_with_parent: Created independent fallback batch span with pointer 0x{span_ptr:x}",
-            ),
-        );
-        return span_ptr;
-    }
-
-    // Convert parent pointer to GlideSpan and use existing add_span method
-    let span = match unsafe { GlideOpenTelemetry::span_from_pointer(parent_span_ptr) } {
-        Ok(parent_span) => {
-            // Use existing add_span method to create child batch span
-            match parent_span.add_span(command_name) {
-                Ok(child_span) => child_span,
-                Err(e) => {
-                    logger_core::log_warn(
-                        "ffi_otel",
-                        format!(
-                            "create_batch_otel_span_with_parent: Failed to create child batch span with parent 0x{parent_span_ptr:x}: {e}. Creating independent batch span as fallback.",
-                        ),
-                    );
-                    // Graceful fallback: create independent batch span
-                    GlideOpenTelemetry::new_span(command_name)
-                }
+pub unsafe extern "C" fn init(level: *const Level, file_name: *const c_char) -> *mut LogResult {
+    let level_option = if level.is_null() {
+        None
+    } else {
+        Some(unsafe { *level }.into())
+    };
+
+    let file_name_option = if file_name.is_null() {
+        None
+    } else {
+        match unsafe { CStr::from_ptr(file_name).to_str() } {
+            Ok(file_str) => Some(file_str),
+            Err(err) => {
+                let c_err = CString::new(format!("File name contains invalid UTF-8: {err}"))
+                    .unwrap_or_default()
+                    .into_raw();
+                return Box::into_raw(Box::new(LogResult {
+                    log_error: c_err,
+                    level: Level::OFF, // Default value, should be ignored when there's an error
+                }));
             }
         }
-        Err(e) => {
-            logger_core::log_warn(
-                "ffi_otel",
-                format!(
-                    "create_batch_otel_span_with_parent: Invalid parent span pointer 0x{parent_span_ptr:x}: {e}. Creating independent batch span as fallback.",
-                ),
-            );
-            // Graceful fallback: create independent batch span
-            GlideOpenTelemetry::new_span(command_name)
-        }
     };
 
-    // Convert span to pointer and return
-    let arc = Arc::new(span);
-    let ptr = Arc::into_raw(arc);
-    let span_ptr = ptr as u64;
+    let logger_level = logger_core::init(level_option, file_name_option);
 
-    logger_core::log_debug(
-        "ffi_otel",
-        format!(
-            "create_batch_otel_span_with_parent: Successfully created batch span with parent 0x{parent_span_ptr:x}, child pointer 0x{span_ptr:x}",
-        ),
-    );
-    span_ptr
+    Box::into_raw(Box::new(LogResult {
+        log_error: std::ptr::null_mut(),
+        level: logger_level.into(),
+    }))
 }
 
-/// Creates an OpenTelemetry span with a custom name and returns a pointer to the span as u64.
-/// This function is intended for creating parent spans that can be used with create_otel_span_with_parent.
-/// Returns 0 on failure.
+/// Frees a log result.
+///
+/// This function deallocates a `LogResult` struct and any error message it contains.
 ///
 /// # Parameters
-/// * `span_name`: A null-terminated C string containing the name for the span
 ///
-/// # Returns
-/// * A u64 pointer to the created span, or 0 if creation fails
+/// * `result_ptr` - A pointer to the `LogResult` to free, or null.
 ///
 /// # Safety
-/// * `span_name` must be a valid pointer to a null-terminated C string
-/// * The string must be valid UTF-8
-/// * The caller is responsible for eventually calling drop_otel_span with the returned pointer
+///
+/// * `result_ptr` must be a valid pointer to a `LogResult` returned by [`glide_log`] or [`init`], or null.
+/// * This function must be called exactly once for each `LogResult`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn create_named_otel_span(span_name: *const c_char) -> u64 {
-    // Validate input pointer
-    if span_name.is_null() {
-        logger_core::log_error(
-            "ffi_otel",
-            "create_named_otel_span: span_name pointer is null",
-        );
-        return 0;
+pub unsafe extern "C" fn free_log_result(result_ptr: *mut LogResult) {
+    if result_ptr.is_null() {
+        return;
     }
-
-    // Convert C string to Rust string with safe error handling
-    let c_str = unsafe { CStr::from_ptr(span_name) };
-
-    let name_str = match c_str.to_str() {
-        Ok(s) => s,
-        Err(e) => {
-            logger_core::log_error(
-                "ffi_otel",
-                format!("create_named_otel_span: span_name is not valid UTF-8: {e}",),
-            );
-            return 0;
+    unsafe {
+        let result = Box::from_raw(result_ptr);
+        if !result.log_error.is_null() {
+            free_c_string(result.log_error);
         }
-    };
-
-    // Validate string length (reasonable limit to prevent abuse)
-    // Note: Empty names are allowed as per test expectations
-    if name_str.len() > 256 {
-        logger_core::log_error(
-            "ffi_otel",
-            format!(
-                "create_named_otel_span: span_name too long ({} chars), max 256",
-                name_str.len()
-            ),
-        );
-        return 0;
-    }
-
-    // Validate string content (basic sanity check for control characters)
-    if name_str
-        .chars()
-        .any(|c| c.is_control() && c != '\t' && c != '\n' && c != '\r')
-    {
-        logger_core::log_error(
-            "ffi_otel",
-            "create_named_otel_span: span_name contains invalid control characters",
-        );
-        return 0;
     }
+}
 
-    // Create the named span using existing new_span method
-    let span = GlideOpenTelemetry::new_span(name_str);
-    let arc = Arc::new(span);
-    let ptr = Arc::into_raw(arc);
-    let span_ptr = ptr as u64;
+/// Statistics structure containing telemetry data.
+///
+/// This struct provides compression and connection statistics for the client.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Statistics {
+    /// Total number of connections opened to Valkey
+    pub total_connections: c_ulong,
+    /// Total number of GLIDE clients
+    pub total_clients: c_ulong,
+    /// Total number of values compressed
+    pub total_values_compressed: c_ulong,
+    /// Total number of values decompressed
+    pub total_values_decompressed: c_ulong,
+    /// Total original bytes before compression
+    pub total_original_bytes: c_ulong,
+    /// Total bytes after compression
+    pub total_bytes_compressed: c_ulong,
+    /// Total bytes after decompression
+    pub total_bytes_decompressed: c_ulong,
+    /// Number of times compression was skipped
+    pub compression_skipped_count: c_ulong,
+    /// Number of times subscriptions were out of sync during reconciliation
+    pub subscription_out_of_sync_count: c_ulong,
+    /// Timestamp of last successful subscription sync (milliseconds since epoch)
+    pub subscription_last_sync_timestamp: c_ulong,
+    /// Number of replica reads that failed on a stale replica and were retried on the primary
+    pub primary_fallback_reads_count: c_ulong,
+    /// Number of times a node circuit breaker was opened.
+    pub circuit_breaker_opened_count: c_ulong,
+    /// Number of requests that failed fast because the circuit breaker of their node was open
+    pub circuit_breaker_rejected_count: c_ulong,
+    /// Number of pubsub messages dropped because the pubsub buffer of their client was full
+    pub pubsub_messages_dropped_count: c_ulong,
+    /// Number of connections recycled because they reached the maximum connection age
+    pub connections_recycled_max_age_count: c_ulong,
+    /// Number of connections recycled because they weren't used within the idle timeout
+    pub connections_recycled_idle_count: c_ulong,
+}
 
-    logger_core::log_debug(
-        "ffi_otel",
-        format!(
-            "create_named_otel_span: Successfully created named span '{name_str}' with pointer 0x{span_ptr:x}",
-        ),
-    );
-    span_ptr
+/// Get compression and connection statistics.
+///
+/// Returns a `Statistics` struct containing current telemetry data.
+/// This function is thread-safe and can be called at any time.
+///
+/// # Returns
+///
+/// A `Statistics` struct with the current statistics values.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_statistics() -> Statistics {
+    Statistics {
+        total_connections: Telemetry::total_connections() as c_ulong,
+        total_clients: Telemetry::total_clients() as c_ulong,
+        total_values_compressed: Telemetry::total_values_compressed() as c_ulong,
+        total_values_decompressed: Telemetry::total_values_decompressed() as c_ulong,
+        total_original_bytes: Telemetry::total_original_bytes() as c_ulong,
+        total_bytes_compressed: Telemetry::total_bytes_compressed() as c_ulong,
+        total_bytes_decompressed: Telemetry::total_bytes_decompressed() as c_ulong,
+        compression_skipped_count: Telemetry::compression_skipped_count() as c_ulong,
+        subscription_out_of_sync_count: Telemetry::subscription_out_of_sync_count() as c_ulong,
+        subscription_last_sync_timestamp: Telemetry::subscription_last_sync_timestamp() as c_ulong,
+        primary_fallback_reads_count: Telemetry::primary_fallback_reads_count() as c_ulong,
+        circuit_breaker_opened_count: Telemetry::circuit_breaker_opened_count() as c_ulong,
+        circuit_breaker_rejected_count: Telemetry::circuit_breaker_rejected_count() as c_ulong,
+        pubsub_messages_dropped_count: Telemetry::pubsub_messages_dropped_count() as c_ulong,
+        connections_recycled_max_age_count: Telemetry::connections_recycled_max_age_count()
+            as c_ulong,
+        connections_recycled_idle_count: Telemetry::connections_recycled_idle_count() as c_ulong,
+    }
 }
 
-/// Creates an OpenTelemetry span with the given request type as a child of the provided parent span.
-/// Returns a pointer to the child span as u64, or 0 on failure.
+/// Get the telemetry statistics shared by all clients, together with the connection count of the given client.
+///
+/// Unlike [`get_statistics`], the statistics are returned as a map, so new counters don't change the layout of a
+/// struct, and the connection counts of each client can be exported alongside the totals.
 ///
 /// # Parameters
-/// * `request_type`: The type of request to create a span for
-/// * `parent_span_ptr`: A pointer to the parent span (created by create_otel_span or create_named_otel_span)
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
 ///
 /// # Returns
-/// * A u64 pointer to the created child span, or 0 if creation fails
+///
+/// * A pointer to a [`CommandResult`] containing a map of the counters of [`Statistics`] by name, and
+///   `client_connections`, the number of connections held by the client.
 ///
 /// # Safety
-/// * `parent_span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`], [`create_named_otel_span`], or [`create_batch_otel_span`], or 0.
-/// * If `parent_span_ptr` is 0 or invalid, the function will create an independent span as fallback.
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn create_otel_span_with_parent(
-    request_type: RequestType,
-    parent_span_ptr: u64,
-) -> u64 {
-    let command_name = match extract_command_name(request_type, "create_otel_span_with_parent") {
-        Some(name) => name,
-        None => return 0,
-    };
-
-    // Handle parent span pointer validation with graceful fallback
-    if parent_span_ptr == 0 {
-        logger_core::log_warn(
-            "ffi_otel",
-            "create_otel_span_with_parent: parent_span_ptr is null (0), creating independent span as fallback",
-        );
-        // Graceful fallback: create independent span
-        let span = GlideOpenTelemetry::new_span(&command_name);
-        let arc = Arc::new(span);
-        let ptr = Arc::into_raw(arc);
-        let span_ptr = ptr as u64;
-        logger_core::log_debug(
-            "ffi_otel",
-            format!(
-                "create_otel_span_with_parent: Created independent fallback span '{command_name}' with pointer 0x{span_ptr:x}",
-            ),
-        );
-        return span_ptr;
-    }
-
-    // Convert parent pointer to GlideSpan and use existing add_span method
-    let span = match unsafe { GlideOpenTelemetry::span_from_pointer(parent_span_ptr) } {
-        Ok(parent_span) => {
-            // Use existing add_span method to create child span
-            match parent_span.add_span(&command_name) {
-                Ok(child_span) => child_span,
-                Err(e) => {
-                    logger_core::log_warn(
-                        "ffi_otel",
-                        format!(
-                            "create_otel_span_with_parent: Failed to create child span '{command_name}' with parent 0x{parent_span_ptr:x}: {e}. Creating independent span as fallback.",
-                        ),
-                    );
-                    // Graceful fallback: create independent span
-                    GlideOpenTelemetry::new_span(&command_name)
-                }
-            }
-        }
-        Err(e) => {
-            logger_core::log_warn(
-                "ffi_otel",
-                format!(
-                    "create_otel_span_with_parent: Invalid parent span pointer 0x{parent_span_ptr:x}: {e}. Creating independent span as fallback.",
-                ),
-            );
-            // Graceful fallback: create independent span
-            GlideOpenTelemetry::new_span(&command_name)
-        }
+pub unsafe extern "C-unwind" fn get_statistics_map(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
 
-    // Convert span to pointer and return
-    let arc = Arc::new(span);
-    let ptr = Arc::into_raw(arc);
-    let span_ptr = ptr as u64;
-
-    logger_core::log_debug(
-        "ffi_otel",
-        format!(
-            "create_otel_span_with_parent: Successfully created span '{command_name}' with parent 0x{parent_span_ptr:x}, child pointer 0x{span_ptr:x}",
-        ),
-    );
-    span_ptr
+    let client = client_adapter.core.client();
+    client_adapter.execute_request(request_id, async move { client.statistics().await })
 }
 
-/// Drops an OpenTelemetry span given its pointer as u64.
+/// Compression statistics of a single client.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct ClientCompressionStatistics {
+    /// Number of values compressed by the client
+    pub values_compressed: c_ulong,
+    /// Number of values decompressed by the client
+    pub values_decompressed: c_ulong,
+    /// Total size of the compressed values before compression
+    pub original_bytes: c_ulong,
+    /// Total size of the compressed values after compression
+    pub compressed_bytes: c_ulong,
+    /// Number of bytes saved by compression
+    pub bytes_saved: c_ulong,
+}
+
+/// Get the compression statistics of a client.
+///
+/// Unlike the compression counters of [`get_statistics`], which are shared by all clients, these only cover the
+/// values compressed and decompressed by the given client.
+///
+/// # Returns
+///
+/// A `ClientCompressionStatistics` struct. All values are zero if compression isn't enabled for the client.
 ///
 /// # Safety
-/// * `span_ptr` must be a valid pointer to a [`Arc<GlideSpan>`] span created by [`create_otel_span`] or `0`.
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn drop_otel_span(span_ptr: u64) {
-    // Validate span pointer
-    if span_ptr == 0 {
-        logger_core::log_debug("ffi_otel", "drop_otel_span: Ignoring null span pointer (0)");
-        return;
-    }
-
-    // Validate pointer alignment and bounds (basic safety checks)
-    if !span_ptr.is_multiple_of(8) {
-        logger_core::log_error(
-            "ffi_otel",
-            format!("drop_otel_span: Invalid span pointer - misaligned: 0x{span_ptr:x}",),
-        );
-        return;
-    }
+pub unsafe extern "C" fn get_client_compression_statistics(
+    client_adapter_ptr: *const c_void,
+) -> ClientCompressionStatistics {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    client_adapter
+        .core
+        .client()
+        .compression_manager()
+        .map(|manager| {
+            let statistics = manager.statistics();
+            ClientCompressionStatistics {
+                values_compressed: statistics.values_compressed as c_ulong,
+                values_decompressed: statistics.values_decompressed as c_ulong,
+                original_bytes: statistics.original_bytes as c_ulong,
+                compressed_bytes: statistics.compressed_bytes as c_ulong,
+                bytes_saved: statistics.bytes_saved() as c_ulong,
+            }
+        })
+        .unwrap_or_default()
+}
 
-    // Check for obviously invalid pointer values
-    const MIN_VALID_ADDRESS: u64 = 0x1000; // 4KB, below this is likely invalid
-    const MAX_VALID_ADDRESS: u64 = 0x7FFF_FFFF_FFFF_FFF8; // Max user space on most 64-bit systems
+/// Latency percentiles of a single command type, in microseconds.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CommandLatencyPercentiles {
+    /// Number of latencies recorded since collection started or the histograms were last reset
+    pub count: c_ulong,
+    /// Median latency
+    pub p50_us: c_ulong,
+    /// 95th percentile latency
+    pub p95_us: c_ulong,
+    /// 99th percentile latency
+    pub p99_us: c_ulong,
+}
 
-    if span_ptr < MIN_VALID_ADDRESS {
-        logger_core::log_error(
-            "ffi_otel",
-            format!("drop_otel_span: Invalid span pointer - address too low: 0x{span_ptr:x}",),
-        );
-        return;
-    }
+/// Enables or disables the collection of per-command latency histograms.
+///
+/// Collection is disabled by default. Histograms that were already collected are kept when it's disabled.
+#[unsafe(no_mangle)]
+pub extern "C" fn set_command_latency_histogram_enabled(enabled: bool) {
+    glide_core::command_latency::set_enabled(enabled);
+}
 
-    if span_ptr > MAX_VALID_ADDRESS {
-        logger_core::log_error(
-            "ffi_otel",
-            format!("drop_otel_span: Invalid span pointer - address too high: 0x{span_ptr:x}",),
-        );
-        return;
+/// Get the latency percentiles of a command type.
+///
+/// # Returns
+///
+/// A `CommandLatencyPercentiles` struct with the percentiles of the commands of the given type.
+/// All values are zero if no command of this type was recorded.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_command_latency_percentiles(
+    request_type: RequestType,
+) -> CommandLatencyPercentiles {
+    let percentiles = glide_core::command_latency::percentiles(request_type);
+    CommandLatencyPercentiles {
+        count: percentiles.count as c_ulong,
+        p50_us: percentiles.p50_us as c_ulong,
+        p95_us: percentiles.p95_us as c_ulong,
+        p99_us: percentiles.p99_us as c_ulong,
     }
+}
 
-    // Attempt to safely drop the span
-    unsafe {
-        // Use std::panic::catch_unwind to handle potential panics during Arc::from_raw
-        let result = std::panic::catch_unwind(|| {
-            Arc::from_raw(span_ptr as *const GlideSpan);
-        });
+/// Clears the latency histograms of all command types.
+#[unsafe(no_mangle)]
+pub extern "C" fn reset_command_latency_histograms() {
+    glide_core::command_latency::reset();
+}
 
-        match result {
-            Ok(_) => {
-                logger_core::log_debug(
-                    "ffi_otel",
-                    format!(
-                        "drop_otel_span: Successfully dropped span with pointer 0x{span_ptr:x}",
-                    ),
-                );
-            }
-            Err(_) => {
-                logger_core::log_error(
-                    "ffi_otel",
-                    format!(
-                        "drop_otel_span: Panic occurred while dropping span pointer 0x{span_ptr:x} - likely invalid pointer",
-                    ),
-                );
-            }
-        }
-    }
+/// Invocation statistics of a script invoked with [`invoke_script_inline`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScriptInvocationStatistics {
+    /// Number of invocations since the statistics were last reset
+    pub invocations: c_ulong,
+    /// Number of invocations in which the script wasn't cached on the server, and was sent with `EVAL`
+    pub eval_fallbacks: c_ulong,
+    /// Number of invocations that returned an error
+    pub errors: c_ulong,
+    /// Total latency of the invocations
+    pub total_latency_us: c_ulong,
+    /// Highest latency of an invocation
+    pub max_latency_us: c_ulong,
 }
 
-/// Configuration for OpenTelemetry integration in the Node.js client.
+/// Get the invocation statistics of a script.
 ///
-/// This struct allows you to configure how telemetry data (traces and metrics) is exported to an OpenTelemetry collector.
-/// - `traces`: Optional configuration for exporting trace data. If `None`, trace data will not be exported.
-/// - `metrics`: Optional configuration for exporting metrics data. If `None`, metrics data will not be exported.
-/// - `flush_interval_ms`: Optional interval in milliseconds between consecutive exports of telemetry data. If `None`, a default value will be used.
+/// # Returns
 ///
-/// At least one of traces or metrics must be provided.
-#[repr(C)]
-#[derive(Clone, Debug)]
-pub struct OpenTelemetryConfig {
-    /// Configuration for exporting trace data. Only valid if has_traces is true.
-    pub traces: *const OpenTelemetryTracesConfig,
-    /// Configuration for exporting metrics data. Only valid if has_metrics is true.
-    pub metrics: *const OpenTelemetryMetricsConfig,
-    /// Whether flush interval is specified
-    pub has_flush_interval_ms: bool,
-    /// Interval in milliseconds between consecutive exports of telemetry data. Only valid if has_flush_interval_ms is true.
-    pub flush_interval_ms: i64,
+/// A `ScriptInvocationStatistics` struct with the statistics of the script with the given SHA1 hash.
+/// All values are zero if the script wasn't invoked with [`invoke_script_inline`].
+///
+/// # Safety
+///
+/// * `hash` must be a valid null-terminated C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_script_statistics(hash: *const c_char) -> ScriptInvocationStatistics {
+    assert!(!hash.is_null());
+    let Ok(hash) = (unsafe { CStr::from_ptr(hash) }).to_str() else {
+        return ScriptInvocationStatistics::default();
+    };
+    scripts_container::script_statistics(hash)
+        .map(|statistics| ScriptInvocationStatistics {
+            invocations: statistics.invocations as c_ulong,
+            eval_fallbacks: statistics.eval_fallbacks as c_ulong,
+            errors: statistics.errors as c_ulong,
+            total_latency_us: statistics.total_latency.as_micros() as c_ulong,
+            max_latency_us: statistics.max_latency.as_micros() as c_ulong,
+        })
+        .unwrap_or_default()
 }
 
-/// Configuration for exporting OpenTelemetry traces.
-///
-/// - `endpoint`: The endpoint to which trace data will be exported. Expected format:
-///   - For gRPC: `grpc://host:port`
-///   - For HTTP: `http://host:port` or `https://host:port`
-///   - For file exporter: `file:///absolute/path/to/folder/file.json`
-/// - `has_sample_percentage`: Whether sample percentage is specified
-/// - `sample_percentage`: The percentage of requests to sample and create a span for, used to measure command duration. Only valid if has_sample_percentage is true.
-#[repr(C)]
-#[derive(Clone, Debug)]
-pub struct OpenTelemetryTracesConfig {
-    /// The endpoint to which trace data will be exported, `null` if not specified.
-    pub endpoint: *const c_char,
-    /// Whether sample percentage is specified
-    pub has_sample_percentage: bool,
-    /// The percentage of requests to sample and create a span for, used to measure command duration. Only valid if has_sample_percentage is true.
-    pub sample_percentage: u32,
+/// Clears the invocation statistics of all scripts.
+#[unsafe(no_mangle)]
+pub extern "C" fn reset_script_statistics() {
+    scripts_container::reset_script_statistics();
 }
 
-/// Configuration for exporting OpenTelemetry metrics.
-///
-/// - `endpoint`: The endpoint to which metrics data will be exported. Expected format:
-///   - For gRPC: `grpc://host:port`
-///   - For HTTP: `http://host:port` or `https://host:port`
-///   - For file exporter: `file:///absolute/path/to/folder/file.json`
+/// Statistics of the commands coalesced into pipelines by a client.
 #[repr(C)]
-#[derive(Clone, Debug)]
-pub struct OpenTelemetryMetricsConfig {
-    /// The endpoint to which metrics data will be exported, `null` if not specified.
-    pub endpoint: *const c_char,
+#[derive(Debug, Default)]
+pub struct CommandCoalescingStatistics {
+    /// Number of pipelines sent by the coalescer
+    pub batches_count: c_ulong,
+    /// Number of commands sent in these pipelines
+    pub commands_count: c_ulong,
+    /// Number of commands in the largest pipeline
+    pub max_batch_size: c_ulong,
 }
 
-/// Initializes OpenTelemetry with the given configuration.
+/// Enables or disables the coalescing of the commands of an async client.
+///
+/// When enabled, single-slot commands sent with [`command`] without an explicit route, that arrive within
+/// `window_us` microseconds of each other, are sent to the server as a single pipeline per node.
+/// This reduces the number of socket writes under high concurrency, at the cost of up to `window_us` added latency.
+/// The pipelines are sent one after the other, in the order of the commands. Commands that need the handling the client
+/// applies to each command on its own, such as reads retried on the primary or read-your-writes sessions, are sent on
+/// their own.
+/// Enabling coalescing again replaces the previous configuration and resets the statistics.
+/// Commands of sync clients are never coalesced.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `enabled`: Whether commands should be coalesced.
+/// * `window_us`: How long to wait for more commands after the first command of a pipeline, in microseconds.
+///   Pass 0 to use the default of 100 microseconds.
+/// * `max_batch_size`: The maximal number of commands in a pipeline. Pass 0 to use the default of 64 commands.
 ///
 /// # Safety
-/// * `open_telemetry_config` and its underlying traces and metrics pointers must be valid until the function returns.
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn init_open_telemetry(
-    open_telemetry_config: *const OpenTelemetryConfig,
-) -> *const c_char {
-    // At least one of traces or metrics must be provided
-    if unsafe { (*open_telemetry_config).traces.is_null() }
-        && unsafe { (*open_telemetry_config).metrics.is_null() }
-    {
-        let error_msg =
-            "At least one of traces or metrics must be provided for OpenTelemetry configuration";
-        return CString::new(error_msg)
-            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
-            .into_raw();
+pub unsafe extern "C" fn set_command_coalescing(
+    client_adapter_ptr: *const c_void,
+    enabled: bool,
+    window_us: u64,
+    max_batch_size: usize,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let coalescer = enabled.then(|| {
+        let config = CoalescingConfig {
+            window: match window_us {
+                0 => DEFAULT_COALESCING_WINDOW,
+                window_us => std::time::Duration::from_micros(window_us),
+            },
+            max_batch_size: match max_batch_size {
+                0 => DEFAULT_COALESCING_MAX_BATCH_SIZE,
+                max_batch_size => max_batch_size,
+            },
+        };
+        let client = client_adapter.core.client();
+        // The coalescer spawns its background task, so it must be created inside the client's runtime.
+        Arc::new(
+            client_adapter
+                .runtime
+                .block_on(async move { CommandCoalescer::new(client, config) }),
+        )
+    });
+    if let Ok(mut guard) = client_adapter.core.coalescer.write() {
+        *guard = coalescer;
     }
+}
 
-    let mut config = GlideOpenTelemetryConfigBuilder::default();
-
-    // Initialize open telemetry traces exporter
-    if !unsafe { (*open_telemetry_config).traces.is_null() } {
-        let endpoint = unsafe { CStr::from_ptr((*(*open_telemetry_config).traces).endpoint) }
-            .to_string_lossy()
-            .to_string();
-        match GlideOpenTelemetrySignalsExporter::from_str(&endpoint) {
-            Ok(exporter) => {
-                let sample_percentage =
-                    if unsafe { (*(*open_telemetry_config).traces).has_sample_percentage } {
-                        Some(unsafe { (*(*open_telemetry_config).traces).sample_percentage })
-                    } else {
-                        None
-                    };
-                config = config.with_trace_exporter(exporter, sample_percentage);
-            }
-            Err(e) => {
-                let error_msg = format!("Invalid traces exporter configuration: {e}");
-                return CString::new(error_msg)
-                    .unwrap_or_else(|_| {
-                        CString::new("Couldn't convert error message to C string").unwrap()
-                    })
-                    .into_raw();
-            }
-        }
-    }
+/// Get the statistics of the commands coalesced by a client since coalescing was last enabled.
+///
+/// # Returns
+///
+/// A `CommandCoalescingStatistics` struct. All values are zero if coalescing isn't enabled.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_command_coalescing_statistics(
+    client_adapter_ptr: *const c_void,
+) -> CommandCoalescingStatistics {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let statistics = match client_adapter.core.coalescer.read() {
+        Ok(guard) => guard.as_ref().map(|coalescer| coalescer.statistics()),
+        Err(_) => None,
+    };
+    statistics
+        .map(|statistics| CommandCoalescingStatistics {
+            batches_count: statistics.batches_count as c_ulong,
+            commands_count: statistics.commands_count as c_ulong,
+            max_batch_size: statistics.max_batch_size as c_ulong,
+        })
+        .unwrap_or_default()
+}
 
-    // Initialize open telemetry metrics exporter
-    if !unsafe { (*open_telemetry_config).metrics.is_null() } {
-        let endpoint = unsafe { CStr::from_ptr((*(*open_telemetry_config).metrics).endpoint) }
-            .to_string_lossy()
-            .to_string();
-        match GlideOpenTelemetrySignalsExporter::from_str(&endpoint) {
-            Ok(exporter) => {
-                config = config.with_metrics_exporter(exporter);
-            }
-            Err(e) => {
-                let error_msg = format!("Invalid metrics exporter configuration: {e}");
-                return CString::new(error_msg)
-                    .unwrap_or_else(|_| {
-                        CString::new("Couldn't convert error message to C string").unwrap()
-                    })
-                    .into_raw();
-            }
-        }
-    }
+/// Statistics of the requests deduplicated by a client.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct RequestDeduplicationStatistics {
+    /// Number of requests that could be deduplicated
+    pub requests_count: c_ulong,
+    /// Number of requests that were answered by the round trip of an identical in-flight request
+    pub hits_count: c_ulong,
+}
 
-    let flush_interval_ms = if unsafe { (*open_telemetry_config).has_flush_interval_ms } {
-        unsafe { (*open_telemetry_config).flush_interval_ms }
-    } else {
-        DEFAULT_FLUSH_SIGNAL_INTERVAL_MS as i64
+/// Enables or disables the deduplication of concurrent identical read requests of a client.
+///
+/// When enabled, a `GET` or `HGETALL` sent with [`command`] without an explicit route, while an identical command
+/// is in flight, isn't sent to the server, and receives the response of the in-flight command instead.
+/// This protects the server from a thundering herd of reads of the same key, such as after a cache entry expired.
+/// Enabling deduplication again resets the statistics.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `enabled`: Whether requests should be deduplicated.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_request_deduplication(
+    client_adapter_ptr: *const c_void,
+    enabled: bool,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
-
-    if flush_interval_ms <= 0 {
-        let error_msg = format!(
-            "InvalidInput: flushIntervalMs must be a positive integer (got: {flush_interval_ms})"
-        );
-        return CString::new(error_msg)
-            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
-            .into_raw();
+    if let Ok(mut guard) = client_adapter.core.deduplicator.write() {
+        *guard = enabled.then(|| Arc::new(RequestDeduplicator::new()));
     }
+}
 
-    config = config.with_flush_interval(std::time::Duration::from_millis(flush_interval_ms as u64));
-
-    // Initialize OpenTelemetry synchronously
-    match glide_core::client::get_or_init_runtime() {
-        Ok(glide_runtime) => {
-            match glide_runtime
-                .runtime
-                .block_on(async { GlideOpenTelemetry::initialise(config.build()) })
-            {
-                Ok(_) => std::ptr::null(), // Success
-                Err(e) => {
-                    let error_msg = format!("Failed to initialize OpenTelemetry: {e}");
-                    CString::new(error_msg)
-                        .unwrap_or_else(|_| {
-                            CString::new("Couldn't convert error message to C string").unwrap()
-                        })
-                        .into_raw()
-                }
-            }
-        }
-        Err(e) => CString::new(e)
+/// Replaces the address translation of a cluster client, which maps the `host:port` addresses that the nodes
+/// announce to the `host:port` addresses the client dials instead, for nodes that aren't reachable at their announced
+/// addresses, such as behind NAT or inside containers. The translation can also be set at creation, with the
+/// `address_translation` field of the `ConnectionRequest`. It applies to the node connections made afterwards, such as
+/// after a reconnection or a topology change, and doesn't affect the existing connections.
+///
+/// # Returns
+///
+/// `null` on success, or an error message if an address isn't a `host:port` address, in which case the translation is
+/// unchanged. The error message must be freed with [`free_c_string`].
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `announced_addresses`, `announced_addresses_len`, `reachable_addresses` and `reachable_addresses_len` must
+///   either be null with an `entry_count` of 0, or point to arrays of `entry_count` elements, each argument pointing
+///   to as many bytes as its length.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_address_translation(
+    client_adapter_ptr: *const c_void,
+    entry_count: c_ulong,
+    announced_addresses: *const usize,
+    announced_addresses_len: *const c_ulong,
+    reachable_addresses: *const usize,
+    reachable_addresses_len: *const c_ulong,
+) -> *const c_char {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let announced =
+        unsafe { owned_byte_arrays(entry_count, announced_addresses, announced_addresses_len) };
+    let reachable =
+        unsafe { owned_byte_arrays(entry_count, reachable_addresses, reachable_addresses_len) };
+    let table = announced
+        .into_iter()
+        .zip(reachable)
+        .map(|(announced, reachable)| {
+            (
+                String::from_utf8_lossy(&announced).into_owned(),
+                String::from_utf8_lossy(&reachable).into_owned(),
+            )
+        })
+        .collect();
+    match client_adapter.core.client().set_address_translation(table) {
+        Ok(()) => std::ptr::null(),
+        Err(err) => CString::new(error_message(&err))
             .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
             .into_raw(),
     }
 }
 
-/// Frees a C string.
+/// Sets the callback that receives the details of the errors of an async client, such as the server error code and
+/// the node the error originated from. The callback is called right before the failure callback, with the same
+/// `index_ptr`. Synchronous clients return the details in the [`CommandError`] instead.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `error_details_callback`: The callback that receives the error details, or `None` to stop reporting them.
 ///
 /// # Safety
-/// * `s` must be a valid pointer to a C string or `null`.
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `error_details_callback` must be a valid function pointer that lives while the client is open/active.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn free_c_string(s: *mut c_char) {
-    unsafe {
-        if s.is_null() {
-            return;
-        }
-        drop(CString::from_raw(s));
+pub unsafe extern "C" fn set_error_details_callback(
+    client_adapter_ptr: *const c_void,
+    error_details_callback: Option<ErrorDetailsCallback>,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
+    if let Ok(mut guard) = client_adapter.core.error_details_callback.write() {
+        *guard = error_details_callback;
+    }
 }
 
-/// This function converts a raw pointer to a GlideSpan into a safe Rust reference.
-/// It handles the unsafe pointer operations internally, incrementing the reference count
-/// to ensure the span remains valid while in use.
+/// Sets the callback that receives the metadata of the successful commands of an async client sent with [`command`]:
+/// the number of attempts, the node that served the command, how long it waited before it was sent, and the time the
+/// server reports it spent on it. The callback is called right before the success callback, with the same
+/// `index_ptr`. Tracing the commands has a small cost, so it's only done while a callback is set.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `command_metadata_callback`: The callback that receives the metadata, or `None` to stop reporting it.
 ///
 /// # Safety
 ///
-/// This function is marked as unsafe because it dereferences a raw pointer. The caller
-/// must ensure that:
-/// * The pointer is valid and points to a properly allocated GlideSpan
-/// * The pointer is properly aligned
-/// * The data pointed to is not modified while the returned reference is in use
-/// * The pointer is not used after the referenced data is dropped
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `command_metadata_callback` must be a valid function pointer that lives while the client is open/active.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_command_metadata_callback(
+    client_adapter_ptr: *const c_void,
+    command_metadata_callback: Option<CommandMetadataCallback>,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    if let Ok(mut guard) = client_adapter.core.command_metadata_callback.write() {
+        *guard = command_metadata_callback;
+    }
+}
+
+/// Sets the value codec of a client, which encodes the values of commands before they are sent and decodes the values
+/// in their responses, such as for transparent field-level encryption or custom serialization.
 ///
-/// # Arguments
+/// The values written by `SET`, `SETNX`, `SETEX`, `PSETEX`, `GETSET`, `MSET`, `MSETNX`, `HSET`, `HSETNX`, `HMSET` and
+/// `HSETEX` are passed to `encode`, and the values returned by `GET`, `GETDEL`, `GETEX`, `GETSET`, `SET` with the `GET`
+/// option, `MGET`, `HGET`, `HMGET`, `HGETALL`, `HVALS` and `HGETEX` are passed to `decode`, for the commands sent with
+/// [`command`] and its variants, [`command_blocking`], [`batch`] and [`execute_transaction_with_watch`]. Only the
+/// commands in `request_types` are handled, and other commands are sent as is. The commands that read or write a part
+/// of a value, such as `APPEND`, `GETRANGE` and `SETRANGE`, can't be handled. The arguments of scripts are sent as is as well, since they aren't typed as values.
+/// When compression is enabled, values are encoded before they're compressed. Setting a codec replaces the previous
+/// one, and commands that were already sent are decoded by the codec they were encoded with.
 ///
-/// * `command_span` - An optional raw pointer (as u64) to a GlideSpan
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `encode`: The callback that encodes values, or `None` together with `decode` to remove the codec.
+/// * `decode`: The callback that decodes values, or `None` together with `encode` to remove the codec.
+/// * `context`: A baton-pass given to the callbacks, such as the handle of the codec in the calling language.
+/// * `request_types`: The commands whose values are encoded and decoded, out of the commands listed above.
+/// * `request_types_count`: The number of elements in `request_types`.
 ///
 /// # Returns
 ///
-/// * `Some(GlideSpan)` - A cloned GlideSpan if the pointer is valid
-/// * `None` - If the pointer is None
-unsafe fn get_unsafe_span_from_ptr(command_span: Option<u64>) -> Option<GlideSpan> {
-    command_span.map(|command_span| unsafe {
-        Arc::increment_strong_count(command_span as *const GlideSpan);
-        (*Arc::from_raw(command_span as *const GlideSpan)).clone()
-    })
+/// `true` if the codec was set or removed, or `false` if only one of the callbacks is given, or if `request_types`
+/// contains a command whose values aren't supported.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `encode` and `decode` must be valid function pointers that live while the client is open/active.
+/// * `request_types` must point to `request_types_count` consecutive request types, and may be `null` only if `request_types_count` is 0.
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_value_codec(
+    client_adapter_ptr: *const c_void,
+    encode: Option<ValueCodecCallback>,
+    decode: Option<ValueCodecCallback>,
+    context: usize,
+    request_types: *const RequestType,
+    request_types_count: usize,
+) -> bool {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let value_codec = match (encode, decode) {
+        (Some(encode), Some(decode)) => {
+            let request_types = if request_types.is_null() {
+                &[]
+            } else {
+                unsafe { from_raw_parts(request_types, request_types_count) }
+            };
+            match ValueCodec::new(encode, decode, context, request_types) {
+                Some(value_codec) => Some(Arc::new(value_codec)),
+                None => return false,
+            }
+        }
+        (None, None) => None,
+        _ => return false,
+    };
+    if let Ok(mut guard) = client_adapter.core.value_codec.write() {
+        *guard = value_codec;
+    }
+    true
 }
 
-/// Creates a child span for telemetry if telemetry is enabled
-fn create_child_span(span: Option<&GlideSpan>, name: &str) -> Result<GlideSpan, String> {
-    // Early return if no parent span is provided
-    let parent_span = span.ok_or_else(|| "No parent span provided".to_string())?;
-
-    match parent_span.add_span(name) {
-        Ok(child_span) => Ok(child_span),
-        Err(error_msg) => Err(format!(
-            "Opentelemetry failed to create child span with name `{name}`. Error: {error_msg:?}"
-        )),
-    }
+/// Writes the encoded or decoded value to the `output` of a [`ValueCodecCallback`]. The value is copied, so it can be
+/// freed once this function returns. Writing again replaces the previous value.
+///
+/// # Safety
+///
+/// * `output` must be the `output` given to the [`ValueCodecCallback`] that calls this function, during its execution.
+/// * `value` must point to `value_len` consecutive bytes, and may be `null` only if `value_len` is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn write_value_codec_output(
+    output: *mut c_void,
+    value: *const u8,
+    value_len: usize,
+) {
+    assert!(!output.is_null());
+    let value = if value.is_null() {
+        Vec::new()
+    } else {
+        unsafe { from_raw_parts(value, value_len) }.to_vec()
+    };
+    unsafe { *(output as *mut Option<Vec<u8>>) = Some(value) };
 }
 
-#[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Level {
-    ERROR = 0,
-    WARN = 1,
-    INFO = 2,
-    DEBUG = 3,
-    TRACE = 4,
-    OFF = 5,
+/// Get the statistics of the requests deduplicated by a client since deduplication was last enabled.
+///
+/// # Returns
+///
+/// A `RequestDeduplicationStatistics` struct. All values are zero if deduplication isn't enabled.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_request_deduplication_statistics(
+    client_adapter_ptr: *const c_void,
+) -> RequestDeduplicationStatistics {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let statistics = match client_adapter.core.deduplicator.read() {
+        Ok(guard) => guard.as_ref().map(|deduplicator| deduplicator.statistics()),
+        Err(_) => None,
+    };
+    statistics
+        .map(|statistics| RequestDeduplicationStatistics {
+            requests_count: statistics.requests_count as c_ulong,
+            hits_count: statistics.hits_count as c_ulong,
+        })
+        .unwrap_or_default()
 }
 
-impl From<logger_core::Level> for Level {
-    fn from(level: logger_core::Level) -> Self {
-        match level {
-            logger_core::Level::Error => Level::ERROR,
-            logger_core::Level::Warn => Level::WARN,
-            logger_core::Level::Info => Level::INFO,
-            logger_core::Level::Debug => Level::DEBUG,
-            logger_core::Level::Trace => Level::TRACE,
-            logger_core::Level::Off => Level::OFF,
-        }
+/// Enables or disables the slow command log of a client, a client-side analog of the server's `SLOWLOG`.
+///
+/// When enabled, every command sent with [`command`] whose latency, measured from the client, exceeds `threshold_us`
+/// is recorded with its name, a digest of its arguments, its duration and the node that served it, when it's known.
+/// The log keeps the latest `capacity` entries. Enabling the log again, or disabling it, discards the recorded entries.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `enabled`: Whether slow commands should be recorded.
+/// * `threshold_us`: The latency, in microseconds, above which a command is recorded.
+/// * `capacity`: The maximal number of entries kept in the log.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_slow_command_log(
+    client_adapter_ptr: *const c_void,
+    enabled: bool,
+    threshold_us: u64,
+    capacity: u32,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    if let Ok(mut guard) = client_adapter.core.slow_command_log.write() {
+        *guard = enabled.then(|| {
+            Arc::new(SlowCommandLog::new(
+                Duration::from_micros(threshold_us),
+                capacity as usize,
+            ))
+        });
     }
 }
 
-impl From<Level> for logger_core::Level {
-    fn from(level: Level) -> Self {
-        match level {
-            Level::ERROR => logger_core::Level::Error,
-            Level::WARN => logger_core::Level::Warn,
-            Level::INFO => logger_core::Level::Info,
-            Level::DEBUG => logger_core::Level::Debug,
-            Level::TRACE => logger_core::Level::Trace,
-            Level::OFF => logger_core::Level::Off,
-        }
-    }
+/// Get the latest commands recorded by the slow command log of a client.
+///
+/// # Parameters
+///
+/// * `client_adapter_ptr`: Pointer to a valid client returned from [`create_client`].
+/// * `request_id`: Unique identifier for a valid payload buffer created in the calling language.
+/// * `max_entries`: The maximal number of entries to return.
+///
+/// # Returns
+///
+/// * A pointer to a [`CommandResult`] containing an array of the entries, newest first. Each entry is a map of its
+///   `id`, `command`, `args_digest`, `duration_us` and `node`, which is null when the serving node isn't known.
+///   The array is empty if the slow command log isn't enabled.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * `client_adapter_ptr` must be able to be safely casted to a valid [`Arc<ClientAdapter>`] via [`Arc::from_raw`].
+/// * `request_id` must be valid until it is passed in a call to [`free_command_response`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn get_slow_commands(
+    client_adapter_ptr: *const c_void,
+    request_id: usize,
+    max_entries: u32,
+) -> *mut CommandResult {
+    let client_adapter = unsafe {
+        // we increment the strong count to ensure that the client is not dropped just because we turned it into an Arc.
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+
+    let entries = match client_adapter.core.slow_command_log.read() {
+        Ok(guard) => guard
+            .as_ref()
+            .map(|slow_command_log| slow_command_log.entries(max_entries as usize))
+            .unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    client_adapter.execute_request(request_id, async move {
+        let entry = |key: &str, value: Value| (Value::SimpleString(key.to_string()), value);
+        Ok(Value::Array(
+            entries
+                .into_iter()
+                .map(|slow_command| {
+                    Value::Map(vec![
+                        entry("id", Value::Int(slow_command.id as i64)),
+                        entry(
+                            "command",
+                            Value::BulkString(slow_command.command_name.into_bytes()),
+                        ),
+                        entry("args_digest", Value::Int(slow_command.args_digest as i64)),
+                        entry(
+                            "duration_us",
+                            Value::Int(slow_command.duration.as_micros() as i64),
+                        ),
+                        entry(
+                            "node",
+                            slow_command.node_address.map_or(Value::Nil, |address| {
+                                Value::BulkString(address.into_bytes())
+                            }),
+                        ),
+                    ])
+                })
+                .collect(),
+        ))
+    })
 }
 
-/// Logs a message using the logger backend.
+/// Get the latency percentiles of all the commands recorded by the slow command log of a client, slow or not.
 ///
-/// # Parameters
+/// # Returns
 ///
-/// * `level` - The severity level of the current message (e.g., Error, Warn, Info).
-/// * `identifier` - A pointer to a null-terminated C string identifying the source of the log message.
-/// * `message` - A pointer to a null-terminated C string containing the actual log message.
+/// A `CommandLatencyPercentiles` struct with the percentiles of the commands recorded since the log was enabled or
+/// last cleared. All values are zero if the slow command log isn't enabled.
 ///
 /// # Safety
 ///
-///  The returned pointer must be freed using [`free_log_result`].
-///
-/// * `identifier` must be a valid, non-null pointer to a null-terminated UTF-8 encoded C string.
-/// * `message` must be a valid, non-null pointer to a null-terminated UTF-8 encoded C string.
-///
-/// # Note
-///
-/// The caller (Python Sync wrapper, Go wrapper, etc.) is responsible for filtering log messages according to the logger's current log level.
-/// This function will log any message it receives.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn glide_log(
-    level: Level,
-    identifier: *const c_char,
-    message: *const c_char,
-) -> *mut LogResult {
-    let id_str = match unsafe { CStr::from_ptr(identifier).to_str() } {
-        Ok(s) => s,
-        Err(err) => {
-            let c_err = CString::new(format!("Log identifier contains invalid UTF-8: {err}"))
-                .unwrap_or_default()
-                .into_raw();
-            return Box::into_raw(Box::new(LogResult {
-                log_error: c_err,
-                level: Level::OFF, // Default value, should be ignored when there's an error
-            }));
-        }
+pub unsafe extern "C" fn get_slow_command_latency_percentiles(
+    client_adapter_ptr: *const c_void,
+) -> CommandLatencyPercentiles {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
-
-    let msg_str = match unsafe { CStr::from_ptr(message).to_str() } {
-        Ok(s) => s,
-        Err(err) => {
-            let c_err = CString::new(format!("Log message contains invalid UTF-8: {err}"))
-                .unwrap_or_default()
-                .into_raw();
-            return Box::into_raw(Box::new(LogResult {
-                log_error: c_err,
-                level: Level::OFF, // Default value, should be ignored when there's an error
-            }));
-        }
+    let percentiles = match client_adapter.core.slow_command_log.read() {
+        Ok(guard) => guard
+            .as_ref()
+            .map(|slow_command_log| slow_command_log.latency_percentiles())
+            .unwrap_or_default(),
+        Err(_) => Default::default(),
     };
-
-    logger_core::log(level.into(), id_str, msg_str);
-
-    Box::into_raw(Box::new(LogResult {
-        log_error: std::ptr::null_mut(),
-        level, // Level is not meaningful for log operations, but set a default
-    }))
+    CommandLatencyPercentiles {
+        count: percentiles.count as c_ulong,
+        p50_us: percentiles.p50_us as c_ulong,
+        p95_us: percentiles.p95_us as c_ulong,
+        p99_us: percentiles.p99_us as c_ulong,
+    }
 }
 
-/// Initializes the logger with the provided log level and optional log file path.
-///
-/// Success is indicated by a `LogResult` with a null `log_error` field and the actual
-/// log level set in the `level` field. Failure is indicated by a `LogResult` with a non-null
-/// `log_error` field containing an error message, and the `level` field should be ignored.
-///
-/// # Parameters
-///
-/// * `level` - A pointer to a `Level` enum value that sets the maximum log level. If null, a WARN level will be used.
-/// * `file_name` - A pointer to a null-terminated C string representing the desired log file path.
-///
-/// # Returns
-///
-/// A pointer to a `LogResult` struct containing either:
-/// - Success: `log_error` is null, `level` contains the actual log level that was set
-/// - Error: `log_error` contains the error message, `level` should be ignored
-///
+/// Removes the commands and the latencies recorded by the slow command log of a client, if it's enabled.
 ///
 /// # Safety
 ///
-/// The returned pointer must be freed using [`free_log_result`].
-///
-/// * `level` may be null. If not null, it must point to a valid instance of the `Level` enum.
-/// * `file_name` may be null. If not null, it must point to a valid, null-terminated C string.
-///   If the string contains invalid UTF-8, an error will be returned instead of panicking.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn init(level: *const Level, file_name: *const c_char) -> *mut LogResult {
-    let level_option = if level.is_null() {
-        None
-    } else {
-        Some(unsafe { *level }.into())
+pub unsafe extern "C" fn clear_slow_commands(client_adapter_ptr: *const c_void) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
     };
-
-    let file_name_option = if file_name.is_null() {
-        None
-    } else {
-        match unsafe { CStr::from_ptr(file_name).to_str() } {
-            Ok(file_str) => Some(file_str),
-            Err(err) => {
-                let c_err = CString::new(format!("File name contains invalid UTF-8: {err}"))
-                    .unwrap_or_default()
-                    .into_raw();
-                return Box::into_raw(Box::new(LogResult {
-                    log_error: c_err,
-                    level: Level::OFF, // Default value, should be ignored when there's an error
-                }));
-            }
+    if let Ok(guard) = client_adapter.core.slow_command_log.read() {
+        if let Some(slow_command_log) = guard.as_ref() {
+            slow_command_log.clear();
         }
-    };
-
-    let logger_level = logger_core::init(level_option, file_name_option);
+    }
+}
 
-    Box::into_raw(Box::new(LogResult {
-        log_error: std::ptr::null_mut(),
-        level: logger_level.into(),
-    }))
+/// Statistics of the pubsub buffer of a client.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct PubSubBufferStatistics {
+    /// Number of messages waiting to be delivered
+    pub buffered_count: c_ulong,
+    /// The highest number of messages that waited to be delivered at once
+    pub max_buffered_count: c_ulong,
+    /// Number of messages dropped because the buffer was full
+    pub dropped_count: c_ulong,
 }
 
-/// Frees a log result.
+/// Enables or disables the buffering of the pubsub messages of a client.
 ///
-/// This function deallocates a `LogResult` struct and any error message it contains.
+/// When enabled, push notifications are queued in a bounded buffer and delivered to the pubsub and keyspace event
+/// callbacks by a dedicated thread, so a slow callback doesn't delay the client's runtime. Once the buffer holds
+/// `capacity` messages, arriving messages are dropped according to `overflow_policy`, and counted in the statistics.
+/// Enabling buffering again, or disabling it, discards the messages that weren't delivered yet and resets the statistics.
 ///
 /// # Parameters
 ///
-/// * `result_ptr` - A pointer to the `LogResult` to free, or null.
+/// * `client_adapter_ptr`: Pointer to a valid `ClientAdapter` returned from [`create_client`].
+/// * `enabled`: Whether pubsub messages should be buffered.
+/// * `capacity`: The maximal number of buffered messages. Pass 0 to use the default of 10000 messages.
+/// * `overflow_policy`: Whether the oldest buffered message or the arriving message is dropped when the buffer is full.
 ///
 /// # Safety
 ///
-/// * `result_ptr` must be a valid pointer to a `LogResult` returned by [`glide_log`] or [`init`], or null.
-/// * This function must be called exactly once for each `LogResult`.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn free_log_result(result_ptr: *mut LogResult) {
-    if result_ptr.is_null() {
-        return;
+pub unsafe extern "C" fn set_pubsub_buffer(
+    client_adapter_ptr: *const c_void,
+    enabled: bool,
+    capacity: usize,
+    overflow_policy: PubSubOverflowPolicy,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let buffer = enabled.then(|| {
+        Arc::new(PubSubBuffer::new(PubSubBufferConfig {
+            capacity: match capacity {
+                0 => DEFAULT_PUBSUB_BUFFER_CAPACITY,
+                capacity => capacity,
+            },
+            overflow_policy,
+        }))
+    });
+    if let Some(buffer) = &buffer {
+        let buffer = buffer.clone();
+        let pubsub_callback_store = client_adapter.pubsub_callback.clone();
+        let keyspace_event_callback_store = client_adapter.keyspace_event_callback.clone();
+        let client_adapter_ptr = client_adapter_ptr.addr();
+        let spawned = std::thread::Builder::new()
+            .name("Valkey-GLIDE pubsub".to_string())
+            .spawn(move || {
+                while let Some(push_msg) = buffer.pop() {
+                    unsafe {
+                        deliver_push_notification(
+                            push_msg,
+                            &pubsub_callback_store,
+                            &keyspace_event_callback_store,
+                            client_adapter_ptr,
+                        )
+                    };
+                }
+            });
+        if let Err(err) = spawned {
+            logger_core::log_error(
+                "ffi_pubsub",
+                format!("Failed to start the pubsub delivery thread: {err}"),
+            );
+            return;
+        }
     }
-    unsafe {
-        let result = Box::from_raw(result_ptr);
-        if !result.log_error.is_null() {
-            free_c_string(result.log_error);
+    if let Ok(mut guard) = client_adapter.pubsub_buffer.write() {
+        if let Some(previous) = guard.as_ref() {
+            previous.close();
         }
+        *guard = buffer;
     }
 }
 
-/// Statistics structure containing telemetry data.
+/// Get the statistics of the pubsub buffer of a client since buffering was last enabled.
 ///
-/// This struct provides compression and connection statistics for the client.
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct Statistics {
-    /// Total number of connections opened to Valkey
-    pub total_connections: c_ulong,
-    /// Total number of GLIDE clients
-    pub total_clients: c_ulong,
-    /// Total number of values compressed
-    pub total_values_compressed: c_ulong,
-    /// Total number of values decompressed
-    pub total_values_decompressed: c_ulong,
-    /// Total original bytes before compression
-    pub total_original_bytes: c_ulong,
-    /// Total bytes after compression
-    pub total_bytes_compressed: c_ulong,
-    /// Total bytes after decompression
-    pub total_bytes_decompressed: c_ulong,
-    /// Number of times compression was skipped
-    pub compression_skipped_count: c_ulong,
-    /// Number of times subscriptions were out of sync during reconciliation
-    pub subscription_out_of_sync_count: c_ulong,
-    /// Timestamp of last successful subscription sync (milliseconds since epoch)
-    pub subscription_last_sync_timestamp: c_ulong,
-}
-
-/// Get compression and connection statistics.
+/// # Returns
 ///
-/// Returns a `Statistics` struct containing current telemetry data.
-/// This function is thread-safe and can be called at any time.
+/// A `PubSubBufferStatistics` struct. All values are zero if buffering isn't enabled.
 ///
-/// # Returns
+/// # Safety
 ///
-/// A `Statistics` struct with the current statistics values.
+/// * `client_adapter_ptr` must not be `null` and must be obtained from the `ConnectionResponse` returned from [`create_client`].
+/// * This function should only be called with a `client_adapter_ptr` created by [`create_client`], before [`close_client`] was called with the pointer.
 #[unsafe(no_mangle)]
-pub extern "C" fn get_statistics() -> Statistics {
-    Statistics {
-        total_connections: Telemetry::total_connections() as c_ulong,
-        total_clients: Telemetry::total_clients() as c_ulong,
-        total_values_compressed: Telemetry::total_values_compressed() as c_ulong,
-        total_values_decompressed: Telemetry::total_values_decompressed() as c_ulong,
-        total_original_bytes: Telemetry::total_original_bytes() as c_ulong,
-        total_bytes_compressed: Telemetry::total_bytes_compressed() as c_ulong,
-        total_bytes_decompressed: Telemetry::total_bytes_decompressed() as c_ulong,
-        compression_skipped_count: Telemetry::compression_skipped_count() as c_ulong,
-        subscription_out_of_sync_count: Telemetry::subscription_out_of_sync_count() as c_ulong,
-        subscription_last_sync_timestamp: Telemetry::subscription_last_sync_timestamp() as c_ulong,
-    }
+pub unsafe extern "C" fn get_pubsub_buffer_statistics(
+    client_adapter_ptr: *const c_void,
+) -> PubSubBufferStatistics {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe {
+        Arc::increment_strong_count(client_adapter_ptr);
+        Arc::from_raw(client_adapter_ptr as *mut ClientAdapter)
+    };
+    let statistics = match client_adapter.pubsub_buffer.read() {
+        Ok(guard) => guard.as_ref().map(|buffer| buffer.statistics()),
+        Err(_) => None,
+    };
+    statistics
+        .map(|statistics| PubSubBufferStatistics {
+            buffered_count: statistics.buffered_count as c_ulong,
+            max_buffered_count: statistics.max_buffered_count as c_ulong,
+            dropped_count: statistics.dropped_count as c_ulong,
+        })
+        .unwrap_or_default()
 }
 
 /// Returns the minimum size in bytes for compression.
@@ -3190,3 +9529,295 @@ pub unsafe extern "C" fn unregister_pubsub_callback(
             .into_raw(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string_of(response: &CommandResponse) -> String {
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                response.string_value as *const u8,
+                response.string_value_len as usize,
+            )
+        };
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_map_keys_keep_their_types() {
+        let Value::BigNumber(big_number) =
+            redis::parse_redis_value(b"(123456789012345678901234567890\r\n").unwrap()
+        else {
+            panic!("expected a big number");
+        };
+        let map = Value::Map(vec![
+            (Value::Int(12), Value::BulkString(b"int".to_vec())),
+            (Value::Double(1.5), Value::BulkString(b"double".to_vec())),
+            (Value::BigNumber(big_number), Value::Int(3)),
+        ]);
+        let response = valkey_value_to_command_response(map, None).unwrap();
+        assert!(matches!(response.response_type, ResponseType::Map));
+        let entries = unsafe {
+            std::slice::from_raw_parts(response.array_value, response.array_value_len as usize)
+        };
+        let keys: Vec<&CommandResponse> = entries
+            .iter()
+            .map(|entry| unsafe { &*entry.map_key })
+            .collect();
+
+        assert!(matches!(keys[0].response_type, ResponseType::Int));
+        assert_eq!(keys[0].int_value, 12);
+        assert_eq!(string_of(keys[0]), "12");
+        assert!(matches!(keys[1].response_type, ResponseType::Float));
+        assert_eq!(keys[1].float_value, 1.5);
+        assert_eq!(string_of(keys[1]), "1.5");
+        assert!(matches!(keys[2].response_type, ResponseType::String));
+        assert_eq!(string_of(keys[2]), "123456789012345678901234567890");
+        assert_eq!(string_of(unsafe { &*entries[0].map_value }), "int");
+
+        unsafe { free_command_response_elements(response) };
+    }
+
+    unsafe extern "C-unwind" fn reverse_value(
+        _context: usize,
+        _request_type: RequestType,
+        value: *const u8,
+        value_len: usize,
+        output: *mut c_void,
+    ) -> bool {
+        let mut value = unsafe { from_raw_parts(value, value_len) }.to_vec();
+        value.reverse();
+        unsafe { write_value_codec_output(output, value.as_ptr(), value.len()) };
+        true
+    }
+
+    fn reversing_codec() -> ValueCodec {
+        ValueCodec::new(
+            reverse_value,
+            reverse_value,
+            0,
+            &[
+                RequestType::Set,
+                RequestType::HSet,
+                RequestType::Get,
+                RequestType::MSet,
+                RequestType::MGet,
+                RequestType::HSetEx,
+                RequestType::HGetAll,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_value_codec_encodes_the_values_of_the_arguments() {
+        let value_codec = reversing_codec();
+        let mut args = vec![b"key".to_vec(), b"value".to_vec(), b"GET".to_vec()];
+        unsafe { value_codec.encode_args(RequestType::Set, &mut args) }.unwrap();
+        assert_eq!(args, [&b"key"[..], b"eulav", b"GET"]);
+
+        let mut args = vec![
+            b"key".to_vec(),
+            b"field1".to_vec(),
+            b"value1".to_vec(),
+            b"field2".to_vec(),
+            b"value2".to_vec(),
+        ];
+        unsafe { value_codec.encode_args(RequestType::HSet, &mut args) }.unwrap();
+        assert_eq!(
+            args,
+            [&b"key"[..], b"field1", b"1eulav", b"field2", b"2eulav"]
+        );
+
+        let mut args = vec![
+            b"key1".to_vec(),
+            b"value1".to_vec(),
+            b"key2".to_vec(),
+            b"value2".to_vec(),
+        ];
+        unsafe { value_codec.encode_args(RequestType::MSet, &mut args) }.unwrap();
+        assert_eq!(args, [&b"key1"[..], b"1eulav", b"key2", b"2eulav"]);
+
+        let mut args = vec![
+            b"key".to_vec(),
+            b"EX".to_vec(),
+            b"10".to_vec(),
+            b"fields".to_vec(),
+            b"1".to_vec(),
+            b"field".to_vec(),
+            b"value".to_vec(),
+        ];
+        unsafe { value_codec.encode_args(RequestType::HSetEx, &mut args) }.unwrap();
+        assert_eq!(
+            args,
+            [
+                &b"key"[..],
+                b"EX",
+                b"10",
+                b"fields",
+                b"1",
+                b"field",
+                b"eulav"
+            ]
+        );
+
+        // The commands that aren't handled are sent as is.
+        let mut args = vec![b"key".to_vec(), b"field".to_vec(), b"value".to_vec()];
+        unsafe { value_codec.encode_args(RequestType::HSetNX, &mut args) }.unwrap();
+        assert_eq!(args, [&b"key"[..], b"field", b"value"]);
+    }
+
+    #[test]
+    fn test_value_codec_rejects_unsupported_commands() {
+        let new = |request_types: &[RequestType]| {
+            ValueCodec::new(reverse_value, reverse_value, 0, request_types).is_some()
+        };
+        assert!(new(&[RequestType::GetEx, RequestType::HMGet]));
+        assert!(!new(&[RequestType::Get, RequestType::Append]));
+        assert!(!new(&[RequestType::GetRange]));
+    }
+
+    #[test]
+    fn test_value_codec_decodes_the_values_of_the_responses() {
+        let value_codec = reversing_codec();
+        let decode = |request_type, value| {
+            unsafe { value_codec.decode_response(request_type, value) }.unwrap()
+        };
+        assert_eq!(
+            decode(RequestType::Get, Value::BulkString(b"eulav".to_vec())),
+            Value::BulkString(b"value".to_vec())
+        );
+        // The previous value returned by `SET` with the `GET` option.
+        assert_eq!(
+            decode(RequestType::Set, Value::BulkString(b"eulav".to_vec())),
+            Value::BulkString(b"value".to_vec())
+        );
+        assert_eq!(decode(RequestType::Set, Value::Okay), Value::Okay);
+        assert_eq!(decode(RequestType::Get, Value::Nil), Value::Nil);
+        assert_eq!(
+            decode(RequestType::HGet, Value::BulkString(b"eulav".to_vec())),
+            Value::BulkString(b"eulav".to_vec())
+        );
+        assert_eq!(
+            decode(
+                RequestType::MGet,
+                Value::Array(vec![Value::BulkString(b"1eulav".to_vec()), Value::Nil])
+            ),
+            Value::Array(vec![Value::BulkString(b"value1".to_vec()), Value::Nil])
+        );
+        assert_eq!(
+            decode(
+                RequestType::HGetAll,
+                Value::Map(vec![(
+                    Value::BulkString(b"field".to_vec()),
+                    Value::BulkString(b"eulav".to_vec())
+                )])
+            ),
+            Value::Map(vec![(
+                Value::BulkString(b"field".to_vec()),
+                Value::BulkString(b"value".to_vec())
+            )])
+        );
+
+        let batch_response = Value::Array(vec![
+            Value::Okay,
+            Value::BulkString(b"eulav".to_vec()),
+            Value::BulkString(b"eulav".to_vec()),
+        ]);
+        assert_eq!(
+            unsafe {
+                value_codec.decode_batch_response(
+                    &[RequestType::Set, RequestType::Get, RequestType::Echo],
+                    batch_response,
+                )
+            }
+            .unwrap(),
+            Value::Array(vec![
+                Value::Okay,
+                Value::BulkString(b"value".to_vec()),
+                Value::BulkString(b"eulav".to_vec()),
+            ])
+        );
+        // The response of an aborted transaction.
+        assert_eq!(
+            unsafe { value_codec.decode_batch_response(&[RequestType::Get], Value::Nil) }.unwrap(),
+            Value::Nil
+        );
+    }
+
+    #[test]
+    fn test_take_server_time() {
+        let response = Value::Attribute {
+            data: Box::new(Value::BulkString(b"value".to_vec())),
+            attributes: vec![
+                (Value::SimpleString("other".to_string()), Value::Int(1)),
+                (
+                    Value::SimpleString("server-time".to_string()),
+                    Value::Int(42),
+                ),
+            ],
+        };
+        assert_eq!(
+            take_server_time(response),
+            (Value::BulkString(b"value".to_vec()), Some(42))
+        );
+        assert_eq!(take_server_time(Value::Okay), (Value::Okay, None));
+    }
+
+    #[test]
+    fn test_resubscription_keeps_the_kind_and_bytes_of_the_subscriptions() {
+        let push_msg = redis::PushInfo {
+            kind: redis::PushKind::Resubscribed,
+            data: vec![
+                Value::BulkString(b"1500".to_vec()),
+                Value::Array(vec![
+                    Value::Int(0),
+                    Value::BulkString(b"news\nsports".to_vec()),
+                ]),
+                Value::Array(vec![Value::Int(2), Value::BulkString(b"shard".to_vec())]),
+            ],
+        };
+        let notification = resubscription_to_notification(push_msg);
+        let mut expected = vec![0, 0, 0, 0, 11];
+        expected.extend_from_slice(b"news\nsports");
+        expected.extend_from_slice(&[2, 0, 0, 0, 5]);
+        expected.extend_from_slice(b"shard");
+        assert_eq!(
+            notification.data,
+            vec![
+                Value::BulkString(expected),
+                Value::BulkString(b"1500".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_az_affinity_route_requires_an_availability_zone() {
+        let slot_key = CString::new("key").unwrap();
+        let az_route = |az: *const c_char| RouteInfo {
+            route_type: RouteType::AzAffinity,
+            slot_id: 0,
+            slot_key: slot_key.as_ptr(),
+            slot_type: SlotType::Replica,
+            hostname: std::ptr::null(),
+            port: 0,
+            az,
+        };
+
+        let az = CString::new("use-1a").unwrap();
+        let route = az_route(az.as_ptr());
+        assert!(matches!(
+            unsafe { create_route(&route, None) },
+            Ok(Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ReplicaInAZ { az, .. })))
+                if az == "use-1a"
+        ));
+
+        let empty_az = CString::new("").unwrap();
+        for az in [std::ptr::null(), empty_az.as_ptr()] {
+            let route = az_route(az);
+            let err = unsafe { create_route(&route, None) }.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ClientError);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use glide_core::client::FlushMode;
 use glide_core::connection_request::{ConnectionRequest, NodeAddress, TlsMode};
 use glide_core::errors::RequestErrorType;
 use glide_core::request_type::RequestType;
@@ -145,6 +146,103 @@ fn create_connection_request(port: u16) -> Vec<u8> {
     request.write_to_bytes().expect("Failed to serialize")
 }
 
+/// A null pubsub callback, for the clients that don't subscribe.
+fn no_pubsub_callback() -> PubSubCallback {
+    unsafe { std::mem::transmute::<*mut c_void, PubSubCallback>(std::ptr::null_mut()) }
+}
+
+/// Creates a client of `client_type` with [`create_client`], and asserts that it connected.
+/// Returns the connection response, to be freed with [`free_connection_response`], and the client pointer.
+unsafe fn create_test_client(
+    connection_request_bytes: &[u8],
+    client_type: ClientType,
+) -> (*const ConnectionResponse, *const c_void) {
+    let client_type = Box::into_raw(Box::new(client_type));
+    unsafe {
+        let response_ptr = create_client(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type,
+            no_pubsub_callback(),
+        );
+
+        connected_client(response_ptr)
+    }
+}
+
+lazy_static! {
+    /// The addresses of the connection responses passed to [`client_creation_callback`], by request ID.
+    static ref CREATED_CLIENTS: std::sync::Mutex<HashMap<usize, usize>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+extern "C-unwind" fn client_creation_callback(
+    request_id: usize,
+    response_ptr: *const ConnectionResponse,
+) {
+    CREATED_CLIENTS
+        .lock()
+        .unwrap()
+        .insert(request_id, response_ptr as usize);
+}
+
+/// Starts creating a client of `client_type` with [`create_client_async`], and waits for `request_id` to be passed to
+/// [`client_creation_callback`]. Returns the connection response, to be freed with [`free_connection_response`].
+unsafe fn create_client_async_and_wait(
+    connection_request_bytes: &[u8],
+    client_type: ClientType,
+    request_id: usize,
+) -> *const ConnectionResponse {
+    let client_type = Box::into_raw(Box::new(client_type));
+    unsafe {
+        create_client_async(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type,
+            no_pubsub_callback(),
+            None,
+            request_id,
+            client_creation_callback,
+        );
+    }
+    for _ in 0..100 {
+        if let Some(response_addr) = CREATED_CLIENTS.lock().unwrap().remove(&request_id) {
+            return response_addr as *const ConnectionResponse;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    panic!("The client creation callback wasn't called");
+}
+
+/// Creates a client of `client_type` with [`create_client_async`], and asserts that it connected.
+/// Returns the connection response, to be freed with [`free_connection_response`], and the client pointer.
+unsafe fn create_test_client_async(
+    connection_request_bytes: &[u8],
+    client_type: ClientType,
+    request_id: usize,
+) -> (*const ConnectionResponse, *const c_void) {
+    unsafe {
+        connected_client(create_client_async_and_wait(
+            connection_request_bytes,
+            client_type,
+            request_id,
+        ))
+    }
+}
+
+/// Asserts that `response_ptr` holds a connected client, and returns it with the client pointer.
+unsafe fn connected_client(
+    response_ptr: *const ConnectionResponse,
+) -> (*const ConnectionResponse, *const c_void) {
+    assert!(!response_ptr.is_null(), "Failed to create client");
+    let response = unsafe { &*response_ptr };
+    assert!(
+        !response.conn_ptr.is_null() && response.connection_error_message.is_null(),
+        "Connection response should be valid"
+    );
+    (response_ptr, response.conn_ptr)
+}
+
 fn create_connection_request_with_inflight_limit(port: u16, limit: u32) -> Vec<u8> {
     let host = "localhost";
     let mut request = ConnectionRequest::new();
@@ -240,44 +338,16 @@ fn get_async_error(index: usize) -> (String, RequestErrorType) {
 fn test_ffi_client_command_execution(#[values(false, true)] async_client: bool) {
     let server = Server::new();
     let connection_request_bytes = create_connection_request(server.port);
-    let connection_request_len = connection_request_bytes.len();
-    let connection_request_ptr = connection_request_bytes.as_ptr();
-    let client_type = Box::into_raw(Box::new(if async_client {
+    let client_type = if async_client {
         ClientType::AsyncClient {
             success_callback: string_success_callback,
             failure_callback,
         }
     } else {
         ClientType::SyncClient
-    }));
+    };
     unsafe {
-        let response_ptr = create_client(
-            connection_request_ptr,
-            connection_request_len,
-            client_type,
-            std::mem::transmute::<
-                *mut c_void,
-                unsafe extern "C-unwind" fn(
-                    client_ptr: usize,
-                    kind: PushKind,
-                    message: *const u8,
-                    message_len: i64,
-                    channel: *const u8,
-                    channel_len: i64,
-                    pattern: *const u8,
-                    pattern_len: i64,
-                ),
-            >(std::ptr::null_mut()),
-        );
-
-        assert!(!response_ptr.is_null(), "Failed to create client");
-        let response = &*response_ptr;
-        assert!(
-            !response.conn_ptr.is_null() && response.connection_error_message.is_null(),
-            "Connection response should be valid"
-        );
-
-        let client_ptr = response.conn_ptr;
+        let (response_ptr, client_ptr) = create_test_client(&connection_request_bytes, client_type);
         // Good command: PING IS_WORKING
         let good_cmd_idx = 0;
         let ping_value = b"IS_WORKING";
@@ -442,38 +512,10 @@ fn test_inflight_request_limit_sync_client() {
     let inflight_limit = 2;
     let connection_request_bytes =
         create_connection_request_with_inflight_limit(server.port, inflight_limit);
-    let connection_request_len = connection_request_bytes.len();
-    let connection_request_ptr = connection_request_bytes.as_ptr();
-    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
 
     unsafe {
-        let response_ptr = create_client(
-            connection_request_ptr,
-            connection_request_len,
-            client_type,
-            std::mem::transmute::<
-                *mut c_void,
-                unsafe extern "C-unwind" fn(
-                    client_ptr: usize,
-                    kind: PushKind,
-                    message: *const u8,
-                    message_len: i64,
-                    channel: *const u8,
-                    channel_len: i64,
-                    pattern: *const u8,
-                    pattern_len: i64,
-                ),
-            >(std::ptr::null_mut()),
-        );
-
-        assert!(!response_ptr.is_null(), "Failed to create client");
-        let response = &*response_ptr;
-        assert!(
-            !response.conn_ptr.is_null() && response.connection_error_message.is_null(),
-            "Connection response should be valid"
-        );
-
-        let client_ptr = response.conn_ptr;
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
 
         // First, verify the client works with normal commands
         let key = b"test_key";
@@ -516,3 +558,819 @@ fn test_inflight_request_limit_sync_client() {
         close_client(client_ptr);
     }
 }
+
+#[test]
+fn test_ping_all_nodes_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let result = ping_all_nodes(client_ptr, 0);
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(
+            cmd_result.command_error.is_null(),
+            "PING to all nodes should not error"
+        );
+
+        // A standalone client with a single address has exactly one node.
+        let nodes = &*cmd_result.response;
+        assert!(matches!(nodes.response_type, ResponseType::Map));
+        assert_eq!(nodes.array_value_len, 1);
+        let entry = &*nodes.array_value;
+        assert!(parse_string_res(entry.map_key).ends_with(&server.port.to_string()));
+        let latency = &*entry.map_value;
+        assert!(matches!(latency.response_type, ResponseType::Int));
+        assert!(latency.int_value >= 0);
+
+        free_command_response(cmd_result.response);
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_script_exists_all_nodes_and_script_flush_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let hash = b"0000000000000000000000000000000000000000";
+        let hashes = [hash.as_ptr() as usize];
+        let hashes_len = [hash.len() as c_ulong];
+
+        let result =
+            script_exists_all_nodes(client_ptr, 0, 1, hashes.as_ptr(), hashes_len.as_ptr());
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let exists = &*cmd_result.response;
+        assert!(matches!(exists.response_type, ResponseType::Array));
+        assert_eq!(exists.array_value_len, 1);
+        assert!(!(*exists.array_value).bool_value);
+        free_command_response(cmd_result.response);
+
+        // Without hashes, the result is empty instead of the error of a bare `SCRIPT EXISTS`.
+        let result = script_exists_all_nodes(client_ptr, 0, 0, std::ptr::null(), std::ptr::null());
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let exists = &*cmd_result.response;
+        assert!(matches!(exists.response_type, ResponseType::Array));
+        assert_eq!(exists.array_value_len, 0);
+        free_command_response(cmd_result.response);
+
+        let result = script_flush(client_ptr, 1, FlushMode::Sync, std::ptr::null(), 0);
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        assert!(matches!(
+            (*cmd_result.response).response_type,
+            ResponseType::Ok
+        ));
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_command_blocking_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let key = b"blocking_list";
+        let timeout = b"0.1";
+        let args = [key.as_ptr() as usize, timeout.as_ptr() as usize];
+        let args_len = [key.len() as c_ulong, timeout.len() as c_ulong];
+
+        let result = command_blocking(
+            client_ptr,
+            0,
+            RequestType::BLPop,
+            2,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+        );
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        assert!(matches!(
+            (*cmd_result.response).response_type,
+            ResponseType::Null
+        ));
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_describe_key_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let key = b"missing_key";
+
+        let result = describe_key(client_ptr, 0, key.as_ptr(), key.len());
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let description = &*cmd_result.response;
+        assert!(matches!(description.response_type, ResponseType::Map));
+        assert_eq!(description.array_value_len, 4);
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_get_slot_for_key() {
+    unsafe {
+        let key = b"foo";
+        assert_eq!(get_slot_for_key(key.as_ptr(), key.len()), 12182);
+        let tagged_key = b"{foo}.bar";
+        assert_eq!(
+            get_slot_for_key(tagged_key.as_ptr(), tagged_key.len()),
+            12182
+        );
+    }
+}
+
+#[test]
+fn test_get_node_for_key_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let key = b"key";
+
+        let result = get_node_for_key(client_ptr, 0, key.as_ptr(), key.len());
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let address = parse_string_res(cmd_result.response);
+        assert!(address.ends_with(&format!(":{}", server.port)));
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+static SCANNED_KEYS_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+extern "C-unwind" fn scan_page_callback(_request_id: usize, keys: *const CommandResponse) {
+    let keys = unsafe { &*keys };
+    assert!(matches!(keys.response_type, ResponseType::Array));
+    SCANNED_KEYS_COUNT.fetch_add(keys.array_value_len as usize, Ordering::SeqCst);
+}
+
+#[test]
+fn test_scan_database_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let empty_route: Vec<u8> = vec![];
+        for i in 0..25 {
+            let key = format!("scan_key:{i}");
+            let args = [key.as_ptr(), b"value".as_ptr()];
+            let args_len = [key.len() as c_ulong, 5];
+            let result = command(
+                client_ptr,
+                0,
+                RequestType::Set,
+                2,
+                args.as_ptr() as *const usize,
+                args_len.as_ptr(),
+                empty_route.as_ptr(),
+                0,
+                0,
+            );
+            let cmd_result = Box::from_raw(result);
+            assert!(cmd_result.command_error.is_null());
+            free_command_response(cmd_result.response);
+        }
+        let other_key = b"other_key";
+        let args = [other_key.as_ptr(), b"value".as_ptr()];
+        let args_len = [other_key.len() as c_ulong, 5];
+        let result = command(
+            client_ptr,
+            0,
+            RequestType::Set,
+            2,
+            args.as_ptr() as *const usize,
+            args_len.as_ptr(),
+            empty_route.as_ptr(),
+            0,
+            0,
+        );
+        free_command_response(Box::from_raw(result).response);
+
+        let scan_args = [
+            b"MATCH".as_ptr(),
+            b"scan_key:*".as_ptr(),
+            b"COUNT".as_ptr(),
+            b"5".as_ptr(),
+        ];
+        let scan_args_len: [c_ulong; 4] = [5, 10, 5, 1];
+        let result = scan_database(
+            client_ptr,
+            0,
+            4,
+            scan_args.as_ptr() as *const usize,
+            scan_args_len.as_ptr(),
+            scan_page_callback,
+        );
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let keys_count = (*cmd_result.response).int_value as usize;
+        assert!(keys_count >= 25);
+        assert_eq!(SCANNED_KEYS_COUNT.load(Ordering::SeqCst), keys_count);
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_response_streaming_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+        let key = b"streamed_key";
+        let value = b"0123456789";
+        let args = [key.as_ptr(), value.as_ptr()];
+        let args_len = [key.len() as c_ulong, value.len() as c_ulong];
+        let result = command(
+            client_ptr,
+            0,
+            RequestType::Set,
+            2,
+            args.as_ptr() as *const usize,
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        free_command_response(cmd_result.response);
+
+        set_response_streaming_threshold(client_ptr, 4);
+        let cmd_result = execute_command(client_ptr, 1, key, 1, RequestType::Get)
+            .expect("Sync client should return a result");
+        assert!(cmd_result.command_error.is_null());
+        let streamed = &*cmd_result.response;
+        assert!(matches!(
+            streamed.response_type,
+            ResponseType::StreamedString
+        ));
+        assert_eq!(streamed.string_value_len, value.len() as i64);
+
+        let handle = streamed.int_value;
+        let mut read = Vec::new();
+        let mut chunk = [0u8; 4];
+        while read.len() < value.len() {
+            let chunk_len = read_response_chunk(handle, chunk.as_mut_ptr(), chunk.len());
+            assert!(chunk_len > 0);
+            read.extend_from_slice(&chunk[..chunk_len as usize]);
+        }
+        assert_eq!(read, value);
+        // The response is released once it was read to the end.
+        assert_eq!(
+            read_response_chunk(handle, chunk.as_mut_ptr(), chunk.len()),
+            -1
+        );
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+static CONNECTED_EVENTS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C-unwind" fn connection_event_callback(
+    _client_ptr: usize,
+    event: ConnectionEvent,
+    _address: *const u8,
+    address_len: i64,
+    _error: *const u8,
+    _error_len: i64,
+) {
+    if matches!(event, ConnectionEvent::Connected) && address_len > 0 {
+        CONNECTED_EVENTS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_create_client_with_connection_events() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+    let connection_request_len = connection_request_bytes.len();
+    let connection_request_ptr = connection_request_bytes.as_ptr();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    unsafe {
+        let response_ptr = create_client_with_connection_events(
+            connection_request_ptr,
+            connection_request_len,
+            client_type,
+            no_pubsub_callback(),
+            Some(connection_event_callback),
+        );
+
+        assert!(!response_ptr.is_null(), "Failed to create client");
+        let response = &*response_ptr;
+        assert!(
+            !response.conn_ptr.is_null() && response.connection_error_message.is_null(),
+            "Connection response should be valid"
+        );
+        assert!(CONNECTED_EVENTS.load(Ordering::SeqCst) > 0);
+
+        let client_ptr = response.conn_ptr;
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_create_client_async_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client_async(&connection_request_bytes, ClientType::SyncClient, 1);
+        let ping_value = b"IS_WORKING";
+        let res = execute_command(client_ptr, 0, ping_value, 1_u64, RequestType::Ping)
+            .expect("Sync client should return a result");
+        assert_eq!(
+            get_sync_response(res.response),
+            String::from_utf8_lossy(ping_value)
+        );
+        free_command_response(res.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_create_client_async_reports_connection_failure() {
+    // Nothing listens on the port, so the client fails to connect.
+    let connection_request_bytes = create_connection_request(Server::get_available_port());
+
+    unsafe {
+        let response_ptr =
+            create_client_async_and_wait(&connection_request_bytes, ClientType::SyncClient, 2);
+        assert!(!response_ptr.is_null(), "Failed to create client");
+        let response = &*response_ptr;
+        assert!(response.conn_ptr.is_null());
+        assert!(!response.connection_error_message.is_null());
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+    }
+}
+
+#[test]
+fn test_create_client_async_reports_invalid_request_before_returning() {
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let invalid_request = [0xff_u8];
+
+    unsafe {
+        create_client_async(
+            invalid_request.as_ptr(),
+            invalid_request.len(),
+            client_type,
+            no_pubsub_callback(),
+            None,
+            3,
+            client_creation_callback,
+        );
+        let response_addr = CREATED_CLIENTS
+            .lock()
+            .unwrap()
+            .remove(&3)
+            .expect("The client creation callback should be called before returning");
+        let response_ptr = response_addr as *const ConnectionResponse;
+        assert!((*response_ptr).conn_ptr.is_null());
+        assert!(!(*response_ptr).connection_error_message.is_null());
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+    }
+}
+
+#[test]
+fn test_routed_client_rejects_commands_across_clusters_sync_client() {
+    let first_server = Server::new();
+    let second_server = Server::new();
+    let names = [b"first".as_slice(), b"second".as_slice()];
+    let requests = [
+        create_connection_request(first_server.port),
+        create_connection_request(second_server.port),
+    ];
+    let prefixes = [b"first:".as_slice(), b"second:".as_slice()];
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    let as_ptrs = |args: &[&[u8]]| -> (Vec<usize>, Vec<c_ulong>) {
+        (
+            args.iter().map(|arg| arg.as_ptr() as usize).collect(),
+            args.iter().map(|arg| arg.len() as c_ulong).collect(),
+        )
+    };
+    let (names_ptrs, names_len) = as_ptrs(&names);
+    let (requests_ptrs, requests_len) = as_ptrs(&[requests[0].as_slice(), requests[1].as_slice()]);
+    let (prefixes_ptrs, prefixes_len) = as_ptrs(&prefixes);
+
+    unsafe {
+        let response_ptr = create_routed_client(
+            2,
+            names_ptrs.as_ptr(),
+            names_len.as_ptr(),
+            requests_ptrs.as_ptr(),
+            requests_len.as_ptr(),
+            2,
+            prefixes_ptrs.as_ptr(),
+            prefixes_len.as_ptr(),
+            names_ptrs.as_ptr(),
+            names_len.as_ptr(),
+            std::ptr::null(),
+            client_type,
+        );
+        assert!(!response_ptr.is_null(), "Failed to create client");
+        let response = &*response_ptr;
+        assert!(
+            !response.conn_ptr.is_null() && response.connection_error_message.is_null(),
+            "Connection response should be valid"
+        );
+        let client_ptr = response.conn_ptr;
+
+        let send = |command_type: RequestType, args: &[&[u8]]| {
+            let (args_ptrs, args_len) = as_ptrs(args);
+            let result = routed_command(
+                client_ptr,
+                0,
+                command_type,
+                args.len() as c_ulong,
+                args_ptrs.as_ptr(),
+                args_len.as_ptr(),
+                std::ptr::null(),
+                0,
+            );
+            assert!(!result.is_null(), "Sync client should return a result");
+            Box::from_raw(result)
+        };
+
+        let cmd_result = send(RequestType::Set, &[b"first:source", b"value"]);
+        assert!(cmd_result.command_error.is_null());
+        free_command_response(cmd_result.response);
+
+        // The destination key is routed to another cluster than the source key.
+        let cmd_result = send(
+            RequestType::Rename,
+            &[b"first:source", b"second:destination"],
+        );
+        assert!(cmd_result.response.is_null());
+        let (err_msg, err_type) = get_sync_error(cmd_result.command_error);
+        assert!(
+            err_msg.contains("routed to different clusters"),
+            "{err_msg}"
+        );
+        assert_eq!(err_type, RequestErrorType::Unspecified);
+
+        let cmd_result = send(
+            RequestType::Rename,
+            &[b"first:source", b"first:destination"],
+        );
+        assert!(cmd_result.command_error.is_null());
+        assert!(matches!(
+            (*cmd_result.response).response_type,
+            ResponseType::Ok
+        ));
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_routed_client(client_ptr);
+    }
+}
+
+unsafe extern "C-unwind" fn reverse_value(
+    _context: usize,
+    _request_type: RequestType,
+    value: *const u8,
+    value_len: usize,
+    output: *mut c_void,
+) -> bool {
+    let mut value = unsafe { std::slice::from_raw_parts(value, value_len) }.to_vec();
+    value.reverse();
+    unsafe { write_value_codec_output(output, value.as_ptr(), value.len()) };
+    true
+}
+
+#[test]
+fn test_value_codec_sync_client() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+
+        let request_types = [RequestType::Set, RequestType::Get];
+        assert!(set_value_codec(
+            client_ptr,
+            Some(reverse_value),
+            Some(reverse_value),
+            0,
+            request_types.as_ptr(),
+            request_types.len(),
+        ));
+
+        // The value is encoded in a batch, and decoded by GET.
+        let key = b"codec_key";
+        let value = b"value";
+        let set_args = [key.as_ptr(), value.as_ptr()];
+        let set_args_len = [key.len(), value.len()];
+        let set_cmd = CmdInfo {
+            request_type: RequestType::Set,
+            args: set_args.as_ptr(),
+            arg_count: 2,
+            args_len: set_args_len.as_ptr(),
+        };
+        let cmds = [&set_cmd as *const CmdInfo];
+        let batch_info = BatchInfo {
+            cmd_count: 1,
+            cmds: cmds.as_ptr(),
+            is_atomic: false,
+            expected_types: std::ptr::null(),
+        };
+        let result = batch(client_ptr, 0, &batch_info, true, std::ptr::null(), 0);
+        assert!(!result.is_null(), "Sync client should return a result");
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        free_command_response(cmd_result.response);
+
+        let cmd_result = execute_command(client_ptr, 1, key, 1, RequestType::Get).unwrap();
+        assert!(cmd_result.command_error.is_null());
+        assert_eq!(get_sync_response(cmd_result.response), "value");
+        free_command_response(cmd_result.response);
+
+        // The previous value returned by SET with the GET option is decoded as well.
+        let new_value = b"other";
+        let get_option = b"GET";
+        let args = [
+            key.as_ptr() as usize,
+            new_value.as_ptr() as usize,
+            get_option.as_ptr() as usize,
+        ];
+        let args_len = [
+            key.len() as c_ulong,
+            new_value.len() as c_ulong,
+            get_option.len() as c_ulong,
+        ];
+        let result = command(
+            client_ptr,
+            2,
+            RequestType::Set,
+            3,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        assert_eq!(get_sync_response(cmd_result.response), "value");
+        free_command_response(cmd_result.response);
+
+        // Without the codec, the stored value is the encoded one.
+        assert!(set_value_codec(
+            client_ptr,
+            None,
+            None,
+            0,
+            std::ptr::null(),
+            0
+        ));
+        let cmd_result = execute_command(client_ptr, 3, key, 1, RequestType::Get).unwrap();
+        assert!(cmd_result.command_error.is_null());
+        assert_eq!(get_sync_response(cmd_result.response), "rehto");
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+#[test]
+fn test_value_codec_multi_key_commands() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) =
+            create_test_client(&connection_request_bytes, ClientType::SyncClient);
+
+        // Commands whose values can't be encoded are rejected.
+        let request_types = [RequestType::MSet, RequestType::Append];
+        assert!(!set_value_codec(
+            client_ptr,
+            Some(reverse_value),
+            Some(reverse_value),
+            0,
+            request_types.as_ptr(),
+            request_types.len(),
+        ));
+        let request_types = [RequestType::MSet, RequestType::MGet];
+        assert!(set_value_codec(
+            client_ptr,
+            Some(reverse_value),
+            Some(reverse_value),
+            0,
+            request_types.as_ptr(),
+            request_types.len(),
+        ));
+
+        let mset_args: [&[u8]; 4] = [b"codec_key1", b"value1", b"codec_key2", b"value2"];
+        let args = mset_args.map(|arg| arg.as_ptr() as usize);
+        let args_len = mset_args.map(|arg| arg.len() as c_ulong);
+        let result = command(
+            client_ptr,
+            0,
+            RequestType::MSet,
+            4,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        free_command_response(cmd_result.response);
+
+        let mget_args: [&[u8]; 3] = [b"codec_key1", b"codec_key2", b"codec_missing"];
+        let args = mget_args.map(|arg| arg.as_ptr() as usize);
+        let args_len = mget_args.map(|arg| arg.len() as c_ulong);
+        let result = command(
+            client_ptr,
+            1,
+            RequestType::MGet,
+            3,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        let cmd_result = Box::from_raw(result);
+        assert!(cmd_result.command_error.is_null());
+        let values = &*cmd_result.response;
+        assert!(matches!(values.response_type, ResponseType::Array));
+        let values =
+            std::slice::from_raw_parts(values.array_value, values.array_value_len as usize);
+        assert_eq!(parse_string_res(&values[0]), "value1");
+        assert_eq!(parse_string_res(&values[1]), "value2");
+        assert!(matches!(values[2].response_type, ResponseType::Null));
+        free_command_response(cmd_result.response);
+
+        // GET isn't handled, so it returns the encoded value.
+        let cmd_result =
+            execute_command(client_ptr, 2, b"codec_key1", 1, RequestType::Get).unwrap();
+        assert!(cmd_result.command_error.is_null());
+        assert_eq!(get_sync_response(cmd_result.response), "1eulav");
+        free_command_response(cmd_result.response);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
+
+static RECONFIGURED_CLIENT_RESPONSES: AtomicUsize = AtomicUsize::new(0);
+static RECONFIGURED_CLIENT_FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+extern "C-unwind" fn reconfigured_client_success_callback(
+    _index: usize,
+    _response_ptr: *const CommandResponse,
+) {
+    RECONFIGURED_CLIENT_RESPONSES.fetch_add(1, Ordering::SeqCst);
+}
+
+extern "C-unwind" fn reconfigured_client_failure_callback(
+    _index: usize,
+    _err_msg_ptr: *const c_char,
+    _error_type: RequestErrorType,
+) {
+    RECONFIGURED_CLIENT_FAILURES.fetch_add(1, Ordering::SeqCst);
+}
+
+fn wait_for_reconfigured_client_responses(count: usize) {
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while RECONFIGURED_CLIENT_RESPONSES.load(Ordering::SeqCst)
+        + RECONFIGURED_CLIENT_FAILURES.load(Ordering::SeqCst)
+        < count
+    {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "Timed out waiting for responses"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(RECONFIGURED_CLIENT_FAILURES.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_reconfigure_client_keeps_command_coalescing() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+
+    unsafe {
+        let (response_ptr, client_ptr) = create_test_client(
+            &connection_request_bytes,
+            ClientType::AsyncClient {
+                success_callback: reconfigured_client_success_callback,
+                failure_callback: reconfigured_client_failure_callback,
+            },
+        );
+        set_command_coalescing(client_ptr, true, 0, 0);
+
+        let key = b"reconfigured_key";
+        let value = b"value";
+        let args = [key.as_ptr() as usize, value.as_ptr() as usize];
+        let args_len = [key.len() as c_ulong, value.len() as c_ulong];
+        let result = command(
+            client_ptr,
+            0,
+            RequestType::Set,
+            2,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        assert!(result.is_null());
+        wait_for_reconfigured_client_responses(1);
+        assert_eq!(
+            get_command_coalescing_statistics(client_ptr).commands_count,
+            1
+        );
+
+        let result = reconfigure_client(
+            client_ptr,
+            1,
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+        );
+        assert!(result.is_null());
+        wait_for_reconfigured_client_responses(2);
+
+        // The commands of the new client are still coalesced, and counted with the previous ones.
+        let result = command(
+            client_ptr,
+            2,
+            RequestType::Get,
+            1,
+            args.as_ptr(),
+            args_len.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        );
+        assert!(result.is_null());
+        wait_for_reconfigured_client_responses(3);
+        let statistics = get_command_coalescing_statistics(client_ptr);
+        assert_eq!(statistics.commands_count, 2);
+        assert_eq!(statistics.batches_count, 2);
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+    }
+}
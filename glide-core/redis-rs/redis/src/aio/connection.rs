@@ -11,7 +11,9 @@ use crate::connection::{
 use crate::parser::ValueCodec;
 use crate::pipeline::PipelineRetryStrategy;
 use crate::types::{ErrorKind, FromRedisValue, RedisError, RedisFuture, RedisResult, Value};
-use crate::{from_owned_redis_value, ProtocolVersion, ToRedisArgs};
+use crate::{
+    from_owned_redis_value, ProtocolVersion, ProxyConfig, TcpKeepaliveConfig, ToRedisArgs,
+};
 use ::tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 #[cfg(feature = "tokio-comp")]
 use ::tokio::net::lookup_host;
@@ -66,7 +68,7 @@ where
             pubsub: false,
             protocol: connection_info.protocol,
         };
-        setup_connection(connection_info, &mut rv, false).await?;
+        rv.protocol = setup_connection(connection_info, &mut rv, false).await?;
         Ok(rv)
     }
 
@@ -405,6 +407,33 @@ pub(crate) async fn get_socket_addrs(
     }
 }
 
+/// Connects to the node through `proxy`. The node's host name is resolved by the proxy, so the IP address of the node
+/// isn't known.
+async fn connect_via_proxy<T: RedisRuntime>(
+    connection_info: &ConnectionInfo,
+    proxy: &ProxyConfig,
+    tcp_nodelay: bool,
+    tcp_keepalive: TcpKeepaliveConfig,
+) -> RedisResult<(T, Option<IpAddr>)> {
+    let proxy_addrs = get_socket_addrs(&proxy.host, proxy.port).await?;
+    log_conn_creation(
+        "proxied",
+        format!("{} via {}:{}", connection_info.addr, proxy.host, proxy.port),
+        None,
+    );
+    let (conn, _) = select_ok(proxy_addrs.map(|proxy_addr| {
+        <T>::connect_tcp_via_proxy(
+            proxy_addr,
+            proxy,
+            &connection_info.addr,
+            tcp_nodelay,
+            tcp_keepalive,
+        )
+    }))
+    .await?;
+    Ok((conn, None))
+}
+
 /// Logs the creation of a connection, including its type, the node, and optionally its IP address.
 fn log_conn_creation<T>(conn_type: &str, node: T, ip: Option<IpAddr>)
 where
@@ -420,12 +449,19 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
     connection_info: &ConnectionInfo,
     _socket_addr: Option<SocketAddr>,
     tcp_nodelay: bool,
+    tcp_keepalive: TcpKeepaliveConfig,
+    proxy: Option<&ProxyConfig>,
 ) -> RedisResult<(T, Option<IpAddr>)> {
+    if let Some(proxy) = proxy {
+        if !matches!(connection_info.addr, ConnectionAddr::Unix(_)) {
+            return connect_via_proxy(connection_info, proxy, tcp_nodelay, tcp_keepalive).await;
+        }
+    }
     Ok(match connection_info.addr {
         ConnectionAddr::Tcp(ref host, port) => {
             if let Some(socket_addr) = _socket_addr {
                 return Ok::<_, RedisError>((
-                    <T>::connect_tcp(socket_addr, tcp_nodelay).await?,
+                    <T>::connect_tcp(socket_addr, tcp_nodelay, tcp_keepalive).await?,
                     Some(socket_addr.ip()),
                 ));
             }
@@ -434,7 +470,7 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
                 log_conn_creation("TCP", format!("{host}:{port}"), Some(socket_addr.ip()));
                 Box::pin(async move {
                     Ok::<_, RedisError>((
-                        <T>::connect_tcp(socket_addr, tcp_nodelay).await?,
+                        <T>::connect_tcp(socket_addr, tcp_nodelay, tcp_keepalive).await?,
                         Some(socket_addr.ip()),
                     ))
                 })
@@ -451,8 +487,15 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
         } => {
             if let Some(socket_addr) = _socket_addr {
                 return Ok::<_, RedisError>((
-                    <T>::connect_tcp_tls(host, socket_addr, insecure, tls_params, tcp_nodelay)
-                        .await?,
+                    <T>::connect_tcp_tls(
+                        host,
+                        socket_addr,
+                        insecure,
+                        tls_params,
+                        tcp_nodelay,
+                        tcp_keepalive,
+                    )
+                    .await?,
                     Some(socket_addr.ip()),
                 ));
             }
@@ -465,8 +508,15 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
                 );
                 Box::pin(async move {
                     Ok::<_, RedisError>((
-                        <T>::connect_tcp_tls(host, socket_addr, insecure, tls_params, tcp_nodelay)
-                            .await?,
+                        <T>::connect_tcp_tls(
+                            host,
+                            socket_addr,
+                            insecure,
+                            tls_params,
+                            tcp_nodelay,
+                            tcp_keepalive,
+                        )
+                        .await?,
                         Some(socket_addr.ip()),
                     ))
                 })
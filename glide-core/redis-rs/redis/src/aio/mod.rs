@@ -15,7 +15,9 @@ use std::path::Path;
 use std::pin::Pin;
 use std::time::Duration;
 
+use crate::connection::ConnectionAddr;
 use crate::tls::TlsConnParams;
+use crate::{ProxyConfig, TcpKeepaliveConfig};
 
 /// Enables the tokio compatibility
 #[cfg(feature = "tokio-comp")]
@@ -26,7 +28,11 @@ pub mod tokio;
 #[async_trait]
 pub(crate) trait RedisRuntime: AsyncStream + Send + Sync + Sized + 'static {
     /// Performs a TCP connection
-    async fn connect_tcp(socket_addr: SocketAddr, tcp_nodelay: bool) -> RedisResult<Self>;
+    async fn connect_tcp(
+        socket_addr: SocketAddr,
+        tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
+    ) -> RedisResult<Self>;
 
     // Performs a TCP TLS connection
     async fn connect_tcp_tls(
@@ -35,6 +41,17 @@ pub(crate) trait RedisRuntime: AsyncStream + Send + Sync + Sized + 'static {
         insecure: bool,
         tls_params: &Option<TlsConnParams>,
         tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
+    ) -> RedisResult<Self>;
+
+    /// Performs a TCP connection, with TLS if `addr` requires it, to the node at `addr` through the proxy at
+    /// `proxy_addr`
+    async fn connect_tcp_via_proxy(
+        proxy_addr: SocketAddr,
+        proxy: &ProxyConfig,
+        addr: &ConnectionAddr,
+        tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
     ) -> RedisResult<Self>;
 
     /// Performs a UNIX connection
@@ -144,23 +161,34 @@ where
 }
 
 // Initial setup for every connection.
+// Returns the protocol of the connection, which is RESP2 if the connection fell back from RESP3.
 async fn setup_connection<C>(
     connection_info: &RedisConnectionInfo,
     con: &mut C,
     // This parameter is set to 'true' if ReadFromReplica strategy is set to AZAffinity or AZAffinityReplicasAndPrimary.
     // An INFO command will be triggered in the connection's setup to update the 'availability_zone' property.
     discover_az: bool,
-) -> RedisResult<()>
+) -> RedisResult<ProtocolVersion>
 where
     C: ConnectionLike,
 {
-    if connection_info.protocol != ProtocolVersion::RESP2 {
+    let mut protocol = connection_info.protocol;
+    if protocol != ProtocolVersion::RESP2 {
         let hello_cmd = resp3_hello(connection_info);
         let val: RedisResult<Value> = hello_cmd.query_async(con).await;
         if let Err(err) = val {
-            return Err(get_resp3_hello_command_error(err));
+            let err = get_resp3_hello_command_error(err);
+            if !connection_info.resp3_fallback || err.kind() != ErrorKind::RESP3NotSupported {
+                return Err(err);
+            }
+            protocol = ProtocolVersion::RESP2;
         }
-    } else if let Some(password) = &connection_info.password {
+    }
+    let password = connection_info
+        .password
+        .as_ref()
+        .filter(|_| protocol == ProtocolVersion::RESP2);
+    if let Some(password) = password {
         let mut command = cmd("AUTH");
         if let Some(username) = &connection_info.username {
             command.arg(username);
@@ -225,6 +253,16 @@ where
         }
     }
 
+    for protection_cmd in crate::connection::client_protection_cmds(connection_info) {
+        match protection_cmd.query_async(con).await {
+            Ok(Value::Okay) => {}
+            _ => fail!((
+                ErrorKind::ResponseError,
+                "Redis server refused to set client eviction or touch mode"
+            )),
+        }
+    }
+
     if discover_az {
         update_az_from_info(con).await?;
     }
@@ -235,11 +273,12 @@ where
         crate::connection::client_set_info_pipeline(connection_info.lib_name.as_deref())
             .query_async(con)
             .await;
-    Ok(())
+    Ok(protocol)
 }
 
 mod connection;
 pub use connection::*;
+mod proxy;
 mod multiplexed_connection;
 pub use multiplexed_connection::*;
 #[cfg(feature = "connection-manager")]
@@ -1,14 +1,14 @@
 use super::{ConnectionLike, Runtime};
 use crate::aio::setup_connection;
 use crate::aio::DisconnectNotifier;
-use crate::client::GlideConnectionOptions;
+use crate::client::{GlideConnectionOptions, SocketTimeouts};
 use crate::cmd::Cmd;
 #[cfg(feature = "tokio-comp")]
 use crate::parser::ValueCodec;
 use crate::pipeline::PipelineRetryStrategy;
 use crate::push_manager::PushManager;
 use crate::types::{RedisError, RedisFuture, RedisResult, Value};
-use crate::{cmd, ConnectionInfo, ProtocolVersion, PushKind};
+use crate::{cmd, ConnectionEvent, ConnectionInfo, ProtocolVersion, PushKind};
 use ::tokio::{
     io::{AsyncRead, AsyncWrite},
     sync::{mpsc, oneshot},
@@ -20,7 +20,7 @@ use futures_util::{
     sink::Sink,
     stream::{self, Stream, StreamExt, TryStreamExt as _},
 };
-use logger_core::log_error;
+use logger_core::{log_error, log_warn};
 use pin_project_lite::pin_project;
 use std::collections::VecDeque;
 use std::fmt;
@@ -112,6 +112,11 @@ pin_project! {
         disconnect_notifier: Option<Box<dyn DisconnectNotifier>>,
         is_stream_closed: Arc<AtomicBool>,
         response_sync_lost: bool,
+        socket_timeouts: SocketTimeouts,
+        // Started when a read or a flush becomes pending, and cleared once a complete response is read or the flush
+        // completes.
+        read_deadline: Option<Pin<Box<::tokio::time::Sleep>>>,
+        write_deadline: Option<Pin<Box<::tokio::time::Sleep>>>,
     }
 
         impl<T> PinnedDrop for PipelineSink<T> {
@@ -139,6 +144,7 @@ where
         push_manager: Arc<ArcSwap<PushManager>>,
         disconnect_notifier: Option<Box<dyn DisconnectNotifier>>,
         is_stream_closed: Arc<AtomicBool>,
+        socket_timeouts: SocketTimeouts,
     ) -> Self
     where
         T: Sink<SinkItem, Error = RedisError> + Stream<Item = RedisResult<Value>> + 'static,
@@ -151,17 +157,33 @@ where
             disconnect_notifier,
             is_stream_closed,
             response_sync_lost: false,
+            socket_timeouts,
+            read_deadline: None,
+            write_deadline: None,
         }
     }
 
     // Read messages from the stream and send them back to the caller
     fn poll_read(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Result<(), ()>> {
         loop {
-            let item = match ready!(self.as_mut().project().sink_stream.poll_next(cx)) {
-                Some(result) => result,
+            let item = match self.as_mut().project().sink_stream.poll_next(cx) {
+                Poll::Ready(Some(result)) => result,
+                // A read only times out while responses are pending, since an idle connection receives no data.
+                Poll::Pending => {
+                    let self_ = self.as_mut().project();
+                    let read_timeout = match self_.socket_timeouts.read_timeout {
+                        Some(read_timeout) if !self_.in_flight.is_empty() => read_timeout,
+                        _ => {
+                            *self_.read_deadline = None;
+                            return Poll::Pending;
+                        }
+                    };
+                    ready!(poll_deadline(self_.read_deadline, read_timeout, cx));
+                    return self.fail_timed_out_socket("Socket read timed out");
+                }
                 // The redis response stream is not going to produce any more items so we `Err`
                 // to break out of the `forward` combinator and stop handling requests
-                None => {
+                Poll::Ready(None) => {
                     // this is the right place to notify about the passive TCP disconnect
                     // In other places we cannot distinguish between the active destruction of MultiplexedConnection and passive disconnect
                     if let Some(disconnect_notifier) = self.as_mut().project().disconnect_notifier {
@@ -171,10 +193,32 @@ where
                     return Poll::Ready(Err(()));
                 }
             };
+            *self.as_mut().project().read_deadline = None;
             self.as_mut().send_result(item);
         }
     }
 
+    // Fails the requests waiting for responses and closes the connection, after a socket read or write timed out.
+    fn fail_timed_out_socket(
+        mut self: Pin<&mut Self>,
+        description: &'static str,
+    ) -> Poll<Result<(), ()>> {
+        let self_ = self.as_mut().project();
+        for entry in self_.in_flight.drain(..) {
+            let err = RedisError::from(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                description,
+            ));
+            entry.output.send(Err(err)).ok();
+        }
+        log_error("socket timeout", description);
+        if let Some(disconnect_notifier) = self_.disconnect_notifier {
+            disconnect_notifier.notify_disconnect();
+        }
+        self_.is_stream_closed.store(true, Ordering::Relaxed);
+        Poll::Ready(Err(()))
+    }
+
     fn send_result(self: Pin<&mut Self>, result: RedisResult<Value>) {
         let self_ = self.project();
 
@@ -355,6 +399,18 @@ where
     }
 }
 
+// Polls the timer of a pending socket operation, starting it on the first poll.
+fn poll_deadline(
+    deadline: &mut Option<Pin<Box<::tokio::time::Sleep>>>,
+    timeout: Duration,
+    cx: &mut task::Context,
+) -> Poll<()> {
+    deadline
+        .get_or_insert_with(|| Box::pin(::tokio::time::sleep(timeout)))
+        .as_mut()
+        .poll(cx)
+}
+
 impl<SinkItem, T> Sink<PipelineMessage<SinkItem>> for PipelineSink<T>
 where
     T: Sink<SinkItem, Error = RedisError> + Stream<Item = RedisResult<Value>> + 'static,
@@ -433,14 +489,19 @@ where
         mut self: Pin<&mut Self>,
         cx: &mut task::Context,
     ) -> Poll<Result<(), Self::Error>> {
-        ready!(self
-            .as_mut()
-            .project()
-            .sink_stream
-            .poll_flush(cx)
-            .map_err(|err| {
-                self.as_mut().send_result(Err(err));
-            }))?;
+        let flushed = self.as_mut().project().sink_stream.poll_flush(cx);
+        if flushed.is_pending() {
+            let self_ = self.as_mut().project();
+            if let Some(write_timeout) = self_.socket_timeouts.write_timeout {
+                ready!(poll_deadline(self_.write_deadline, write_timeout, cx));
+                return self.fail_timed_out_socket("Socket write timed out");
+            }
+            return Poll::Pending;
+        }
+        *self.as_mut().project().write_deadline = None;
+        ready!(flushed.map_err(|err| {
+            self.as_mut().send_result(Err(err));
+        }))?;
         self.poll_read(cx)
     }
 
@@ -467,6 +528,7 @@ where
     fn new<T>(
         sink_stream: T,
         disconnect_notifier: Option<Box<dyn DisconnectNotifier>>,
+        socket_timeouts: SocketTimeouts,
     ) -> (Self, impl Future<Output = ()>)
     where
         T: Sink<SinkItem, Error = RedisError> + Stream<Item = RedisResult<Value>> + 'static,
@@ -485,6 +547,7 @@ where
             push_manager.clone(),
             disconnect_notifier,
             is_stream_closed.clone(),
+            socket_timeouts,
         );
         let f = stream::poll_fn(move |cx| receiver.poll_recv(cx))
             .map(Ok)
@@ -575,6 +638,8 @@ pub struct MultiplexedConnection {
     db: i64,
     response_timeout: Duration,
     protocol: ProtocolVersion,
+    // Set when the server rejected RESP3 and the connection fell back to RESP2, which can't deliver pubsub messages.
+    fell_back_to_resp2: bool,
     push_manager: PushManager,
     availability_zone: Option<String>,
     password: Option<String>,
@@ -623,8 +688,11 @@ impl MultiplexedConnection {
         let codec = ValueCodec::default()
             .framed(stream)
             .and_then(|msg| async move { msg });
-        let (mut pipeline, driver) =
-            Pipeline::new(codec, glide_connection_options.disconnect_notifier);
+        let (mut pipeline, driver) = Pipeline::new(
+            codec,
+            glide_connection_options.disconnect_notifier,
+            glide_connection_options.socket_timeouts,
+        );
         let driver = Box::pin(driver);
         let pm = PushManager::new(
             glide_connection_options.push_sender,
@@ -644,7 +712,7 @@ impl MultiplexedConnection {
             .build()
             .await?;
 
-        let driver = {
+        let (driver, protocol) = {
             let auth = setup_connection(
                 &connection_info.redis,
                 &mut con,
@@ -654,10 +722,7 @@ impl MultiplexedConnection {
             futures_util::pin_mut!(auth);
 
             match futures_util::future::select(auth, driver).await {
-                futures_util::future::Either::Left((result, driver)) => {
-                    result?;
-                    driver
-                }
+                futures_util::future::Either::Left((result, driver)) => (driver, result?),
                 futures_util::future::Either::Right(((), _)) => {
                     return Err(RedisError::from((
                         crate::ErrorKind::IoError,
@@ -667,6 +732,20 @@ impl MultiplexedConnection {
             }
         };
 
+        if protocol != con.protocol {
+            // The connection fell back to RESP2, so the RESP3-only features, such as push notifications, are disabled.
+            con.protocol = protocol;
+            con.fell_back_to_resp2 = true;
+            let address = connection_info.addr.to_string();
+            log_warn(
+                "MultiplexedConnection::new",
+                format!("{address} rejected RESP3, the connection fell back to RESP2"),
+            );
+            if let Some(listener) = &glide_connection_options.connection_event_listener {
+                listener.on_connection_event(ConnectionEvent::ProtocolDowngraded, &address, None);
+            }
+        }
+
         Ok((con, driver))
     }
 
@@ -678,6 +757,14 @@ impl MultiplexedConnection {
     /// Sends an already encoded (packed) command into the TCP socket and
     /// reads the single response from it.
     pub async fn send_packed_command(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+        // On RESP2 the replies of the subscriptions would be interleaved with the replies of the other commands
+        // multiplexed on the connection, so they're rejected instead.
+        if self.fell_back_to_resp2 && is_subscription_command(cmd) {
+            return Err(RedisError::from((
+                crate::ErrorKind::ClientError,
+                "Pubsub isn't available on a connection that fell back to RESP2",
+            )));
+        }
         let result = self
             .pipeline
             .send_single(
@@ -854,6 +941,7 @@ impl MultiplexedConnectionBuilder {
             response_timeout,
             push_manager,
             protocol,
+            fell_back_to_resp2: false,
             password,
             availability_zone: self.availability_zone,
         };
@@ -862,6 +950,21 @@ impl MultiplexedConnectionBuilder {
     }
 }
 
+fn is_subscription_command(cmd: &Cmd) -> bool {
+    cmd.arg_idx(0).is_some_and(|name| {
+        [
+            "SUBSCRIBE",
+            "PSUBSCRIBE",
+            "SSUBSCRIBE",
+            "UNSUBSCRIBE",
+            "PUNSUBSCRIBE",
+            "SUNSUBSCRIBE",
+        ]
+        .iter()
+        .any(|command| name.eq_ignore_ascii_case(command.as_bytes()))
+    })
+}
+
 impl ConnectionLike for MultiplexedConnection {
     fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
         (async move { self.send_packed_command(cmd).await }).boxed()
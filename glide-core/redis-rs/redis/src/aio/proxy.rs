@@ -0,0 +1,354 @@
+//! The handshakes that open a tunnel to a node through a [`ProxyConfig`], on a stream connected to the proxy.
+
+use crate::types::{ErrorKind, RedisError, RedisResult};
+use crate::{ProxyConfig, ProxyType};
+use std::net::IpAddr;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+const SOCKS_VERSION: u8 = 5;
+const SOCKS_NO_AUTHENTICATION: u8 = 0;
+const SOCKS_USERNAME_PASSWORD: u8 = 2;
+const SOCKS_NO_ACCEPTABLE_METHOD: u8 = 0xff;
+const SOCKS_USERNAME_PASSWORD_VERSION: u8 = 1;
+const SOCKS_CONNECT: u8 = 1;
+const SOCKS_IPV4: u8 = 1;
+const SOCKS_DOMAIN_NAME: u8 = 3;
+const SOCKS_IPV6: u8 = 4;
+/// The longest response header accepted from an HTTP proxy, so a misbehaving proxy can't exhaust the memory.
+const MAX_HTTP_RESPONSE_HEADER_LEN: usize = 8 * 1024;
+
+fn proxy_error(description: &'static str, detail: String) -> RedisError {
+    RedisError::from((ErrorKind::IoError, description, detail))
+}
+
+/// Opens a tunnel to `host:port` through `proxy`, on `stream`, which is connected to the proxy. Once this returns,
+/// the stream is connected to the node.
+pub(crate) async fn open_tunnel<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> RedisResult<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    match proxy.proxy_type {
+        ProxyType::Socks5 => socks5_connect(stream, proxy, host, port).await,
+        ProxyType::HttpConnect => http_connect(stream, proxy, host, port).await,
+    }
+}
+
+fn credentials(proxy: &ProxyConfig) -> Option<(&str, &str)> {
+    match (&proxy.username, &proxy.password) {
+        (Some(username), password) => {
+            Some((username.as_str(), password.as_deref().unwrap_or_default()))
+        }
+        (None, _) => None,
+    }
+}
+
+async fn socks5_connect<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> RedisResult<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let credentials = credentials(proxy);
+    let greeting: &[u8] = match credentials {
+        Some(_) => &[
+            SOCKS_VERSION,
+            2,
+            SOCKS_NO_AUTHENTICATION,
+            SOCKS_USERNAME_PASSWORD,
+        ],
+        None => &[SOCKS_VERSION, 1, SOCKS_NO_AUTHENTICATION],
+    };
+    stream.write_all(greeting).await?;
+    let mut method = [0u8; 2];
+    stream.read_exact(&mut method).await?;
+    match (method, credentials) {
+        ([SOCKS_VERSION, SOCKS_NO_AUTHENTICATION], _) => {}
+        ([SOCKS_VERSION, SOCKS_USERNAME_PASSWORD], Some((username, password))) => {
+            let (username, password) = (username.as_bytes(), password.as_bytes());
+            if username.len() > 255 || password.len() > 255 {
+                return Err(RedisError::from((
+                    ErrorKind::InvalidClientConfig,
+                    "SOCKS5 proxy credentials are longer than 255 bytes",
+                )));
+            }
+            let mut request = vec![SOCKS_USERNAME_PASSWORD_VERSION, username.len() as u8];
+            request.extend_from_slice(username);
+            request.push(password.len() as u8);
+            request.extend_from_slice(password);
+            stream.write_all(&request).await?;
+            let mut status = [0u8; 2];
+            stream.read_exact(&mut status).await?;
+            if status[1] != 0 {
+                return Err(RedisError::from((
+                    ErrorKind::AuthenticationFailed,
+                    "SOCKS5 proxy rejected the credentials",
+                )));
+            }
+        }
+        ([SOCKS_VERSION, SOCKS_NO_ACCEPTABLE_METHOD], _) => {
+            return Err(RedisError::from((
+                ErrorKind::AuthenticationFailed,
+                "SOCKS5 proxy requires an authentication method that isn't configured",
+            )));
+        }
+        (method, _) => {
+            return Err(proxy_error(
+                "Unexpected SOCKS5 proxy response",
+                format!("method selection {method:?}"),
+            ));
+        }
+    }
+
+    let mut request = vec![SOCKS_VERSION, SOCKS_CONNECT, 0];
+    match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(SOCKS_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(SOCKS_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            if host.len() > 255 {
+                return Err(RedisError::from((
+                    ErrorKind::InvalidClientConfig,
+                    "Host names longer than 255 bytes can't be reached through a SOCKS5 proxy",
+                )));
+            }
+            request.push(SOCKS_DOMAIN_NAME);
+            request.push(host.len() as u8);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != SOCKS_VERSION || reply[1] != 0 {
+        return Err(proxy_error(
+            "SOCKS5 proxy failed to connect to the node",
+            format!("{host}:{port} (reply code {})", reply[1]),
+        ));
+    }
+    // The address the proxy bound the tunnel to is skipped, with the port that follows it.
+    let bound_address_len = match reply[3] {
+        SOCKS_IPV4 => 4,
+        SOCKS_IPV6 => 16,
+        SOCKS_DOMAIN_NAME => stream.read_u8().await? as usize,
+        address_type => {
+            return Err(proxy_error(
+                "Unexpected SOCKS5 proxy response",
+                format!("address type {address_type}"),
+            ));
+        }
+    };
+    let mut bound_address = vec![0u8; bound_address_len + 2];
+    stream.read_exact(&mut bound_address).await?;
+    Ok(())
+}
+
+async fn http_connect<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> RedisResult<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let authority = if host.contains(':') && !host.starts_with('[') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((username, password)) = credentials(proxy) {
+        let token = base64_encode(format!("{username}:{password}").as_bytes());
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // The header is read a byte at a time, so the data the node sends after it is left in the stream.
+    let mut header = Vec::new();
+    while !header.ends_with(b"\r\n\r\n") {
+        if header.len() >= MAX_HTTP_RESPONSE_HEADER_LEN {
+            return Err(proxy_error(
+                "Unexpected HTTP proxy response",
+                "the response header is too long".to_string(),
+            ));
+        }
+        header.push(stream.read_u8().await?);
+    }
+    let header = String::from_utf8_lossy(&header);
+    let status_line = header.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        Some("407") => Err(RedisError::from((
+            ErrorKind::AuthenticationFailed,
+            "HTTP proxy rejected the credentials",
+            status_line.to_string(),
+        ))),
+        _ => Err(proxy_error(
+            "HTTP proxy failed to connect to the node",
+            format!("{authority} ({status_line})"),
+        )),
+    }
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[((group >> (18 - 6 * index)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    fn proxy(proxy_type: ProxyType, username: Option<&str>) -> ProxyConfig {
+        ProxyConfig {
+            proxy_type,
+            host: "bastion".to_string(),
+            port: 1080,
+            username: username.map(str::to_string),
+            password: username.map(|_| "secret".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b"user:secret"), "dXNlcjpzZWNyZXQ=");
+        assert_eq!(base64_encode(b"ab"), "YWI=");
+        assert_eq!(base64_encode(b"abc"), "YWJj");
+        assert_eq!(base64_encode(b""), "");
+    }
+
+    #[tokio::test]
+    async fn test_socks5_tunnel_with_credentials() {
+        let (mut client, mut server) = duplex(1024);
+        let server = tokio::spawn(async move {
+            let mut greeting = [0u8; 4];
+            server.read_exact(&mut greeting).await.unwrap();
+            assert_eq!(greeting, [5, 2, 0, 2]);
+            server.write_all(&[5, 2]).await.unwrap();
+
+            let mut authentication = [0u8; 13];
+            server.read_exact(&mut authentication).await.unwrap();
+            assert_eq!(&authentication, b"\x01\x04user\x06secret");
+            server.write_all(&[1, 0]).await.unwrap();
+
+            let mut request = [0u8; 19];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(&request, b"\x05\x01\x00\x03\x0cnode.example\x18\xeb");
+            server
+                .write_all(&[5, 0, 0, 1, 10, 0, 0, 1, 0x04, 0x38, b'+'])
+                .await
+                .unwrap();
+        });
+
+        let proxy = proxy(ProxyType::Socks5, Some("user"));
+        open_tunnel(&mut client, &proxy, "node.example", 6379)
+            .await
+            .unwrap();
+        // The data the node sent after the reply is left in the stream.
+        assert_eq!(client.read_u8().await.unwrap(), b'+');
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_socks5_connect_failure() {
+        let (mut client, mut server) = duplex(1024);
+        tokio::spawn(async move {
+            let mut greeting = [0u8; 3];
+            server.read_exact(&mut greeting).await.unwrap();
+            server.write_all(&[5, 0]).await.unwrap();
+            let mut request = [0u8; 10];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(request[3..8], [1, 10, 0, 0, 2]);
+            // Host unreachable
+            server
+                .write_all(&[5, 4, 0, 1, 0, 0, 0, 0, 0, 0])
+                .await
+                .unwrap();
+        });
+
+        let proxy = proxy(ProxyType::Socks5, None);
+        let err = open_tunnel(&mut client, &proxy, "10.0.0.2", 6379)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IoError);
+    }
+
+    #[tokio::test]
+    async fn test_http_connect_tunnel() {
+        let (mut client, mut server) = duplex(1024);
+        let server = tokio::spawn(async move {
+            let expected = "CONNECT node.example:6379 HTTP/1.1\r\nHost: node.example:6379\r\n\
+                            Proxy-Authorization: Basic dXNlcjpzZWNyZXQ=\r\n\r\n";
+            let mut request = vec![0u8; expected.len()];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(String::from_utf8(request).unwrap(), expected);
+            server
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n+")
+                .await
+                .unwrap();
+        });
+
+        let proxy = proxy(ProxyType::HttpConnect, Some("user"));
+        open_tunnel(&mut client, &proxy, "node.example", 6379)
+            .await
+            .unwrap();
+        assert_eq!(client.read_u8().await.unwrap(), b'+');
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_http_connect_rejected_credentials() {
+        let (mut client, mut server) = duplex(1024);
+        tokio::spawn(async move {
+            let mut request = [0u8; 64];
+            let _ = server.read(&mut request).await.unwrap();
+            server
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let proxy = proxy(ProxyType::HttpConnect, None);
+        let err = open_tunnel(&mut client, &proxy, "node.example", 6379)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AuthenticationFailed);
+    }
+}
@@ -15,27 +15,60 @@ use tokio::{
     net::TcpStream as TcpStreamTokio,
 };
 
-use crate::connection::create_rustls_config;
+use super::proxy::open_tunnel;
+use crate::connection::{create_rustls_config, ConnectionAddr};
+use crate::types::{ErrorKind, RedisError};
 use std::sync::Arc;
 use tokio_rustls::{client::TlsStream, TlsConnector};
 
 use crate::tls::TlsConnParams;
+use crate::{ProxyConfig, TcpKeepaliveConfig};
 
 #[cfg(unix)]
 use super::Path;
 
 #[inline(always)]
-async fn connect_tcp(addr: &SocketAddr, tcp_nodelay: bool) -> io::Result<TcpStreamTokio> {
+async fn connect_tcp(
+    addr: &SocketAddr,
+    tcp_nodelay: bool,
+    tcp_keepalive: TcpKeepaliveConfig,
+) -> io::Result<TcpStreamTokio> {
     let socket = TcpStreamTokio::connect(addr).await?;
     socket.set_nodelay(tcp_nodelay)?;
     #[cfg(feature = "keep-alive")]
     {
-        //For now rely on system defaults
-        const KEEP_ALIVE: socket2::TcpKeepalive = socket2::TcpKeepalive::new();
+        // Settings that aren't configured use the system defaults
+        let mut keep_alive = socket2::TcpKeepalive::new();
+        if let Some(idle) = tcp_keepalive.idle {
+            keep_alive = keep_alive.with_time(idle);
+        }
+        #[cfg(any(
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            windows
+        ))]
+        if let Some(interval) = tcp_keepalive.interval {
+            keep_alive = keep_alive.with_interval(interval);
+        }
+        #[cfg(any(
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd"
+        ))]
+        if let Some(retries) = tcp_keepalive.retries {
+            keep_alive = keep_alive.with_retries(retries);
+        }
         //these are useless error that not going to happen
         let std_socket = socket.into_std()?;
         let socket2: socket2::Socket = std_socket.into();
-        socket2.set_tcp_keepalive(&KEEP_ALIVE)?;
+        socket2.set_tcp_keepalive(&keep_alive)?;
         // TCP_USER_TIMEOUT configuration isn't supported across all operation systems
         #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
         {
@@ -48,6 +81,7 @@ async fn connect_tcp(addr: &SocketAddr, tcp_nodelay: bool) -> io::Result<TcpStre
 
     #[cfg(not(feature = "keep-alive"))]
     {
+        let _ = tcp_keepalive;
         Ok(socket)
     }
 }
@@ -112,8 +146,12 @@ impl AsyncRead for Tokio {
 
 #[async_trait]
 impl RedisRuntime for Tokio {
-    async fn connect_tcp(socket_addr: SocketAddr, tcp_nodelay: bool) -> RedisResult<Self> {
-        Ok(connect_tcp(&socket_addr, tcp_nodelay)
+    async fn connect_tcp(
+        socket_addr: SocketAddr,
+        tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
+    ) -> RedisResult<Self> {
+        Ok(connect_tcp(&socket_addr, tcp_nodelay, tcp_keepalive)
             .await
             .map(Tokio::Tcp)?)
     }
@@ -124,19 +162,65 @@ impl RedisRuntime for Tokio {
         insecure: bool,
         tls_params: &Option<TlsConnParams>,
         tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
     ) -> RedisResult<Self> {
         let config = create_rustls_config(insecure, tls_params.clone())?;
         let tls_connector = TlsConnector::from(Arc::new(config));
 
         Ok(tls_connector
             .connect(
-                rustls_pki_types::ServerName::try_from(hostname)?.to_owned(),
-                connect_tcp(&socket_addr, tcp_nodelay).await?,
+                rustls_pki_types::ServerName::try_from(TlsConnParams::server_name(
+                    tls_params.as_ref(),
+                    hostname,
+                ))?
+                .to_owned(),
+                connect_tcp(&socket_addr, tcp_nodelay, tcp_keepalive).await?,
             )
             .await
             .map(|con| Tokio::TcpTls(Box::new(con)))?)
     }
 
+    async fn connect_tcp_via_proxy(
+        proxy_addr: SocketAddr,
+        proxy: &ProxyConfig,
+        addr: &ConnectionAddr,
+        tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
+    ) -> RedisResult<Self> {
+        let mut socket = connect_tcp(&proxy_addr, tcp_nodelay, tcp_keepalive).await?;
+        match addr {
+            ConnectionAddr::Tcp(host, port) => {
+                open_tunnel(&mut socket, proxy, host, *port).await?;
+                Ok(Tokio::Tcp(socket))
+            }
+            ConnectionAddr::TcpTls {
+                host,
+                port,
+                insecure,
+                tls_params,
+            } => {
+                open_tunnel(&mut socket, proxy, host, *port).await?;
+                let config = create_rustls_config(*insecure, tls_params.clone())?;
+                let tls_connector = TlsConnector::from(Arc::new(config));
+                Ok(tls_connector
+                    .connect(
+                        rustls_pki_types::ServerName::try_from(TlsConnParams::server_name(
+                            tls_params.as_ref(),
+                            host,
+                        ))?
+                        .to_owned(),
+                        socket,
+                    )
+                    .await
+                    .map(|con| Tokio::TcpTls(Box::new(con)))?)
+            }
+            ConnectionAddr::Unix(_) => Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Unix socket connections can't go through a proxy",
+            ))),
+        }
+    }
+
     #[cfg(unix)]
     async fn connect_unix(path: &Path) -> RedisResult<Self> {
         Ok(UnixStreamTokio::connect(path).await.map(Tokio::Unix)?)
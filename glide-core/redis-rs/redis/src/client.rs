@@ -7,17 +7,19 @@ use crate::{
     connection::{connect, Connection, ConnectionInfo, ConnectionLike, IntoConnectionInfo},
     push_manager::PushInfo,
     retry_strategies::RetryStrategy,
-    types::{ProtocolVersion, RedisResult, Value},
+    types::{ErrorKind, ProtocolVersion, RedisResult, Value},
 };
+use std::collections::HashMap;
 #[cfg(feature = "aio")]
 use std::net::IpAddr;
 #[cfg(feature = "aio")]
 use std::net::SocketAddr;
 #[cfg(feature = "aio")]
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc;
 
+use crate::connection_events::ConnectionEventListener;
 use crate::pubsub_synchronizer::PubSubSynchronizer;
 use crate::tls::{inner_build_with_tls, TlsCertificates};
 
@@ -103,6 +105,218 @@ pub struct GlideConnectionOptions {
     pub tcp_nodelay: bool,
     /// Optional PubSub synchronizer for managing subscription state
     pub pubsub_synchronizer: Option<Arc<dyn PubSubSynchronizer>>,
+    /// Optional listener notified when connections are established, lost or reconnecting
+    pub connection_event_listener: Option<Arc<dyn ConnectionEventListener>>,
+    /// Timeouts of the socket reads and writes, which detect an unresponsive peer regardless of the response timeout.
+    pub socket_timeouts: SocketTimeouts,
+    /// TCP keepalive settings of the connection's socket.
+    pub tcp_keepalive: TcpKeepaliveConfig,
+    /// The proxy the connection is tunneled through, if any.
+    pub proxy: Option<ProxyConfig>,
+}
+
+/// Socket-level timeouts of a connection.
+///
+/// A timed out read or write fails the requests that are waiting for responses on the connection, and closes it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SocketTimeouts {
+    /// The longest time to wait for the next complete response from the server while responses are pending.
+    /// The time is measured per response, not per byte, so a large response that arrives slowly may time out.
+    /// If `None`, reads don't time out.
+    pub read_timeout: Option<Duration>,
+    /// The longest time a write to the socket may stay blocked.
+    /// If `None`, writes don't time out.
+    pub write_timeout: Option<Duration>,
+}
+
+/// TCP keepalive settings of a connection. Settings that aren't set use the system defaults.
+///
+/// Keepalive probes are only sent when the `keep-alive` feature is enabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TcpKeepaliveConfig {
+    /// How long the connection stays idle before the first keepalive probe is sent.
+    pub idle: Option<Duration>,
+    /// The time between keepalive probes that aren't acknowledged.
+    pub interval: Option<Duration>,
+    /// The number of unacknowledged keepalive probes after which the connection is dropped.
+    pub retries: Option<u32>,
+}
+
+/// The protocol of a [`ProxyConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProxyType {
+    /// A SOCKS5 proxy, authenticated with a username and password if they're set.
+    #[default]
+    Socks5,
+    /// An HTTP proxy that tunnels the connections with `CONNECT`, authenticated with basic authentication if a
+    /// username and password are set.
+    HttpConnect,
+}
+
+/// A proxy that the TCP connections to the nodes are tunneled through, such as a bastion in front of nodes that
+/// aren't reachable otherwise. The node addresses are resolved by the proxy. TLS connections are established through
+/// the tunnel, so the proxy doesn't see the traffic.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ProxyConfig {
+    pub proxy_type: ProxyType,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The password isn't printed, since the configuration is logged.
+        f.debug_struct("ProxyConfig")
+            .field("proxy_type", &self.proxy_type)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Limits after which the connections of a client are proactively replaced, such as behind load balancers that
+/// silently drop long-lived or idle connections. A connection is replaced once it reaches either limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionRecyclingPolicy {
+    /// The longest time a connection is used for, from when it was established. If `None`, connections don't expire.
+    pub max_age: Option<Duration>,
+    /// The longest time a connection may go without sending a request. If `None`, idle connections are kept.
+    pub idle_timeout: Option<Duration>,
+}
+
+/// Why a connection was recycled by its [`ConnectionRecyclingPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecycleReason {
+    /// The connection reached the maximum connection age.
+    MaxAge,
+    /// The connection wasn't used within the idle timeout.
+    IdleTimeout,
+}
+
+impl RecycleReason {
+    /// Describes the reason in the [`crate::ConnectionEvent::Recycled`] event.
+    pub fn description(self) -> &'static str {
+        match self {
+            RecycleReason::MaxAge => "max age",
+            RecycleReason::IdleTimeout => "idle timeout",
+        }
+    }
+}
+
+impl ConnectionRecyclingPolicy {
+    const MIN_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+    const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+    /// Returns whether either limit is set.
+    pub fn is_enabled(&self) -> bool {
+        self.max_age.is_some() || self.idle_timeout.is_some()
+    }
+
+    /// Returns how often the connections should be checked against the limits, so a connection is recycled shortly
+    /// after reaching them, or `None` if no limit is set.
+    pub fn check_interval(&self) -> Option<Duration> {
+        let shortest = match (self.max_age, self.idle_timeout) {
+            (Some(max_age), Some(idle_timeout)) => max_age.min(idle_timeout),
+            (limit, None) | (None, limit) => limit?,
+        };
+        Some((shortest / 10).clamp(Self::MIN_CHECK_INTERVAL, Self::MAX_CHECK_INTERVAL))
+    }
+
+    /// Returns why a connection that was established `age` ago and last used `idle` ago should be recycled, if it
+    /// should. The maximum age takes precedence.
+    pub fn recycle_reason(&self, age: Duration, idle: Duration) -> Option<RecycleReason> {
+        if self.max_age.is_some_and(|max_age| age >= max_age) {
+            Some(RecycleReason::MaxAge)
+        } else if self
+            .idle_timeout
+            .is_some_and(|idle_timeout| idle >= idle_timeout)
+        {
+            Some(RecycleReason::IdleTimeout)
+        } else {
+            None
+        }
+    }
+}
+
+/// Translates the addresses that the nodes of a cluster announce, such as in `CLUSTER SLOTS` or in `MOVED`
+/// redirections, to the addresses the client dials instead, for nodes that aren't reachable at the addresses they
+/// announce, such as behind NAT or inside containers. Clones share the same table, so replacing it applies to the
+/// connections made afterwards by every clone.
+#[derive(Clone, Debug, Default)]
+pub struct AddressTranslation {
+    table: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl AddressTranslation {
+    /// Creates a translation of the announced `host:port` addresses, as the nodes announce them, to the `host:port`
+    /// addresses to dial. The addresses are validated by [`Self::validate`].
+    pub fn new(table: HashMap<String, String>) -> Self {
+        Self {
+            table: Arc::new(RwLock::new(table)),
+        }
+    }
+
+    /// Replaces the table, unless one of its addresses isn't a valid `host:port` address.
+    pub fn replace(&self, table: HashMap<String, String>) -> RedisResult<()> {
+        Self::validate_table(&table)?;
+        *self.table.write().unwrap_or_else(|err| err.into_inner()) = table;
+        Ok(())
+    }
+
+    /// Fails if one of the addresses of the table isn't a valid `host:port` address.
+    pub fn validate(&self) -> RedisResult<()> {
+        Self::validate_table(&self.table.read().unwrap_or_else(|err| err.into_inner()))
+    }
+
+    /// Returns the address to dial instead of the announced `address`, if it's translated.
+    pub fn translate(&self, address: &str) -> Option<String> {
+        self.table
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(address)
+            .cloned()
+    }
+
+    /// Returns the number of translated addresses.
+    pub fn len(&self) -> usize {
+        self.table
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .len()
+    }
+
+    /// Returns whether no address is translated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn validate_table(table: &HashMap<String, String>) -> RedisResult<()> {
+        let is_valid = |address: &str| {
+            address.rsplit_once(':').is_some_and(|(host, port)| {
+                !host
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .is_empty()
+                    && port.parse::<u16>().is_ok()
+            })
+        };
+        match table
+            .iter()
+            .flat_map(|(announced, reachable)| [announced, reachable])
+            .find(|address| !is_valid(address))
+        {
+            Some(address) => Err((
+                ErrorKind::InvalidClientConfig,
+                "Invalid address translation",
+                format!("`{address}` isn't a host:port address"),
+            )
+                .into()),
+            None => Ok(()),
+        }
+    }
 }
 
 /// To enable async support you need to enable the feature: `tokio-comp`
@@ -125,8 +339,13 @@ impl Client {
                 // Note: tcp_nodelay is hardcoded to true (default) since this deprecated API
                 // doesn't accept GlideConnectionOptions. Modern code should use
                 // get_multiplexed_async_connection which allows configuring tcp_nodelay.
-                self.get_simple_async_connection::<crate::aio::tokio::Tokio>(None, true)
-                    .await?
+                self.get_simple_async_connection::<crate::aio::tokio::Tokio>(
+                    None,
+                    true,
+                    TcpKeepaliveConfig::default(),
+                    None,
+                )
+                .await?
             }
         };
 
@@ -438,7 +657,12 @@ impl Client {
         T: crate::aio::RedisRuntime,
     {
         let (con, ip) = self
-            .get_simple_async_connection::<T>(socket_addr, glide_connection_options.tcp_nodelay)
+            .get_simple_async_connection::<T>(
+                socket_addr,
+                glide_connection_options.tcp_nodelay,
+                glide_connection_options.tcp_keepalive,
+                glide_connection_options.proxy.as_ref(),
+            )
             .await?;
         crate::aio::MultiplexedConnection::new_with_response_timeout(
             &self.connection_info,
@@ -454,6 +678,8 @@ impl Client {
         &self,
         socket_addr: Option<SocketAddr>,
         tcp_nodelay: bool,
+        tcp_keepalive: TcpKeepaliveConfig,
+        proxy: Option<&ProxyConfig>,
     ) -> RedisResult<(
         Pin<Box<dyn crate::aio::AsyncStream + Send + Sync>>,
         Option<IpAddr>,
@@ -461,9 +687,14 @@ impl Client {
     where
         T: crate::aio::RedisRuntime,
     {
-        let (conn, ip) =
-            crate::aio::connect_simple::<T>(&self.connection_info, socket_addr, tcp_nodelay)
-                .await?;
+        let (conn, ip) = crate::aio::connect_simple::<T>(
+            &self.connection_info,
+            socket_addr,
+            tcp_nodelay,
+            tcp_keepalive,
+            proxy,
+        )
+        .await?;
         Ok((conn.boxed(), ip))
     }
 
@@ -694,4 +925,64 @@ mod test {
         // Verify database was updated
         assert_eq!(client.connection_info.redis.db, 1);
     }
+
+    #[test]
+    fn test_connection_recycling_policy() {
+        let policy = ConnectionRecyclingPolicy {
+            max_age: Some(Duration::from_secs(600)),
+            idle_timeout: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(policy.check_interval(), Some(Duration::from_secs(6)));
+        assert_eq!(
+            policy.recycle_reason(Duration::from_secs(30), Duration::from_secs(10)),
+            None
+        );
+        assert_eq!(
+            policy.recycle_reason(Duration::from_secs(120), Duration::from_secs(60)),
+            Some(RecycleReason::IdleTimeout)
+        );
+        assert_eq!(
+            policy.recycle_reason(Duration::from_secs(600), Duration::from_secs(60)),
+            Some(RecycleReason::MaxAge)
+        );
+
+        let disabled = ConnectionRecyclingPolicy::default();
+        assert!(!disabled.is_enabled());
+        assert_eq!(disabled.check_interval(), None);
+        assert_eq!(disabled.recycle_reason(Duration::MAX, Duration::MAX), None);
+    }
+
+    #[test]
+    fn test_address_translation() {
+        let translation = AddressTranslation::new(HashMap::from([(
+            "10.0.0.1:6379".to_string(),
+            "localhost:7001".to_string(),
+        )]));
+        assert!(translation.validate().is_ok());
+        assert_eq!(
+            translation.translate("10.0.0.1:6379").as_deref(),
+            Some("localhost:7001")
+        );
+        assert_eq!(translation.translate("10.0.0.2:6379"), None);
+
+        // Clones share the table, and an invalid table leaves it unchanged.
+        let clone = translation.clone();
+        let invalid = HashMap::from([("10.0.0.2".to_string(), "localhost:7002".to_string())]);
+        assert_eq!(
+            clone.replace(invalid).unwrap_err().kind(),
+            ErrorKind::InvalidClientConfig
+        );
+        assert_eq!(translation.len(), 1);
+        clone
+            .replace(HashMap::from([(
+                "[::1]:6379".to_string(),
+                "localhost:7003".to_string(),
+            )]))
+            .unwrap();
+        assert_eq!(translation.translate("10.0.0.1:6379"), None);
+        assert_eq!(
+            translation.translate("[::1]:6379").as_deref(),
+            Some("localhost:7003")
+        );
+    }
 }
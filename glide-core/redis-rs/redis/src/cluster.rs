@@ -39,7 +39,7 @@ pub use crate::cluster_client::{ClusterClient, ClusterClientBuilder};
 use crate::cluster_pipeline::UNROUTABLE_ERROR;
 pub use crate::cluster_pipeline::{cluster_pipe, ClusterPipeline};
 use crate::cluster_routing::{
-    MultipleNodeRoutingInfo, ResponsePolicy, Routable, SingleNodeRoutingInfo,
+    MultipleNodeRoutingInfo, ResponsePolicy, Routable, SingleNodeRoutingInfo, SlotAddr,
 };
 use crate::cluster_slotmap::SlotMap;
 use crate::cluster_topology::{parse_and_count_slots, ParsedSlotsResult};
@@ -749,6 +749,25 @@ where
                             let conn = self.get_connection_by_addr(&mut connections, &address)?;
                             (address, conn)
                         }
+                        SingleNodeRoutingInfo::ReplicaInAZ { slot, az } => {
+                            // Replicas only serve reads on connections in READONLY mode, which is only set when
+                            // reading from replicas, so they would redirect the command to the primary.
+                            if self.cluster_params.read_from_replicas
+                                == crate::cluster_slotmap::ReadFromReplicaStrategy::AlwaysFromPrimary
+                            {
+                                return Err((
+                                    ErrorKind::ClientError,
+                                    "AZ affinity routes require a read from replica strategy",
+                                    format!("(requested replica in {az})"),
+                                )
+                                    .into());
+                            }
+                            // The sync client isn't AZ aware, so any replica of the slot will do.
+                            self.get_connection(
+                                &mut connections,
+                                &Route::new(*slot, SlotAddr::ReplicaRequired),
+                            )?
+                        }
                     }
                 };
                 (addr, input.send(conn))
@@ -986,12 +1005,15 @@ fn get_random_connection<C: ConnectionLike + Connect + Sized>(
 // The node string passed to this function will always be in the format host:port as it is either:
 // - Created by calling ConnectionAddr::to_string (unix connections are not supported in cluster mode)
 // - Returned from redis via the ASK/MOVED response
+// If the node is translated by the address translation of the client, the translated address is dialed instead.
 pub(crate) fn get_connection_info(
     node: &str,
     cluster_params: ClusterParams,
 ) -> RedisResult<ConnectionInfo> {
     let invalid_error = || (ErrorKind::InvalidClientConfig, "Invalid node string");
 
+    let translated = cluster_params.address_translation.translate(node);
+    let node = translated.as_deref().unwrap_or(node);
     let (host, port) = node
         .rsplit_once(':')
         .and_then(|(host, port)| {
@@ -1015,6 +1037,9 @@ pub(crate) fn get_connection_info(
             lib_name: cluster_params.lib_name,
             protocol: cluster_params.protocol,
             db: cluster_params.database_id,
+            client_no_evict: cluster_params.client_no_evict,
+            client_no_touch: cluster_params.client_no_touch,
+            resp3_fallback: cluster_params.resp3_fallback,
         },
     })
 }
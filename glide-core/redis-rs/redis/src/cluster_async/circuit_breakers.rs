@@ -0,0 +1,26 @@
+//! The per-node circuit breakers that a cluster connection consults before sending a command to a single node.
+//! The breakers themselves are implemented by the user of the connection.
+
+use crate::RedisError;
+
+/// Whether a request may be sent to a node.
+#[derive(Debug)]
+pub enum NodeAdmission {
+    /// The request is sent to the node.
+    Allowed,
+    /// The node is probed with `PING` first, and the request is sent if the breaker admits it after the probe.
+    Probe,
+    /// The request fails with the error, without being sent.
+    Rejected(RedisError),
+}
+
+/// Per-node circuit breakers, which stop sending requests to a node after consecutive failures.
+///
+/// The breakers are called from the tasks that send the requests, so they should return quickly.
+pub trait NodeCircuitBreakers: Send + Sync {
+    /// Decides whether a request may be sent to the node at `address`.
+    fn admit(&self, address: &str) -> NodeAdmission;
+
+    /// Records the outcome of a request sent to the node at `address`, including the probes.
+    fn record_result(&self, address: &str, result: Result<(), &RedisError>);
+}
@@ -0,0 +1,119 @@
+//! Tracking of the age and the last use of the node connections, so connections that reach the limits of the
+//! [`ConnectionRecyclingPolicy`] of the client are replaced before a load balancer silently drops them.
+
+use super::connections_logic::ConnectionFuture;
+use crate::{ConnectionRecyclingPolicy, RecycleReason};
+use dashmap::DashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::Hasher;
+use std::time::Instant;
+
+/// Identifies a connection by its shared future, which is replaced when the node is reconnected.
+pub(crate) fn connection_id<C>(conn: &ConnectionFuture<C>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    conn.ptr_hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The connection to a node that is currently tracked.
+struct TrackedConnection {
+    /// Identifies the connection, so a reconnection is noticed.
+    connection_id: u64,
+    established_at: Instant,
+    last_used: Instant,
+}
+
+/// The age and the last use of the connections of a cluster client, by node address.
+#[derive(Default)]
+pub(crate) struct ConnectionUsage {
+    connections: DashMap<String, TrackedConnection>,
+}
+
+impl ConnectionUsage {
+    /// Records that a request was sent on the connection to the node at `address`.
+    pub(crate) fn record_use(&self, address: &str) {
+        if let Some(mut connection) = self.connections.get_mut(address) {
+            connection.last_used = Instant::now();
+        }
+    }
+
+    /// Returns the nodes whose connections reached the limits of `policy`, given the current connection of each node
+    /// and its id. Connections that weren't seen before are tracked from now, and the connections that are returned
+    /// are tracked anew, so they aren't returned again while they're replaced. Nodes that aren't given are forgotten.
+    pub(crate) fn connections_to_recycle(
+        &self,
+        policy: &ConnectionRecyclingPolicy,
+        connections: impl IntoIterator<Item = (String, u64)>,
+    ) -> Vec<(String, RecycleReason)> {
+        let now = Instant::now();
+        let mut addresses = HashSet::new();
+        let mut to_recycle = Vec::new();
+        for (address, connection_id) in connections {
+            let mut connection =
+                self.connections
+                    .entry(address.clone())
+                    .or_insert(TrackedConnection {
+                        connection_id,
+                        established_at: now,
+                        last_used: now,
+                    });
+            if connection.connection_id != connection_id {
+                *connection = TrackedConnection {
+                    connection_id,
+                    established_at: now,
+                    last_used: now,
+                };
+            }
+            if let Some(reason) =
+                policy.recycle_reason(now - connection.established_at, now - connection.last_used)
+            {
+                connection.established_at = now;
+                connection.last_used = now;
+                to_recycle.push((address.clone(), reason));
+            }
+            drop(connection);
+            addresses.insert(address);
+        }
+        self.connections
+            .retain(|address, _| addresses.contains(address));
+        to_recycle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_connections_are_recycled_once_per_connection() {
+        let usage = ConnectionUsage::default();
+        let policy = ConnectionRecyclingPolicy {
+            max_age: None,
+            idle_timeout: Some(Duration::from_millis(20)),
+        };
+        let connections = || vec![("node1:6379".to_string(), 1), ("node2:6379".to_string(), 2)];
+        assert!(usage
+            .connections_to_recycle(&policy, connections())
+            .is_empty());
+
+        std::thread::sleep(Duration::from_millis(30));
+        usage.record_use("node1:6379");
+        assert_eq!(
+            usage.connections_to_recycle(&policy, connections()),
+            vec![("node2:6379".to_string(), RecycleReason::IdleTimeout)]
+        );
+        assert!(usage
+            .connections_to_recycle(&policy, connections())
+            .is_empty());
+
+        // A reconnected node is tracked from the new connection, and removed nodes are forgotten.
+        std::thread::sleep(Duration::from_millis(30));
+        usage.record_use("node1:6379");
+        assert!(usage
+            .connections_to_recycle(&policy, vec![("node1:6379".to_string(), 3)])
+            .is_empty());
+        assert_eq!(usage.connections.len(), 1);
+    }
+}
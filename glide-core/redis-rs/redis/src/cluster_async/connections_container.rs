@@ -1,10 +1,12 @@
-use crate::cluster_async::ConnectionFuture;
+use crate::cluster_async::{ConnectionFuture, ReadRoutingStats};
 use crate::cluster_routing::{Route, ShardAddrs, SlotAddr};
-use crate::cluster_slotmap::{ReadFromReplicaStrategy, SlotMap, SlotMapValue};
+use crate::cluster_slotmap::{
+    ReadFromReplicaStrategy, ReplicaSelectionPolicy, SlotMap, SlotMapValue,
+};
 use crate::cluster_topology::TopologyHash;
 use dashmap::DashMap;
 use futures::FutureExt;
-use rand::seq::IteratorRandom;
+use rand::seq::{IndexedRandom, IteratorRandom};
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::atomic::Ordering;
@@ -294,6 +296,8 @@ pub(crate) struct ConnectionsContainer<Connection> {
     connection_map: DashMap<String, ClusterNode<Connection>>,
     pub(crate) slot_map: SlotMap,
     read_from_replica_strategy: ReadFromReplicaStrategy,
+    replica_selection_policy: ReplicaSelectionPolicy,
+    read_routing_stats: Arc<ReadRoutingStats>,
     topology_hash: TopologyHash,
     pub(crate) refresh_conn_state: RefreshConnectionStates,
 }
@@ -311,6 +315,8 @@ impl<Connection> Default for ConnectionsContainer<Connection> {
             connection_map: Default::default(),
             slot_map: Default::default(),
             read_from_replica_strategy: ReadFromReplicaStrategy::AlwaysFromPrimary,
+            replica_selection_policy: Default::default(),
+            read_routing_stats: Default::default(),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
         }
@@ -339,11 +345,25 @@ where
             connection_map,
             slot_map,
             read_from_replica_strategy,
+            replica_selection_policy: Default::default(),
+            read_routing_stats: Default::default(),
             topology_hash,
             refresh_conn_state: Default::default(),
         }
     }
 
+    /// Sets how the AZ affinity strategies choose among the replicas in the client's availability zone, and the
+    /// statistics whose latencies [`ReplicaSelectionPolicy::LowestLatency`] compares.
+    pub(crate) fn with_replica_selection(
+        mut self,
+        replica_selection_policy: ReplicaSelectionPolicy,
+        read_routing_stats: Arc<ReadRoutingStats>,
+    ) -> Self {
+        self.replica_selection_policy = replica_selection_policy;
+        self.read_routing_stats = read_routing_stats;
+        self
+    }
+
     /// Returns an iterator over the nodes in the `slot_map`, yielding tuples of
     /// (node address, (optional IP address, shard addresses)).
     pub(crate) fn slot_map_nodes(
@@ -396,7 +416,7 @@ where
             let index = (initial_index + check_count) % addrs.replicas().len();
             if let Some(connection) = self.connection_for_address(addrs.replicas()[index].as_str())
             {
-                let _ = slot_map_value.last_used_replica.compare_exchange_weak(
+                let _ = slot_map_value.last_used_replica.compare_exchange(
                     initial_index,
                     index,
                     Ordering::Relaxed,
@@ -407,6 +427,22 @@ where
         }
     }
 
+    /// Returns the connection of the reachable replica with the lowest latency. Replicas whose latency wasn't measured
+    /// yet are chosen first. Falls back to the primary if no replica is available.
+    fn lowest_latency_read_from_replica(
+        &self,
+        slot_map_value: &SlotMapValue,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let addrs = &slot_map_value.addrs;
+        addrs
+            .replicas()
+            .iter()
+            .filter(|replica| self.read_routing_stats.is_reachable(replica))
+            .filter_map(|replica| self.connection_for_address(replica.as_str()))
+            .min_by_key(|(address, _)| self.read_routing_stats.latency(address))
+            .or_else(|| self.connection_for_address(addrs.primary().as_str()))
+    }
+
     /// Returns the node's connection in the same availability zone as `client_az` in round robin strategy if exits,
     /// if not, will fall back to any available replica or primary.
     pub(crate) fn round_robin_read_from_replica_with_az_awareness(
@@ -434,15 +470,71 @@ where
         check_primary: bool, // Strategy flag
     ) -> Option<ConnectionAndAddress<Connection>> {
         let addrs = &slot_map_value.addrs;
+
+        // Step 1: Try to find a replica in the same AZ
+        if let Some(connection) = match self.replica_selection_policy {
+            ReplicaSelectionPolicy::RoundRobin => {
+                self.round_robin_replica_in_az(slot_map_value, &client_az)
+            }
+            ReplicaSelectionPolicy::Random => self
+                .connected_replicas_in_az(slot_map_value, &client_az)
+                .choose(&mut rand::rng())
+                .cloned(),
+            // Replicas that didn't serve a read yet have no latency, so they are chosen first.
+            ReplicaSelectionPolicy::LowestLatency => self
+                .connected_replicas_in_az(slot_map_value, &client_az)
+                .into_iter()
+                .min_by_key(|(address, _)| self.read_routing_stats.latency(address)),
+        } {
+            return Some(connection);
+        }
+
+        // Step 2: Check if primary is in the same AZ
+        if check_primary {
+            if let Some((address, connection_details)) =
+                self.connection_details_for_address(addrs.primary().as_str())
+            {
+                if self.az_for_address(&address) == Some(client_az) {
+                    return Some((address, connection_details.conn));
+                }
+            }
+        }
+
+        // Step 3: Fall back to any available replica using round-robin or primary if needed
+        self.round_robin_read_from_replica(slot_map_value)
+    }
+
+    /// Returns the connections of the connected replicas in the availability zone `client_az`, in the order of the
+    /// slot map.
+    fn connected_replicas_in_az(
+        &self,
+        slot_map_value: &SlotMapValue,
+        client_az: &str,
+    ) -> Vec<ConnectionAndAddress<Connection>> {
+        slot_map_value
+            .addrs
+            .replicas()
+            .iter()
+            .filter_map(|replica| self.connection_details_for_address(replica.as_str()))
+            .filter(|(address, _)| self.az_for_address(address).as_deref() == Some(client_az))
+            .map(|(address, connection_details)| (address, connection_details.conn))
+            .collect()
+    }
+
+    fn round_robin_replica_in_az(
+        &self,
+        slot_map_value: &SlotMapValue,
+        client_az: &str,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let addrs = &slot_map_value.addrs;
         let initial_index = slot_map_value.last_used_replica.load(Ordering::Relaxed);
         let mut retries = 0usize;
 
-        // Step 1: Try to find a replica in the same AZ
         loop {
             retries = retries.saturating_add(1);
             // Looped through all replicas; no connected replica found in the same availability zone.
             if retries > addrs.replicas().len() {
-                break;
+                return None;
             }
 
             // Calculate index based on initial index and check count.
@@ -453,9 +545,9 @@ where
             if let Some((address, connection_details)) =
                 self.connection_details_for_address(replica.as_str())
             {
-                if self.az_for_address(&address) == Some(client_az.clone()) {
+                if self.az_for_address(&address).as_deref() == Some(client_az) {
                     // Attempt to update `latest_used_replica` with the index of this replica.
-                    let _ = slot_map_value.last_used_replica.compare_exchange_weak(
+                    let _ = slot_map_value.last_used_replica.compare_exchange(
                         initial_index,
                         index,
                         Ordering::Relaxed,
@@ -465,20 +557,29 @@ where
                 }
             }
         }
+    }
 
-        // Step 2: Check if primary is in the same AZ
-        if check_primary {
-            if let Some((address, connection_details)) =
-                self.connection_details_for_address(addrs.primary().as_str())
-            {
-                if self.az_for_address(&address) == Some(client_az) {
-                    return Some((address, connection_details.conn));
-                }
-            }
-        }
+    /// Whether reads are always sent to the primaries, in which case the replica connections aren't in `READONLY` mode.
+    pub(crate) fn reads_from_primary_only(&self) -> bool {
+        self.read_from_replica_strategy == ReadFromReplicaStrategy::AlwaysFromPrimary
+    }
 
-        // Step 3: Fall back to any available replica using round-robin or primary if needed
-        self.round_robin_read_from_replica(slot_map_value)
+    /// Returns a connection to a replica of `slot` in the availability zone `az`, whichever replica
+    /// strategy is configured. Falls back to any available replica, or to the primary.
+    /// Callers must reject the route if [`Self::reads_from_primary_only`], since the replica
+    /// connections aren't in `READONLY` mode then.
+    pub(crate) fn connection_for_replica_in_az(
+        &self,
+        slot: u16,
+        az: &str,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let slot_map_value = self
+            .slot_map
+            .slot_value_for_route(&Route::new(slot, SlotAddr::ReplicaRequired))?;
+        if slot_map_value.addrs.replicas().is_empty() {
+            return self.connection_for_address(slot_map_value.addrs.primary().as_str());
+        }
+        self.round_robin_read_from_replica_with_az_awareness(slot_map_value, az.to_string())
     }
 
     fn lookup_route(&self, route: &Route) -> Option<ConnectionAndAddress<Connection>> {
@@ -499,6 +600,9 @@ where
                 ReadFromReplicaStrategy::RoundRobin => {
                     self.round_robin_read_from_replica(slot_map_value)
                 }
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 ReadFromReplicaStrategy::AZAffinity(az) => self
                     .round_robin_read_from_replica_with_az_awareness(
                         slot_map_value,
@@ -522,6 +626,9 @@ where
                         slot_map_value,
                         az.to_string(),
                     ),
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 _ => self.round_robin_read_from_replica(slot_map_value),
             },
         }
@@ -694,6 +801,11 @@ where
         self.connection_map.len()
     }
 
+    /// Returns the number of connections to all nodes, including the management connections.
+    pub(crate) fn connections_count(&self) -> usize {
+        count_connections!(&self.connection_map)
+    }
+
     pub(crate) fn connection_map(&self) -> &DashMap<String, ClusterNode<Connection>> {
         &self.connection_map
     }
@@ -711,6 +823,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::time::Duration;
 
     use crate::cluster_routing::Slot;
 
@@ -833,6 +946,8 @@ mod tests {
             connection_map,
             read_from_replica_strategy: strategy
                 .unwrap_or(ReadFromReplicaStrategy::AZAffinity("use-1a".to_string())),
+            replica_selection_policy: Default::default(),
+            read_routing_stats: Default::default(),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
         }
@@ -891,6 +1006,8 @@ mod tests {
             slot_map,
             connection_map,
             read_from_replica_strategy: strategy,
+            replica_selection_policy: Default::default(),
+            read_routing_stats: Default::default(),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
         }
@@ -1064,6 +1181,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_connection_for_lowest_latency_route() {
+        let read_routing_stats = Arc::new(ReadRoutingStats::default());
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::LowestLatency, false)
+                .with_replica_selection(Default::default(), read_routing_stats.clone());
+
+        read_routing_stats.record_probe("replica3-1", Some(Duration::from_millis(5)));
+        read_routing_stats.record_probe("replica3-2", Some(Duration::from_millis(1)));
+        assert_eq!(
+            32,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+
+        // Unreachable replicas are skipped.
+        read_routing_stats.record_probe("replica3-2", None);
+        assert_eq!(
+            31,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaRequired))
+                .unwrap()
+                .1
+        );
+
+        // Fall back to the primary once no replica is reachable.
+        read_routing_stats.record_probe("replica3-1", None);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn get_lowest_latency_replica_in_client_az() {
+        let read_routing_stats = Arc::new(ReadRoutingStats::default());
+        let container = create_container_with_az_strategy(
+            false,
+            Some(ReadFromReplicaStrategy::AZAffinity("use-1a".to_string())),
+        )
+        .with_replica_selection(
+            ReplicaSelectionPolicy::LowestLatency,
+            read_routing_stats.clone(),
+        );
+
+        read_routing_stats.record_read("replica3-1", Some(Duration::from_millis(5)));
+        // The unmeasured replica in the client's az is chosen first.
+        assert_eq!(
+            33,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+
+        read_routing_stats.record_read("replica3-3", Some(Duration::from_millis(10)));
+        read_routing_stats.record_read("replica3-2", Some(Duration::from_millis(1)));
+        // The faster replica in another az isn't chosen.
+        assert_eq!(
+            31,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
     #[test]
     fn get_connection_for_az_affinity_route() {
         let container = create_container_with_az_strategy(
@@ -1159,6 +1348,42 @@ mod tests {
         assert_eq!(addresses, vec![31, 31, 33, 33]);
     }
 
+    #[test]
+    fn get_connection_for_replica_in_az_ignores_client_strategy() {
+        let container = create_container_with_az_strategy(
+            false,
+            Some(ReadFromReplicaStrategy::AlwaysFromPrimary),
+        );
+        // the replica connections aren't in READONLY mode, so the cluster client rejects these routes
+        assert!(container.reads_from_primary_only());
+
+        // the container still picks one of the replicas in the requested availability zone
+        assert!(one_of(
+            container.connection_for_replica_in_az(2001, "use-1b"),
+            &[32],
+        ));
+        assert!(one_of(
+            container.connection_for_replica_in_az(2001, "use-1a"),
+            &[31, 33],
+        ));
+
+        // slot without replicas falls back to the primary
+        assert_eq!(
+            1,
+            container
+                .connection_for_replica_in_az(500, "use-1a")
+                .unwrap()
+                .1
+        );
+
+        // remove the replica in the requested az and get one of the other replicas
+        remove_nodes(&container, &["replica3-2"]);
+        assert!(one_of(
+            container.connection_for_replica_in_az(2001, "use-1b"),
+            &[31, 33],
+        ));
+    }
+
     #[test]
     fn get_connection_for_az_affinity_replicas_and_primary_route() {
         // Create a container with AZAffinityReplicasAndPrimary strategy
@@ -195,6 +195,10 @@ where
             connection_retry_strategy: None,
             tcp_nodelay: params.tcp_nodelay,
             pubsub_synchronizer: None,
+            connection_event_listener: None,
+            socket_timeouts: params.socket_timeouts,
+            tcp_keepalive: params.tcp_keepalive,
+            proxy: params.proxy.clone(),
         },
     )
     .await
@@ -394,6 +398,8 @@ where
 {
     let connection_timeout = params.connection_timeout;
     let response_timeout = params.response_timeout;
+    // The address the announced node resolved to doesn't apply to the address it's translated to.
+    let socket_addr = socket_addr.filter(|_| params.address_translation.translate(node).is_none());
     let info = get_connection_info(node, params)?;
     // management connection does not require notifications or disconnect notifications
     // or pubsub synchronizer (subscriptions only exist on user connections)
@@ -22,9 +22,16 @@
 //! }
 //! ```
 
+mod circuit_breakers;
+mod connection_recycling;
 mod connections_container;
 mod connections_logic;
 mod pipeline_routing;
+mod read_routing;
+mod redirect_stats;
+pub use circuit_breakers::{NodeAdmission, NodeCircuitBreakers};
+pub use read_routing::{ReadRoutingStats, DEFAULT_SERVED_READS_HISTORY};
+pub use redirect_stats::{NodeRedirects, RedirectKind, RedirectStats};
 /// Exposed only for testing.
 pub mod testing {
     pub use super::connections_container::ConnectionDetails;
@@ -33,7 +40,7 @@ pub mod testing {
 use crate::{
     client::GlideConnectionOptions,
     cluster_routing::{Routable, RoutingInfo, ShardUpdateResult},
-    cluster_slotmap::SlotMap,
+    cluster_slotmap::{ReadFromReplicaStrategy, SlotMap},
     cluster_topology::{
         calculate_topology, SlotRefreshState, TopologyHash,
         DEFAULT_NUMBER_OF_REFRESH_SLOTS_RETRIES, DEFAULT_REFRESH_SLOTS_RETRY_BASE_DURATION_MILLIS,
@@ -42,8 +49,10 @@ use crate::{
     cmd,
     commands::cluster_scan::{cluster_scan, ClusterScanArgs, ScanStateRC},
     types::ServerError,
-    FromRedisValue, InfoDict, PipelineRetryStrategy,
+    ConnectionEvent, ConnectionRecyclingPolicy, FromRedisValue, InfoDict, PipelineRetryStrategy,
+    RecycleReason,
 };
+use connection_recycling::ConnectionUsage;
 use connections_container::{RefreshTaskNotifier, RefreshTaskState, RefreshTaskStatus};
 use dashmap::DashMap;
 use pipeline_routing::{
@@ -53,6 +62,7 @@ use pipeline_routing::{
 
 use logger_core::log_error;
 use rand::seq::IteratorRandom;
+use rand::Rng;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -64,7 +74,7 @@ use std::{
         Arc, Mutex,
     },
     task::{self, Poll},
-    time::SystemTime,
+    time::{Instant, SystemTime},
 };
 use strum_macros::Display;
 #[cfg(feature = "tokio-comp")]
@@ -83,6 +93,7 @@ use crate::{
     cluster_client::{ClusterParams, RetryParams},
     cluster_routing::{
         self, MultipleNodeRoutingInfo, Redirect, ResponsePolicy, Route, SingleNodeRoutingInfo,
+        SlotAddr,
     },
     push_manager::PushInfo,
     types::ProtocolVersion,
@@ -413,6 +424,64 @@ where
         self.route_operation_request(Operation::GetUsername).await
     }
 
+    /// Get the address of the primary node that serves the given slot, according to the current slot map.
+    /// Returns `Nil` if the slot isn't covered by the slot map.
+    pub async fn get_address_for_slot(&mut self, slot: u16) -> RedisResult<Value> {
+        self.route_operation_request(Operation::GetAddressForSlot(slot))
+            .await
+    }
+
+    /// Get the number of connections the client holds to the cluster nodes, including the management connections.
+    pub async fn connections_count(&mut self) -> RedisResult<usize> {
+        match self
+            .route_operation_request(Operation::GetConnectionsCount)
+            .await?
+        {
+            Value::Int(count) => Ok(count as usize),
+            value => Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response to a connections count request",
+                format!("{value:?}"),
+            ))),
+        }
+    }
+
+    /// Get the addresses of the nodes in the current slot map, or only of the primaries if `primaries_only` is set.
+    /// The addresses are taken from the slot map, so nodes that are currently unreachable are included.
+    pub async fn node_addresses(&mut self, primaries_only: bool) -> RedisResult<Vec<String>> {
+        match self
+            .route_operation_request(Operation::GetNodeAddresses { primaries_only })
+            .await?
+        {
+            Value::Array(addresses) => addresses
+                .into_iter()
+                .map(String::from_owned_redis_value)
+                .collect(),
+            value => Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response to a node addresses request",
+                format!("{value:?}"),
+            ))),
+        }
+    }
+
+    /// Checks the topology of the cluster right away, and refreshes the slot map if it changed,
+    /// such as after the application learned about a failover.
+    /// Returns true if the topology changed and the slot map was refreshed.
+    pub async fn refresh_topology(&mut self) -> RedisResult<bool> {
+        match self
+            .route_operation_request(Operation::RefreshTopology)
+            .await?
+        {
+            Value::Boolean(topology_changed) => Ok(topology_changed),
+            value => Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response to a topology refresh",
+                format!("{value:?}"),
+            ))),
+        }
+    }
+
     /// Routes an operation request to the appropriate handler.
     async fn route_operation_request(
         &mut self,
@@ -492,6 +561,8 @@ pub(crate) struct InnerCore<C> {
     /// This prevents validation from removing connections that were just created
     /// during topology discovery but haven't been assigned slots yet.
     pub(crate) topology_refresh_lock: tokio::sync::Mutex<()>,
+    /// The age and the last use of the node connections, for the connection recycling policy.
+    connection_usage: ConnectionUsage,
 }
 
 pub(crate) type Core<C> = Arc<InnerCore<C>>;
@@ -511,6 +582,33 @@ where
             .map_err(|_| RedisError::from((ErrorKind::ClientError, MUTEX_READ_ERR)))
     }
 
+    fn notify_connection_event(&self, event: ConnectionEvent, address: &str, error: Option<&str>) {
+        if let Some(listener) = &self.glide_connection_options.connection_event_listener {
+            listener.on_connection_event(event, address, error);
+        }
+    }
+
+    /// Counts a redirection of a request to `redirect`, and reports it to the connection event listener if enabled.
+    fn record_redirect(&self, redirect: &RedirectNode, kind: RedirectKind) {
+        let (redirect_stats, report_redirect_events) = self
+            .get_cluster_param(|params| {
+                (params.redirect_stats.clone(), params.report_redirect_events)
+            })
+            .expect(MUTEX_READ_ERR);
+        redirect_stats.record(&redirect.address, kind);
+        if report_redirect_events {
+            let detail = match kind {
+                RedirectKind::Moved => format!("MOVED {}", redirect.slot),
+                RedirectKind::Ask => format!("ASK {}", redirect.slot),
+            };
+            self.notify_connection_event(
+                ConnectionEvent::Redirected,
+                &redirect.address,
+                Some(&detail),
+            );
+        }
+    }
+
     fn set_cluster_param<F>(&self, f: F) -> Result<(), RedisError>
     where
         F: FnOnce(&mut ClusterParams),
@@ -594,6 +692,10 @@ pub(crate) struct ClusterConnInner<C> {
     periodic_checks_handler: Option<JoinHandle<()>>,
     // Handler of fast connection validation task
     connections_validation_handler: Option<JoinHandle<()>>,
+    // Handler of the heartbeat task
+    heartbeat_handler: Option<JoinHandle<()>>,
+    // Handler of the connection recycling task
+    connection_recycling_handler: Option<JoinHandle<()>>,
 }
 
 impl<C> Dispose for ClusterConnInner<C> {
@@ -617,6 +719,16 @@ impl<C> Dispose for ClusterConnInner<C> {
             handle.abort()
         }
 
+        if let Some(handle) = self.heartbeat_handler {
+            #[cfg(feature = "tokio-comp")]
+            handle.abort()
+        }
+
+        if let Some(handle) = self.connection_recycling_handler {
+            #[cfg(feature = "tokio-comp")]
+            handle.abort()
+        }
+
         // Reduce the number of clients
         Telemetry::decr_total_clients(1);
     }
@@ -673,6 +785,10 @@ pub(crate) enum InternalSingleNodeRouting<C> {
     Random,
     SpecificNode(Route),
     ByAddress(String),
+    ReplicaInAZ {
+        slot: u16,
+        az: String,
+    },
     Connection {
         address: String,
         conn: ConnectionFuture<C>,
@@ -696,6 +812,9 @@ impl<C> From<SingleNodeRoutingInfo> for InternalSingleNodeRouting<C> {
             SingleNodeRoutingInfo::ByAddress { host, port } => {
                 InternalSingleNodeRouting::ByAddress(format!("{host}:{port}"))
             }
+            SingleNodeRoutingInfo::ReplicaInAZ { slot, az } => {
+                InternalSingleNodeRouting::ReplicaInAZ { slot, az }
+            }
         }
     }
 }
@@ -743,6 +862,10 @@ enum Operation {
     UpdateConnectionUsername(Option<String>),
     UpdateConnectionProtocol(ProtocolVersion),
     GetUsername,
+    GetAddressForSlot(u16),
+    RefreshTopology,
+    GetConnectionsCount,
+    GetNodeAddresses { primaries_only: bool },
 }
 
 fn boxed_sleep(duration: Duration) -> BoxFuture<'static, ()> {
@@ -942,6 +1065,10 @@ enum Next<C> {
     Retry {
         request: PendingRequest<C>,
     },
+    AskRedirect {
+        request: PendingRequest<C>,
+        redirect: RedirectNode,
+    },
     RetryBusyLoadingError {
         request: PendingRequest<C>,
         address: String,
@@ -1007,6 +1134,10 @@ impl<C> Future for Request<C> {
                 Next::Done.into()
             }
             Err((target, err)) => {
+                let err = match &target {
+                    OperationTarget::Node { address } => err.with_node_address(address),
+                    _ => err,
+                };
                 let request = this.request.as_mut().unwrap();
                 // TODO - would be nice if we didn't need to repeat this code twice, with & without retries.
                 if request.retry >= this.retry_params.number_of_retries {
@@ -1096,7 +1227,10 @@ impl<C> Future for Request<C> {
                             err.redirect_node()
                                 .map(|(node, _slot)| Redirect::Ask(node.to_string(), true)),
                         );
-                        Next::Retry { request }.into()
+                        match RedirectNode::from_option_tuple(err.redirect_node()) {
+                            Some(redirect) => Next::AskRedirect { request, redirect }.into(),
+                            None => Next::Retry { request }.into(),
+                        }
                     }
                     RetryMethod::MovedRedirect => {
                         let mut request = this.request.take().unwrap();
@@ -1218,6 +1352,10 @@ where
             connection_retry_strategy: Some(connection_retry_strategy),
             tcp_nodelay: cluster_params.tcp_nodelay,
             pubsub_synchronizer,
+            connection_event_listener: cluster_params.connection_event_listener.clone(),
+            socket_timeouts: cluster_params.socket_timeouts,
+            tcp_keepalive: cluster_params.tcp_keepalive,
+            proxy: cluster_params.proxy.clone(),
         };
 
         let connections = Self::create_initial_connections(
@@ -1228,20 +1366,28 @@ where
         .await?;
 
         let topology_checks_interval = cluster_params.topology_checks_interval;
+        let topology_checks_max_jitter = cluster_params.topology_checks_max_jitter;
         let slots_refresh_rate_limiter = cluster_params.slots_refresh_rate_limit;
         let inner = Arc::new(InnerCore {
-            conn_lock: StdRwLock::new(ConnectionsContainer::new(
-                Default::default(),
-                connections,
-                cluster_params.read_from_replicas.clone(),
-                0,
-            )),
+            conn_lock: StdRwLock::new(
+                ConnectionsContainer::new(
+                    Default::default(),
+                    connections,
+                    cluster_params.read_from_replicas.clone(),
+                    0,
+                )
+                .with_replica_selection(
+                    cluster_params.replica_selection_policy,
+                    cluster_params.read_routing_stats.clone(),
+                ),
+            ),
             cluster_params: StdRwLock::new(cluster_params.clone()),
             pending_requests: Mutex::new(Vec::new()),
             slot_refresh_state: SlotRefreshState::new(slots_refresh_rate_limiter),
             initial_nodes: initial_nodes.to_vec(),
             glide_connection_options,
             topology_refresh_lock: tokio::sync::Mutex::new(()),
+            connection_usage: ConnectionUsage::default(),
         });
         let mut connection = ClusterConnInner {
             inner,
@@ -1250,6 +1396,8 @@ where
             state: ConnectionState::PollComplete,
             periodic_checks_handler: None,
             connections_validation_handler: None,
+            heartbeat_handler: None,
+            connection_recycling_handler: None,
         };
         // Initial slots and subscriptions refresh
         Self::refresh_slots_and_subscriptions_with_retries(
@@ -1260,8 +1408,11 @@ where
         .await?;
 
         if let Some(duration) = topology_checks_interval {
-            let periodic_task =
-                ClusterConnInner::periodic_topology_check(connection.inner.clone(), duration);
+            let periodic_task = ClusterConnInner::periodic_topology_check(
+                connection.inner.clone(),
+                duration,
+                topology_checks_max_jitter,
+            );
             #[cfg(feature = "tokio-comp")]
             {
                 connection.periodic_checks_handler = Some(tokio::spawn(periodic_task));
@@ -1279,6 +1430,29 @@ where
             }
         }
 
+        if let Some(interval) = cluster_params.heartbeat_interval {
+            let heartbeat_task =
+                ClusterConnInner::heartbeat_task(connection.inner.clone(), interval);
+            #[cfg(feature = "tokio-comp")]
+            {
+                connection.heartbeat_handler = Some(tokio::spawn(heartbeat_task));
+            }
+        }
+
+        let connection_recycling = cluster_params.connection_recycling;
+        if let Some(interval) = connection_recycling.check_interval() {
+            let connection_recycling_task = ClusterConnInner::connection_recycling_task(
+                connection.inner.clone(),
+                connection_recycling,
+                interval,
+            );
+            #[cfg(feature = "tokio-comp")]
+            {
+                connection.connection_recycling_handler =
+                    Some(tokio::spawn(connection_recycling_task));
+            }
+        }
+
         // New client added
         Telemetry::incr_total_clients(1);
         Ok(Disposable::new(connection))
@@ -1606,6 +1780,11 @@ where
                     "No attempts performed",
                 )));
                 let mut first_attempt = true;
+                inner_clone.notify_connection_event(
+                    ConnectionEvent::Reconnecting,
+                    &address_clone_for_task,
+                    None,
+                );
                 for backoff_duration in infinite_backoff_iter {
                     let cluster_params = inner_clone
                         .cluster_params
@@ -1639,6 +1818,11 @@ where
                                     conn_state.status.flip_status_to_too_long();
                                 }
 
+                                inner_clone.notify_connection_event(
+                                    ConnectionEvent::Disconnected,
+                                    &address_clone_for_task,
+                                    Some(&err.to_string()),
+                                );
                                 first_attempt = false;
                             }
                             debug!(
@@ -1661,6 +1845,11 @@ where
                             .read()
                             .expect(MUTEX_READ_ERR)
                             .replace_or_add_connection_for_address(&address_clone_for_task, node);
+                        inner_clone.notify_connection_event(
+                            ConnectionEvent::Connected,
+                            &address_clone_for_task,
+                            None,
+                        );
                     }
                     Err(err) => {
                         warn!(
@@ -2186,9 +2375,17 @@ where
         Ok(topology_changed)
     }
 
-    async fn periodic_topology_check(inner: Arc<InnerCore<C>>, interval_duration: Duration) {
+    async fn periodic_topology_check(
+        inner: Arc<InnerCore<C>>,
+        interval_duration: Duration,
+        max_jitter: Option<Duration>,
+    ) {
         loop {
-            let _ = boxed_sleep(interval_duration).await;
+            let jitter = max_jitter
+                .filter(|max_jitter| !max_jitter.is_zero())
+                .map(|max_jitter| rand::rng().random_range(Duration::ZERO..max_jitter))
+                .unwrap_or_default();
+            let _ = boxed_sleep(interval_duration + jitter).await;
             // Check and refresh topology if needed
             let _ = match Self::check_topology_and_refresh_if_diff(
                 inner.clone(),
@@ -2205,6 +2402,141 @@ where
                     true
                 }
             };
+            let read_from_replicas = inner
+                .get_cluster_param(|params| params.read_from_replicas.clone())
+                .unwrap_or_default();
+            if read_from_replicas == ReadFromReplicaStrategy::LowestLatency {
+                Self::measure_node_latencies(inner.clone()).await;
+            }
+        }
+    }
+
+    /// Measures the round-trip latency of every node with `PING`, for routing the reads to the replica with the
+    /// lowest latency. Nodes that don't respond within the connection timeout are marked as unreachable until they
+    /// respond again.
+    async fn measure_node_latencies(inner: Arc<InnerCore<C>>) {
+        let Ok((read_routing_stats, connection_timeout)) = inner.get_cluster_param(|params| {
+            (params.read_routing_stats.clone(), params.connection_timeout)
+        }) else {
+            return;
+        };
+        let connections: Vec<_> = inner
+            .conn_lock
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .all_node_connections()
+            .collect();
+        futures::future::join_all(connections.into_iter().map(|(address, conn)| {
+            let read_routing_stats = read_routing_stats.clone();
+            async move {
+                let start = Instant::now();
+                let result = tokio::time::timeout(connection_timeout, async {
+                    let mut conn = conn.await;
+                    conn.req_packed_command(&cmd("PING")).await
+                })
+                .await;
+                let latency = matches!(result, Ok(Ok(_))).then(|| start.elapsed());
+                read_routing_stats.record_probe(&address, latency);
+            }
+        }))
+        .await;
+    }
+
+    /// Sends `PING` to every node in the given interval, keeping idle connections alive, and reconnects the nodes
+    /// whose connections fail or don't respond within the connection timeout.
+    async fn heartbeat_task(inner: Arc<InnerCore<C>>, interval_duration: Duration) {
+        loop {
+            let _ = boxed_sleep(interval_duration).await;
+            let Ok(connection_timeout) =
+                inner.get_cluster_param(|params| params.connection_timeout)
+            else {
+                continue;
+            };
+            let connections: Vec<_> = inner
+                .conn_lock
+                .read()
+                .expect(MUTEX_READ_ERR)
+                .all_node_connections()
+                .collect();
+            let failed_addresses: HashSet<String> = futures::future::join_all(
+                connections.into_iter().map(|(address, conn)| async move {
+                    let result = tokio::time::timeout(connection_timeout, async {
+                        let mut conn = conn.await;
+                        conn.req_packed_command(&cmd("PING")).await
+                    })
+                    .await;
+                    match result {
+                        Ok(Ok(_)) => None,
+                        Ok(Err(err))
+                            if !err.is_connection_dropped() && !err.is_connection_refusal() =>
+                        {
+                            None
+                        }
+                        _ => Some(address.to_string()),
+                    }
+                }),
+            )
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+            if !failed_addresses.is_empty() {
+                debug!("Heartbeat failed for {failed_addresses:?}, reconnecting");
+                Self::trigger_refresh_connection_tasks(
+                    inner.clone(),
+                    failed_addresses,
+                    RefreshConnectionType::AllConnections,
+                    false,
+                )
+                .await;
+            }
+        }
+    }
+
+    /// Checks the node connections against the recycling policy in the given interval, and reconnects the nodes whose
+    /// connections reached the maximum connection age or weren't used within the idle timeout.
+    async fn connection_recycling_task(
+        inner: Arc<InnerCore<C>>,
+        policy: ConnectionRecyclingPolicy,
+        interval_duration: Duration,
+    ) {
+        loop {
+            let _ = boxed_sleep(interval_duration).await;
+            let connections: Vec<_> = inner
+                .conn_lock
+                .read()
+                .expect(MUTEX_READ_ERR)
+                .all_node_connections()
+                .map(|(address, conn)| (address, connection_recycling::connection_id(&conn)))
+                .collect();
+            let to_recycle = inner
+                .connection_usage
+                .connections_to_recycle(&policy, connections);
+            if to_recycle.is_empty() {
+                continue;
+            }
+            for (address, reason) in &to_recycle {
+                debug!(
+                    "Recycling the connection to {address} ({})",
+                    reason.description()
+                );
+                match reason {
+                    RecycleReason::MaxAge => Telemetry::incr_connections_recycled_max_age(),
+                    RecycleReason::IdleTimeout => Telemetry::incr_connections_recycled_idle(),
+                };
+                inner.notify_connection_event(
+                    ConnectionEvent::Recycled,
+                    address,
+                    Some(reason.description()),
+                );
+            }
+            Self::trigger_refresh_connection_tasks(
+                inner.clone(),
+                to_recycle.into_iter().map(|(address, _)| address).collect(),
+                RefreshConnectionType::AllConnections,
+                false,
+            )
+            .await;
         }
     }
 
@@ -2398,15 +2730,26 @@ where
         // Clear the refresh tasks of the prev instance
         // TODO - Maybe we can take the running refresh tasks and use them instead of running new connection creation
         write_guard.refresh_conn_state.clear_refresh_state();
-        let read_from_replicas = inner
-            .get_cluster_param(|params| params.read_from_replicas.clone())
+        let (read_from_replicas, replica_selection_policy, read_routing_stats) = inner
+            .get_cluster_param(|params| {
+                (
+                    params.read_from_replicas.clone(),
+                    params.replica_selection_policy,
+                    params.read_routing_stats.clone(),
+                )
+            })
             .expect(MUTEX_READ_ERR);
+        // The initial refresh replaces an empty topology, so it isn't reported as a change.
+        let previous_topology_hash = write_guard.get_current_topology_hash();
+        let topology_changed =
+            previous_topology_hash != 0 && previous_topology_hash != topology_hash;
         *write_guard = ConnectionsContainer::new(
             new_slots,
             new_connections,
             read_from_replicas,
             topology_hash,
-        );
+        )
+        .with_replica_selection(replica_selection_policy, read_routing_stats);
 
         // Notify the PubSub synchronizer about the new topology (using same lock)
         // Since handle_topology_refresh is sync, no other task can benefit from us
@@ -2414,6 +2757,11 @@ where
         if let Some(sync) = &inner.glide_connection_options.pubsub_synchronizer {
             sync.handle_topology_refresh(&write_guard.slot_map);
         }
+        drop(write_guard);
+
+        if topology_changed {
+            inner.notify_connection_event(ConnectionEvent::TopologyChanged, "", None);
+        }
 
         Ok(())
     }
@@ -2625,19 +2973,84 @@ where
 
         // if we reached this point, we're sending the command only to single node, and we need to find the
         // right connection to the node.
+        #[cfg(feature = "fault-injection")]
+        let fault_injector = core
+            .get_cluster_param(|params| params.fault_injector.clone())
+            .map_err(|err| (OperationTarget::NotFound, err))?;
+        let circuit_breakers = core
+            .get_cluster_param(|params| params.circuit_breakers.clone())
+            .map_err(|err| (OperationTarget::NotFound, err))?;
+        // Reads are the commands routed to a slot, that may be served by a replica.
+        let read_routing_stats = match &routing {
+            InternalSingleNodeRouting::SpecificNode(route)
+                if route.slot_addr() != SlotAddr::Master =>
+            {
+                Some(
+                    core.get_cluster_param(|params| params.read_routing_stats.clone())
+                        .map_err(|err| (OperationTarget::NotFound, err))?,
+                )
+            }
+            _ => None,
+        };
         let (address, mut conn) = Self::get_connection(routing, core, Some(cmd.clone()))
             .await
             .map_err(|err| (OperationTarget::NotFound, err))?;
+        // The command may have waited for the connection, such as while the node was reconnecting.
+        cmd.check_deadline()
+            .map_err(|err| (address.clone().into(), err))?;
+        #[cfg(feature = "fault-injection")]
+        if let Some(fault_injector) = fault_injector {
+            fault_injector
+                .inject(&address, &cmd)
+                .await
+                .map_err(|err| (address.clone().into(), err))?;
+        }
+        if let Some(circuit_breakers) = &circuit_breakers {
+            Self::admit_to_node(circuit_breakers.as_ref(), &address, &mut conn)
+                .await
+                .map_err(|err| (address.clone().into(), err))?;
+        }
         // Update OTel span with actual routed node address
         if let Some(span) = cmd.span() {
             set_routed_node_on_span(&span, &address);
         }
-        conn.req_packed_command(&cmd)
-            .await
+        if let Some(trace) = cmd.trace() {
+            trace.record_attempt(&address);
+        }
+        let start = Instant::now();
+        let result = conn.req_packed_command(&cmd).await;
+        if let Some(circuit_breakers) = &circuit_breakers {
+            circuit_breakers.record_result(&address, result.as_ref().map(|_| ()));
+        }
+        if let Some(read_routing_stats) = read_routing_stats {
+            read_routing_stats.record_read(&address, result.is_ok().then(|| start.elapsed()));
+        }
+        result
             .map(Response::Single)
             .map_err(|err| (address.into(), err))
     }
 
+    /// Lets the circuit breaker of the node decide whether a request may be sent to it. A half-open breaker is
+    /// probed with `PING` first, and admits the request only if the probe closed it.
+    async fn admit_to_node(
+        circuit_breakers: &dyn NodeCircuitBreakers,
+        address: &str,
+        conn: &mut C,
+    ) -> RedisResult<()> {
+        let admission = match circuit_breakers.admit(address) {
+            NodeAdmission::Probe => {
+                let probe = conn.req_packed_command(&cmd("PING")).await;
+                circuit_breakers.record_result(address, probe.as_ref().map(|_| ()));
+                circuit_breakers.admit(address)
+            }
+            admission => admission,
+        };
+        match admission {
+            NodeAdmission::Rejected(err) => Err(err),
+            NodeAdmission::Allowed | NodeAdmission::Probe => Ok(()),
+        }
+    }
+
     async fn try_pipeline_request(
         pipeline: Arc<crate::Pipeline>,
         offset: usize,
@@ -2742,6 +3155,58 @@ where
                     };
                     Ok(Response::Single(username))
                 }
+                Operation::GetAddressForSlot(slot) => {
+                    let address = core
+                        .conn_lock
+                        .read()
+                        .expect(MUTEX_READ_ERR)
+                        .address_for_route(&Route::new(slot, SlotAddr::Master));
+                    Ok(Response::Single(address.map_or(Value::Nil, |address| {
+                        Value::BulkString(address.into_bytes())
+                    })))
+                }
+                Operation::GetConnectionsCount => {
+                    let count = core
+                        .conn_lock
+                        .read()
+                        .expect(MUTEX_READ_ERR)
+                        .connections_count();
+                    Ok(Response::Single(Value::Int(count as i64)))
+                }
+                Operation::GetNodeAddresses { primaries_only } => {
+                    let addresses = {
+                        let conn_lock = core.conn_lock.read().expect(MUTEX_READ_ERR);
+                        if primaries_only {
+                            conn_lock.slot_map.addresses_for_all_primaries()
+                        } else {
+                            conn_lock.slot_map.all_node_addresses()
+                        }
+                    };
+                    let mut addresses: Vec<_> = addresses
+                        .into_iter()
+                        .map(|address| address.to_string())
+                        .collect();
+                    addresses.sort();
+                    Ok(Response::Single(Value::Array(
+                        addresses
+                            .into_iter()
+                            .map(|address| Value::BulkString(address.into_bytes()))
+                            .collect(),
+                    )))
+                }
+                Operation::RefreshTopology => {
+                    match Self::check_topology_and_refresh_if_diff(
+                        core,
+                        &RefreshPolicy::NotThrottable,
+                    )
+                    .await
+                    {
+                        Ok(topology_changed) => {
+                            Ok(Response::Single(Value::Boolean(topology_changed)))
+                        }
+                        Err(err) => Err((OperationTarget::FanOut, err)),
+                    }
+                }
             },
         }
     }
@@ -2890,6 +3355,16 @@ where
         routing: InternalSingleNodeRouting<C>,
         core: Core<C>,
         cmd: Option<Arc<Cmd>>,
+    ) -> RedisResult<(String, C)> {
+        let (address, conn) = Self::find_connection(routing, core.clone(), cmd).await?;
+        core.connection_usage.record_use(&address);
+        Ok((address, conn))
+    }
+
+    async fn find_connection(
+        routing: InternalSingleNodeRouting<C>,
+        core: Core<C>,
+        cmd: Option<Arc<Cmd>>,
     ) -> RedisResult<(String, C)> {
         let mut asking = false;
 
@@ -2997,6 +3472,23 @@ where
                     conn_check
                 }
             }
+            InternalSingleNodeRouting::ReplicaInAZ { slot, az } => {
+                let conn_lock = core.conn_lock.read().expect(MUTEX_READ_ERR);
+                // Replicas only serve reads on connections in READONLY mode, which is only set when reading from
+                // replicas, so they would redirect the command to the primary.
+                if conn_lock.reads_from_primary_only() {
+                    return Err((
+                        ErrorKind::ClientError,
+                        "AZ affinity routes require a read from replica strategy",
+                        format!("(requested replica in {az})"),
+                    )
+                        .into());
+                }
+                conn_lock
+                    .connection_for_replica_in_az(slot, &az)
+                    // Without slot coverage, let the server redirect us to the right node.
+                    .map_or(ConnectionCheck::RandomConnection, ConnectionCheck::Found)
+            }
             InternalSingleNodeRouting::Random => ConnectionCheck::RandomConnection,
             InternalSingleNodeRouting::Connection { address, conn } => {
                 return Ok((address, conn.await));
@@ -3274,6 +3766,17 @@ where
             };
             match result {
                 Next::Done => {}
+                Next::AskRedirect { request, redirect } => {
+                    self.inner.record_redirect(&redirect, RedirectKind::Ask);
+                    let future = Self::try_request(request.info.clone(), self.inner.clone());
+                    self.in_flight_requests.push(Box::pin(Request {
+                        retry_params: retry_params.clone(),
+                        request: Some(request),
+                        future: RequestState::Future {
+                            future: Box::pin(future),
+                        },
+                    }));
+                }
                 Next::Retry { request } => {
                     let future = Self::try_request(request.info.clone(), self.inner.clone());
                     self.in_flight_requests.push(Box::pin(Request {
@@ -3308,6 +3811,10 @@ where
                 } => {
                     poll_flush_action =
                         poll_flush_action.change_state(PollFlushAction::RebuildSlots);
+                    if let Some(moved_redirect) = &moved_redirect {
+                        self.inner
+                            .record_redirect(moved_redirect, RedirectKind::Moved);
+                    }
                     let future: Option<
                         RequestState<Pin<Box<dyn Future<Output = OperationResult> + Send>>>,
                     > = if let Some(moved_redirect) = moved_redirect {
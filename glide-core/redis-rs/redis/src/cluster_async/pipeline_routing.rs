@@ -714,13 +714,23 @@ fn update_retry_map(
                 error,
             ));
         }
-        RetryMethod::AskRedirect | RetryMethod::MovedRedirect => {
+        RetryMethod::MovedRedirect => {
             // If the error is a redirect, we add it to the retry map regardless
             retry_map
                 .entry(retry_method)
                 .or_default()
                 .push(((index, inner_index), address, error));
         }
+        RetryMethod::AskRedirect => {
+            // The slot is being migrated, so the command is retried on the importing node with `ASKING`
+            if pipeline_retry_strategy.follow_ask_redirects {
+                retry_map.entry(retry_method).or_default().push((
+                    (index, inner_index),
+                    address,
+                    error,
+                ));
+            }
+        }
         RetryMethod::RefreshSlotsAndRetry => {
             // TODO: Add support for refreshing slots and retrying in pipelines
             // https://github.com/valkey-io/valkey-glide/issues/5226
@@ -1308,6 +1318,9 @@ pub(crate) fn route_for_pipeline(pipeline: &crate::Pipeline) -> RedisResult<Opti
             Some(cluster_routing::RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
                 ..
             })) => None,
+            Some(cluster_routing::RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::ReplicaInAZ { slot, .. },
+            )) => Some(Route::new(slot, SlotAddr::ReplicaRequired)),
             None => None,
         }
     }
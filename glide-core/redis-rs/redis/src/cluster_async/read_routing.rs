@@ -0,0 +1,134 @@
+//! Statistics of the routing of the read commands of a cluster client: the nodes that served the latest read commands,
+//! and the smoothed latency of each node. They are used to select replicas by latency, and let tests assert how the
+//! reads were distributed.
+
+use dashmap::{DashMap, DashSet};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The default number of read commands whose serving node is remembered.
+pub const DEFAULT_SERVED_READS_HISTORY: usize = 1000;
+// Each new sample weighs 1/8 of the smoothed latency, as in the smoothed round-trip time of TCP.
+const LATENCY_SMOOTHING_FACTOR: u64 = 8;
+
+/// Statistics of the routing of the read commands of a cluster client.
+#[derive(Debug)]
+pub struct ReadRoutingStats {
+    history_capacity: usize,
+    served_reads: Mutex<VecDeque<String>>,
+    latencies_us: DashMap<String, u64>,
+    // The nodes that failed their latest latency check, until they serve a command again.
+    unreachable: DashSet<String>,
+}
+
+impl Default for ReadRoutingStats {
+    fn default() -> Self {
+        Self::new(DEFAULT_SERVED_READS_HISTORY)
+    }
+}
+
+impl ReadRoutingStats {
+    /// Creates statistics remembering the nodes that served the latest `history_capacity` read commands.
+    pub fn new(history_capacity: usize) -> Self {
+        Self {
+            history_capacity,
+            served_reads: Mutex::new(VecDeque::with_capacity(history_capacity)),
+            latencies_us: DashMap::new(),
+            unreachable: DashSet::new(),
+        }
+    }
+
+    /// Returns the addresses of the nodes that served the latest `count` read commands, oldest first.
+    pub fn served_reads(&self, count: usize) -> Vec<String> {
+        let served_reads = self.served_reads.lock().unwrap();
+        let skipped = served_reads.len().saturating_sub(count);
+        served_reads.iter().skip(skipped).cloned().collect()
+    }
+
+    /// Returns the smoothed latency of the read commands served by the node at `address`, or `None` if the node
+    /// didn't serve a read command successfully yet.
+    pub fn latency(&self, address: &str) -> Option<Duration> {
+        self.latencies_us
+            .get(address)
+            .map(|latency_us| Duration::from_micros(*latency_us))
+    }
+
+    /// Returns whether the node at `address` passed its latest latency check, or wasn't checked yet.
+    pub fn is_reachable(&self, address: &str) -> bool {
+        !self.unreachable.contains(address)
+    }
+
+    /// Returns the smoothed latency of every node that served a read command, sorted by address.
+    pub fn latencies(&self) -> Vec<(String, Duration)> {
+        let mut latencies: Vec<_> = self
+            .latencies_us
+            .iter()
+            .map(|item| (item.key().clone(), Duration::from_micros(*item.value())))
+            .collect();
+        latencies.sort();
+        latencies
+    }
+
+    /// Records that the node at `address` served a read command, which took `latency` if it succeeded.
+    pub(crate) fn record_read(&self, address: &str, latency: Option<Duration>) {
+        if self.history_capacity > 0 {
+            let mut served_reads = self.served_reads.lock().unwrap();
+            if served_reads.len() == self.history_capacity {
+                served_reads.pop_front();
+            }
+            served_reads.push_back(address.to_string());
+        }
+        if let Some(latency) = latency {
+            self.record_latency(address, latency);
+        }
+    }
+
+    /// Records the result of a latency check of the node at `address`, which took `latency` if it succeeded.
+    pub(crate) fn record_probe(&self, address: &str, latency: Option<Duration>) {
+        match latency {
+            Some(latency) => self.record_latency(address, latency),
+            None => {
+                self.unreachable.insert(address.to_string());
+            }
+        }
+    }
+
+    pub(crate) fn record_latency(&self, address: &str, latency: Duration) {
+        self.unreachable.remove(address);
+        let sample_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.latencies_us
+            .entry(address.to_string())
+            .and_modify(|latency_us| {
+                *latency_us = (*latency_us * (LATENCY_SMOOTHING_FACTOR - 1) + sample_us)
+                    / LATENCY_SMOOTHING_FACTOR;
+            })
+            .or_insert(sample_us);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_served_reads_history_and_smoothed_latency() {
+        let stats = ReadRoutingStats::new(2);
+        stats.record_read("node1:6379", Some(Duration::from_micros(800)));
+        stats.record_read("node2:6379", None);
+        stats.record_read("node1:6379", Some(Duration::from_micros(1600)));
+
+        assert_eq!(stats.served_reads(5), vec!["node2:6379", "node1:6379"]);
+        assert_eq!(stats.served_reads(1), vec!["node1:6379"]);
+        assert_eq!(
+            stats.latency("node1:6379"),
+            Some(Duration::from_micros(900))
+        );
+        assert_eq!(stats.latency("node2:6379"), None);
+
+        stats.record_probe("node1:6379", None);
+        assert!(!stats.is_reachable("node1:6379"));
+        stats.record_probe("node1:6379", Some(Duration::from_micros(900)));
+        assert!(stats.is_reachable("node1:6379"));
+    }
+}
@@ -0,0 +1,83 @@
+//! Statistics of the `MOVED` and `ASK` redirections received by a cluster client, so slot migrations and stale slot
+//! maps are visible in the client metrics.
+
+use dashmap::DashMap;
+
+/// The kind of a redirection received from a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectKind {
+    /// The slot was moved to another node, and the slot map is refreshed.
+    Moved,
+    /// The slot is being migrated, and the key is served by the importing node.
+    Ask,
+}
+
+/// The number of redirections to a single node.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeRedirects {
+    /// The number of `MOVED` redirections to the node.
+    pub moved: u64,
+    /// The number of `ASK` redirections to the node.
+    pub ask: u64,
+}
+
+/// The `MOVED` and `ASK` redirections of a cluster client, counted by the node the requests were redirected to.
+#[derive(Debug, Default)]
+pub struct RedirectStats {
+    redirects: DashMap<String, NodeRedirects>,
+}
+
+impl RedirectStats {
+    /// Records a redirection of a request to the node at `address`.
+    pub(crate) fn record(&self, address: &str, kind: RedirectKind) {
+        let mut redirects = self.redirects.entry(address.to_string()).or_default();
+        match kind {
+            RedirectKind::Moved => redirects.moved += 1,
+            RedirectKind::Ask => redirects.ask += 1,
+        }
+    }
+
+    /// Returns the redirections to each node, sorted by address.
+    pub fn redirects(&self) -> Vec<(String, NodeRedirects)> {
+        let mut redirects: Vec<_> = self
+            .redirects
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        redirects.sort_by(|(first, _), (second, _)| first.cmp(second));
+        redirects
+    }
+
+    /// Returns the total number of redirections to all nodes.
+    pub fn total(&self) -> NodeRedirects {
+        self.redirects
+            .iter()
+            .fold(NodeRedirects::default(), |total, entry| NodeRedirects {
+                moved: total.moved + entry.moved,
+                ask: total.ask + entry.ask,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirects_are_counted_by_node_and_kind() {
+        let stats = RedirectStats::default();
+        stats.record("node2:6379", RedirectKind::Moved);
+        stats.record("node1:6379", RedirectKind::Ask);
+        stats.record("node2:6379", RedirectKind::Ask);
+        stats.record("node2:6379", RedirectKind::Moved);
+
+        assert_eq!(
+            stats.redirects(),
+            vec![
+                ("node1:6379".to_string(), NodeRedirects { moved: 0, ask: 1 }),
+                ("node2:6379".to_string(), NodeRedirects { moved: 2, ask: 1 }),
+            ]
+        );
+        assert_eq!(stats.total(), NodeRedirects { moved: 2, ask: 2 });
+    }
+}
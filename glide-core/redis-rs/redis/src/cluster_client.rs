@@ -1,12 +1,21 @@
-use crate::cluster_slotmap::ReadFromReplicaStrategy;
+#[cfg(feature = "cluster-async")]
+use crate::cluster_async::{NodeCircuitBreakers, ReadRoutingStats, RedirectStats};
+use crate::cluster_slotmap::{ReadFromReplicaStrategy, ReplicaSelectionPolicy};
 #[cfg(feature = "cluster-async")]
 use crate::cluster_topology::{
     DEFAULT_SLOTS_REFRESH_MAX_JITTER_MILLI, DEFAULT_SLOTS_REFRESH_WAIT_DURATION,
 };
 use crate::connection::{ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
 use crate::types::{ErrorKind, ProtocolVersion, RedisError, RedisResult};
+#[cfg(feature = "fault-injection")]
+use crate::FaultInjector;
 use crate::{cluster, cluster::TlsMode};
-use crate::{PushInfo, RetryStrategy};
+use crate::{
+    AddressTranslation, ConnectionEventListener, PushInfo, RetryStrategy, SocketTimeouts,
+    TcpKeepaliveConfig,
+};
+#[cfg(feature = "cluster-async")]
+use crate::{ConnectionRecyclingPolicy, ProxyConfig};
 use rand::Rng;
 #[cfg(feature = "cluster-async")]
 use std::ops::Add;
@@ -32,22 +41,49 @@ struct BuilderParams {
     read_from_replicas: ReadFromReplicaStrategy,
     tls: Option<TlsMode>,
     certs: Option<TlsCertificates>,
+    tls_server_name: Option<String>,
+    tls_skip_hostname_verification: bool,
     retries_configuration: RetryParams,
     connection_timeout: Option<Duration>,
     #[cfg(feature = "cluster-async")]
     topology_checks_interval: Option<Duration>,
     #[cfg(feature = "cluster-async")]
+    topology_checks_max_jitter: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
     connections_validation_interval: Option<Duration>,
     #[cfg(feature = "cluster-async")]
+    heartbeat_interval: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
+    connection_recycling: ConnectionRecyclingPolicy,
+    #[cfg(feature = "cluster-async")]
     slots_refresh_rate_limit: SlotsRefreshRateLimit,
+    address_translation: AddressTranslation,
+    #[cfg(feature = "cluster-async")]
+    proxy: Option<ProxyConfig>,
     client_name: Option<String>,
     lib_name: Option<String>,
+    client_no_evict: bool,
+    client_no_touch: bool,
+    resp3_fallback: bool,
     response_timeout: Option<Duration>,
     protocol: ProtocolVersion,
     reconnect_retry_strategy: Option<RetryStrategy>,
     refresh_topology_from_initial_nodes: bool,
     database_id: i64,
     tcp_nodelay: bool,
+    socket_timeouts: SocketTimeouts,
+    tcp_keepalive: TcpKeepaliveConfig,
+    connection_event_listener: Option<Arc<dyn ConnectionEventListener>>,
+    #[cfg(feature = "fault-injection")]
+    fault_injector: Option<Arc<FaultInjector>>,
+    replica_selection_policy: ReplicaSelectionPolicy,
+    #[cfg(feature = "cluster-async")]
+    read_routing_stats: Option<Arc<ReadRoutingStats>>,
+    #[cfg(feature = "cluster-async")]
+    redirect_stats: Option<Arc<RedirectStats>>,
+    report_redirect_events: bool,
+    #[cfg(feature = "cluster-async")]
+    circuit_breakers: Option<Arc<dyn NodeCircuitBreakers>>,
 }
 
 #[derive(Clone)]
@@ -138,12 +174,26 @@ pub struct ClusterParams {
     #[cfg(feature = "cluster-async")]
     pub(crate) topology_checks_interval: Option<Duration>,
     #[cfg(feature = "cluster-async")]
+    pub(crate) topology_checks_max_jitter: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
     pub(crate) slots_refresh_rate_limit: SlotsRefreshRateLimit,
     #[cfg(feature = "cluster-async")]
     pub(crate) connections_validation_interval: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) heartbeat_interval: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) connection_recycling: ConnectionRecyclingPolicy,
+    /// The addresses dialed instead of the addresses the nodes announce.
+    pub(crate) address_translation: AddressTranslation,
+    /// The proxy that the connections to the nodes go through.
+    #[cfg(feature = "cluster-async")]
+    pub(crate) proxy: Option<ProxyConfig>,
     pub(crate) tls_params: Option<TlsConnParams>,
     pub(crate) client_name: Option<String>,
     pub(crate) lib_name: Option<String>,
+    pub(crate) client_no_evict: bool,
+    pub(crate) client_no_touch: bool,
+    pub(crate) resp3_fallback: bool,
     pub(crate) connection_timeout: Duration,
     pub(crate) response_timeout: Duration,
     pub(crate) protocol: ProtocolVersion,
@@ -151,6 +201,19 @@ pub struct ClusterParams {
     pub(crate) refresh_topology_from_initial_nodes: bool,
     pub(crate) database_id: i64,
     pub(crate) tcp_nodelay: bool,
+    pub(crate) socket_timeouts: SocketTimeouts,
+    pub(crate) tcp_keepalive: TcpKeepaliveConfig,
+    pub(crate) connection_event_listener: Option<Arc<dyn ConnectionEventListener>>,
+    #[cfg(feature = "fault-injection")]
+    pub(crate) fault_injector: Option<Arc<FaultInjector>>,
+    pub(crate) replica_selection_policy: ReplicaSelectionPolicy,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) read_routing_stats: Arc<ReadRoutingStats>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) redirect_stats: Arc<RedirectStats>,
+    pub(crate) report_redirect_events: bool,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) circuit_breakers: Option<Arc<dyn NodeCircuitBreakers>>,
 }
 
 impl ClusterParams {
@@ -158,7 +221,18 @@ impl ClusterParams {
         let tls_params = {
             let retrieved_tls_params = value.certs.clone().map(retrieve_tls_certificates);
 
-            retrieved_tls_params.transpose()?
+            let mut tls_params = retrieved_tls_params.transpose()?;
+            if let Some(server_name) = value.tls_server_name {
+                tls_params = Some(tls_params.unwrap_or_default().with_server_name(server_name));
+            }
+            if value.tls_skip_hostname_verification {
+                tls_params = Some(
+                    tls_params
+                        .unwrap_or_default()
+                        .without_hostname_verification(),
+                );
+            }
+            tls_params
         };
 
         Ok(Self {
@@ -171,18 +245,43 @@ impl ClusterParams {
             #[cfg(feature = "cluster-async")]
             topology_checks_interval: value.topology_checks_interval,
             #[cfg(feature = "cluster-async")]
+            topology_checks_max_jitter: value.topology_checks_max_jitter,
+            #[cfg(feature = "cluster-async")]
             slots_refresh_rate_limit: value.slots_refresh_rate_limit,
             #[cfg(feature = "cluster-async")]
             connections_validation_interval: value.connections_validation_interval,
+            #[cfg(feature = "cluster-async")]
+            heartbeat_interval: value.heartbeat_interval,
+            #[cfg(feature = "cluster-async")]
+            connection_recycling: value.connection_recycling,
+            address_translation: value.address_translation,
+            #[cfg(feature = "cluster-async")]
+            proxy: value.proxy,
             tls_params,
             client_name: value.client_name,
             lib_name: value.lib_name,
+            client_no_evict: value.client_no_evict,
+            client_no_touch: value.client_no_touch,
+            resp3_fallback: value.resp3_fallback,
             response_timeout: value.response_timeout.unwrap_or(Duration::MAX),
             protocol: value.protocol,
             reconnect_retry_strategy: value.reconnect_retry_strategy,
             refresh_topology_from_initial_nodes: value.refresh_topology_from_initial_nodes,
             database_id: value.database_id,
             tcp_nodelay: value.tcp_nodelay,
+            socket_timeouts: value.socket_timeouts,
+            tcp_keepalive: value.tcp_keepalive,
+            connection_event_listener: value.connection_event_listener,
+            #[cfg(feature = "fault-injection")]
+            fault_injector: value.fault_injector,
+            replica_selection_policy: value.replica_selection_policy,
+            #[cfg(feature = "cluster-async")]
+            read_routing_stats: value.read_routing_stats.unwrap_or_default(),
+            #[cfg(feature = "cluster-async")]
+            redirect_stats: value.redirect_stats.unwrap_or_default(),
+            report_redirect_events: value.report_redirect_events,
+            #[cfg(feature = "cluster-async")]
+            circuit_breakers: value.circuit_breakers,
         })
     }
 }
@@ -318,6 +417,24 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets whether the connections of the new ClusterClient send `CLIENT NO-EVICT ON` when they are set up.
+    pub fn client_no_evict(mut self, client_no_evict: bool) -> ClusterClientBuilder {
+        self.builder_params.client_no_evict = client_no_evict;
+        self
+    }
+
+    /// Sets whether the connections of the new ClusterClient send `CLIENT NO-TOUCH ON` when they are set up.
+    pub fn client_no_touch(mut self, client_no_touch: bool) -> ClusterClientBuilder {
+        self.builder_params.client_no_touch = client_no_touch;
+        self
+    }
+
+    /// Sets whether the connections of the new ClusterClient fall back to RESP2 when a node rejects `HELLO 3`.
+    pub fn resp3_fallback(mut self, resp3_fallback: bool) -> ClusterClientBuilder {
+        self.builder_params.resp3_fallback = resp3_fallback;
+        self
+    }
+
     /// Sets password for the new ClusterClient.
     pub fn password(mut self, password: String) -> ClusterClientBuilder {
         self.builder_params.password = Some(password);
@@ -385,6 +502,23 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets the name sent in the SNI extension and checked against the certificates of all nodes,
+    /// instead of the host of each node.
+    ///
+    /// Only used for TLS connections.
+    pub fn tls_server_name(mut self, server_name: String) -> ClusterClientBuilder {
+        self.builder_params.tls_server_name = Some(server_name);
+        self
+    }
+
+    /// Verifies the certificate chain of the nodes, but not that the certificates were issued for the node hostnames.
+    ///
+    /// Only used for secure TLS connections. See [`TlsConnParams::without_hostname_verification`].
+    pub fn tls_skip_hostname_verification(mut self) -> ClusterClientBuilder {
+        self.builder_params.tls_skip_hostname_verification = true;
+        self
+    }
+
     /// Enables reading from replicas for all new connections (default is disabled).
     ///
     /// If enabled, then read queries will go to the replica nodes & write queries will go to the
@@ -402,6 +536,7 @@ impl ClusterClientBuilder {
     /// `ReadFromReplicaStrategy::AZAffinityReplicasAndPrimary(availability_zone)` - attempt to access nodes in the same availability zone.
     ///  prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     /// `ReadFromReplicaStrategy::RoundRobin` - reads are distributed across replicas for load balancing using round-robin algorithm. Falling back to primary if needed.
+    /// `ReadFromReplicaStrategy::LowestLatency` - reads go to the reachable replica with the lowest latency, measured by the periodic topology checks. Falling back to primary if needed.
     /// `ReadFromReplicaStrategy::AlwaysFromPrimary` ensures all read and write queries are directed to the primary node.
     ///
     /// # Parameters
@@ -435,6 +570,14 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Adds a random jitter of up to `max_jitter` to each interval of the periodic topology checks, so the clients
+    /// that were created together don't check the topology of the cluster at the same time.
+    #[cfg(feature = "cluster-async")]
+    pub fn periodic_topology_checks_jitter(mut self, max_jitter: Duration) -> ClusterClientBuilder {
+        self.builder_params.topology_checks_max_jitter = Some(max_jitter);
+        self
+    }
+
     /// Enables periodic connections checks for this client.
     /// If enabled, the connections to the cluster nodes will be validated periodically, per configured interval.
     /// In addition, for tokio runtime, passive disconnections could be detected instantly,
@@ -506,6 +649,136 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets the socket read and write timeouts of the node connections.
+    ///
+    /// Unlike the response timeout, they detect a peer that stopped responding or accepting data even while
+    /// a request with a long timeout, such as a blocking command, is pending.
+    /// Defaults to no socket timeouts.
+    pub fn socket_timeouts(mut self, socket_timeouts: SocketTimeouts) -> ClusterClientBuilder {
+        self.builder_params.socket_timeouts = socket_timeouts;
+        self
+    }
+
+    /// Sets the TCP keepalive settings of the node connections.
+    ///
+    /// Settings that aren't set use the system defaults.
+    pub fn tcp_keepalive(mut self, tcp_keepalive: TcpKeepaliveConfig) -> ClusterClientBuilder {
+        self.builder_params.tcp_keepalive = tcp_keepalive;
+        self
+    }
+
+    /// Enables sending `PING` to every node in the given interval, keeping idle connections alive through NATs and
+    /// load balancers. Nodes whose connections fail the `PING` are reconnected.
+    ///
+    /// Defaults to no heartbeat.
+    #[cfg(feature = "cluster-async")]
+    pub fn heartbeat_interval(mut self, interval: Duration) -> ClusterClientBuilder {
+        self.builder_params.heartbeat_interval = Some(interval);
+        self
+    }
+
+    /// Sets the limits after which the node connections are replaced, reporting each replacement to the connection
+    /// event listener as a [`crate::ConnectionEvent::Recycled`] event.
+    ///
+    /// Defaults to no limits.
+    #[cfg(feature = "cluster-async")]
+    pub fn connection_recycling(
+        mut self,
+        connection_recycling: ConnectionRecyclingPolicy,
+    ) -> ClusterClientBuilder {
+        self.builder_params.connection_recycling = connection_recycling;
+        self
+    }
+
+    /// Sets the addresses dialed instead of the addresses the nodes announce, for nodes that aren't reachable at their
+    /// announced addresses. The translation is shared with the client, so replacing its table applies to the
+    /// connections made afterwards.
+    ///
+    /// Defaults to no translation.
+    pub fn address_translation(
+        mut self,
+        address_translation: AddressTranslation,
+    ) -> ClusterClientBuilder {
+        self.builder_params.address_translation = address_translation;
+        self
+    }
+
+    /// Sets the SOCKS5 or HTTP CONNECT proxy that the connections to the nodes go through. The proxy resolves the
+    /// host names of the nodes, after their address translation.
+    ///
+    /// Defaults to connecting directly.
+    #[cfg(feature = "cluster-async")]
+    pub fn proxy(mut self, proxy: ProxyConfig) -> ClusterClientBuilder {
+        self.builder_params.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets a listener that is notified when node connections are established, lost or reconnecting,
+    /// and when the cluster topology changes.
+    pub fn connection_event_listener(
+        mut self,
+        listener: Arc<dyn ConnectionEventListener>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.connection_event_listener = Some(listener);
+        self
+    }
+
+    /// Sets how the AZ affinity strategies choose among the replicas in the client's availability zone.
+    /// Defaults to [`ReplicaSelectionPolicy::RoundRobin`].
+    pub fn replica_selection_policy(
+        mut self,
+        replica_selection_policy: ReplicaSelectionPolicy,
+    ) -> ClusterClientBuilder {
+        self.builder_params.replica_selection_policy = replica_selection_policy;
+        self
+    }
+
+    /// Sets the statistics that the routing of the read commands is recorded in, so they can be read while the client
+    /// is used.
+    #[cfg(feature = "cluster-async")]
+    pub fn read_routing_stats(
+        mut self,
+        read_routing_stats: Arc<ReadRoutingStats>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.read_routing_stats = Some(read_routing_stats);
+        self
+    }
+
+    /// Sets the statistics that the `MOVED` and `ASK` redirections are counted in, so they can be read while the
+    /// client is used.
+    #[cfg(feature = "cluster-async")]
+    pub fn redirect_stats(mut self, redirect_stats: Arc<RedirectStats>) -> ClusterClientBuilder {
+        self.builder_params.redirect_stats = Some(redirect_stats);
+        self
+    }
+
+    /// Enables reporting each `MOVED` and `ASK` redirection to the connection event listener as a
+    /// [`crate::ConnectionEvent::Redirected`] event.
+    ///
+    /// Defaults to `false`, since redirections can be frequent while slots are migrated.
+    pub fn report_redirect_events(mut self, report_redirect_events: bool) -> ClusterClientBuilder {
+        self.builder_params.report_redirect_events = report_redirect_events;
+        self
+    }
+
+    /// Sets the circuit breakers consulted before a command is sent to a single node. Multi-node commands and
+    /// pipelines aren't sent through the breakers.
+    #[cfg(feature = "cluster-async")]
+    pub fn circuit_breakers(
+        mut self,
+        circuit_breakers: Arc<dyn NodeCircuitBreakers>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.circuit_breakers = Some(circuit_breakers);
+        self
+    }
+
+    /// Sets the injector of the faults injected into the commands sent to the nodes.
+    #[cfg(feature = "fault-injection")]
+    pub fn fault_injector(mut self, fault_injector: Arc<FaultInjector>) -> ClusterClientBuilder {
+        self.builder_params.fault_injector = Some(fault_injector);
+        self
+    }
+
     /// Enables timing out on slow connection time.
     ///
     /// If enabled, the cluster will only wait the given time on each connection attempt to each node.
@@ -93,6 +93,14 @@ pub enum SingleNodeRoutingInfo {
         /// port of the node
         port: u16,
     },
+    /// Route to a replica of the given slot that is located in the given availability zone.
+    /// If no replica in that zone is connected, any available replica, or the primary, is used instead.
+    ReplicaInAZ {
+        /// slot that the replica should serve
+        slot: u16,
+        /// availability zone of the preferred replica
+        az: String,
+    },
 }
 
 impl From<Option<Route>> for SingleNodeRoutingInfo {
@@ -165,7 +173,9 @@ pub fn aggregate(values: Vec<Value>, op: AggregateOp) -> RedisResult<Value> {
     Ok(Value::Int(result))
 }
 
-/// Aggreagte numeric responses by a boolean operator.
+/// Aggregate numeric responses by a boolean operator.
+/// Each node is expected to reply with an array of the same length, holding either integers or booleans
+/// (e.g. `SCRIPT EXISTS`), and the result is the element-wise combination of all the arrays.
 pub fn logical_aggregate(values: Vec<Value>, op: LogicalAggregateOp) -> RedisResult<Value> {
     let initial_value = match op {
         LogicalAggregateOp::And => true,
@@ -188,9 +198,18 @@ pub fn logical_aggregate(values: Vec<Value>, op: LogicalAggregateOp) -> RedisRes
         } else {
             acc
         };
+        if acc.len() != values.len() {
+            return Err((
+                ErrorKind::TypeError,
+                "expected arrays of the same length as responses",
+                format!("got {} and {}", acc.len(), values.len()),
+            )
+                .into());
+        }
         for (index, value) in values.into_iter().enumerate() {
-            let int = match value {
-                Value::Int(int) => int,
+            let flag = match value {
+                Value::Int(int) => int > 0,
+                Value::Boolean(boolean) => boolean,
                 _ => {
                     return Err((
                         ErrorKind::TypeError,
@@ -200,7 +219,7 @@ pub fn logical_aggregate(values: Vec<Value>, op: LogicalAggregateOp) -> RedisRes
                 }
             };
             acc[index] = match op {
-                LogicalAggregateOp::And => acc[index] && (int > 0),
+                LogicalAggregateOp::And => acc[index] && flag,
             };
         }
         Ok(acc)
@@ -928,6 +947,92 @@ impl RoutingInfo {
     }
 }
 
+/// Returns the keys of the given `routable`, in the order they appear in the command.
+/// Commands that aren't routed by their keys, such as `PING` or `FLUSHALL`, have no keys.
+pub fn command_keys<R>(routable: &R) -> Vec<&[u8]>
+where
+    R: Routable + ?Sized,
+{
+    let Some(cmd) = routable.command() else {
+        return Vec::new();
+    };
+    // Collects `count` keys starting at `first`, separated by `step` arguments.
+    let keys_from = |first: usize, step: usize, count: Option<usize>| -> Vec<&[u8]> {
+        (0..count.unwrap_or(usize::MAX))
+            .map_while(|index| routable.arg_idx(first + index * step))
+            .collect()
+    };
+    let key_count = |idx: usize| {
+        routable
+            .arg_idx(idx)
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .and_then(|x| x.parse::<usize>().ok())
+            .unwrap_or(0)
+    };
+    match base_routing(&cmd) {
+        RouteBy::FirstKey => match cmd.as_slice() {
+            // The second argument is a key as well, such as the destination of a move or a copy.
+            b"BLMOVE" | b"BRPOPLPUSH" | b"COPY" | b"GEOSEARCHSTORE" | b"LCS" | b"LMOVE"
+            | b"RENAME" | b"RENAMENX" | b"RPOPLPUSH" | b"SMOVE" | b"ZRANGESTORE" => {
+                keys_from(1, 1, Some(2))
+            }
+            b"PFCOUNT" | b"PFMERGE" | b"SDIFF" | b"SDIFFSTORE" | b"SINTER" | b"SINTERSTORE"
+            | b"SUNION" | b"SUNIONSTORE" => keys_from(1, 1, None),
+            b"BLPOP" | b"BRPOP" | b"BZPOPMAX" | b"BZPOPMIN" => {
+                let mut keys = keys_from(1, 1, None);
+                // The last argument is the timeout.
+                keys.pop();
+                keys
+            }
+            b"ZDIFFSTORE" | b"ZINTERSTORE" | b"ZUNIONSTORE" => {
+                // The destination is followed by the number of source keys and the source keys.
+                let mut keys = keys_from(1, 1, Some(1));
+                keys.extend(keys_from(3, 1, Some(key_count(2))));
+                keys
+            }
+            b"GEORADIUS" | b"GEORADIUSBYMEMBER" | b"SORT" => {
+                let mut keys = keys_from(1, 1, Some(1));
+                for option in [&b"STORE"[..], b"STOREDIST"] {
+                    if let Some(position) = routable.position(option) {
+                        keys.extend(keys_from(position + 1, 1, Some(1)));
+                    }
+                }
+                keys
+            }
+            _ => keys_from(1, 1, Some(1)),
+        },
+        // The destination of BITOP is followed by the source keys.
+        RouteBy::SecondArg if cmd.as_slice() == b"BITOP" => keys_from(2, 1, None),
+        RouteBy::SecondArg => keys_from(2, 1, Some(1)),
+        RouteBy::ThirdArg => keys_from(3, 1, Some(1)),
+        RouteBy::SecondArgAfterKeyCount => keys_from(2, 1, Some(key_count(1))),
+        RouteBy::ThirdArgAfterKeyCount => keys_from(3, 1, Some(key_count(2))),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeysOnly) => keys_from(1, 1, None),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeyValuePairs) => keys_from(1, 2, None),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeyWithTwoArgTriples) => keys_from(1, 3, None),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeysAndLastArg) => {
+            let mut keys = keys_from(1, 1, None);
+            // The last argument is the shared parameter.
+            keys.pop();
+            keys
+        }
+        RouteBy::StreamsIndex => {
+            let Some(streams_position) = routable.position(b"STREAMS") else {
+                return Vec::new();
+            };
+            // The keys are followed by one ID per key.
+            let mut args = keys_from(streams_position + 1, 1, None);
+            args.truncate(args.len() / 2);
+            args
+        }
+        RouteBy::AllNodes
+        | RouteBy::AllPrimaries
+        | RouteBy::Random
+        | RouteBy::SecondArgSlot
+        | RouteBy::Undefined => Vec::new(),
+    }
+}
+
 /// Returns true if the given `routable` represents a readonly command.
 pub fn is_readonly(routable: &impl Routable) -> bool {
     match routable.command() {
@@ -1491,11 +1596,12 @@ fn random_slot() -> u16 {
 #[cfg(test)]
 mod tests_routing {
     use super::{
-        command_for_multi_slot_indices, AggregateOp, MultiSlotArgPattern, MultipleNodeRoutingInfo,
-        ResponsePolicy, Route, RoutingInfo, ShardAddrs, SingleNodeRoutingInfo, SlotAddr,
+        command_for_multi_slot_indices, command_keys, AggregateOp, LogicalAggregateOp,
+        MultiSlotArgPattern, MultipleNodeRoutingInfo, ResponsePolicy, Route, RoutingInfo,
+        ShardAddrs, SingleNodeRoutingInfo, SlotAddr,
     };
     use crate::cluster_routing::ShardUpdateResult;
-    use crate::{cluster_topology::slot, cmd, parser::parse_redis_value, Value};
+    use crate::{cluster_topology::slot, cmd, parser::parse_redis_value, ErrorKind, Value};
     use core::panic;
     use std::sync::{Arc, RwLock};
 
@@ -1744,6 +1850,97 @@ mod tests_routing {
             ]).unwrap()), Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route(slot, SlotAddr::Master)))) if slot == 5210));
     }
 
+    #[test]
+    fn test_command_keys() {
+        let keys = |cmd: &crate::Cmd| -> Vec<Vec<u8>> {
+            command_keys(cmd).into_iter().map(<[u8]>::to_vec).collect()
+        };
+        let as_keys = |keys: &[&str]| -> Vec<Vec<u8>> {
+            keys.iter().map(|key| key.as_bytes().to_vec()).collect()
+        };
+
+        assert_eq!(keys(cmd("GET").arg("foo")), as_keys(&["foo"]));
+        assert_eq!(
+            keys(cmd("MSET").arg("foo").arg("bar").arg("baz").arg("qux")),
+            as_keys(&["foo", "baz"])
+        );
+        assert_eq!(
+            keys(cmd("JSON.MGET").arg("foo").arg("bar").arg("$")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(
+                cmd("EVAL")
+                    .arg("script")
+                    .arg(2)
+                    .arg("foo")
+                    .arg("bar")
+                    .arg("arg")
+            ),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(
+                cmd("XREAD")
+                    .arg("COUNT")
+                    .arg(2)
+                    .arg("STREAMS")
+                    .arg("foo")
+                    .arg("bar")
+                    .arg(0)
+                    .arg(0)
+            ),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("RENAME").arg("foo").arg("bar")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("LMOVE").arg("foo").arg("bar").arg("LEFT").arg("RIGHT")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("SMOVE").arg("foo").arg("bar").arg("member")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("COPY").arg("foo").arg("bar").arg("REPLACE")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("SINTERSTORE").arg("foo").arg("bar").arg("baz")),
+            as_keys(&["foo", "bar", "baz"])
+        );
+        assert_eq!(
+            keys(
+                cmd("ZUNIONSTORE")
+                    .arg("foo")
+                    .arg(2)
+                    .arg("bar")
+                    .arg("baz")
+                    .arg("WEIGHTS")
+                    .arg(1)
+                    .arg(2)
+            ),
+            as_keys(&["foo", "bar", "baz"])
+        );
+        assert_eq!(
+            keys(cmd("BLPOP").arg("foo").arg("bar").arg(0)),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("SORT").arg("foo").arg("ALPHA").arg("STORE").arg("bar")),
+            as_keys(&["foo", "bar"])
+        );
+        assert_eq!(
+            keys(cmd("BITOP").arg("AND").arg("foo").arg("bar").arg("baz")),
+            as_keys(&["foo", "bar", "baz"])
+        );
+        assert!(keys(&cmd("PING")).is_empty());
+        assert!(keys(&cmd("FLUSHALL")).is_empty());
+    }
+
     #[test]
     fn test_multi_shard_keys_only() {
         let mut cmd = cmd("DEL");
@@ -2004,6 +2201,36 @@ mod tests_routing {
         );
     }
 
+    #[test]
+    fn test_logical_aggregate_and() {
+        let input = vec![
+            Value::Array(vec![Value::Int(1), Value::Int(1), Value::Int(0)]),
+            Value::Array(vec![Value::Int(1), Value::Int(0), Value::Int(1)]),
+        ];
+        let result = super::logical_aggregate(input, LogicalAggregateOp::And).unwrap();
+        assert_eq!(
+            result,
+            Value::Array(vec![Value::Int(1), Value::Int(0), Value::Int(0)])
+        );
+
+        let input = vec![
+            Value::Array(vec![Value::Boolean(true), Value::Boolean(true)]),
+            Value::Array(vec![Value::Int(1), Value::Boolean(false)]),
+        ];
+        let result = super::logical_aggregate(input, LogicalAggregateOp::And).unwrap();
+        assert_eq!(result, Value::Array(vec![Value::Int(1), Value::Int(0)]));
+    }
+
+    #[test]
+    fn test_logical_aggregate_fails_on_mismatched_lengths() {
+        let input = vec![
+            Value::Array(vec![Value::Int(1)]),
+            Value::Array(vec![Value::Int(1), Value::Int(1)]),
+        ];
+        let result = super::logical_aggregate(input, LogicalAggregateOp::And);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::TypeError);
+    }
+
     #[test]
     fn test_combine_map_results() {
         let input = vec![];
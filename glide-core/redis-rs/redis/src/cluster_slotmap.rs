@@ -42,6 +42,24 @@ pub enum ReadFromReplicaStrategy {
     /// Spread the read requests among nodes within the client's Availability Zone (AZ) in a round robin manner,
     /// prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     AZAffinityReplicasAndPrimary(String),
+    /// Route the read requests to the connected replica with the lowest measured latency.
+    /// The latencies are measured by the periodic topology checks, and by the reads served by the replicas.
+    /// Replicas whose latency wasn't measured yet are chosen first, and replicas that failed their latest
+    /// latency check are skipped. If no replica is available, route the requests to the primary.
+    LowestLatency,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// How the AZ affinity strategies choose among the connected replicas in the client's availability zone.
+pub enum ReplicaSelectionPolicy {
+    #[default]
+    /// Cycle through the replicas in order.
+    RoundRobin,
+    /// Choose a random replica for each read.
+    Random,
+    /// Choose the replica with the lowest smoothed read latency.
+    /// Replicas that didn't serve a read yet are chosen first, so the latency of every replica gets measured.
+    LowestLatency,
 }
 
 #[derive(Debug, Default)]
@@ -63,7 +81,8 @@ fn get_address_from_slot(
     }
     match read_from_replica {
         ReadFromReplicaStrategy::AlwaysFromPrimary => addrs.primary(),
-        ReadFromReplicaStrategy::RoundRobin => {
+        // The sync client doesn't measure the latencies of the nodes.
+        ReadFromReplicaStrategy::RoundRobin | ReadFromReplicaStrategy::LowestLatency => {
             let index = slot
                 .last_used_replica
                 .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
@@ -6,11 +6,16 @@ use futures_util::{
 };
 #[cfg(feature = "aio")]
 use std::pin::Pin;
-use std::{borrow::Borrow, fmt, io};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::{borrow::Borrow, fmt, io, time::Duration, time::Instant};
 
 use crate::connection::ConnectionLike;
 use crate::pipeline::Pipeline;
-use crate::types::{from_owned_redis_value, FromRedisValue, RedisResult, RedisWrite, ToRedisArgs};
+use crate::types::{
+    from_owned_redis_value, make_extension_error, FromRedisValue, RedisError, RedisResult,
+    RedisWrite, ToRedisArgs,
+};
 use telemetrylib::GlideSpan;
 
 /// An argument to a redis command
@@ -35,8 +40,64 @@ pub struct Cmd {
     span: Option<GlideSpan>,
     //  A flag indicating whether this is a fenced command  (will have PING appended to ensure ordering)
     is_fenced: bool,
+    /// The time after which the command is dropped instead of being sent
+    deadline: Option<Instant>,
+    /// Records the attempts to send the command
+    trace: Option<Arc<CommandTrace>>,
+}
+
+/// Records how a command was sent, for per-command diagnostics: the number of attempts, including the retries and
+/// redirections, the node of the latest attempt, and how long the command waited before its first attempt. Shared by
+/// the clones of the command.
+#[derive(Debug)]
+pub struct CommandTrace {
+    created_at: Instant,
+    first_attempt_at: OnceLock<Instant>,
+    attempts: AtomicU32,
+    node: Mutex<Option<String>>,
+}
+
+impl Default for CommandTrace {
+    fn default() -> Self {
+        Self {
+            created_at: Instant::now(),
+            first_attempt_at: OnceLock::new(),
+            attempts: AtomicU32::new(0),
+            node: Mutex::new(None),
+        }
+    }
 }
 
+impl CommandTrace {
+    /// Records an attempt to send the command to the node at `address`.
+    pub fn record_attempt(&self, address: &str) {
+        self.first_attempt_at.get_or_init(Instant::now);
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+        *self.node.lock().unwrap() = Some(address.to_string());
+    }
+
+    /// The number of attempts to send the command.
+    pub fn attempts(&self) -> u32 {
+        self.attempts.load(Ordering::Relaxed)
+    }
+
+    /// The address of the node the command was last sent to, if it was sent.
+    pub fn node(&self) -> Option<String> {
+        self.node.lock().unwrap().clone()
+    }
+
+    /// How long the command waited between the creation of the trace and its first attempt, such as in the
+    /// inflight queues or for a reconnection. `None` if it wasn't sent.
+    pub fn queue_time(&self) -> Option<Duration> {
+        self.first_attempt_at
+            .get()
+            .map(|first_attempt_at| *first_attempt_at - self.created_at)
+    }
+}
+
+/// The code of the error returned for a command whose deadline passed before it was sent.
+pub const DEADLINE_EXCEEDED_BEFORE_SEND_CODE: &str = "DEADLINEEXCEEDEDBEFORESEND";
+
 /// The PING command used to fence other commands for ordering guarantees
 const FENCE_COMMAND: &[u8] = b"*1\r\n$4\r\nPING\r\n";
 
@@ -344,6 +405,8 @@ impl Cmd {
             no_response: false,
             span: None,
             is_fenced: false,
+            deadline: None,
+            trace: None,
         }
     }
 
@@ -356,6 +419,8 @@ impl Cmd {
             no_response: false,
             span: None,
             is_fenced: false,
+            deadline: None,
+            trace: None,
         }
     }
 
@@ -648,6 +713,49 @@ impl Cmd {
     pub fn is_fenced(&self) -> bool {
         self.is_fenced
     }
+
+    /// Sets the time after which the command is dropped instead of being sent, such as while it waits for a
+    /// reconnection, failing with a `DEADLINEEXCEEDEDBEFORESEND` error. A command that was already sent isn't affected.
+    #[inline]
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) -> &mut Cmd {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Return the time after which the command is dropped instead of being sent
+    #[inline]
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Sets the trace that records the attempts to send the command.
+    #[inline]
+    pub fn set_trace(&mut self, trace: Option<Arc<CommandTrace>>) -> &mut Cmd {
+        self.trace = trace;
+        self
+    }
+
+    /// Returns the trace that records the attempts to send the command, if one is set
+    #[inline]
+    pub fn trace(&self) -> Option<&CommandTrace> {
+        self.trace.as_deref()
+    }
+
+    /// Fails with a `DEADLINEEXCEEDEDBEFORESEND` error if the deadline of the command passed. Called right before the
+    /// command is sent.
+    pub fn check_deadline(&self) -> RedisResult<()> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(deadline_exceeded_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn deadline_exceeded_error() -> RedisError {
+    make_extension_error(
+        DEADLINE_EXCEEDED_BEFORE_SEND_CODE.to_string(),
+        Some("The deadline of the command passed before it was sent".to_string()),
+    )
 }
 
 impl fmt::Debug for Cmd {
@@ -722,6 +830,7 @@ pub fn pipe() -> Pipeline {
 #[cfg(feature = "cluster")]
 mod tests {
     use super::Cmd;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn test_cmd_arg_idx() {
@@ -738,4 +847,35 @@ mod tests {
         assert_eq!(c.arg_idx(3), None);
         assert_eq!(c.arg_idx(4), None);
     }
+
+    #[test]
+    fn test_cmd_deadline() {
+        let mut c = Cmd::new();
+        c.arg("GET").arg("foo");
+        assert!(c.check_deadline().is_ok());
+
+        c.set_deadline(Some(Instant::now() + Duration::from_secs(60)));
+        assert!(c.check_deadline().is_ok());
+
+        c.set_deadline(Some(Instant::now()));
+        let err = c.check_deadline().unwrap_err();
+        assert_eq!(err.code(), Some(super::DEADLINE_EXCEEDED_BEFORE_SEND_CODE));
+    }
+
+    #[test]
+    fn test_cmd_trace() {
+        let mut c = Cmd::new();
+        c.arg("GET").arg("foo");
+        c.set_trace(Some(Default::default()));
+        assert_eq!(c.trace().unwrap().queue_time(), None);
+
+        // Clones of the command, such as the retries, record into the same trace.
+        let retry = c.clone();
+        c.trace().unwrap().record_attempt("node1:6379");
+        retry.trace().unwrap().record_attempt("node2:6379");
+        let trace = c.trace().unwrap();
+        assert_eq!(trace.attempts(), 2);
+        assert_eq!(trace.node().as_deref(), Some("node2:6379"));
+        assert!(trace.queue_time().is_some());
+    }
 }
@@ -12,6 +12,7 @@
 //! - [`ClusterScanArgs`]: Configuration for scan operations including filtering and behavior options
 //! - [`ScanStateRC`]: Thread-safe reference-counted wrapper for scan state management
 //! - [`ScanState`]: Internal state tracking for cluster-wide scanning progress
+//! - [`ScanProgress`]: Serializable snapshot of the scan state, used to resume a scan in another process
 //! - [`ObjectType`]: Supported data types for filtering scan results
 //!
 //! # Key Features
@@ -317,6 +318,30 @@ impl ScanStateRC {
         self.status == ScanStateStage::Finished
     }
 
+    /// Returns a snapshot of the scan progress, or `None` if the scan didn't start yet or already finished.
+    pub fn progress(&self) -> Option<ScanProgress> {
+        self.state_from_wrapper().map(|state| ScanProgress {
+            cursor: state.cursor,
+            scanned_slots_map: state.scanned_slots_map,
+            address_in_scan: state.address_in_scan.to_string(),
+            address_epoch: state.address_epoch,
+        })
+    }
+
+    /// Creates a new instance of [`ScanStateRC`] that continues the scan from the given progress.
+    ///
+    /// If the epoch of the scanned address changed since the progress was taken, the scan of that address
+    /// restarts, so keys may be returned more than once, but no key that existed during the whole scan is missed.
+    pub fn from_progress(progress: ScanProgress) -> Self {
+        Self::from_scan_state(ScanState::new(
+            progress.cursor,
+            progress.scanned_slots_map,
+            Arc::new(progress.address_in_scan),
+            progress.address_epoch,
+            ScanStateStage::InProgress,
+        ))
+    }
+
     /// Returns a clone of the scan state, if it exist.
     pub(crate) fn state_from_wrapper(&self) -> Option<ScanState> {
         if self.status == ScanStateStage::Initiating || self.status == ScanStateStage::Finished {
@@ -327,6 +352,97 @@ impl ScanStateRC {
     }
 }
 
+const SCAN_PROGRESS_TOKEN_VERSION: &str = "v1";
+
+/// A serializable snapshot of an in-progress cluster scan.
+///
+/// The progress can be persisted as a token with [`ScanProgress::to_token`], and later passed to
+/// [`ScanStateRC::from_progress`] to continue the scan, for example after a process restart.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ScanProgress {
+    /// The cursor of the scan on the address currently being scanned.
+    pub cursor: u64,
+    /// The address currently being scanned.
+    pub address_in_scan: String,
+    /// The epoch of the address currently being scanned, when the progress was taken.
+    pub address_epoch: u64,
+    scanned_slots_map: SlotsBitsArray,
+}
+
+impl ScanProgress {
+    /// Returns the number of slots that were completely scanned.
+    pub fn slots_completed(&self) -> u16 {
+        self.scanned_slots_map
+            .iter()
+            .map(|word| word.count_ones() as u16)
+            .sum()
+    }
+
+    /// Serializes the progress into a token, in the format `v1:<cursor>:<epoch>:<scanned slots bitmap as hex>:<address>`.
+    pub fn to_token(&self) -> String {
+        let slots: String = self
+            .scanned_slots_map
+            .iter()
+            .map(|word| format!("{word:016x}"))
+            .collect();
+        format!(
+            "{SCAN_PROGRESS_TOKEN_VERSION}:{}:{}:{slots}:{}",
+            self.cursor, self.address_epoch, self.address_in_scan
+        )
+    }
+
+    /// Parses a token created by [`ScanProgress::to_token`].
+    pub fn from_token(token: &str) -> RedisResult<Self> {
+        let invalid_token = || {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid cluster scan progress token",
+                format!("Token: `{token}`"),
+            ))
+        };
+        // The address is last, since it may contain `:` itself.
+        let mut parts = token.splitn(5, ':');
+        if parts.next() != Some(SCAN_PROGRESS_TOKEN_VERSION) {
+            return Err(invalid_token());
+        }
+        let cursor = parts
+            .next()
+            .and_then(|cursor| cursor.parse().ok())
+            .ok_or_else(invalid_token)?;
+        let address_epoch = parts
+            .next()
+            .and_then(|epoch| epoch.parse().ok())
+            .ok_or_else(invalid_token)?;
+        let slots = parts
+            .next()
+            .filter(|slots| slots.len() == BITS_ARRAY_SIZE as usize * 16 && slots.is_ascii())
+            .ok_or_else(invalid_token)?;
+        let address_in_scan = parts
+            .next()
+            .filter(|address| !address.is_empty())
+            .ok_or_else(invalid_token)?
+            .to_string();
+
+        let mut scanned_slots_map: SlotsBitsArray = [0; BITS_ARRAY_SIZE as usize];
+        for (word, hex) in scanned_slots_map
+            .iter_mut()
+            .zip(slots.as_bytes().chunks(16))
+        {
+            // The chunks are valid UTF-8, since the slots string is ASCII.
+            *word = std::str::from_utf8(hex)
+                .ok()
+                .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+                .ok_or_else(invalid_token)?;
+        }
+        Ok(Self {
+            cursor,
+            address_in_scan,
+            address_epoch,
+            scanned_slots_map,
+        })
+    }
+}
+
 /// Represents the state of a cluster scan operation.
 ///
 /// This struct keeps track of the current cursor, which slots have been scanned,
@@ -828,6 +944,34 @@ mod tests {
         assert!(args.allow_non_covered_slots);
     }
 
+    #[test]
+    fn test_scan_progress_token_round_trip() {
+        let mut scanned_slots_map = [0; BITS_ARRAY_SIZE as usize];
+        for slot in 0..100 {
+            mark_slot_as_scanned(&mut scanned_slots_map, slot);
+        }
+        let scan_state = ScanStateRC::from_scan_state(ScanState::new(
+            42,
+            scanned_slots_map,
+            Arc::new("[::1]:6379".to_string()),
+            3,
+            ScanStateStage::InProgress,
+        ));
+
+        let progress = scan_state.progress().unwrap();
+        assert_eq!(progress.slots_completed(), 100);
+        let parsed = ScanProgress::from_token(&progress.to_token()).unwrap();
+        assert_eq!(parsed, progress);
+        assert_eq!(
+            ScanStateRC::from_progress(parsed).state_from_wrapper(),
+            scan_state.state_from_wrapper()
+        );
+
+        assert!(ScanStateRC::new().progress().is_none());
+        assert!(ScanProgress::from_token("v1:42:3:00:127.0.0.1:6379").is_err());
+        assert!(ScanProgress::from_token("v2:42:3").is_err());
+    }
+
     #[tokio::test]
     async fn test_scan_state_new() {
         let address = Arc::new("127.0.0.1:6379".to_string());
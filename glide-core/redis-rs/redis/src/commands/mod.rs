@@ -12,6 +12,9 @@ mod macros;
 #[cfg(feature = "cluster-async")]
 pub use cluster_scan::ScanStateRC;
 
+#[cfg(feature = "cluster-async")]
+pub use cluster_scan::ScanProgress;
+
 #[cfg(feature = "cluster-async")]
 pub use cluster_scan::ClusterScanArgs;
 
@@ -234,6 +234,15 @@ pub struct RedisConnectionInfo {
     pub client_name: Option<String>,
     /// Optionally a library name that should be used for connection
     pub lib_name: Option<String>,
+    /// Whether `CLIENT NO-EVICT ON` is sent on connection, so the connection isn't evicted when the server's memory
+    /// for client connections is exhausted.
+    pub client_no_evict: bool,
+    /// Whether `CLIENT NO-TOUCH ON` is sent on connection, so the commands of the connection don't alter the LRU/LFU
+    /// of the keys they access. Requires Valkey 7.2 or later.
+    pub client_no_touch: bool,
+    /// Whether a connection configured with RESP3 falls back to RESP2 when the server rejects `HELLO 3`, instead of
+    /// failing. RESP3-only features, such as push notifications, aren't available on a connection that fell back.
+    pub resp3_fallback: bool,
 }
 
 impl FromStr for ConnectionInfo {
@@ -392,6 +401,9 @@ fn url_to_tcp_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             },
             client_name: None,
             lib_name: None,
+            client_no_evict: false,
+            client_no_touch: false,
+            resp3_fallback: false,
         },
     })
 }
@@ -425,6 +437,9 @@ fn url_to_unix_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             },
             client_name: None,
             lib_name: None,
+            client_no_evict: false,
+            client_no_touch: false,
+            resp3_fallback: false,
         },
     })
 }
@@ -521,6 +536,60 @@ impl fmt::Debug for NoCertificateVerification {
     }
 }
 
+/// Verifies the server certificate chain with the wrapped verifier, but accepts certificates
+/// that weren't issued for the server name.
+#[derive(Debug)]
+struct NoHostnameVerification {
+    inner: Arc<dyn rustls::client::danger::ServerCertVerifier>,
+}
+
+impl rustls::client::danger::ServerCertVerifier for NoHostnameVerification {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls_pki_types::CertificateDer<'_>,
+        intermediates: &[rustls_pki_types::CertificateDer<'_>],
+        server_name: &rustls_pki_types::ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls_pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        match self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            Err(rustls::Error::InvalidCertificate(
+                rustls::CertificateError::NotValidForName
+                | rustls::CertificateError::NotValidForNameContext { .. },
+            )) => Ok(rustls::client::danger::ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls_pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls_pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
 /// Represents a stateful redis TCP connection.
 pub struct Connection {
     con: ActualConnection,
@@ -603,7 +672,8 @@ impl ActualConnection {
             } => {
                 let host: &str = host;
                 let config = create_rustls_config(insecure, tls_params.as_ref().cloned())?;
-                let server_name = rustls_pki_types::ServerName::try_from(host)
+                let server_name = TlsConnParams::server_name(tls_params.as_ref(), host);
+                let server_name = rustls_pki_types::ServerName::try_from(server_name)
                     .map_err(|e| {
                         RedisError::from((
                             ErrorKind::InvalidClientConfig,
@@ -771,6 +841,12 @@ pub(crate) fn create_rustls_config(
     use crate::tls::ClientTlsParams;
     use rustls_platform_verifier::BuilderVerifierExt;
 
+    // The roots the certificate chain is verified against when hostname verification is disabled.
+    let hostname_insensitive_roots = tls_params
+        .as_ref()
+        .filter(|tls_params| tls_params.skip_hostname_verification)
+        .map(|tls_params| tls_params.root_cert_store.clone());
+
     // Build the TLS configuration following rustls best practices:
     // 1. Prefer platform verifier (recommended by rustls team for maximum compatibility)
     // 2. Fall back to custom root certificates only when explicitly provided
@@ -869,6 +945,36 @@ pub(crate) fn create_rustls_config(
         }
         (false, _) => {
             // Secure mode (default) - return the properly configured client
+            let mut config = config;
+            if let Some(root_cert_store) = hostname_insensitive_roots {
+                let inner: Arc<dyn rustls::client::danger::ServerCertVerifier> =
+                    match root_cert_store {
+                        Some(root_cert_store) => {
+                            rustls::client::WebPkiServerVerifier::builder(Arc::new(root_cert_store))
+                                .build()
+                                .map_err(|err| {
+                                    tls_config_error(
+                                "Failed to configure certificate verifier with custom root store",
+                                err,
+                            )
+                                })?
+                        }
+                        None => Arc::new(
+                            rustls_platform_verifier::Verifier::new(
+                                config.crypto_provider().clone(),
+                            )
+                            .map_err(|err| {
+                                tls_config_error(
+                                    "Failed to configure platform certificate verifier",
+                                    err,
+                                )
+                            })?,
+                        ),
+                    };
+                config
+                    .dangerous()
+                    .set_certificate_verifier(Arc::new(NoHostnameVerification { inner }));
+            }
             Ok(config)
         }
     }
@@ -925,6 +1031,19 @@ pub fn connect(
     setup_connection(con, &connection_info.redis)
 }
 
+/// Returns the `CLIENT NO-EVICT` and `CLIENT NO-TOUCH` commands enabled in the connection info, which are sent when
+/// the connection is set up.
+pub(crate) fn client_protection_cmds(connection_info: &RedisConnectionInfo) -> Vec<Cmd> {
+    [
+        ("NO-EVICT", connection_info.client_no_evict),
+        ("NO-TOUCH", connection_info.client_no_touch),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(mode, _)| cmd("CLIENT").arg(mode).arg("ON").clone())
+    .collect()
+}
+
 pub(crate) fn client_set_info_pipeline(lib_name: Option<&str>) -> Pipeline {
     let mut pipeline = crate::pipe();
     let lib_name_value = lib_name.unwrap_or("UnknownClient");
@@ -961,9 +1080,14 @@ fn setup_connection(
         let hello_cmd = resp3_hello(connection_info);
         let val: RedisResult<Value> = hello_cmd.query(&mut rv);
         if let Err(err) = val {
-            return Err(get_resp3_hello_command_error(err));
+            let err = get_resp3_hello_command_error(err);
+            if !connection_info.resp3_fallback || err.kind() != ErrorKind::RESP3NotSupported {
+                return Err(err);
+            }
+            rv.protocol = ProtocolVersion::RESP2;
         }
-    } else if connection_info.password.is_some() {
+    }
+    if rv.protocol == ProtocolVersion::RESP2 && connection_info.password.is_some() {
         connect_auth(&mut rv, connection_info)?;
     }
     if connection_info.db != 0 {
@@ -993,6 +1117,16 @@ fn setup_connection(
         }
     }
 
+    for protection_cmd in client_protection_cmds(connection_info) {
+        match protection_cmd.query::<Value>(&mut rv) {
+            Ok(Value::Okay) => {}
+            _ => fail!((
+                ErrorKind::ResponseError,
+                "Redis server refused to set client eviction or touch mode"
+            )),
+        }
+    }
+
     // result is ignored, as per the command's instructions.
     // https://redis.io/commands/client-setinfo/
     let _: RedisResult<()> =
@@ -1713,6 +1847,13 @@ pub fn resp3_is_pub_sub_state_cleared(
 
 /// Common logic for checking real cause of hello3 command error
 pub fn get_resp3_hello_command_error(err: RedisError) -> RedisError {
+    if err.code() == Some("NOPROTO") {
+        return (
+            ErrorKind::RESP3NotSupported,
+            "Redis Server doesn't support the requested protocol therefore resp3 cannot be used",
+        )
+            .into();
+    }
     if let Some(detail) = err.detail() {
         if detail.starts_with("unknown command `HELLO`") {
             return (
@@ -1746,6 +1887,51 @@ mod tests {
         assert!(cmd_str.contains("Glide") || cmd_str.contains("UnknownClient"));
     }
 
+    #[test]
+    fn test_client_protection_cmds() {
+        let mut connection_info = RedisConnectionInfo::default();
+        assert!(client_protection_cmds(&connection_info).is_empty());
+
+        connection_info.client_no_touch = true;
+        let cmds = client_protection_cmds(&connection_info);
+        assert_eq!(cmds.len(), 1);
+        assert_eq!(
+            cmds[0].get_packed_command(),
+            cmd("CLIENT").arg("NO-TOUCH").arg("ON").get_packed_command()
+        );
+
+        connection_info.client_no_evict = true;
+        assert_eq!(client_protection_cmds(&connection_info).len(), 2);
+    }
+
+    #[test]
+    fn test_get_resp3_hello_command_error() {
+        let unknown_command = RedisError::from((
+            ErrorKind::ResponseError,
+            "An error was signalled by the server",
+            "unknown command `HELLO`, with args beginning with: `3`, ".to_string(),
+        ));
+        assert_eq!(
+            get_resp3_hello_command_error(unknown_command).kind(),
+            ErrorKind::RESP3NotSupported
+        );
+
+        let no_proto = RedisError::from(crate::types::ServerError::ExtensionError {
+            code: "NOPROTO".to_string(),
+            detail: Some("unsupported protocol version".to_string()),
+        });
+        assert_eq!(
+            get_resp3_hello_command_error(no_proto).kind(),
+            ErrorKind::RESP3NotSupported
+        );
+
+        let wrong_password = RedisError::from((ErrorKind::AuthenticationFailed, "WRONGPASS"));
+        assert_eq!(
+            get_resp3_hello_command_error(wrong_password).kind(),
+            ErrorKind::AuthenticationFailed
+        );
+    }
+
     #[test]
     fn test_client_set_info_pipeline_logic() {
         // Test the logic directly by simulating what happens when GLIDE_NAME is not set
@@ -1872,6 +2058,9 @@ mod tests {
                         protocol: ProtocolVersion::RESP2,
                         client_name: None,
                         lib_name: None,
+                        client_no_evict: false,
+                        client_no_touch: false,
+                        resp3_fallback: false,
                     },
                 },
             ),
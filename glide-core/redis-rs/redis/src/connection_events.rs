@@ -0,0 +1,47 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use std::fmt;
+
+/// A change in the state of a connection to a node, or in the cluster topology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// A connection to the node was established, either initially or after a reconnection.
+    Connected,
+    /// The connection to the node was lost, or couldn't be established.
+    Disconnected,
+    /// A reconnection to the node has started.
+    Reconnecting,
+    /// The cluster topology was refreshed and found to be different from the previous one.
+    TopologyChanged,
+    /// The credentials of the client couldn't be refreshed, or the connections couldn't re-authenticate with the
+    /// refreshed credentials. The connections keep using their current credentials until they expire.
+    AuthenticationRefreshFailed,
+    /// A request was redirected to the node with a `MOVED` or `ASK` error, such as while a slot is migrated.
+    /// Only reported when enabled for the client, and described as `MOVED <slot>` or `ASK <slot>`.
+    Redirected,
+    /// The node rejected `HELLO 3`, and the connection fell back to RESP2. Only reported when the fallback is enabled
+    /// for the client. RESP3-only features, such as push notifications, aren't available on the connection.
+    ProtocolDowngraded,
+    /// The connection to the node was replaced because it reached the maximum connection age or the idle timeout of
+    /// the client, described as `max age` or `idle timeout`. Followed by the events of the reconnection.
+    Recycled,
+}
+
+/// Trait for receiving the connection events of a client, so that the connection state can be
+/// surfaced without polling the client.
+///
+/// The listener is called from the tasks that manage the connections, so it should return quickly.
+pub trait ConnectionEventListener: Send + Sync {
+    /// Called when `event` occurs.
+    ///
+    /// `address` is the address of the node the event refers to, and is empty for topology changes and
+    /// authentication refresh failures.
+    /// `error` describes the reason for a disconnection or a failure, if known.
+    fn on_connection_event(&self, event: ConnectionEvent, address: &str, error: Option<&str>);
+}
+
+impl fmt::Debug for dyn ConnectionEventListener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ConnectionEventListener")
+    }
+}
@@ -0,0 +1,155 @@
+//! Faults injected into the commands sent to the nodes, so integration tests can deterministically reproduce slow
+//! nodes, redirections and dropped connections.
+
+use crate::types::{RedisError, RedisResult, Value};
+use crate::{parse_redis_value, Cmd};
+use std::io;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The fault injected into a command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FaultKind {
+    /// Delays the command by the given duration before sending it.
+    Latency(Duration),
+    /// Fails the command with the given server error, such as `ERR injected failure`.
+    Error(String),
+    /// Fails the command with a `MOVED` redirection of `slot` to the node at `address`, given as `host:port`.
+    Moved {
+        /// The redirected slot.
+        slot: u16,
+        /// The address the slot is redirected to.
+        address: String,
+    },
+    /// Fails the command as if the connection to the node was dropped, which makes the client reconnect to the node.
+    Disconnect,
+}
+
+/// A fault and the commands it is injected into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FaultSpec {
+    /// The injected fault.
+    pub kind: FaultKind,
+    /// The address of the targeted node, given as `host:port`. `None` targets every node.
+    pub node: Option<String>,
+    /// The name of the targeted command, matched case-insensitively. `None` targets every command.
+    pub command: Option<String>,
+    /// The number of commands the fault is injected into. 0 injects the fault until the faults are cleared.
+    pub count: u32,
+}
+
+impl FaultSpec {
+    fn matches(&self, address: &str, cmd: &Cmd) -> bool {
+        self.node.as_deref().map_or(true, |node| node == address)
+            && self.command.as_deref().map_or(true, |command| {
+                cmd.arg_idx(0)
+                    .is_some_and(|name| name.eq_ignore_ascii_case(command.as_bytes()))
+            })
+    }
+}
+
+fn server_error(message: &str) -> RedisError {
+    match parse_redis_value(format!("-{message}\r\n").as_bytes()) {
+        Ok(Value::ServerError(err)) => err.into(),
+        Ok(_) => unreachable!("A RESP error was parsed as another value"),
+        Err(err) => err,
+    }
+}
+
+/// The faults injected into the commands of a client. Each command gets the first matching fault, in the order the
+/// faults were added.
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    faults: Mutex<Vec<FaultSpec>>,
+}
+
+impl FaultInjector {
+    /// Adds a fault, injected after the faults that were already added.
+    pub fn add(&self, fault: FaultSpec) {
+        self.faults.lock().unwrap().push(fault);
+    }
+
+    /// Removes all the faults.
+    pub fn clear(&self) {
+        self.faults.lock().unwrap().clear();
+    }
+
+    /// Injects the first fault matching a command sent to the node at `address`. Returns the error the command fails
+    /// with, or `Ok` once the command may be sent.
+    pub async fn inject(&self, address: &str, cmd: &Cmd) -> RedisResult<()> {
+        let kind = {
+            let mut faults = self.faults.lock().unwrap();
+            let Some(index) = faults.iter().position(|fault| fault.matches(address, cmd)) else {
+                return Ok(());
+            };
+            let fault = &mut faults[index];
+            let kind = fault.kind.clone();
+            match fault.count {
+                0 => {}
+                1 => {
+                    faults.remove(index);
+                }
+                _ => fault.count -= 1,
+            }
+            kind
+        };
+        match kind {
+            FaultKind::Latency(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+            // The errors are parsed like the errors sent by the server, so they are handled the same way.
+            FaultKind::Error(message) => Err(server_error(&message)),
+            FaultKind::Moved { slot, address } => {
+                Err(server_error(&format!("MOVED {slot} {address}")))
+            }
+            FaultKind::Disconnect => Err(RedisError::from(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "Injected disconnect",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[tokio::test]
+    async fn test_inject_fault_into_matching_commands() {
+        let injector = FaultInjector::default();
+        injector.add(FaultSpec {
+            kind: FaultKind::Moved {
+                slot: 866,
+                address: "127.0.0.1:7001".to_string(),
+            },
+            node: Some("127.0.0.1:7000".to_string()),
+            command: Some("get".to_string()),
+            count: 1,
+        });
+        let get = crate::cmd("GET").arg("key").clone();
+
+        assert!(injector.inject("127.0.0.1:7002", &get).await.is_ok());
+        assert!(injector
+            .inject("127.0.0.1:7000", &crate::cmd("SET"))
+            .await
+            .is_ok());
+        let err = injector.inject("127.0.0.1:7000", &get).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Moved);
+        assert_eq!(err.redirect_node(), Some(("127.0.0.1:7001", 866)));
+        // The fault was injected into a single command.
+        assert!(injector.inject("127.0.0.1:7000", &get).await.is_ok());
+
+        injector.add(FaultSpec {
+            kind: FaultKind::Disconnect,
+            node: None,
+            command: None,
+            count: 0,
+        });
+        let err = injector.inject("127.0.0.1:7000", &get).await.unwrap_err();
+        assert!(err.is_unrecoverable_error());
+        injector.clear();
+        assert!(injector.inject("127.0.0.1:7000", &get).await.is_ok());
+    }
+}
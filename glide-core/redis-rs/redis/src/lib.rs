@@ -338,7 +338,14 @@ assert_eq!(result, Ok(("foo".to_string(), b"bar".to_vec())));
 // public api
 pub use crate::client::Client;
 pub use crate::client::GlideConnectionOptions;
-pub use crate::cmd::{cmd, fenced_cmd, pack_command, pipe, Arg, Cmd, Iter};
+pub use crate::client::SocketTimeouts;
+pub use crate::client::TcpKeepaliveConfig;
+pub use crate::client::{ProxyConfig, ProxyType};
+pub use crate::client::{AddressTranslation, ConnectionRecyclingPolicy, RecycleReason};
+pub use crate::cmd::{
+    cmd, fenced_cmd, pack_command, pipe, Arg, Cmd, CommandTrace, Iter,
+    DEADLINE_EXCEEDED_BEFORE_SEND_CODE,
+};
 pub use crate::commands::{
     Commands, ControlFlow, Direction, LposOptions, PubSubCommands, SetOptions,
 };
@@ -347,6 +354,7 @@ pub use crate::connection::{
     IntoConnectionInfo, Msg, PubSub, PubSubChannelOrPattern, PubSubSubscriptionInfo,
     PubSubSubscriptionKind, RedisConnectionInfo, TlsMode,
 };
+pub use crate::connection_events::{ConnectionEvent, ConnectionEventListener};
 pub use crate::parser::{parse_redis_value, Parser};
 pub use crate::pipeline::{Pipeline, PipelineRetryStrategy};
 pub use crate::pubsub_synchronizer::PubSubSynchronizer;
@@ -359,6 +367,7 @@ pub use crate::types::{
     // utility functions
     from_redis_value,
     from_owned_redis_value,
+    make_extension_error,
 
     // error kinds
     ErrorKind,
@@ -411,6 +420,9 @@ pub mod cluster_slotmap;
 #[cfg(feature = "cluster-async")]
 pub use crate::commands::ScanStateRC;
 
+#[cfg(feature = "cluster-async")]
+pub use crate::commands::ScanProgress;
+
 #[cfg(feature = "cluster-async")]
 pub use crate::commands::ObjectType;
 
@@ -446,6 +458,12 @@ pub mod cluster_async;
 #[cfg(feature = "sentinel")]
 pub mod sentinel;
 
+#[cfg(feature = "fault-injection")]
+mod fault_injection;
+
+#[cfg(feature = "fault-injection")]
+pub use crate::fault_injection::{FaultInjector, FaultKind, FaultSpec};
+
 mod tls;
 
 pub use crate::tls::{retrieve_tls_certificates, ClientTlsConfig, TlsCertificates, TlsConnParams};
@@ -454,6 +472,7 @@ mod client;
 mod cmd;
 mod commands;
 mod connection;
+mod connection_events;
 mod parser;
 mod pubsub_synchronizer;
 mod push_manager;
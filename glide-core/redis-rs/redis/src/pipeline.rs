@@ -375,7 +375,7 @@ macro_rules! implement_pipeline_commands {
 
 implement_pipeline_commands!(Pipeline);
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy)]
 /// Defines a retry strategy for pipeline requests, allowing control over retries in case of server or connection errors.
 ///
 /// This strategy determines whether failed commands should be retried, which can impact execution order and potential side effects.
@@ -397,14 +397,27 @@ pub struct PipelineRetryStrategy {
     /// - Since a connection error does not indicate which commands succeeded or failed, retrying may lead to duplicate executions.
     /// - This is particularly risky for non-idempotent commands like `INCR`, which modify state irreversibly.
     pub retry_connection_error: bool,
+    /// If `true`, commands redirected with `ASK`, because their slot is being migrated, are split from their
+    /// sub-pipeline and retried on the importing node, preceded by `ASKING`. If `false`, they fail with the `ASK` error.
+    ///
+    /// Enabled by default. A redirected command fails with the last error once the retries are exhausted.
+    pub follow_ask_redirects: bool,
 }
 
 impl PipelineRetryStrategy {
     /// Creates a new `PipelineRetryStrategy` with the specified flags for retrying server and connection errors.
+    /// Commands redirected with `ASK` are followed.
     pub fn new(retry_server_error: bool, retry_connection_error: bool) -> Self {
         Self {
             retry_server_error,
             retry_connection_error,
+            follow_ask_redirects: true,
         }
     }
 }
+
+impl Default for PipelineRetryStrategy {
+    fn default() -> Self {
+        Self::new(false, false)
+    }
+}
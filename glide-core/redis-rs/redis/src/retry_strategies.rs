@@ -54,6 +54,26 @@ impl RetryStrategy {
         strategy
     }
 
+    /// The base of the exponential backoff.
+    pub fn exponent_base(&self) -> u32 {
+        self.exponent_base
+    }
+
+    /// The factor the exponential backoff is multiplied by, in milliseconds.
+    pub fn factor(&self) -> u32 {
+        self.factor
+    }
+
+    /// The number of retries whose delay grows exponentially.
+    pub fn number_of_retries(&self) -> u32 {
+        self.number_of_retries
+    }
+
+    /// The jitter applied to each delay, as a percentage of the delay.
+    pub fn jitter_percent(&self) -> u32 {
+        self.jitter_percent
+    }
+
     /// Return a bounded iterator: stops after number_of_retries attempts
     pub fn get_bounded_backoff_dur_iterator(&self) -> impl Iterator<Item = Duration> {
         let base_backoff =
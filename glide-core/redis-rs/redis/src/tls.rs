@@ -123,6 +123,7 @@ pub fn retrieve_tls_certificates(certificates: TlsCertificates) -> RedisResult<T
     Ok(TlsConnParams {
         client_tls_params,
         root_cert_store,
+        ..Default::default()
     })
 }
 
@@ -149,8 +150,40 @@ impl Clone for ClientTlsParams {
 }
 
 /// TLS connection parameters containing client certificates and root certificate store.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TlsConnParams {
     pub(crate) client_tls_params: Option<ClientTlsParams>,
     pub(crate) root_cert_store: Option<RootCertStore>,
+    pub(crate) server_name: Option<String>,
+    pub(crate) skip_hostname_verification: bool,
+}
+
+impl TlsConnParams {
+    /// Sets the name sent in the SNI extension and checked against the server certificate,
+    /// instead of the host the connection is made to.
+    ///
+    /// This is needed when connecting through a TLS-terminating proxy, or to nodes announced by IP
+    /// that present a certificate issued for a shared hostname.
+    pub fn with_server_name(mut self, server_name: impl Into<String>) -> Self {
+        self.server_name = Some(server_name.into());
+        self
+    }
+
+    /// Verifies the server certificate chain, but not that the certificate was issued for the server name.
+    ///
+    /// # Warning
+    ///
+    /// Any certificate signed by a trusted authority will be accepted for any server,
+    /// so this should only be used when the server name can't be known in advance.
+    pub fn without_hostname_verification(mut self) -> Self {
+        self.skip_hostname_verification = true;
+        self
+    }
+
+    /// Returns the name to use for SNI and certificate verification when connecting to `host`.
+    pub(crate) fn server_name<'a>(tls_params: Option<&'a TlsConnParams>, host: &'a str) -> &'a str {
+        tls_params
+            .and_then(|params| params.server_name.as_deref())
+            .unwrap_or(host)
+    }
 }
@@ -441,6 +441,10 @@ pub enum PushKind {
     PSubscribe,
     /// `ssubscribe` is received when client subscribed to a shard channel.
     SSubscribe,
+    /// `Resubscribed` is sent from the **library** when the subscriptions lost on disconnection were restored.
+    /// The first element of the data is the time in milliseconds since the disconnection, followed by a `[kind, name]` array
+    /// for each restored channel or pattern, where `kind` is the integer value of its `PubSubSubscriptionKind`.
+    Resubscribed,
 }
 
 impl PushKind {
@@ -490,6 +494,7 @@ impl fmt::Display for PushKind {
             PushKind::PSubscribe => write!(f, "psubscribe"),
             PushKind::SSubscribe => write!(f, "ssubscribe"),
             PushKind::Disconnection => write!(f, "disconnection"),
+            PushKind::Resubscribed => write!(f, "resubscribed"),
         }
     }
 }
@@ -711,6 +716,8 @@ impl fmt::Debug for Value {
 /// struct.
 pub struct RedisError {
     repr: ErrorRepr,
+    // The address of the node the error originated from, if known.
+    node_address: Option<String>,
 }
 
 #[derive(Debug)]
@@ -741,6 +748,7 @@ impl From<io::Error> for RedisError {
     fn from(err: io::Error) -> RedisError {
         RedisError {
             repr: ErrorRepr::IoError(err),
+            node_address: None,
         }
     }
 }
@@ -749,6 +757,7 @@ impl From<Utf8Error> for RedisError {
     fn from(_: Utf8Error) -> RedisError {
         RedisError {
             repr: ErrorRepr::WithDescription(ErrorKind::TypeError, "Invalid UTF-8"),
+            node_address: None,
         }
     }
 }
@@ -761,6 +770,7 @@ impl From<NulError> for RedisError {
                 "Value contains interior nul terminator",
                 err.to_string(),
             ),
+            node_address: None,
         }
     }
 }
@@ -773,6 +783,7 @@ impl From<rustls::Error> for RedisError {
                 "TLS error",
                 err.to_string(),
             ),
+            node_address: None,
         }
     }
 }
@@ -785,6 +796,7 @@ impl From<rustls_pki_types::InvalidDnsNameError> for RedisError {
                 "TLS Error",
                 err.to_string(),
             ),
+            node_address: None,
         }
     }
 }
@@ -793,6 +805,7 @@ impl From<FromUtf8Error> for RedisError {
     fn from(_: FromUtf8Error) -> RedisError {
         RedisError {
             repr: ErrorRepr::WithDescription(ErrorKind::TypeError, "Cannot convert from UTF-8"),
+            node_address: None,
         }
     }
 }
@@ -804,6 +817,7 @@ impl From<ParseIntError> for RedisError {
                 ErrorKind::TypeError,
                 "Cannot parse string as an integer",
             ),
+            node_address: None,
         }
     }
 }
@@ -812,6 +826,7 @@ impl From<(ErrorKind, &'static str)> for RedisError {
     fn from((kind, desc): (ErrorKind, &'static str)) -> RedisError {
         RedisError {
             repr: ErrorRepr::WithDescription(kind, desc),
+            node_address: None,
         }
     }
 }
@@ -820,6 +835,7 @@ impl From<(ErrorKind, &'static str, String)> for RedisError {
     fn from((kind, desc, detail): (ErrorKind, &'static str, String)) -> RedisError {
         RedisError {
             repr: ErrorRepr::WithDescriptionAndDetail(kind, desc, detail),
+            node_address: None,
         }
     }
 }
@@ -1057,6 +1073,25 @@ impl RedisError {
         }
     }
 
+    /// Returns true if the request that failed with this error may succeed when it's retried, possibly after
+    /// reconnecting or on another node.
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self.retry_method(), RetryMethod::NoRetry)
+    }
+
+    /// Returns the address of the node the error originated from, if known.
+    pub fn node_address(&self) -> Option<&str> {
+        self.node_address.as_deref()
+    }
+
+    /// Sets the address of the node the error originated from, unless it was already set.
+    pub fn with_node_address(mut self, address: &str) -> Self {
+        if self.node_address.is_none() {
+            self.node_address = Some(address.to_string());
+        }
+        self
+    }
+
     /// Returns the node the error refers to.
     ///
     /// This returns `(addr, slot_id)`.
@@ -1116,7 +1151,10 @@ impl RedisError {
                 format!("{ioerror_description}: {e}"),
             )),
         };
-        Self { repr }
+        Self {
+            repr,
+            node_address: self.node_address.clone(),
+        }
     }
 
     pub(crate) fn retry_method(&self) -> RetryMethod {
@@ -1187,6 +1225,7 @@ pub fn make_extension_error(code: String, detail: Option<String>) -> RedisError
                 None => "Unknown extension error encountered".to_string(),
             },
         ),
+        node_address: None,
     }
 }
 
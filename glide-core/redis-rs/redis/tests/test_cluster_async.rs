@@ -360,6 +360,7 @@ mod cluster_async {
                     Some(PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     }),
                 )
                 .await;
@@ -445,6 +446,7 @@ mod cluster_async {
                     Some(PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     }),
                 )
                 .await
@@ -547,6 +549,7 @@ mod cluster_async {
                         Some(PipelineRetryStrategy {
                             retry_server_error: retry,
                             retry_connection_error: false,
+                            follow_ask_redirects: true,
                         }),
                     )
                     .await
@@ -642,6 +645,7 @@ mod cluster_async {
                         Some(PipelineRetryStrategy {
                             retry_server_error: retry,
                             retry_connection_error: false,
+                            follow_ask_redirects: true,
                         }),
                     )
                     .await
@@ -226,6 +226,7 @@ mod test_cluster_pipeline {
                 Some(PipelineRetryStrategy {
                     retry_server_error: true,
                     retry_connection_error: false,
+                    follow_ask_redirects: true,
                 }),
             )
             .await
@@ -303,6 +304,7 @@ mod test_cluster_pipeline {
                     Some(PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     }),
                 )
                 .await
@@ -384,6 +386,7 @@ mod test_cluster_pipeline {
                     Some(PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     }),
                 )
                 .await;
@@ -608,6 +611,7 @@ mod test_cluster_pipeline {
                     Some(PipelineRetryStrategy {
                         retry_server_error: retries > 0,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     }),
                 )
                 .await
@@ -725,6 +729,7 @@ mod test_cluster_pipeline {
                         Some(PipelineRetryStrategy {
                             retry_server_error: retry,
                             retry_connection_error: false,
+                            follow_ask_redirects: true,
                         }),
                     )
                     .await
@@ -773,6 +778,51 @@ mod test_cluster_pipeline {
         }
     }
 
+    /// Tests that commands redirected with `ASK` fail with the `ASK` error when `follow_ask_redirects` is disabled,
+    /// while the other commands of the pipeline succeed.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_pipeline_with_ask_redirects_not_followed() {
+        let cluster = TestClusterContext::new_with_cluster_client_builder(
+            3,
+            0,
+            |builder| builder.retries(10),
+            false,
+        );
+        let mut connection = cluster.async_connection(None).await;
+
+        let cluster_nodes = cluster.get_cluster_nodes().await;
+        let slot_distribution = cluster.get_slots_ranges_distribution(&cluster_nodes);
+
+        let migrated_key = generate_random_string(10);
+        let key_slot = get_slot(migrated_key.as_bytes());
+        let stable_key = generate_random_string(10);
+        cluster.migrate_slot(key_slot, slot_distribution).await;
+
+        let mut pipeline = redis::pipe();
+        pipeline.get(&migrated_key).set(&stable_key, "value");
+        let result = connection
+            .route_pipeline(
+                &pipeline,
+                0,
+                2,
+                None,
+                Some(PipelineRetryStrategy {
+                    retry_server_error: true,
+                    retry_connection_error: false,
+                    follow_ask_redirects: false,
+                }),
+            )
+            .await
+            .expect("Pipeline execution failed");
+
+        assert!(
+            matches!(result[0], Value::ServerError(ref err) if err.kind() == ErrorKind::Ask),
+            "Expected an ASK error for the migrated key '{migrated_key}', got: {result:?}"
+        );
+        assert_eq!(result[1], Value::Okay, "Actual result: {result:?}");
+    }
+
     /// Tests pipeline retry behavior when encountering connection errors.
     ///
     /// This test is executed twice—once with `retry_connection_error = false` and once with `retry_connection_error = true`.
@@ -847,6 +897,7 @@ mod test_cluster_pipeline {
                         Some(PipelineRetryStrategy {
                             retry_server_error: false,
                             retry_connection_error: retry,
+                            follow_ask_redirects: true,
                         }),
                     )
                     .await
@@ -918,6 +969,7 @@ mod test_cluster_pipeline {
                 Some(PipelineRetryStrategy {
                     retry_server_error: true,
                     retry_connection_error: false,
+                    follow_ask_redirects: true,
                 }),
             )
             .await
@@ -1017,6 +1069,7 @@ mod test_cluster_pipeline {
                 Some(PipelineRetryStrategy {
                     retry_server_error: true,
                     retry_connection_error: false,
+                    follow_ask_redirects: true,
                 }),
             )
             .await
@@ -1154,6 +1207,7 @@ mod test_cluster_pipeline {
                 Some(PipelineRetryStrategy {
                     retry_server_error: true,
                     retry_connection_error: false,
+                    follow_ask_redirects: true,
                 }),
             )
             .await
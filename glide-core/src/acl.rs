@@ -0,0 +1,284 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Helpers for the ACL commands (`ACL SETUSER`, `ACL GETUSER` and `ACL WHOAMI`).
+
+use redis::{ErrorKind, RedisError, RedisResult, Value};
+
+/// The fields of an `ACL GETUSER` reply that hold space-separated rules, which are split into arrays.
+const RULE_FIELDS: [&str; 3] = ["commands", "keys", "channels"];
+
+/// The rules of `ACL SETUSER` that are a single keyword. Keywords are case-insensitive.
+const KEYWORD_RULES: [&str; 14] = [
+    "on",
+    "off",
+    "nopass",
+    "resetpass",
+    "reset",
+    "resetkeys",
+    "resetchannels",
+    "allkeys",
+    "allchannels",
+    "allcommands",
+    "nocommands",
+    "clearselectors",
+    "sanitize-payload",
+    "skip-sanitize-payload",
+];
+
+/// Converts an `ACL GETUSER` reply, either a RESP3 map or a RESP2 array of names and values, into a map of:
+///
+/// ```text
+/// flags => 1) "on"
+/// passwords => 1) "<SHA-256 of the password>"
+/// commands => 1) "+@all" 2) "-debug"
+/// keys => 1) "~*"
+/// channels => 1) "&*"
+/// selectors => 1) commands => 1) "-@all" 2) "+get"
+///                 keys => 1) "%R~cache:*"
+///                 channels => (empty array)
+/// ```
+///
+/// The rules of `commands`, `keys` and `channels` are split into arrays, both for the user and its selectors.
+/// Fields returned by newer servers are kept as is. A user that doesn't exist is returned as nil.
+pub fn convert_acl_getuser_response(value: Value) -> RedisResult<Value> {
+    if matches!(value, Value::Nil) {
+        return Ok(Value::Nil);
+    }
+    let fields = into_fields(value)?;
+    fields
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match field_name(&name).as_deref() {
+                Some("selectors") => convert_selectors(value)?,
+                Some(field) if RULE_FIELDS.contains(&field) => split_rules(value)?,
+                _ => value,
+            };
+            Ok((name, value))
+        })
+        .collect::<RedisResult<_>>()
+        .map(Value::Map)
+}
+
+fn convert_selectors(value: Value) -> RedisResult<Value> {
+    let Value::Array(selectors) = value else {
+        return Err(unexpected_response(&value));
+    };
+    selectors
+        .into_iter()
+        .map(|selector| {
+            into_fields(selector)?
+                .into_iter()
+                .map(|(name, value)| match field_name(&name).as_deref() {
+                    Some(field) if RULE_FIELDS.contains(&field) => Ok((name, split_rules(value)?)),
+                    _ => Ok((name, value)),
+                })
+                .collect::<RedisResult<_>>()
+                .map(Value::Map)
+        })
+        .collect::<RedisResult<_>>()
+        .map(Value::Array)
+}
+
+/// Returns the fields of a RESP3 map, or of a RESP2 array of names and values.
+fn into_fields(value: Value) -> RedisResult<Vec<(Value, Value)>> {
+    match value {
+        Value::Map(fields) => Ok(fields),
+        Value::Array(values) if values.len() % 2 == 0 => {
+            let mut values = values.into_iter();
+            Ok(std::iter::from_fn(|| Some((values.next()?, values.next()?))).collect())
+        }
+        value => Err(unexpected_response(&value)),
+    }
+}
+
+fn field_name(name: &Value) -> Option<String> {
+    match name {
+        Value::SimpleString(name) => Some(name.clone()),
+        Value::BulkString(name) => String::from_utf8(name.clone()).ok(),
+        _ => None,
+    }
+}
+
+/// Splits a string of space-separated rules into an array. Servers older than 7.0 already return the key patterns
+/// as an array, which is kept as is.
+fn split_rules(value: Value) -> RedisResult<Value> {
+    let rules = match value {
+        Value::SimpleString(rules) => rules.into_bytes(),
+        Value::BulkString(rules) => rules,
+        value @ Value::Array(_) => return Ok(value),
+        value => return Err(unexpected_response(&value)),
+    };
+    Ok(Value::Array(
+        rules
+            .split(|byte| *byte == b' ')
+            .filter(|rule| !rule.is_empty())
+            .map(|rule| Value::BulkString(rule.to_vec()))
+            .collect(),
+    ))
+}
+
+fn unexpected_response(value: &Value) -> RedisError {
+    RedisError::from((
+        ErrorKind::ResponseError,
+        "Unexpected response for ACL GETUSER",
+        format!("(response was {value:?})"),
+    ))
+}
+
+/// Validates the rules of `ACL SETUSER` before they're sent, so a rule with a typo fails on the client instead of
+/// on some of the nodes only. Returns a client error naming the first invalid rule.
+pub fn validate_acl_setuser_rules(rules: &[&[u8]]) -> RedisResult<()> {
+    for rule in rules {
+        if let Err(reason) = validate_rule(rule, true) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid ACL rule",
+                format!("`{}`: {reason}", String::from_utf8_lossy(rule)),
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_rule(rule: &[u8], allow_selector: bool) -> Result<(), &'static str> {
+    let Some((first, rest)) = rule.split_first() else {
+        return Err("the rule is empty");
+    };
+    match first {
+        b'>' | b'<' => Ok(()),
+        b'#' | b'!' => {
+            if rest.len() == 64 && rest.iter().all(u8::is_ascii_hexdigit) {
+                Ok(())
+            } else {
+                Err("a password hash must be 64 hexadecimal characters")
+            }
+        }
+        b'~' | b'&' => Ok(()),
+        b'%' => {
+            let Some(separator) = rest.iter().position(|byte| *byte == b'~') else {
+                return Err("key permissions must be followed by `~` and a pattern");
+            };
+            let permissions = &rest[..separator];
+            if !permissions.is_empty()
+                && permissions
+                    .iter()
+                    .all(|byte| matches!(byte.to_ascii_uppercase(), b'R' | b'W'))
+            {
+                Ok(())
+            } else {
+                Err("key permissions must be `R`, `W` or `RW`")
+            }
+        }
+        b'+' | b'-' => match rest {
+            [] | [b'@'] => Err("a command or category name is missing"),
+            _ => Ok(()),
+        },
+        b'(' if allow_selector => match rest.split_last() {
+            Some((b')', selector)) => selector
+                .split(u8::is_ascii_whitespace)
+                .filter(|rule| !rule.is_empty())
+                .try_for_each(|rule| validate_rule(rule, false)),
+            _ => Err("a selector must be enclosed in parentheses"),
+        },
+        _ => {
+            if KEYWORD_RULES
+                .iter()
+                .any(|keyword| keyword.as_bytes().eq_ignore_ascii_case(rule))
+            {
+                Ok(())
+            } else {
+                Err("unknown rule")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    fn bulks(values: &[&str]) -> Value {
+        Value::Array(values.iter().map(|value| bulk(value)).collect())
+    }
+
+    #[test]
+    fn test_convert_resp2_getuser_response() {
+        let response = Value::Array(vec![
+            bulk("flags"),
+            bulks(&["on"]),
+            bulk("passwords"),
+            bulks(&[]),
+            bulk("commands"),
+            bulk("+@all -debug"),
+            bulk("keys"),
+            bulk("~* %R~cache:*"),
+            bulk("channels"),
+            bulk(""),
+            bulk("selectors"),
+            Value::Array(vec![Value::Array(vec![
+                bulk("commands"),
+                bulk("-@all +get"),
+                bulk("keys"),
+                bulk("~other:*"),
+            ])]),
+        ]);
+
+        let converted = convert_acl_getuser_response(response).unwrap();
+        let Value::Map(fields) = converted else {
+            panic!("expected a map");
+        };
+        assert_eq!(fields[0], (bulk("flags"), bulks(&["on"])));
+        assert_eq!(fields[2], (bulk("commands"), bulks(&["+@all", "-debug"])));
+        assert_eq!(fields[3], (bulk("keys"), bulks(&["~*", "%R~cache:*"])));
+        assert_eq!(fields[4], (bulk("channels"), bulks(&[])));
+        assert_eq!(
+            fields[5].1,
+            Value::Array(vec![Value::Map(vec![
+                (bulk("commands"), bulks(&["-@all", "+get"])),
+                (bulk("keys"), bulks(&["~other:*"])),
+            ])])
+        );
+    }
+
+    #[test]
+    fn test_convert_missing_user() {
+        assert_eq!(
+            convert_acl_getuser_response(Value::Nil).unwrap(),
+            Value::Nil
+        );
+    }
+
+    #[test]
+    fn test_validate_acl_setuser_rules() {
+        let hashed_password = format!("#{}", "a".repeat(64));
+        let valid: [&[u8]; 10] = [
+            b"on",
+            b"RESETKEYS",
+            b">secret",
+            hashed_password.as_bytes(),
+            b"~*",
+            b"%R~cache:*",
+            b"&news.*",
+            b"+@read",
+            b"-config|set",
+            b"(~cache:* %RW~session:* +get)",
+        ];
+        assert!(validate_acl_setuser_rules(&valid).is_ok());
+
+        for invalid in [
+            b"onn" as &[u8],
+            b"#abc",
+            b"%X~key",
+            b"+@",
+            b"(~a (~b))",
+            b"",
+        ] {
+            let err = validate_acl_setuser_rules(&[invalid]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ClientError);
+        }
+    }
+}
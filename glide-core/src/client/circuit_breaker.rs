@@ -0,0 +1,345 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Per-node circuit breakers, which stop sending requests to a node after consecutive failures. Cluster clients send
+//! only the commands routed to a single node through the breakers, and don't route reads away from an open breaker.
+//!
+//! A breaker opens after `failure_threshold` consecutive connection failures or timeouts of its node. While it's open,
+//! reads are routed to other nodes and other requests to the node fail fast with a circuit-open error. Once the
+//! cooldown elapses the breaker is half-open, and the next request to the node first probes it with `PING`:
+//! the breaker closes if the probe succeeds, and opens for another cooldown otherwise.
+
+use super::types::CircuitBreakerConfig;
+use super::{Client, ClientWrapper};
+use redis::cluster_async::{NodeAdmission, NodeCircuitBreakers};
+use redis::{RedisError, RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use telemetrylib::Telemetry;
+
+const LOCK_ERR: &str = "Failed to acquire the circuit breaker lock";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent to the node.
+    Closed,
+    /// Requests aren't sent to the node until the cooldown elapses.
+    Open,
+    /// The cooldown elapsed, and the node is being probed.
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Whether a request may be sent to the node of a breaker.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Admission {
+    Allowed,
+    /// The request may be sent once a `PING` probe to the node succeeds.
+    Probe,
+    Rejected,
+}
+
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    /// When the breaker was opened, or when the probe of a half-open breaker started.
+    changed_at: Instant,
+}
+
+/// The circuit breaker of a single node.
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+impl std::fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (state, consecutive_failures) = self.state();
+        f.debug_struct("CircuitBreaker")
+            .field("state", &state)
+            .field("consecutive_failures", &consecutive_failures)
+            .finish()
+    }
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                changed_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Returns true if requests that can be sent to other nodes should avoid this node.
+    pub(crate) fn is_open(&self) -> bool {
+        let state = self.state.lock().expect(LOCK_ERR);
+        match state.state {
+            CircuitState::Closed => false,
+            CircuitState::Open | CircuitState::HalfOpen => {
+                state.changed_at.elapsed() < self.config.cooldown
+            }
+        }
+    }
+
+    /// Decides whether a request may be sent to the node. Only a single request probes a half-open node,
+    /// and the requests that arrive during the probe are rejected. A probe that didn't complete within the cooldown,
+    /// for example because its request was dropped, is replaced by a new one.
+    pub(crate) fn admit(&self) -> Admission {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        match state.state {
+            CircuitState::Closed => Admission::Allowed,
+            CircuitState::Open | CircuitState::HalfOpen
+                if state.changed_at.elapsed() >= self.config.cooldown =>
+            {
+                state.state = CircuitState::HalfOpen;
+                state.changed_at = Instant::now();
+                Admission::Probe
+            }
+            CircuitState::Open | CircuitState::HalfOpen => Admission::Rejected,
+        }
+    }
+
+    pub(crate) fn record_success(&self) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        state.state = CircuitState::Closed;
+        state.consecutive_failures = 0;
+    }
+
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        let should_open = match state.state {
+            CircuitState::Closed => state.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if should_open {
+            state.state = CircuitState::Open;
+            state.changed_at = Instant::now();
+            Telemetry::incr_circuit_breaker_opened();
+        }
+    }
+
+    /// Records the outcome of a request sent to the node. Only connection failures and timeouts count as failures
+    /// of the node, since server errors show that the node is responsive.
+    pub(crate) fn record_result<T>(&self, result: &Result<T, RedisError>) {
+        match result {
+            Err(err) if is_node_failure(err) => self.record_failure(),
+            _ => self.record_success(),
+        }
+    }
+
+    /// Returns the state of the breaker and the number of consecutive failures of its node.
+    pub(crate) fn state(&self) -> (CircuitState, u32) {
+        let state = self.state.lock().expect(LOCK_ERR);
+        (state.state, state.consecutive_failures)
+    }
+}
+
+/// The circuit breakers of the nodes of a cluster client, created when a node is first sent a command.
+pub(crate) struct ClusterCircuitBreakers {
+    config: CircuitBreakerConfig,
+    breakers: Mutex<HashMap<String, Arc<CircuitBreaker>>>,
+}
+
+impl ClusterCircuitBreakers {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, address: &str) -> Arc<CircuitBreaker> {
+        let mut breakers = self.breakers.lock().expect(LOCK_ERR);
+        if let Some(breaker) = breakers.get(address) {
+            return breaker.clone();
+        }
+        let breaker = Arc::new(CircuitBreaker::new(self.config));
+        breakers.insert(address.to_string(), breaker.clone());
+        breaker
+    }
+
+    /// Returns the address, state and consecutive failures of each node, sorted by address.
+    pub(crate) fn states(&self) -> Vec<(String, CircuitState, u32)> {
+        let breakers = self.breakers.lock().expect(LOCK_ERR);
+        let mut states: Vec<_> = breakers
+            .iter()
+            .map(|(address, breaker)| {
+                let (state, consecutive_failures) = breaker.state();
+                (address.clone(), state, consecutive_failures)
+            })
+            .collect();
+        states.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+        states
+    }
+}
+
+impl NodeCircuitBreakers for ClusterCircuitBreakers {
+    fn admit(&self, address: &str) -> NodeAdmission {
+        match self.get(address).admit() {
+            Admission::Allowed => NodeAdmission::Allowed,
+            Admission::Probe => NodeAdmission::Probe,
+            Admission::Rejected => {
+                Telemetry::incr_circuit_breaker_rejected();
+                NodeAdmission::Rejected(crate::errors::circuit_open_error(address))
+            }
+        }
+    }
+
+    fn record_result(&self, address: &str, result: Result<(), &RedisError>) {
+        let breaker = self.get(address);
+        match result {
+            Err(err) if is_node_failure(err) => breaker.record_failure(),
+            _ => breaker.record_success(),
+        }
+    }
+}
+
+impl Client {
+    /// Returns the circuit breaker state of each node, as a map from the node address to:
+    ///
+    /// ```text
+    /// state => closed | open | half_open
+    /// consecutive_failures => (integer)
+    /// ```
+    ///
+    /// The map is empty if circuit breakers are disabled.
+    pub async fn circuit_breaker_states(&mut self) -> RedisResult<Value> {
+        let states = match self.get_or_initialize_client().await? {
+            ClientWrapper::Standalone(client) => client.circuit_breaker_states(),
+            ClientWrapper::Cluster { .. } => self
+                .cluster_circuit_breakers
+                .as_ref()
+                .map(|breakers| breakers.states())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        Ok(Value::Map(
+            states
+                .into_iter()
+                .map(|(address, state, consecutive_failures)| {
+                    (
+                        Value::BulkString(address.into_bytes()),
+                        Value::Map(vec![
+                            (
+                                Value::SimpleString("state".to_string()),
+                                Value::SimpleString(state.as_str().to_string()),
+                            ),
+                            (
+                                Value::SimpleString("consecutive_failures".to_string()),
+                                Value::Int(consecutive_failures.into()),
+                            ),
+                        ]),
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
+
+fn is_node_failure(err: &RedisError) -> bool {
+    err.is_unrecoverable_error() || err.is_timeout() || err.is_connection_refusal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn breaker(cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown,
+        })
+    }
+
+    fn io_error() -> RedisError {
+        std::io::Error::from(std::io::ErrorKind::ConnectionReset).into()
+    }
+
+    #[test]
+    fn test_breaker_opens_after_consecutive_failures() {
+        let breaker = breaker(Duration::from_secs(60));
+        breaker.record_result::<()>(&Err(io_error()));
+        // Server errors don't count as failures, and reset the count.
+        breaker.record_result::<()>(&Err(RedisError::from((
+            redis::ErrorKind::ResponseError,
+            "WRONGTYPE",
+        ))));
+        breaker.record_result::<()>(&Err(io_error()));
+        assert_eq!(breaker.state(), (CircuitState::Closed, 1));
+        assert_eq!(breaker.admit(), Admission::Allowed);
+
+        breaker.record_result::<()>(&Err(io_error()));
+        assert_eq!(breaker.state(), (CircuitState::Open, 2));
+        assert!(breaker.is_open());
+        assert_eq!(breaker.admit(), Admission::Rejected);
+    }
+
+    fn elapse_cooldown(breaker: &CircuitBreaker) {
+        let mut state = breaker.state.lock().unwrap();
+        state.changed_at -= breaker.config.cooldown;
+    }
+
+    #[test]
+    fn test_half_open_breaker_probes_once() {
+        let breaker = breaker(Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_failure();
+        elapse_cooldown(&breaker);
+        assert!(!breaker.is_open());
+        assert_eq!(breaker.admit(), Admission::Probe);
+        assert_eq!(breaker.admit(), Admission::Rejected);
+
+        // A failed probe opens the breaker again.
+        breaker.record_failure();
+        assert_eq!(breaker.state(), (CircuitState::Open, 3));
+        assert_eq!(breaker.admit(), Admission::Rejected);
+        elapse_cooldown(&breaker);
+        assert_eq!(breaker.admit(), Admission::Probe);
+        breaker.record_success();
+        assert_eq!(breaker.state(), (CircuitState::Closed, 0));
+        assert_eq!(breaker.admit(), Admission::Allowed);
+    }
+    #[test]
+    fn test_cluster_breakers_are_per_node() {
+        let breakers = ClusterCircuitBreakers::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(60),
+        });
+        let err = io_error();
+        breakers.record_result("node2:6379", Err(&err));
+        breakers.record_result("node1:6379", Ok(()));
+        assert!(matches!(
+            breakers.admit("node1:6379"),
+            NodeAdmission::Allowed
+        ));
+        match breakers.admit("node2:6379") {
+            NodeAdmission::Rejected(err) => {
+                assert_eq!(err.code(), Some(crate::errors::CIRCUIT_OPEN_CODE))
+            }
+            admission => panic!("Expected a rejection, got {admission:?}"),
+        }
+        assert_eq!(
+            breakers.states(),
+            vec![
+                ("node1:6379".to_string(), CircuitState::Closed, 0),
+                ("node2:6379".to_string(), CircuitState::Open, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Manual failover of a cluster replica with `CLUSTER FAILOVER`, with the checks an operator would run around it:
+//! the target is checked to be a replica, its replication offset may be awaited until it reaches the offset of its
+//! primary, and after the failover is issued the target is polled until it's a primary and the topology of the client
+//! lists it among the primaries.
+
+use super::types::NodeAddress;
+use super::{Client, ClientWrapper, discover_cluster_nodes};
+use redis::cluster_routing::{MultipleNodeRoutingInfo, RoutingInfo, SingleNodeRoutingInfo};
+use redis::{Cmd, ErrorKind, RedisError, RedisResult, Value};
+use std::time::{Duration, Instant};
+
+/// How often the replication state of the nodes and the topology are polled.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// The default time allowed for the promotion of the target and the topology update.
+pub const DEFAULT_FAILOVER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The mode of `CLUSTER FAILOVER`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+#[repr(C)]
+pub enum ClusterFailoverMode {
+    /// Coordinates with the primary, which stops accepting writes until the replica caught up.
+    #[default]
+    Default,
+    /// Doesn't coordinate with the primary, for when it's unreachable. Still requires the agreement of the primaries.
+    Force,
+    /// Doesn't coordinate with any node, for when the majority of the primaries is unreachable.
+    Takeover,
+}
+
+/// Options of [`Client::trigger_failover`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClusterFailoverOptions {
+    pub mode: ClusterFailoverMode,
+    /// Wait for the replication offset of the target to reach the offset of its primary, for at most this long,
+    /// before failing over. `None` fails over right away.
+    pub catch_up_timeout: Option<Duration>,
+    /// How long to wait for the promotion of the target and the topology update, after `CLUSTER FAILOVER`. Zero uses
+    /// [`DEFAULT_FAILOVER_TIMEOUT`].
+    pub timeout: Duration,
+}
+
+/// The steps of a failover, reported as they complete.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(C)]
+pub enum ClusterFailoverStage {
+    /// The target was found to be a replica.
+    Validated,
+    /// The replication offset of the target reached the offset of its primary.
+    CaughtUp,
+    /// `CLUSTER FAILOVER` was accepted by the target.
+    FailoverIssued,
+    /// The target reports itself as a primary.
+    Promoted,
+    /// The topology of the client lists the target among the primaries.
+    TopologyUpdated,
+}
+
+/// The progress of a failover, reported after each step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClusterFailoverProgress {
+    pub stage: ClusterFailoverStage,
+    /// The latest replication offset of the target.
+    pub replica_offset: i64,
+    /// The replication offset of the primary that the target catches up with. 0 if the catch-up isn't awaited.
+    pub primary_offset: i64,
+}
+
+impl ClusterFailoverMode {
+    fn command(self) -> Cmd {
+        let mut cmd = redis::cmd("CLUSTER");
+        cmd.arg("FAILOVER");
+        match self {
+            ClusterFailoverMode::Default => {}
+            ClusterFailoverMode::Force => {
+                cmd.arg("FORCE");
+            }
+            ClusterFailoverMode::Takeover => {
+                cmd.arg("TAKEOVER");
+            }
+        }
+        cmd
+    }
+}
+
+/// The replication state of a node, read from the `replication` section of `INFO`.
+#[derive(Clone, Debug)]
+struct ReplicationState {
+    is_primary: bool,
+    /// The address of the primary of a replica.
+    primary: Option<NodeAddress>,
+    offset: i64,
+}
+
+impl ReplicationState {
+    /// Reads the replication state from `INFO replication`, as converted by [`crate::info::convert_info_response`].
+    fn from_info(info: &Value) -> RedisResult<Self> {
+        let field = |name: &str| match info {
+            Value::Map(sections) => sections.iter().find_map(|(_, fields)| match fields {
+                Value::Map(fields) => fields
+                    .iter()
+                    .find(|(field, _)| matches!(field, Value::SimpleString(field) if field == name))
+                    .map(|(_, value)| value),
+                _ => None,
+            }),
+            _ => None,
+        };
+        let is_primary = match field("role") {
+            Some(Value::BulkString(role)) => role == b"master",
+            _ => {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected response for INFO replication",
+                    format!("(response was {info:?})"),
+                )));
+            }
+        };
+        let primary = match (field("master_host"), field("master_port")) {
+            (Some(Value::BulkString(host)), Some(Value::Int(port))) if !is_primary => {
+                Some(NodeAddress {
+                    host: String::from_utf8_lossy(host).into_owned(),
+                    port: *port as u16,
+                })
+            }
+            _ => None,
+        };
+        let offset_field = if is_primary {
+            "master_repl_offset"
+        } else {
+            "slave_repl_offset"
+        };
+        let offset = match field(offset_field) {
+            Some(Value::Int(offset)) => *offset,
+            _ => 0,
+        };
+        Ok(Self {
+            is_primary,
+            primary,
+            offset,
+        })
+    }
+}
+
+fn route_to(address: &NodeAddress) -> RoutingInfo {
+    RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+        host: address.host.clone(),
+        port: address.port,
+    })
+}
+
+fn timeout_error(step: &str, target: &NodeAddress) -> RedisError {
+    RedisError::from((
+        ErrorKind::IoError,
+        "Failover timed out",
+        format!("{step} of {target} didn't complete in time"),
+    ))
+}
+
+impl Client {
+    /// Fails over the replica at `target` with `CLUSTER FAILOVER`, and calls `on_progress` after each step. Fails if
+    /// the target isn't a replica, or if a step doesn't complete in time, leaving the failover to the cluster. Returns:
+    ///
+    /// ```text
+    /// node => (string) the promoted node, as host:port
+    /// previous_primary => (string) the primary of the node before the failover, as host:port
+    /// duration_ms => (integer)
+    /// ```
+    pub async fn trigger_failover(
+        &mut self,
+        target: NodeAddress,
+        options: ClusterFailoverOptions,
+        mut on_progress: impl FnMut(ClusterFailoverProgress) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        if !matches!(
+            self.get_or_initialize_client().await?,
+            ClientWrapper::Cluster { .. }
+        ) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "trigger_failover is only supported by cluster clients",
+            )));
+        }
+        let started_at = Instant::now();
+        let target_route = route_to(&target);
+        let replica = self.replication_state(target_route.clone()).await?;
+        let Some(primary) = replica.primary else {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid failover target",
+                format!("{target} isn't a replica"),
+            )));
+        };
+        let mut progress = ClusterFailoverProgress {
+            stage: ClusterFailoverStage::Validated,
+            replica_offset: replica.offset,
+            primary_offset: 0,
+        };
+        on_progress(progress)?;
+
+        if let Some(catch_up_timeout) = options.catch_up_timeout {
+            let deadline = Instant::now() + catch_up_timeout;
+            progress.primary_offset = self.replication_state(route_to(&primary)).await?.offset;
+            while progress.replica_offset < progress.primary_offset {
+                if Instant::now() >= deadline {
+                    return Err(timeout_error("The replication catch-up", &target));
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+                progress.replica_offset =
+                    self.replication_state(target_route.clone()).await?.offset;
+            }
+            progress.stage = ClusterFailoverStage::CaughtUp;
+            on_progress(progress)?;
+        }
+
+        self.send_command(&mut options.mode.command(), Some(target_route.clone()))
+            .await?;
+        progress.stage = ClusterFailoverStage::FailoverIssued;
+        on_progress(progress)?;
+
+        let deadline = Instant::now()
+            + match options.timeout {
+                Duration::ZERO => DEFAULT_FAILOVER_TIMEOUT,
+                timeout => timeout,
+            };
+        loop {
+            let state = self.replication_state(target_route.clone()).await?;
+            progress.replica_offset = state.offset;
+            if state.is_primary {
+                break;
+            }
+            if Instant::now() >= deadline {
+                return Err(timeout_error("The promotion", &target));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        progress.stage = ClusterFailoverStage::Promoted;
+        on_progress(progress)?;
+
+        let address = format!("{}:{}", target.host, target.port);
+        loop {
+            self.refresh_topology().await?;
+            let ClientWrapper::Cluster { mut client } = self.get_or_initialize_client().await?
+            else {
+                unreachable!("The client was checked to be a cluster client");
+            };
+            let primaries =
+                discover_cluster_nodes(&mut client, MultipleNodeRoutingInfo::AllMasters).await?;
+            if primaries.contains(&address) {
+                break;
+            }
+            if Instant::now() >= deadline {
+                return Err(timeout_error("The topology update", &target));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        progress.stage = ClusterFailoverStage::TopologyUpdated;
+        on_progress(progress)?;
+
+        Ok(Value::Map(vec![
+            (
+                Value::SimpleString("node".to_string()),
+                Value::BulkString(address.into_bytes()),
+            ),
+            (
+                Value::SimpleString("previous_primary".to_string()),
+                Value::BulkString(format!("{}:{}", primary.host, primary.port).into_bytes()),
+            ),
+            (
+                Value::SimpleString("duration_ms".to_string()),
+                Value::Int(started_at.elapsed().as_millis() as i64),
+            ),
+        ]))
+    }
+
+    async fn replication_state(&mut self, route: RoutingInfo) -> RedisResult<ReplicationState> {
+        let info = self
+            .get_info(&[b"replication".as_slice()], Some(route))
+            .await?;
+        ReplicationState::from_info(&info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(text: &str) -> Value {
+        crate::info::convert_info_response(Value::BulkString(text.as_bytes().to_vec())).unwrap()
+    }
+
+    #[test]
+    fn test_replication_state() {
+        let replica = info(
+            "# Replication\r\nrole:slave\r\nmaster_host:10.0.0.1\r\nmaster_port:6379\r\n\
+             master_link_status:up\r\nslave_repl_offset:1500\r\nmaster_repl_offset:1500\r\n",
+        );
+        let replica = ReplicationState::from_info(&replica).unwrap();
+        assert!(!replica.is_primary);
+        let primary = replica.primary.unwrap();
+        assert_eq!((primary.host.as_str(), primary.port), ("10.0.0.1", 6379));
+        assert_eq!(replica.offset, 1500);
+
+        let primary = info(
+            "# Replication\r\nrole:master\r\nconnected_slaves:1\r\n\
+             slave0:ip=10.0.0.2,port=6379,state=online,offset=1400,lag=0\r\nmaster_repl_offset:1600\r\n",
+        );
+        let primary = ReplicationState::from_info(&primary).unwrap();
+        assert!(primary.is_primary);
+        assert!(primary.primary.is_none());
+        assert_eq!(primary.offset, 1600);
+        assert!(ReplicationState::from_info(&info("# Server\r\nuptime_in_seconds:1\r\n")).is_err());
+    }
+
+    #[test]
+    fn test_failover_command() {
+        assert_eq!(
+            ClusterFailoverMode::Takeover.command().get_packed_command(),
+            redis::cmd("CLUSTER")
+                .arg("FAILOVER")
+                .arg("TAKEOVER")
+                .get_packed_command()
+        );
+        assert_eq!(
+            ClusterFailoverMode::Default.command().get_packed_command(),
+            redis::cmd("CLUSTER").arg("FAILOVER").get_packed_command()
+        );
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::Client;
+use super::connection_pool::{is_blocking_command, is_pinned_to_main_connection};
+use redis::cluster_routing::{Routable, RoutingInfo, SingleNodeRoutingInfo};
+use redis::{Cmd, ErrorKind, Pipeline, PipelineRetryStrategy, RedisError, RedisResult, Value};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+pub const DEFAULT_COALESCING_WINDOW: Duration = Duration::from_micros(100);
+pub const DEFAULT_COALESCING_MAX_BATCH_SIZE: usize = 64;
+
+/// Configuration of a [`CommandCoalescer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoalescingConfig {
+    /// How long the coalescer waits for more commands after the first command of a batch arrives.
+    pub window: Duration,
+    /// The maximal number of commands in a batch. A full batch is sent without waiting for the window to end.
+    pub max_batch_size: usize,
+}
+
+impl Default for CoalescingConfig {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_COALESCING_WINDOW,
+            max_batch_size: DEFAULT_COALESCING_MAX_BATCH_SIZE,
+        }
+    }
+}
+
+/// Statistics of the batches sent by a [`CommandCoalescer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CoalescingStatistics {
+    pub batches_count: u64,
+    pub commands_count: u64,
+    pub max_batch_size: u64,
+}
+
+#[derive(Default)]
+struct CoalescingCounters {
+    batches_count: AtomicU64,
+    commands_count: AtomicU64,
+    max_batch_size: AtomicU64,
+}
+
+struct QueuedCommand {
+    cmd: Cmd,
+    sender: oneshot::Sender<RedisResult<Value>>,
+}
+
+/// Coalesces commands that arrive within a short window into a single non-atomic pipeline,
+/// reducing the number of writes to the sockets under high concurrency.
+/// In cluster mode the pipeline is split by the client, so each node receives a single pipeline per batch.
+/// The batches are sent one after the other, so the commands are sent in the order they were queued.
+///
+/// The coalescer must be created inside a tokio runtime, and its background task stops once the coalescer is dropped.
+pub struct CommandCoalescer {
+    // The client the batches are sent through, used to check which commands can be coalesced.
+    client: Client,
+    sender: mpsc::UnboundedSender<QueuedCommand>,
+    config: CoalescingConfig,
+    counters: Arc<CoalescingCounters>,
+}
+
+impl CommandCoalescer {
+    pub fn new(client: Client, config: CoalescingConfig) -> Self {
+        Self::with_counters(client, config, Default::default())
+    }
+
+    /// Returns a coalescer that sends the commands through `client`, with the configuration of this coalescer.
+    /// The statistics are shared with this coalescer, which keeps sending the commands it already queued.
+    pub fn with_client(&self, client: Client) -> Self {
+        Self::with_counters(client, self.config, self.counters.clone())
+    }
+
+    fn with_counters(
+        client: Client,
+        config: CoalescingConfig,
+        counters: Arc<CoalescingCounters>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(run_coalescer(
+            client.clone(),
+            config,
+            receiver,
+            counters.clone(),
+        ));
+        Self {
+            client,
+            sender,
+            config,
+            counters,
+        }
+    }
+
+    /// Returns true if the command can be sent as part of a coalesced batch.
+    /// Only single-slot key-based commands without an explicit route are coalesced, excluding blocking commands,
+    /// commands that must be sent through the main connection, and commands that need the handling that
+    /// [`Client::send_command`] applies to each command on its own.
+    pub fn can_coalesce(&self, cmd: &Cmd, routing: Option<&RoutingInfo>) -> bool {
+        is_coalescable_command(cmd, routing) && self.client.can_send_in_pipeline(cmd)
+    }
+
+    /// Queues the command for the next batch and waits for its response.
+    pub async fn send_command(&self, cmd: Cmd) -> RedisResult<Value> {
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send(QueuedCommand { cmd, sender })
+            .map_err(|_| coalescer_closed_error())?;
+        receiver.await.map_err(|_| coalescer_closed_error())?
+    }
+
+    pub fn statistics(&self) -> CoalescingStatistics {
+        CoalescingStatistics {
+            batches_count: self.counters.batches_count.load(Ordering::Relaxed),
+            commands_count: self.counters.commands_count.load(Ordering::Relaxed),
+            max_batch_size: self.counters.max_batch_size.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn is_coalescable_command(cmd: &Cmd, routing: Option<&RoutingInfo>) -> bool {
+    routing.is_none()
+        && matches!(
+            RoutingInfo::for_routable(cmd),
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::SpecificNode(_)
+            ))
+        )
+        && !is_blocking_command(cmd)
+        && !is_pinned_to_main_connection(cmd)
+}
+
+fn coalescer_closed_error() -> RedisError {
+    RedisError::from((
+        ErrorKind::ClientError,
+        "Command coalescer was closed before the command completed",
+    ))
+}
+
+async fn run_coalescer(
+    client: Client,
+    config: CoalescingConfig,
+    mut receiver: mpsc::UnboundedReceiver<QueuedCommand>,
+    counters: Arc<CoalescingCounters>,
+) {
+    let max_batch_size = config.max_batch_size.max(1);
+    while let Some(first) = receiver.recv().await {
+        let deadline = Instant::now() + config.window;
+        let mut batch = vec![first];
+        while batch.len() < max_batch_size {
+            match tokio::time::timeout_at(deadline, receiver.recv()).await {
+                Ok(Some(command)) => batch.push(command),
+                // The window ended, or the coalescer was dropped - the collected commands are still sent.
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        counters.batches_count.fetch_add(1, Ordering::Relaxed);
+        counters
+            .commands_count
+            .fetch_add(batch.len() as u64, Ordering::Relaxed);
+        counters
+            .max_batch_size
+            .fetch_max(batch.len() as u64, Ordering::Relaxed);
+
+        // The next batch is sent only once this one completed, so a later command can't overtake an earlier one.
+        send_batch(client.clone(), batch).await;
+    }
+}
+
+async fn send_batch(mut client: Client, batch: Vec<QueuedCommand>) {
+    let (cmds, senders): (Vec<_>, Vec<_>) = batch
+        .into_iter()
+        .map(|command| (command.cmd, command.sender))
+        .unzip();
+    let mut pipeline = Pipeline::with_capacity(cmds.len());
+    for cmd in cmds {
+        pipeline.add_command(cmd);
+    }
+
+    let result = client
+        .send_pipeline(
+            &pipeline,
+            None,
+            false,
+            None,
+            // Like a command sent on its own, a coalesced command is retried after a connection error.
+            PipelineRetryStrategy {
+                retry_server_error: false,
+                retry_connection_error: true,
+                follow_ask_redirects: true,
+            },
+        )
+        .await;
+    for (sender, result) in senders
+        .into_iter()
+        .zip(split_batch_result(result, pipeline.len()))
+    {
+        // The caller may have stopped waiting for the response.
+        let _ = sender.send(result);
+    }
+}
+
+/// Splits the response of a coalesced pipeline into the responses of its commands.
+fn split_batch_result(result: RedisResult<Value>, command_count: usize) -> Vec<RedisResult<Value>> {
+    match result {
+        Ok(Value::Array(values)) if values.len() == command_count => values
+            .into_iter()
+            .map(|value| value.extract_error())
+            .collect(),
+        Ok(value) => (0..command_count)
+            .map(|_| {
+                Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected response for a coalesced batch",
+                    format!("(response was {value:?})"),
+                )))
+            })
+            .collect(),
+        Err(err) => (0..command_count)
+            .map(|_| {
+                Err(RedisError::from((
+                    err.kind(),
+                    "Coalesced batch failed",
+                    err.to_string(),
+                )))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_coalescable_command() {
+        assert!(is_coalescable_command(redis::cmd("GET").arg("key"), None));
+        assert!(!is_coalescable_command(
+            redis::cmd("GET").arg("key"),
+            Some(&RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
+        ));
+        assert!(!is_coalescable_command(&redis::cmd("PING"), None));
+        assert!(!is_coalescable_command(
+            redis::cmd("BLPOP").arg("key").arg(1),
+            None
+        ));
+        assert!(!is_coalescable_command(
+            redis::cmd("WATCH").arg("key"),
+            None
+        ));
+    }
+
+    #[test]
+    fn test_split_batch_result() {
+        let results = split_batch_result(Ok(Value::Array(vec![Value::Okay, Value::Nil])), 2);
+        assert_eq!(results, vec![Ok(Value::Okay), Ok(Value::Nil)]);
+
+        let results = split_batch_result(Ok(Value::Okay), 2);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| result.is_err()));
+
+        let results = split_batch_result(
+            Err(RedisError::from((ErrorKind::IoError, "disconnected"))),
+            3,
+        );
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| {
+            result
+                .as_ref()
+                .is_err_and(|err| err.kind() == ErrorKind::IoError)
+        }));
+    }
+}
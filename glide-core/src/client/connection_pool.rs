@@ -0,0 +1,946 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::{Client, ConnectionError, ConnectionRequest, NodeAddress};
+use redis::cluster_routing::{Routable, RoutingInfo};
+use redis::{Cmd, ErrorKind, RedisError, RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Notify, OnceCell};
+
+/// Additional connections opened next to the client's main connection when `connection_pool_size` is set.
+///
+/// Each member is a full client with its own multiplexed connection to every node, so a command that blocks
+/// one member on the server doesn't delay the commands sent through the others.
+/// Commands that change the connection state (SELECT, AUTH, HELLO, CLIENT SETNAME) are sent through the main connection
+/// and then replayed on every member, while transactions and the (un)subscribe commands are handled by the main connection
+/// only, since the members don't receive push notifications.
+pub(super) struct ConnectionPool {
+    members: Vec<PoolMember>,
+    next_member: AtomicUsize,
+}
+
+struct PoolMember {
+    client: Client,
+    // Set while the member is pinned to a blocking command.
+    pinned: AtomicBool,
+}
+
+/// A pool member checked out for a single command. A member pinned to a blocking command is released when the lease is dropped.
+pub(super) struct PoolLease {
+    pool: Arc<ConnectionPool>,
+    index: usize,
+    pinned: bool,
+}
+
+impl PoolLease {
+    pub(super) fn client(&self) -> Client {
+        self.pool.members[self.index].client.clone()
+    }
+}
+
+impl Drop for PoolLease {
+    fn drop(&mut self) {
+        if self.pinned {
+            self.pool.members[self.index]
+                .pinned
+                .store(false, Ordering::Release);
+        }
+    }
+}
+
+impl ConnectionPool {
+    pub(super) fn new(members: Vec<Client>) -> Self {
+        Self {
+            members: members
+                .into_iter()
+                .map(|client| PoolMember {
+                    client,
+                    pinned: AtomicBool::new(false),
+                })
+                .collect(),
+            next_member: AtomicUsize::new(0),
+        }
+    }
+
+    pub(super) fn members(&self) -> impl Iterator<Item = Client> + '_ {
+        self.members.iter().map(|member| member.client.clone())
+    }
+
+    /// Selects the connection the command should be sent through.
+    /// Returns `None` if the command should be sent through the main connection.
+    ///
+    /// Regular commands are spread in a round-robin manner between the main connection and the pool members
+    /// that aren't pinned to a blocking command. A blocking command pins a free member until it completes,
+    /// and falls back to the main connection if all members are pinned.
+    /// Commands pinned to the main connection are never sent through a member.
+    pub(super) fn checkout(self: &Arc<Self>, cmd: &Cmd) -> Option<PoolLease> {
+        if is_pinned_to_main_connection(cmd) {
+            return None;
+        }
+        if is_blocking_command(cmd) {
+            return self.pin_free_member();
+        }
+
+        let slots = self.members.len() + 1;
+        for _ in 0..slots {
+            let slot = self.next_member.fetch_add(1, Ordering::Relaxed) % slots;
+            // Slot 0 is the main connection, which is never pinned.
+            let Some(index) = slot.checked_sub(1) else {
+                return None;
+            };
+            if !self.members[index].pinned.load(Ordering::Acquire) {
+                return Some(PoolLease {
+                    pool: self.clone(),
+                    index,
+                    pinned: false,
+                });
+            }
+        }
+        None
+    }
+
+    fn pin_free_member(self: &Arc<Self>) -> Option<PoolLease> {
+        self.members.iter().enumerate().find_map(|(index, member)| {
+            member
+                .pinned
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .ok()
+                .map(|_| PoolLease {
+                    pool: self.clone(),
+                    index,
+                    pinned: true,
+                })
+        })
+    }
+}
+
+/// Returns the request of a side connection opened next to the client's main connection.
+fn side_connection_request(mut request: ConnectionRequest) -> ConnectionRequest {
+    // The side connections don't take part in pubsub,
+    // and their connection events would duplicate the events of the main connection.
+    request.connection_pool_size = None;
+    request.pubsub_subscriptions = None;
+    request.connection_event_listener = None;
+    request.lazy_connect = true;
+    request
+}
+
+/// Returns the request of a side connection to the cluster node `address` only, as a standalone connection.
+fn node_connection_request(
+    mut request: ConnectionRequest,
+    address: &str,
+) -> RedisResult<ConnectionRequest> {
+    // The slot map holds the addresses the nodes announce, which may have to be translated before dialing.
+    let dial_address = request
+        .address_translation
+        .translate(address)
+        .unwrap_or_else(|| address.to_string());
+    let Some((host, port)) = dial_address
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host.to_string(), port.parse::<u16>().ok()?)))
+    else {
+        return Err(RedisError::from((
+            ErrorKind::ClientError,
+            "Failed to parse node address",
+            dial_address,
+        )));
+    };
+    request.addresses = vec![NodeAddress { host, port }];
+    request.cluster_mode_enabled = false;
+    request.read_from = None;
+    Ok(request)
+}
+
+async fn create_side_connection(request: &ConnectionRequest) -> RedisResult<Client> {
+    Box::pin(Client::new(request.clone(), None))
+        .await
+        .map_err(|err: ConnectionError| {
+            RedisError::from((
+                ErrorKind::IoError,
+                "Failed to create a side connection",
+                err.to_string(),
+            ))
+        })
+}
+
+/// The request the side connections are created with. It starts as the request the client was created with, and
+/// follows the changes made to the state of the main connection since, such as the database selected with `SELECT`,
+/// the name set with `CLIENT SETNAME` or a new password, so a new side connection starts in the same state as the
+/// main connection.
+pub(super) struct SideConnectionState {
+    request: Mutex<ConnectionRequest>,
+    // Incremented on every change, so the connections created before a change aren't reused after it.
+    generation: AtomicU64,
+}
+
+impl SideConnectionState {
+    pub(super) fn new(request: ConnectionRequest) -> Self {
+        Self {
+            request: Mutex::new(side_connection_request(request)),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the current request, with its generation.
+    fn request(&self) -> (ConnectionRequest, u64) {
+        let request = self
+            .request
+            .lock()
+            .expect("Failed to acquire the side connection state lock");
+        (request.clone(), self.generation.load(Ordering::Acquire))
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Applies a change of the state of the main connection to the side connections created from now on.
+    pub(super) fn update(&self, update: impl FnOnce(&mut ConnectionRequest)) {
+        let mut request = self
+            .request
+            .lock()
+            .expect("Failed to acquire the side connection state lock");
+        update(&mut request);
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+/// A side connection shared by the commands sent through it. The connection is created on the first command, from
+/// the [`SideConnectionState`] of the client at that time. Since the connection may hold state of its own, such as
+/// the keys it watches, it isn't replaced when the state changes afterwards: [`Client`] applies the changes to it.
+pub(super) struct SideConnection {
+    state: Arc<SideConnectionState>,
+    database_id: Option<i64>,
+    client: OnceCell<Client>,
+}
+
+impl SideConnection {
+    /// Creates a side connection to `database_id`, or to the database of the main connection if `None`.
+    pub(super) fn new(state: Arc<SideConnectionState>, database_id: Option<i64>) -> Self {
+        Self {
+            state,
+            database_id,
+            client: OnceCell::new(),
+        }
+    }
+
+    pub(super) async fn client(&self) -> RedisResult<Client> {
+        self.client
+            .get_or_try_init(|| async {
+                loop {
+                    let (mut request, generation) = self.state.request();
+                    if let Some(database_id) = self.database_id {
+                        request.database_id = database_id;
+                    }
+                    let client = create_side_connection(&request).await?;
+                    // A change made while the connection was created isn't applied to it, so it's created again.
+                    if generation == self.state.generation() {
+                        return Ok(client);
+                    }
+                }
+            })
+            .await
+            .cloned()
+    }
+
+    /// Returns the connection if it was already created.
+    pub(super) fn connected_client(&self) -> Option<Client> {
+        self.client.get().cloned()
+    }
+}
+
+/// The number of idle blocking connections kept for the next blocking commands, per node. Connections opened
+/// while more blocking commands were in flight are closed once their command completes.
+const MAX_IDLE_BLOCKING_CONNECTIONS: usize = 4;
+
+/// Side connections reserved for blocking commands, so they don't delay the commands multiplexed on the main connection.
+///
+/// Each blocking command in flight holds a connection of its own, so a command that blocks on the server
+/// doesn't delay the other blocking commands either. An idle connection is reused, or a new one is opened
+/// if all the connections are in use. The blocking commands of a cluster client are sent through connections to
+/// their node only, rather than through connections to the whole cluster.
+///
+/// The connections are created from the [`SideConnectionState`] of the client. Once the state changes, the idle
+/// connections are dropped, and the connections in use aren't reused after their command.
+pub(super) struct BlockingConnections {
+    state: Arc<SideConnectionState>,
+    // The database the connections select instead of the database of the client, if any.
+    database_id: Option<i64>,
+    idle: Mutex<Vec<IdleConnection>>,
+}
+
+/// An idle blocking connection, to the cluster node `node`, or to the nodes of a standalone client if it's `None`.
+struct IdleConnection {
+    node: Option<String>,
+    generation: u64,
+    client: Client,
+}
+
+/// A blocking connection checked out for a single command.
+/// The connection is returned to the idle connections only if [`BlockingLease::reuse`] is called, since a command
+/// that timed out or was cancelled may still be blocking the connection on the server.
+pub(super) struct BlockingLease {
+    connections: Arc<BlockingConnections>,
+    connection: Option<IdleConnection>,
+    reuse: bool,
+}
+
+impl BlockingLease {
+    pub(super) fn client(&self) -> Client {
+        self.connection
+            .as_ref()
+            .map(|connection| connection.client.clone())
+            .expect("The connection of a lease is taken only when it's dropped")
+    }
+
+    /// Marks the connection as free for the next blocking command once the lease is dropped.
+    pub(super) fn reuse(&mut self) {
+        self.reuse = true;
+    }
+}
+
+impl Drop for BlockingLease {
+    fn drop(&mut self) {
+        let Some(connection) = self.connection.take() else {
+            return;
+        };
+        // A connection created before the state changed doesn't follow the state of the main connection.
+        if !self.reuse || connection.generation != self.connections.state.generation() {
+            return;
+        }
+        let mut idle = self
+            .connections
+            .idle
+            .lock()
+            .expect("Failed to acquire the blocking connections lock");
+        let idle_for_node = idle
+            .iter()
+            .filter(|idle| idle.node == connection.node)
+            .count();
+        if idle_for_node < MAX_IDLE_BLOCKING_CONNECTIONS {
+            idle.push(connection);
+        }
+    }
+}
+
+impl BlockingConnections {
+    pub(super) fn new(state: Arc<SideConnectionState>, database_id: Option<i64>) -> Self {
+        Self {
+            state,
+            database_id,
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Checks out an idle connection to `node`, or opens a new one if none is idle.
+    /// `node` is the address of a cluster node, or `None` for the nodes of a standalone client.
+    pub(super) async fn checkout(
+        self: &Arc<Self>,
+        node: Option<&str>,
+    ) -> RedisResult<BlockingLease> {
+        let idle = {
+            let generation = self.state.generation();
+            let mut idle = self
+                .idle
+                .lock()
+                .expect("Failed to acquire the blocking connections lock");
+            idle.retain(|idle| idle.generation == generation);
+            idle.iter()
+                .rposition(|idle| idle.node.as_deref() == node)
+                .map(|index| idle.swap_remove(index))
+        };
+        let connection = match idle {
+            Some(connection) => connection,
+            None => self.connect(node).await?,
+        };
+        Ok(BlockingLease {
+            connections: self.clone(),
+            connection: Some(connection),
+            reuse: false,
+        })
+    }
+
+    async fn connect(&self, node: Option<&str>) -> RedisResult<IdleConnection> {
+        let (mut request, generation) = self.state.request();
+        if let Some(database_id) = self.database_id {
+            request.database_id = database_id;
+        }
+        if let Some(node) = node {
+            request = node_connection_request(request, node)?;
+        }
+        Ok(IdleConnection {
+            node: node.map(str::to_string),
+            generation,
+            client: create_side_connection(&request).await?,
+        })
+    }
+
+    /// Drops the idle connections, after the state of the main connection changed.
+    pub(super) fn drop_idle(&self) {
+        self.idle
+            .lock()
+            .expect("Failed to acquire the blocking connections lock")
+            .clear();
+    }
+
+    /// Sends a blocking command through a connection of its own, to the cluster node `node`, or through the
+    /// connections of a standalone client if `node` is `None`.
+    /// If the slot of the command moved to another node since `node` was looked up, the command is sent once more
+    /// to the node it moved to.
+    pub(super) async fn send_command(
+        self: &Arc<Self>,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+        node: Option<String>,
+    ) -> RedisResult<Value> {
+        let Some(node) = node else {
+            return self.send_command_through(cmd, routing, None).await;
+        };
+        match self.send_command_through(cmd, None, Some(&node)).await {
+            Err(err) if err.kind() == ErrorKind::Moved => match err.redirect_node() {
+                Some((moved_to, _)) => {
+                    let moved_to = moved_to.to_string();
+                    self.send_command_through(cmd, None, Some(&moved_to)).await
+                }
+                None => Err(err),
+            },
+            result => result,
+        }
+    }
+
+    async fn send_command_through(
+        self: &Arc<Self>,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+        node: Option<&str>,
+    ) -> RedisResult<Value> {
+        let mut lease = self.checkout(node).await?;
+        let mut client = lease.client();
+        let result = client.send_command(cmd, routing).await;
+        if !matches!(&result, Err(err) if err.is_timeout()) {
+            lease.reuse();
+        }
+        result
+    }
+}
+
+/// Side connections to other logical databases than the client's, that the commands overriding the database are sent
+/// through. Each connection selects its database while connecting, so the database of the main connection never
+/// changes. The connection to a database is created on the first command sent to it.
+///
+/// The regular commands of a database are multiplexed on a shared connection, while its blocking commands are sent
+/// through blocking connections of their own, so a blocking command doesn't stall the other commands of its database.
+pub(super) struct DatabaseConnections {
+    state: Arc<SideConnectionState>,
+    connections: Mutex<HashMap<i64, Arc<DatabaseConnection>>>,
+}
+
+struct DatabaseConnection {
+    regular: SideConnection,
+    blocking: Arc<BlockingConnections>,
+}
+
+impl DatabaseConnections {
+    pub(super) fn new(state: Arc<SideConnectionState>) -> Self {
+        Self {
+            state,
+            connections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn connection(&self, database_id: i64) -> Arc<DatabaseConnection> {
+        self.connections
+            .lock()
+            .expect("Failed to acquire the database connections lock")
+            .entry(database_id)
+            .or_insert_with(|| {
+                Arc::new(DatabaseConnection {
+                    regular: SideConnection::new(self.state.clone(), Some(database_id)),
+                    blocking: Arc::new(BlockingConnections::new(
+                        self.state.clone(),
+                        Some(database_id),
+                    )),
+                })
+            })
+            .clone()
+    }
+
+    /// Returns the regular connections of the databases that were already created.
+    pub(super) fn connected_clients(&self) -> Vec<Client> {
+        self.connections
+            .lock()
+            .expect("Failed to acquire the database connections lock")
+            .values()
+            .filter_map(|connection| connection.regular.connected_client())
+            .collect()
+    }
+
+    /// Drops the idle blocking connections of all the databases.
+    pub(super) fn drop_idle(&self) {
+        for connection in self
+            .connections
+            .lock()
+            .expect("Failed to acquire the database connections lock")
+            .values()
+        {
+            connection.blocking.drop_idle();
+        }
+    }
+
+    /// Sends the command through the connections of the database.
+    pub(super) async fn send_command(
+        &self,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+        database_id: i64,
+    ) -> RedisResult<Value> {
+        let connection = self.connection(database_id);
+        if is_blocking_command(cmd) {
+            return connection.blocking.send_command(cmd, routing, None).await;
+        }
+        connection
+            .regular
+            .client()
+            .await?
+            .send_command(cmd, routing)
+            .await
+    }
+}
+
+/// How long a watch stays reserved without `EXEC` or `UNWATCH`, after which another watch may take over its connection.
+pub const WATCH_EXPIRY: Duration = Duration::from_secs(60);
+
+/// A side connection that `WATCH`, `UNWATCH` and the watched transactions are sent through, so the commands of
+/// the main connection can't release or trigger the watch between `WATCH` and `EXEC`.
+///
+/// Only one watch can be active at a time: the connection is reserved from `WATCH` until the watched transaction
+/// is executed or `UNWATCH` is sent with the token of the reservation, and a second `WATCH` waits until then. A watch
+/// that is neither executed nor released expires after [`WATCH_EXPIRY`], so it can't block the later watches forever.
+pub(super) struct WatchConnection {
+    connection: SideConnection,
+    reservation: Mutex<Option<WatchReservation>>,
+    released: Notify,
+    next_token: AtomicU64,
+}
+
+/// The reservation of the watch connection by an active watch.
+struct WatchReservation {
+    /// Identifies the watch to the caller that started it, so other callers can't release it.
+    token: u64,
+    expires_at: Instant,
+}
+
+impl WatchConnection {
+    pub(super) fn new(state: Arc<SideConnectionState>) -> Self {
+        Self {
+            connection: SideConnection::new(state, None),
+            reservation: Mutex::new(None),
+            released: Notify::new(),
+            next_token: AtomicU64::new(1),
+        }
+    }
+
+    /// Waits until the connection isn't reserved by another watch, or its reservation expired, and reserves it.
+    /// Returns the token of the new reservation, with the connection.
+    pub(super) async fn reserve(&self) -> RedisResult<(u64, Client)> {
+        let (token, took_over_expired_watch) = loop {
+            // Created before checking the reservation, so a release in between isn't missed.
+            let released = self.released.notified();
+            let expires_at = {
+                let mut reservation = self
+                    .reservation
+                    .lock()
+                    .expect("Failed to acquire the watch reservation lock");
+                let now = Instant::now();
+                match reservation.as_ref().map(|active| active.expires_at) {
+                    Some(expires_at) if expires_at > now => expires_at,
+                    expired => {
+                        let token = self.next_token.fetch_add(1, Ordering::Relaxed);
+                        *reservation = Some(WatchReservation {
+                            token,
+                            expires_at: now + WATCH_EXPIRY,
+                        });
+                        break (token, expired.is_some());
+                    }
+                }
+            };
+            let _ = tokio::time::timeout_at(expires_at.into(), released).await;
+        };
+
+        let client = async {
+            let mut client = self.connection.client().await?;
+            if took_over_expired_watch {
+                // The keys of the expired watch are still watched on the server, and would abort the new transaction.
+                client
+                    .send_command(&mut redis::cmd("UNWATCH"), None)
+                    .await?;
+            }
+            Ok::<_, RedisError>(client)
+        }
+        .await;
+        if client.is_err() {
+            self.release(token);
+        }
+        client.map(|client| (token, client))
+    }
+
+    /// Returns the connection if it was already created, so the changes of the state of the client can be applied.
+    pub(super) fn connected_client(&self) -> Option<Client> {
+        self.connection.connected_client()
+    }
+
+    /// Returns the connection if `token` identifies the active watch, and it didn't expire.
+    pub(super) async fn reserved_client(&self, token: u64) -> Option<RedisResult<Client>> {
+        if !self.is_reserved_by(token) {
+            return None;
+        }
+        Some(self.connection.client().await)
+    }
+
+    fn is_reserved_by(&self, token: u64) -> bool {
+        self.reservation
+            .lock()
+            .expect("Failed to acquire the watch reservation lock")
+            .as_ref()
+            .is_some_and(|active| active.token == token && active.expires_at > Instant::now())
+    }
+
+    /// Ends the reservation of the watch identified by `token`, if it's still active.
+    pub(super) fn release(&self, token: u64) {
+        let mut reservation = self
+            .reservation
+            .lock()
+            .expect("Failed to acquire the watch reservation lock");
+        if reservation
+            .as_ref()
+            .is_some_and(|active| active.token == token)
+        {
+            *reservation = None;
+            self.released.notify_waiters();
+        }
+    }
+}
+
+/// Returns true if the command may occupy its connection on the server for a long period of time.
+pub(super) fn is_blocking_command(cmd: &Cmd) -> bool {
+    let command = cmd.command().unwrap_or_default();
+    match command.as_slice() {
+        b"BLPOP" | b"BRPOP" | b"BLMOVE" | b"BZPOPMAX" | b"BZPOPMIN" | b"BRPOPLPUSH" | b"BLMPOP"
+        | b"BZMPOP" => true,
+        b"XREAD" | b"XREADGROUP" => cmd.position(b"BLOCK").is_some(),
+        b"DEBUG" => cmd
+            .arg_idx(1)
+            .is_some_and(|subcommand| subcommand.eq_ignore_ascii_case(b"SLEEP")),
+        _ => false,
+    }
+}
+
+/// Returns true if the command changes the state of the connection it was sent through.
+pub(super) fn is_connection_state_command(cmd: &Cmd) -> bool {
+    let command = cmd.command().unwrap_or_default();
+    matches!(
+        command.as_slice(),
+        b"SELECT" | b"AUTH" | b"HELLO" | b"CLIENT SETNAME"
+    )
+}
+
+/// Returns true if the command must be sent through the main connection.
+pub(super) fn is_pinned_to_main_connection(cmd: &Cmd) -> bool {
+    let command = cmd.command().unwrap_or_default();
+    is_connection_state_command(cmd)
+        || matches!(
+            command.as_slice(),
+            b"WATCH"
+                | b"UNWATCH"
+                | b"MULTI"
+                | b"EXEC"
+                | b"DISCARD"
+                | b"SUBSCRIBE"
+                | b"PSUBSCRIBE"
+                | b"SSUBSCRIBE"
+                | b"UNSUBSCRIBE"
+                | b"PUNSUBSCRIBE"
+                | b"SUNSUBSCRIBE"
+                | b"WAIT"
+                | b"WAITAOF"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_blocking_command() {
+        assert!(is_blocking_command(redis::cmd("BLPOP").arg("key").arg(1)));
+        assert!(is_blocking_command(
+            redis::cmd("XREAD").arg("BLOCK").arg(100).arg("STREAMS")
+        ));
+        assert!(!is_blocking_command(redis::cmd("XREAD").arg("STREAMS")));
+        assert!(is_blocking_command(redis::cmd("DEBUG").arg("sleep").arg(1)));
+        assert!(!is_blocking_command(redis::cmd("GET").arg("key")));
+        // WAIT and WAITAOF count the acknowledgements of the writes of their connection, so they aren't moved to
+        // another one.
+        assert!(!is_blocking_command(redis::cmd("WAIT").arg(1).arg(0)));
+        assert!(!is_blocking_command(
+            redis::cmd("WAITAOF").arg(1).arg(0).arg(0)
+        ));
+        assert!(is_pinned_to_main_connection(
+            redis::cmd("WAIT").arg(1).arg(0)
+        ));
+    }
+
+    #[test]
+    fn test_is_pinned_to_main_connection() {
+        assert!(is_pinned_to_main_connection(redis::cmd("SELECT").arg(1)));
+        assert!(is_pinned_to_main_connection(
+            redis::cmd("CLIENT").arg("SETNAME").arg("name")
+        ));
+        assert!(is_pinned_to_main_connection(redis::cmd("WATCH").arg("key")));
+        for command in [
+            "SUBSCRIBE",
+            "PSUBSCRIBE",
+            "SSUBSCRIBE",
+            "UNSUBSCRIBE",
+            "PUNSUBSCRIBE",
+            "SUNSUBSCRIBE",
+        ] {
+            assert!(is_pinned_to_main_connection(
+                redis::cmd(command).arg("channel")
+            ));
+        }
+        assert!(!is_pinned_to_main_connection(redis::cmd("GET").arg("key")));
+    }
+
+    fn lazy_request() -> ConnectionRequest {
+        ConnectionRequest {
+            addresses: vec![super::super::NodeAddress {
+                host: "127.0.0.1".to_string(),
+                port: 6379,
+            }],
+            lazy_connect: true,
+            ..Default::default()
+        }
+    }
+
+    async fn lazy_pool(size: usize) -> Arc<ConnectionPool> {
+        let mut members = Vec::new();
+        for _ in 0..size {
+            members.push(Client::new(lazy_request(), None).await.unwrap());
+        }
+        Arc::new(ConnectionPool::new(members))
+    }
+
+    fn lazy_state() -> Arc<SideConnectionState> {
+        Arc::new(SideConnectionState::new(lazy_request()))
+    }
+
+    #[test]
+    fn test_each_database_has_connections_of_its_own() {
+        let database_connections = DatabaseConnections::new(lazy_state());
+        let first = database_connections.connection(1);
+        assert!(Arc::ptr_eq(&first, &database_connections.connection(1)));
+        assert!(!Arc::ptr_eq(&first, &database_connections.connection(2)));
+        assert_eq!(first.regular.database_id, Some(1));
+        assert_eq!(first.blocking.database_id, Some(1));
+        assert_eq!(
+            database_connections.connection(2).blocking.database_id,
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_each_blocking_command_in_flight_holds_a_connection_of_its_own() {
+        let connections = Arc::new(BlockingConnections::new(lazy_state(), None));
+        let idle = || connections.idle.lock().unwrap().len();
+
+        let mut first = connections.checkout(None).await.unwrap();
+        let second = connections.checkout(None).await.unwrap();
+        assert_eq!(idle(), 0);
+
+        // A completed command frees its connection for the next one.
+        first.reuse();
+        drop(first);
+        assert_eq!(idle(), 1);
+        let third = connections.checkout(None).await.unwrap();
+        assert_eq!(idle(), 0);
+
+        // A command that timed out or was cancelled may still block its connection, which isn't reused.
+        drop(second);
+        drop(third);
+        assert_eq!(idle(), 0);
+
+        let mut leases = Vec::new();
+        for _ in 0..MAX_IDLE_BLOCKING_CONNECTIONS + 2 {
+            leases.push(connections.checkout(None).await.unwrap());
+        }
+        for mut lease in leases {
+            lease.reuse();
+        }
+        assert_eq!(idle(), MAX_IDLE_BLOCKING_CONNECTIONS);
+    }
+
+    #[tokio::test]
+    async fn test_blocking_connections_are_kept_per_node() {
+        let connections = Arc::new(BlockingConnections::new(lazy_state(), None));
+        let mut lease = connections.checkout(Some("127.0.0.1:7000")).await.unwrap();
+        lease.reuse();
+        drop(lease);
+
+        // An idle connection to another node isn't reused.
+        let mut lease = connections.checkout(Some("127.0.0.1:7001")).await.unwrap();
+        lease.reuse();
+        drop(lease);
+        assert_eq!(connections.idle.lock().unwrap().len(), 2);
+
+        let lease = connections.checkout(Some("127.0.0.1:7000")).await.unwrap();
+        let idle_nodes: Vec<_> = connections
+            .idle
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|idle| idle.node.clone())
+            .collect();
+        assert_eq!(idle_nodes, vec![Some("127.0.0.1:7001".to_string())]);
+        drop(lease);
+    }
+
+    #[tokio::test]
+    async fn test_blocking_connections_follow_the_state_of_the_main_connection() {
+        let state = lazy_state();
+        let connections = Arc::new(BlockingConnections::new(state.clone(), None));
+        let mut idle = connections.checkout(None).await.unwrap();
+        idle.reuse();
+        drop(idle);
+        let mut in_flight = connections.checkout(None).await.unwrap();
+        in_flight.reuse();
+
+        state.update(|request| request.database_id = 4);
+        connections.drop_idle();
+        assert!(connections.idle.lock().unwrap().is_empty());
+
+        // A connection created before the change isn't reused after its command.
+        drop(in_flight);
+        assert!(connections.idle.lock().unwrap().is_empty());
+
+        let lease = connections.checkout(None).await.unwrap();
+        let (request, generation) = state.request();
+        assert_eq!(request.database_id, 4);
+        assert_eq!(lease.connection.as_ref().unwrap().generation, generation);
+    }
+
+    #[test]
+    fn test_node_connection_request_connects_to_the_node_only() {
+        let mut request = lazy_request();
+        request.cluster_mode_enabled = true;
+        request.read_from = Some(super::super::ReadFrom::PreferReplica);
+        request.address_translation = redis::AddressTranslation::new(HashMap::from([(
+            "10.0.0.1:6379".to_string(),
+            "127.0.0.1:7000".to_string(),
+        )]));
+
+        let node_request = node_connection_request(request.clone(), "10.0.0.1:6379").unwrap();
+        assert!(!node_request.cluster_mode_enabled);
+        assert!(node_request.read_from.is_none());
+        assert_eq!(node_request.addresses.len(), 1);
+        assert_eq!(node_request.addresses[0].host, "127.0.0.1");
+        assert_eq!(node_request.addresses[0].port, 7000);
+
+        let node_request = node_connection_request(request.clone(), "10.0.0.2:6380").unwrap();
+        assert_eq!(node_request.addresses[0].host, "10.0.0.2");
+        assert_eq!(node_request.addresses[0].port, 6380);
+        assert!(node_connection_request(request, "10.0.0.2").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_checkout_keeps_pinned_commands_on_the_main_connection() {
+        let pool = lazy_pool(2).await;
+        for _ in 0..3 {
+            for command in [
+                "SUBSCRIBE",
+                "PSUBSCRIBE",
+                "SSUBSCRIBE",
+                "UNSUBSCRIBE",
+                "PUNSUBSCRIBE",
+                "SUNSUBSCRIBE",
+                "WATCH",
+                "MULTI",
+                "SELECT",
+            ] {
+                assert!(pool.checkout(redis::cmd(command).arg("arg")).is_none());
+            }
+        }
+        // Regular commands are spread over the main connection and the members.
+        let leases = (0..3)
+            .filter_map(|_| pool.checkout(redis::cmd("GET").arg("key")))
+            .count();
+        assert_eq!(leases, 2);
+    }
+
+    #[tokio::test]
+    async fn test_checkout_pins_a_member_to_a_blocking_command() {
+        let pool = lazy_pool(1).await;
+        let lease = pool
+            .checkout(redis::cmd("BLPOP").arg("key").arg(0))
+            .unwrap();
+        assert!(
+            pool.checkout(redis::cmd("BLPOP").arg("key").arg(0))
+                .is_none()
+        );
+        // The pinned member is skipped by regular commands too.
+        for _ in 0..2 {
+            assert!(pool.checkout(redis::cmd("GET").arg("key")).is_none());
+        }
+        drop(lease);
+        assert!(
+            pool.checkout(redis::cmd("BLPOP").arg("key").arg(0))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_watch_reservation_is_released_only_by_its_token() {
+        let watch_connection = WatchConnection::new(lazy_state());
+        let reserve = |token, expires_at| {
+            *watch_connection.reservation.lock().unwrap() =
+                Some(WatchReservation { token, expires_at });
+        };
+        reserve(1, Instant::now() + WATCH_EXPIRY);
+        watch_connection.release(2);
+        assert!(watch_connection.is_reserved_by(1));
+        assert!(!watch_connection.is_reserved_by(2));
+        watch_connection.release(1);
+        assert!(!watch_connection.is_reserved_by(1));
+
+        // An expired watch can't be used anymore, even by its own token.
+        reserve(3, Instant::now() - Duration::from_millis(1));
+        assert!(!watch_connection.is_reserved_by(3));
+    }
+
+    #[tokio::test]
+    async fn test_watch_reservation_waits_for_the_release_of_the_active_watch() {
+        let watch_connection = Arc::new(WatchConnection::new(lazy_state()));
+        *watch_connection.reservation.lock().unwrap() = Some(WatchReservation {
+            token: 1,
+            expires_at: Instant::now() + WATCH_EXPIRY,
+        });
+        let waiting = tokio::spawn({
+            let watch_connection = watch_connection.clone();
+            async move { watch_connection.reserve().await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+
+        watch_connection.release(1);
+        match waiting.await.unwrap() {
+            Ok((token, _)) => {
+                assert_ne!(token, 1);
+                assert!(watch_connection.is_reserved_by(token));
+            }
+            // A connection that couldn't be created ends the new reservation.
+            Err(_) => assert!(watch_connection.reservation.lock().unwrap().is_none()),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Samples the keyspace and aggregates the type, TTL and memory usage of the sampled keys,
+//! so wrappers get a keyspace report from a single native call.
+
+use super::{Client, ClientWrapper};
+use redis::{
+    ClusterScanArgs, ErrorKind, Pipeline, PipelineRetryStrategy, RedisError, RedisResult,
+    ScanStateRC, Value,
+};
+use std::collections::BTreeMap;
+
+/// The number of biggest keys included in the report.
+const BIGGEST_KEYS_COUNT: usize = 10;
+/// The number of keys described by a single pipeline.
+const DESCRIBE_BATCH_SIZE: usize = 100;
+const SCAN_COUNT: u32 = 100;
+
+/// The description of a sampled key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyDescription {
+    key: Vec<u8>,
+    key_type: String,
+    // The TTL in milliseconds, or a negative value if the key has no expiry.
+    ttl_ms: i64,
+    memory_usage: i64,
+}
+
+#[derive(Default)]
+struct TypeStatistics {
+    keys_count: i64,
+    memory_usage: i64,
+}
+
+impl Client {
+    /// Samples up to `sample_size` keys that match `pattern` (or all keys, if no pattern is given) by scanning the
+    /// keyspace, and returns aggregated statistics of the sampled keys:
+    ///
+    /// ```text
+    /// sampled_keys => (integer)
+    /// total_memory_usage => (integer) bytes
+    /// keys_with_ttl => (integer)
+    /// types => type => { keys_count => (integer), memory_usage => (integer) }
+    /// biggest_keys => [ { key, type, ttl_ms, memory_usage } ], sorted by memory usage, descending
+    /// ```
+    ///
+    /// In cluster mode the keys are sampled from all the nodes. Keys that were deleted between the scan and their
+    /// description are skipped.
+    pub async fn analyze_keyspace(
+        &mut self,
+        sample_size: usize,
+        pattern: Option<&[u8]>,
+    ) -> RedisResult<Value> {
+        let keys = self.sample_keys(sample_size, pattern).await?;
+        let mut descriptions = Vec::with_capacity(keys.len());
+        for keys in keys.chunks(DESCRIBE_BATCH_SIZE) {
+            descriptions.extend(self.describe_keys(keys).await?);
+        }
+        Ok(keyspace_report(descriptions))
+    }
+
+    async fn sample_keys(
+        &mut self,
+        sample_size: usize,
+        pattern: Option<&[u8]>,
+    ) -> RedisResult<Vec<Vec<u8>>> {
+        let mut keys = Vec::with_capacity(sample_size);
+        match self.get_or_initialize_client().await? {
+            ClientWrapper::Cluster { mut client } => {
+                let mut args = ClusterScanArgs::builder().with_count(SCAN_COUNT);
+                if let Some(pattern) = pattern {
+                    args = args.with_match_pattern(pattern);
+                }
+                let args = args.build();
+                let mut scan_state = ScanStateRC::new();
+                while keys.len() < sample_size {
+                    let (next_scan_state, page) =
+                        client.cluster_scan(scan_state, args.clone()).await?;
+                    keys.extend(page.into_iter().filter_map(key_bytes));
+                    if next_scan_state.is_finished() {
+                        break;
+                    }
+                    scan_state = next_scan_state;
+                }
+            }
+            _ => {
+                let mut cursor = b"0".to_vec();
+                while keys.len() < sample_size {
+                    let mut cmd = redis::cmd("SCAN");
+                    cmd.arg(&cursor).arg("COUNT").arg(SCAN_COUNT);
+                    if let Some(pattern) = pattern {
+                        cmd.arg("MATCH").arg(pattern);
+                    }
+                    let (next_cursor, page) = match self.send_command(&mut cmd, None).await? {
+                        Value::Array(mut response) if response.len() == 2 => {
+                            match (response.pop(), response.pop()) {
+                                (Some(Value::Array(page)), Some(Value::BulkString(cursor))) => {
+                                    (cursor, page)
+                                }
+                                _ => return Err(unexpected_scan_response()),
+                            }
+                        }
+                        _ => return Err(unexpected_scan_response()),
+                    };
+                    keys.extend(page.into_iter().filter_map(key_bytes));
+                    if next_cursor == b"0" {
+                        break;
+                    }
+                    cursor = next_cursor;
+                }
+            }
+        }
+        keys.truncate(sample_size);
+        Ok(keys)
+    }
+
+    async fn describe_keys(&mut self, keys: &[Vec<u8>]) -> RedisResult<Vec<KeyDescription>> {
+        let mut pipeline = Pipeline::with_capacity(keys.len() * 3);
+        for key in keys {
+            pipeline
+                .cmd("TYPE")
+                .arg(key)
+                .cmd("PTTL")
+                .arg(key)
+                .cmd("MEMORY")
+                .arg("USAGE")
+                .arg(key);
+        }
+        let Value::Array(values) = self
+            .send_pipeline(
+                &pipeline,
+                None,
+                false,
+                None,
+                PipelineRetryStrategy::default(),
+            )
+            .await?
+        else {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for keys description",
+            )));
+        };
+
+        Ok(keys
+            .iter()
+            .zip(values.chunks(3))
+            .filter_map(|(key, values)| match values {
+                [key_type, Value::Int(ttl_ms), Value::Int(memory_usage)] => {
+                    let key_type = match key_type {
+                        Value::SimpleString(key_type) => key_type.clone(),
+                        Value::BulkString(key_type) => {
+                            String::from_utf8_lossy(key_type).into_owned()
+                        }
+                        _ => return None,
+                    };
+                    // A key that was deleted after it was scanned has the `none` type.
+                    (key_type != "none").then(|| KeyDescription {
+                        key: key.clone(),
+                        key_type,
+                        ttl_ms: *ttl_ms,
+                        memory_usage: *memory_usage,
+                    })
+                }
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+fn key_bytes(key: Value) -> Option<Vec<u8>> {
+    match key {
+        Value::BulkString(key) => Some(key),
+        Value::SimpleString(key) => Some(key.into_bytes()),
+        _ => None,
+    }
+}
+
+fn unexpected_scan_response() -> RedisError {
+    RedisError::from((ErrorKind::ResponseError, "Unexpected SCAN response"))
+}
+
+fn field(name: &str, value: Value) -> (Value, Value) {
+    (Value::SimpleString(name.to_string()), value)
+}
+
+fn keyspace_report(mut descriptions: Vec<KeyDescription>) -> Value {
+    let mut types: BTreeMap<String, TypeStatistics> = BTreeMap::new();
+    for description in &descriptions {
+        let statistics = types.entry(description.key_type.clone()).or_default();
+        statistics.keys_count += 1;
+        statistics.memory_usage += description.memory_usage;
+    }
+    let total_memory_usage = types.values().map(|stats| stats.memory_usage).sum();
+    let keys_with_ttl = descriptions
+        .iter()
+        .filter(|description| description.ttl_ms >= 0)
+        .count() as i64;
+
+    descriptions.sort_by(|a, b| b.memory_usage.cmp(&a.memory_usage));
+    let sampled_keys = descriptions.len() as i64;
+    let biggest_keys = descriptions
+        .into_iter()
+        .take(BIGGEST_KEYS_COUNT)
+        .map(|description| {
+            Value::Map(vec![
+                field("key", Value::BulkString(description.key)),
+                field("type", Value::SimpleString(description.key_type)),
+                field("ttl_ms", Value::Int(description.ttl_ms)),
+                field("memory_usage", Value::Int(description.memory_usage)),
+            ])
+        })
+        .collect();
+
+    Value::Map(vec![
+        field("sampled_keys", Value::Int(sampled_keys)),
+        field("total_memory_usage", Value::Int(total_memory_usage)),
+        field("keys_with_ttl", Value::Int(keys_with_ttl)),
+        field(
+            "types",
+            Value::Map(
+                types
+                    .into_iter()
+                    .map(|(key_type, stats)| {
+                        (
+                            Value::SimpleString(key_type),
+                            Value::Map(vec![
+                                field("keys_count", Value::Int(stats.keys_count)),
+                                field("memory_usage", Value::Int(stats.memory_usage)),
+                            ]),
+                        )
+                    })
+                    .collect(),
+            ),
+        ),
+        field("biggest_keys", Value::Array(biggest_keys)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn description(key: &str, key_type: &str, ttl_ms: i64, memory_usage: i64) -> KeyDescription {
+        KeyDescription {
+            key: key.as_bytes().to_vec(),
+            key_type: key_type.to_string(),
+            ttl_ms,
+            memory_usage,
+        }
+    }
+
+    #[test]
+    fn test_keyspace_report() {
+        let report = keyspace_report(vec![
+            description("a", "string", -1, 50),
+            description("b", "hash", 1000, 300),
+            description("c", "string", 500, 100),
+        ]);
+        let Value::Map(fields) = report else {
+            panic!("Expected a map, got {report:?}");
+        };
+        assert_eq!(fields[0], field("sampled_keys", Value::Int(3)));
+        assert_eq!(fields[1], field("total_memory_usage", Value::Int(450)));
+        assert_eq!(fields[2], field("keys_with_ttl", Value::Int(2)));
+        assert_eq!(
+            fields[3].1,
+            Value::Map(vec![
+                (
+                    Value::SimpleString("hash".to_string()),
+                    Value::Map(vec![
+                        field("keys_count", Value::Int(1)),
+                        field("memory_usage", Value::Int(300)),
+                    ]),
+                ),
+                (
+                    Value::SimpleString("string".to_string()),
+                    Value::Map(vec![
+                        field("keys_count", Value::Int(2)),
+                        field("memory_usage", Value::Int(150)),
+                    ]),
+                ),
+            ])
+        );
+        let Value::Array(biggest_keys) = &fields[4].1 else {
+            panic!("Expected an array, got {:?}", fields[4].1);
+        };
+        let biggest_key_names: Vec<_> = biggest_keys
+            .iter()
+            .map(|key| match key {
+                Value::Map(key_fields) => key_fields[0].1.clone(),
+                other => panic!("Expected a map, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            biggest_key_names,
+            vec![
+                Value::BulkString(b"b".to_vec()),
+                Value::BulkString(b"c".to_vec()),
+                Value::BulkString(b"a".to_vec()),
+            ]
+        );
+    }
+}
@@ -7,14 +7,17 @@ use crate::compression::CompressionBackendType;
 use crate::compression::lz4_backend::Lz4Backend;
 use crate::compression::zstd_backend::ZstdBackend;
 use crate::compression::{CompressionConfig, CompressionManager};
-use crate::scripts_container::get_script;
+use crate::scripts_container::{add_script, get_script, record_invocation, remove_script};
 use futures::FutureExt;
 use logger_core::{log_debug, log_error, log_info, log_warn};
+use nanoid::nanoid;
 use once_cell::sync::OnceCell;
+use rand::seq::SliceRandom;
 use redis::aio::ConnectionLike;
 use redis::cluster_async::ClusterConnection;
 use redis::cluster_routing::{
-    MultipleNodeRoutingInfo, ResponsePolicy, Routable, RoutingInfo, SingleNodeRoutingInfo,
+    MultipleNodeRoutingInfo, ResponsePolicy, Routable, Route, RoutingInfo, SingleNodeRoutingInfo,
+    SlotAddr,
 };
 use redis::cluster_slotmap::ReadFromReplicaStrategy;
 use redis::{
@@ -22,17 +25,53 @@ use redis::{
     RedisResult, RetryStrategy, ScanStateRC, Value,
 };
 pub use standalone_client::StandaloneClient;
+use standalone_client::StandaloneClientConnectionError;
+use std::collections::HashMap;
 use std::io;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicIsize, Ordering};
 use std::thread;
 use std::thread::JoinHandle;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::runtime::{Builder, Handle};
 pub use types::*;
 
-use self::value_conversion::{convert_to_expected_type, expected_type_for_cmd, get_value_type};
+use self::connection_pool::{
+    BlockingConnections, ConnectionPool, DatabaseConnections, SideConnectionState, WatchConnection,
+};
+pub(crate) use self::value_conversion::get_value_type;
+use self::value_conversion::{convert_to_expected_type, expected_type_for_cmd};
+mod circuit_breaker;
+pub use circuit_breaker::CircuitState;
+use circuit_breaker::ClusterCircuitBreakers;
+mod cluster_failover;
+pub use cluster_failover::{
+    ClusterFailoverMode, ClusterFailoverOptions, ClusterFailoverProgress, ClusterFailoverStage,
+    DEFAULT_FAILOVER_TIMEOUT,
+};
+mod command_coalescer;
+pub use command_coalescer::{
+    CoalescingConfig, CoalescingStatistics, CommandCoalescer, DEFAULT_COALESCING_MAX_BATCH_SIZE,
+    DEFAULT_COALESCING_WINDOW,
+};
+mod connection_pool;
+pub use connection_pool::WATCH_EXPIRY;
+mod keyspace_analyzer;
+mod read_your_writes;
+pub use read_your_writes::ReadYourWrites;
 mod reconnecting_connection;
+mod request_deduplicator;
+mod sentinel;
+pub use sentinel::SentinelTopology;
+mod server_capabilities;
+pub use request_deduplicator::{DeduplicationStatistics, RequestDeduplicator};
+mod slot_migration;
+mod slow_command_log;
+pub use slot_migration::{
+    DEFAULT_MIGRATION_BATCH_SIZE, DEFAULT_MIGRATION_TIMEOUT, SlotMigrationOptions,
+    SlotMigrationProgress,
+};
+pub use slow_command_log::{CommandSignature, SlowCommand, SlowCommandLog};
 mod standalone_client;
 mod value_conversion;
 use crate::pubsub::{PubSubSynchronizer, create_pubsub_synchronizer};
@@ -40,7 +79,7 @@ use crate::request_type::RequestType;
 use redis::InfoDict;
 use std::future::Future;
 use std::pin::Pin;
-use telemetrylib::GlideOpenTelemetry;
+use telemetrylib::{GlideOpenTelemetry, Telemetry};
 use tokio::sync::{Notify, RwLock, mpsc, oneshot};
 use versions::Versioning;
 
@@ -166,6 +205,14 @@ pub async fn get_valkey_connection_info(
     let db = connection_request.database_id;
     let client_name = connection_request.client_name.clone();
     let lib_name = connection_request.lib_name.clone();
+    let client_no_evict = connection_request.client_no_evict;
+    let client_no_touch = connection_request.client_no_touch;
+    // The connections of a client with subscriptions must deliver their messages, so they fail instead of falling back.
+    let resp3_fallback = connection_request.resp3_fallback
+        && connection_request
+            .pubsub_subscriptions
+            .as_ref()
+            .is_none_or(|subscriptions| subscriptions.values().all(|channels| channels.is_empty()));
 
     match &connection_request.authentication_info {
         Some(info) => {
@@ -185,6 +232,9 @@ pub async fn get_valkey_connection_info(
                     protocol,
                     client_name,
                     lib_name,
+                    client_no_evict,
+                    client_no_touch,
+                    resp3_fallback,
                 }
             } else {
                 // Regular password-based authentication
@@ -195,6 +245,9 @@ pub async fn get_valkey_connection_info(
                     protocol,
                     client_name,
                     lib_name,
+                    client_no_evict,
+                    client_no_touch,
+                    resp3_fallback,
                 }
             }
         }
@@ -203,6 +256,9 @@ pub async fn get_valkey_connection_info(
             protocol,
             client_name,
             lib_name,
+            client_no_evict,
+            client_no_touch,
+            resp3_fallback,
             ..Default::default()
         },
     }
@@ -234,6 +290,32 @@ pub(super) fn get_connection_info(
     }
 }
 
+/// Applies the server name and hostname verification settings of the request to the TLS parameters.
+pub(super) fn get_tls_params_with_overrides(
+    request: &ConnectionRequest,
+    tls_params: Option<redis::TlsConnParams>,
+) -> Option<redis::TlsConnParams> {
+    if request.tls_mode.unwrap_or_default() == TlsMode::NoTls {
+        return tls_params;
+    }
+    let mut tls_params = tls_params;
+    if let Some(server_name) = &request.tls_server_name {
+        tls_params = Some(
+            tls_params
+                .unwrap_or_default()
+                .with_server_name(server_name.clone()),
+        );
+    }
+    if request.tls_skip_hostname_verification {
+        tls_params = Some(
+            tls_params
+                .unwrap_or_default()
+                .without_hostname_verification(),
+        );
+    }
+    tls_params
+}
+
 #[derive(Clone)]
 pub enum ClientWrapper {
     Standalone(StandaloneClient),
@@ -254,12 +336,39 @@ pub struct Client {
     request_timeout: Duration,
     // Setting this counter to limit the inflight requests, in case of any queue is blocked, so we return error to the customer.
     inflight_requests_allowed: Arc<AtomicIsize>,
+    // The inflight request slots that only high-priority requests may take, when `inflight_requests_high_watermark` is configured.
+    high_priority_reserved_requests: isize,
     // IAM token manager for automatic credential refresh
     iam_token_manager: Option<Arc<crate::iam::IAMTokenManager>>,
     // Optional compression manager for automatic compression/decompression
     compression_manager: Option<Arc<CompressionManager>>,
     pubsub_synchronizer: Arc<dyn PubSubSynchronizer>,
     otel_metadata: types::OTelMetadata,
+    // Additional connections used to spread the commands, when `connection_pool_size` is configured.
+    connection_pool: Option<Arc<ConnectionPool>>,
+    // The request the side connections are created with, following the state of the main connection.
+    side_connection_state: Arc<SideConnectionState>,
+    // Side connections used by `send_blocking_command`, one per blocking command in flight, created on first use.
+    blocking_connections: Arc<BlockingConnections>,
+    // Side connection used by `watch` and the watched transactions, created on first use.
+    watch_connection: Arc<WatchConnection>,
+    // Side connections used by `send_command_to_database`, one per database, created on first use.
+    database_connections: Arc<DatabaseConnections>,
+    // Retry reads that failed on a stale replica on the primary. Standalone clients handle this internally.
+    retry_reads_on_primary: bool,
+    // The nodes that served the latest read commands of a cluster client.
+    read_routing_stats: Arc<redis::cluster_async::ReadRoutingStats>,
+    redirect_stats: Arc<redis::cluster_async::RedirectStats>,
+    // The circuit breakers of the nodes of a cluster client, when `circuit_breaker` is configured.
+    cluster_circuit_breakers: Option<Arc<ClusterCircuitBreakers>>,
+    // The configuration the client was created with, reported by `effective_config`.
+    config: Arc<ConnectionRequest>,
+    // The capabilities of the nodes, recorded after connecting and reported by `server_capabilities`.
+    server_capabilities: Arc<std::sync::Mutex<Option<Value>>>,
+    // The denied acquisitions of `rate_limit_acquire` that are cached locally.
+    rate_limit_deny_cache: Arc<crate::rate_limiter::DenyCache>,
+    // The locks acquired with `acquire_lock` that are extended automatically.
+    lock_extensions: Arc<crate::distributed_lock::LockExtensions>,
 }
 
 async fn run_with_timeout<T>(
@@ -284,9 +393,23 @@ async fn run_with_timeout<T>(
     }
 }
 
+/// Converts the deadline of a request, given by the wrappers in milliseconds since the Unix epoch, into an [`Instant`]
+/// to set with [`Cmd::set_deadline`]. A deadline that already passed is converted into the current time.
+pub fn deadline_from_unix_millis(deadline_unix_ms: u64) -> Instant {
+    let deadline = SystemTime::UNIX_EPOCH + Duration::from_millis(deadline_unix_ms);
+    let now = Instant::now();
+    deadline
+        .duration_since(SystemTime::now())
+        .map_or(now, |remaining| now + remaining)
+}
+
 /// Extension to the request timeout for blocking commands to ensure we won't return with timeout error before the server responded
 const BLOCKING_CMD_TIMEOUT_EXTENSION: f64 = 0.5; // seconds
 
+/// Expiry of the temporary keys of [`Client::pfmerge_across_slots`], so they are removed even if the client doesn't
+/// get to delete them.
+const PFMERGE_TEMPORARY_KEY_TTL_MS: u64 = 60_000;
+
 enum TimeUnit {
     Milliseconds = 1000,
     Seconds = 1,
@@ -366,6 +489,269 @@ fn get_timeout_from_cmd_arg(
     }
 }
 
+fn restore_cmd(key: &[u8], ttl: u64, serialized_value: &[u8], replace: bool, absttl: bool) -> Cmd {
+    let mut cmd = redis::cmd("RESTORE");
+    cmd.arg(key).arg(ttl).arg(serialized_value);
+    if replace {
+        cmd.arg("REPLACE");
+    }
+    if absttl {
+        cmd.arg("ABSTTL");
+    }
+    cmd
+}
+
+/// Returns `suffix` tagged with the part of `key` that is hashed, so it hashes to the slot of `key`, or `None` if that
+/// part can't be used as a hash tag.
+fn key_in_slot_of(key: &[u8], suffix: &str) -> Option<Vec<u8>> {
+    let hashed = key
+        .iter()
+        .position(|byte| *byte == b'{')
+        .and_then(|open| {
+            let tag = &key[open + 1..];
+            tag.iter()
+                .position(|byte| *byte == b'}')
+                .filter(|close| *close > 0)
+                .map(|close| &tag[..close])
+        })
+        .unwrap_or(key);
+    let mut tagged = Vec::with_capacity(hashed.len() + suffix.len() + 2);
+    tagged.push(b'{');
+    tagged.extend_from_slice(hashed);
+    tagged.push(b'}');
+    tagged.extend_from_slice(suffix.as_bytes());
+    (redis::cluster_topology::get_slot(&tagged) == redis::cluster_topology::get_slot(key))
+        .then_some(tagged)
+}
+
+fn failover_cmd(options: &FailoverOptions) -> RedisResult<Cmd> {
+    let invalid_options = |detail: &str| {
+        RedisError::from((
+            ErrorKind::ClientError,
+            "Invalid failover options",
+            detail.to_string(),
+        ))
+    };
+    if options.abort && (options.target.is_some() || options.force || options.timeout_ms.is_some())
+    {
+        return Err(invalid_options(
+            "ABORT can't be combined with other options",
+        ));
+    }
+    if options.force && (options.target.is_none() || options.timeout_ms.is_none()) {
+        return Err(invalid_options("FORCE requires a target and a timeout"));
+    }
+
+    let mut cmd = redis::cmd("FAILOVER");
+    if let Some((host, port)) = &options.target {
+        cmd.arg("TO").arg(host).arg(port);
+        if options.force {
+            cmd.arg("FORCE");
+        }
+    }
+    if options.abort {
+        cmd.arg("ABORT");
+    }
+    if let Some(timeout_ms) = options.timeout_ms {
+        cmd.arg("TIMEOUT").arg(timeout_ms);
+    }
+    Ok(cmd)
+}
+
+/// Discovers the addresses of the cluster nodes selected by `routing`: the primaries for
+/// [`MultipleNodeRoutingInfo::AllMasters`], and every node for the other routings.
+/// The addresses are taken from the slot map of the connection, so a node that is unreachable is still listed,
+/// and the callers report its failure next to the results of the other nodes.
+async fn discover_cluster_nodes(
+    client: &mut ClusterConnection,
+    routing: MultipleNodeRoutingInfo,
+) -> RedisResult<Vec<String>> {
+    client
+        .node_addresses(matches!(routing, MultipleNodeRoutingInfo::AllMasters))
+        .await
+}
+
+/// Returns the routing that sends a command to the node with the given `host:port` address.
+fn routing_for_address(address: &str) -> RedisResult<RoutingInfo> {
+    let Some((host, port)) = address
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+    else {
+        return Err(RedisError::from((
+            ErrorKind::ClientError,
+            "Failed to parse node address",
+            address.to_string(),
+        )));
+    };
+    Ok(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+        host: host.to_string(),
+        port,
+    }))
+}
+
+/// Builds the report returned by [`Client::flush_cluster`] from the result of each node.
+fn flush_report(results: Vec<(String, RedisResult<Value>)>) -> Value {
+    let mut succeeded = Vec::new();
+    let mut failed = Vec::new();
+    for (address, result) in results {
+        let address = Value::BulkString(address.into_bytes());
+        match result {
+            Ok(_) => succeeded.push(address),
+            Err(err) => failed.push((address, Value::SimpleString(err.to_string()))),
+        }
+    }
+    Value::Map(vec![
+        (
+            Value::SimpleString("succeeded".to_string()),
+            Value::Array(succeeded),
+        ),
+        (
+            Value::SimpleString("failed".to_string()),
+            Value::Map(failed),
+        ),
+    ])
+}
+
+/// Builds the report returned by [`Client::config_get_all_nodes`] and [`Client::config_set_all_nodes`] from the
+/// `CONFIG GET` result of each node.
+///
+/// The report is a map of:
+/// * `values` to a map of the addresses of the nodes that responded to their parameters, as a map sorted by name.
+/// * `disagreeing` to an array of the addresses of the nodes whose parameters differ from the ones of most nodes.
+/// * `failed` to a map of the addresses of the nodes that failed to the error message.
+fn config_report(results: Vec<(String, RedisResult<Value>)>) -> Value {
+    consistency_report(results, sorted_config)
+}
+
+/// Builds a report of the result of each node, converted by `convert`, in the form described in [`config_report`].
+fn consistency_report(
+    mut results: Vec<(String, RedisResult<Value>)>,
+    convert: fn(Value) -> RedisResult<Value>,
+) -> Value {
+    results.sort_by(|(address, _), (other, _)| address.cmp(other));
+    let mut values = Vec::new();
+    let mut failed = Vec::new();
+    for (address, result) in results {
+        match result.and_then(convert) {
+            Ok(config) => values.push((address, config)),
+            Err(err) => failed.push((
+                Value::BulkString(address.into_bytes()),
+                Value::SimpleString(err.to_string()),
+            )),
+        }
+    }
+    // The parameters of most nodes are the expected ones. On a tie, the ones of the first of these nodes are.
+    let expected = values
+        .iter()
+        .map(|(_, config)| config)
+        .rev()
+        .max_by_key(|config| values.iter().filter(|(_, other)| other == *config).count())
+        .cloned();
+    let disagreeing = values
+        .iter()
+        .filter(|(_, config)| Some(config) != expected.as_ref())
+        .map(|(address, _)| Value::BulkString(address.clone().into_bytes()))
+        .collect();
+    Value::Map(vec![
+        (
+            Value::SimpleString("values".to_string()),
+            Value::Map(
+                values
+                    .into_iter()
+                    .map(|(address, config)| (Value::BulkString(address.into_bytes()), config))
+                    .collect(),
+            ),
+        ),
+        (
+            Value::SimpleString("disagreeing".to_string()),
+            Value::Array(disagreeing),
+        ),
+        (
+            Value::SimpleString("failed".to_string()),
+            Value::Map(failed),
+        ),
+    ])
+}
+
+/// Replaces the read-back result of each node that failed to set, with the error of setting it.
+fn with_set_failures(
+    set_results: Vec<(String, RedisResult<Value>)>,
+    mut get_results: Vec<(String, RedisResult<Value>)>,
+) -> Vec<(String, RedisResult<Value>)> {
+    for (address, result) in set_results {
+        if let Err(err) = result {
+            match get_results.iter_mut().find(|(other, _)| *other == address) {
+                Some((_, get_result)) => *get_result = Err(err),
+                None => get_results.push((address, Err(err))),
+            }
+        }
+    }
+    get_results
+}
+
+/// Converts a `CONFIG GET` response, either a RESP3 map or a RESP2 array of names and values, to a map sorted by name.
+fn sorted_config(response: Value) -> RedisResult<Value> {
+    let mut pairs = match response {
+        Value::Map(pairs) => pairs,
+        Value::Array(values) if values.len() % 2 == 0 => {
+            let mut values = values.into_iter();
+            std::iter::from_fn(|| Some((values.next()?, values.next()?))).collect()
+        }
+        response => {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for CONFIG GET",
+                format!("(response was {response:?})"),
+            )));
+        }
+    };
+    pairs.sort_by_key(|(name, _)| match name {
+        Value::BulkString(name) => name.clone(),
+        Value::SimpleString(name) => name.clone().into_bytes(),
+        _ => Vec::new(),
+    });
+    Ok(Value::Map(pairs))
+}
+
+/// Returns true if the command is one of the multi-key commands whose keys may span several slots.
+/// This only checks the command name: it doesn't split the command. The split by slot is done by the
+/// cluster connection, which routes such a command with [`MultipleNodeRoutingInfo::MultiSlot`] when it's
+/// sent without explicit routing.
+pub fn is_multi_key_split_command(cmd: &Cmd) -> bool {
+    let command = cmd.command().unwrap_or_default();
+    matches!(
+        command.as_slice(),
+        b"MGET" | b"MSET" | b"DEL" | b"UNLINK" | b"EXISTS" | b"TOUCH"
+    )
+}
+
+/// Returns true if the error means that the node can't serve reads yet,
+/// such as a replica that is loading its dataset or that lost the link to its primary.
+pub(super) fn is_stale_read_error(err: &RedisError) -> bool {
+    matches!(
+        err.kind(),
+        ErrorKind::BusyLoadingError | ErrorKind::MasterDown
+    )
+}
+
+/// Returns the routing that sends a read that may have been served by a replica to the primary instead,
+/// or `None` if the read was already routed to a primary.
+fn primary_routing_for_read(routing: &RoutingInfo) -> Option<RoutingInfo> {
+    match routing {
+        RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))
+            if route.slot_addr() != SlotAddr::Master =>
+        {
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::SpecificNode(Route::new(route.slot(), SlotAddr::Master)),
+            ))
+        }
+        RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random) => Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::RandomPrimary,
+        )),
+        _ => None,
+    }
+}
+
 fn get_request_timeout(cmd: &Cmd, default_timeout: Duration) -> RedisResult<Option<Duration>> {
     let command = cmd.command().unwrap_or_default();
     let timeout = match command.as_slice() {
@@ -391,6 +777,18 @@ fn get_request_timeout(cmd: &Cmd, default_timeout: Duration) -> RedisResult<Opti
 }
 
 impl Client {
+    /// Returns true if the command can be sent as part of a pipeline without losing the handling that
+    /// [`Client::send_command`] applies to each command on its own: the retry of stale reads on the primary,
+    /// the `JSON.GET` fallback, and the routing and the `WAIT` of a read-your-writes session.
+    pub(crate) fn can_send_in_pipeline(&self, cmd: &Cmd) -> bool {
+        if self.config.read_your_writes.is_some() {
+            return false;
+        }
+        let command = cmd.command().unwrap_or_default();
+        !(self.retry_reads_on_primary && redis::cluster_routing::is_readonly_cmd(&command))
+            && !(self.config.json_path_fallback && command.eq_ignore_ascii_case(b"JSON.GET"))
+    }
+
     /// Checks if the given command is a SELECT command.
     /// Returns true if the command is "SELECT", false otherwise.
     /// Handles cases where command() returns None gracefully.
@@ -444,6 +842,32 @@ impl Client {
         Ok(())
     }
 
+    /// Returns the connections, other than the main connection, that keep the state they were created in: the members
+    /// of the connection pool, the watch connection once it's connected, and the connections of the databases used
+    /// with [`Client::send_command_to_database`] if `with_database_connections` is set. Unlike the other side
+    /// connections, they can't be replaced when the state of the main connection changes, so the change is applied to
+    /// them in place.
+    fn stateful_connections(&self, with_database_connections: bool) -> Vec<Client> {
+        let mut connections: Vec<Client> = self
+            .connection_pool
+            .iter()
+            .flat_map(|pool| pool.members())
+            .collect();
+        connections.extend(self.watch_connection.connected_client());
+        if with_database_connections {
+            connections.extend(self.database_connections.connected_clients());
+        }
+        connections
+    }
+
+    /// Applies a change of the state of the main connection to the side connections. The side connections created
+    /// from now on start in the new state, and the idle blocking connections, created in the previous state, are dropped.
+    fn update_side_connections(&self, update: impl FnOnce(&mut ConnectionRequest)) {
+        self.side_connection_state.update(update);
+        self.blocking_connections.drop_idle();
+        self.database_connections.drop_idle();
+    }
+
     /// Updates the stored database ID for different client types.
     /// Handles standalone, cluster, and lazy clients appropriately.
     /// Ensures thread-safe updates using existing synchronization mechanisms.
@@ -452,17 +876,17 @@ impl Client {
         match &mut *guard {
             ClientWrapper::Standalone(client) => {
                 client.update_connection_database(database_id).await?;
-                Ok(())
             }
             ClientWrapper::Cluster { client } => {
                 // Update cluster connection database configuration
                 client.update_connection_database(database_id).await?;
-                Ok(())
             }
             ClientWrapper::Lazy(_) => {
                 unreachable!("Lazy client should have been initialized")
             }
         }
+        self.update_side_connections(|request| request.database_id = database_id);
+        Ok(())
     }
 
     /// Checks if the given command is a CLIENT SETNAME command.
@@ -504,18 +928,22 @@ impl Client {
         let mut guard = self.internal_client.write().await;
         match &mut *guard {
             ClientWrapper::Standalone(client) => {
-                client.update_connection_client_name(client_name).await?;
-                Ok(())
+                client
+                    .update_connection_client_name(client_name.clone())
+                    .await?;
             }
             ClientWrapper::Cluster { client } => {
                 // Update cluster connection database configuration
-                client.update_connection_client_name(client_name).await?;
-                Ok(())
+                client
+                    .update_connection_client_name(client_name.clone())
+                    .await?;
             }
             ClientWrapper::Lazy(_) => {
                 unreachable!("Lazy client should have been initialized")
             }
         }
+        self.update_side_connections(|request| request.client_name = client_name);
+        Ok(())
     }
 
     /// Checks if the given command is an AUTH command.
@@ -574,17 +1002,22 @@ impl Client {
         let mut guard = self.internal_client.write().await;
         match &mut *guard {
             ClientWrapper::Standalone(client) => {
-                client.update_connection_username(username).await?;
-                Ok(())
+                client.update_connection_username(username.clone()).await?;
             }
             ClientWrapper::Cluster { client } => {
-                client.update_connection_username(username).await?;
-                Ok(())
+                client.update_connection_username(username.clone()).await?;
             }
             ClientWrapper::Lazy(_) => {
                 unreachable!("Lazy client should have been initialized")
             }
         }
+        self.update_side_connections(|request| {
+            request
+                .authentication_info
+                .get_or_insert_with(Default::default)
+                .username = username
+        });
+        Ok(())
     }
 
     /// Updates the stored password for different client types.
@@ -592,17 +1025,22 @@ impl Client {
         let mut guard = self.internal_client.write().await;
         match &mut *guard {
             ClientWrapper::Standalone(client) => {
-                client.update_connection_password(password).await?;
-                Ok(())
+                client.update_connection_password(password.clone()).await?;
             }
             ClientWrapper::Cluster { client } => {
-                client.update_connection_password(password).await?;
-                Ok(())
+                client.update_connection_password(password.clone()).await?;
             }
             ClientWrapper::Lazy(_) => {
                 unreachable!("Lazy client should have been initialized")
             }
         }
+        self.update_side_connections(|request| {
+            request
+                .authentication_info
+                .get_or_insert_with(Default::default)
+                .password = password
+        });
+        Ok(())
     }
 
     /// Checks if the given command is a HELLO command.
@@ -709,16 +1147,16 @@ impl Client {
         match &mut *guard {
             ClientWrapper::Standalone(client) => {
                 client.update_connection_protocol(protocol).await?;
-                Ok(())
             }
             ClientWrapper::Cluster { client } => {
                 client.update_connection_protocol(protocol).await?;
-                Ok(())
             }
             ClientWrapper::Lazy(_) => {
                 unreachable!("Lazy client should have been initialized")
             }
         }
+        self.update_side_connections(|request| request.protocol = Some(protocol));
+        Ok(())
     }
 
     async fn get_or_initialize_client(&self) -> RedisResult<ClientWrapper> {
@@ -757,6 +1195,7 @@ impl Client {
                     push_sender,
                     iam_manager_ref,
                     self.pubsub_synchronizer.clone(),
+                    self.cluster_circuit_breakers.clone(),
                 )
                 .await?;
                 ClientWrapper::Cluster { client }
@@ -803,6 +1242,23 @@ impl Client {
         Ok(guard.clone()) // ✅ Return clone of the now-initialized wrapper
     }
 
+    /// Evaluates a `JSON.GET` rejected by a server without the JSON module on the client, on the value of its key.
+    async fn send_json_get_fallback(
+        &mut self,
+        cmd: &Cmd,
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        let request = crate::json_path::JsonGetRequest::parse(cmd)?;
+        log_debug(
+            "send_command",
+            "The server doesn't have the JSON module, evaluating JSON.GET on the client",
+        );
+        let mut get = redis::cmd("GET");
+        get.arg(&request.key);
+        let value = self.send_command(&mut get, routing).await?;
+        request.evaluate(value)
+    }
+
     /// Send a command to the server.
     /// This function will route the command to the correct node, and retry if needed.
     pub fn send_command<'a>(
@@ -839,11 +1295,21 @@ impl Client {
             }
 
             let client = self.get_or_initialize_client().await?;
+            // The command may have waited in the queues of the wrapper, or for a lazy client to connect.
+            cmd.check_deadline()?;
 
             if let Some(result) = self.pubsub_synchronizer.intercept_pubsub_command(cmd).await {
                 return result;
             }
 
+            if let Some(pool) = &self.connection_pool
+                && let Some(lease) = pool.checkout(cmd)
+            {
+                // The lease is held until the command completes, so a blocking command keeps its member pinned.
+                let mut member = lease.client();
+                return member.send_command(cmd, routing).await;
+            }
+
             // let expected_type = expected_type_for_cmd(cmd);
             let request_timeout = match get_request_timeout(cmd, self.request_timeout) {
                 Ok(request_timeout) => request_timeout,
@@ -852,10 +1318,23 @@ impl Client {
 
             // Clone compression_manager reference before moving into async block
             let compression_manager = self.compression_manager.clone();
+            let retry_reads_on_primary = self.retry_reads_on_primary;
+            let json_get_fallback_routing = self.config.json_path_fallback.then(|| routing.clone());
+            let read_your_writes = self.config.read_your_writes.clone();
+            let written_slots = read_your_writes
+                .as_ref()
+                .map(|_| ReadYourWrites::written_slots(cmd, routing.as_ref()))
+                .unwrap_or_default();
+            let read_on_primary = read_your_writes
+                .as_ref()
+                .is_some_and(|read_your_writes| read_your_writes.requires_primary(cmd));
 
             let result = run_with_timeout(request_timeout, async move {
                 let expected_type = expected_type_for_cmd(cmd);
                 let value  = match client {
+                    ClientWrapper::Standalone(mut client) if read_on_primary => {
+                        client.send_command_to_primary(cmd).await
+                    }
                     ClientWrapper::Standalone(mut client) => client.send_command(cmd).await,
                     ClientWrapper::Cluster {mut client } => {
                         let final_routing =
@@ -883,7 +1362,26 @@ impl Client {
                                     .or_else(|| RoutingInfo::for_routable(cmd))
                                     .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
                             };
-                        client.route_command(cmd, final_routing).await
+                        // The replicas may not have the latest write to the slot of a read-your-writes session.
+                        let final_routing = if read_on_primary {
+                            primary_routing_for_read(&final_routing).unwrap_or(final_routing)
+                        } else {
+                            final_routing
+                        };
+                        let primary_routing = retry_reads_on_primary
+                            .then(|| primary_routing_for_read(&final_routing))
+                            .flatten();
+                        match client.route_command(cmd, final_routing).await {
+                            Err(err) if is_stale_read_error(&err) && primary_routing.is_some() => {
+                                log_debug(
+                                    "send_command",
+                                    format!("Retrying read on the primary after replica error `{err}`"),
+                                );
+                                Telemetry::incr_primary_fallback_reads();
+                                client.route_command(cmd, primary_routing.unwrap()).await
+                            }
+                            result => result,
+                        }
                     },
                     ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
                 }
@@ -937,12 +1435,163 @@ impl Client {
                 }
                 Ok(value)
             })
-            .await?;
+            .await;
+            let result = match result {
+                Err(err)
+                    if json_get_fallback_routing.is_some()
+                        && crate::json_path::is_json_get_unsupported(cmd, &err) =>
+                {
+                    return self
+                        .send_json_get_fallback(cmd, json_get_fallback_routing.flatten())
+                        .await;
+                }
+                result => result?,
+            };
+
+            if let Some(read_your_writes) = &read_your_writes {
+                self.wait_for_replicas(read_your_writes, &written_slots)
+                    .await;
+            }
+
+            // Replay commands that change the connection state on the other stateful connections, so all connections
+            // stay consistent. The connections of the other databases keep their database on `SELECT`. Every
+            // connection is updated even if another one fails, and the first failure is returned.
+            if connection_pool::is_connection_state_command(cmd) {
+                let with_database_connections = !self.is_select_command(cmd);
+                let mut replayed = Ok(());
+                for mut connection in self.stateful_connections(with_database_connections) {
+                    if let Err(err) = connection.send_command(cmd, None).await {
+                        log_warn(
+                            "send_command",
+                            format!(
+                                "Failed to apply the connection state to a side connection: {err}"
+                            ),
+                        );
+                        replayed = replayed.and(Err(err));
+                    }
+                }
+                replayed?;
+            }
 
             Ok(result)
         })
     }
 
+    /// Send a blocking command (such as BLPOP, BRPOP, BLMOVE or XREAD with BLOCK) to the server.
+    /// Unlike [`Client::send_command`], the command is sent through a connection of its own, so it doesn't delay
+    /// the commands multiplexed on the main connection, or the other blocking commands, while it waits on the server.
+    /// In cluster mode, the connection is made to the node of the command only: the node given by `routing`, or the
+    /// primary that serves the slot of its keys.
+    /// The request times out on the client side after the timeout given in the command, extended by a short grace period.
+    /// If a connection pool is configured, a free pool member is pinned to the command instead.
+    /// Commands that aren't blocking are sent through the regular path.
+    pub async fn send_blocking_command(
+        &mut self,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        if !connection_pool::is_blocking_command(cmd) {
+            return self.send_command(cmd, routing).await;
+        }
+
+        if let Some(lease) = self
+            .connection_pool
+            .as_ref()
+            .and_then(|pool| pool.checkout(cmd))
+        {
+            let mut member = lease.client();
+            return member.send_command(cmd, routing).await;
+        }
+
+        let node = self.blocking_command_node(cmd, routing.as_ref()).await?;
+        self.blocking_connections
+            .send_command(cmd, routing, node)
+            .await
+    }
+
+    /// Returns the address of the cluster node a blocking command is sent to: the node given by `routing`, the primary
+    /// that serves the slot of its keys, or a random primary for a keyless command. Returns `None` for standalone
+    /// clients, whose blocking connections connect to all their nodes.
+    async fn blocking_command_node(
+        &self,
+        cmd: &Cmd,
+        routing: Option<&RoutingInfo>,
+    ) -> RedisResult<Option<String>> {
+        let ClientWrapper::Cluster { mut client } = self.get_or_initialize_client().await? else {
+            return Ok(None);
+        };
+        let slot = match routing.cloned().or_else(|| RoutingInfo::for_routable(cmd)) {
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress { host, port })) => {
+                return Ok(Some(format!("{host}:{port}")));
+            }
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))) => {
+                Some(route.slot())
+            }
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ReplicaInAZ { slot, .. })) => {
+                Some(slot)
+            }
+            Some(RoutingInfo::MultiNode(_)) => {
+                return Err(RedisError::from((
+                    ErrorKind::ClientError,
+                    "Blocking commands can't be sent to multiple nodes",
+                )));
+            }
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::Random | SingleNodeRoutingInfo::RandomPrimary,
+            ))
+            | None => None,
+        };
+        let address = match slot {
+            Some(slot) => match client.get_address_for_slot(slot).await? {
+                Value::Nil => None,
+                address => Some(String::from_owned_redis_value(address)?),
+            },
+            None => {
+                let primaries = client.node_addresses(true).await?;
+                primaries.choose(&mut rand::thread_rng()).cloned()
+            }
+        };
+        address.map(Some).ok_or_else(|| {
+            RedisError::from((
+                ErrorKind::ConnectionNotFoundForRoute,
+                "No node serves the blocking command",
+            ))
+        })
+    }
+
+    /// Sends a command to the logical database `database_id` instead of the client's database, without changing the
+    /// database of the client. The command is sent through a side connection that selected the database while
+    /// connecting, so the commands multiplexed on the main connection can't run against the other database.
+    /// Blocking commands are sent through a connection of their own, as with [`Client::send_blocking_command`].
+    /// Standalone clients only.
+    pub async fn send_command_to_database(
+        &mut self,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+        database_id: i64,
+    ) -> RedisResult<Value> {
+        if matches!(
+            self.get_or_initialize_client().await?,
+            ClientWrapper::Cluster { .. }
+        ) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Database override is only supported by standalone clients",
+            )));
+        }
+        // The side connection of a database must stay on its database, and in the client's connection state.
+        if connection_pool::is_pinned_to_main_connection(cmd) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Command can't be sent with a database override",
+                String::from_utf8_lossy(&cmd.command().unwrap_or_default()).into_owned(),
+            )));
+        }
+        self.database_connections
+            .send_command(cmd, routing, database_id)
+            .await
+    }
+
     // Cluster scan is not passed to redis-rs as a regular command, so we need to handle it separately.
     // We send the command to a specific function in the redis-rs cluster client, which internally handles the
     // the complication of a command scan, and generate the command base on the logic in the redis-rs library.
@@ -960,6 +1609,28 @@ impl Client {
         &'a mut self,
         scan_state_cursor: &'a ScanStateRC,
         cluster_scan_args: ClusterScanArgs,
+    ) -> RedisResult<Value> {
+        self.cluster_scan_internal(scan_state_cursor, cluster_scan_args, false)
+            .await
+    }
+
+    /// Same as [`Client::cluster_scan`], with a third element in the response: a progress token that can be persisted
+    /// and passed to [`ScanStateRC::from_progress`] (after parsing it with [`redis::ScanProgress::from_token`])
+    /// in order to resume the scan, even from another process. The token is `Nil` once the scan is finished.
+    pub async fn cluster_scan_with_progress<'a>(
+        &'a mut self,
+        scan_state_cursor: &'a ScanStateRC,
+        cluster_scan_args: ClusterScanArgs,
+    ) -> RedisResult<Value> {
+        self.cluster_scan_internal(scan_state_cursor, cluster_scan_args, true)
+            .await
+    }
+
+    async fn cluster_scan_internal<'a>(
+        &'a mut self,
+        scan_state_cursor: &'a ScanStateRC,
+        cluster_scan_args: ClusterScanArgs,
+        with_progress: bool,
     ) -> RedisResult<Value> {
         // Clone arguments before the async block (ScanStateRC is Arc, clone is cheap)
         let scan_state_cursor_clone = scan_state_cursor.clone();
@@ -976,12 +1647,20 @@ impl Client {
                 let (cursor, keys) = client
                     .cluster_scan(scan_state_cursor_clone, cluster_scan_args_clone) // Use clones
                     .await?;
+                let progress = cursor.progress();
                 let cluster_cursor_id = if cursor.is_finished() {
                     Value::BulkString(FINISHED_SCAN_CURSOR.into()) // Use constant
                 } else {
                     Value::BulkString(insert_cluster_scan_cursor(cursor).into())
                 };
-                Ok(Value::Array(vec![cluster_cursor_id, Value::Array(keys)]))
+                let mut response = vec![cluster_cursor_id, Value::Array(keys)];
+                if with_progress {
+                    response.push(match progress {
+                        Some(progress) => Value::BulkString(progress.to_token().into()),
+                        None => Value::Nil,
+                    });
+                }
+                Ok(Value::Array(response))
             }
             // Lazy case is now handled by the initial check
             ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
@@ -1081,8 +1760,13 @@ impl Client {
             // After these initial responses (OK and QUEUED), we expect a single response,
             // which is an array containing the results of all the commands in the pipeline.
             let offset = command_count + 1;
+            let read_your_writes = self.config.read_your_writes.clone();
+            let written_slots = read_your_writes
+                .as_ref()
+                .map(|_| ReadYourWrites::batch_written_slots(pipeline, routing.as_ref()))
+                .unwrap_or_default();
 
-            run_with_timeout(
+            let result = run_with_timeout(
                 Some(to_duration(transaction_timeout, self.request_timeout)),
                 async move {
                     match client {
@@ -1123,7 +1807,13 @@ impl Client {
                     }
                 },
             )
-            .await
+            .await?;
+
+            if let Some(read_your_writes) = &read_your_writes {
+                self.wait_for_replicas(read_your_writes, &written_slots)
+                    .await;
+            }
+            Ok(result)
         })
     }
 
@@ -1159,7 +1849,13 @@ impl Client {
                 )));
             }
 
-            run_with_timeout(
+            let read_your_writes = self.config.read_your_writes.clone();
+            let written_slots = read_your_writes
+                .as_ref()
+                .map(|_| ReadYourWrites::batch_written_slots(pipeline, routing.as_ref()))
+                .unwrap_or_default();
+
+            let result = run_with_timeout(
                 Some(to_duration(pipeline_timeout, self.request_timeout)),
                 async move {
                     let values = match client {
@@ -1203,7 +1899,13 @@ impl Client {
                     )
                 },
             )
-            .await
+            .await?;
+
+            if let Some(read_your_writes) = &read_your_writes {
+                self.wait_for_replicas(read_your_writes, &written_slots)
+                    .await;
+            }
+            Ok(result)
         })
     }
 
@@ -1213,10 +1915,36 @@ impl Client {
         keys: &Vec<&[u8]>,
         args: &Vec<&[u8]>,
         routing: Option<RoutingInfo>,
+    ) -> redis::RedisResult<Value> {
+        self.invoke_script_with_mode(hash, keys, args, routing, false)
+            .await
+    }
+
+    /// Invokes a read-only script with `EVALSHA_RO`.
+    /// Without explicit routing, the script may be served by a replica, according to the client's `ReadFrom` strategy.
+    /// The script must not modify the dataset, otherwise the server rejects it.
+    pub async fn invoke_script_ro<'a>(
+        &'a mut self,
+        hash: &'a str,
+        keys: &Vec<&[u8]>,
+        args: &Vec<&[u8]>,
+        routing: Option<RoutingInfo>,
+    ) -> redis::RedisResult<Value> {
+        self.invoke_script_with_mode(hash, keys, args, routing, true)
+            .await
+    }
+
+    async fn invoke_script_with_mode<'a>(
+        &'a mut self,
+        hash: &'a str,
+        keys: &Vec<&[u8]>,
+        args: &Vec<&[u8]>,
+        routing: Option<RoutingInfo>,
+        read_only: bool,
     ) -> redis::RedisResult<Value> {
         let _ = self.get_or_initialize_client().await?;
 
-        let mut eval = eval_cmd(hash, keys, args);
+        let mut eval = eval_cmd(hash, keys, args, read_only);
         let result = self.send_command(&mut eval, routing.clone()).await;
         let Err(err) = result else {
             return result;
@@ -1233,34 +1961,244 @@ impl Client {
         }
     }
 
-    pub fn reserve_inflight_request(&self) -> bool {
-        // We use this approach of checking the `inflight_requests_allowed` value
-        // twice, before and after decrementing, to prevent it from reaching negative
-        // values. Allowing the `inflight_requests_allowed` value to go below zero
-        // could lead to a race condition where tasks might not be able to run even
-        // when there are available slots.
-        if self.inflight_requests_allowed.load(Ordering::SeqCst) <= 0 {
-            false
-        } else {
-            // The value is being checked again because it might have changed
-            // during the intervening period since the load by other tasks.
-            if self
-                .inflight_requests_allowed
-                .fetch_sub(1, Ordering::SeqCst)
-                <= 0
-            {
-                self.inflight_requests_allowed
-                    .fetch_add(1, Ordering::SeqCst);
-                return false;
+    /// Invokes a script given by its source with `EVALSHA`, falling back to `EVAL` when the script isn't cached on
+    /// the server, which also caches it there.
+    ///
+    /// The script is held in the scripts container until the call completes, and each invocation is recorded in the
+    /// statistics of the script, see [`crate::scripts_container::script_statistics`].
+    pub async fn invoke_script_inline(
+        &mut self,
+        source: &[u8],
+        keys: &Vec<&[u8]>,
+        args: &Vec<&[u8]>,
+        routing: Option<RoutingInfo>,
+    ) -> redis::RedisResult<Value> {
+        let _ = self.get_or_initialize_client().await?;
+
+        let hash = add_script(source);
+        let start = Instant::now();
+        let mut evalsha = eval_cmd(&hash, keys, args, false);
+        let mut result = self.send_command(&mut evalsha, routing.clone()).await;
+        let eval_fallback = matches!(&result, Err(err) if err.kind() == ErrorKind::NoScriptError);
+        if eval_fallback {
+            let mut eval = redis::cmd("EVAL");
+            eval.arg(source).arg(keys.len());
+            for arg in keys.iter().chain(args) {
+                eval.arg(arg);
             }
-            true
+            result = self.send_command(&mut eval, routing).await;
         }
+        record_invocation(&hash, start.elapsed(), eval_fallback, result.is_err());
+        remove_script(&hash);
+        result
     }
 
-    pub fn release_inflight_request(&self) -> isize {
-        self.inflight_requests_allowed
-            .fetch_add(1, Ordering::SeqCst)
-    }
+    /// Acquires `tokens` from the rate limiter bucket `bucket_key`, with the bundled script of the algorithm of
+    /// `config`, see [`crate::rate_limiter`]. The script is invoked as in [`Self::invoke_script_inline`].
+    /// When `config` enables it, a denied acquisition is cached locally, and acquiring as many tokens or more from the
+    /// bucket is denied without reaching the server until the cache entry expires.
+    ///
+    /// # Returns
+    /// A map of `allowed`, `remaining`, `retry_after_ms` and `cached`, as described in
+    /// [`crate::rate_limiter::RateLimitDecision`].
+    pub async fn rate_limit_acquire(
+        &mut self,
+        bucket_key: &[u8],
+        tokens: u64,
+        config: &crate::rate_limiter::RateLimitConfig,
+    ) -> RedisResult<Value> {
+        config.validate(tokens)?;
+        let now = Instant::now();
+        if config.deny_cache_duration.is_some()
+            && let Some(decision) = self.rate_limit_deny_cache.get(bucket_key, tokens, now)
+        {
+            return Ok(decision.into_value());
+        }
+        let [limit, window, requested] = config.script_args(tokens);
+        let response = self
+            .invoke_script_inline(
+                config.script().as_bytes(),
+                &vec![bucket_key],
+                &vec![limit.as_slice(), window.as_slice(), requested.as_slice()],
+                None,
+            )
+            .await?;
+        let decision = crate::rate_limiter::RateLimitDecision::from_script_response(response)?;
+        if let Some(duration) = config.deny_cache_duration
+            && !decision.allowed
+        {
+            self.rate_limit_deny_cache
+                .insert(bucket_key, tokens, &decision, duration, now);
+        }
+        Ok(decision.into_value())
+    }
+
+    /// Acquires the lock `key` with `SET NX PX` and a new random token, see [`crate::distributed_lock`]. The lock
+    /// expires after `ttl`, unless it's extended. With `auto_extend`, the client extends the lock every third of `ttl`
+    /// until it's released with [`Self::release_lock`] or lost, and then notifies `listener`.
+    ///
+    /// # Returns
+    /// The token of the lock, or nil if the lock is held by another owner.
+    pub async fn acquire_lock(
+        &mut self,
+        key: &[u8],
+        ttl: Duration,
+        auto_extend: bool,
+        listener: Option<crate::distributed_lock::LockExtensionListener>,
+    ) -> RedisResult<Value> {
+        if ttl < Duration::from_millis(1) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid lock TTL",
+                "the TTL must be at least a millisecond".to_string(),
+            )));
+        }
+        let token = crate::distributed_lock::new_token();
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key)
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64);
+        if self.send_command(&mut cmd, None).await? == Value::Nil {
+            return Ok(Value::Nil);
+        }
+        if auto_extend {
+            crate::distributed_lock::spawn_extension(
+                self.clone(),
+                self.lock_extensions.clone(),
+                key.to_vec(),
+                token.clone(),
+                ttl,
+                listener,
+            );
+        }
+        Ok(Value::BulkString(token))
+    }
+
+    /// Releases the lock `key` if it's held with `token`, and stops its automatic extension, notifying its listener.
+    ///
+    /// # Returns
+    /// Whether the lock was held with `token` and released.
+    pub async fn release_lock(&mut self, key: &[u8], token: &[u8]) -> RedisResult<Value> {
+        // Stopped first, so a concurrent extension of the released lock isn't reported as lost.
+        self.lock_extensions.stop(token, None);
+        let response = self
+            .invoke_script_inline(
+                crate::distributed_lock::RELEASE_SCRIPT.as_bytes(),
+                &vec![key],
+                &vec![token],
+                None,
+            )
+            .await?;
+        crate::distributed_lock::convert_script_response(response)
+    }
+
+    /// Stops the automatic extension of every lock acquired by the client, so the extension tasks release their clone
+    /// of the client. Called when the client is closed, and the listeners aren't notified.
+    pub fn stop_lock_extensions(&self) {
+        self.lock_extensions.stop_all();
+    }
+
+    /// Sets the expiration of the lock `key` to `ttl` if it's held with `token`.
+    ///
+    /// # Returns
+    /// Whether the lock was held with `token` and extended.
+    pub async fn extend_lock(
+        &mut self,
+        key: &[u8],
+        token: &[u8],
+        ttl: Duration,
+    ) -> RedisResult<Value> {
+        let ttl = ttl.as_millis().to_string();
+        let response = self
+            .invoke_script_inline(
+                crate::distributed_lock::EXTEND_SCRIPT.as_bytes(),
+                &vec![key],
+                &vec![token, ttl.as_bytes()],
+                None,
+            )
+            .await?;
+        crate::distributed_lock::convert_script_response(response)
+    }
+
+    /// Check whether the scripts with the given SHA1 hashes exist in the script cache.
+    /// In cluster mode the check is sent to all primaries, and a script is reported as existing
+    /// only if it's cached on every one of them. Without hashes, an empty array is returned without
+    /// sending the command, since `SCRIPT EXISTS` requires at least one hash.
+    pub async fn script_exists_all_nodes(&mut self, hashes: &[&[u8]]) -> RedisResult<Value> {
+        if hashes.is_empty() {
+            return Ok(Value::Array(Vec::new()));
+        }
+        let mut cmd = redis::cmd("SCRIPT");
+        cmd.arg("EXISTS").arg(hashes);
+        let routing = RoutingInfo::MultiNode((
+            MultipleNodeRoutingInfo::AllMasters,
+            ResponsePolicy::for_command(b"SCRIPT EXISTS"),
+        ));
+        self.send_command(&mut cmd, Some(routing)).await
+    }
+
+    /// Flush the server-side script cache. If `routing` is `None`, the cache is flushed on all nodes.
+    pub async fn script_flush(
+        &mut self,
+        mode: FlushMode,
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("SCRIPT");
+        cmd.arg("FLUSH");
+        if let Some(mode) = mode.as_arg() {
+            cmd.arg(mode);
+        }
+        self.send_command(&mut cmd, routing).await
+    }
+
+    pub fn reserve_inflight_request(&self) -> bool {
+        self.reserve_inflight_request_with_priority(RequestPriority::Normal)
+    }
+
+    /// Reserves an inflight request slot for a request of the given priority. Normal-priority requests are rejected
+    /// once the inflight requests reach the high watermark, while high-priority requests may take the remaining slots
+    /// up to the inflight requests limit.
+    pub fn reserve_inflight_request_with_priority(&self, priority: RequestPriority) -> bool {
+        let reserved = match priority {
+            RequestPriority::Normal => self.high_priority_reserved_requests,
+            RequestPriority::High => 0,
+        };
+        // We use this approach of checking the `inflight_requests_allowed` value
+        // twice, before and after decrementing, to prevent it from reaching negative
+        // values. Allowing the `inflight_requests_allowed` value to go below zero
+        // could lead to a race condition where tasks might not be able to run even
+        // when there are available slots.
+        if self.inflight_requests_allowed.load(Ordering::SeqCst) <= reserved {
+            false
+        } else {
+            // The value is being checked again because it might have changed
+            // during the intervening period since the load by other tasks.
+            if self
+                .inflight_requests_allowed
+                .fetch_sub(1, Ordering::SeqCst)
+                <= reserved
+            {
+                self.inflight_requests_allowed
+                    .fetch_add(1, Ordering::SeqCst);
+                return false;
+            }
+            true
+        }
+    }
+
+    pub fn release_inflight_request(&self) -> isize {
+        self.inflight_requests_allowed
+            .fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Replaces the address translation of a cluster client. The translation applies to the node connections made
+    /// afterwards, such as after a reconnection or a topology change, and doesn't affect the existing connections.
+    /// Fails, leaving the translation unchanged, if one of the addresses isn't a `host:port` address.
+    pub fn set_address_translation(&self, table: HashMap<String, String>) -> RedisResult<()> {
+        self.config.address_translation.replace(table)
+    }
 
     /// Update the password used to authenticate with the servers.
     /// If None is passed, the password will be removed.
@@ -1272,12 +2210,34 @@ impl Client {
         password: Option<String>,
         immediate_auth: bool,
     ) -> RedisResult<Value> {
+        // Every connection is updated even if another one fails, and the first failure is returned once the main
+        // connection is updated too.
+        let mut side_connections_updated = Ok(());
+        for mut connection in self.stateful_connections(true) {
+            if let Err(err) =
+                Box::pin(connection.update_connection_password(password.clone(), immediate_auth))
+                    .await
+            {
+                log_warn(
+                    "update_connection_password",
+                    format!("Failed to update the password of a side connection: {err}"),
+                );
+                side_connections_updated = side_connections_updated.and(Err(err));
+            }
+        }
+        self.update_side_connections(|request| {
+            request
+                .authentication_info
+                .get_or_insert_with(Default::default)
+                .password = password.clone()
+        });
+
         let timeout = self.request_timeout;
         // The password update operation is wrapped in a timeout to prevent it from blocking indefinitely.
         // If the operation times out, an error is returned.
         // Since the password update operation is not a command that go through the regular command pipeline,
         // it is not have the regular timeout handling, as such we need to handle it separately.
-        match tokio::time::timeout(timeout, async {
+        let result = match tokio::time::timeout(timeout, async {
             let mut client = self.get_or_initialize_client().await?;
             match client {
                 ClientWrapper::Standalone(ref mut client) => {
@@ -1302,7 +2262,8 @@ impl Client {
                 ErrorKind::IoError,
                 "Password update operation timed out, please check the connection",
             ))),
-        }
+        };
+        result.and_then(|value| side_connections_updated.map(|()| value))
     }
 
     /// Send AUTH command using IAM token (preferred) or the provided password
@@ -1365,22 +2326,35 @@ impl Client {
 
     /// Create an `IAMTokenManager` when IAM auth is configured.
     ///
-    /// Client retrieves tokens on-demand during command execution.
+    /// Client retrieves tokens on-demand during command execution, and the outcome of each
+    /// background refresh is reported to `refresh_listener`.
     async fn create_iam_token_manager(
         auth_info: &crate::client::types::AuthenticationInfo,
+        refresh_listener: crate::iam::TokenRefreshListener,
     ) -> Option<std::sync::Arc<crate::iam::IAMTokenManager>> {
         if let Some(iam_config) = &auth_info.iam_config {
             if let Some(username) = &auth_info.username {
+                let refresh_interval_seconds = match iam_config.refresh_ttl_percent {
+                    Some(percent) => match crate::iam::refresh_interval_for_ttl_percent(percent) {
+                        Ok(interval) => Some(interval),
+                        Err(e) => {
+                            log_error("IAM", format!("Failed to create IAM token manager: {e}"));
+                            return None;
+                        }
+                    },
+                    None => iam_config.refresh_interval_seconds,
+                };
                 match crate::iam::IAMTokenManager::new(
                     iam_config.cluster_name.clone(),
                     username.clone(),
                     iam_config.region.clone(),
                     iam_config.service_type,
-                    iam_config.refresh_interval_seconds,
+                    refresh_interval_seconds,
                 )
                 .await
                 {
                     Ok(mut token_manager) => {
+                        token_manager.set_refresh_listener(refresh_listener);
                         token_manager.start_refresh_task();
                         Some(std::sync::Arc::new(token_manager))
                     }
@@ -1398,6 +2372,41 @@ impl Client {
         }
     }
 
+    /// Re-authenticates the connections with every token refreshed by the IAM token manager (push model), so idle
+    /// connections don't wait for the next command to pick up the new token. Refresh and re-authentication failures
+    /// are reported to `connection_event_listener` as [`redis::ConnectionEvent::AuthenticationRefreshFailed`].
+    ///
+    /// `client` must not hold the IAM token manager, so the task stops once the manager is dropped with the client.
+    async fn reauthenticate_on_token_refresh(
+        mut client: Client,
+        mut refreshed_tokens: mpsc::UnboundedReceiver<Result<String, String>>,
+        connection_event_listener: Option<Arc<dyn redis::ConnectionEventListener>>,
+    ) {
+        while let Some(refreshed_token) = refreshed_tokens.recv().await {
+            let result = match refreshed_token {
+                Ok(token) => client
+                    .update_connection_password(Some(token), true)
+                    .await
+                    .map(|_| ())
+                    .map_err(|err| err.to_string()),
+                Err(err) => Err(err),
+            };
+            if let Err(err) = result {
+                log_warn(
+                    "IAM",
+                    format!("Failed to re-authenticate with the refreshed IAM token: {err}"),
+                );
+                if let Some(listener) = &connection_event_listener {
+                    listener.on_connection_event(
+                        redis::ConnectionEvent::AuthenticationRefreshFailed,
+                        "",
+                        Some(&err),
+                    );
+                }
+            }
+        }
+    }
+
     /// Manually refresh the IAM token and update connection authentication
     ///
     /// This method generates a new IAM token using the configured IAM token manager
@@ -1420,6 +2429,921 @@ impl Client {
         iam_manager.refresh_token().await;
         Ok(())
     }
+
+    /// Send `PING` to every known node concurrently and report the round-trip latency of each one.
+    ///
+    /// # Returns
+    /// A map of node address to the measured latency in microseconds. A node that failed to
+    /// respond within the request timeout maps to a simple string holding the error message,
+    /// so a single unhealthy node doesn't hide the results of the others.
+    pub async fn ping_all_nodes(&mut self) -> RedisResult<Value> {
+        let results = self
+            .send_to_each_node_timed(&redis::cmd("PING"), MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        Ok(Value::Map(
+            results
+                .into_iter()
+                .map(|(address, result)| {
+                    let value = match result {
+                        Ok((_, latency)) => Value::Int(latency.as_micros() as i64),
+                        Err(err) => Value::SimpleString(err.to_string()),
+                    };
+                    (Value::BulkString(address.into_bytes()), value)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Flushes all databases with `FLUSHALL`, or the selected database with `FLUSHDB` if `flush_all` isn't set,
+    /// on every primary node. Each primary is flushed separately, so a failing node doesn't hide the results of the others.
+    ///
+    /// # Returns
+    /// A map of `succeeded` to an array of the addresses of the flushed nodes, and `failed` to a map of the addresses
+    /// of the nodes that failed to the error message.
+    pub async fn flush_cluster(&mut self, flush_all: bool, mode: FlushMode) -> RedisResult<Value> {
+        let mut cmd = redis::cmd(if flush_all { "FLUSHALL" } else { "FLUSHDB" });
+        if let Some(mode) = mode.as_arg() {
+            cmd.arg(mode);
+        }
+
+        let results = self
+            .send_to_each_node(&cmd, MultipleNodeRoutingInfo::AllMasters)
+            .await?;
+        Ok(flush_report(results))
+    }
+
+    /// Sends `cmd` separately to each of the nodes selected by `routing`, so a failing node doesn't hide the results
+    /// of the others, and returns the result of each node paired with its address. In standalone mode, `AllMasters`
+    /// selects the primary, and the other routings select every node.
+    async fn send_to_each_node(
+        &mut self,
+        cmd: &Cmd,
+        routing: MultipleNodeRoutingInfo,
+    ) -> RedisResult<Vec<(String, RedisResult<Value>)>> {
+        Ok(self
+            .send_to_each_node_timed(cmd, routing)
+            .await?
+            .into_iter()
+            .map(|(address, result)| (address, result.map(|(value, _)| value)))
+            .collect())
+    }
+
+    /// Like [`Self::send_to_each_node`], but also returns the round-trip time of each successful request.
+    async fn send_to_each_node_timed(
+        &mut self,
+        cmd: &Cmd,
+        routing: MultipleNodeRoutingInfo,
+    ) -> RedisResult<Vec<(String, RedisResult<(Value, Duration)>)>> {
+        let timeout = Some(self.request_timeout);
+        let client = self.get_or_initialize_client().await?;
+        let results = match client {
+            ClientWrapper::Standalone(mut client) => match routing {
+                MultipleNodeRoutingInfo::AllMasters => {
+                    let start = Instant::now();
+                    let result = run_with_timeout(timeout, client.send_command(cmd))
+                        .await
+                        .map(|value| (value, start.elapsed()));
+                    vec![(client.primary_address(), result)]
+                }
+                _ => client.send_to_each_node(cmd, timeout).await,
+            },
+            ClientWrapper::Cluster { mut client } => {
+                let addresses = discover_cluster_nodes(&mut client, routing).await?;
+                let requests = addresses.into_iter().map(|address| {
+                    let mut client = client.clone();
+                    async move {
+                        let routing = match routing_for_address(&address) {
+                            Ok(routing) => routing,
+                            Err(err) => return (address, Err(err)),
+                        };
+                        let start = Instant::now();
+                        let result = run_with_timeout(timeout, client.route_command(cmd, routing))
+                            .await
+                            .map(|value| (value, start.elapsed()));
+                        (address, result)
+                    }
+                });
+                futures::future::join_all(requests).await
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        };
+        Ok(results)
+    }
+
+    /// Gets the configuration parameters matching `parameters` with `CONFIG GET` on every node, and checks that all
+    /// the nodes agree on their values.
+    ///
+    /// # Returns
+    /// A report, as described in [`config_report`].
+    pub async fn config_get_all_nodes(&mut self, parameters: &[&[u8]]) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("CONFIG");
+        cmd.arg("GET").arg(parameters);
+        let results = self
+            .send_to_each_node(&cmd, MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        Ok(config_report(results))
+    }
+
+    /// Sets the configuration parameters with `CONFIG SET` on every node, then reads them back with `CONFIG GET` to
+    /// check that all the nodes applied the same values. The nodes are set separately, so the parameters may be set on
+    /// some nodes only.
+    ///
+    /// # Returns
+    /// A report, as described in [`config_report`]. A node that failed to set the parameters is reported as failed
+    /// with the error of `CONFIG SET`.
+    pub async fn config_set_all_nodes(
+        &mut self,
+        parameters: &[(&[u8], &[u8])],
+    ) -> RedisResult<Value> {
+        let mut set_cmd = redis::cmd("CONFIG");
+        set_cmd.arg("SET");
+        let mut get_cmd = redis::cmd("CONFIG");
+        get_cmd.arg("GET");
+        for (parameter, value) in parameters {
+            set_cmd.arg(*parameter).arg(*value);
+            get_cmd.arg(*parameter);
+        }
+        let set_results = self
+            .send_to_each_node(&set_cmd, MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        let get_results = self
+            .send_to_each_node(&get_cmd, MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        Ok(config_report(with_set_failures(set_results, get_results)))
+    }
+
+    /// Gets the ACL rules of `username` with `ACL GETUSER`, converted into a structured map as described in
+    /// [`crate::acl::convert_acl_getuser_response`]. Returns nil if the user doesn't exist.
+    pub async fn acl_getuser(&mut self, username: &[u8]) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("ACL");
+        cmd.arg("GETUSER").arg(username);
+        let value = self.send_command(&mut cmd, None).await?;
+        crate::acl::convert_acl_getuser_response(value)
+    }
+
+    /// Returns the name of the user the client is authenticated as, with `ACL WHOAMI`.
+    pub async fn acl_whoami(&mut self) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("ACL");
+        cmd.arg("WHOAMI");
+        self.send_command(&mut cmd, None).await
+    }
+
+    /// Sets the ACL rules of `username` with `ACL SETUSER` on every node, then reads the user back with
+    /// `ACL GETUSER` to check that all the nodes hold the same rules. The rules are validated with
+    /// [`crate::acl::validate_acl_setuser_rules`] before they're sent, and the nodes are set separately, so the rules
+    /// may be set on some nodes only.
+    ///
+    /// # Returns
+    /// A report, as described in [`config_report`], of the users converted by
+    /// [`crate::acl::convert_acl_getuser_response`]. A node that failed to set the rules is reported as failed with
+    /// the error of `ACL SETUSER`.
+    pub async fn acl_setuser_all_nodes(
+        &mut self,
+        username: &[u8],
+        rules: &[&[u8]],
+    ) -> RedisResult<Value> {
+        crate::acl::validate_acl_setuser_rules(rules)?;
+        let mut set_cmd = redis::cmd("ACL");
+        set_cmd.arg("SETUSER").arg(username).arg(rules);
+        let mut get_cmd = redis::cmd("ACL");
+        get_cmd.arg("GETUSER").arg(username);
+        let set_results = self
+            .send_to_each_node(&set_cmd, MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        let get_results = self
+            .send_to_each_node(&get_cmd, MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        Ok(consistency_report(
+            with_set_failures(set_results, get_results),
+            crate::acl::convert_acl_getuser_response,
+        ))
+    }
+
+    /// Returns the capabilities of the nodes, recorded with `HELLO` after the client connected, so wrappers can gate
+    /// features without sending their own `INFO`. With `refresh`, or if they weren't recorded yet, such as for a
+    /// lazily connected client, the capabilities are collected again and recorded. Since the protocol may change with
+    /// `HELLO`, the recorded protocol of each node is the one negotiated when the capabilities were collected.
+    ///
+    /// # Returns
+    /// A report of the capabilities of each node, as described in [`server_capabilities::capabilities_report`].
+    pub async fn server_capabilities(&mut self, refresh: bool) -> RedisResult<Value> {
+        if !refresh && let Some(capabilities) = self.server_capabilities.lock().unwrap().clone() {
+            return Ok(capabilities);
+        }
+        let results = self
+            .send_to_each_node(&redis::cmd("HELLO"), MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        let capabilities = server_capabilities::capabilities_report(
+            results,
+            self.config.protocol.unwrap_or_default(),
+        );
+        *self.server_capabilities.lock().unwrap() = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// Gets the `sections` of `INFO`, or its default sections if `sections` is empty, parsed into a structured form.
+    /// With a multi-node `routing`, `INFO` is sent separately to each of the selected nodes. Without `routing`, a
+    /// cluster client sends it to all the primaries, and a standalone client to the primary.
+    ///
+    /// # Returns
+    /// For a single node, a map of its sections to their fields, as described in
+    /// [`crate::info::convert_info_response`]. For several nodes, a map of the address of each node to its sections,
+    /// as described in [`crate::info::aggregate_info`].
+    pub async fn get_info(
+        &mut self,
+        sections: &[&[u8]],
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("INFO");
+        cmd.arg(sections);
+        let routing = match routing {
+            None if self.config.cluster_mode_enabled => Some(RoutingInfo::MultiNode((
+                MultipleNodeRoutingInfo::AllMasters,
+                None,
+            ))),
+            routing => routing,
+        };
+        match routing {
+            Some(RoutingInfo::MultiNode((routing, _))) => {
+                let results = self.send_to_each_node(&cmd, routing).await?;
+                crate::info::aggregate_info(results)
+            }
+            routing => {
+                let value = self.send_command(&mut cmd, routing).await?;
+                crate::info::convert_info_response(value)
+            }
+        }
+    }
+
+    /// Lists the connections of all the nodes with `CLIENT LIST`, filtered by `filters`.
+    ///
+    /// # Returns
+    /// A report of the connections of each node, and of the nodes that failed, as described in
+    /// [`crate::client_list::client_lists_report`].
+    pub async fn list_clients(
+        &mut self,
+        filters: &crate::client_list::ClientListFilters,
+    ) -> RedisResult<Value> {
+        let results = self
+            .send_to_each_node(&filters.command(), MultipleNodeRoutingInfo::AllNodes)
+            .await?;
+        Ok(crate::client_list::client_lists_report(results, filters))
+    }
+
+    /// Describes a key by sending `TYPE`, `OBJECT ENCODING`, `TTL` and `MEMORY USAGE` in a single pipeline.
+    /// Returns a map of `type`, `encoding`, `ttl` and `memory_usage` to the corresponding responses.
+    /// For a key that doesn't exist, `type` is `none`, `ttl` is `-2`, and `encoding` and `memory_usage` are `nil`.
+    pub async fn describe_key(&mut self, key: &[u8]) -> RedisResult<Value> {
+        const FIELDS: [&str; 4] = ["type", "encoding", "ttl", "memory_usage"];
+
+        let mut pipeline = redis::Pipeline::with_capacity(FIELDS.len());
+        pipeline
+            .cmd("TYPE")
+            .arg(key)
+            .cmd("OBJECT")
+            .arg("ENCODING")
+            .arg(key)
+            .cmd("TTL")
+            .arg(key)
+            .cmd("MEMORY")
+            .arg("USAGE")
+            .arg(key);
+
+        let Value::Array(values) = self
+            .send_pipeline(
+                &pipeline,
+                None,
+                true,
+                None,
+                PipelineRetryStrategy::default(),
+            )
+            .await?
+        else {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for key description",
+            )));
+        };
+
+        Ok(Value::Map(
+            FIELDS
+                .into_iter()
+                .map(|field| Value::SimpleString(field.to_string()))
+                .zip(values)
+                .collect(),
+        ))
+    }
+
+    /// Serializes the value stored at the key with `DUMP`.
+    /// Returns the serialized value as a bulk string, or `nil` if the key doesn't exist.
+    pub async fn dump_key(&mut self, key: &[u8]) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("DUMP");
+        cmd.arg(key);
+        self.send_command(&mut cmd, None).await
+    }
+
+    /// Creates a key from a value serialized with `DUMP`, using `RESTORE`.
+    ///
+    /// `ttl` is the time to live of the key in milliseconds, where `0` means no expiry, or a Unix timestamp in
+    /// milliseconds if `absttl` is set. An existing key is overwritten only if `replace` is set.
+    pub async fn restore_key(
+        &mut self,
+        key: &[u8],
+        ttl: u64,
+        serialized_value: &[u8],
+        replace: bool,
+        absttl: bool,
+    ) -> RedisResult<Value> {
+        let mut cmd = restore_cmd(key, ttl, serialized_value, replace, absttl);
+        self.send_command(&mut cmd, None).await
+    }
+
+    /// Copies the value stored at `source` to `destination`, like `COPY`, and returns whether the value was copied.
+    ///
+    /// `COPY` fails when the keys hash to different slots in cluster mode, so in that case the value is copied with
+    /// `DUMP` and `RESTORE` instead, keeping the remaining time to live of the source. The destination is overwritten
+    /// only if `replace` is set, and otherwise the value isn't copied if the destination exists.
+    /// Unlike `COPY`, the fallback isn't atomic.
+    pub async fn copy_key_cross_slot(
+        &mut self,
+        source: &[u8],
+        destination: &[u8],
+        replace: bool,
+    ) -> RedisResult<Value> {
+        let same_slot = match self.get_or_initialize_client().await? {
+            ClientWrapper::Cluster { .. } => {
+                redis::cluster_topology::get_slot(source)
+                    == redis::cluster_topology::get_slot(destination)
+            }
+            _ => true,
+        };
+        if same_slot {
+            let mut cmd = redis::cmd("COPY");
+            cmd.arg(source).arg(destination);
+            if replace {
+                cmd.arg("REPLACE");
+            }
+            return match self.send_command(&mut cmd, None).await? {
+                Value::Int(copied) => Ok(Value::Boolean(copied == 1)),
+                value => Ok(value),
+            };
+        }
+
+        let mut pipeline = redis::Pipeline::with_capacity(2);
+        pipeline.cmd("DUMP").arg(source).cmd("PTTL").arg(source);
+        let unexpected_response = |value: &Value| {
+            RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for copying a key",
+                format!("(response was {value:?})"),
+            ))
+        };
+        let (serialized_value, ttl) = match self
+            .send_pipeline(
+                &pipeline,
+                None,
+                true,
+                None,
+                PipelineRetryStrategy::default(),
+            )
+            .await?
+        {
+            Value::Array(values) => match values.as_slice() {
+                [Value::Nil, _] => return Ok(Value::Boolean(false)),
+                [Value::BulkString(serialized_value), Value::Int(ttl)] => {
+                    (serialized_value.clone(), *ttl)
+                }
+                other => return Err(unexpected_response(&Value::Array(other.to_vec()))),
+            },
+            value => return Err(unexpected_response(&value)),
+        };
+        // The key may have expired after it was dumped.
+        if ttl == -2 {
+            return Ok(Value::Boolean(false));
+        }
+
+        let mut cmd = restore_cmd(
+            destination,
+            ttl.max(0) as u64,
+            &serialized_value,
+            replace,
+            false,
+        );
+        match self.send_command(&mut cmd, None).await {
+            Ok(_) => Ok(Value::Boolean(true)),
+            Err(err) if err.code() == Some("BUSYKEY") => Ok(Value::Boolean(false)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Merges the HyperLogLogs stored at `sources` into `destination`, like `PFMERGE`.
+    ///
+    /// `PFMERGE` fails when the keys hash to different slots in cluster mode, so in that case the sources are grouped
+    /// by slot, and the sources of each group are merged into a temporary key of their slot, whose value is read. The
+    /// values are written to temporary keys of the destination's slot, which are merged into the destination with the
+    /// sources of that slot, and deleted. The temporary keys expire after a minute, in case they aren't deleted.
+    /// Unlike `PFMERGE`, merging sources of several slots isn't atomic.
+    pub async fn pfmerge_across_slots(
+        &mut self,
+        destination: &[u8],
+        sources: &[Vec<u8>],
+    ) -> RedisResult<Value> {
+        let is_cluster = matches!(
+            self.get_or_initialize_client().await?,
+            ClientWrapper::Cluster { .. }
+        );
+        let destination_slot = redis::cluster_topology::get_slot(destination);
+        let mut local_sources: Vec<&[u8]> = Vec::new();
+        let mut remote_sources: HashMap<u16, Vec<&[u8]>> = HashMap::new();
+        for source in sources {
+            let slot = redis::cluster_topology::get_slot(source);
+            if !is_cluster || slot == destination_slot {
+                local_sources.push(source);
+            } else {
+                remote_sources.entry(slot).or_default().push(source);
+            }
+        }
+        if remote_sources.is_empty() {
+            let mut cmd = redis::cmd("PFMERGE");
+            cmd.arg(destination).arg(&local_sources);
+            return self.send_command(&mut cmd, None).await;
+        }
+
+        let temporary_prefix = format!("glide-pfmerge:{}", nanoid!());
+        let payloads = futures::future::try_join_all(remote_sources.into_values().map(|keys| {
+            let mut client = self.clone();
+            let temporary_prefix = temporary_prefix.clone();
+            async move {
+                client
+                    .read_merged_hyperloglogs(&keys, &temporary_prefix)
+                    .await
+            }
+        }))
+        .await?;
+
+        let mut temporary_keys = Vec::new();
+        let mut pipeline = redis::Pipeline::new();
+        for (index, payload) in payloads.iter().flatten().enumerate() {
+            let temporary_key = key_in_slot_of(destination, &format!("{temporary_prefix}:{index}"))
+                .ok_or_else(|| {
+                    RedisError::from((
+                        ErrorKind::ClientError,
+                        "The destination's hash tag can't be used for temporary keys",
+                    ))
+                })?;
+            pipeline
+                .cmd("SET")
+                .arg(&temporary_key)
+                .arg(payload)
+                .arg("PX")
+                .arg(PFMERGE_TEMPORARY_KEY_TTL_MS);
+            temporary_keys.push(temporary_key);
+        }
+        let merge_index = pipeline.cmd_iter().count();
+        pipeline
+            .cmd("PFMERGE")
+            .arg(destination)
+            .arg(&local_sources)
+            .arg(&temporary_keys);
+        if !temporary_keys.is_empty() {
+            pipeline.cmd("DEL").arg(&temporary_keys);
+        }
+        match self.send_transaction(&pipeline, None, None, true).await? {
+            Value::Array(mut values) if values.len() > merge_index => {
+                Ok(values.swap_remove(merge_index))
+            }
+            value => Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for merging HyperLogLogs",
+                format!("(response was {value:?})"),
+            ))),
+        }
+    }
+
+    /// Returns the values of HyperLogLogs merging the HyperLogLogs stored at `keys`, which hash to the same slot.
+    /// The keys are merged into a temporary key of their slot, and otherwise their values are returned as is.
+    async fn read_merged_hyperloglogs(
+        &mut self,
+        keys: &[&[u8]],
+        temporary_prefix: &str,
+    ) -> RedisResult<Vec<Vec<u8>>> {
+        let mut pipeline = redis::Pipeline::new();
+        let response = match key_in_slot_of(keys[0], temporary_prefix) {
+            Some(temporary_key) if keys.len() > 1 => {
+                pipeline
+                    .cmd("PFMERGE")
+                    .arg(&temporary_key)
+                    .arg(keys)
+                    .cmd("PEXPIRE")
+                    .arg(&temporary_key)
+                    .arg(PFMERGE_TEMPORARY_KEY_TTL_MS)
+                    .cmd("GET")
+                    .arg(&temporary_key)
+                    .cmd("DEL")
+                    .arg(&temporary_key);
+                match self.send_transaction(&pipeline, None, None, true).await? {
+                    Value::Array(mut values) if values.len() == 4 => {
+                        Value::Array(vec![values.swap_remove(2)])
+                    }
+                    value => value,
+                }
+            }
+            _ => {
+                for key in keys {
+                    pipeline.cmd("GET").arg(*key);
+                }
+                self.send_pipeline(
+                    &pipeline,
+                    None,
+                    true,
+                    None,
+                    PipelineRetryStrategy::default(),
+                )
+                .await?
+            }
+        };
+        let Value::Array(values) = response else {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for reading HyperLogLogs",
+                format!("(response was {response:?})"),
+            )));
+        };
+        // Missing keys are merged as empty HyperLogLogs, so they are skipped.
+        values
+            .into_iter()
+            .filter(|value| *value != Value::Nil)
+            .map(|value| Vec::<u8>::from_owned_redis_value(value))
+            .collect()
+    }
+
+    /// Returns the address of the primary node that serves the given key.
+    /// In cluster mode the address is taken from the current slot map, and `nil` is returned
+    /// if the key's slot isn't covered by it. In standalone mode the primary's address is returned.
+    pub async fn get_node_for_key(&mut self, key: &[u8]) -> RedisResult<Value> {
+        let client = self.get_or_initialize_client().await?;
+        match client {
+            ClientWrapper::Standalone(client) => {
+                Ok(Value::BulkString(client.primary_address().into_bytes()))
+            }
+            ClientWrapper::Cluster { mut client } => {
+                client
+                    .get_address_for_slot(redis::cluster_topology::get_slot(key))
+                    .await
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        }
+    }
+
+    /// Returns the address of the node that serves a command with the given routing, or `None` if the routing doesn't
+    /// resolve to a single known node: random and multi-node routes, and reads that may be served by a replica.
+    /// In cluster mode the address is taken from the current slot map.
+    pub async fn node_address_for_route(&mut self, routing: &RoutingInfo) -> Option<String> {
+        let served_by_primary = |slot_addr: SlotAddr| match slot_addr {
+            SlotAddr::Master => true,
+            SlotAddr::ReplicaOptional => {
+                matches!(self.config.read_from, None | Some(ReadFrom::Primary))
+            }
+            SlotAddr::ReplicaRequired => false,
+        };
+        let primary_route = match routing {
+            RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress { host, port }) => {
+                return Some(format!("{host}:{port}"));
+            }
+            RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))
+                if served_by_primary(route.slot_addr()) =>
+            {
+                Some(route.slot())
+            }
+            _ => None,
+        };
+        let reads_from_primary = served_by_primary(SlotAddr::ReplicaOptional);
+        match self.get_or_initialize_client().await.ok()? {
+            // Every command is sent to the primary, except for the reads when reading from replicas is allowed.
+            ClientWrapper::Standalone(client) => {
+                (primary_route.is_some() || reads_from_primary).then(|| client.primary_address())
+            }
+            ClientWrapper::Cluster { mut client } => {
+                let value = client.get_address_for_slot(primary_route?).await.ok()?;
+                Option::<String>::from_owned_redis_value(value)
+                    .ok()
+                    .flatten()
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        }
+    }
+
+    /// Checks the cluster topology right away, instead of waiting for the next periodic check, and refreshes the
+    /// slot map if the topology changed. Useful when the application learns about a failover before the client does.
+    /// Returns true if the slot map changed. Supported in cluster mode only.
+    pub async fn refresh_topology(&mut self) -> RedisResult<bool> {
+        match self.get_or_initialize_client().await? {
+            ClientWrapper::Standalone(_) => Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Topology refresh is only supported in cluster mode",
+            ))),
+            ClientWrapper::Cluster { mut client } => client.refresh_topology().await,
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        }
+    }
+
+    /// Waits until the previous writes are acknowledged by at least `numreplicas` replicas, or until `timeout_ms`
+    /// milliseconds passed, with `WAIT`. A timeout of `0` waits indefinitely.
+    ///
+    /// In cluster mode `key` should be a key of the previous write, so `WAIT` is sent to the primary that handled it.
+    /// Without a key `WAIT` is sent to all primaries, and the lowest acknowledgement count is returned.
+    /// In standalone mode `key` is ignored. Note that `WAIT` only covers the writes sent through the same connection,
+    /// so with a connection pool the writes sent through the other connections aren't awaited.
+    ///
+    /// Returns a map with the `acknowledged` replicas count, the `requested` replicas count, and whether the
+    /// request was `satisfied`.
+    pub async fn wait_for_replication(
+        &mut self,
+        numreplicas: u32,
+        timeout_ms: u64,
+        key: Option<&[u8]>,
+    ) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("WAIT");
+        cmd.arg(numreplicas).arg(timeout_ms);
+        let routing = match self.get_or_initialize_client().await? {
+            ClientWrapper::Cluster { .. } => key.map(|key| {
+                RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route::new(
+                    redis::cluster_topology::get_slot(key),
+                    SlotAddr::Master,
+                )))
+            }),
+            _ => None,
+        };
+
+        let acknowledged = match self.send_command(&mut cmd, routing).await? {
+            Value::Int(acknowledged) => acknowledged,
+            value => {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected WAIT response",
+                    format!("(response was {value:?})"),
+                )));
+            }
+        };
+        Ok(Value::Map(vec![
+            (
+                Value::SimpleString("acknowledged".to_string()),
+                Value::Int(acknowledged),
+            ),
+            (
+                Value::SimpleString("requested".to_string()),
+                Value::Int(numreplicas.into()),
+            ),
+            (
+                Value::SimpleString("satisfied".to_string()),
+                Value::Boolean(acknowledged >= i64::from(numreplicas)),
+            ),
+        ]))
+    }
+
+    /// Promotes a replica of a standalone primary with `FAILOVER`, after validating the options.
+    /// Cluster mode isn't supported, since cluster failovers are triggered with `CLUSTER FAILOVER` on the replica.
+    pub async fn failover(&mut self, options: &FailoverOptions) -> RedisResult<Value> {
+        let mut cmd = failover_cmd(options)?;
+        if let ClientWrapper::Cluster { .. } = self.get_or_initialize_client().await? {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "FAILOVER isn't supported in cluster mode, use CLUSTER FAILOVER on the replica instead",
+            )));
+        }
+        self.send_command(&mut cmd, None).await
+    }
+
+    /// Watches the keys for an optimistic transaction, with `WATCH`, and returns the token of the watch as an
+    /// integer, which [`Client::unwatch`] and [`Client::execute_transaction_with_watch`] take to end it.
+    ///
+    /// `WATCH` is sent through a side connection that is reserved until the watched transaction is executed or the
+    /// watch is released, so the commands multiplexed on the main connection can't interfere with it. Only one watch
+    /// is active at a time, and a second call waits until the active watch ends, or until it expires after
+    /// [`WATCH_EXPIRY`] without `EXEC` or `UNWATCH`. In cluster mode all the keys must map to the
+    /// same slot.
+    pub async fn watch(&mut self, keys: &[Vec<u8>]) -> RedisResult<Value> {
+        let (token, result) = self.start_watch(keys).await?;
+        result.map(|_| Value::Int(token as i64))
+    }
+
+    /// Reserves the watch connection and sends `WATCH` through it. The reservation is released if `WATCH` fails.
+    async fn start_watch(&mut self, keys: &[Vec<u8>]) -> RedisResult<(u64, RedisResult<Value>)> {
+        if keys.is_empty() {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "WATCH requires at least one key",
+            )));
+        }
+        let mut cmd = redis::cmd("WATCH");
+        for key in keys {
+            cmd.arg(key);
+        }
+
+        let (token, mut client) = self.watch_connection.reserve().await?;
+        let result = client.send_command(&mut cmd, None).await;
+        if result.is_err() {
+            self.watch_connection.release(token);
+        }
+        Ok((token, result))
+    }
+
+    /// Releases the watch identified by `token` with `UNWATCH`. Does nothing if the watch already ended or expired, so
+    /// the watch of another caller is never released.
+    pub async fn unwatch(&mut self, token: u64) -> RedisResult<Value> {
+        let Some(client) = self.watch_connection.reserved_client(token).await else {
+            return Ok(Value::Okay);
+        };
+        let mut cmd = redis::cmd("UNWATCH");
+        let result = match client {
+            Ok(mut client) => client.send_command(&mut cmd, None).await,
+            Err(err) => Err(err),
+        };
+        self.watch_connection.release(token);
+        result
+    }
+
+    /// Executes a transaction through the connection of a watch, and releases the watch. If `keys` isn't empty, they
+    /// are watched first, as with [`Client::watch`], and `token` is ignored. Otherwise the watch identified by `token`
+    /// is used, and the call fails if it already ended or expired. Without keys and token, the transaction is executed
+    /// as with [`Client::send_transaction`].
+    ///
+    /// Returns an error of type [`crate::errors::RequestErrorType::WatchedKeyModified`] if the transaction was aborted
+    /// because a watched key was modified.
+    pub async fn execute_transaction_with_watch(
+        &mut self,
+        token: Option<u64>,
+        keys: &[Vec<u8>],
+        pipeline: &redis::Pipeline,
+        routing: Option<RoutingInfo>,
+        transaction_timeout: Option<u32>,
+        raise_on_error: bool,
+    ) -> RedisResult<Value> {
+        let token = if keys.is_empty() {
+            token
+        } else {
+            let (token, result) = self.start_watch(keys).await?;
+            result?;
+            Some(token)
+        };
+        let Some(token) = token else {
+            return self
+                .send_transaction(pipeline, routing, transaction_timeout, raise_on_error)
+                .await;
+        };
+        let Some(client) = self.watch_connection.reserved_client(token).await else {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "The watch of the transaction already ended or expired",
+            )));
+        };
+
+        let result = match client {
+            Ok(mut client) => {
+                client
+                    .send_transaction(pipeline, routing, transaction_timeout, raise_on_error)
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+        // `EXEC` releases the watched keys on the server, and so does an aborted transaction.
+        self.watch_connection.release(token);
+        match result {
+            Ok(Value::Nil) => Err(crate::errors::watched_key_modified_error()),
+            result => result,
+        }
+    }
+
+    /// Publishes many messages in a single pipeline, and returns the number of clients that received each message,
+    /// in the order of `messages`.
+    ///
+    /// Each message is a `(channel, message, sharded)` tuple, published with `SPUBLISH` if `sharded` is set and with
+    /// `PUBLISH` otherwise. In cluster mode the sharded messages are grouped by the primary that serves the slot of
+    /// their channel, so each node receives a single sub-pipeline. A failed publish is returned as an error in its
+    /// position of the response instead of failing the other messages.
+    pub async fn publish_many(&mut self, messages: &[(&[u8], &[u8], bool)]) -> RedisResult<Value> {
+        let mut pipeline = redis::Pipeline::with_capacity(messages.len());
+        for (channel, message, sharded) in messages {
+            let command = if *sharded { "SPUBLISH" } else { "PUBLISH" };
+            pipeline.cmd(command).arg(*channel).arg(*message);
+        }
+        self.send_pipeline(
+            &pipeline,
+            None,
+            false,
+            None,
+            PipelineRetryStrategy::new(false, false),
+        )
+        .await
+    }
+
+    /// Returns the exact, pattern and sharded subscriptions of the client, as tracked by its pubsub synchronizer,
+    /// including the subscriptions that are waiting to be restored after a reconnection.
+    /// See [`crate::pubsub::subscriptions_summary`] for the format of the response.
+    pub fn get_subscriptions(&self) -> Value {
+        let (desired, actual) = self.pubsub_synchronizer.get_subscription_state();
+        crate::pubsub::subscriptions_summary(&desired, &actual)
+    }
+
+    /// Subscribes to the keyspace notifications of keys that match any of `key_patterns`, in all databases,
+    /// or to all keyspace notifications if no pattern is given.
+    /// The notifications are delivered as pattern messages, and can be parsed with
+    /// [`crate::pubsub::keyspace_events::parse_keyspace_event`].
+    /// In cluster mode the patterns are subscribed on every primary, since each node only publishes the notifications
+    /// of its own keys, and the subscriptions follow the primaries through topology changes.
+    ///
+    /// If `event_mask` is given, `notify-keyspace-events` is set to it on all nodes first,
+    /// otherwise the server must already be configured to publish the notifications.
+    pub async fn subscribe_keyspace_events(
+        &mut self,
+        key_patterns: &[Vec<u8>],
+        event_mask: Option<&str>,
+    ) -> RedisResult<Value> {
+        if let Some(event_mask) = event_mask {
+            let mut config_cmd = redis::cmd("CONFIG");
+            config_cmd
+                .arg("SET")
+                .arg("notify-keyspace-events")
+                .arg(event_mask);
+            self.send_command(&mut config_cmd, None).await?;
+        }
+
+        let mut subscribe_cmd = redis::cmd("PSUBSCRIBE");
+        for channel_pattern in
+            crate::pubsub::keyspace_events::keyspace_channel_patterns(key_patterns)
+        {
+            subscribe_cmd.arg(channel_pattern);
+        }
+        self.send_command(&mut subscribe_cmd, None).await
+    }
+
+    /// Iterates over the whole keyspace of a standalone client with `SCAN`, and passes each non-empty page of keys to `on_page`.
+    /// The iteration ends when the server returns the `0` cursor, so every key that exists during the whole iteration is
+    /// passed at least once, or when `on_page` returns an error. Returns the total number of keys passed to `on_page`.
+    ///
+    /// A cursor is only meaningful to the node that issued it, so every page is read from the node that the read
+    /// strategy selected for the first page, instead of spreading the pages over the replicas.
+    ///
+    /// Cluster clients should use [`Client::cluster_scan`], which also handles topology changes during the scan.
+    pub async fn scan_database(
+        &mut self,
+        match_pattern: Option<&[u8]>,
+        count: Option<u32>,
+        object_type: Option<&str>,
+        mut on_page: impl FnMut(Vec<Value>) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        let ClientWrapper::Standalone(mut client) = self.get_or_initialize_client().await? else {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "scan_database is only supported by standalone clients, use cluster_scan instead",
+            )));
+        };
+        let timeout = Some(self.request_timeout);
+        let address = client.read_node_address().await;
+
+        let mut cursor = b"0".to_vec();
+        let mut keys_count = 0;
+        loop {
+            let mut scan_cmd = redis::cmd("SCAN");
+            scan_cmd.arg(&cursor);
+            if let Some(match_pattern) = match_pattern {
+                scan_cmd.arg("MATCH").arg(match_pattern);
+            }
+            if let Some(count) = count {
+                scan_cmd.arg("COUNT").arg(count);
+            }
+            if let Some(object_type) = object_type {
+                scan_cmd.arg("TYPE").arg(object_type);
+            }
+
+            let Value::Array(mut response) =
+                run_with_timeout(timeout, client.send_command_to_address(&scan_cmd, &address))
+                    .await?
+            else {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected SCAN response",
+                )));
+            };
+            let (Some(Value::Array(keys)), Some(Value::BulkString(next_cursor)), None) =
+                (response.pop(), response.pop(), response.pop())
+            else {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected SCAN response",
+                )));
+            };
+
+            if !keys.is_empty() {
+                keys_count += keys.len() as i64;
+                on_page(keys)?;
+            }
+            if next_cursor == b"0" {
+                return Ok(Value::Int(keys_count));
+            }
+            cursor = next_cursor;
+        }
+    }
 }
 /// Trait for executing PubSub commands on the internal client wrapper
 pub trait PubSubCommandApplier: Send + Sync {
@@ -1477,8 +3401,8 @@ fn load_cmd(code: &[u8]) -> Cmd {
     cmd
 }
 
-fn eval_cmd(hash: &str, keys: &Vec<&[u8]>, args: &Vec<&[u8]>) -> Cmd {
-    let mut cmd = redis::cmd("EVALSHA");
+fn eval_cmd(hash: &str, keys: &Vec<&[u8]>, args: &Vec<&[u8]>, read_only: bool) -> Cmd {
+    let mut cmd = redis::cmd(if read_only { "EVALSHA_RO" } else { "EVALSHA" });
     cmd.arg(hash).arg(keys.len());
     for key in keys {
         cmd.arg(key);
@@ -1500,6 +3424,7 @@ async fn create_cluster_client(
     push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     iam_token_manager: Option<&Arc<crate::iam::IAMTokenManager>>,
     pubsub_synchronizer: Arc<dyn crate::pubsub::PubSubSynchronizer>,
+    circuit_breakers: Option<Arc<ClusterCircuitBreakers>>,
 ) -> RedisResult<redis::cluster_async::ClusterConnection> {
     let tls_mode = request.tls_mode.unwrap_or_default();
 
@@ -1557,6 +3482,7 @@ async fn create_cluster_client(
     } else {
         (None, None)
     };
+    let tls_params = get_tls_params_with_overrides(&request, tls_params);
     let periodic_topology_checks = match request.periodic_checks {
         Some(PeriodicCheck::Disabled) => None,
         Some(PeriodicCheck::Enabled) => Some(DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL),
@@ -1587,10 +3513,14 @@ async fn create_cluster_client(
             ReadFromReplicaStrategy::AZAffinityReplicasAndPrimary(az)
         }
         ReadFrom::PreferReplica => ReadFromReplicaStrategy::RoundRobin,
+        ReadFrom::LowestLatency => ReadFromReplicaStrategy::LowestLatency,
         ReadFrom::Primary => ReadFromReplicaStrategy::AlwaysFromPrimary,
     });
     if let Some(interval_duration) = periodic_topology_checks {
         builder = builder.periodic_topology_checks(interval_duration);
+        if let Some(max_jitter) = request.periodic_checks_max_jitter {
+            builder = builder.periodic_topology_checks_jitter(max_jitter);
+        }
     }
     builder = builder.use_protocol(request.protocol.unwrap_or_default());
     builder = builder.database_id(valkey_connection_info.db);
@@ -1600,6 +3530,9 @@ async fn create_cluster_client(
     if let Some(lib_name) = valkey_connection_info.lib_name {
         builder = builder.lib_name(lib_name);
     }
+    builder = builder.client_no_evict(valkey_connection_info.client_no_evict);
+    builder = builder.client_no_touch(valkey_connection_info.client_no_touch);
+    builder = builder.resp3_fallback(valkey_connection_info.resp3_fallback);
     if tls_mode != TlsMode::NoTls {
         let tls = if tls_mode == TlsMode::SecureTls {
             redis::cluster::TlsMode::Secure
@@ -1610,6 +3543,27 @@ async fn create_cluster_client(
         if let Some(certs) = tls_certificates {
             builder = builder.certs(certs);
         }
+        if let Some(server_name) = request.tls_server_name.clone() {
+            builder = builder.tls_server_name(server_name);
+        }
+        if request.tls_skip_hostname_verification {
+            builder = builder.tls_skip_hostname_verification();
+        }
+    }
+    builder = builder
+        .replica_selection_policy(request.replica_selection_policy)
+        .read_routing_stats(request.read_routing_stats.clone())
+        .redirect_stats(request.redirect_stats.clone())
+        .report_redirect_events(request.report_redirect_events);
+    if let Some(listener) = request.connection_event_listener.clone() {
+        builder = builder.connection_event_listener(listener);
+    }
+    if let Some(circuit_breakers) = circuit_breakers {
+        builder = builder.circuit_breakers(circuit_breakers);
+    }
+    #[cfg(feature = "fault-injection")]
+    if let Some(fault_injector) = request.fault_injector.clone() {
+        builder = builder.fault_injector(fault_injector);
     }
 
     let retry_strategy = match request.connection_retry_strategy {
@@ -1627,6 +3581,17 @@ async fn create_cluster_client(
         builder.refresh_topology_from_initial_nodes(request.refresh_topology_from_initial_nodes);
 
     builder = builder.tcp_nodelay(request.tcp_nodelay);
+    builder = builder.socket_timeouts(request.socket_timeouts);
+    builder = builder.tcp_keepalive(request.tcp_keepalive);
+    if let Some(heartbeat_interval) = request.heartbeat_interval {
+        builder = builder.heartbeat_interval(heartbeat_interval);
+    }
+    builder = builder.connection_recycling(request.connection_recycling);
+    request.address_translation.validate()?;
+    builder = builder.address_translation(request.address_translation.clone());
+    if let Some(proxy) = &request.proxy {
+        builder = builder.proxy(proxy.clone());
+    }
 
     // Always use with Glide
     builder = builder.periodic_connections_checks(Some(CONNECTION_CHECKS_INTERVAL));
@@ -1773,6 +3738,7 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
                 match rfr {
                     ReadFrom::Primary => "Only primary",
                     ReadFrom::PreferReplica => "Prefer replica",
+                    ReadFrom::LowestLatency => "Prefer replica with the lowest latency",
                     ReadFrom::AZAffinity(_) => "Prefer replica in user's availability zone",
                     ReadFrom::AZAffinityReplicasAndPrimary(_) =>
                         "Prefer replica and primary in user's availability zone",
@@ -1807,20 +3773,135 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
     } else {
         String::new()
     };
-
-    let pubsub_subscriptions = request
-        .pubsub_subscriptions
+    let periodic_checks_jitter = request
+        .periodic_checks_max_jitter
+        .filter(|_| request.cluster_mode_enabled)
+        .map(|max_jitter| format!("\nPeriodic Checks jitter: up to {max_jitter:?}"))
+        .unwrap_or_default();
+
+    let pubsub_subscriptions = request
+        .pubsub_subscriptions
+        .as_ref()
+        .map(|pubsub_subscriptions| format!("\nPubsub subscriptions: {pubsub_subscriptions:?}"))
+        .unwrap_or_default();
+
+    let inflight_requests_limit = format_optional_value(
+        "\nInflight requests limit: {}",
+        request.inflight_requests_limit,
+    );
+
+    let connection_pool_size =
+        format_optional_value("Connection pool size", request.connection_pool_size);
+    let tls_server_name =
+        format_optional_value("TLS server name", request.tls_server_name.as_deref());
+    let retry_reads_on_primary = if request.retry_reads_on_primary {
+        "\nRetry reads on primary: Enabled"
+    } else {
+        ""
+    };
+    let sentinel_master_name = format_optional_value(
+        "Sentinel master name",
+        request
+            .sentinel
+            .as_ref()
+            .map(|sentinel| sentinel.master_name.as_str()),
+    );
+    let circuit_breaker = request
+        .circuit_breaker
+        .map(|config| {
+            format!(
+                "\nCircuit breaker: failure threshold {}, cooldown {}ms",
+                config.failure_threshold,
+                config.cooldown.as_millis()
+            )
+        })
+        .unwrap_or_default();
+    let tls_skip_hostname_verification = if request.tls_skip_hostname_verification {
+        "\nTLS hostname verification: Disabled"
+    } else {
+        ""
+    };
+    let socket_read_timeout = request
+        .socket_timeouts
+        .read_timeout
+        .map(|timeout| format!("\nSocket read timeout: {}ms", timeout.as_millis()))
+        .unwrap_or_default();
+    let socket_write_timeout = request
+        .socket_timeouts
+        .write_timeout
+        .map(|timeout| format!("\nSocket write timeout: {}ms", timeout.as_millis()))
+        .unwrap_or_default();
+    let tcp_keepalive = if request.tcp_keepalive != redis::TcpKeepaliveConfig::default() {
+        format!("\nTCP keepalive: {:?}", request.tcp_keepalive)
+    } else {
+        String::new()
+    };
+    let heartbeat_interval = request
+        .heartbeat_interval
+        .map(|interval| format!("\nHeartbeat interval: {}ms", interval.as_millis()))
+        .unwrap_or_default();
+    let max_connection_age = request
+        .connection_recycling
+        .max_age
+        .map(|age| format!("\nMax connection age: {}ms", age.as_millis()))
+        .unwrap_or_default();
+    let connection_idle_timeout = request
+        .connection_recycling
+        .idle_timeout
+        .map(|timeout| format!("\nConnection idle timeout: {}ms", timeout.as_millis()))
+        .unwrap_or_default();
+    let address_translation = if request.address_translation.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\nAddress translation: {} addresses",
+            request.address_translation.len()
+        )
+    };
+    let read_your_writes = request
+        .read_your_writes
         .as_ref()
-        .map(|pubsub_subscriptions| format!("\nPubsub subscriptions: {pubsub_subscriptions:?}"))
+        .map(|read_your_writes| {
+            format!(
+                "\nRead your writes: WAIT {} {}ms",
+                read_your_writes.num_replicas,
+                read_your_writes.timeout.as_millis()
+            )
+        })
         .unwrap_or_default();
-
-    let inflight_requests_limit = format_optional_value(
-        "\nInflight requests limit: {}",
-        request.inflight_requests_limit,
-    );
+    let proxy = request
+        .proxy
+        .as_ref()
+        .map(|proxy| {
+            format!(
+                "\nProxy: {:?} {}:{}",
+                proxy.proxy_type, proxy.host, proxy.port
+            )
+        })
+        .unwrap_or_default();
+    let client_no_evict = if request.client_no_evict {
+        "\nClient no-evict: Enabled"
+    } else {
+        ""
+    };
+    let client_no_touch = if request.client_no_touch {
+        "\nClient no-touch: Enabled"
+    } else {
+        ""
+    };
+    let resp3_fallback = if request.resp3_fallback {
+        "\nRESP3 fallback: Enabled"
+    } else {
+        ""
+    };
+    let json_path_fallback = if request.json_path_fallback {
+        "\nJSON path fallback: Enabled"
+    } else {
+        ""
+    };
 
     format!(
-        "\nAddresses: {addresses}{tls_mode}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{pubsub_subscriptions}{inflight_requests_limit}",
+        "\nAddresses: {addresses}{tls_mode}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{periodic_checks_jitter}{pubsub_subscriptions}{inflight_requests_limit}{connection_pool_size}{tls_server_name}{tls_skip_hostname_verification}{retry_reads_on_primary}{sentinel_master_name}{circuit_breaker}{client_no_evict}{client_no_touch}{resp3_fallback}{json_path_fallback}{socket_read_timeout}{socket_write_timeout}{tcp_keepalive}{heartbeat_interval}{max_connection_age}{connection_idle_timeout}{address_translation}{proxy}{read_your_writes}",
     )
 }
 
@@ -1850,10 +3931,22 @@ fn create_compression_manager(
 }
 
 impl Client {
+    /// Creates a client that reports its connection events to `connection_event_listener`.
+    pub async fn new_with_connection_events(
+        mut request: ConnectionRequest,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+        connection_event_listener: Arc<dyn redis::ConnectionEventListener>,
+    ) -> Result<Self, ConnectionError> {
+        request.connection_event_listener = Some(connection_event_listener);
+        Self::new(request, push_sender).await
+    }
+
     pub async fn new(
-        request: ConnectionRequest,
+        mut request: ConnectionRequest,
         push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     ) -> Result<Self, ConnectionError> {
+        request.load_tls_files().map_err(ConnectionError::IoError)?;
+
         // Add buffer to connection_timeout to allow inner connection logic to fully execute before the outer timeout triggers
         let client_creation_timeout = request.get_connection_timeout() + Duration::from_millis(500);
 
@@ -1868,16 +3961,96 @@ impl Client {
         let inflight_requests_allowed = Arc::new(AtomicIsize::new(
             inflight_requests_limit.try_into().unwrap(),
         ));
+        let high_priority_reserved_requests = request
+            .inflight_requests_high_watermark
+            .map_or(0, |watermark| {
+                inflight_requests_limit.saturating_sub(watermark) as isize
+            });
 
         // Create compression manager from configuration
         let compression_manager = create_compression_manager(request.compression_config.clone())?;
 
+        // In Sentinel mode, only the main connection queries the Sentinel nodes and follows their events. The pool
+        // members and side connections, created from the clones of the request, connect to the nodes it discovered.
+        let discovers_sentinel_nodes =
+            request.sentinel.is_some() && request.sentinel_topology.is_none();
+        if discovers_sentinel_nodes {
+            request.sentinel_topology = Some(Default::default());
+        }
+
+        let side_connection_state = Arc::new(SideConnectionState::new(request.clone()));
+        let blocking_connections = Arc::new(BlockingConnections::new(
+            side_connection_state.clone(),
+            None,
+        ));
+        let watch_connection = Arc::new(WatchConnection::new(side_connection_state.clone()));
+        let database_connections =
+            Arc::new(DatabaseConnections::new(side_connection_state.clone()));
+        let retry_reads_on_primary = request.retry_reads_on_primary
+            && request.cluster_mode_enabled
+            && request
+                .read_from
+                .as_ref()
+                .is_some_and(|read_from| *read_from != ReadFrom::Primary);
+        let read_routing_stats = request.read_routing_stats.clone();
+        let redirect_stats = request.redirect_stats.clone();
+        let cluster_circuit_breakers = request
+            .circuit_breaker
+            .filter(|_| request.cluster_mode_enabled)
+            .map(|config| Arc::new(ClusterCircuitBreakers::new(config)));
+        let config = Arc::new(request.clone());
+        let pool_request = request
+            .connection_pool_size
+            .map(|pool_size| (pool_size, request.clone()));
+
         let reconciliation_interval = match request.pubsub_reconciliation_interval_ms {
             Some(ms) if ms > 0 => Some(Duration::from_millis(ms as u64)),
             _ => None,
         };
+        let connection_event_listener = request.connection_event_listener.clone();
+        let (token_refresh_sender, token_refresh_receiver) = mpsc::unbounded_channel();
+        let token_refresh_listener: crate::iam::TokenRefreshListener =
+            Arc::new(move |result: Result<&str, &crate::iam::GlideIAMError>| {
+                // The receiver is dropped if the client doesn't use IAM authentication.
+                let _ = token_refresh_sender
+                    .send(result.map(str::to_string).map_err(|err| err.to_string()));
+            });
 
         tokio::time::timeout(client_creation_timeout, async move {
+            // In Sentinel mode, the client connects to the nodes discovered through the Sentinel nodes.
+            let failover_monitor = match request.sentinel.clone() {
+                Some(_) if request.cluster_mode_enabled => {
+                    return Err(ConnectionError::Configuration(
+                        "Sentinel mode is only supported in standalone mode".to_string(),
+                    ));
+                }
+                Some(config) => {
+                    let topology = request.sentinel_topology.clone().unwrap_or_default();
+                    let sentinel_addresses = std::mem::take(&mut request.addresses);
+                    if !discovers_sentinel_nodes {
+                        request.addresses = topology.nodes();
+                        None
+                    } else {
+                        request.addresses =
+                            sentinel::discover_nodes(&request, &config, &sentinel_addresses)
+                                .await
+                                .map_err(|errors| {
+                                    ConnectionError::Standalone(
+                                        StandaloneClientConnectionError::FailedConnection(errors),
+                                    )
+                                })?;
+                        topology.set_nodes(request.addresses.clone());
+                        Some(sentinel::FailoverMonitor {
+                            config,
+                            sentinel_addresses,
+                            request: request.clone(),
+                            topology,
+                        })
+                    }
+                }
+                None => None,
+            };
+
             // Create shared, thread-safe wrapper for the internal client that starts as lazy
             // Arc<RwLock<T>> enables multiple async tasks to safely share and modify the client state
             let internal_client_arc =
@@ -1885,6 +4058,13 @@ impl Client {
                     config: request.clone(),
                     push_sender: push_sender.clone(),
                 }))));
+            if let Some(topology) = &request.sentinel_topology {
+                topology.register(
+                    Arc::downgrade(&internal_client_arc),
+                    request.clone(),
+                    push_sender.clone(),
+                );
+            }
 
             let initial_subscriptions = request.pubsub_subscriptions.clone();
 
@@ -1920,17 +4100,31 @@ impl Client {
                 internal_client: internal_client_arc.clone(),
                 request_timeout,
                 inflight_requests_allowed,
+                high_priority_reserved_requests,
                 compression_manager: compression_manager.clone(),
                 iam_token_manager: None,
                 pubsub_synchronizer: pubsub_synchronizer.clone(),
                 otel_metadata,
+                connection_pool: None,
+                side_connection_state,
+                blocking_connections,
+                watch_connection,
+                database_connections,
+                retry_reads_on_primary,
+                read_routing_stats,
+                redirect_stats,
+                cluster_circuit_breakers: cluster_circuit_breakers.clone(),
+                config,
+                server_capabilities: Default::default(),
+                rate_limit_deny_cache: Default::default(),
+                lock_extensions: Default::default(),
             };
 
             let client_arc = Arc::new(RwLock::new(client));
 
             // Create IAM token manager if needed
             let iam_token_manager = if let Some(auth_info) = &request.authentication_info {
-                Self::create_iam_token_manager(auth_info).await
+                Self::create_iam_token_manager(auth_info, token_refresh_listener).await
             } else {
                 None
             };
@@ -1953,6 +4147,7 @@ impl Client {
                     push_sender,
                     iam_token_manager.as_ref(),
                     pubsub_synchronizer.clone(),
+                    cluster_circuit_breakers,
                 )
                 .await
                 .map_err(ConnectionError::Cluster)?;
@@ -1976,6 +4171,10 @@ impl Client {
                 *guard = internal_client;
             }
 
+            if let Some(failover_monitor) = failover_monitor {
+                tokio::spawn(failover_monitor.run(Arc::downgrade(&internal_client_arc)));
+            }
+
             if !is_lazy {
                 pubsub_synchronizer.trigger_reconciliation();
                 if let Err(e) = pubsub_synchronizer.wait_for_sync(0, None, None, None).await {
@@ -1990,17 +4189,62 @@ impl Client {
             }
 
             // Return the client from the Arc
-            let client = {
+            let mut client = {
                 let client_guard = client_arc.read().await;
                 client_guard.clone()
             };
 
+            if let Some((pool_size, request)) = pool_request {
+                client.connection_pool = Some(Arc::new(
+                    Self::create_connection_pool(request, pool_size).await?,
+                ));
+            }
+
+            if client.iam_token_manager.is_some() {
+                let mut reauthentication_client = client.clone();
+                reauthentication_client.iam_token_manager = None;
+                tokio::spawn(Self::reauthenticate_on_token_refresh(
+                    reauthentication_client,
+                    token_refresh_receiver,
+                    connection_event_listener,
+                ));
+            }
+
+            if !is_lazy {
+                let mut capabilities_client = client.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = capabilities_client.server_capabilities(true).await {
+                        log_warn(
+                            "Client::new",
+                            format!("Failed to record the server capabilities: {err}"),
+                        );
+                    }
+                });
+            }
+
             Ok(client)
         })
         .await
         .map_err(|_| ConnectionError::Timeout)?
     }
 
+    /// Creates the pool members opened next to the client's main connection, up to a total of `pool_size` connections.
+    async fn create_connection_pool(
+        mut request: ConnectionRequest,
+        pool_size: u32,
+    ) -> Result<ConnectionPool, ConnectionError> {
+        // Pool members only serve regular commands, so they don't take part in pubsub.
+        // Their connection events would duplicate the events of the main connection.
+        request.connection_pool_size = None;
+        request.pubsub_subscriptions = None;
+        request.connection_event_listener = None;
+        let members = futures::future::try_join_all(
+            (1..pool_size).map(|_| Box::pin(Self::new(request.clone(), None))),
+        )
+        .await?;
+        Ok(ConnectionPool::new(members))
+    }
+
     /// Get the compression manager if compression is enabled
     ///
     /// # Returns
@@ -2037,6 +4281,289 @@ impl Client {
     pub fn db_namespace(&self) -> &str {
         &self.otel_metadata.db_namespace
     }
+
+    /// Returns the addresses of the nodes that served the latest `count` read commands, oldest first.
+    /// Only the reads of cluster clients, routed by their `ReadFrom` strategy, are recorded.
+    pub fn served_read_nodes(&self, count: usize) -> Vec<String> {
+        self.read_routing_stats.served_reads(count)
+    }
+
+    /// Returns the configuration of the client, with the defaults resolved for the options that weren't set, so the
+    /// wrappers don't duplicate the default values. Returns:
+    ///
+    /// ```text
+    /// cluster_mode => (boolean)
+    /// request_timeout_ms => (integer)
+    /// connection_timeout_ms => (integer)
+    /// inflight_requests_limit => (integer)
+    /// protocol => "RESP2" | "RESP3"
+    /// read_from => "Primary" | "PreferReplica" | "LowestLatency" | "AZAffinity" | "AZAffinityReplicasAndPrimary",
+    ///              the strategy in use, which is "PreferReplica" for standalone clients configured with "LowestLatency"
+    /// client_az => (string) or nil, set for the AZ affinity strategies
+    /// tls_mode => "NoTls" | "InsecureTls" | "SecureTls"
+    /// database_id => (integer)
+    /// reconnect_strategy => map of exponent_base, factor_ms, number_of_retries and jitter_percent (integers)
+    /// ```
+    pub fn effective_config(&self) -> Value {
+        let config = &self.config;
+        let entry = |key: &str, value: Value| (Value::SimpleString(key.to_string()), value);
+        let string = |value: &str| Value::SimpleString(value.to_string());
+        let read_from = config.read_from.clone().unwrap_or_default();
+        let (read_from, client_az) = match &read_from {
+            ReadFrom::Primary => ("Primary", None),
+            ReadFrom::PreferReplica => ("PreferReplica", None),
+            // Standalone clients fall back to `PreferReplica`, as they don't measure the latencies of the nodes.
+            ReadFrom::LowestLatency if !config.cluster_mode_enabled => ("PreferReplica", None),
+            ReadFrom::LowestLatency => ("LowestLatency", None),
+            ReadFrom::AZAffinity(az) => ("AZAffinity", Some(az)),
+            ReadFrom::AZAffinityReplicasAndPrimary(az) => {
+                ("AZAffinityReplicasAndPrimary", Some(az))
+            }
+        };
+        let reconnect_strategy = match config.connection_retry_strategy {
+            Some(strategy) => RetryStrategy::new(
+                strategy.exponent_base,
+                strategy.factor,
+                strategy.number_of_retries,
+                strategy.jitter_percent,
+            ),
+            None => RetryStrategy::default(),
+        };
+        Value::Map(vec![
+            entry("cluster_mode", Value::Boolean(config.cluster_mode_enabled)),
+            entry(
+                "request_timeout_ms",
+                Value::Int(self.request_timeout.as_millis() as i64),
+            ),
+            entry(
+                "connection_timeout_ms",
+                Value::Int(config.get_connection_timeout().as_millis() as i64),
+            ),
+            entry(
+                "inflight_requests_limit",
+                Value::Int(
+                    config
+                        .inflight_requests_limit
+                        .unwrap_or(DEFAULT_MAX_INFLIGHT_REQUESTS)
+                        .into(),
+                ),
+            ),
+            entry(
+                "protocol",
+                string(&format!("{:?}", config.protocol.unwrap_or_default())),
+            ),
+            entry("read_from", string(read_from)),
+            entry("client_az", client_az.map_or(Value::Nil, |az| string(az))),
+            entry(
+                "tls_mode",
+                string(&format!("{:?}", config.tls_mode.unwrap_or_default())),
+            ),
+            entry("database_id", Value::Int(config.database_id)),
+            entry(
+                "reconnect_strategy",
+                Value::Map(vec![
+                    entry(
+                        "exponent_base",
+                        Value::Int(reconnect_strategy.exponent_base().into()),
+                    ),
+                    entry("factor_ms", Value::Int(reconnect_strategy.factor().into())),
+                    entry(
+                        "number_of_retries",
+                        Value::Int(reconnect_strategy.number_of_retries().into()),
+                    ),
+                    entry(
+                        "jitter_percent",
+                        Value::Int(reconnect_strategy.jitter_percent().into()),
+                    ),
+                ]),
+            ),
+        ])
+    }
+
+    /// Returns the statistics shared by all clients, such as the total number of connections and clients, together
+    /// with the number of connections held by this client. Returns:
+    ///
+    /// ```text
+    /// total_connections => (integer)
+    /// total_clients => (integer)
+    /// total_values_compressed, total_values_decompressed => (integer)
+    /// total_original_bytes, total_bytes_compressed, total_bytes_decompressed => (integer)
+    /// compression_skipped_count => (integer)
+    /// subscription_out_of_sync_count, subscription_last_sync_timestamp => (integer)
+    /// primary_fallback_reads_count => (integer)
+    /// circuit_breaker_opened_count, circuit_breaker_rejected_count => (integer)
+    /// pubsub_messages_dropped_count => (integer)
+    /// cluster_scan_cursors_reaped_count => (integer)
+    /// connections_recycled_max_age_count, connections_recycled_idle_count => (integer)
+    /// client_connections => (integer)
+    /// redirects_moved, redirects_ask => (integer)
+    /// redirects => { "host:port" => { moved => (integer), ask => (integer) } }
+    /// connections => { "host:port" => {
+    ///     primary => (boolean), connected => (boolean), age_ms => (integer) or nil,
+    ///     commands_served => (integer), reconnects => (integer), last_error => (string) or nil } }
+    /// ```
+    ///
+    /// `connections` holds the state of the connection to each node of a standalone client, and is empty for cluster
+    /// clients.
+    pub async fn statistics(&self) -> RedisResult<Value> {
+        // A lazy client that didn't connect yet has no connections, and isn't connected just to report them.
+        let client = self.internal_client.read().await.clone();
+        let (client_connections, connection_stats) = match client {
+            ClientWrapper::Standalone(client) => {
+                (client.connections_count(), client.connection_stats())
+            }
+            ClientWrapper::Cluster { mut client } => (client.connections_count().await?, vec![]),
+            ClientWrapper::Lazy(_) => (0, vec![]),
+        };
+        let redirects = self.redirect_stats.total();
+        let entry = |key: &str, value: usize| {
+            (
+                Value::SimpleString(key.to_string()),
+                Value::Int(value as i64),
+            )
+        };
+        Ok(Value::Map(vec![
+            entry("total_connections", Telemetry::total_connections()),
+            entry("total_clients", Telemetry::total_clients()),
+            entry(
+                "total_values_compressed",
+                Telemetry::total_values_compressed(),
+            ),
+            entry(
+                "total_values_decompressed",
+                Telemetry::total_values_decompressed(),
+            ),
+            entry("total_original_bytes", Telemetry::total_original_bytes()),
+            entry(
+                "total_bytes_compressed",
+                Telemetry::total_bytes_compressed(),
+            ),
+            entry(
+                "total_bytes_decompressed",
+                Telemetry::total_bytes_decompressed(),
+            ),
+            entry(
+                "compression_skipped_count",
+                Telemetry::compression_skipped_count(),
+            ),
+            entry(
+                "subscription_out_of_sync_count",
+                Telemetry::subscription_out_of_sync_count(),
+            ),
+            entry(
+                "subscription_last_sync_timestamp",
+                Telemetry::subscription_last_sync_timestamp() as usize,
+            ),
+            entry(
+                "primary_fallback_reads_count",
+                Telemetry::primary_fallback_reads_count(),
+            ),
+            entry(
+                "circuit_breaker_opened_count",
+                Telemetry::circuit_breaker_opened_count(),
+            ),
+            entry(
+                "circuit_breaker_rejected_count",
+                Telemetry::circuit_breaker_rejected_count(),
+            ),
+            entry(
+                "pubsub_messages_dropped_count",
+                Telemetry::pubsub_messages_dropped_count(),
+            ),
+            entry(
+                "cluster_scan_cursors_reaped_count",
+                Telemetry::cluster_scan_cursors_reaped_count(),
+            ),
+            entry(
+                "connections_recycled_max_age_count",
+                Telemetry::connections_recycled_max_age_count(),
+            ),
+            entry(
+                "connections_recycled_idle_count",
+                Telemetry::connections_recycled_idle_count(),
+            ),
+            entry("client_connections", client_connections),
+            entry("redirects_moved", redirects.moved as usize),
+            entry("redirects_ask", redirects.ask as usize),
+            (
+                Value::SimpleString("redirects".to_string()),
+                Value::Map(
+                    self.redirect_stats
+                        .redirects()
+                        .into_iter()
+                        .map(|(address, node_redirects)| {
+                            (
+                                Value::BulkString(address.into_bytes()),
+                                Value::Map(vec![
+                                    entry("moved", node_redirects.moved as usize),
+                                    entry("ask", node_redirects.ask as usize),
+                                ]),
+                            )
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                Value::SimpleString("connections".to_string()),
+                Value::Map(
+                    connection_stats
+                        .into_iter()
+                        .map(|(stats, primary)| {
+                            let field = |key: &str, value: Value| {
+                                (Value::SimpleString(key.to_string()), value)
+                            };
+                            (
+                                Value::BulkString(stats.address.into_bytes()),
+                                Value::Map(vec![
+                                    field("primary", Value::Boolean(primary)),
+                                    field("connected", Value::Boolean(stats.connected)),
+                                    field(
+                                        "age_ms",
+                                        stats.age.map_or(Value::Nil, |age| {
+                                            Value::Int(age.as_millis() as i64)
+                                        }),
+                                    ),
+                                    field(
+                                        "commands_served",
+                                        Value::Int(stats.commands_served as i64),
+                                    ),
+                                    field("reconnects", Value::Int(stats.reconnects as i64)),
+                                    field(
+                                        "last_error",
+                                        stats.last_error.map_or(Value::Nil, |error| {
+                                            Value::BulkString(error.into_bytes())
+                                        }),
+                                    ),
+                                ]),
+                            )
+                        })
+                        .collect(),
+                ),
+            ),
+        ]))
+    }
+
+    /// Returns the smoothed round-trip latency of each node of a cluster client, in microseconds, keyed by the node's
+    /// address. The latencies are measured by the reads served by replicas, and by the periodic checks when reading
+    /// with `ReadFrom::LowestLatency`. Returns:
+    ///
+    /// ```text
+    /// "host:port" => (integer)
+    /// ```
+    pub fn node_latencies(&self) -> Value {
+        Value::Map(
+            self.read_routing_stats
+                .latencies()
+                .into_iter()
+                .map(|(address, latency)| {
+                    (
+                        Value::BulkString(address.into_bytes()),
+                        Value::Int(latency.as_micros() as i64),
+                    )
+                })
+                .collect(),
+        )
+    }
 }
 
 pub trait GlideClientForTests {
@@ -2087,14 +4614,30 @@ mod tests {
 
     use redis::Cmd;
 
-    use crate::client::types::{ConnectionRequest, NodeAddress, OTelMetadata};
+    use crate::client::types::{ConnectionRequest, FailoverOptions, NodeAddress, OTelMetadata};
     use crate::client::{
         BLOCKING_CMD_TIMEOUT_EXTENSION, RequestTimeoutOption, TimeUnit, get_request_timeout,
     };
 
-    use super::{Client, ClientWrapper, LazyClient, get_timeout_from_cmd_arg};
+    use super::{
+        BlockingConnections, Client, ClientWrapper, DatabaseConnections, LazyClient,
+        SideConnectionState, WatchConnection, deadline_from_unix_millis, get_timeout_from_cmd_arg,
+    };
     use std::sync::Weak;
 
+    #[test]
+    fn test_deadline_from_unix_millis() {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let start = std::time::Instant::now();
+        let deadline = deadline_from_unix_millis(now_ms + 60_000);
+        assert!(deadline > start + Duration::from_secs(59));
+        assert!(deadline <= std::time::Instant::now() + Duration::from_secs(60));
+        assert!(deadline_from_unix_millis(now_ms - 60_000) <= std::time::Instant::now());
+    }
+
     #[test]
     fn test_get_timeout_from_cmd_returns_correct_duration_int() {
         let mut cmd = Cmd::new();
@@ -2329,6 +4872,14 @@ mod tests {
             ..Default::default()
         };
 
+        let side_connection_state = Arc::new(SideConnectionState::new(config.clone()));
+        let blocking_connections = Arc::new(BlockingConnections::new(
+            side_connection_state.clone(),
+            None,
+        ));
+        let watch_connection = Arc::new(WatchConnection::new(side_connection_state.clone()));
+        let database_connections =
+            Arc::new(DatabaseConnections::new(side_connection_state.clone()));
         let lazy_client = LazyClient {
             config,
             push_sender: None,
@@ -2350,6 +4901,7 @@ mod tests {
             internal_client: Arc::new(RwLock::new(ClientWrapper::Lazy(Box::new(lazy_client)))),
             request_timeout: Duration::from_millis(250),
             inflight_requests_allowed: Arc::new(AtomicIsize::new(1000)),
+            high_priority_reserved_requests: 0,
             iam_token_manager: None,
             compression_manager: None,
             pubsub_synchronizer,
@@ -2360,6 +4912,108 @@ mod tests {
                 },
                 db_namespace: "0".to_string(),
             },
+            connection_pool: None,
+            side_connection_state,
+            blocking_connections,
+            watch_connection,
+            database_connections,
+            retry_reads_on_primary: false,
+            read_routing_stats: Default::default(),
+            redirect_stats: Default::default(),
+            cluster_circuit_breakers: None,
+            config: Default::default(),
+            server_capabilities: Default::default(),
+            rate_limit_deny_cache: Default::default(),
+            lock_extensions: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_reserve_inflight_request_with_priority() {
+        use crate::client::types::RequestPriority;
+        use std::sync::Arc;
+        use std::sync::atomic::AtomicIsize;
+
+        let mut client = create_test_client();
+        client.inflight_requests_allowed = Arc::new(AtomicIsize::new(3));
+        client.high_priority_reserved_requests = 2;
+
+        assert!(client.reserve_inflight_request_with_priority(RequestPriority::Normal));
+        // The remaining slots are reserved for high-priority requests.
+        assert!(!client.reserve_inflight_request());
+        assert!(client.reserve_inflight_request_with_priority(RequestPriority::High));
+        assert!(client.reserve_inflight_request_with_priority(RequestPriority::High));
+        assert!(!client.reserve_inflight_request_with_priority(RequestPriority::High));
+
+        client.release_inflight_request();
+        assert!(!client.reserve_inflight_request());
+        client.release_inflight_request();
+        client.release_inflight_request();
+        assert!(client.reserve_inflight_request());
+    }
+
+    #[test]
+    fn test_effective_config_resolves_defaults() {
+        let mut client = create_test_client();
+        client.config = std::sync::Arc::new(ConnectionRequest {
+            read_from: Some(crate::client::ReadFrom::AZAffinity("use-1a".to_string())),
+            ..Default::default()
+        });
+
+        let redis::Value::Map(config) = client.effective_config() else {
+            panic!("Expected a map");
+        };
+        let get = |key: &str| {
+            config
+                .iter()
+                .find(|(name, _)| *name == redis::Value::SimpleString(key.to_string()))
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        assert_eq!(get("request_timeout_ms"), redis::Value::Int(250));
+        assert_eq!(get("connection_timeout_ms"), redis::Value::Int(2000));
+        assert_eq!(
+            get("inflight_requests_limit"),
+            redis::Value::Int(super::DEFAULT_MAX_INFLIGHT_REQUESTS.into())
+        );
+        assert_eq!(
+            get("protocol"),
+            redis::Value::SimpleString("RESP3".to_string())
+        );
+        assert_eq!(
+            get("client_az"),
+            redis::Value::SimpleString("use-1a".to_string())
+        );
+        assert_eq!(
+            get("tls_mode"),
+            redis::Value::SimpleString("NoTls".to_string())
+        );
+    }
+
+    #[test]
+    fn test_effective_config_reports_the_read_strategy_in_use() {
+        let mut client = create_test_client();
+        let read_from = |client: &Client| {
+            let redis::Value::Map(config) = client.effective_config() else {
+                panic!("Expected a map");
+            };
+            config
+                .into_iter()
+                .find(|(name, _)| *name == redis::Value::SimpleString("read_from".to_string()))
+                .map(|(_, value)| value)
+                .unwrap()
+        };
+        for (cluster_mode_enabled, expected) in [(false, "PreferReplica"), (true, "LowestLatency")]
+        {
+            client.config = std::sync::Arc::new(ConnectionRequest {
+                cluster_mode_enabled,
+                read_from: Some(crate::client::ReadFrom::LowestLatency),
+                ..Default::default()
+            });
+            assert_eq!(
+                read_from(&client),
+                redis::Value::SimpleString(expected.to_string())
+            );
         }
     }
 
@@ -2551,4 +5205,276 @@ mod tests {
         assert_eq!(password, None);
         assert_eq!(client_name, None);
     }
+
+    #[test]
+    fn test_primary_routing_for_read() {
+        use redis::cluster_routing::{Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr};
+
+        let replica_route = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(
+            Route::new(42, SlotAddr::ReplicaOptional),
+        ));
+        assert!(matches!(
+            super::primary_routing_for_read(&replica_route),
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route)))
+                if route.slot() == 42 && route.slot_addr() == SlotAddr::Master
+        ));
+        assert!(matches!(
+            super::primary_routing_for_read(&RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::Random
+            )),
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::RandomPrimary
+            ))
+        ));
+        let primary_route = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(
+            Route::new(42, SlotAddr::Master),
+        ));
+        assert!(super::primary_routing_for_read(&primary_route).is_none());
+    }
+
+    #[test]
+    fn test_can_send_in_pipeline() {
+        use super::ReadYourWrites;
+        use std::sync::Arc;
+
+        let get = redis::cmd("GET").arg("key").clone();
+        let set = redis::cmd("SET").arg("key").arg("value").clone();
+        let json_get = redis::cmd("JSON.GET").arg("key").clone();
+
+        let mut client = create_test_client();
+        assert!(client.can_send_in_pipeline(&get));
+        assert!(client.can_send_in_pipeline(&json_get));
+
+        client.retry_reads_on_primary = true;
+        assert!(!client.can_send_in_pipeline(&get));
+        assert!(client.can_send_in_pipeline(&set));
+
+        let mut client = create_test_client();
+        client.config = Arc::new(ConnectionRequest {
+            json_path_fallback: true,
+            ..Default::default()
+        });
+        assert!(!client.can_send_in_pipeline(&json_get));
+        assert!(client.can_send_in_pipeline(&get));
+
+        client.config = Arc::new(ConnectionRequest {
+            read_your_writes: Some(ReadYourWrites::new(1, Duration::from_millis(100))),
+            ..Default::default()
+        });
+        assert!(!client.can_send_in_pipeline(&get));
+        assert!(!client.can_send_in_pipeline(&set));
+    }
+
+    #[test]
+    fn test_is_multi_key_split_command() {
+        assert!(super::is_multi_key_split_command(
+            redis::cmd("MGET").arg("a").arg("b")
+        ));
+        assert!(super::is_multi_key_split_command(
+            redis::cmd("MSET").arg("a").arg(1)
+        ));
+        assert!(!super::is_multi_key_split_command(
+            redis::cmd("SUNION").arg("a").arg("b")
+        ));
+    }
+
+    #[test]
+    fn test_restore_cmd() {
+        let cmd = super::restore_cmd(b"key", 0, b"value", true, true);
+        let args: Vec<&[u8]> = cmd
+            .args_iter()
+            .filter_map(|arg| match arg {
+                redis::Arg::Simple(arg) => Some(arg),
+                redis::Arg::Cursor => None,
+            })
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                b"RESTORE".as_slice(),
+                b"key",
+                b"0",
+                b"value",
+                b"REPLACE",
+                b"ABSTTL"
+            ]
+        );
+        assert_eq!(
+            super::restore_cmd(b"key", 10, b"value", false, false)
+                .args_iter()
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_failover_cmd() {
+        let args = |cmd: Cmd| -> Vec<Vec<u8>> {
+            cmd.args_iter()
+                .filter_map(|arg| match arg {
+                    redis::Arg::Simple(arg) => Some(arg.to_vec()),
+                    redis::Arg::Cursor => None,
+                })
+                .collect()
+        };
+        let options = FailoverOptions {
+            target: Some(("replica".to_string(), 6380)),
+            force: true,
+            abort: false,
+            timeout_ms: Some(100),
+        };
+        assert_eq!(
+            args(super::failover_cmd(&options).unwrap()),
+            vec![
+                b"FAILOVER".to_vec(),
+                b"TO".to_vec(),
+                b"replica".to_vec(),
+                b"6380".to_vec(),
+                b"FORCE".to_vec(),
+                b"TIMEOUT".to_vec(),
+                b"100".to_vec(),
+            ]
+        );
+
+        let abort = FailoverOptions {
+            abort: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            args(super::failover_cmd(&abort).unwrap()),
+            vec![b"FAILOVER".to_vec(), b"ABORT".to_vec()]
+        );
+        assert!(
+            super::failover_cmd(&FailoverOptions {
+                timeout_ms: Some(100),
+                ..abort
+            })
+            .is_err()
+        );
+        assert!(
+            super::failover_cmd(&FailoverOptions {
+                timeout_ms: None,
+                ..options
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_flush_report() {
+        let report = super::flush_report(vec![
+            ("node1:6379".to_string(), Ok(redis::Value::Okay)),
+            (
+                "node2:6379".to_string(),
+                Err(redis::RedisError::from((
+                    redis::ErrorKind::IoError,
+                    "Connection dropped",
+                ))),
+            ),
+        ]);
+
+        let redis::Value::Map(report) = report else {
+            panic!("Expected a map, got {report:?}");
+        };
+        assert_eq!(
+            report[0].1,
+            redis::Value::Array(vec![redis::Value::BulkString(b"node1:6379".to_vec())])
+        );
+        let redis::Value::Map(failed) = &report[1].1 else {
+            panic!("Expected a map of failed nodes");
+        };
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            failed[0].0,
+            redis::Value::BulkString(b"node2:6379".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_config_report() {
+        use redis::Value;
+
+        let config = |value: &str| {
+            Value::Array(vec![
+                Value::BulkString(b"maxmemory-policy".to_vec()),
+                Value::BulkString(value.as_bytes().to_vec()),
+            ])
+        };
+        let report = super::config_report(vec![
+            ("node3:6379".to_string(), Ok(config("noeviction"))),
+            ("node1:6379".to_string(), Ok(config("allkeys-lru"))),
+            ("node2:6379".to_string(), Ok(config("noeviction"))),
+            (
+                "node4:6379".to_string(),
+                Err(redis::RedisError::from((
+                    redis::ErrorKind::IoError,
+                    "Connection dropped",
+                ))),
+            ),
+        ]);
+
+        let Value::Map(report) = report else {
+            panic!("Expected a map, got {report:?}");
+        };
+        let Value::Map(values) = &report[0].1 else {
+            panic!("Expected a map of values");
+        };
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0].0, Value::BulkString(b"node1:6379".to_vec()));
+        assert_eq!(
+            report[1].1,
+            Value::Array(vec![Value::BulkString(b"node1:6379".to_vec())])
+        );
+        let Value::Map(failed) = &report[2].1 else {
+            panic!("Expected a map of failed nodes");
+        };
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, Value::BulkString(b"node4:6379".to_vec()));
+    }
+
+    #[test]
+    fn test_is_stale_read_error() {
+        let loading = redis::RedisError::from((redis::ErrorKind::BusyLoadingError, "loading"));
+        assert!(super::is_stale_read_error(&loading));
+        let other = redis::RedisError::from((redis::ErrorKind::ResponseError, "error"));
+        assert!(!super::is_stale_read_error(&other));
+    }
+
+    #[test]
+    fn test_key_in_slot_of() {
+        use redis::cluster_topology::get_slot;
+
+        let tagged = super::key_in_slot_of(b"{user:1}:visits", "tmp").unwrap();
+        assert_eq!(tagged, b"{user:1}tmp".to_vec());
+        let untagged = super::key_in_slot_of(b"visits", "tmp").unwrap();
+        assert_eq!(untagged, b"{visits}tmp".to_vec());
+        assert_eq!(get_slot(&untagged), get_slot(b"visits"));
+        // The hashed part of this key contains a closing brace, so it can't be used as a hash tag.
+        assert_eq!(super::key_in_slot_of(b"{}visits}", "tmp"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resp3_fallback_is_ignored_with_subscriptions() {
+        let mut request = ConnectionRequest {
+            resp3_fallback: true,
+            ..Default::default()
+        };
+        assert!(
+            super::get_valkey_connection_info(&request, None)
+                .await
+                .resp3_fallback
+        );
+
+        let mut subscriptions = redis::PubSubSubscriptionInfo::new();
+        subscriptions.insert(
+            redis::PubSubSubscriptionKind::Exact,
+            [b"channel".to_vec()].into(),
+        );
+        request.pubsub_subscriptions = Some(subscriptions);
+        assert!(
+            !super::get_valkey_connection_info(&request, None)
+                .await
+                .resp3_fallback
+        );
+    }
 }
@@ -0,0 +1,258 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Read-your-writes sessions: every write command, batch and transaction is followed by `WAIT` on the connections of
+//! the primaries of the slots it wrote, so it completes once the replicas acknowledged it, and the reads of the slots
+//! whose latest write wasn't acknowledged by enough replicas are sent to the primary instead of a replica.
+
+use super::{Client, ClientWrapper, get_request_timeout, run_with_timeout};
+use logger_core::log_warn;
+use redis::cluster_routing::{
+    Routable, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr, command_keys, is_readonly_cmd,
+};
+use redis::cluster_topology::get_slot;
+use redis::{Cmd, Pipeline, RedisResult, Value};
+use std::collections::{BTreeSet, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The configuration and the state of a read-your-writes session. The clones of a session share its state, so the
+/// pool members and side connections of a client route their reads the same way.
+#[derive(Clone, Debug)]
+pub struct ReadYourWrites {
+    /// The number of replicas that must acknowledge a write before the reads of its slot may be served by replicas.
+    /// Replicas can't be told apart in the reply of `WAIT`, so this should be the number of replicas of each shard
+    /// for every replica to be known to have the write.
+    pub num_replicas: u32,
+    /// How long `WAIT` waits for the acknowledgements. Zero waits until the replicas acknowledged the write.
+    pub timeout: Duration,
+    /// The slots whose latest write wasn't acknowledged by `num_replicas` replicas.
+    unacknowledged_slots: Arc<Mutex<HashSet<u16>>>,
+}
+
+impl PartialEq for ReadYourWrites {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_replicas == other.num_replicas && self.timeout == other.timeout
+    }
+}
+
+impl ReadYourWrites {
+    pub fn new(num_replicas: u32, timeout: Duration) -> Self {
+        Self {
+            num_replicas,
+            timeout,
+            unacknowledged_slots: Default::default(),
+        }
+    }
+
+    /// Returns the slots of the keys written by `cmd`, and the slot it's explicitly routed to, for the write commands
+    /// that are followed by `WAIT`. Keyless writes without a slot route aren't tracked.
+    pub(super) fn written_slots(cmd: &Cmd, routing: Option<&RoutingInfo>) -> BTreeSet<u16> {
+        let mut slots = BTreeSet::new();
+        if cmd.command().is_none_or(|name| is_readonly_cmd(&name)) {
+            return slots;
+        }
+        slots.extend(command_keys(cmd).into_iter().map(get_slot));
+        if let Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))) = routing {
+            slots.insert(route.slot());
+        }
+        slots
+    }
+
+    /// Returns the slots written by the commands of a batch or a transaction.
+    pub(super) fn batch_written_slots(
+        pipeline: &Pipeline,
+        routing: Option<&RoutingInfo>,
+    ) -> BTreeSet<u16> {
+        pipeline
+            .cmd_iter()
+            .flat_map(|cmd| Self::written_slots(cmd, routing))
+            .collect()
+    }
+
+    /// Whether `cmd` reads a slot whose latest write wasn't acknowledged by enough replicas, so it must be sent to the
+    /// primary.
+    pub(super) fn requires_primary(&self, cmd: &Cmd) -> bool {
+        if !cmd.command().is_some_and(|name| is_readonly_cmd(&name)) {
+            return false;
+        }
+        let unacknowledged_slots = self.unacknowledged_slots.lock().unwrap();
+        command_keys(cmd)
+            .into_iter()
+            .any(|key| unacknowledged_slots.contains(&get_slot(key)))
+    }
+
+    fn wait_command(&self) -> Cmd {
+        let mut wait = redis::cmd("WAIT");
+        wait.arg(self.num_replicas)
+            .arg(self.timeout.as_millis() as u64);
+        wait
+    }
+
+    /// Records the number of replicas that acknowledged the latest write to `slot`.
+    fn record_acknowledgements(&self, slot: u16, acknowledged: i64) {
+        let mut unacknowledged_slots = self.unacknowledged_slots.lock().unwrap();
+        if acknowledged >= self.num_replicas as i64 {
+            unacknowledged_slots.remove(&slot);
+        } else {
+            unacknowledged_slots.insert(slot);
+        }
+    }
+}
+
+/// Returns the number of replicas that acknowledged a write in the reply of `WAIT`, or zero if it failed.
+fn acknowledgements(result: RedisResult<Value>) -> i64 {
+    match result {
+        Ok(Value::Int(acknowledged)) => acknowledged,
+        Ok(value) => {
+            log_warn(
+                "read_your_writes",
+                format!("Unexpected response for WAIT: {value:?}"),
+            );
+            0
+        }
+        Err(err) => {
+            log_warn("read_your_writes", format!("WAIT failed: {err}"));
+            0
+        }
+    }
+}
+
+impl Client {
+    /// Sends `WAIT` after a write to `slots`, on the connections of their primaries that sent the write, and records
+    /// how many replicas acknowledged it. A failed `WAIT` is recorded as unacknowledged instead of failing the write,
+    /// which succeeded.
+    pub(super) async fn wait_for_replicas(
+        &mut self,
+        read_your_writes: &ReadYourWrites,
+        slots: &BTreeSet<u16>,
+    ) {
+        if slots.is_empty() {
+            return;
+        }
+        let wait = read_your_writes.wait_command();
+        let result = async {
+            let request_timeout = get_request_timeout(&wait, self.request_timeout)?;
+            let client = self.get_or_initialize_client().await?;
+            run_with_timeout(request_timeout, async {
+                match client {
+                    ClientWrapper::Standalone(mut client) => {
+                        let acknowledged = acknowledgements(client.send_command(&wait).await);
+                        Ok(slots.iter().map(|slot| (*slot, acknowledged)).collect())
+                    }
+                    ClientWrapper::Cluster { client } => {
+                        // Each primary counts the acknowledgements of the writes sent on its own connection.
+                        let waits = slots.iter().map(|slot| {
+                            let mut client = client.clone();
+                            let wait = &wait;
+                            async move {
+                                let routing =
+                                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(
+                                        Route::new(*slot, SlotAddr::Master),
+                                    ));
+                                let result = client.route_command(wait, routing).await;
+                                (*slot, acknowledgements(result))
+                            }
+                        });
+                        Ok(futures::future::join_all(waits).await)
+                    }
+                    ClientWrapper::Lazy(_) => {
+                        unreachable!("Lazy client should have been initialized")
+                    }
+                }
+            })
+            .await
+        }
+        .await;
+        let acknowledgements: Vec<(u16, i64)> = result.unwrap_or_else(|err| {
+            log_warn("read_your_writes", format!("WAIT failed: {err}"));
+            slots.iter().map(|slot| (*slot, 0)).collect()
+        });
+        for (slot, acknowledged) in acknowledgements {
+            read_your_writes.record_acknowledgements(slot, acknowledged);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> Cmd {
+        let mut cmd = redis::cmd(args[0]);
+        for arg in &args[1..] {
+            cmd.arg(*arg);
+        }
+        cmd
+    }
+
+    #[test]
+    fn test_reads_of_unacknowledged_slots_require_the_primary() {
+        let read_your_writes = ReadYourWrites::new(2, Duration::from_millis(100));
+        let set = command(&["SET", "key", "value"]);
+        let get = command(&["GET", "key"]);
+        let slot = get_slot(b"key");
+        assert_eq!(ReadYourWrites::written_slots(&set, None), [slot].into());
+        assert!(ReadYourWrites::written_slots(&get, None).is_empty());
+        assert!(ReadYourWrites::written_slots(&command(&["PING"]), None).is_empty());
+        assert!(!read_your_writes.requires_primary(&get));
+
+        // The clones of the session share its state.
+        let clone = read_your_writes.clone();
+        clone.record_acknowledgements(slot, 1);
+        assert!(read_your_writes.requires_primary(&get));
+        assert!(!read_your_writes.requires_primary(&set));
+        assert!(!read_your_writes.requires_primary(&command(&["GET", "other"])));
+
+        read_your_writes.record_acknowledgements(slot, 2);
+        assert!(!clone.requires_primary(&get));
+    }
+
+    #[test]
+    fn test_every_written_slot_is_tracked() {
+        let slots = |keys: &[&str]| keys.iter().map(|key| get_slot(key.as_bytes())).collect();
+        assert_eq!(
+            ReadYourWrites::written_slots(&command(&["MSET", "a", "1", "b", "2"]), None),
+            slots(&["a", "b"])
+        );
+        assert_eq!(
+            ReadYourWrites::written_slots(&command(&["DEL", "a", "b", "c"]), None),
+            slots(&["a", "b", "c"])
+        );
+        assert_eq!(
+            ReadYourWrites::written_slots(&command(&["SUNIONSTORE", "a", "b", "c"]), None),
+            slots(&["a", "b", "c"])
+        );
+        let routing = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route::new(
+            7,
+            SlotAddr::Master,
+        )));
+        assert_eq!(
+            ReadYourWrites::written_slots(&command(&["FLUSHDB"]), Some(&routing)),
+            [7].into()
+        );
+
+        let mut pipeline = Pipeline::new();
+        pipeline
+            .add_command(command(&["SET", "a", "1"]))
+            .add_command(command(&["GET", "b"]))
+            .add_command(command(&["UNLINK", "c"]));
+        assert_eq!(
+            ReadYourWrites::batch_written_slots(&pipeline, None),
+            slots(&["a", "c"])
+        );
+
+        let read_your_writes = ReadYourWrites::new(1, Duration::ZERO);
+        read_your_writes.record_acknowledgements(get_slot(b"b"), 0);
+        assert!(read_your_writes.requires_primary(&command(&["MGET", "a", "b"])));
+        assert!(!read_your_writes.requires_primary(&command(&["MGET", "a", "c"])));
+    }
+
+    #[test]
+    fn test_wait_command() {
+        let read_your_writes = ReadYourWrites::new(1, Duration::from_millis(500));
+        assert_eq!(
+            read_your_writes.wait_command().get_packed_command(),
+            command(&["WAIT", "1", "500"]).get_packed_command()
+        );
+    }
+}
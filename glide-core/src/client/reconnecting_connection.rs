@@ -6,14 +6,15 @@ use futures_intrusive::sync::ManualResetEvent;
 use logger_core::{log_debug, log_error, log_trace, log_warn};
 use redis::aio::{DisconnectNotifier, MultiplexedConnection};
 use redis::{
-    GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
+    ConnectionEvent, ConnectionRecyclingPolicy, GlideConnectionOptions, PushInfo, RecycleReason,
+    RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
 };
 use std::fmt;
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{RwLock, RwLockReadGuard};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use telemetrylib::Telemetry;
 use tokio::sync::{Notify, mpsc};
 use tokio::task;
@@ -54,9 +55,36 @@ enum ConnectionState {
     InitializedDisconnected,
 }
 
+/// The usage of a connection, reported in the statistics of standalone clients.
+#[derive(Default)]
+struct ConnectionMetrics {
+    /// When the current connection was established, or `None` while disconnected.
+    connected_at: Mutex<Option<Instant>>,
+    /// When a command last received a reply, for the idle timeout of the connection recycling policy.
+    last_used: Mutex<Option<Instant>>,
+    commands_served: AtomicU64,
+    reconnects: AtomicU64,
+    last_error: Mutex<Option<String>>,
+}
+
+/// A snapshot of the state of a connection to a node.
+#[derive(Clone, Debug)]
+pub(crate) struct ConnectionStats {
+    pub(crate) address: String,
+    pub(crate) connected: bool,
+    /// How long the current connection has been established, or `None` while disconnected.
+    pub(crate) age: Option<Duration>,
+    /// The number of commands that received a reply, including error replies.
+    pub(crate) commands_served: u64,
+    /// The number of times the connection was reestablished.
+    pub(crate) reconnects: u64,
+    pub(crate) last_error: Option<String>,
+}
+
 struct InnerReconnectingConnection {
     state: Mutex<ConnectionState>,
     backend: ConnectionBackend,
+    metrics: ConnectionMetrics,
 }
 
 #[derive(Clone)]
@@ -117,6 +145,7 @@ impl TokioDisconnectNotifier {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn create_connection(
     connection_backend: ConnectionBackend,
     retry_strategy: RetryStrategy,
@@ -124,7 +153,11 @@ async fn create_connection(
     discover_az: bool,
     connection_timeout: Duration,
     tcp_nodelay: bool,
+    socket_timeouts: redis::SocketTimeouts,
+    tcp_keepalive: redis::TcpKeepaliveConfig,
+    proxy: Option<redis::ProxyConfig>,
     pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
+    connection_event_listener: Option<Arc<dyn redis::ConnectionEventListener>>,
 ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
     let client = {
         let guard = connection_backend
@@ -144,6 +177,10 @@ async fn create_connection(
         connection_retry_strategy: Some(retry_strategy),
         tcp_nodelay,
         pubsub_synchronizer,
+        connection_event_listener,
+        socket_timeouts,
+        tcp_keepalive,
+        proxy,
     };
 
     // Wrap retry loop in timeout so total time respects connection_timeout
@@ -183,13 +220,19 @@ async fn create_connection(
                 ),
             );
             Telemetry::incr_total_connections(1);
-            Ok(ReconnectingConnection {
+            let connection = ReconnectingConnection {
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::Connected(connection)),
                     backend: connection_backend,
+                    metrics: ConnectionMetrics {
+                        connected_at: Mutex::new(Some(Instant::now())),
+                        ..Default::default()
+                    },
                 }),
                 connection_options,
-            })
+            };
+            connection.notify_connection_event(ConnectionEvent::Connected, None);
+            Ok(connection)
         }
         err => {
             let err: RedisError = match err {
@@ -210,9 +253,15 @@ async fn create_connection(
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::InitializedDisconnected),
                     backend: connection_backend,
+                    metrics: ConnectionMetrics {
+                        last_error: Mutex::new(Some(err.to_string())),
+                        ..Default::default()
+                    },
                 }),
                 connection_options,
             };
+            connection
+                .notify_connection_event(ConnectionEvent::Disconnected, Some(&err.to_string()));
             connection.reconnect(ReconnectReason::CreateError);
             Err((connection, err))
         }
@@ -251,7 +300,11 @@ impl ReconnectingConnection {
         connection_timeout: Duration,
         tls_params: Option<redis::TlsConnParams>,
         tcp_nodelay: bool,
+        socket_timeouts: redis::SocketTimeouts,
+        tcp_keepalive: redis::TcpKeepaliveConfig,
+        proxy: Option<redis::ProxyConfig>,
         pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
+        connection_event_listener: Option<Arc<dyn redis::ConnectionEventListener>>,
     ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
         log_debug(
             "connection creation",
@@ -271,11 +324,21 @@ impl ReconnectingConnection {
             discover_az,
             connection_timeout,
             tcp_nodelay,
+            socket_timeouts,
+            tcp_keepalive,
+            proxy,
             pubsub_synchronizer,
+            connection_event_listener,
         )
         .await
     }
 
+    fn notify_connection_event(&self, event: ConnectionEvent, error: Option<&str>) {
+        if let Some(listener) = &self.connection_options.connection_event_listener {
+            listener.on_connection_event(event, &self.node_address(), error);
+        }
+    }
+
     pub(crate) fn node_address(&self) -> String {
         self.inner
             .backend
@@ -335,15 +398,20 @@ impl ReconnectingConnection {
             *guard = ConnectionState::Reconnecting;
         };
         log_debug("reconnect", "starting");
+        *self.inner.metrics.connected_at.lock().unwrap() = None;
 
         let connection_clone = self.clone();
 
         if reason.eq(&ReconnectReason::ConnectionDropped) {
+            let last_error = self.inner.metrics.last_error.lock().unwrap().clone();
+            self.notify_connection_event(ConnectionEvent::Disconnected, last_error.as_deref());
             // Attempting to reconnect a connection that was dropped (for any reason) - update the telemetry by reducing
             // the number of opened connections by 1, it will be incremented by 1 after a successful re-connect
             Telemetry::decr_total_connections(1);
         }
 
+        self.notify_connection_event(ConnectionEvent::Reconnecting, None);
+
         // The reconnect task is spawned instead of awaited here, so that the reconnect attempt will continue in the
         // background, regardless of whether the calling task is dropped or not.
         task::spawn(async move {
@@ -391,8 +459,12 @@ impl ReconnectingConnection {
                                 .set();
                             *guard = ConnectionState::Connected(connection);
                         }
+                        let metrics = &connection_clone.inner.metrics;
+                        *metrics.connected_at.lock().unwrap() = Some(Instant::now());
+                        metrics.reconnects.fetch_add(1, Ordering::Relaxed);
 
                         Telemetry::incr_total_connections(1);
+                        connection_clone.notify_connection_event(ConnectionEvent::Connected, None);
                         return;
                     }
                     Err(_) => tokio::time::sleep(sleep_duration).await,
@@ -401,6 +473,99 @@ impl ReconnectingConnection {
         });
     }
 
+    /// Records the reply of `commands` commands sent on the connection, and the error if the request failed.
+    pub(super) fn record_result<T>(&self, result: &RedisResult<T>, commands: u64) {
+        let metrics = &self.inner.metrics;
+        *metrics.last_used.lock().unwrap() = Some(Instant::now());
+        match result {
+            Ok(_) => {
+                metrics
+                    .commands_served
+                    .fetch_add(commands, Ordering::Relaxed);
+            }
+            Err(err) => {
+                if !err.is_unrecoverable_error() {
+                    metrics.commands_served.fetch_add(1, Ordering::Relaxed);
+                }
+                *metrics.last_error.lock().unwrap() = Some(err.to_string());
+            }
+        }
+    }
+
+    /// Returns why the connection should be recycled according to `policy`, if it should. Connections that aren't
+    /// established aren't recycled.
+    pub(super) fn recycle_reason(
+        &self,
+        policy: &ConnectionRecyclingPolicy,
+    ) -> Option<RecycleReason> {
+        let metrics = &self.inner.metrics;
+        let connected_at = (*metrics.connected_at.lock().unwrap())?;
+        let last_used = metrics
+            .last_used
+            .lock()
+            .unwrap()
+            .map_or(connected_at, |last_used| last_used.max(connected_at));
+        policy.recycle_reason(connected_at.elapsed(), last_used.elapsed())
+    }
+
+    /// Replaces the connection because it reached a limit of the connection recycling policy, reporting it to the
+    /// connection event listener and in the statistics. The replacement is opened and verified with `PING` while the
+    /// current connection keeps serving the commands, and swapped in once it's ready. If it can't be opened, or the
+    /// current connection was lost meanwhile, the connection isn't recycled, and `false` is returned.
+    pub(super) async fn recycle(&self, reason: RecycleReason) -> bool {
+        let client = self.inner.backend.get_backend_client().clone();
+        let replacement = match get_multiplexed_connection(&client, &self.connection_options).await
+        {
+            Ok(mut connection) => connection
+                .send_packed_command(&redis::cmd("PING"))
+                .await
+                .map(|_| connection),
+            Err(err) => Err(err),
+        };
+        let replacement = match replacement {
+            Ok(replacement) => replacement,
+            Err(err) => {
+                log_warn(
+                    "recycle",
+                    format!("Keeping the connection, failed to open its replacement: {err}"),
+                );
+                return false;
+            }
+        };
+        {
+            let mut guard = self.inner.state.lock().unwrap();
+            if !matches!(*guard, ConnectionState::Connected(_)) {
+                // The connection is being reestablished.
+                return false;
+            }
+            *guard = ConnectionState::Connected(replacement);
+        }
+        *self.inner.metrics.connected_at.lock().unwrap() = Some(Instant::now());
+        match reason {
+            RecycleReason::MaxAge => Telemetry::incr_connections_recycled_max_age(),
+            RecycleReason::IdleTimeout => Telemetry::incr_connections_recycled_idle(),
+        };
+        self.notify_connection_event(ConnectionEvent::Recycled, Some(reason.description()));
+        true
+    }
+
+    /// Returns a snapshot of the state of the connection.
+    pub(super) fn stats(&self) -> ConnectionStats {
+        let metrics = &self.inner.metrics;
+        ConnectionStats {
+            address: self.node_address(),
+            connected: self.is_connected(),
+            age: metrics
+                .connected_at
+                .lock()
+                .unwrap()
+                .map(|connected_at| connected_at.elapsed()),
+            commands_served: metrics.commands_served.load(Ordering::Relaxed),
+            reconnects: metrics.reconnects.load(Ordering::Relaxed),
+            last_error: metrics.last_error.lock().unwrap().clone(),
+        }
+    }
+
     pub fn is_connected(&self) -> bool {
         !matches!(
             *self.inner.state.lock().unwrap(),
@@ -0,0 +1,207 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::cluster_routing::{Routable, RoutingInfo};
+use redis::{Cmd, RedisError, RedisResult, Value};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::oneshot;
+
+const LOCK_ERR: &str = "Failed to acquire the request deduplicator lock";
+
+type Waiters = Vec<oneshot::Sender<RedisResult<Value>>>;
+
+/// Statistics of the requests handled by a [`RequestDeduplicator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeduplicationStatistics {
+    /// Number of requests that could be deduplicated.
+    pub requests_count: u64,
+    /// Number of requests that were answered by the round trip of an identical in-flight request.
+    pub hits_count: u64,
+}
+
+/// Collapses concurrent identical read requests into a single round trip to the server ("singleflight").
+///
+/// The first request for a command is sent to the server, and identical commands that arrive while it's in flight
+/// wait for its response instead of being sent. Only commands whose response doesn't depend on the caller,
+/// and that don't change the server state, are deduplicated.
+#[derive(Default)]
+pub struct RequestDeduplicator {
+    in_flight: Mutex<HashMap<Vec<u8>, Waiters>>,
+    requests_count: AtomicU64,
+    hits_count: AtomicU64,
+}
+
+/// Removes the in-flight entry of a request if it's dropped before completing,
+/// so its waiters stop waiting and send the command themselves.
+struct InFlightGuard<'a> {
+    deduplicator: &'a RequestDeduplicator,
+    key: Option<Vec<u8>>,
+}
+
+impl InFlightGuard<'_> {
+    fn complete(mut self) -> Waiters {
+        let key = self.key.take().expect("The request was already completed");
+        self.deduplicator.remove(&key)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.deduplicator.remove(&key);
+        }
+    }
+}
+
+impl RequestDeduplicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the command can be deduplicated. Only `GET` and `HGETALL` without an explicit route are deduplicated.
+    pub fn can_deduplicate(cmd: &Cmd, routing: Option<&RoutingInfo>) -> bool {
+        routing.is_none()
+            && matches!(
+                cmd.command().unwrap_or_default().as_slice(),
+                b"GET" | b"HGETALL"
+            )
+    }
+
+    /// Awaits `request` and returns its response, unless an identical command is already in flight,
+    /// in which case `request` is dropped and the response of the in-flight command is returned instead.
+    pub async fn deduplicate<F>(&self, cmd: &Cmd, request: F) -> RedisResult<Value>
+    where
+        F: Future<Output = RedisResult<Value>>,
+    {
+        self.requests_count.fetch_add(1, Ordering::Relaxed);
+        let key = cmd.get_packed_command();
+        let receiver = {
+            let mut in_flight = self.in_flight.lock().expect(LOCK_ERR);
+            match in_flight.get_mut(&key) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    in_flight.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+
+        if let Some(receiver) = receiver {
+            match receiver.await {
+                Ok(result) => {
+                    self.hits_count.fetch_add(1, Ordering::Relaxed);
+                    return result;
+                }
+                // The in-flight request was dropped before completing.
+                Err(_) => return request.await,
+            }
+        }
+
+        let guard = InFlightGuard {
+            deduplicator: self,
+            key: Some(key),
+        };
+        let result = request.await;
+        for waiter in guard.complete() {
+            // The waiter may have stopped waiting for the response.
+            let _ = waiter.send(clone_result(&result));
+        }
+        result
+    }
+
+    pub fn statistics(&self) -> DeduplicationStatistics {
+        DeduplicationStatistics {
+            requests_count: self.requests_count.load(Ordering::Relaxed),
+            hits_count: self.hits_count.load(Ordering::Relaxed),
+        }
+    }
+
+    fn remove(&self, key: &[u8]) -> Waiters {
+        self.in_flight
+            .lock()
+            .expect(LOCK_ERR)
+            .remove(key)
+            .unwrap_or_default()
+    }
+}
+
+fn clone_result(result: &RedisResult<Value>) -> RedisResult<Value> {
+    match result {
+        Ok(value) => Ok(value.clone()),
+        Err(err) => Err(RedisError::from((
+            err.kind(),
+            "Deduplicated request failed",
+            err.to_string(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::cluster_routing::SingleNodeRoutingInfo;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[test]
+    fn test_can_deduplicate() {
+        assert!(RequestDeduplicator::can_deduplicate(
+            redis::cmd("GET").arg("key"),
+            None
+        ));
+        assert!(RequestDeduplicator::can_deduplicate(
+            redis::cmd("HGETALL").arg("key"),
+            None
+        ));
+        assert!(!RequestDeduplicator::can_deduplicate(
+            redis::cmd("GET").arg("key"),
+            Some(&RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
+        ));
+        assert!(!RequestDeduplicator::can_deduplicate(
+            redis::cmd("SET").arg("key").arg("value"),
+            None
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_identical_requests_are_deduplicated() {
+        let deduplicator = RequestDeduplicator::new();
+        let sent_count = AtomicUsize::new(0);
+        let send = |value: &'static str| {
+            let sent_count = &sent_count;
+            async move {
+                sent_count.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Ok(Value::BulkString(value.into()))
+            }
+        };
+
+        let get_key = redis::cmd("GET").arg("key").clone();
+        let get_other_key = redis::cmd("GET").arg("other_key").clone();
+        let results = futures::future::join_all([
+            deduplicator.deduplicate(&get_key, send("value")),
+            deduplicator.deduplicate(&get_key, send("value")),
+            deduplicator.deduplicate(&get_key, send("value")),
+            deduplicator.deduplicate(&get_other_key, send("other_value")),
+        ])
+        .await;
+
+        assert_eq!(sent_count.load(Ordering::SeqCst), 2);
+        assert_eq!(results[2], Ok(Value::BulkString(b"value".to_vec())));
+        assert_eq!(results[3], Ok(Value::BulkString(b"other_value".to_vec())));
+        assert_eq!(
+            deduplicator.statistics(),
+            DeduplicationStatistics {
+                requests_count: 4,
+                hits_count: 2,
+            }
+        );
+        assert!(deduplicator.in_flight.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,433 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Sentinel mode: the addresses of the connection request point at Sentinel nodes, which are queried for the current
+//! primary and replicas of the monitored primary. The client connects to the discovered nodes as a standalone client,
+//! and a [`FailoverMonitor`] follows the failover and replica events of the Sentinel nodes, so the client reconnects
+//! to the new primary after a failover, and to the current replicas when they change.
+//!
+//! The pool members and side connections of the client connect to the nodes discovered by its main connection,
+//! through the [`SentinelTopology`] shared by the clones of the connection request, and the monitor of the main
+//! connection replaces all of them when the nodes change.
+
+use super::types::{NodeAddress, SentinelConfig, TlsMode};
+use super::{
+    ClientWrapper, ConnectionRequest, LazyClient, get_connection_info,
+    get_tls_params_with_overrides,
+};
+use futures::StreamExt;
+use logger_core::{log_info, log_warn};
+use redis::{
+    ConnectionInfo, ErrorKind, GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisError,
+    RedisResult,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::{RwLock, mpsc};
+
+const SWITCH_MASTER_CHANNEL: &str = "+switch-master";
+/// The events announcing that a replica was added, went down or came back, or was reconfigured after a failover.
+const REPLICA_CHANNELS: [&str; 4] = ["+slave", "+sdown", "-sdown", "+slave-reconf-done"];
+/// How often the monitor checks whether the client was dropped while waiting for events.
+const CLIENT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+/// Delay before resubscribing after the connections to all the Sentinel nodes were lost.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// Returns the connection info of a Sentinel node, which is connected with the TLS settings of the request.
+fn sentinel_connection_info(
+    request: &ConnectionRequest,
+    config: &SentinelConfig,
+    address: &NodeAddress,
+) -> RedisResult<ConnectionInfo> {
+    let tls_mode = request.tls_mode.unwrap_or_default();
+    let has_root_certs = !request.root_certs.is_empty();
+    let has_client_cert = !request.client_cert.is_empty() && !request.client_key.is_empty();
+    let tls_params = if tls_mode != TlsMode::NoTls && (has_root_certs || has_client_cert) {
+        Some(redis::retrieve_tls_certificates(redis::TlsCertificates {
+            client_tls: has_client_cert.then(|| redis::ClientTlsConfig {
+                client_cert: request.client_cert.clone(),
+                client_key: request.client_key.clone(),
+            }),
+            root_cert: has_root_certs.then(|| request.root_certs.concat()),
+        })?)
+    } else {
+        None
+    };
+    Ok(get_connection_info(
+        address,
+        tls_mode,
+        RedisConnectionInfo {
+            username: config.username.clone(),
+            password: config.password.clone(),
+            ..Default::default()
+        },
+        get_tls_params_with_overrides(request, tls_params),
+    ))
+}
+
+/// Returns true if the Sentinel flags of a node mark it as unreachable.
+fn is_node_down(flags: &str) -> bool {
+    flags
+        .split(',')
+        .any(|flag| matches!(flag, "s_down" | "o_down" | "disconnected"))
+}
+
+async fn query_sentinel(
+    request: &ConnectionRequest,
+    config: &SentinelConfig,
+    sentinel_address: &NodeAddress,
+) -> RedisResult<Vec<NodeAddress>> {
+    let timeout = request.get_connection_timeout();
+    let client = redis::Client::open(sentinel_connection_info(request, config, sentinel_address)?)?;
+    let mut connection = client
+        .get_multiplexed_async_connection_with_timeouts(
+            timeout,
+            timeout,
+            GlideConnectionOptions::default(),
+        )
+        .await?;
+
+    let primary: Option<(String, u16)> = redis::cmd("SENTINEL")
+        .arg("GET-MASTER-ADDR-BY-NAME")
+        .arg(&config.master_name)
+        .query_async(&mut connection)
+        .await?;
+    let Some((host, port)) = primary else {
+        return Err(RedisError::from((
+            ErrorKind::MasterNameNotFoundBySentinel,
+            "Master with given name not found in sentinel",
+            config.master_name.clone(),
+        )));
+    };
+    let replicas: Vec<HashMap<String, String>> = redis::cmd("SENTINEL")
+        .arg("REPLICAS")
+        .arg(&config.master_name)
+        .query_async(&mut connection)
+        .await?;
+
+    let mut nodes = vec![NodeAddress { host, port }];
+    nodes.extend(replicas.into_iter().filter_map(|replica| {
+        if replica
+            .get("flags")
+            .is_some_and(|flags| is_node_down(flags))
+        {
+            return None;
+        }
+        Some(NodeAddress {
+            host: replica.get("ip")?.clone(),
+            port: replica.get("port")?.parse().ok()?,
+        })
+    }));
+    Ok(nodes)
+}
+
+/// Queries the Sentinel nodes, in order, for the addresses of the primary and the reachable replicas of the
+/// monitored primary, and returns them with the primary first. Fails with the error of each Sentinel node if none
+/// of them answered.
+pub(super) async fn discover_nodes(
+    request: &ConnectionRequest,
+    config: &SentinelConfig,
+    sentinel_addresses: &[NodeAddress],
+) -> Result<Vec<NodeAddress>, Vec<(Option<String>, RedisError)>> {
+    let mut errors = Vec::with_capacity(sentinel_addresses.len());
+    for address in sentinel_addresses {
+        match query_sentinel(request, config, address).await {
+            Ok(nodes) => return Ok(nodes),
+            Err(err) => errors.push((Some(format!("{}:{}", address.host, address.port)), err)),
+        }
+    }
+    Err(errors)
+}
+
+/// Returns true if the primary or the set of replicas differ between two lists of nodes, each with the primary first.
+fn nodes_changed(current: &[NodeAddress], discovered: &[NodeAddress]) -> bool {
+    let sorted_replicas = |nodes: &[NodeAddress]| {
+        let mut replicas: Vec<(String, u16)> = nodes
+            .iter()
+            .skip(1)
+            .map(|node| (node.host.clone(), node.port))
+            .collect();
+        replicas.sort_unstable();
+        replicas
+    };
+    match (current.first(), discovered.first()) {
+        (Some(current_primary), Some(discovered_primary)) => {
+            current_primary.host != discovered_primary.host
+                || current_primary.port != discovered_primary.port
+                || sorted_replicas(current) != sorted_replicas(discovered)
+        }
+        _ => true,
+    }
+}
+
+/// Returns true if a Sentinel event concerns the monitored primary or one of its replicas.
+fn is_event_of(channel: &str, payload: &str, master_name: &str) -> bool {
+    let mut words = payload.split(' ');
+    if channel == SWITCH_MASTER_CHANNEL {
+        // `<master name> <old ip> <old port> <new ip> <new port>`
+        return words.next() == Some(master_name);
+    }
+    // `master <name> <ip> <port>`, or `<instance type> <name> <ip> <port> @ <master name> <master ip> <master port>`.
+    if payload.starts_with("master ") {
+        return words.nth(1) == Some(master_name);
+    }
+    words.skip_while(|word| *word != "@").nth(1) == Some(master_name)
+}
+
+/// The nodes discovered through the Sentinel nodes, and the clients connected to them: the main connection of a
+/// client in Sentinel mode, and its pool members and side connections, which connect to these nodes instead of
+/// querying the Sentinel nodes themselves.
+#[derive(Default)]
+pub struct SentinelTopology {
+    nodes: Mutex<Vec<NodeAddress>>,
+    /// The registered clients, with the lazy clients that replace them when the nodes change.
+    clients: Mutex<Vec<(Weak<RwLock<ClientWrapper>>, LazyClient)>>,
+}
+
+impl fmt::Debug for SentinelTopology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SentinelTopology")
+            .field("nodes", &self.nodes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SentinelTopology {
+    /// The discovered nodes, with the primary first.
+    pub(super) fn nodes(&self) -> Vec<NodeAddress> {
+        self.nodes.lock().unwrap().clone()
+    }
+
+    pub(super) fn set_nodes(&self, nodes: Vec<NodeAddress>) {
+        *self.nodes.lock().unwrap() = nodes;
+    }
+
+    /// Registers a client connected to the discovered nodes, to be replaced with a lazy client created from its
+    /// connection request when the nodes change.
+    pub(super) fn register(
+        &self,
+        internal_client: Weak<RwLock<ClientWrapper>>,
+        mut request: ConnectionRequest,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    ) {
+        // The topology holds the requests of its clients, so they don't hold it back.
+        request.sentinel_topology = None;
+        let mut clients = self.clients.lock().unwrap();
+        clients.retain(|(client, _)| client.strong_count() > 0);
+        clients.push((
+            internal_client,
+            LazyClient {
+                config: request,
+                push_sender,
+            },
+        ));
+    }
+
+    /// Stores the new nodes, and replaces every registered client with a lazy client for them, which connects on
+    /// the next command.
+    async fn replace_nodes(&self, nodes: Vec<NodeAddress>) {
+        self.set_nodes(nodes.clone());
+        let clients: Vec<_> = {
+            let mut clients = self.clients.lock().unwrap();
+            clients.retain(|(client, _)| client.strong_count() > 0);
+            clients
+                .iter_mut()
+                .filter_map(|(client, lazy_client)| {
+                    lazy_client.config.addresses = nodes.clone();
+                    Some((client.upgrade()?, lazy_client.clone()))
+                })
+                .collect()
+        };
+        for (client, lazy_client) in clients {
+            *client.write().await = ClientWrapper::Lazy(Box::new(lazy_client));
+        }
+    }
+}
+
+/// Follows the failovers of the monitored primary and the changes of its replicas, by subscribing to
+/// `+switch-master` and the replica events on one Sentinel node at a time.
+///
+/// When the primary or the replicas change, the nodes are discovered again and every client of the topology is
+/// replaced with a lazy client for the new nodes. The monitor stops once the main client is dropped.
+pub(super) struct FailoverMonitor {
+    pub(super) config: SentinelConfig,
+    pub(super) sentinel_addresses: Vec<NodeAddress>,
+    /// The connection request of the client, used to connect to the Sentinel nodes.
+    pub(super) request: ConnectionRequest,
+    pub(super) topology: Arc<SentinelTopology>,
+}
+
+impl FailoverMonitor {
+    pub(super) async fn run(mut self, internal_client: Weak<RwLock<ClientWrapper>>) {
+        loop {
+            for address in self.sentinel_addresses.clone() {
+                if internal_client.strong_count() == 0 {
+                    return;
+                }
+                if let Err(err) = self.follow_sentinel(&address, &internal_client).await {
+                    log_warn(
+                        "sentinel",
+                        format!(
+                            "Lost the subscription to the events of {}:{}: {err}",
+                            address.host, address.port
+                        ),
+                    );
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    }
+
+    /// Listens to the failovers and replica changes announced by a Sentinel node, until the connection to it is lost or the client is dropped.
+    async fn follow_sentinel(
+        &mut self,
+        address: &NodeAddress,
+        internal_client: &Weak<RwLock<ClientWrapper>>,
+    ) -> RedisResult<()> {
+        let timeout = self.request.get_connection_timeout();
+        let client = redis::Client::open(sentinel_connection_info(
+            &self.request,
+            &self.config,
+            address,
+        )?)?;
+        let mut pubsub = tokio::time::timeout(timeout, client.get_async_pubsub())
+            .await
+            .map_err(|_| RedisError::from((ErrorKind::IoError, "Connection timed out")))??;
+        pubsub.subscribe(SWITCH_MASTER_CHANNEL).await?;
+        for channel in REPLICA_CHANNELS {
+            pubsub.subscribe(channel).await?;
+        }
+
+        // A failover or a replica change may have happened while the monitor wasn't subscribed.
+        self.refresh_nodes(internal_client).await;
+
+        let mut messages = pubsub.into_on_message();
+        loop {
+            let message = match tokio::time::timeout(CLIENT_CHECK_INTERVAL, messages.next()).await {
+                Ok(Some(message)) => message,
+                Ok(None) => {
+                    return Err(RedisError::from((
+                        ErrorKind::IoError,
+                        "Connection to the Sentinel node was closed",
+                    )));
+                }
+                Err(_) if internal_client.strong_count() == 0 => return Ok(()),
+                Err(_) => continue,
+            };
+            let channel = message.get_channel_name();
+            let payload: String = message.get_payload()?;
+            if is_event_of(channel, &payload, &self.config.master_name) {
+                log_info(
+                    "sentinel",
+                    format!(
+                        "Nodes of `{}` changed: {channel} {payload}",
+                        self.config.master_name
+                    ),
+                );
+                self.refresh_nodes(internal_client).await;
+            }
+        }
+    }
+
+    /// Discovers the nodes again, and replaces the clients of the topology if the primary or the replicas changed.
+    async fn refresh_nodes(&mut self, internal_client: &Weak<RwLock<ClientWrapper>>) {
+        let nodes =
+            match discover_nodes(&self.request, &self.config, &self.sentinel_addresses).await {
+                Ok(nodes) => nodes,
+                Err(errors) => {
+                    log_warn(
+                        "sentinel",
+                        format!("Failed to discover the nodes: {errors:?}"),
+                    );
+                    return;
+                }
+            };
+        if !nodes_changed(&self.topology.nodes(), &nodes) || internal_client.strong_count() == 0 {
+            return;
+        }
+
+        log_info(
+            "sentinel",
+            format!(
+                "Reconnecting to the primary {} and {} replicas",
+                nodes[0],
+                nodes.len() - 1
+            ),
+        );
+        self.topology.replace_nodes(nodes).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_node_down() {
+        assert!(!is_node_down("slave"));
+        assert!(is_node_down("slave,s_down"));
+        assert!(is_node_down("s_down,slave,disconnected"));
+        assert!(!is_node_down("slave,master_down_ok"));
+    }
+
+    fn node(host: &str, port: u16) -> NodeAddress {
+        NodeAddress {
+            host: host.to_string(),
+            port,
+        }
+    }
+
+    #[test]
+    fn test_nodes_changed() {
+        let nodes = [node("p", 1), node("r1", 2), node("r2", 3)];
+        assert!(!nodes_changed(
+            &nodes,
+            &[node("p", 1), node("r2", 3), node("r1", 2)]
+        ));
+        assert!(nodes_changed(
+            &nodes,
+            &[node("r1", 2), node("p", 1), node("r2", 3)]
+        ));
+        assert!(nodes_changed(&nodes, &[node("p", 1), node("r1", 2)]));
+        assert!(nodes_changed(
+            &nodes,
+            &[node("p", 1), node("r1", 2), node("r3", 4)]
+        ));
+        assert!(nodes_changed(&[], &nodes));
+    }
+
+    #[test]
+    fn test_is_event_of() {
+        assert!(is_event_of(
+            "+switch-master",
+            "mymaster 10.0.0.1 6379 10.0.0.2 6379",
+            "mymaster"
+        ));
+        assert!(!is_event_of(
+            "+switch-master",
+            "other 10.0.0.1 6379 10.0.0.2 6379",
+            "mymaster"
+        ));
+        assert!(is_event_of(
+            "+sdown",
+            "slave 10.0.0.3:6379 10.0.0.3 6379 @ mymaster 10.0.0.1 6379",
+            "mymaster"
+        ));
+        assert!(!is_event_of(
+            "+slave",
+            "slave 10.0.0.3:6379 10.0.0.3 6379 @ mymaster2 10.0.0.1 6379",
+            "mymaster"
+        ));
+        assert!(is_event_of(
+            "-sdown",
+            "master mymaster 10.0.0.1 6379",
+            "mymaster"
+        ));
+        assert!(!is_event_of(
+            "-sdown",
+            "master other 10.0.0.1 6379",
+            "mymaster"
+        ));
+    }
+}
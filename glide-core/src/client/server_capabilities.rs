@@ -0,0 +1,283 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::{ErrorKind, ProtocolVersion, RedisError, RedisResult, Value};
+use versions::Versioning;
+
+/// The version that introduced sharded pubsub and functions.
+const SHARDED_PUBSUB_AND_FUNCTIONS_VERSION: &str = "7.0";
+
+/// Builds the report of the capabilities of the nodes from the reply of `HELLO`, sent without arguments to each node,
+/// so it doesn't change the protocol of the connection.
+///
+/// The report is a map of:
+/// * `nodes` to a map of the addresses of the nodes that responded to their capabilities, as described in
+///   [`node_capabilities`].
+/// * `failed` to a map of the addresses of the nodes that failed to the error message.
+///
+/// A node that doesn't support `HELLO` can only be served with RESP2, so it's reported with `protocol` 2 and without
+/// its `server` and `version`. A node is reported as `downgraded` when the client was configured with RESP3 but the
+/// node is served with RESP2, such as when the connection fell back to RESP2.
+pub(crate) fn capabilities_report(
+    mut results: Vec<(String, RedisResult<Value>)>,
+    configured_protocol: ProtocolVersion,
+) -> Value {
+    results.sort_by(|(address, _), (other, _)| address.cmp(other));
+    let mut nodes = Vec::new();
+    let mut failed = Vec::new();
+    for (address, result) in results {
+        let address = Value::BulkString(address.into_bytes());
+        let capabilities = match result {
+            Ok(hello) => node_capabilities(hello, configured_protocol),
+            Err(err) if is_hello_unsupported(&err) => {
+                Ok(legacy_node_capabilities(configured_protocol))
+            }
+            Err(err) => Err(err),
+        };
+        match capabilities {
+            Ok(capabilities) => nodes.push((address, capabilities)),
+            Err(err) => failed.push((address, Value::SimpleString(err.to_string()))),
+        }
+    }
+    Value::Map(vec![
+        (Value::SimpleString("nodes".to_string()), Value::Map(nodes)),
+        (
+            Value::SimpleString("failed".to_string()),
+            Value::Map(failed),
+        ),
+    ])
+}
+
+/// Converts the `HELLO` reply of a node, either a RESP3 map or a RESP2 array of names and values, to a map of:
+///
+/// ```text
+/// server => "valkey"
+/// version => "8.0.1"
+/// protocol => (integer) 3
+/// modules => "search" => (integer) 10000
+/// sharded_pubsub => (boolean)
+/// functions => (boolean)
+/// downgraded => (boolean)
+/// ```
+fn node_capabilities(hello: Value, configured_protocol: ProtocolVersion) -> RedisResult<Value> {
+    let fields = match hello {
+        Value::Map(fields) => fields,
+        Value::Array(values) if values.len() % 2 == 0 => {
+            let mut values = values.into_iter();
+            std::iter::from_fn(|| Some((values.next()?, values.next()?))).collect()
+        }
+        hello => return Err(unexpected_response(&hello)),
+    };
+    let mut server = Value::Nil;
+    let mut version = None;
+    let mut protocol = Value::Nil;
+    let mut modules = Vec::new();
+    for (name, value) in fields {
+        match text(&name).as_deref() {
+            Some("server") => server = value,
+            Some("version") => version = text(&value),
+            Some("proto") => protocol = value,
+            Some("modules") => modules = module_versions(value)?,
+            _ => {}
+        }
+    }
+    let Some(version) = version else {
+        return Err(unexpected_response(&Value::Nil));
+    };
+    let supports_7_0 = match (
+        Versioning::new(&version),
+        Versioning::new(SHARDED_PUBSUB_AND_FUNCTIONS_VERSION),
+    ) {
+        (Some(version), Some(min_version)) => version >= min_version,
+        _ => false,
+    };
+    let downgraded = configured_protocol != ProtocolVersion::RESP2 && protocol == Value::Int(2);
+    Ok(capabilities_map(
+        server,
+        Value::BulkString(version.into_bytes()),
+        protocol,
+        modules,
+        supports_7_0,
+        downgraded,
+    ))
+}
+
+/// The capabilities of a node that predates `HELLO`, and so RESP3, sharded pubsub and functions.
+fn legacy_node_capabilities(configured_protocol: ProtocolVersion) -> Value {
+    capabilities_map(
+        Value::Nil,
+        Value::Nil,
+        Value::Int(2),
+        Vec::new(),
+        false,
+        configured_protocol != ProtocolVersion::RESP2,
+    )
+}
+
+fn capabilities_map(
+    server: Value,
+    version: Value,
+    protocol: Value,
+    modules: Vec<(Value, Value)>,
+    supports_7_0: bool,
+    downgraded: bool,
+) -> Value {
+    let entry = |name: &str, value: Value| (Value::SimpleString(name.to_string()), value);
+    Value::Map(vec![
+        entry("server", server),
+        entry("version", version),
+        entry("protocol", protocol),
+        entry("modules", Value::Map(modules)),
+        entry("sharded_pubsub", Value::Boolean(supports_7_0)),
+        entry("functions", Value::Boolean(supports_7_0)),
+        entry("downgraded", Value::Boolean(downgraded)),
+    ])
+}
+
+fn is_hello_unsupported(err: &RedisError) -> bool {
+    err.detail()
+        .is_some_and(|detail| detail.starts_with("unknown command `HELLO`"))
+}
+
+/// Converts the `modules` of a `HELLO` reply, an array of the `name`, `ver`, `path` and `args` of each module, to
+/// pairs of the name and version of each module.
+fn module_versions(modules: Value) -> RedisResult<Vec<(Value, Value)>> {
+    let Value::Array(modules) = modules else {
+        return Err(unexpected_response(&modules));
+    };
+    modules
+        .into_iter()
+        .map(|module| {
+            let fields = match module {
+                Value::Map(fields) => fields,
+                Value::Array(values) if values.len() % 2 == 0 => {
+                    let mut values = values.into_iter();
+                    std::iter::from_fn(|| Some((values.next()?, values.next()?))).collect()
+                }
+                module => return Err(unexpected_response(&module)),
+            };
+            let mut name = Value::Nil;
+            let mut version = Value::Nil;
+            for (field, value) in fields {
+                match text(&field).as_deref() {
+                    Some("name") => name = value,
+                    Some("ver") => version = value,
+                    _ => {}
+                }
+            }
+            Ok((name, version))
+        })
+        .collect()
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::SimpleString(text) => Some(text.clone()),
+        Value::BulkString(bytes) => String::from_utf8(bytes.clone()).ok(),
+        Value::VerbatimString { text, .. } => Some(text.clone()),
+        _ => None,
+    }
+}
+
+fn unexpected_response(value: &Value) -> RedisError {
+    RedisError::from((
+        ErrorKind::ResponseError,
+        "Unexpected response for HELLO",
+        format!("(response was {value:?})"),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_capabilities_report() {
+        let hello = Value::Array(vec![
+            bulk("server"),
+            bulk("valkey"),
+            bulk("version"),
+            bulk("8.0.1"),
+            bulk("proto"),
+            Value::Int(3),
+            bulk("modules"),
+            Value::Array(vec![Value::Map(vec![
+                (bulk("name"), bulk("search")),
+                (bulk("ver"), Value::Int(10000)),
+            ])]),
+        ]);
+        let old_hello = Value::Map(vec![
+            (bulk("version"), bulk("6.2.14")),
+            (bulk("proto"), Value::Int(2)),
+            (bulk("modules"), Value::Array(vec![])),
+        ]);
+        let report = capabilities_report(
+            vec![
+                ("node2:6379".to_string(), Ok(old_hello)),
+                (
+                    "node3:6379".to_string(),
+                    Err(RedisError::from((ErrorKind::IoError, "disconnected"))),
+                ),
+                ("node1:6379".to_string(), Ok(hello)),
+                (
+                    "node4:6379".to_string(),
+                    Err(RedisError::from((
+                        ErrorKind::ResponseError,
+                        "An error was signalled by the server",
+                        "unknown command `HELLO`, with args beginning with: ".to_string(),
+                    ))),
+                ),
+            ],
+            ProtocolVersion::RESP3,
+        );
+
+        let Value::Map(report) = report else {
+            panic!("expected a map");
+        };
+        let Value::Map(nodes) = &report[0].1 else {
+            panic!("expected a map of nodes");
+        };
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0].0, bulk("node1:6379"));
+        assert_eq!(
+            nodes[0].1,
+            Value::Map(vec![
+                (Value::SimpleString("server".into()), bulk("valkey")),
+                (Value::SimpleString("version".into()), bulk("8.0.1")),
+                (Value::SimpleString("protocol".into()), Value::Int(3)),
+                (
+                    Value::SimpleString("modules".into()),
+                    Value::Map(vec![(bulk("search"), Value::Int(10000))])
+                ),
+                (
+                    Value::SimpleString("sharded_pubsub".into()),
+                    Value::Boolean(true)
+                ),
+                (
+                    Value::SimpleString("functions".into()),
+                    Value::Boolean(true)
+                ),
+                (
+                    Value::SimpleString("downgraded".into()),
+                    Value::Boolean(false)
+                ),
+            ])
+        );
+        let Value::Map(old_node) = &nodes[1].1 else {
+            panic!("expected a map of capabilities");
+        };
+        assert_eq!(old_node[4].1, Value::Boolean(false));
+        assert_eq!(old_node[6].1, Value::Boolean(true));
+        assert_eq!(nodes[2].0, bulk("node4:6379"));
+        assert_eq!(nodes[2].1, legacy_node_capabilities(ProtocolVersion::RESP3));
+
+        let Value::Map(failed) = &report[1].1 else {
+            panic!("expected a map of failed nodes");
+        };
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, bulk("node3:6379"));
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Migration of a hash slot to another primary of the cluster, following the resharding procedure of `valkey-cli`:
+//! the target primary is set to import the slot and its current owner to migrate it, the keys of the slot are moved
+//! in batches with `MIGRATE`, and finally all the primaries are told that the target owns the slot.
+
+use super::types::NodeAddress;
+use super::{Client, ClientWrapper};
+use redis::cluster_routing::{
+    MultipleNodeRoutingInfo, ResponsePolicy, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
+};
+use redis::{Cmd, ErrorKind, Pipeline, PipelineRetryStrategy, RedisError, RedisResult, Value};
+use std::time::Duration;
+
+/// The default number of keys moved by a single `MIGRATE`.
+pub const DEFAULT_MIGRATION_BATCH_SIZE: u32 = 100;
+/// The default timeout of a single `MIGRATE`.
+pub const DEFAULT_MIGRATION_TIMEOUT: Duration = Duration::from_secs(5);
+const SLOTS_COUNT: u16 = 16384;
+
+/// Options of [`Client::migrate_slot`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotMigrationOptions {
+    /// The number of keys moved by a single `MIGRATE`. 0 uses [`DEFAULT_MIGRATION_BATCH_SIZE`].
+    pub batch_size: u32,
+    /// The timeout of a single `MIGRATE`. Zero uses [`DEFAULT_MIGRATION_TIMEOUT`].
+    pub timeout: Duration,
+    /// Whether keys that already exist on the target are replaced, instead of failing the migration.
+    pub replace: bool,
+    /// The username that `MIGRATE` authenticates with on the target.
+    pub username: Option<String>,
+    /// The password that `MIGRATE` authenticates with on the target. Required for authenticating.
+    pub password: Option<String>,
+}
+
+/// The progress of a slot migration, reported after each batch of keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SlotMigrationProgress {
+    /// Number of keys moved to the target so far.
+    pub migrated_keys: u64,
+    /// Number of keys that were in the slot when the migration started.
+    pub total_keys: u64,
+}
+
+impl SlotMigrationOptions {
+    fn migrate_command(&self, target: &NodeAddress, keys: Vec<Value>) -> Cmd {
+        let timeout = match self.timeout {
+            Duration::ZERO => DEFAULT_MIGRATION_TIMEOUT,
+            timeout => timeout,
+        };
+        let mut cmd = redis::cmd("MIGRATE");
+        cmd.arg(&target.host)
+            .arg(target.port)
+            .arg("")
+            .arg(0)
+            .arg(timeout.as_millis() as u64);
+        if self.replace {
+            cmd.arg("REPLACE");
+        }
+        match (&self.username, &self.password) {
+            (Some(username), Some(password)) => {
+                cmd.arg("AUTH2").arg(username).arg(password);
+            }
+            (None, Some(password)) => {
+                cmd.arg("AUTH").arg(password);
+            }
+            _ => {}
+        }
+        cmd.arg("KEYS");
+        for key in keys {
+            if let Value::BulkString(key) = key {
+                cmd.arg(key);
+            }
+        }
+        cmd
+    }
+}
+
+fn get_keys_in_slot_command(slot: u16, count: u32) -> Cmd {
+    let mut cmd = redis::cmd("CLUSTER");
+    cmd.arg("GETKEYSINSLOT").arg(slot).arg(count);
+    cmd
+}
+
+fn set_slot_command(slot: u16, state: &str, node_id: &str) -> Cmd {
+    let mut cmd = redis::cmd("CLUSTER");
+    cmd.arg("SETSLOT").arg(slot).arg(state).arg(node_id);
+    cmd
+}
+
+fn unexpected_response(command: &str, value: &Value) -> RedisError {
+    RedisError::from((
+        ErrorKind::ResponseError,
+        "Unexpected slot migration response",
+        format!("(response of {command} was {value:?})"),
+    ))
+}
+
+fn into_keys(value: Value) -> RedisResult<Vec<Value>> {
+    match value {
+        Value::Array(keys) => Ok(keys),
+        value => Err(unexpected_response("CLUSTER GETKEYSINSLOT", &value)),
+    }
+}
+
+impl Client {
+    /// Moves the hash slot `slot` from its current primary to the primary at `target`, and calls `on_progress` after
+    /// each batch of keys. Each round trip to the current owner moves a batch of keys with `MIGRATE` and fetches the
+    /// next batch with `CLUSTER GETKEYSINSLOT` in a single pipeline. Returns:
+    ///
+    /// ```text
+    /// migrated_keys => (integer)
+    /// batches => (integer)
+    /// ```
+    ///
+    /// A migration that fails midway leaves the slot in the migrating state, and can be resumed by calling this
+    /// function again.
+    pub async fn migrate_slot(
+        &mut self,
+        slot: u16,
+        target: NodeAddress,
+        options: SlotMigrationOptions,
+        mut on_progress: impl FnMut(SlotMigrationProgress) -> RedisResult<()> + Send,
+    ) -> RedisResult<Value> {
+        if !matches!(
+            self.get_or_initialize_client().await?,
+            ClientWrapper::Cluster { .. }
+        ) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "migrate_slot is only supported by cluster clients",
+            )));
+        }
+        if slot >= SLOTS_COUNT {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid slot",
+                format!("Slot {slot} is out of the range 0-{}", SLOTS_COUNT - 1),
+            )));
+        }
+        let batch_size = match options.batch_size {
+            0 => DEFAULT_MIGRATION_BATCH_SIZE,
+            batch_size => batch_size,
+        };
+        let source_route = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(
+            Route::new(slot, SlotAddr::Master),
+        ));
+        let target_route = RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+            host: target.host.clone(),
+            port: target.port,
+        });
+
+        let source_id = self.node_id(source_route.clone()).await?;
+        let target_id = self.node_id(target_route.clone()).await?;
+        if source_id == target_id {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid migration target",
+                format!("Slot {slot} is already owned by {target}"),
+            )));
+        }
+
+        let mut count_cmd = redis::cmd("CLUSTER");
+        count_cmd.arg("COUNTKEYSINSLOT").arg(slot);
+        let total_keys = match self
+            .send_command(&mut count_cmd, Some(source_route.clone()))
+            .await?
+        {
+            Value::Int(count) => count.max(0) as u64,
+            value => return Err(unexpected_response("CLUSTER COUNTKEYSINSLOT", &value)),
+        };
+
+        self.send_command(
+            &mut set_slot_command(slot, "IMPORTING", &source_id),
+            Some(target_route.clone()),
+        )
+        .await?;
+        self.send_command(
+            &mut set_slot_command(slot, "MIGRATING", &target_id),
+            Some(source_route.clone()),
+        )
+        .await?;
+
+        let mut progress = SlotMigrationProgress {
+            migrated_keys: 0,
+            total_keys,
+        };
+        let mut batches = 0;
+        let mut keys = into_keys(
+            self.send_command(
+                &mut get_keys_in_slot_command(slot, batch_size),
+                Some(source_route.clone()),
+            )
+            .await?,
+        )?;
+        // `MIGRATE` may take its whole timeout, on top of the round trip.
+        let pipeline_timeout = self.request_timeout
+            + match options.timeout {
+                Duration::ZERO => DEFAULT_MIGRATION_TIMEOUT,
+                timeout => timeout,
+            };
+        while !keys.is_empty() {
+            let batch_len = keys.len() as u64;
+            let mut pipeline = Pipeline::with_capacity(2);
+            pipeline
+                .add_command(options.migrate_command(&target, keys))
+                .add_command(get_keys_in_slot_command(slot, batch_size));
+            let Value::Array(mut responses) = self
+                .send_pipeline(
+                    &pipeline,
+                    Some(source_route.clone()),
+                    true,
+                    Some(pipeline_timeout.as_millis() as u32),
+                    PipelineRetryStrategy::default(),
+                )
+                .await?
+            else {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected response for slot migration batch",
+                )));
+            };
+            keys = match responses.pop() {
+                Some(next_keys) => into_keys(next_keys)?,
+                None => Vec::new(),
+            };
+            batches += 1;
+            progress.migrated_keys += batch_len;
+            on_progress(progress)?;
+        }
+
+        // The target is updated first, so it serves the slot before the source redirects the clients to it.
+        self.send_command(
+            &mut set_slot_command(slot, "NODE", &target_id),
+            Some(target_route),
+        )
+        .await?;
+        self.send_command(
+            &mut set_slot_command(slot, "NODE", &target_id),
+            Some(RoutingInfo::MultiNode((
+                MultipleNodeRoutingInfo::AllMasters,
+                Some(ResponsePolicy::AllSucceeded),
+            ))),
+        )
+        .await?;
+
+        Ok(Value::Map(vec![
+            (
+                Value::SimpleString("migrated_keys".to_string()),
+                Value::Int(progress.migrated_keys as i64),
+            ),
+            (
+                Value::SimpleString("batches".to_string()),
+                Value::Int(batches),
+            ),
+        ]))
+    }
+
+    async fn node_id(&mut self, route: RoutingInfo) -> RedisResult<String> {
+        let mut cmd = redis::cmd("CLUSTER");
+        cmd.arg("MYID");
+        match self.send_command(&mut cmd, Some(route)).await? {
+            Value::BulkString(id) => Ok(String::from_utf8_lossy(&id).into_owned()),
+            Value::SimpleString(id) => Ok(id),
+            value => Err(unexpected_response("CLUSTER MYID", &value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_command() {
+        let options = SlotMigrationOptions {
+            timeout: Duration::from_millis(2000),
+            replace: true,
+            username: Some("admin".to_string()),
+            password: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let target = NodeAddress {
+            host: "10.0.0.2".to_string(),
+            port: 6380,
+        };
+        let keys = vec![
+            Value::BulkString(b"{a}1".to_vec()),
+            Value::BulkString(b"{a}2".to_vec()),
+        ];
+        let expected = redis::cmd("MIGRATE")
+            .arg("10.0.0.2")
+            .arg(6380)
+            .arg("")
+            .arg(0)
+            .arg(2000)
+            .arg("REPLACE")
+            .arg("AUTH2")
+            .arg("admin")
+            .arg("secret")
+            .arg("KEYS")
+            .arg("{a}1")
+            .arg("{a}2")
+            .get_packed_command();
+        assert_eq!(
+            options.migrate_command(&target, keys).get_packed_command(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::command_latency::{LatencyHistogram, LatencyPercentiles};
+use redis::Cmd;
+use redis::cluster_routing::Routable;
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+const LOCK_ERR: &str = "Failed to acquire the slow command log lock";
+
+/// A command that took longer than the threshold of a [`SlowCommandLog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlowCommand {
+    /// Unique, increasing identifier of the entry, like the IDs of the server's `SLOWLOG`.
+    pub id: u64,
+    /// The command name, including the subcommand of container commands such as `CONFIG GET`.
+    pub command_name: String,
+    /// A digest of the command arguments, so identical commands can be told apart without keeping their values.
+    pub args_digest: u64,
+    /// The time from sending the command until its response was received.
+    pub duration: Duration,
+    /// The address of the node that served the command, if it's known.
+    pub node_address: Option<String>,
+}
+
+/// What a [`SlowCommandLog`] keeps of a command, taken before the command is sent, so the command itself isn't kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandSignature {
+    command_name: String,
+    args_digest: u64,
+}
+
+impl CommandSignature {
+    pub fn new(cmd: &Cmd) -> Self {
+        Self {
+            command_name: cmd
+                .command()
+                .map(|name| String::from_utf8_lossy(&name).into_owned())
+                .unwrap_or_default(),
+            args_digest: args_digest(cmd),
+        }
+    }
+}
+
+/// A bounded log of the commands whose client-side latency exceeded a threshold, a client-side analog of `SLOWLOG`.
+///
+/// Once the log holds `capacity` entries, recording a command evicts the oldest entry. The latencies of all the
+/// recorded commands, slow or not, are kept in a histogram, so the threshold can be compared with their percentiles.
+pub struct SlowCommandLog {
+    threshold: Duration,
+    capacity: usize,
+    next_id: AtomicU64,
+    entries: Mutex<VecDeque<SlowCommand>>,
+    latencies: LatencyHistogram,
+}
+
+impl SlowCommandLog {
+    pub fn new(threshold: Duration, capacity: usize) -> Self {
+        Self {
+            threshold,
+            capacity,
+            next_id: AtomicU64::new(0),
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            latencies: LatencyHistogram::new(),
+        }
+    }
+
+    /// Whether a command that took `duration` exceeds the threshold of the log.
+    pub fn is_slow(&self, duration: Duration) -> bool {
+        duration > self.threshold
+    }
+
+    /// Records the latency of the command in the histogram of the log, and adds the command to the log if
+    /// `duration` exceeds the threshold.
+    pub fn record(
+        &self,
+        signature: CommandSignature,
+        duration: Duration,
+        node_address: Option<String>,
+    ) {
+        self.latencies
+            .record(duration.as_micros().min(u64::MAX as u128) as u64);
+        if !self.is_slow(duration) || self.capacity == 0 {
+            return;
+        }
+        let entry = SlowCommand {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            command_name: signature.command_name,
+            args_digest: signature.args_digest,
+            duration,
+            node_address,
+        };
+        let mut entries = self.entries.lock().expect(LOCK_ERR);
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Returns up to `max_entries` of the recorded commands, newest first.
+    pub fn entries(&self, max_entries: usize) -> Vec<SlowCommand> {
+        self.entries
+            .lock()
+            .expect(LOCK_ERR)
+            .iter()
+            .rev()
+            .take(max_entries)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the latency percentiles of all the commands recorded since the log was created or last cleared.
+    pub fn latency_percentiles(&self) -> LatencyPercentiles {
+        self.latencies.percentiles()
+    }
+
+    /// Removes all the recorded commands and latencies.
+    pub fn clear(&self) {
+        self.entries.lock().expect(LOCK_ERR).clear();
+        self.latencies.reset();
+    }
+}
+
+fn args_digest(cmd: &Cmd) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for arg in cmd.args_iter() {
+        if let redis::Arg::Simple(arg) = arg {
+            arg.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_commands_over_the_threshold_are_recorded() {
+        let log = SlowCommandLog::new(Duration::from_millis(10), 8);
+        log.record(
+            CommandSignature::new(redis::cmd("GET").arg("key")),
+            Duration::from_millis(5),
+            None,
+        );
+        log.record(
+            CommandSignature::new(redis::cmd("CONFIG").arg("get").arg("maxmemory")),
+            Duration::from_millis(20),
+            Some("127.0.0.1:6379".to_string()),
+        );
+
+        let entries = log.entries(10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].command_name, "CONFIG GET");
+        assert_eq!(entries[0].duration, Duration::from_millis(20));
+        assert_eq!(entries[0].node_address.as_deref(), Some("127.0.0.1:6379"));
+
+        // The latencies of the commands under the threshold are kept in the histogram as well.
+        let percentiles = log.latency_percentiles();
+        assert_eq!(percentiles.count, 2);
+        assert!((5_000..5_400).contains(&percentiles.p50_us));
+        assert!((20_000..21_300).contains(&percentiles.p99_us));
+    }
+
+    #[test]
+    fn test_oldest_entries_are_evicted() {
+        let log = SlowCommandLog::new(Duration::ZERO, 2);
+        for key in ["a", "b", "c"] {
+            log.record(
+                CommandSignature::new(redis::cmd("GET").arg(key)),
+                Duration::from_millis(1),
+                None,
+            );
+        }
+
+        let entries = log.entries(10);
+        assert_eq!(
+            entries.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(
+            entries[1].args_digest,
+            args_digest(redis::cmd("GET").arg("b"))
+        );
+        assert_ne!(entries[0].args_digest, entries[1].args_digest);
+        assert_eq!(log.entries(1).len(), 1);
+
+        log.clear();
+        assert!(log.entries(10).is_empty());
+        assert_eq!(log.latency_percentiles(), LatencyPercentiles::default());
+    }
+}
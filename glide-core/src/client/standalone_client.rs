@@ -1,19 +1,21 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use super::get_valkey_connection_info;
-use super::reconnecting_connection::{ReconnectReason, ReconnectingConnection};
+use super::circuit_breaker::{Admission, CircuitBreaker, CircuitState};
+use super::reconnecting_connection::{ConnectionStats, ReconnectReason, ReconnectingConnection};
 use super::{ConnectionRequest, NodeAddress, TlsMode};
+use super::{get_tls_params_with_overrides, get_valkey_connection_info};
 use crate::client::types::ReadFrom as ClientReadFrom;
 use futures::{StreamExt, future, stream};
 use logger_core::log_debug;
 use logger_core::log_warn;
 use redis::aio::ConnectionLike;
 use redis::cluster_routing::{self, ResponsePolicy, Routable, RoutingInfo, is_readonly_cmd};
-use redis::{PushInfo, RedisError, RedisResult, RetryStrategy, Value};
+use redis::{ConnectionRecyclingPolicy, PushInfo, RedisError, RedisResult, RetryStrategy, Value};
+use std::future::Future;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use telemetrylib::Telemetry;
 use tokio::sync::mpsc;
 use tokio::task;
@@ -42,6 +44,12 @@ struct DropWrapper {
     read_from: ReadFrom,
     /// When true, write commands are blocked and INFO REPLICATION is skipped during connection.
     read_only: bool,
+    /// When true, reads that fail on a stale replica are retried on the primary.
+    retry_reads_on_primary: bool,
+    /// The circuit breakers of the nodes, in the order of `nodes`. Empty if circuit breakers are disabled.
+    circuit_breakers: Vec<CircuitBreaker>,
+    #[cfg(feature = "fault-injection")]
+    fault_injector: Option<Arc<redis::FaultInjector>>,
 }
 
 impl Drop for DropWrapper {
@@ -163,6 +171,11 @@ impl StandaloneClient {
         let connection_timeout = connection_request.get_connection_timeout();
 
         let tcp_nodelay = connection_request.tcp_nodelay;
+        let socket_timeouts = connection_request.socket_timeouts;
+        let tcp_keepalive = connection_request.tcp_keepalive;
+        let proxy = connection_request.proxy.clone();
+        let heartbeat_interval = connection_request.heartbeat_interval;
+        let connection_recycling = connection_request.connection_recycling;
 
         let has_root_certs = !connection_request.root_certs.is_empty();
         let has_client_cert = !connection_request.client_cert.is_empty();
@@ -219,10 +232,17 @@ impl StandaloneClient {
         } else {
             None
         };
+        let tls_params = get_tls_params_with_overrides(&connection_request, tls_params);
 
         let read_only = connection_request.read_only;
+        // In read-only mode the primary isn't known, so there's no node to fall back to.
+        let retry_reads_on_primary = connection_request.retry_reads_on_primary && !read_only;
+        let connection_event_listener = connection_request.connection_event_listener.clone();
         let addresses = connection_request.addresses.clone();
         let read_from_option = connection_request.read_from.clone();
+        let circuit_breaker_config = connection_request.circuit_breaker;
+        #[cfg(feature = "fault-injection")]
+        let fault_injector = connection_request.fault_injector.clone();
 
         let mut stream = stream::iter(addresses.into_iter())
             .map(move |address| {
@@ -234,7 +254,11 @@ impl StandaloneClient {
                 let timeout = connection_timeout;
                 let params = tls_params.clone();
                 let nodelay = tcp_nodelay;
+                let socket_timeouts = socket_timeouts;
+                let tcp_keepalive = tcp_keepalive;
+                let proxy = proxy.clone();
                 let sync = pubsub_synchronizer.clone();
+                let listener = connection_event_listener.clone();
                 let skip_replication = read_only;
                 async move {
                     get_connection_and_replication_info(
@@ -247,7 +271,11 @@ impl StandaloneClient {
                         timeout,
                         params,
                         nodelay,
+                        socket_timeouts,
+                        tcp_keepalive,
+                        proxy,
                         &sync,
+                        &listener,
                         skip_replication,
                     )
                     .await
@@ -341,14 +369,28 @@ impl StandaloneClient {
         };
 
         #[cfg(feature = "standalone_heartbeat")]
-        for node in nodes.iter() {
-            Self::start_heartbeat(node.clone());
+        let heartbeat_interval = heartbeat_interval.or(Some(super::HEARTBEAT_SLEEP_DURATION));
+        if let Some(heartbeat_interval) = heartbeat_interval {
+            for node in nodes.iter() {
+                Self::start_heartbeat(node.clone(), heartbeat_interval);
+            }
+        }
+
+        if let Some(interval) = connection_recycling.check_interval() {
+            for node in nodes.iter() {
+                Self::start_connection_recycling(node.clone(), connection_recycling, interval);
+            }
         }
 
         for node in nodes.iter() {
             Self::start_periodic_connection_check(node.clone());
         }
 
+        let circuit_breakers = match circuit_breaker_config {
+            Some(config) => nodes.iter().map(|_| CircuitBreaker::new(config)).collect(),
+            None => Vec::new(),
+        };
+
         // Successfully created new client. Update the telemetry
         Telemetry::incr_total_clients(1);
 
@@ -358,6 +400,10 @@ impl StandaloneClient {
                 nodes,
                 read_from,
                 read_only,
+                retry_reads_on_primary,
+                circuit_breakers,
+                #[cfg(feature = "fault-injection")]
+                fault_injector,
             }),
         })
     }
@@ -366,6 +412,54 @@ impl StandaloneClient {
         self.inner.nodes.get(self.inner.primary_index).unwrap()
     }
 
+    fn get_circuit_breaker(&self, connection: &ReconnectingConnection) -> Option<&CircuitBreaker> {
+        let index = self
+            .inner
+            .nodes
+            .iter()
+            .position(|node| std::ptr::eq(node, connection))?;
+        self.inner.circuit_breakers.get(index)
+    }
+
+    /// Returns true if reads should avoid the node, because its circuit breaker is open.
+    fn is_circuit_open(&self, connection: &ReconnectingConnection) -> bool {
+        self.get_circuit_breaker(connection)
+            .is_some_and(|circuit_breaker| circuit_breaker.is_open())
+    }
+
+    /// Returns the number of nodes the client is currently connected to.
+    pub(crate) fn connections_count(&self) -> usize {
+        self.inner
+            .nodes
+            .iter()
+            .filter(|node| node.is_connected())
+            .count()
+    }
+
+    /// Returns the address, circuit state and number of consecutive failures of each node.
+    /// Empty if circuit breakers are disabled.
+    pub(crate) fn circuit_breaker_states(&self) -> Vec<(String, CircuitState, u32)> {
+        self.inner
+            .nodes
+            .iter()
+            .zip(&self.inner.circuit_breakers)
+            .map(|(node, circuit_breaker)| {
+                let (state, consecutive_failures) = circuit_breaker.state();
+                (node.node_address(), state, consecutive_failures)
+            })
+            .collect()
+    }
+
+    /// Returns the state of the connection to each node, and whether the node is the primary.
+    pub(crate) fn connection_stats(&self) -> Vec<(ConnectionStats, bool)> {
+        self.inner
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.stats(), index == self.inner.primary_index))
+            .collect()
+    }
+
     fn round_robin_read_from_replica(
         &self,
         latest_read_replica_index: &Arc<AtomicUsize>,
@@ -386,7 +480,7 @@ impl StandaloneClient {
             let Some(connection) = self.inner.nodes.get(index) else {
                 continue;
             };
-            if connection.is_connected() {
+            if connection.is_connected() && !self.is_circuit_open(connection) {
                 let _ = latest_read_replica_index.compare_exchange_weak(
                     initial_index,
                     index,
@@ -419,7 +513,8 @@ impl StandaloneClient {
             let replica = &self.inner.nodes[index];
 
             // Attempt to get a connection and retrieve the replica's AZ.
-            if let Ok(connection) = replica.get_connection().await
+            if !self.is_circuit_open(replica)
+                && let Ok(connection) = replica.get_connection().await
                 && let Some(replica_az) = connection.get_az().as_deref()
                 && replica_az == client_az
             {
@@ -456,7 +551,8 @@ impl StandaloneClient {
             let replica = &self.inner.nodes[index];
 
             // Attempt to get a connection and retrieve the replica's AZ.
-            if let Ok(connection) = replica.get_connection().await
+            if !self.is_circuit_open(replica)
+                && let Ok(connection) = replica.get_connection().await
                 && let Some(replica_az) = connection.get_az().as_deref()
                 && replica_az == client_az
             {
@@ -517,12 +613,76 @@ impl StandaloneClient {
         }
     }
 
+    /// Sends a request to a node through its circuit breaker, which fails the request fast while it's open,
+    /// and probes the node with `PING` before sending the request once the cooldown elapsed.
+    async fn send_through_circuit_breaker<T, Fut>(
+        reconnecting_connection: &ReconnectingConnection,
+        circuit_breaker: Option<&CircuitBreaker>,
+        request: Fut,
+    ) -> RedisResult<T>
+    where
+        Fut: Future<Output = RedisResult<T>>,
+    {
+        let Some(circuit_breaker) = circuit_breaker else {
+            return request.await;
+        };
+        let admitted = match circuit_breaker.admit() {
+            Admission::Allowed => true,
+            Admission::Probe => {
+                let probe = Self::send_request(&redis::cmd("PING"), reconnecting_connection).await;
+                circuit_breaker.record_result(&probe);
+                circuit_breaker.state().0 == CircuitState::Closed
+            }
+            Admission::Rejected => false,
+        };
+        if !admitted {
+            Telemetry::incr_circuit_breaker_rejected();
+            return Err(crate::errors::circuit_open_error(
+                &reconnecting_connection.node_address(),
+            ));
+        }
+        let result = request.await;
+        circuit_breaker.record_result(&result);
+        result
+    }
+
+    async fn send_request_to_node(
+        &self,
+        cmd: &redis::Cmd,
+        reconnecting_connection: &ReconnectingConnection,
+    ) -> RedisResult<Value> {
+        #[cfg(feature = "fault-injection")]
+        if let Some(fault_injector) = &self.inner.fault_injector
+            && let Err(err) = fault_injector
+                .inject(&reconnecting_connection.node_address(), cmd)
+                .await
+        {
+            if err.is_unrecoverable_error() {
+                reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
+            }
+            return Err(err);
+        }
+        Self::send_through_circuit_breaker(
+            reconnecting_connection,
+            self.get_circuit_breaker(reconnecting_connection),
+            Self::send_request(cmd, reconnecting_connection),
+        )
+        .await
+        .map_err(|err| err.with_node_address(&reconnecting_connection.node_address()))
+    }
+
     async fn send_request(
         cmd: &redis::Cmd,
         reconnecting_connection: &ReconnectingConnection,
     ) -> RedisResult<Value> {
         let mut connection = reconnecting_connection.get_connection().await?;
+        // The command may have waited for the connection while the node was reconnecting.
+        cmd.check_deadline()?;
+        if let Some(trace) = cmd.trace() {
+            trace.record_attempt(&reconnecting_connection.node_address());
+        }
         let result = connection.send_packed_command(cmd).await;
+        reconnecting_connection.record_result(&result, 1);
         match result {
             Err(err) if err.is_unrecoverable_error() => {
                 log_warn("send request", format!("received disconnect error `{err}`"));
@@ -542,7 +702,7 @@ impl StandaloneClient {
             .inner
             .nodes
             .iter()
-            .map(|node| Self::send_request(cmd, node));
+            .map(|node| self.send_request_to_node(cmd, node));
 
         // TODO - once Value::Error will be merged, these will need to be updated to handle this new value.
         match response_policy {
@@ -613,7 +773,25 @@ impl StandaloneClient {
         readonly: bool,
     ) -> RedisResult<Value> {
         let reconnecting_connection = self.get_connection(readonly).await;
-        Self::send_request(cmd, reconnecting_connection).await
+        let result = self
+            .send_request_to_node(cmd, reconnecting_connection)
+            .await;
+        match result {
+            Err(err)
+                if self.inner.retry_reads_on_primary
+                    && super::is_stale_read_error(&err)
+                    && !std::ptr::eq(reconnecting_connection, self.get_primary_connection()) =>
+            {
+                log_debug(
+                    "send request",
+                    format!("Retrying read on the primary after replica error `{err}`"),
+                );
+                Telemetry::incr_primary_fallback_reads();
+                self.send_request_to_node(cmd, self.get_primary_connection())
+                    .await
+            }
+            _ => result,
+        }
     }
 
     pub async fn send_command(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
@@ -637,6 +815,39 @@ impl StandaloneClient {
             .await
     }
 
+    /// Sends `cmd` to the primary, even if it's a read that the read strategy would send to a replica.
+    pub(super) async fn send_command_to_primary(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
+        self.send_request_to_node(cmd, self.get_primary_connection())
+            .await
+    }
+
+    /// Returns the address of the node that the read strategy selects for the next read.
+    pub async fn read_node_address(&self) -> String {
+        self.get_connection(true).await.node_address()
+    }
+
+    /// Sends `cmd` to the node with the given address, for commands whose state is kept by a single node, like the
+    /// cursor of `SCAN`.
+    pub async fn send_command_to_address(
+        &mut self,
+        cmd: &redis::Cmd,
+        address: &str,
+    ) -> RedisResult<Value> {
+        let Some(node) = self
+            .inner
+            .nodes
+            .iter()
+            .find(|node| node.node_address() == address)
+        else {
+            return Err(RedisError::from((
+                redis::ErrorKind::ClientError,
+                "Unknown node address",
+                address.to_string(),
+            )));
+        };
+        self.send_request_to_node(cmd, node).await
+    }
+
     pub async fn send_pipeline(
         &mut self,
         pipeline: &redis::Pipeline,
@@ -644,28 +855,36 @@ impl StandaloneClient {
         count: usize,
     ) -> RedisResult<Vec<Value>> {
         let reconnecting_connection = self.get_primary_connection();
-        let mut connection = reconnecting_connection.get_connection().await?;
-        let result = connection
-            .send_packed_commands(pipeline, offset, count)
-            .await;
-        match result {
-            Err(err) if err.is_unrecoverable_error() => {
-                log_warn(
-                    "pipeline request",
-                    format!("received disconnect error `{err}`"),
-                );
-                reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
-                Err(err)
+        let request = async {
+            let mut connection = reconnecting_connection.get_connection().await?;
+            let result = connection
+                .send_packed_commands(pipeline, offset, count)
+                .await;
+            reconnecting_connection.record_result(&result, count as u64);
+            match result {
+                Err(err) if err.is_unrecoverable_error() => {
+                    log_warn(
+                        "pipeline request",
+                        format!("received disconnect error `{err}`"),
+                    );
+                    reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
+                    Err(err)
+                }
+                _ => result,
             }
-            _ => result,
-        }
+        };
+        Self::send_through_circuit_breaker(
+            reconnecting_connection,
+            self.get_circuit_breaker(reconnecting_connection),
+            request,
+        )
+        .await
     }
 
-    #[cfg(feature = "standalone_heartbeat")]
-    fn start_heartbeat(reconnecting_connection: ReconnectingConnection) {
+    fn start_heartbeat(reconnecting_connection: ReconnectingConnection, interval: Duration) {
         task::spawn(async move {
             loop {
-                tokio::time::sleep(super::HEARTBEAT_SLEEP_DURATION).await;
+                tokio::time::sleep(interval).await;
                 if reconnecting_connection.is_dropped() {
                     log_debug(
                         "StandaloneClient",
@@ -697,6 +916,35 @@ impl StandaloneClient {
         });
     }
 
+    /// Checks the connection against the recycling policy in the given interval, and replaces it once it reached the
+    /// maximum connection age or wasn't used within the idle timeout.
+    fn start_connection_recycling(
+        reconnecting_connection: ReconnectingConnection,
+        policy: ConnectionRecyclingPolicy,
+        interval: Duration,
+    ) {
+        task::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if reconnecting_connection.is_dropped() {
+                    log_debug(
+                        "StandaloneClient",
+                        "connection recycling stopped after connection was dropped",
+                    );
+                    // Client was dropped, recycling can stop.
+                    return;
+                }
+                if let Some(reason) = reconnecting_connection.recycle_reason(&policy) {
+                    log_debug(
+                        "StandaloneClient",
+                        format!("recycling connection ({})", reason.description()),
+                    );
+                    reconnecting_connection.recycle(reason).await;
+                }
+            }
+        });
+    }
+
     // Monitors passive connection status and reconnects if necessary.
     // This function is cheaper alternative to start_heartbeat(),
     // as it avoids sending PING commands to the server, checking only the connection state.
@@ -816,6 +1064,28 @@ impl StandaloneClient {
         // All nodes in the client should have the same username configured, thus any connection would work here.
         self.get_primary_connection().get_username()
     }
+
+    /// Send `cmd` to every node concurrently, returning the result of each node separately, along with the round-trip
+    /// time of each successful request. The results are returned in node order, paired with the address of the node.
+    pub(crate) async fn send_to_each_node(
+        &self,
+        cmd: &redis::Cmd,
+        timeout: Option<Duration>,
+    ) -> Vec<(String, RedisResult<(Value, Duration)>)> {
+        let requests = self.inner.nodes.iter().map(|node| async move {
+            let start = Instant::now();
+            let result = super::run_with_timeout(timeout, self.send_request_to_node(cmd, node))
+                .await
+                .map(|value| (value, start.elapsed()));
+            (node.node_address(), result)
+        });
+        future::join_all(requests).await
+    }
+
+    /// Returns the address of the primary node.
+    pub(crate) fn primary_address(&self) -> String {
+        self.get_primary_connection().node_address()
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -829,7 +1099,11 @@ async fn get_connection_and_replication_info(
     connection_timeout: Duration,
     tls_params: Option<redis::TlsConnParams>,
     tcp_nodelay: bool,
+    socket_timeouts: redis::SocketTimeouts,
+    tcp_keepalive: redis::TcpKeepaliveConfig,
+    proxy: Option<redis::ProxyConfig>,
     pubsub_synchronizer: &Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
+    connection_event_listener: &Option<Arc<dyn redis::ConnectionEventListener>>,
     skip_replication_check: bool,
 ) -> Result<(ReconnectingConnection, Option<Value>), (ReconnectingConnection, RedisError)> {
     let reconnecting_connection = ReconnectingConnection::new(
@@ -842,7 +1116,11 @@ async fn get_connection_and_replication_info(
         connection_timeout,
         tls_params,
         tcp_nodelay,
+        socket_timeouts,
+        tcp_keepalive,
+        proxy,
         pubsub_synchronizer.clone(),
+        connection_event_listener.clone(),
     )
     .await?;
 
@@ -874,6 +1152,16 @@ fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
         Some(super::ReadFrom::PreferReplica) => ReadFrom::PreferReplica {
             latest_read_replica_index: Default::default(),
         },
+        // Standalone clients don't measure the latencies of the nodes.
+        Some(super::ReadFrom::LowestLatency) => {
+            log_warn(
+                "client creation",
+                "`ReadFrom::LowestLatency` is only supported in cluster mode. Falling back to `ReadFrom::PreferReplica`",
+            );
+            ReadFrom::PreferReplica {
+                latest_read_replica_index: Default::default(),
+            }
+        }
         Some(super::ReadFrom::AZAffinity(az)) => ReadFrom::AZAffinity {
             client_az: az,
             last_read_replica_index: Default::default(),
@@ -4,6 +4,7 @@
 use logger_core::log_warn;
 #[allow(unused_imports)]
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[cfg(feature = "proto")]
@@ -31,8 +32,13 @@ pub struct ConnectionRequest {
     pub connection_timeout: Option<u32>,
     pub connection_retry_strategy: Option<ConnectionRetryStrategy>,
     pub periodic_checks: Option<PeriodicCheck>,
+    /// The maximal random jitter added to each interval of the periodic topology checks. Cluster mode only.
+    pub periodic_checks_max_jitter: Option<Duration>,
     pub pubsub_subscriptions: Option<redis::PubSubSubscriptionInfo>,
     pub inflight_requests_limit: Option<u32>,
+    /// The number of inflight requests at which normal-priority requests are rejected. The remaining requests up to
+    /// `inflight_requests_limit` are reserved for high-priority requests.
+    pub inflight_requests_high_watermark: Option<u32>,
     pub lazy_connect: bool,
     pub refresh_topology_from_initial_nodes: bool,
     pub root_certs: Vec<Vec<u8>>,
@@ -40,8 +46,68 @@ pub struct ConnectionRequest {
     pub client_key: Vec<u8>,
     pub compression_config: Option<CompressionConfig>,
     pub tcp_nodelay: bool,
+    /// Timeouts of the socket reads and writes, which detect an unresponsive node even while a request with a long
+    /// timeout, such as a blocking command, is pending.
+    pub socket_timeouts: redis::SocketTimeouts,
+    pub tcp_keepalive: redis::TcpKeepaliveConfig,
+    /// The interval of the `PING`s sent to every node to keep idle connections alive.
+    pub heartbeat_interval: Option<Duration>,
+    /// The limits after which the node connections are replaced.
+    pub connection_recycling: redis::ConnectionRecyclingPolicy,
+    /// The addresses dialed instead of the addresses the nodes announce, shared with the connections of the client so
+    /// it can be replaced at runtime. Cluster mode only.
+    pub address_translation: redis::AddressTranslation,
+    /// The proxy that the node connections are tunneled through.
+    pub proxy: Option<redis::ProxyConfig>,
+    /// Follow every write command with `WAIT`, and send the reads of the slots whose latest write wasn't acknowledged
+    /// by enough replicas to the primary. Shared by the clones of the request, so the clients created from them
+    /// route their reads the same way.
+    pub read_your_writes: Option<super::ReadYourWrites>,
     pub pubsub_reconciliation_interval_ms: Option<u32>,
     pub read_only: bool,
+    pub connection_pool_size: Option<u32>,
+    pub root_certs_path: Option<String>,
+    pub client_cert_path: Option<String>,
+    pub client_key_path: Option<String>,
+    pub tls_server_name: Option<String>,
+    pub tls_skip_hostname_verification: bool,
+    pub retry_reads_on_primary: bool,
+    /// Discover the primary and replicas through the Sentinel nodes listed in `addresses`. Standalone mode only.
+    pub sentinel: Option<SentinelConfig>,
+    /// The nodes discovered by the main connection of a client in Sentinel mode, shared by the clones of the request,
+    /// so the pool members and side connections created from them connect to these nodes instead of querying the
+    /// Sentinel nodes. Set by the client, and not part of the protobuf request.
+    pub sentinel_topology: Option<Arc<super::SentinelTopology>>,
+    /// Stop sending requests to nodes after consecutive failures. Standalone mode only.
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// How the AZ affinity strategies choose among the replicas in the client's availability zone. Cluster mode only.
+    pub replica_selection_policy: redis::cluster_slotmap::ReplicaSelectionPolicy,
+    /// Send `CLIENT NO-EVICT ON` on every connection, including reconnections.
+    pub client_no_evict: bool,
+    /// Send `CLIENT NO-TOUCH ON` on every connection, including reconnections, so the client's commands don't alter
+    /// the LRU/LFU of the keys.
+    pub client_no_touch: bool,
+    /// Fall back to RESP2 when a node rejects `HELLO 3`, instead of failing the connection. Reported as a
+    /// `ProtocolDowngraded` connection event, and in the server capabilities of the client. Ignored when
+    /// `pubsub_subscriptions` are configured, and subscribing on a connection that fell back fails.
+    pub resp3_fallback: bool,
+    /// Evaluate `JSON.GET` on the client, on the string value of the key read with `GET`, when the server rejects it
+    /// as an unknown command because it doesn't have the JSON module.
+    pub json_path_fallback: bool,
+    /// The nodes that served the latest read commands, and their read latencies. Shared by the clones of the request,
+    /// so the clients created from them record into the same statistics. Cluster mode only.
+    pub read_routing_stats: Arc<redis::cluster_async::ReadRoutingStats>,
+    /// The `MOVED` and `ASK` redirections of the client, counted by node. Cluster mode only.
+    pub redirect_stats: Arc<redis::cluster_async::RedirectStats>,
+    /// Report each `MOVED` and `ASK` redirection to the connection event listener. Cluster mode only.
+    pub report_redirect_events: bool,
+    /// Notified when connections are established, lost or reconnecting, and when the cluster topology changes.
+    /// Set by the wrappers when creating the client, and not part of the protobuf request.
+    pub connection_event_listener: Option<Arc<dyn redis::ConnectionEventListener>>,
+    /// Injects faults into the commands sent to the nodes, for integration testing.
+    /// Set by the wrappers when creating the client, and not part of the protobuf request.
+    #[cfg(feature = "fault-injection")]
+    pub fault_injector: Option<Arc<redis::FaultInjector>>,
 }
 
 /// Default connection timeout used when not specified in the request.
@@ -56,6 +122,33 @@ impl ConnectionRequest {
             .map(|val| Duration::from_millis(val as u64))
             .unwrap_or(DEFAULT_CONNECTION_TIMEOUT)
     }
+
+    /// Reads the PEM files configured by path into `root_certs`, `client_cert` and `client_key`.
+    /// The CA bundle is added to the root certificates passed directly, while the client certificate and key
+    /// must not be passed both directly and by path.
+    pub fn load_tls_files(&mut self) -> std::io::Result<()> {
+        if let Some(path) = self.root_certs_path.take() {
+            self.root_certs.push(std::fs::read(path)?);
+        }
+        if let Some(path) = self.client_cert_path.take() {
+            Self::load_tls_file(&mut self.client_cert, path, "client_cert")?;
+        }
+        if let Some(path) = self.client_key_path.take() {
+            Self::load_tls_file(&mut self.client_key, path, "client_key")?;
+        }
+        Ok(())
+    }
+
+    fn load_tls_file(target: &mut Vec<u8>, path: String, name: &str) -> std::io::Result<()> {
+        if !target.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{name} must not be provided both directly and by path"),
+            ));
+        }
+        *target = std::fs::read(path)?;
+        Ok(())
+    }
 }
 
 /// Authentication information for connecting to Redis/Valkey servers
@@ -91,6 +184,9 @@ pub struct IamAuthenticationConfig {
 
     /// Token refresh interval in seconds (1 second to 12 hours, default 14 minutes)
     pub refresh_interval_seconds: Option<u32>,
+
+    /// Token refresh interval as a percentage of the token TTL (1 to 99), overrides `refresh_interval_seconds`
+    pub refresh_ttl_percent: Option<u32>,
 }
 
 #[derive(Default, Clone, Copy, Debug)]
@@ -127,6 +223,8 @@ pub enum ReadFrom {
     PreferReplica,
     AZAffinity(String),
     AZAffinityReplicasAndPrimary(String),
+    /// Read from the reachable replica with the lowest latency, measured by the periodic checks. Cluster mode only.
+    LowestLatency,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
@@ -138,6 +236,81 @@ pub enum TlsMode {
     SecureTls,
 }
 
+/// Mode of the server-side flush commands. `Default` leaves the choice to the server's configuration.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+#[repr(C)]
+pub enum FlushMode {
+    #[default]
+    Default,
+    Sync,
+    Async,
+}
+
+impl FlushMode {
+    /// Returns the argument to append to the flush command, if any.
+    pub fn as_arg(&self) -> Option<&'static str> {
+        match self {
+            FlushMode::Default => None,
+            FlushMode::Sync => Some("SYNC"),
+            FlushMode::Async => Some("ASYNC"),
+        }
+    }
+}
+
+/// Options of the `FAILOVER` command, which promotes a replica of a standalone primary.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct FailoverOptions {
+    /// The host and port of the replica to promote. If not set, the server chooses a replica.
+    pub target: Option<(String, u16)>,
+    /// Promote the target even if it didn't catch up with the primary within the timeout. Requires a target and a timeout.
+    pub force: bool,
+    /// Abort an ongoing failover. Can't be combined with the other options.
+    pub abort: bool,
+    /// How long the primary waits for the target to catch up, in milliseconds. If not set, it waits indefinitely.
+    pub timeout_ms: Option<u64>,
+}
+
+/// Configuration of the Sentinel mode, in which the addresses of the connection request point at Sentinel nodes,
+/// which are queried for the current primary and replicas of `master_name`.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct SentinelConfig {
+    /// The name of the monitored primary, as configured in the Sentinel nodes.
+    pub master_name: String,
+    /// Username for the Sentinel nodes, which may differ from the username of the data nodes.
+    pub username: Option<String>,
+    /// Password for the Sentinel nodes, which may differ from the password of the data nodes.
+    pub password: Option<String>,
+}
+
+/// The priority class of a request, which decides whether it's rejected once the inflight requests reach the high
+/// watermark.
+#[repr(C)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum RequestPriority {
+    #[default]
+    Normal = 0,
+    High = 1,
+}
+
+#[cfg(feature = "proto")]
+impl From<crate::command_request::RequestPriority> for RequestPriority {
+    fn from(value: crate::command_request::RequestPriority) -> Self {
+        match value {
+            crate::command_request::RequestPriority::Normal => RequestPriority::Normal,
+            crate::command_request::RequestPriority::High => RequestPriority::High,
+        }
+    }
+}
+
+/// Configuration of the per-node circuit breakers, which stop sending requests to a node after consecutive failures.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CircuitBreakerConfig {
+    /// The number of consecutive connection failures or timeouts of a node that opens its breaker.
+    pub failure_threshold: u32,
+    /// How long a breaker stays open before its node is probed with `PING`.
+    pub cooldown: Duration,
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 #[repr(C)]
 pub struct ConnectionRetryStrategy {
@@ -167,7 +340,7 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
         let read_from = value.read_from.enum_value().ok().map(|val| match val {
             protobuf::ReadFrom::Primary => ReadFrom::Primary,
             protobuf::ReadFrom::PreferReplica => ReadFrom::PreferReplica,
-            protobuf::ReadFrom::LowestLatency => todo!(),
+            protobuf::ReadFrom::LowestLatency => ReadFrom::LowestLatency,
             protobuf::ReadFrom::AZAffinity => {
                 if let Some(client_az) = chars_to_string_option(&value.client_az) {
                     ReadFrom::AZAffinity(client_az)
@@ -212,12 +385,14 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
                     _ => ServiceType::ElastiCache,
                 };
                 let refresh_interval_seconds = iam_creds.refresh_interval_seconds;
+                let refresh_ttl_percent = iam_creds.refresh_ttl_percent;
 
                 IamAuthenticationConfig {
                     cluster_name,
                     region,
                     service_type,
                     refresh_interval_seconds,
+                    refresh_ttl_percent,
                 }
             });
 
@@ -308,6 +483,9 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
         }
 
         let inflight_requests_limit = none_if_zero(value.inflight_requests_limit);
+        let inflight_requests_high_watermark = value
+            .inflight_requests_high_watermark
+            .filter(|&watermark| watermark != 0);
         let lazy_connect = value.lazy_connect;
         let refresh_topology_from_initial_nodes = value.refresh_topology_from_initial_nodes;
         let root_certs = value
@@ -345,9 +523,105 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
         });
 
         let tcp_nodelay = value.tcp_nodelay.unwrap_or(true);
+        let socket_timeouts = redis::SocketTimeouts {
+            read_timeout: value
+                .socket_read_timeout_ms
+                .filter(|timeout_ms| *timeout_ms > 0)
+                .map(|timeout_ms| Duration::from_millis(timeout_ms.into())),
+            write_timeout: value
+                .socket_write_timeout_ms
+                .filter(|timeout_ms| *timeout_ms > 0)
+                .map(|timeout_ms| Duration::from_millis(timeout_ms.into())),
+        };
+        let tcp_keepalive = value
+            .tcp_keepalive
+            .0
+            .map(|keepalive| redis::TcpKeepaliveConfig {
+                idle: keepalive
+                    .idle_ms
+                    .filter(|idle_ms| *idle_ms > 0)
+                    .map(|idle_ms| Duration::from_millis(idle_ms.into())),
+                interval: keepalive
+                    .interval_ms
+                    .filter(|interval_ms| *interval_ms > 0)
+                    .map(|interval_ms| Duration::from_millis(interval_ms.into())),
+                retries: keepalive.count.filter(|count| *count > 0),
+            })
+            .unwrap_or_default();
+        let heartbeat_interval = value
+            .heartbeat_interval_ms
+            .filter(|interval_ms| *interval_ms > 0)
+            .map(|interval_ms| Duration::from_millis(interval_ms.into()));
+        let connection_recycling = redis::ConnectionRecyclingPolicy {
+            max_age: value
+                .max_connection_age_ms
+                .filter(|age_ms| *age_ms > 0)
+                .map(|age_ms| Duration::from_millis(age_ms.into())),
+            idle_timeout: value
+                .connection_idle_timeout_ms
+                .filter(|timeout_ms| *timeout_ms > 0)
+                .map(|timeout_ms| Duration::from_millis(timeout_ms.into())),
+        };
+        let address_translation = redis::AddressTranslation::new(value.address_translation);
+        let proxy = value.proxy.0.map(|proxy| redis::ProxyConfig {
+            proxy_type: match proxy.proxy_type.enum_value() {
+                Ok(protobuf::ProxyType::HttpConnect) => redis::ProxyType::HttpConnect,
+                _ => redis::ProxyType::Socks5,
+            },
+            host: proxy.host.to_string(),
+            port: proxy.port as u16,
+            username: chars_to_string_option(&proxy.username),
+            password: chars_to_string_option(&proxy.password),
+        });
+        let read_your_writes = value.read_your_writes.0.map(|config| {
+            super::ReadYourWrites::new(
+                config.num_replicas,
+                Duration::from_millis(config.timeout_ms.into()),
+            )
+        });
         let pubsub_reconciliation_interval_ms =
             value.pubsub_reconciliation_interval_ms.filter(|&v| v != 0);
         let read_only = value.read_only.unwrap_or(false);
+        // A pool of a single connection is the same as not using a pool.
+        let connection_pool_size = value.connection_pool_size.filter(|&size| size > 1);
+        let root_certs_path = value.root_certs_path.filter(|path| !path.is_empty());
+        let client_cert_path = value.client_cert_path.filter(|path| !path.is_empty());
+        let client_key_path = value.client_key_path.filter(|path| !path.is_empty());
+        let tls_server_name = value.tls_server_name.filter(|name| !name.is_empty());
+        let tls_skip_hostname_verification = value.tls_skip_hostname_verification.unwrap_or(false);
+        let retry_reads_on_primary = value.retry_reads_on_primary.unwrap_or(false);
+        let sentinel = value.sentinel.0.map(|sentinel| SentinelConfig {
+            master_name: sentinel.master_name.to_string(),
+            username: chars_to_string_option(&sentinel.username),
+            password: chars_to_string_option(&sentinel.password),
+        });
+        let circuit_breaker = value
+            .circuit_breaker
+            .0
+            .filter(|config| config.failure_threshold > 0)
+            .map(|config| CircuitBreakerConfig {
+                failure_threshold: config.failure_threshold,
+                cooldown: Duration::from_millis(config.cooldown_ms.into()),
+            });
+        let replica_selection_policy = match value.replica_selection_policy.enum_value() {
+            Ok(protobuf::ReplicaSelectionPolicy::RandomSelection) => {
+                redis::cluster_slotmap::ReplicaSelectionPolicy::Random
+            }
+            Ok(protobuf::ReplicaSelectionPolicy::LowestLatencySelection) => {
+                redis::cluster_slotmap::ReplicaSelectionPolicy::LowestLatency
+            }
+            _ => redis::cluster_slotmap::ReplicaSelectionPolicy::RoundRobin,
+        };
+
+        let periodic_checks_max_jitter = value
+            .periodic_checks_jitter_ms
+            .filter(|jitter_ms| *jitter_ms > 0)
+            .map(|jitter_ms| Duration::from_millis(jitter_ms.into()));
+        let client_no_evict = value.client_no_evict.unwrap_or(false);
+        let client_no_touch = value.client_no_touch.unwrap_or(false);
+        let resp3_fallback = value.resp3_fallback.unwrap_or(false);
+        let json_path_fallback = value.json_path_fallback.unwrap_or(false);
+        let report_redirect_events = value.report_redirect_events.unwrap_or(false);
 
         ConnectionRequest {
             read_from,
@@ -363,8 +637,10 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             connection_timeout,
             connection_retry_strategy,
             periodic_checks,
+            periodic_checks_max_jitter,
             pubsub_subscriptions,
             inflight_requests_limit,
+            inflight_requests_high_watermark,
             lazy_connect,
             refresh_topology_from_initial_nodes,
             root_certs,
@@ -372,8 +648,36 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             client_key,
             compression_config,
             tcp_nodelay,
+            socket_timeouts,
+            tcp_keepalive,
+            heartbeat_interval,
+            connection_recycling,
+            address_translation,
+            proxy,
+            read_your_writes,
             pubsub_reconciliation_interval_ms,
             read_only,
+            connection_pool_size,
+            root_certs_path,
+            client_cert_path,
+            client_key_path,
+            tls_server_name,
+            tls_skip_hostname_verification,
+            retry_reads_on_primary,
+            sentinel,
+            sentinel_topology: None,
+            circuit_breaker,
+            replica_selection_policy,
+            client_no_evict,
+            client_no_touch,
+            resp3_fallback,
+            json_path_fallback,
+            read_routing_stats: Default::default(),
+            redirect_stats: Default::default(),
+            report_redirect_events,
+            connection_event_listener: None,
+            #[cfg(feature = "fault-injection")]
+            fault_injector: None,
         }
     }
 }
@@ -478,5 +782,262 @@ mod tests {
             // Should fall back to Zstd for unknown backends
             assert_eq!(config.backend, CompressionBackendType::Zstd);
         }
+
+        #[test]
+        fn test_connection_pool_size_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            proto_request.connection_pool_size = Some(1);
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.connection_pool_size, None);
+
+            proto_request.connection_pool_size = Some(4);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(request.connection_pool_size, Some(4));
+        }
+
+        #[test]
+        fn test_tls_server_name_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.tls_server_name, None);
+            assert!(!request.tls_skip_hostname_verification);
+
+            proto_request.tls_server_name = Some("valkey.example.com".into());
+            proto_request.tls_skip_hostname_verification = Some(true);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.tls_server_name.as_deref(),
+                Some("valkey.example.com")
+            );
+            assert!(request.tls_skip_hostname_verification);
+        }
+
+        #[test]
+        fn test_sentinel_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.sentinel, None);
+
+            let mut sentinel = protobuf::SentinelConfig::new();
+            sentinel.master_name = "mymaster".into();
+            sentinel.password = "secret".into();
+            proto_request.sentinel = ::protobuf::MessageField::some(sentinel);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.sentinel,
+                Some(crate::client::SentinelConfig {
+                    master_name: "mymaster".to_string(),
+                    username: None,
+                    password: Some("secret".to_string()),
+                })
+            );
+        }
+
+        #[test]
+        fn test_circuit_breaker_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let mut circuit_breaker = protobuf::CircuitBreakerConfig::new();
+            proto_request.circuit_breaker = ::protobuf::MessageField::some(circuit_breaker.clone());
+            // A breaker that never opens is the same as not using breakers.
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.circuit_breaker, None);
+
+            circuit_breaker.failure_threshold = 5;
+            circuit_breaker.cooldown_ms = 2000;
+            proto_request.circuit_breaker = ::protobuf::MessageField::some(circuit_breaker);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.circuit_breaker,
+                Some(crate::client::CircuitBreakerConfig {
+                    failure_threshold: 5,
+                    cooldown: std::time::Duration::from_secs(2),
+                })
+            );
+        }
+
+        #[test]
+        fn test_periodic_checks_jitter_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            proto_request.periodic_checks_jitter_ms = Some(0);
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.periodic_checks_max_jitter, None);
+
+            proto_request.periodic_checks_jitter_ms = Some(500);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.periodic_checks_max_jitter,
+                Some(std::time::Duration::from_millis(500))
+            );
+        }
+
+        #[test]
+        fn test_socket_timeouts_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.socket_timeouts, redis::SocketTimeouts::default());
+
+            proto_request.socket_read_timeout_ms = Some(3000);
+            proto_request.socket_write_timeout_ms = Some(0);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.socket_timeouts,
+                redis::SocketTimeouts {
+                    read_timeout: Some(std::time::Duration::from_secs(3)),
+                    write_timeout: None,
+                }
+            );
+        }
+
+        #[test]
+        fn test_tcp_keepalive_and_heartbeat_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert_eq!(request.tcp_keepalive, redis::TcpKeepaliveConfig::default());
+            assert_eq!(request.heartbeat_interval, None);
+
+            let mut keepalive = protobuf::TcpKeepalive::new();
+            keepalive.idle_ms = Some(30_000);
+            keepalive.count = Some(3);
+            proto_request.tcp_keepalive = ::protobuf::MessageField::some(keepalive);
+            proto_request.heartbeat_interval_ms = Some(10_000);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.tcp_keepalive,
+                redis::TcpKeepaliveConfig {
+                    idle: Some(std::time::Duration::from_secs(30)),
+                    interval: None,
+                    retries: Some(3),
+                }
+            );
+            assert_eq!(
+                request.heartbeat_interval,
+                Some(std::time::Duration::from_secs(10))
+            );
+        }
+
+        #[test]
+        fn test_connection_recycling_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert!(!request.connection_recycling.is_enabled());
+
+            proto_request.max_connection_age_ms = Some(600_000);
+            proto_request.connection_idle_timeout_ms = Some(0);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.connection_recycling,
+                redis::ConnectionRecyclingPolicy {
+                    max_age: Some(std::time::Duration::from_secs(600)),
+                    idle_timeout: None,
+                }
+            );
+        }
+
+        #[test]
+        fn test_address_translation_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            proto_request
+                .address_translation
+                .insert("10.0.0.1:6379".to_string(), "localhost:7001".to_string());
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request
+                    .address_translation
+                    .translate("10.0.0.1:6379")
+                    .as_deref(),
+                Some("localhost:7001")
+            );
+
+            // Clones of the request share the translation, so replacing it applies to the connections of the client.
+            let clone = request.clone();
+            clone
+                .address_translation
+                .replace(std::collections::HashMap::new())
+                .unwrap();
+            assert!(request.address_translation.is_empty());
+        }
+
+        #[test]
+        fn test_read_your_writes_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert!(request.read_your_writes.is_none());
+
+            let mut read_your_writes = protobuf::ReadYourWritesConfig::new();
+            read_your_writes.num_replicas = 2;
+            read_your_writes.timeout_ms = 500;
+            proto_request.read_your_writes = ::protobuf::MessageField::some(read_your_writes);
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.read_your_writes,
+                Some(crate::client::ReadYourWrites::new(
+                    2,
+                    std::time::Duration::from_millis(500)
+                ))
+            );
+        }
+
+        #[test]
+        fn test_proxy_conversion() {
+            let mut proto_request = protobuf::ConnectionRequest::new();
+            let request: ConnectionRequest = proto_request.clone().into();
+            assert!(request.proxy.is_none());
+
+            let mut proxy = protobuf::ProxyConfig::new();
+            proxy.proxy_type = protobuf::ProxyType::HttpConnect.into();
+            proxy.host = "bastion.example".into();
+            proxy.port = 3128;
+            proxy.username = "user".into();
+            proto_request.proxy = Some(proxy).into();
+            let request: ConnectionRequest = proto_request.into();
+            assert_eq!(
+                request.proxy,
+                Some(redis::ProxyConfig {
+                    proxy_type: redis::ProxyType::HttpConnect,
+                    host: "bastion.example".to_string(),
+                    port: 3128,
+                    username: Some("user".to_string()),
+                    password: None,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_tls_files() {
+        use super::ConnectionRequest;
+
+        let dir = std::env::temp_dir().join(format!("glide_tls_files_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let ca_path = dir.join("ca.pem");
+        let cert_path = dir.join("client.pem");
+        let key_path = dir.join("client.key");
+        std::fs::write(&ca_path, b"ca").unwrap();
+        std::fs::write(&cert_path, b"cert").unwrap();
+        std::fs::write(&key_path, b"key").unwrap();
+
+        let mut request = ConnectionRequest {
+            root_certs: vec![b"inline ca".to_vec()],
+            root_certs_path: Some(ca_path.to_string_lossy().into_owned()),
+            client_cert_path: Some(cert_path.to_string_lossy().into_owned()),
+            client_key_path: Some(key_path.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        request.load_tls_files().unwrap();
+        assert_eq!(
+            request.root_certs,
+            vec![b"inline ca".to_vec(), b"ca".to_vec()]
+        );
+        assert_eq!(request.client_cert, b"cert".to_vec());
+        assert_eq!(request.client_key, b"key".to_vec());
+
+        let mut request = ConnectionRequest {
+            client_cert: b"inline cert".to_vec(),
+            client_cert_path: Some(cert_path.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert!(request.load_tls_files().is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -19,6 +19,8 @@ pub(crate) enum ExpectedReturnType<'a> {
     Double,
     Boolean,
     BulkString,
+    BulkStringOrNull,
+    IntOrNull,
     Set,
     DoubleOrNull,
     ZRankReturnType,
@@ -144,6 +146,14 @@ pub(crate) fn convert_to_expected_type(
             Value::BulkString(_) => Ok(value),
             _ => Ok(Value::BulkString(from_owned_redis_value::<String>(value)?.into())),
         },
+        ExpectedReturnType::BulkStringOrNull => match value {
+            Value::Nil | Value::BulkString(_) => Ok(value),
+            _ => Ok(Value::BulkString(from_owned_redis_value::<String>(value)?.into())),
+        },
+        ExpectedReturnType::IntOrNull => match value {
+            Value::Nil | Value::Int(_) => Ok(value),
+            _ => Ok(Value::Int(from_owned_redis_value::<i64>(value)?)),
+        },
         ExpectedReturnType::SimpleString => Ok(Value::SimpleString(
             from_owned_redis_value::<String>(value)?,
         )),
@@ -1449,6 +1459,8 @@ pub(crate) fn expected_type_for_cmd(cmd: &Cmd) -> Option<ExpectedReturnType<'_>>
             value_type: &None,
         }),
         b"INCRBYFLOAT" | b"HINCRBYFLOAT" | b"ZINCRBY" => Some(ExpectedReturnType::Double),
+        b"GETEX" | b"OBJECT ENCODING" => Some(ExpectedReturnType::BulkStringOrNull),
+        b"OBJECT FREQ" => Some(ExpectedReturnType::IntOrNull),
         b"HEXISTS"
         | b"HSETNX"
         | b"EXPIRE"
@@ -3177,6 +3189,57 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn convert_getex_and_object_encoding_to_bulk_string_or_null() {
+        assert!(matches!(
+            expected_type_for_cmd(redis::cmd("GETEX").arg("key").arg("PERSIST")),
+            Some(ExpectedReturnType::BulkStringOrNull)
+        ));
+        assert!(matches!(
+            expected_type_for_cmd(redis::cmd("OBJECT").arg("ENCODING").arg("key")),
+            Some(ExpectedReturnType::BulkStringOrNull)
+        ));
+
+        assert_eq!(
+            convert_to_expected_type(Value::Nil, Some(ExpectedReturnType::BulkStringOrNull)),
+            Ok(Value::Nil)
+        );
+        assert_eq!(
+            convert_to_expected_type(
+                Value::SimpleString("embstr".to_string()),
+                Some(ExpectedReturnType::BulkStringOrNull)
+            ),
+            Ok(Value::BulkString(b"embstr".to_vec()))
+        );
+    }
+
+    #[test]
+    fn convert_object_freq_to_int_or_null() {
+        assert!(matches!(
+            expected_type_for_cmd(redis::cmd("OBJECT").arg("FREQ").arg("key")),
+            Some(ExpectedReturnType::IntOrNull)
+        ));
+
+        assert_eq!(
+            convert_to_expected_type(Value::Nil, Some(ExpectedReturnType::IntOrNull)),
+            Ok(Value::Nil)
+        );
+        assert_eq!(
+            convert_to_expected_type(
+                Value::BulkString(b"5".to_vec()),
+                Some(ExpectedReturnType::IntOrNull)
+            ),
+            Ok(Value::Int(5))
+        );
+        assert!(
+            convert_to_expected_type(
+                Value::BulkString(b"five".to_vec()),
+                Some(ExpectedReturnType::IntOrNull)
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_convert_to_map_of_string_to_double() {
         assert_eq!(
@@ -0,0 +1,297 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Aggregation of `CLIENT LIST` across the nodes, so every wrapper lists the connections of a cluster the same way.
+
+use redis::{ErrorKind, RedisError, RedisResult, Value};
+use std::time::Duration;
+
+/// The fields of a `CLIENT LIST` entry that are converted to integers. Other fields are kept as strings.
+const INTEGER_FIELDS: [&str; 22] = [
+    "id",
+    "fd",
+    "age",
+    "idle",
+    "db",
+    "sub",
+    "psub",
+    "ssub",
+    "multi",
+    "watch",
+    "qbuf",
+    "qbuf-free",
+    "argv-mem",
+    "multi-mem",
+    "obl",
+    "oll",
+    "omem",
+    "tot-mem",
+    "rbs",
+    "rbp",
+    "tot-net-in",
+    "tot-net-out",
+];
+
+/// The type of the connections listed by `CLIENT LIST TYPE`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(C)]
+pub enum ClientConnectionType {
+    Normal,
+    Master,
+    Replica,
+    PubSub,
+}
+
+impl ClientConnectionType {
+    fn as_arg(self) -> &'static str {
+        match self {
+            ClientConnectionType::Normal => "normal",
+            ClientConnectionType::Master => "master",
+            ClientConnectionType::Replica => "replica",
+            ClientConnectionType::PubSub => "pubsub",
+        }
+    }
+}
+
+/// The filters of the listed connections. Connections are listed if they match all the set filters.
+#[derive(Clone, Debug, Default)]
+pub struct ClientListFilters {
+    /// Only list connections of this type. Filtered by the server.
+    pub client_type: Option<ClientConnectionType>,
+    /// Only list connections open for at least this long.
+    pub min_age: Option<Duration>,
+    /// Only list connections idle for at least this long.
+    pub min_idle: Option<Duration>,
+}
+
+impl ClientListFilters {
+    /// Returns the `CLIENT LIST` command sent to each node.
+    pub(crate) fn command(&self) -> redis::Cmd {
+        let mut cmd = redis::cmd("CLIENT");
+        cmd.arg("LIST");
+        if let Some(client_type) = self.client_type {
+            cmd.arg("TYPE").arg(client_type.as_arg());
+        }
+        cmd
+    }
+
+    fn matches(&self, entry: &[(Value, Value)]) -> bool {
+        let at_least = |field: &str, min: Option<Duration>| {
+            let Some(min) = min else {
+                return true;
+            };
+            entry.iter().any(|(name, value)| {
+                matches!(name, Value::SimpleString(name) if name == field)
+                    && matches!(value, Value::Int(seconds) if *seconds >= min.as_secs() as i64)
+            })
+        };
+        at_least("age", self.min_age) && at_least("idle", self.min_idle)
+    }
+}
+
+/// Builds a report of the `CLIENT LIST` replies of the nodes, so a failing node doesn't hide the connections of the
+/// others.
+///
+/// The report is a map of:
+/// * `clients` to a map of the addresses of the nodes that responded, sorted by address, to an array with a map per
+///   listed connection. Each map holds the fields of the connection, such as `id`, `addr`, `name`, `age`, `idle` and
+///   `flags`, with the numeric fields converted to integers.
+/// * `failed` to a map of the addresses of the nodes that failed to the error message.
+pub fn client_lists_report(
+    mut results: Vec<(String, RedisResult<Value>)>,
+    filters: &ClientListFilters,
+) -> Value {
+    results.sort_by(|(address, _), (other, _)| address.cmp(other));
+    let mut clients = Vec::new();
+    let mut failed = Vec::new();
+    for (address, result) in results {
+        let address = Value::BulkString(address.into_bytes());
+        match result.and_then(|value| parse_client_list(value, filters)) {
+            Ok(entries) => clients.push((address, Value::Array(entries))),
+            Err(err) => failed.push((address, Value::SimpleString(err.to_string()))),
+        }
+    }
+    Value::Map(vec![
+        (
+            Value::SimpleString("clients".to_string()),
+            Value::Map(clients),
+        ),
+        (
+            Value::SimpleString("failed".to_string()),
+            Value::Map(failed),
+        ),
+    ])
+}
+
+fn parse_client_list(value: Value, filters: &ClientListFilters) -> RedisResult<Vec<Value>> {
+    let text = match value {
+        Value::BulkString(text) => text,
+        Value::VerbatimString { text, .. } => text.into_bytes(),
+        value => {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for CLIENT LIST",
+                format!("(response was {value:?})"),
+            )));
+        }
+    };
+    Ok(text
+        .split(|byte| *byte == b'\n')
+        .map(parse_client_entry)
+        .filter(|entry| !entry.is_empty() && filters.matches(entry))
+        .map(Value::Map)
+        .collect())
+}
+
+/// Parses a line of `CLIENT LIST`, or the reply of `CLIENT INFO`, of space-separated `name=value` fields.
+pub fn parse_client_entry(line: &[u8]) -> Vec<(Value, Value)> {
+    line.trim_ascii()
+        .split(|byte| *byte == b' ')
+        .filter_map(|field| {
+            let separator = field.iter().position(|byte| *byte == b'=')?;
+            let name = String::from_utf8_lossy(&field[..separator]).into_owned();
+            let value = &field[separator + 1..];
+            let value = match std::str::from_utf8(value)
+                .ok()
+                .and_then(|value| value.parse().ok())
+            {
+                Some(integer) if INTEGER_FIELDS.contains(&name.as_str()) => Value::Int(integer),
+                _ => Value::BulkString(value.to_vec()),
+            };
+            Some((Value::SimpleString(name), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map_value<'a>(map: &'a Value, key: &Value) -> &'a Value {
+        let Value::Map(entries) = map else {
+            panic!("expected a map");
+        };
+        &entries.iter().find(|(name, _)| name == key).unwrap().1
+    }
+
+    fn field<'a>(entry: &'a Value, name: &str) -> &'a Value {
+        map_value(entry, &Value::SimpleString(name.to_string()))
+    }
+
+    fn node_clients<'a>(report: &'a Value, address: &str) -> &'a [Value] {
+        let Value::Array(entries) = map_value(
+            field(report, "clients"),
+            &Value::BulkString(address.as_bytes().to_vec()),
+        ) else {
+            panic!("expected an array");
+        };
+        entries
+    }
+
+    #[test]
+    fn test_client_lists_report() {
+        let node1 = "id=3 addr=10.0.0.1:50000 laddr=10.0.0.2:6379 fd=8 name=worker age=120 idle=100 flags=N db=0 cmd=get\n\
+                     id=4 addr=10.0.0.1:50001 laddr=10.0.0.2:6379 fd=9 name= age=5 idle=0 flags=N db=0 cmd=client|list\n";
+        let node2 = "id=7 addr=10.0.0.3:50002 laddr=10.0.0.4:6379 fd=10 name=42 age=300 idle=200 flags=P db=0 cmd=subscribe\r\n";
+        let results = vec![
+            (
+                "node2:6379".to_string(),
+                Ok(Value::VerbatimString {
+                    format: redis::VerbatimFormat::Text,
+                    text: node2.to_string(),
+                }),
+            ),
+            (
+                "node1:6379".to_string(),
+                Ok(Value::BulkString(node1.as_bytes().to_vec())),
+            ),
+        ];
+
+        let filters = ClientListFilters {
+            min_idle: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        let report = client_lists_report(results, &filters);
+        let Value::Map(clients) = field(&report, "clients") else {
+            panic!("expected a map");
+        };
+        assert_eq!(
+            clients
+                .iter()
+                .map(|(address, _)| address.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Value::BulkString(b"node1:6379".to_vec()),
+                Value::BulkString(b"node2:6379".to_vec())
+            ]
+        );
+        assert_eq!(field(&report, "failed"), &Value::Map(vec![]));
+
+        let node1_clients = node_clients(&report, "node1:6379");
+        assert_eq!(node1_clients.len(), 1);
+        assert_eq!(field(&node1_clients[0], "id"), &Value::Int(3));
+        assert_eq!(
+            field(&node1_clients[0], "name"),
+            &Value::BulkString(b"worker".to_vec())
+        );
+        assert_eq!(
+            field(&node1_clients[0], "cmd"),
+            &Value::BulkString(b"get".to_vec())
+        );
+        // Numeric names aren't converted, and the trailing carriage return is trimmed.
+        let node2_clients = node_clients(&report, "node2:6379");
+        assert_eq!(node2_clients.len(), 1);
+        assert_eq!(
+            field(&node2_clients[0], "name"),
+            &Value::BulkString(b"42".to_vec())
+        );
+        assert_eq!(
+            field(&node2_clients[0], "cmd"),
+            &Value::BulkString(b"subscribe".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_client_lists_report_keeps_the_clients_of_the_other_nodes_on_node_error() {
+        let results = vec![
+            (
+                "node1:6379".to_string(),
+                Ok(Value::BulkString(b"id=3 age=1".to_vec())),
+            ),
+            (
+                "node2:6379".to_string(),
+                Err(RedisError::from((ErrorKind::IoError, "disconnected"))),
+            ),
+            ("node3:6379".to_string(), Ok(Value::Okay)),
+        ];
+        let report = client_lists_report(results, &ClientListFilters::default());
+
+        let node1_clients = node_clients(&report, "node1:6379");
+        assert_eq!(node1_clients.len(), 1);
+        assert_eq!(field(&node1_clients[0], "id"), &Value::Int(3));
+        let Value::Map(failed) = field(&report, "failed") else {
+            panic!("expected a map");
+        };
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0].0, Value::BulkString(b"node2:6379".to_vec()));
+        assert!(
+            matches!(&failed[0].1, Value::SimpleString(message) if message.contains("disconnected"))
+        );
+        assert_eq!(failed[1].0, Value::BulkString(b"node3:6379".to_vec()));
+    }
+
+    #[test]
+    fn test_client_list_command() {
+        let filters = ClientListFilters {
+            client_type: Some(ClientConnectionType::PubSub),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.command().get_packed_command(),
+            redis::cmd("CLIENT")
+                .arg("LIST")
+                .arg("TYPE")
+                .arg("pubsub")
+                .get_packed_command()
+        );
+    }
+}
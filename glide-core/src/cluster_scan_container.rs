@@ -1,10 +1,14 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use logger_core::log_debug;
+use crate::client::get_or_init_runtime;
+use logger_core::{log_debug, log_warn};
 use nanoid::nanoid;
 use once_cell::sync::Lazy;
 use redis::{RedisResult, ScanStateRC};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, sync::Mutex};
+use telemetrylib::Telemetry;
 
 // This is a container for storing the cursor of a cluster scan.
 // The cursor for a cluster scan is a ref to the actual ScanState struct in redis-rs.
@@ -13,13 +17,40 @@ use std::{collections::HashMap, sync::Mutex};
 // The cursor is stored in the container and can be retrieved using the id.
 // In wrapper layer we wrap the id in an object, which, when dropped, trigger the removal of the cursor from the container.
 // When the ref is removed from the container, the actual ScanState struct is dropped by Rust GC.
+// A wrapper that crashes mid-scan never drops its object, so when a TTL is set with `set_cluster_scan_cursor_ttl`,
+// cursors that weren't used for longer than the TTL are reaped whenever a cursor is inserted, and by a periodic task,
+// so they're reaped even if no cursor is inserted anymore.
 
-static CONTAINER: Lazy<Mutex<HashMap<String, ScanStateRC>>> =
+struct CursorEntry {
+    scan_state: ScanStateRC,
+    last_used: Instant,
+}
+
+static CONTAINER: Lazy<Mutex<HashMap<String, CursorEntry>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+static CURSOR_TTL: Mutex<Option<Duration>> = Mutex::new(None);
+
+static REAPER_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// The longest time between two runs of the periodic reaping. Shorter TTLs are reaped at the interval of the TTL.
+const MAX_REAP_INTERVAL: Duration = Duration::from_secs(60);
+
 pub fn insert_cluster_scan_cursor(scan_state: ScanStateRC) -> String {
     let id = nanoid!();
-    CONTAINER.lock().unwrap().insert(id.clone(), scan_state);
+    let now = Instant::now();
+    let ttl = *CURSOR_TTL.lock().unwrap();
+    let mut container = CONTAINER.lock().unwrap();
+    if let Some(ttl) = ttl {
+        report_reaped_cursors(reap_expired_cursors(&mut container, ttl, now));
+    }
+    container.insert(
+        id.clone(),
+        CursorEntry {
+            scan_state,
+            last_used: now,
+        },
+    );
     log_debug(
         "scan_state_cursor insert",
         format!("Inserted to container scan_state_cursor with id: `{id:?}`"),
@@ -28,7 +59,10 @@ pub fn insert_cluster_scan_cursor(scan_state: ScanStateRC) -> String {
 }
 
 pub fn get_cluster_scan_cursor(id: String) -> RedisResult<ScanStateRC> {
-    let scan_state_rc = CONTAINER.lock().unwrap().get(&id).cloned();
+    let scan_state_rc = CONTAINER.lock().unwrap().get_mut(&id).map(|entry| {
+        entry.last_used = Instant::now();
+        entry.scan_state.clone()
+    });
     log_debug(
         "scan_state_cursor get",
         format!("Retrieved from container scan_state_cursor with id: `{id:?}`"),
@@ -50,3 +84,127 @@ pub fn remove_scan_state_cursor(id: String) {
     );
     CONTAINER.lock().unwrap().remove(&id);
 }
+
+/// Cancels the cluster scan of the cursor, so resuming it fails with an invalid cursor error.
+/// Returns `false` if the cursor doesn't exist, such as when it was already removed, cancelled or reaped.
+pub fn cancel_cluster_scan(id: &str) -> bool {
+    let cancelled = CONTAINER.lock().unwrap().remove(id).is_some();
+    log_debug(
+        "scan_state_cursor cancel",
+        format!("Cancelled cluster scan of scan_state_cursor with id: `{id:?}`: {cancelled}"),
+    );
+    cancelled
+}
+
+/// Sets how long a cursor may stay unused before it's reaped, or `None` to keep the cursors until they're removed.
+pub fn set_cluster_scan_cursor_ttl(ttl: Option<Duration>) {
+    *CURSOR_TTL.lock().unwrap() = ttl;
+    if ttl.is_some() && !REAPER_STARTED.swap(true, Ordering::AcqRel) {
+        start_reaper();
+    }
+}
+
+// Reaps the expired cursors periodically, for as long as the runtime lives. Once started, the task keeps running when
+// the TTL is cleared, and reaps nothing until a TTL is set again.
+fn start_reaper() {
+    let runtime = match get_or_init_runtime() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            REAPER_STARTED.store(false, Ordering::Release);
+            log_warn(
+                "scan_state_cursor reap",
+                format!("Failed to start the periodic reaping of scan_state_cursors: {err}"),
+            );
+            return;
+        }
+    };
+    runtime.runtime.spawn(async {
+        loop {
+            let interval = CURSOR_TTL
+                .lock()
+                .unwrap()
+                .map_or(MAX_REAP_INTERVAL, |ttl| ttl.min(MAX_REAP_INTERVAL));
+            tokio::time::sleep(interval).await;
+            reap_expired_cluster_scan_cursors();
+        }
+    });
+}
+
+/// Reaps the cursors that weren't used for longer than the TTL set with [`set_cluster_scan_cursor_ttl`].
+/// Returns the number of reaped cursors.
+pub fn reap_expired_cluster_scan_cursors() -> usize {
+    let Some(ttl) = *CURSOR_TTL.lock().unwrap() else {
+        return 0;
+    };
+    let reaped = reap_expired_cursors(&mut CONTAINER.lock().unwrap(), ttl, Instant::now());
+    report_reaped_cursors(reaped);
+    reaped
+}
+
+fn reap_expired_cursors(
+    container: &mut HashMap<String, CursorEntry>,
+    ttl: Duration,
+    now: Instant,
+) -> usize {
+    let count = container.len();
+    container.retain(|_, entry| now.saturating_duration_since(entry.last_used) <= ttl);
+    count - container.len()
+}
+
+fn report_reaped_cursors(reaped: usize) {
+    if reaped == 0 {
+        return;
+    }
+    Telemetry::incr_cluster_scan_cursors_reaped(reaped);
+    log_warn(
+        "scan_state_cursor reap",
+        format!("Reaped {reaped} scan_state_cursors that weren't used within their TTL"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reap_expired_cursors_keeps_recently_used() {
+        let now = Instant::now();
+        let mut container = HashMap::new();
+        for (id, age) in [("old", 10), ("recent", 1)] {
+            container.insert(
+                id.to_string(),
+                CursorEntry {
+                    scan_state: ScanStateRC::new(),
+                    last_used: now - Duration::from_secs(age),
+                },
+            );
+        }
+
+        assert_eq!(
+            reap_expired_cursors(&mut container, Duration::from_secs(5), now),
+            1
+        );
+        assert!(container.contains_key("recent"));
+        assert!(!container.contains_key("old"));
+    }
+
+    #[test]
+    fn test_expired_cursors_are_reaped_periodically() {
+        set_cluster_scan_cursor_ttl(Some(Duration::from_millis(100)));
+        let id = insert_cluster_scan_cursor(ScanStateRC::new());
+
+        // Unless another cursor is inserted meanwhile, the cursor can only be reaped by the periodic task.
+        std::thread::sleep(Duration::from_millis(500));
+        let reaped = get_cluster_scan_cursor(id).is_err();
+        set_cluster_scan_cursor_ttl(None);
+        assert!(reaped);
+    }
+
+    #[test]
+    fn test_cancel_cluster_scan() {
+        let id = insert_cluster_scan_cursor(ScanStateRC::new());
+        assert!(cancel_cluster_scan(&id));
+        assert!(get_cluster_scan_cursor(id.clone()).is_err());
+        assert!(!cancel_cluster_scan(&id));
+    }
+}
@@ -0,0 +1,432 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Normalization of the `CLUSTER SLOTS` and `CLUSTER SHARDS` responses, which are deeply nested arrays whose layout
+//! depends on the command and on the protocol, into a single shape shared by all wrappers:
+//!
+//! ```text
+//! [
+//!   {
+//!     slots => [ [start, end], ... ],
+//!     nodes => [ { id, host, port, role => primary | replica, health }, ... ],
+//!   },
+//!   ...
+//! ]
+//! ```
+//!
+//! The first node of each shard is its primary. `CLUSTER SLOTS` doesn't report the health of the nodes, so `health`
+//! is `null` in its normalized response.
+
+use redis::{Cmd, ErrorKind, RedisError, RedisResult, Value};
+
+const PRIMARY_ROLE: &str = "primary";
+const REPLICA_ROLE: &str = "replica";
+
+struct Shard {
+    slots: Vec<(i64, i64)>,
+    nodes: Vec<Node>,
+}
+
+struct Node {
+    id: Value,
+    host: Value,
+    port: Value,
+    role: &'static str,
+    health: Value,
+}
+
+/// Returns true if the response of the command can be normalized with [`normalize_cluster_topology`].
+pub fn is_cluster_topology_command(cmd: &Cmd) -> bool {
+    matches!(
+        cmd.command().as_deref(),
+        Some(b"CLUSTER SLOTS" | b"CLUSTER SHARDS")
+    )
+}
+
+/// Normalizes the response of `CLUSTER SLOTS` or `CLUSTER SHARDS` into a list of shards, as described in the module
+/// documentation. Responses of commands that were routed to multiple nodes are normalized per node.
+/// Responses of other commands, and server errors, are returned as is.
+pub fn normalize_cluster_topology(cmd: &Cmd, value: Value) -> RedisResult<Value> {
+    let normalize: fn(Value) -> RedisResult<Vec<Shard>> = match cmd.command().as_deref() {
+        Some(b"CLUSTER SLOTS") => parse_cluster_slots,
+        Some(b"CLUSTER SHARDS") => parse_cluster_shards,
+        _ => return Ok(value),
+    };
+    match value {
+        Value::ServerError(_) => Ok(value),
+        // Multi-node responses are mapped by the node address.
+        Value::Map(responses) => responses
+            .into_iter()
+            .map(|(address, response)| Ok((address, shards_to_value(normalize(response)?))))
+            .collect::<RedisResult<_>>()
+            .map(Value::Map),
+        value => Ok(shards_to_value(normalize(value)?)),
+    }
+}
+
+fn unexpected_response(value: &Value) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response couldn't be converted to cluster topology",
+        format!("(response was {value:?})"),
+    ))
+}
+
+fn into_array(value: Value) -> RedisResult<Vec<Value>> {
+    match value {
+        Value::Array(values) => Ok(values),
+        value => Err(unexpected_response(&value)),
+    }
+}
+
+fn into_int(value: &Value) -> RedisResult<i64> {
+    match value {
+        Value::Int(value) => Ok(*value),
+        value => Err(unexpected_response(value)),
+    }
+}
+
+/// Returns the fields of a RESP3 map, or of a RESP2 array of alternating field names and values.
+fn into_fields(value: Value) -> RedisResult<Vec<(String, Value)>> {
+    let pairs = match value {
+        Value::Map(pairs) => pairs,
+        Value::Array(values) if values.len() % 2 == 0 => {
+            let mut values = values.into_iter();
+            std::iter::from_fn(|| Some((values.next()?, values.next()?))).collect()
+        }
+        value => return Err(unexpected_response(&value)),
+    };
+    pairs
+        .into_iter()
+        .map(|(name, value)| match name {
+            Value::BulkString(name) => Ok((String::from_utf8_lossy(&name).into_owned(), value)),
+            Value::SimpleString(name) => Ok((name, value)),
+            name => Err(unexpected_response(&name)),
+        })
+        .collect()
+}
+
+/// Parses `CLUSTER SLOTS`: `[ [start, end, [host, port, id, ...], replica, ...], ... ]`.
+/// Slot ranges served by the same primary are grouped into a single shard.
+fn parse_cluster_slots(value: Value) -> RedisResult<Vec<Shard>> {
+    let mut shards: Vec<Shard> = Vec::new();
+    for slot_range in into_array(value)? {
+        let mut slot_range = into_array(slot_range)?.into_iter();
+        let (Some(start), Some(end)) = (slot_range.next(), slot_range.next()) else {
+            return Err(unexpected_response(&Value::Nil));
+        };
+        let slots = (into_int(&start)?, into_int(&end)?);
+        let nodes = slot_range
+            .enumerate()
+            .map(|(index, node)| {
+                let mut node = into_array(node)?.into_iter();
+                let (Some(host), Some(port)) = (node.next(), node.next()) else {
+                    return Err(unexpected_response(&Value::Nil));
+                };
+                into_int(&port)?;
+                Ok(Node {
+                    id: node.next().unwrap_or(Value::Nil),
+                    host,
+                    port,
+                    role: if index == 0 {
+                        PRIMARY_ROLE
+                    } else {
+                        REPLICA_ROLE
+                    },
+                    health: Value::Nil,
+                })
+            })
+            .collect::<RedisResult<Vec<_>>>()?;
+
+        let Some(primary) = nodes.first() else {
+            return Err(unexpected_response(&Value::Nil));
+        };
+        let same_primary = |shard: &&mut Shard| {
+            let shard_primary = &shard.nodes[0];
+            match primary.id {
+                Value::Nil => {
+                    shard_primary.host == primary.host && shard_primary.port == primary.port
+                }
+                _ => shard_primary.id == primary.id,
+            }
+        };
+        match shards.iter_mut().find(same_primary) {
+            Some(shard) => shard.slots.push(slots),
+            None => shards.push(Shard {
+                slots: vec![slots],
+                nodes,
+            }),
+        }
+    }
+    Ok(shards)
+}
+
+/// Parses `CLUSTER SHARDS`: `[ { slots => [start, end, ...], nodes => [ { id, ip, endpoint, port, role, health, ... } ] } ]`.
+fn parse_cluster_shards(value: Value) -> RedisResult<Vec<Shard>> {
+    into_array(value)?
+        .into_iter()
+        .map(|shard| {
+            let mut slots = Vec::new();
+            let mut nodes = Vec::new();
+            for (name, value) in into_fields(shard)? {
+                match name.as_str() {
+                    "slots" => {
+                        let bounds = into_array(value)?;
+                        if bounds.len() % 2 != 0 {
+                            return Err(unexpected_response(&Value::Array(bounds)));
+                        }
+                        for range in bounds.chunks(2) {
+                            slots.push((into_int(&range[0])?, into_int(&range[1])?));
+                        }
+                    }
+                    "nodes" => {
+                        for node in into_array(value)? {
+                            nodes.push(parse_shard_node(node)?);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // The primary is listed first, as in `CLUSTER SLOTS`.
+            nodes.sort_by_key(|node| node.role != PRIMARY_ROLE);
+            Ok(Shard { slots, nodes })
+        })
+        .collect()
+}
+
+fn parse_shard_node(node: Value) -> RedisResult<Node> {
+    let mut id = Value::Nil;
+    let mut ip = Value::Nil;
+    let mut endpoint = Value::Nil;
+    let mut port = Value::Nil;
+    let mut tls_port = Value::Nil;
+    let mut role = REPLICA_ROLE;
+    let mut health = Value::Nil;
+    for (name, value) in into_fields(node)? {
+        match name.as_str() {
+            "id" => id = value,
+            "ip" => ip = value,
+            "endpoint" => endpoint = value,
+            "port" => port = value,
+            "tls-port" => tls_port = value,
+            "role" => {
+                if matches!(&value, Value::BulkString(role) if role == b"master")
+                    || matches!(&value, Value::SimpleString(role) if role == "master")
+                {
+                    role = PRIMARY_ROLE;
+                }
+            }
+            "health" => health = value,
+            _ => {}
+        }
+    }
+    // The endpoint is the address that clients are expected to connect to, and may be a hostname.
+    let host = match endpoint {
+        Value::Nil => ip,
+        Value::BulkString(ref endpoint) if endpoint.is_empty() || endpoint == b"?" => ip,
+        endpoint => endpoint,
+    };
+    Ok(Node {
+        id,
+        host,
+        port: match port {
+            Value::Nil => tls_port,
+            port => port,
+        },
+        role,
+        health,
+    })
+}
+
+fn field(name: &str, value: Value) -> (Value, Value) {
+    (Value::SimpleString(name.to_string()), value)
+}
+
+fn shards_to_value(shards: Vec<Shard>) -> Value {
+    Value::Array(
+        shards
+            .into_iter()
+            .map(|shard| {
+                Value::Map(vec![
+                    field(
+                        "slots",
+                        Value::Array(
+                            shard
+                                .slots
+                                .into_iter()
+                                .map(|(start, end)| {
+                                    Value::Array(vec![Value::Int(start), Value::Int(end)])
+                                })
+                                .collect(),
+                        ),
+                    ),
+                    field(
+                        "nodes",
+                        Value::Array(
+                            shard
+                                .nodes
+                                .into_iter()
+                                .map(|node| {
+                                    Value::Map(vec![
+                                        field("id", node.id),
+                                        field("host", node.host),
+                                        field("port", node.port),
+                                        field("role", Value::SimpleString(node.role.to_string())),
+                                        field("health", node.health),
+                                    ])
+                                })
+                                .collect(),
+                        ),
+                    ),
+                ])
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    fn expected_node(id: &str, port: i64, role: &str, health: Value) -> Value {
+        Value::Map(vec![
+            field("id", bulk(id)),
+            field("host", bulk("127.0.0.1")),
+            field("port", Value::Int(port)),
+            field("role", Value::SimpleString(role.to_string())),
+            field("health", health),
+        ])
+    }
+
+    #[test]
+    fn test_normalize_cluster_slots() {
+        let node = |port: i64, id: &str| {
+            Value::Array(vec![
+                bulk("127.0.0.1"),
+                Value::Int(port),
+                bulk(id),
+                Value::Array(vec![]),
+            ])
+        };
+        let response = Value::Array(vec![
+            Value::Array(vec![
+                Value::Int(0),
+                Value::Int(100),
+                node(7000, "a"),
+                node(7001, "b"),
+            ]),
+            Value::Array(vec![Value::Int(101), Value::Int(16383), node(7002, "c")]),
+            Value::Array(vec![
+                Value::Int(200),
+                Value::Int(300),
+                node(7000, "a"),
+                node(7001, "b"),
+            ]),
+        ]);
+
+        let normalized =
+            normalize_cluster_topology(&redis::cmd("CLUSTER").arg("SLOTS"), response).unwrap();
+        assert_eq!(
+            normalized,
+            Value::Array(vec![
+                Value::Map(vec![
+                    field(
+                        "slots",
+                        Value::Array(vec![
+                            Value::Array(vec![Value::Int(0), Value::Int(100)]),
+                            Value::Array(vec![Value::Int(200), Value::Int(300)]),
+                        ])
+                    ),
+                    field(
+                        "nodes",
+                        Value::Array(vec![
+                            expected_node("a", 7000, "primary", Value::Nil),
+                            expected_node("b", 7001, "replica", Value::Nil),
+                        ])
+                    ),
+                ]),
+                Value::Map(vec![
+                    field(
+                        "slots",
+                        Value::Array(vec![Value::Array(vec![Value::Int(101), Value::Int(16383)])])
+                    ),
+                    field(
+                        "nodes",
+                        Value::Array(vec![expected_node("c", 7002, "primary", Value::Nil)])
+                    ),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_normalize_cluster_shards() {
+        // A RESP2 response, in which maps are flat arrays, with the replica listed first.
+        let node = |id: &str, port: i64, role: &str| {
+            Value::Array(vec![
+                bulk("id"),
+                bulk(id),
+                bulk("port"),
+                Value::Int(port),
+                bulk("ip"),
+                bulk("127.0.0.1"),
+                bulk("endpoint"),
+                bulk("127.0.0.1"),
+                bulk("role"),
+                bulk(role),
+                bulk("health"),
+                bulk("online"),
+            ])
+        };
+        let response = Value::Array(vec![Value::Array(vec![
+            bulk("slots"),
+            Value::Array(vec![
+                Value::Int(0),
+                Value::Int(5460),
+                Value::Int(6000),
+                Value::Int(6001),
+            ]),
+            bulk("nodes"),
+            Value::Array(vec![node("b", 7001, "replica"), node("a", 7000, "master")]),
+        ])]);
+
+        let normalized =
+            normalize_cluster_topology(&redis::cmd("CLUSTER").arg("SHARDS"), response).unwrap();
+        assert_eq!(
+            normalized,
+            Value::Array(vec![Value::Map(vec![
+                field(
+                    "slots",
+                    Value::Array(vec![
+                        Value::Array(vec![Value::Int(0), Value::Int(5460)]),
+                        Value::Array(vec![Value::Int(6000), Value::Int(6001)]),
+                    ])
+                ),
+                field(
+                    "nodes",
+                    Value::Array(vec![
+                        expected_node("a", 7000, "primary", bulk("online")),
+                        expected_node("b", 7001, "replica", bulk("online")),
+                    ])
+                ),
+            ])])
+        );
+    }
+
+    #[test]
+    fn test_normalize_cluster_topology_ignores_other_commands() {
+        let get = redis::cmd("GET").arg("key").clone();
+        assert!(!is_cluster_topology_command(&get));
+        assert_eq!(
+            normalize_cluster_topology(&get, bulk("value")).unwrap(),
+            bulk("value")
+        );
+        assert!(
+            normalize_cluster_topology(&redis::cmd("CLUSTER").arg("SHARDS"), bulk("value"))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Latency histograms of the commands sent by the client, keyed by [`RequestType`].
+//!
+//! Collection is disabled by default and can be switched on with [`set_enabled`].
+//! Latencies are stored in HDR-style buckets: each power of two is split into [`SUB_BUCKETS`] linear buckets,
+//! so a reported percentile is within ~6% of the recorded latency, regardless of its magnitude.
+
+use crate::request_type::RequestType;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+const LOCK_ERR: &str = "Failed to acquire the command latency histograms lock";
+const SUB_BUCKET_BITS: u32 = 4;
+const SUB_BUCKETS: u64 = 1 << SUB_BUCKET_BITS;
+// Values below `SUB_BUCKETS` get a bucket each, and every following power of two up to 2^63 gets `SUB_BUCKETS` buckets.
+const BUCKETS_COUNT: usize = ((64 - SUB_BUCKET_BITS + 1) as u64 * SUB_BUCKETS) as usize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static HISTOGRAMS: Lazy<RwLock<HashMap<u32, Arc<LatencyHistogram>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The latency percentiles of a single command type, in microseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// Number of latencies recorded since the histogram was created or last reset.
+    pub count: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+}
+
+pub(crate) struct LatencyHistogram {
+    buckets: Box<[AtomicU64]>,
+}
+
+impl LatencyHistogram {
+    pub(crate) fn new() -> Self {
+        Self {
+            buckets: (0..BUCKETS_COUNT).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    pub(crate) fn record(&self, latency_us: u64) {
+        self.buckets[bucket_index(latency_us)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reset(&self) {
+        for bucket in self.buckets.iter() {
+            bucket.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn percentiles(&self) -> LatencyPercentiles {
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|bucket| bucket.load(Ordering::Relaxed))
+            .collect();
+        let count: u64 = counts.iter().sum();
+        LatencyPercentiles {
+            count,
+            p50_us: percentile(&counts, count, 50.0),
+            p95_us: percentile(&counts, count, 95.0),
+            p99_us: percentile(&counts, count, 99.0),
+        }
+    }
+}
+
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKETS {
+        return value as usize;
+    }
+    let exponent = 63 - value.leading_zeros();
+    let sub_bucket = (value >> (exponent - SUB_BUCKET_BITS)) & (SUB_BUCKETS - 1);
+    ((exponent - SUB_BUCKET_BITS + 1) as u64 * SUB_BUCKETS + sub_bucket) as usize
+}
+
+/// Returns the highest value that falls in the bucket.
+fn bucket_upper_bound(index: usize) -> u64 {
+    let index = index as u64;
+    if index < SUB_BUCKETS {
+        return index;
+    }
+    let exponent = (index / SUB_BUCKETS) as u32 + SUB_BUCKET_BITS - 1;
+    let sub_bucket = index % SUB_BUCKETS;
+    let width = 1u64 << (exponent - SUB_BUCKET_BITS);
+    ((SUB_BUCKETS + sub_bucket) << (exponent - SUB_BUCKET_BITS)).saturating_add(width - 1)
+}
+
+fn percentile(counts: &[u64], total: u64, percentile: f64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+    let rank = ((percentile / 100.0) * total as f64).ceil().max(1.0) as u64;
+    let mut seen = 0;
+    for (index, count) in counts.iter().enumerate() {
+        seen += count;
+        if seen >= rank {
+            return bucket_upper_bound(index);
+        }
+    }
+    bucket_upper_bound(counts.len() - 1)
+}
+
+/// Enables or disables the collection of command latencies. Histograms that were already collected are kept.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records the latency of a command, if collection is enabled.
+pub fn record(request_type: RequestType, latency: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+    let key = request_type as u32;
+
+    let histogram = HISTOGRAMS.read().expect(LOCK_ERR).get(&key).cloned();
+    let histogram = match histogram {
+        Some(histogram) => histogram,
+        None => HISTOGRAMS
+            .write()
+            .expect(LOCK_ERR)
+            .entry(key)
+            .or_insert_with(|| Arc::new(LatencyHistogram::new()))
+            .clone(),
+    };
+    histogram.record(latency_us);
+}
+
+/// Returns the latency percentiles of the command type. All values are zero if no latency was recorded for it.
+pub fn percentiles(request_type: RequestType) -> LatencyPercentiles {
+    HISTOGRAMS
+        .read()
+        .expect(LOCK_ERR)
+        .get(&(request_type as u32))
+        .map(|histogram| histogram.percentiles())
+        .unwrap_or_default()
+}
+
+/// Clears the histograms of all command types.
+pub fn reset() {
+    HISTOGRAMS.write().expect(LOCK_ERR).clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_bounds() {
+        for value in [0, 15, 16, 17, 1_000, 123_456, u64::MAX] {
+            let index = bucket_index(value);
+            assert!(index < BUCKETS_COUNT);
+            assert!(bucket_upper_bound(index) >= value);
+            if index > 0 {
+                assert!(bucket_upper_bound(index - 1) < value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let histogram = LatencyHistogram::new();
+        for latency in 1..=100 {
+            histogram.record(latency * 100);
+        }
+
+        let percentiles = histogram.percentiles();
+        assert_eq!(percentiles.count, 100);
+        assert!((5_000..5_400).contains(&percentiles.p50_us));
+        assert!((9_500..10_100).contains(&percentiles.p95_us));
+        assert!((9_900..10_500).contains(&percentiles.p99_us));
+        assert_eq!(
+            LatencyHistogram::new().percentiles(),
+            LatencyPercentiles::default()
+        );
+    }
+}
@@ -5,6 +5,7 @@
 
 use std::borrow::Cow;
 use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::request_type::RequestType;
 use telemetrylib::Telemetry;
@@ -306,10 +307,37 @@ impl std::fmt::Display for CommandCompressionBehavior {
     }
 }
 
+/// Compression statistics of a single client. Unlike the [`Telemetry`] counters, they aren't shared between clients.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompressionStatistics {
+    pub values_compressed: u64,
+    pub values_decompressed: u64,
+    /// Total size of the compressed values before compression.
+    pub original_bytes: u64,
+    /// Total size of the compressed values after compression.
+    pub compressed_bytes: u64,
+}
+
+impl CompressionStatistics {
+    /// Number of bytes that weren't sent to the server thanks to compression.
+    pub fn bytes_saved(&self) -> u64 {
+        self.original_bytes.saturating_sub(self.compressed_bytes)
+    }
+}
+
+#[derive(Debug, Default)]
+struct CompressionCounters {
+    values_compressed: AtomicU64,
+    values_decompressed: AtomicU64,
+    original_bytes: AtomicU64,
+    compressed_bytes: AtomicU64,
+}
+
 #[derive(Debug)]
 pub struct CompressionManager {
     backend: Box<dyn CompressionBackend>,
     config: CompressionConfig,
+    counters: CompressionCounters,
 }
 
 impl CompressionManager {
@@ -334,7 +362,11 @@ impl CompressionManager {
         // Validate compression level using backend-specific validation
         backend.validate_compression_level(config.compression_level)?;
 
-        Ok(Self { backend, config })
+        Ok(Self {
+            backend,
+            config,
+            counters: CompressionCounters::default(),
+        })
     }
 
     pub fn should_compress(&self, data: &[u8]) -> bool {
@@ -360,6 +392,15 @@ impl CompressionManager {
                     Telemetry::incr_total_values_compressed(1);
                     Telemetry::incr_total_original_bytes(value.len());
                     Telemetry::incr_total_bytes_compressed(compressed.len());
+                    self.counters
+                        .values_compressed
+                        .fetch_add(1, Ordering::Relaxed);
+                    self.counters
+                        .original_bytes
+                        .fetch_add(value.len() as u64, Ordering::Relaxed);
+                    self.counters
+                        .compressed_bytes
+                        .fetch_add(compressed.len() as u64, Ordering::Relaxed);
                     Cow::Owned(compressed)
                 } else {
                     // Compression didn't reduce size, skip it
@@ -401,6 +442,9 @@ impl CompressionManager {
             if let Ok(ref decompressed) = result {
                 Telemetry::incr_total_values_decompressed(1);
                 Telemetry::incr_total_bytes_decompressed(decompressed.len());
+                self.counters
+                    .values_decompressed
+                    .fetch_add(1, Ordering::Relaxed);
             }
 
             result
@@ -421,6 +465,16 @@ impl CompressionManager {
         self.config.enabled
     }
 
+    /// Returns the statistics of the values compressed and decompressed by this manager.
+    pub fn statistics(&self) -> CompressionStatistics {
+        CompressionStatistics {
+            values_compressed: self.counters.values_compressed.load(Ordering::Relaxed),
+            values_decompressed: self.counters.values_decompressed.load(Ordering::Relaxed),
+            original_bytes: self.counters.original_bytes.load(Ordering::Relaxed),
+            compressed_bytes: self.counters.compressed_bytes.load(Ordering::Relaxed),
+        }
+    }
+
     /// Attempts to decompress the value with graceful fallback to original data
     pub fn try_decompress_value(&self, value: &[u8]) -> Vec<u8> {
         self.decompress_value(value)
@@ -889,3 +943,24 @@ fn get_backend_for_decompression(
         ))),
     }
 }
+
+/// Returns a protobuf payload passed by a wrapper, such as a connection request or a batch, decompressing it if it
+/// was compressed with LZ4.
+///
+/// A wrapper may compress a large payload to reduce the cost of copying it across JNI/FFI, by framing it with the
+/// header of [`CompressionBackendType::Lz4`] exactly like a compressed value. A serialized protobuf message never
+/// starts with the magic prefix, since a field number can't be 0, so uncompressed payloads are returned as is.
+pub fn decompress_payload(data: &[u8]) -> CompressionResult<Cow<'_, [u8]>> {
+    if !has_magic_header(data) {
+        return Ok(Cow::Borrowed(data));
+    }
+    let backend = static_backends::get_lz4_backend();
+    if extract_backend_id(data) != Some(backend.backend_id()) {
+        return Err(CompressionError::decompression_failed(
+            backend.backend_name(),
+            data.len(),
+            "payloads can only be compressed with LZ4",
+        ));
+    }
+    backend.decompress(data).map(Cow::Owned)
+}
@@ -0,0 +1,216 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! A distributed lock on a single key, so every wrapper locks the same way.
+//!
+//! A lock is acquired with `SET NX PX` and a random token, and is only released or extended by the owner of the
+//! token, with scripts that compare the token before deleting or extending the key. An acquired lock may be extended
+//! automatically by the client until it's released, and the owner is notified when the lock is lost.
+
+use crate::client::Client;
+use logger_core::log_warn;
+use redis::{RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Deletes the lock if it's held with the token. Returns 1 if the lock was released, 0 otherwise.
+pub(crate) const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Sets the expiration of the lock if it's held with the token. Returns 1 if the lock was extended, 0 otherwise.
+pub(crate) const EXTEND_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+/// Called once when the automatic extension of a lock stops, with the key of the lock, and the reason the lock was
+/// lost, or `None` if it was released.
+pub type LockExtensionListener = Arc<dyn Fn(&[u8], Option<&str>) + Send + Sync>;
+
+struct LockExtension {
+    key: Vec<u8>,
+    listener: Option<LockExtensionListener>,
+    // Dropping the sender stops the extension task.
+    _released: oneshot::Sender<()>,
+}
+
+/// The locks of a client that are extended automatically, by token.
+#[derive(Default)]
+pub(crate) struct LockExtensions {
+    extensions: Mutex<HashMap<Vec<u8>, LockExtension>>,
+}
+
+impl LockExtensions {
+    /// Registers the extension of the lock held with `token`. The returned receiver is notified when the lock is
+    /// released.
+    fn register(
+        &self,
+        key: &[u8],
+        token: &[u8],
+        listener: Option<LockExtensionListener>,
+    ) -> oneshot::Receiver<()> {
+        let (released, receiver) = oneshot::channel();
+        let extension = LockExtension {
+            key: key.to_vec(),
+            listener,
+            _released: released,
+        };
+        self.extensions
+            .lock()
+            .unwrap()
+            .insert(token.to_vec(), extension);
+        receiver
+    }
+
+    /// Stops the extension of the lock held with `token`, and notifies its listener with `reason`, if the extension
+    /// wasn't already stopped.
+    pub(crate) fn stop(&self, token: &[u8], reason: Option<&str>) {
+        let extension = self.extensions.lock().unwrap().remove(token);
+        if let Some(LockExtension {
+            key,
+            listener: Some(listener),
+            ..
+        }) = extension
+        {
+            listener(&key, reason);
+        }
+    }
+
+    /// Stops the extension of every lock, without notifying the listeners, when the client is closed. The extension
+    /// tasks hold a clone of the client, which would otherwise keep its connections open and its locks extended.
+    pub(crate) fn stop_all(&self) {
+        self.extensions.lock().unwrap().clear();
+    }
+}
+
+/// Returns a new random token for a lock.
+pub(crate) fn new_token() -> Vec<u8> {
+    nanoid::nanoid!().into_bytes()
+}
+
+/// Extends the lock held with `token` to `ttl` every third of `ttl`, until it's released or lost.
+/// A failed extension is retried on the next interval, and the lock is lost once it's expected to have expired.
+pub(crate) fn spawn_extension(
+    client: Client,
+    extensions: Arc<LockExtensions>,
+    key: Vec<u8>,
+    token: Vec<u8>,
+    ttl: Duration,
+    listener: Option<LockExtensionListener>,
+) {
+    let released = extensions.register(&key, &token, listener);
+    tokio::spawn(extend_until_released(
+        client, extensions, key, token, ttl, released,
+    ));
+}
+
+async fn extend_until_released(
+    mut client: Client,
+    extensions: Arc<LockExtensions>,
+    key: Vec<u8>,
+    token: Vec<u8>,
+    ttl: Duration,
+    mut released: oneshot::Receiver<()>,
+) {
+    let interval = ttl / 3;
+    let mut expires_at = Instant::now() + ttl;
+    loop {
+        tokio::select! {
+            _ = &mut released => return,
+            _ = tokio::time::sleep(interval) => {}
+        }
+        let sent_at = Instant::now();
+        match client.extend_lock(&key, &token, ttl).await {
+            Ok(Value::Boolean(true)) => expires_at = sent_at + ttl,
+            Ok(_) => {
+                extensions.stop(&token, Some("The lock expired or is held by another owner"));
+                return;
+            }
+            Err(err) if Instant::now() >= expires_at => {
+                extensions.stop(&token, Some(&err.to_string()));
+                return;
+            }
+            Err(err) => log_warn(
+                "distributed_lock",
+                format!("Failed to extend the lock, retrying until it expires: {err}"),
+            ),
+        }
+    }
+}
+
+/// Converts the reply of the release and extend scripts to whether the lock was held with the token.
+pub(crate) fn convert_script_response(value: Value) -> RedisResult<Value> {
+    match value {
+        Value::Int(result) => Ok(Value::Boolean(result == 1)),
+        value => Err(redis::RedisError::from((
+            redis::ErrorKind::ResponseError,
+            "Unexpected response for the lock script",
+            format!("(response was {value:?})"),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_stop_notifies_listener_once() {
+        let extensions = LockExtensions::default();
+        let notifications = Arc::new(AtomicUsize::new(0));
+        let counter = notifications.clone();
+        let listener: LockExtensionListener = Arc::new(move |key, reason| {
+            assert_eq!(key, b"lock");
+            assert_eq!(reason, Some("lost"));
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let mut released = extensions.register(b"lock", b"token", Some(listener));
+
+        extensions.stop(b"token", Some("lost"));
+        extensions.stop(b"token", None);
+        assert_eq!(notifications.load(Ordering::SeqCst), 1);
+        // Stopping the extension also stops its task.
+        assert!(released.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_stop_all_stops_every_extension_without_notifying() {
+        let extensions = LockExtensions::default();
+        let listener: LockExtensionListener =
+            Arc::new(|_, _| panic!("listeners aren't notified when the client is closed"));
+        let mut first = extensions.register(b"lock1", b"token1", Some(listener.clone()));
+        let mut second = extensions.register(b"lock2", b"token2", Some(listener));
+
+        extensions.stop_all();
+        assert!(matches!(
+            first.try_recv(),
+            Err(oneshot::error::TryRecvError::Closed)
+        ));
+        assert!(matches!(
+            second.try_recv(),
+            Err(oneshot::error::TryRecvError::Closed)
+        ));
+        extensions.stop(b"token1", None);
+    }
+
+    #[test]
+    fn test_convert_script_response() {
+        assert_eq!(
+            convert_script_response(Value::Int(1)).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            convert_script_response(Value::Int(0)).unwrap(),
+            Value::Boolean(false)
+        );
+        assert!(convert_script_response(Value::Nil).is_err());
+    }
+}
@@ -9,10 +9,63 @@ pub enum RequestErrorType {
     ExecAbort = 1,
     Timeout = 2,
     Disconnect = 3,
+    /// A watched transaction was aborted because a watched key was modified.
+    WatchedKeyModified = 4,
+    /// The request failed fast, because the circuit breaker of its node is open.
+    CircuitOpen = 5,
+    /// A response of a batch doesn't match the response type expected for its command.
+    ResponseSchemaMismatch = 6,
+    /// The deadline of the request passed before it was sent, so the server didn't process it.
+    DeadlineExceededBeforeSend = 7,
+}
+
+/// The code of the error returned when a watched transaction is aborted because a watched key was modified.
+pub const WATCHED_KEY_MODIFIED_CODE: &str = "WATCHEDKEYMODIFIED";
+
+/// Returns the error of a watched transaction that was aborted because a watched key was modified.
+pub fn watched_key_modified_error() -> RedisError {
+    redis::make_extension_error(
+        WATCHED_KEY_MODIFIED_CODE.to_string(),
+        Some("Transaction aborted because a watched key was modified".to_string()),
+    )
+}
+
+/// The code of the error returned when a request fails fast because the circuit breaker of its node is open.
+pub const CIRCUIT_OPEN_CODE: &str = "CIRCUITOPEN";
+
+/// Returns the error of a request that failed fast because the circuit breaker of the node at `address` is open.
+pub fn circuit_open_error(address: &str) -> RedisError {
+    redis::make_extension_error(
+        CIRCUIT_OPEN_CODE.to_string(),
+        Some(format!(
+            "The circuit breaker of node `{address}` is open after consecutive failures"
+        )),
+    )
+}
+
+/// The code of the error returned when a response of a batch doesn't match the type expected for its command.
+pub const RESPONSE_SCHEMA_MISMATCH_CODE: &str = "RESPONSESCHEMAMISMATCH";
+
+/// Returns the error of a batch whose response to the command at `index` is of type `actual` instead of `expected`.
+pub fn response_schema_mismatch_error(index: usize, expected: &str, actual: &str) -> RedisError {
+    redis::make_extension_error(
+        RESPONSE_SCHEMA_MISMATCH_CODE.to_string(),
+        Some(format!(
+            "The response of command {index} of the batch is of type {actual}, expected {expected}"
+        )),
+    )
 }
 
 pub fn error_type(error: &RedisError) -> RequestErrorType {
-    if error.is_timeout() {
+    if error.code() == Some(WATCHED_KEY_MODIFIED_CODE) {
+        RequestErrorType::WatchedKeyModified
+    } else if error.code() == Some(CIRCUIT_OPEN_CODE) {
+        RequestErrorType::CircuitOpen
+    } else if error.code() == Some(RESPONSE_SCHEMA_MISMATCH_CODE) {
+        RequestErrorType::ResponseSchemaMismatch
+    } else if error.code() == Some(redis::DEADLINE_EXCEEDED_BEFORE_SEND_CODE) {
+        RequestErrorType::DeadlineExceededBeforeSend
+    } else if error.is_timeout() {
         RequestErrorType::Timeout
     } else if error.is_unrecoverable_error() {
         RequestErrorType::Disconnect
@@ -23,6 +76,25 @@ pub fn error_type(error: &RedisError) -> RequestErrorType {
     }
 }
 
+/// The details of an error that the wrappers expose alongside its message and type.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ErrorDetails {
+    /// The error code, such as `MOVED`, `CLUSTERDOWN` or `OOM`, if the error has one.
+    pub code: Option<String>,
+    /// Whether the request may succeed when it's retried.
+    pub retryable: bool,
+    /// The address of the node the error originated from, if known.
+    pub node_address: Option<String>,
+}
+
+pub fn error_details(error: &RedisError) -> ErrorDetails {
+    ErrorDetails {
+        code: error.code().map(str::to_string),
+        retryable: error.is_retryable(),
+        node_address: error.node_address().map(str::to_string),
+    }
+}
+
 pub fn error_message(error: &RedisError) -> String {
     let error_message = error.to_string();
     if matches!(error_type(error), RequestErrorType::Disconnect) {
@@ -31,3 +103,50 @@ pub fn error_message(error: &RedisError) -> String {
         error_message
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_details() {
+        let err = RedisError::from((
+            redis::ErrorKind::ClusterDown,
+            "An error was signalled by the server:",
+            "The cluster is down".to_string(),
+        ))
+        .with_node_address("127.0.0.1:7000");
+        assert_eq!(
+            error_details(&err),
+            ErrorDetails {
+                code: Some("CLUSTERDOWN".to_string()),
+                retryable: true,
+                node_address: Some("127.0.0.1:7000".to_string()),
+            }
+        );
+
+        let err = redis::make_extension_error(
+            "OOM".to_string(),
+            Some("command not allowed when used memory > 'maxmemory'".to_string()),
+        );
+        let details = error_details(&err);
+        assert_eq!(details.code.as_deref(), Some("OOM"));
+        assert!(!details.retryable);
+        assert_eq!(details.node_address, None);
+    }
+
+    #[test]
+    fn test_deadline_exceeded_error_type() {
+        let mut cmd = redis::cmd("GET");
+        cmd.arg("key").set_deadline(Some(std::time::Instant::now()));
+        let err = cmd.check_deadline().unwrap_err();
+        assert_eq!(
+            error_type(&err),
+            RequestErrorType::DeadlineExceededBeforeSend
+        );
+        assert_eq!(
+            crate::response_encoding::to_protobuf_error_type(error_type(&err)),
+            crate::response::RequestErrorType::Timeout
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Conversion of the `GEOSEARCH` and `GEORADIUS` family responses, which are positional arrays whose layout depends
+//! on the `WITHDIST`, `WITHHASH` and `WITHCOORD` options, into a list of maps shared by all wrappers:
+//!
+//! ```text
+//! [
+//!   { member, distance => (double), hash => (integer), coordinates => [longitude, latitude] },
+//!   ...
+//! ]
+//! ```
+//!
+//! Fields that weren't requested with the matching option are `null`.
+
+use redis::{Cmd, ErrorKind, RedisError, RedisResult, Value};
+
+/// Returns true if the response of the command can be converted with [`convert_geo_search_response`].
+pub fn is_geo_search_command(cmd: &Cmd) -> bool {
+    matches!(
+        cmd.command().as_deref(),
+        Some(
+            b"GEOSEARCH"
+                | b"GEORADIUS"
+                | b"GEORADIUS_RO"
+                | b"GEORADIUSBYMEMBER"
+                | b"GEORADIUSBYMEMBER_RO"
+        )
+    )
+}
+
+/// Converts the response of a `GEOSEARCH` or `GEORADIUS` family command into a list of maps, as described in the
+/// module documentation. Responses of other commands, server errors, and the count of stored members returned with
+/// the `STORE` options, are returned as is.
+pub fn convert_geo_search_response(cmd: &Cmd, value: Value) -> RedisResult<Value> {
+    if !is_geo_search_command(cmd) {
+        return Ok(value);
+    }
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .map(convert_item)
+            .collect::<RedisResult<_>>()
+            .map(Value::Array),
+        value => Ok(value),
+    }
+}
+
+fn unexpected_response(value: &Value) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response couldn't be converted to geo search results",
+        format!("(response was {value:?})"),
+    ))
+}
+
+fn into_double(value: Value) -> RedisResult<Value> {
+    match value {
+        Value::Double(_) => Ok(value),
+        Value::BulkString(ref bytes) => std::str::from_utf8(bytes)
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .map(Value::Double)
+            .ok_or_else(|| unexpected_response(&value)),
+        value => Err(unexpected_response(&value)),
+    }
+}
+
+fn field(name: &str, value: Value) -> (Value, Value) {
+    (Value::SimpleString(name.to_string()), value)
+}
+
+/// Converts a single result. Without any `WITH*` option the result is the member itself, and otherwise it's an array
+/// of the member followed by the requested fields, in the order distance, hash, coordinates. The fields are
+/// identified by their types, since each of them has a different one.
+fn convert_item(item: Value) -> RedisResult<Value> {
+    let mut values = match item {
+        Value::Array(values) => values.into_iter(),
+        member @ (Value::BulkString(_) | Value::SimpleString(_)) => vec![member].into_iter(),
+        item => return Err(unexpected_response(&item)),
+    };
+    let member = values
+        .next()
+        .ok_or_else(|| unexpected_response(&Value::Array(vec![])))?;
+    let mut distance = Value::Nil;
+    let mut hash = Value::Nil;
+    let mut coordinates = Value::Nil;
+    for value in values {
+        match value {
+            Value::Int(_) => hash = value,
+            Value::Array(position) => {
+                coordinates = Value::Array(
+                    position
+                        .into_iter()
+                        .map(into_double)
+                        .collect::<RedisResult<_>>()?,
+                )
+            }
+            value => distance = into_double(value)?,
+        }
+    }
+    Ok(Value::Map(vec![
+        field("member", member),
+        field("distance", distance),
+        field("hash", hash),
+        field("coordinates", coordinates),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    fn expected_item(member: &str, distance: Value, hash: Value, coordinates: Value) -> Value {
+        Value::Map(vec![
+            field("member", bulk(member)),
+            field("distance", distance),
+            field("hash", hash),
+            field("coordinates", coordinates),
+        ])
+    }
+
+    #[test]
+    fn test_convert_geo_search_response_with_all_fields() {
+        let cmd = redis::cmd("GEOSEARCH")
+            .arg("key")
+            .arg("FROMLONLAT")
+            .arg(15)
+            .arg(37)
+            .arg("BYRADIUS")
+            .arg(200)
+            .arg("km")
+            .arg("WITHCOORD")
+            .arg("WITHDIST")
+            .arg("WITHHASH")
+            .clone();
+        let response = Value::Array(vec![Value::Array(vec![
+            bulk("Palermo"),
+            bulk("190.4424"),
+            Value::Int(3479099956230698),
+            Value::Array(vec![bulk("13.36138933897018433"), Value::Double(38.1)]),
+        ])]);
+        assert_eq!(
+            convert_geo_search_response(&cmd, response),
+            Ok(Value::Array(vec![expected_item(
+                "Palermo",
+                Value::Double(190.4424),
+                Value::Int(3479099956230698),
+                Value::Array(vec![
+                    Value::Double(13.36138933897018433),
+                    Value::Double(38.1)
+                ]),
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_convert_geo_search_response_without_options() {
+        let cmd = redis::cmd("GEORADIUS_RO").arg("key").clone();
+        let response = Value::Array(vec![bulk("Palermo"), bulk("Catania")]);
+        assert_eq!(
+            convert_geo_search_response(&cmd, response),
+            Ok(Value::Array(vec![
+                expected_item("Palermo", Value::Nil, Value::Nil, Value::Nil),
+                expected_item("Catania", Value::Nil, Value::Nil, Value::Nil),
+            ]))
+        );
+
+        // The count of stored members is returned as is.
+        let cmd = redis::cmd("GEORADIUS").arg("key").clone();
+        assert_eq!(
+            convert_geo_search_response(&cmd, Value::Int(2)),
+            Ok(Value::Int(2))
+        );
+        let cmd = redis::cmd("GET").arg("key").clone();
+        assert_eq!(
+            convert_geo_search_response(&cmd, Value::Array(vec![bulk("a")])),
+            Ok(Value::Array(vec![bulk("a")]))
+        );
+    }
+
+    #[test]
+    fn test_convert_geo_search_response_with_invalid_distance() {
+        let cmd = redis::cmd("GEOSEARCH").arg("key").clone();
+        let response = Value::Array(vec![Value::Array(vec![bulk("Palermo"), bulk("far")])]);
+        assert!(convert_geo_search_response(&cmd, response).is_err());
+    }
+}
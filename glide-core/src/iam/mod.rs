@@ -25,6 +25,8 @@ const DEFAULT_REFRESH_INTERVAL_SECONDS: u32 = 300; // 300 seconds (5min)
 const WARNING_REFRESH_INTERVAL_SECONDS: u32 = 15 * 60; // 900 seconds
 /// SigV4 presign expiration (15 minutes)
 const TOKEN_TTL_SECONDS: u64 = 15 * 60; // 900
+/// Maximum refresh interval as a percentage of the token TTL, so the token is refreshed before it expires
+const MAX_REFRESH_TTL_PERCENT: u32 = 99;
 
 /// Exponential backoff settings for token generation
 const TOKEN_GEN_MAX_ATTEMPTS: u32 = 8;
@@ -41,6 +43,12 @@ pub enum GlideIAMError {
     )]
     InvalidRefreshInterval { max: u32, actual: u32 },
 
+    /// Invalid refresh interval percentage of the token TTL (must be 1 to 99)
+    #[error(
+        "IAM authentication error: Invalid refresh TTL percentage. Must be between 1 and {max}, got: {actual}"
+    )]
+    InvalidRefreshTtlPercent { max: u32, actual: u32 },
+
     /// AWS credentials resolution error
     #[error("IAM authentication error: Failed to get AWS credentials: {0}")]
     CredentialsError(String),
@@ -108,6 +116,21 @@ fn validate_refresh_interval(
     }
 }
 
+/// Convert a percentage of the token TTL to a refresh interval in seconds (1% to 99%)
+pub fn refresh_interval_for_ttl_percent(percent: u32) -> Result<u32, GlideIAMError> {
+    if !(1..=MAX_REFRESH_TTL_PERCENT).contains(&percent) {
+        return Err(GlideIAMError::InvalidRefreshTtlPercent {
+            max: MAX_REFRESH_TTL_PERCENT,
+            actual: percent,
+        });
+    }
+    Ok((TOKEN_TTL_SECONDS * percent as u64 / 100).max(1) as u32)
+}
+
+/// Listener that is called with the outcome of every token refresh: the new token, or the error that
+/// prevented generating it.
+pub type TokenRefreshListener = Arc<dyn Fn(Result<&str, &GlideIAMError>) + Send + Sync>;
+
 /// Get AWS credentials using the default credential chain
 async fn get_signing_identity(
     region: &str,
@@ -156,7 +179,7 @@ struct IamTokenState {
 ///
 /// - Tokens: valid 15m, refreshed every 5m by default.
 /// - Refresh: periodic, uses exponential backoff with ±20% jitter on failures.
-/// - Failures: logged only; cached token stays valid until expiry.
+/// - Failures: logged and reported to the refresh listener, if set; cached token stays valid until expiry.
 /// - Thread-safe via `Arc<RwLock<...>>` for token cache and `Arc<AtomicBool>` for change notification.
 pub struct IAMTokenManager {
     /// Cached auth token, stored in an `Arc<RwLock<String>>` to allow many concurrent readers,
@@ -170,6 +193,8 @@ pub struct IAMTokenManager {
     shutdown_notify: Arc<Notify>,
     /// Atomic flag to signal when token has changed (for efficient change detection)
     token_changed: Arc<AtomicBool>,
+    /// Optional listener for the outcome of token refreshes
+    refresh_listener: Option<TokenRefreshListener>,
 }
 
 /// Custom Debug implementation for IAMTokenManager
@@ -181,6 +206,7 @@ impl std::fmt::Debug for IAMTokenManager {
             .field("refresh_task", &self.refresh_task.is_some())
             .field("shutdown_notify", &"<Notify>")
             .field("token_changed", &self.token_changed.load(Ordering::Relaxed))
+            .field("refresh_listener", &self.refresh_listener.is_some())
             .finish()
     }
 }
@@ -223,9 +249,16 @@ impl IAMTokenManager {
             refresh_task: None,
             shutdown_notify: Arc::new(Notify::new()),
             token_changed: Arc::new(AtomicBool::new(true)), // Initially true to trigger first AUTH
+            refresh_listener: None,
         })
     }
 
+    /// Set a listener for the outcome of token refreshes.
+    /// Must be called before [`Self::start_refresh_task`] for the background refreshes to be reported.
+    pub fn set_refresh_listener(&mut self, listener: TokenRefreshListener) {
+        self.refresh_listener = Some(listener);
+    }
+
     /// Start the background token refresh task
     pub fn start_refresh_task(&mut self) {
         if self.refresh_task.is_some() {
@@ -236,12 +269,14 @@ impl IAMTokenManager {
         let cached_token = Arc::clone(&self.cached_token);
         let shutdown_notify = Arc::clone(&self.shutdown_notify);
         let token_changed = Arc::clone(&self.token_changed);
+        let refresh_listener = self.refresh_listener.clone();
 
         let task = tokio::spawn(Self::token_refresh_task(
             iam_token_state,
             cached_token,
             shutdown_notify,
             token_changed,
+            refresh_listener,
         ));
 
         self.refresh_task = Some(task);
@@ -253,6 +288,7 @@ impl IAMTokenManager {
         cached_token: Arc<RwLock<String>>,
         shutdown_notify: Arc<Notify>,
         token_changed: Arc<AtomicBool>,
+        refresh_listener: Option<TokenRefreshListener>,
     ) {
         let refresh_interval = Duration::from_secs(iam_token_state.refresh_interval_seconds as u64);
 
@@ -265,7 +301,13 @@ impl IAMTokenManager {
         loop {
             tokio::select! {
                 _ = interval_timer.tick() => {
-                    Self::handle_token_refresh(&iam_token_state, &cached_token, &token_changed).await;
+                    Self::handle_token_refresh(
+                        &iam_token_state,
+                        &cached_token,
+                        &token_changed,
+                        refresh_listener.as_ref(),
+                    )
+                    .await;
                 }
                 _ = shutdown_notify.notified() => {
                     log_info("IAM token refresh task shutting down", "");
@@ -278,15 +320,20 @@ impl IAMTokenManager {
     /// Refresh cached token with backoff + jitter.
     /// On success: update token + set atomic flag.
     /// On failure: log error, keep old token.
+    /// Either way, the outcome is reported to the refresh listener, if set.
     async fn handle_token_refresh(
         iam_token_state: &IamTokenState,
         cached_token: &Arc<RwLock<String>>,
         token_changed: &Arc<AtomicBool>,
+        refresh_listener: Option<&TokenRefreshListener>,
     ) {
         match Self::generate_token_with_backoff(iam_token_state).await {
             Ok(new_token) => {
                 Self::set_cached_token_static(cached_token, new_token.clone()).await;
                 token_changed.store(true, Ordering::Release);
+                if let Some(listener) = refresh_listener {
+                    listener(Ok(&new_token));
+                }
             }
             Err(err) => {
                 // Leave cached token unchanged; logs already emitted in backoff routine
@@ -294,6 +341,9 @@ impl IAMTokenManager {
                     "IAM token refresh failed",
                     format!("Could not refresh token after backoff: {}", err),
                 );
+                if let Some(listener) = refresh_listener {
+                    listener(Err(&err));
+                }
             }
         }
     }
@@ -354,6 +404,7 @@ impl IAMTokenManager {
             &self.iam_token_state,
             &self.cached_token,
             &self.token_changed,
+            self.refresh_listener.as_ref(),
         )
         .await;
     }
@@ -649,6 +700,57 @@ mod tests {
         log_info("Manual refresh test completed successfully!", "");
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_iam_token_manager_reports_refresh_to_listener() {
+        initialize_test_environment();
+        setup_test_credentials();
+
+        let mut manager = IAMTokenManager::new(
+            "test-cluster".to_string(),
+            "test-user".to_string(),
+            "us-east-1".to_string(),
+            ServiceType::ElastiCache,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let refreshed_tokens = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let listener_tokens = Arc::clone(&refreshed_tokens);
+        manager.set_refresh_listener(Arc::new(move |result: Result<&str, &GlideIAMError>| {
+            listener_tokens
+                .lock()
+                .unwrap()
+                .push(result.map(str::to_string).map_err(|err| err.to_string()));
+        }));
+
+        manager.refresh_token().await;
+
+        let refreshed_tokens = refreshed_tokens.lock().unwrap();
+        assert_eq!(refreshed_tokens.len(), 1);
+        assert_eq!(
+            refreshed_tokens[0].as_deref(),
+            Ok(manager.get_token().await.as_str())
+        );
+    }
+
+    #[test]
+    fn test_refresh_interval_for_ttl_percent() {
+        assert_eq!(refresh_interval_for_ttl_percent(50).unwrap(), 450);
+        assert_eq!(refresh_interval_for_ttl_percent(99).unwrap(), 891);
+        assert_eq!(refresh_interval_for_ttl_percent(1).unwrap(), 9);
+        for percent in [0, 100, 150] {
+            match refresh_interval_for_ttl_percent(percent) {
+                Err(GlideIAMError::InvalidRefreshTtlPercent { max, actual }) => {
+                    assert_eq!(max, MAX_REFRESH_TTL_PERCENT);
+                    assert_eq!(actual, percent);
+                }
+                result => panic!("Expected InvalidRefreshTtlPercent error, got: {result:?}"),
+            }
+        }
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_iam_token_manager_new_creates_initial_token() {
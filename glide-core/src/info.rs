@@ -0,0 +1,186 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Parsing of the `INFO` reply into a structured form, so every wrapper reads the server information the same way.
+
+use redis::{ErrorKind, RedisError, RedisResult, Value};
+
+/// Converts the reply of `INFO` into a map of the sections, by lowercase name such as `server` or `keyspace`, to a
+/// map of their fields. Integer fields are converted to `Int` and decimal fields to `Double`. Fields holding a
+/// comma-separated list of `name=value` pairs, such as the databases of `keyspace` or the `cmdstat_*` fields of
+/// `commandstats`, are converted to a map of the typed values. Other fields are kept as strings.
+pub fn convert_info_response(value: Value) -> RedisResult<Value> {
+    let text = match value {
+        Value::BulkString(text) => text,
+        Value::SimpleString(text) => text.into_bytes(),
+        Value::VerbatimString { text, .. } => text.into_bytes(),
+        value => {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for INFO",
+                format!("(response was {value:?})"),
+            )));
+        }
+    };
+    Ok(parse_info(&text))
+}
+
+/// Consolidates the `INFO` replies of the nodes into a map of the address of each node to its sections, as converted
+/// by [`convert_info_response`], sorted by address.
+///
+/// Fails with the error of the first node that failed, since a partial result can't be told apart from a full one.
+pub fn aggregate_info(mut results: Vec<(String, RedisResult<Value>)>) -> RedisResult<Value> {
+    results.sort_by(|(address, _), (other, _)| address.cmp(other));
+    let mut nodes = Vec::with_capacity(results.len());
+    for (address, result) in results {
+        let sections = convert_info_response(result?)?;
+        nodes.push((Value::BulkString(address.into_bytes()), sections));
+    }
+    Ok(Value::Map(nodes))
+}
+
+fn parse_info(text: &[u8]) -> Value {
+    let mut sections: Vec<(Value, Value)> = Vec::new();
+    let mut fields = Vec::new();
+    let mut section = None;
+    for line in String::from_utf8_lossy(text).lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix('#') {
+            if let Some(section) = section.take() {
+                sections.push((section, Value::Map(std::mem::take(&mut fields))));
+            }
+            section = Some(Value::SimpleString(name.trim().to_ascii_lowercase()));
+            continue;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        fields.push((Value::SimpleString(name.to_string()), parse_field(value)));
+    }
+    if section.is_some() || !fields.is_empty() {
+        let section = section.unwrap_or_else(|| Value::SimpleString(String::new()));
+        sections.push((section, Value::Map(fields)));
+    }
+    Value::Map(sections)
+}
+
+fn parse_field(value: &str) -> Value {
+    if value.contains('=') {
+        let pairs = value
+            .split(',')
+            .map(|pair| {
+                let (name, value) = pair.split_once('=')?;
+                Some((Value::SimpleString(name.to_string()), parse_scalar(value)))
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(pairs) = pairs {
+            return Value::Map(pairs);
+        }
+    }
+    parse_scalar(value)
+}
+
+fn parse_scalar(value: &str) -> Value {
+    if let Ok(integer) = value.parse() {
+        return Value::Int(integer);
+    }
+    let numeric = !value.is_empty()
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || matches!(byte, b'.' | b'-'));
+    match value.parse() {
+        Ok(double) if numeric => Value::Double(double),
+        _ => Value::BulkString(value.as_bytes().to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: &str = "# Server\r\nredis_version:7.2.4\r\nuptime_in_seconds:3600\r\nexecutable:/usr/bin/valkey-server\r\n\r\n\
+                        # Memory\r\nused_memory:1048576\r\nmem_fragmentation_ratio:1.25\r\n\r\n\
+                        # Keyspace\r\ndb0:keys=10,expires=2,avg_ttl=0\r\n";
+
+    fn get<'a>(map: &'a Value, name: &str) -> &'a Value {
+        let Value::Map(entries) = map else {
+            panic!("expected a map, got {map:?}");
+        };
+        entries
+            .iter()
+            .find(|(key, _)| key_matches(key, name))
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| panic!("missing {name}"))
+    }
+
+    fn key_matches(key: &Value, name: &str) -> bool {
+        match key {
+            Value::SimpleString(key) => key == name,
+            Value::BulkString(key) => key == name.as_bytes(),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_convert_info_response() {
+        let info = convert_info_response(Value::VerbatimString {
+            format: redis::VerbatimFormat::Text,
+            text: INFO.to_string(),
+        })
+        .unwrap();
+        let server = get(&info, "server");
+        assert_eq!(
+            get(server, "redis_version"),
+            &Value::BulkString(b"7.2.4".to_vec())
+        );
+        assert_eq!(get(server, "uptime_in_seconds"), &Value::Int(3600));
+        assert_eq!(
+            get(server, "executable"),
+            &Value::BulkString(b"/usr/bin/valkey-server".to_vec())
+        );
+        let memory = get(&info, "memory");
+        assert_eq!(get(memory, "mem_fragmentation_ratio"), &Value::Double(1.25));
+        let db0 = get(get(&info, "keyspace"), "db0");
+        assert_eq!(get(db0, "keys"), &Value::Int(10));
+        assert_eq!(get(db0, "expires"), &Value::Int(2));
+        assert!(convert_info_response(Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_info() {
+        let results = vec![
+            (
+                "node2:6379".to_string(),
+                Ok(Value::BulkString(
+                    b"# Server\r\nuptime_in_seconds:20\r\n".to_vec(),
+                )),
+            ),
+            (
+                "node1:6379".to_string(),
+                Ok(Value::BulkString(
+                    b"# Server\r\nuptime_in_seconds:10\r\n".to_vec(),
+                )),
+            ),
+        ];
+        let Value::Map(nodes) = aggregate_info(results).unwrap() else {
+            panic!("expected a map");
+        };
+        assert_eq!(nodes[0].0, Value::BulkString(b"node1:6379".to_vec()));
+        assert_eq!(
+            get(get(&nodes[0].1, "server"), "uptime_in_seconds"),
+            &Value::Int(10)
+        );
+        assert_eq!(
+            get(get(&nodes[1].1, "server"), "uptime_in_seconds"),
+            &Value::Int(20)
+        );
+
+        let failed = vec![(
+            "node1:6379".to_string(),
+            Err(RedisError::from((ErrorKind::IoError, "disconnected"))),
+        )];
+        assert_eq!(
+            aggregate_info(failed).unwrap_err().kind(),
+            ErrorKind::IoError
+        );
+    }
+}
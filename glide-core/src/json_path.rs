@@ -0,0 +1,654 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Client-side evaluation of `JSON.GET`, used when the client is configured with `json_path_fallback` and the server
+//! doesn't have the JSON module. The value of the key is read with `GET`, and the paths are evaluated on the client,
+//! returning the same response the module would for a document stored as a string.
+//!
+//! Both the JSONPath syntax, with paths starting with `$`, and the legacy syntax are supported, with member names,
+//! array indices, wildcards, recursive descent, unions and slices. Filter expressions aren't supported.
+
+use redis::cluster_routing::Routable;
+use redis::{Arg, Cmd, ErrorKind, RedisError, RedisResult, Value};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Returns whether `err` is the error of a `JSON.GET` sent to a server without the JSON module.
+pub(crate) fn is_json_get_unsupported(cmd: &Cmd, err: &RedisError) -> bool {
+    cmd.command()
+        .is_some_and(|name| name.eq_ignore_ascii_case(b"JSON.GET"))
+        && err
+            .detail()
+            .is_some_and(|detail| detail.starts_with("unknown command"))
+}
+
+/// A JSON document. Unlike `serde_json::Value`, objects keep the order of their members, as the JSON module does,
+/// without enabling the `preserve_order` feature of `serde_json`, which would apply to every crate of the build.
+#[derive(Clone, Debug)]
+enum Json {
+    /// `null`, a boolean, a number or a string.
+    Scalar(serde_json::Value),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn member(&self, name: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(member, _)| member == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Json {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonVisitor)
+    }
+}
+
+struct JsonVisitor;
+
+impl<'de> Visitor<'de> for JsonVisitor {
+    type Value = Json;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Json, E> {
+        Ok(Json::Scalar(serde_json::Value::Null))
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Json, E> {
+        Ok(Json::Scalar(value.into()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Json, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Json::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Json, A::Error> {
+        let mut members: Vec<(String, Json)> = Vec::new();
+        let mut positions = HashMap::new();
+        while let Some((name, value)) = map.next_entry::<String, Json>()? {
+            // A repeated member keeps its first position and takes its last value.
+            match positions.get(&name) {
+                Some(&position) => members[position] = (name, value),
+                None => {
+                    positions.insert(name.clone(), members.len());
+                    members.push((name, value));
+                }
+            }
+        }
+        Ok(Json::Object(members))
+    }
+}
+
+/// The formatting options of `JSON.GET`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Format {
+    indent: String,
+    newline: String,
+    space: String,
+}
+
+impl Format {
+    fn write(&self, value: &Json) -> String {
+        let mut out = String::new();
+        self.write_value(value, 0, &mut out);
+        out
+    }
+
+    fn write_value(&self, value: &Json, level: usize, out: &mut String) {
+        match value {
+            Json::Array(items) if !items.is_empty() => {
+                out.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    self.write_line(level + 1, out);
+                    self.write_value(item, level + 1, out);
+                }
+                self.write_line(level, out);
+                out.push(']');
+            }
+            Json::Object(members) if !members.is_empty() => {
+                out.push('{');
+                for (index, (name, member)) in members.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    self.write_line(level + 1, out);
+                    out.push_str(&serde_json::Value::from(name.as_str()).to_string());
+                    out.push(':');
+                    out.push_str(&self.space);
+                    self.write_value(member, level + 1, out);
+                }
+                self.write_line(level, out);
+                out.push('}');
+            }
+            Json::Scalar(value) => out.push_str(&value.to_string()),
+            Json::Array(_) => out.push_str("[]"),
+            Json::Object(_) => out.push_str("{}"),
+        }
+    }
+
+    fn write_line(&self, level: usize, out: &mut String) {
+        out.push_str(&self.newline);
+        for _ in 0..level {
+            out.push_str(&self.indent);
+        }
+    }
+}
+
+/// A `JSON.GET` command, evaluated on the value of its key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct JsonGetRequest {
+    pub(crate) key: Vec<u8>,
+    format: Format,
+    paths: Vec<String>,
+}
+
+impl JsonGetRequest {
+    /// Parses the arguments of `JSON.GET key [INDENT indent] [NEWLINE newline] [SPACE space] [path ...]`.
+    pub(crate) fn parse(cmd: &Cmd) -> RedisResult<Self> {
+        let mut args = cmd.args_iter().skip(1).filter_map(|arg| match arg {
+            Arg::Simple(arg) => Some(String::from_utf8_lossy(arg).into_owned()),
+            Arg::Cursor => None,
+        });
+        let key = args
+            .next()
+            .ok_or_else(|| invalid_request("wrong number of arguments for 'JSON.GET' command"))?
+            .into_bytes();
+        let mut format = Format::default();
+        let mut paths = Vec::new();
+        while let Some(arg) = args.next() {
+            let option = match arg.to_ascii_uppercase().as_str() {
+                "INDENT" if paths.is_empty() => &mut format.indent,
+                "NEWLINE" if paths.is_empty() => &mut format.newline,
+                "SPACE" if paths.is_empty() => &mut format.space,
+                _ => {
+                    paths.push(arg);
+                    continue;
+                }
+            };
+            *option = args.next().ok_or_else(|| invalid_request("syntax error"))?;
+        }
+        Ok(Self { key, format, paths })
+    }
+
+    /// Evaluates the request on the reply of `GET` for its key. A missing key is returned as `Nil`, as `JSON.GET` does.
+    ///
+    /// Without paths, the whole document is returned. A single JSONPath returns an array of its matches, and a single
+    /// legacy path its first match, failing if there's none. Several paths return an object of the result of each
+    /// path, all evaluated as JSONPaths if any of them is.
+    pub(crate) fn evaluate(&self, value: Value) -> RedisResult<Value> {
+        let text = match value {
+            Value::Nil => return Ok(Value::Nil),
+            Value::BulkString(text) => text,
+            Value::SimpleString(text) => text.into_bytes(),
+            Value::VerbatimString { text, .. } => text.into_bytes(),
+            value => {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "Unexpected response for GET",
+                    format!("(response was {value:?})"),
+                )));
+            }
+        };
+        let document: Json = serde_json::from_slice(&text).map_err(|err| {
+            RedisError::from((
+                ErrorKind::TypeError,
+                "The value of the key isn't a JSON document",
+                err.to_string(),
+            ))
+        })?;
+        let result = match self.paths.as_slice() {
+            [] => document,
+            [path] => evaluate_path(&document, path, is_jsonpath(path))?,
+            paths => {
+                let jsonpath = paths.iter().any(|path| is_jsonpath(path));
+                let mut results: Vec<(String, Json)> = Vec::new();
+                for path in paths {
+                    let result = evaluate_path(&document, path, jsonpath)?;
+                    // A repeated path is returned once.
+                    if !results.iter().any(|(other, _)| other == path) {
+                        results.push((path.clone(), result));
+                    }
+                }
+                Json::Object(results)
+            }
+        };
+        Ok(Value::BulkString(self.format.write(&result).into_bytes()))
+    }
+}
+
+fn is_jsonpath(path: &str) -> bool {
+    path.starts_with('$')
+}
+
+fn invalid_request(detail: &str) -> RedisError {
+    RedisError::from((
+        ErrorKind::ClientError,
+        "Invalid JSON.GET request",
+        detail.to_string(),
+    ))
+}
+
+fn invalid_path(path: &str, detail: &str) -> RedisError {
+    RedisError::from((
+        ErrorKind::ClientError,
+        "Invalid JSON path",
+        format!("{path}: {detail}"),
+    ))
+}
+
+/// Returns the array of the matches of `path` if `jsonpath` is set, and its first match otherwise.
+fn evaluate_path(document: &Json, path: &str, jsonpath: bool) -> RedisResult<Json> {
+    let steps = parse_path(path)?;
+    let matches = select(document, &steps);
+    if jsonpath {
+        return Ok(Json::Array(matches.into_iter().cloned().collect()));
+    }
+    matches
+        .first()
+        .map(|value| (*value).clone())
+        .ok_or_else(|| {
+            redis::make_extension_error(
+                "NONEXISTENT".to_string(),
+                Some(format!("JSON path '{path}' does not exist")),
+            )
+        })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice {
+        start: Option<i64>,
+        end: Option<i64>,
+        step: Option<i64>,
+    },
+    Union(Vec<Selector>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Step {
+    /// Applies the selector to the node and all its descendants, for `..`.
+    recursive: bool,
+    selector: Selector,
+}
+
+/// Parses a JSONPath, or a legacy path such as `.a.b`, `a.b` or `.`, to its steps from the root.
+fn parse_path(path: &str) -> RedisResult<Vec<Step>> {
+    let normalized = match path.strip_prefix('$') {
+        Some(rest) => rest.to_string(),
+        None if path == "." => String::new(),
+        None if path.starts_with(['.', '[']) => path.to_string(),
+        None => format!(".{path}"),
+    };
+    let chars: Vec<char> = normalized.chars().collect();
+    let mut steps = Vec::new();
+    let mut position = 0;
+    while position < chars.len() {
+        let recursive = chars[position..].starts_with(&['.', '.']);
+        match chars[position] {
+            '.' if recursive => position += 2,
+            '.' => position += 1,
+            '[' => {}
+            _ => return Err(invalid_path(path, "expected '.' or '['")),
+        }
+        let selector = if chars.get(position) == Some(&'[') {
+            let end = closing_bracket(&chars, position)
+                .ok_or_else(|| invalid_path(path, "unclosed '['"))?;
+            let content: String = chars[position + 1..end].iter().collect();
+            position = end + 1;
+            parse_bracket(path, &content)?
+        } else {
+            let start = position;
+            while position < chars.len() && !matches!(chars[position], '.' | '[') {
+                position += 1;
+            }
+            match chars[start..position].iter().collect::<String>() {
+                name if name.is_empty() => return Err(invalid_path(path, "empty member name")),
+                name if name == "*" => Selector::Wildcard,
+                name => Selector::Name(name),
+            }
+        };
+        steps.push(Step {
+            recursive,
+            selector,
+        });
+    }
+    Ok(steps)
+}
+
+/// Returns the position of the `]` closing the `[` at `open`, skipping the brackets in quoted names.
+fn closing_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut quote = None;
+    let mut escaped = false;
+    for (position, char) in chars.iter().enumerate().skip(open + 1) {
+        match (quote, *char) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(open_quote), char) if char == open_quote => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(*char),
+            (None, ']') => return Some(position),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+/// Splits the content of brackets on the commas that aren't in quoted names.
+fn split_union(content: &str) -> Vec<String> {
+    let mut items = vec![String::new()];
+    let mut quote = None;
+    let mut escaped = false;
+    for char in content.chars() {
+        match (quote, char) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(open_quote), char) if char == open_quote => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(char),
+            (None, ',') => {
+                items.push(String::new());
+                continue;
+            }
+            (None, _) => {}
+        }
+        items.last_mut().unwrap().push(char);
+    }
+    items
+}
+
+fn parse_bracket(path: &str, content: &str) -> RedisResult<Selector> {
+    let content = content.trim();
+    if content.starts_with('?') {
+        return Err(invalid_path(path, "filter expressions aren't supported"));
+    }
+    let mut selectors = split_union(content)
+        .iter()
+        .map(|item| parse_bracket_item(path, item.trim()))
+        .collect::<RedisResult<Vec<_>>>()?;
+    if selectors.len() == 1 {
+        return Ok(selectors.remove(0));
+    }
+    Ok(Selector::Union(selectors))
+}
+
+fn parse_bracket_item(path: &str, item: &str) -> RedisResult<Selector> {
+    if item == "*" {
+        return Ok(Selector::Wildcard);
+    }
+    if let Some(quote) = item
+        .chars()
+        .next()
+        .filter(|char| matches!(char, '\'' | '"'))
+    {
+        let name = item
+            .strip_prefix(quote)
+            .and_then(|item| item.strip_suffix(quote))
+            .ok_or_else(|| invalid_path(path, "unclosed quoted name"))?;
+        return Ok(Selector::Name(unescape(name)));
+    }
+    let integer = |text: &str| -> RedisResult<Option<i64>> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        text.parse()
+            .map(Some)
+            .map_err(|_| invalid_path(path, "expected an integer"))
+    };
+    if item.contains(':') {
+        let mut bounds = item.splitn(3, ':');
+        return Ok(Selector::Slice {
+            start: integer(bounds.next().unwrap_or_default())?,
+            end: integer(bounds.next().unwrap_or_default())?,
+            step: integer(bounds.next().unwrap_or_default())?,
+        });
+    }
+    integer(item)?
+        .map(Selector::Index)
+        .ok_or_else(|| invalid_path(path, "empty brackets"))
+}
+
+fn unescape(name: &str) -> String {
+    let mut unescaped = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => unescaped.extend(chars.next()),
+            char => unescaped.push(char),
+        }
+    }
+    unescaped
+}
+
+fn select<'a>(document: &'a Json, steps: &[Step]) -> Vec<&'a Json> {
+    let mut nodes = vec![document];
+    for step in steps {
+        if step.recursive {
+            let mut descendants = Vec::new();
+            for node in nodes {
+                push_self_and_descendants(node, &mut descendants);
+            }
+            nodes = descendants;
+        }
+        nodes = nodes
+            .into_iter()
+            .flat_map(|node| step.selector.apply(node))
+            .collect();
+    }
+    nodes
+}
+
+fn push_self_and_descendants<'a>(node: &'a Json, out: &mut Vec<&'a Json>) {
+    out.push(node);
+    match node {
+        Json::Array(items) => items
+            .iter()
+            .for_each(|item| push_self_and_descendants(item, out)),
+        Json::Object(members) => members
+            .iter()
+            .for_each(|(_, member)| push_self_and_descendants(member, out)),
+        _ => {}
+    }
+}
+
+impl Selector {
+    fn apply<'a>(&self, node: &'a Json) -> Vec<&'a Json> {
+        match (self, node) {
+            (Selector::Name(name), node) => node.member(name).into_iter().collect(),
+            (Selector::Index(index), Json::Array(items)) => {
+                let index = if *index < 0 {
+                    items.len() as i64 + index
+                } else {
+                    *index
+                };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| items.get(index))
+                    .into_iter()
+                    .collect()
+            }
+            (Selector::Wildcard, Json::Array(items)) => items.iter().collect(),
+            (Selector::Wildcard, Json::Object(members)) => {
+                members.iter().map(|(_, member)| member).collect()
+            }
+            (Selector::Slice { start, end, step }, Json::Array(items)) => {
+                slice_indices(items.len(), *start, *end, step.unwrap_or(1))
+                    .into_iter()
+                    .map(|index| &items[index])
+                    .collect()
+            }
+            (Selector::Union(selectors), node) => selectors
+                .iter()
+                .flat_map(|selector| selector.apply(node))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Returns the indices selected by a slice of an array of `len` items, with negative bounds counted from the end.
+fn slice_indices(len: usize, start: Option<i64>, end: Option<i64>, step: i64) -> Vec<usize> {
+    let len = len as i64;
+    let normalize = |bound: i64| if bound < 0 { bound + len } else { bound };
+    let mut indices = Vec::new();
+    if step > 0 {
+        let mut index = start.map_or(0, normalize).clamp(0, len);
+        let end = end.map_or(len, normalize).clamp(0, len);
+        while index < end {
+            indices.push(index as usize);
+            index += step;
+        }
+    } else if step < 0 {
+        let mut index = start.map_or(len - 1, normalize).clamp(-1, len - 1);
+        let end = end.map_or(-1, normalize).clamp(-1, len - 1);
+        while index > end {
+            indices.push(index as usize);
+            index += step;
+        }
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{"name":"store","books":[{"title":"A","price":8},{"title":"B","price":12,"tags":["new"]}],"owner":{"name":"alice"}}"#;
+
+    fn json_get(args: &[&str]) -> RedisResult<Value> {
+        let mut cmd = redis::cmd("JSON.GET");
+        cmd.arg("key").arg(args);
+        JsonGetRequest::parse(&cmd)?.evaluate(Value::BulkString(DOCUMENT.as_bytes().to_vec()))
+    }
+
+    fn text(value: RedisResult<Value>) -> String {
+        match value.unwrap() {
+            Value::BulkString(text) => String::from_utf8(text).unwrap(),
+            value => panic!("expected a bulk string, got {value:?}"),
+        }
+    }
+
+    #[test]
+    fn test_jsonpath_returns_array_of_matches() {
+        assert_eq!(text(json_get(&["$.books[*].title"])), r#"["A","B"]"#);
+        assert_eq!(text(json_get(&["$..name"])), r#"["store","alice"]"#);
+        assert_eq!(text(json_get(&["$.books[-1].price"])), "[12]");
+        assert_eq!(text(json_get(&["$.books[0,1].price"])), "[8,12]");
+        assert_eq!(text(json_get(&["$.books[::-1]['title']"])), r#"["B","A"]"#);
+        assert_eq!(text(json_get(&["$.missing"])), "[]");
+    }
+
+    #[test]
+    fn test_legacy_path_returns_first_match() {
+        assert_eq!(text(json_get(&[])), DOCUMENT);
+        assert_eq!(text(json_get(&["."])), DOCUMENT);
+        assert_eq!(text(json_get(&["owner.name"])), r#""alice""#);
+        assert_eq!(text(json_get(&[".books[1].tags"])), r#"["new"]"#);
+        let err = json_get(&[".missing"]).unwrap_err();
+        assert_eq!(err.code(), Some("NONEXISTENT"));
+    }
+
+    #[test]
+    fn test_several_paths_return_object() {
+        assert_eq!(
+            text(json_get(&[".name", "owner.name"])),
+            r#"{".name":"store","owner.name":"alice"}"#
+        );
+        // Legacy paths are evaluated as JSONPaths when any of the paths is one.
+        assert_eq!(
+            text(json_get(&[".name", "$.books[0].price"])),
+            r#"{".name":["store"],"$.books[0].price":[8]}"#
+        );
+    }
+
+    #[test]
+    fn test_members_keep_their_order() {
+        let cmd = redis::cmd("JSON.GET").arg("key").clone();
+        let request = JsonGetRequest::parse(&cmd).unwrap();
+        let document = r#"{"b":1,"a":{"d":2,"c":3},"b":4}"#;
+        assert_eq!(
+            text(request.evaluate(Value::BulkString(document.as_bytes().to_vec()))),
+            r#"{"b":4,"a":{"d":2,"c":3}}"#
+        );
+    }
+
+    #[test]
+    fn test_format_options() {
+        assert_eq!(
+            text(json_get(&[
+                "INDENT", "  ", "NEWLINE", "\n", "SPACE", " ", "$.owner"
+            ])),
+            "[\n  {\n    \"name\": \"alice\"\n  }\n]"
+        );
+    }
+
+    #[test]
+    fn test_missing_key_and_invalid_paths() {
+        let cmd = redis::cmd("JSON.GET").arg("key").arg("$").clone();
+        let request = JsonGetRequest::parse(&cmd).unwrap();
+        assert_eq!(request.evaluate(Value::Nil).unwrap(), Value::Nil);
+        assert!(
+            request
+                .evaluate(Value::BulkString(b"not json".to_vec()))
+                .is_err()
+        );
+        assert!(json_get(&["$.books[?(@.price > 10)]"]).is_err());
+        assert!(json_get(&["$.books[0"]).is_err());
+    }
+
+    #[test]
+    fn test_is_json_get_unsupported() {
+        let unknown = RedisError::from((
+            ErrorKind::ResponseError,
+            "An error was signalled by the server",
+            "unknown command 'JSON.GET', with args beginning with: 'key' ".to_string(),
+        ));
+        assert!(is_json_get_unsupported(
+            &redis::cmd("json.get").arg("key").clone(),
+            &unknown
+        ));
+        assert!(!is_json_get_unsupported(
+            &redis::cmd("GET").arg("key").clone(),
+            &unknown
+        ));
+    }
+}
@@ -2,7 +2,9 @@
 
 #[cfg(feature = "proto")]
 include!("generated/mod.rs");
+pub mod acl;
 pub mod client;
+pub mod client_list;
 #[cfg(feature = "socket-layer")]
 pub(crate) mod otel_db_semantics;
 #[cfg(feature = "socket-layer")]
@@ -12,13 +14,29 @@ mod socket_listener;
 #[cfg(feature = "socket-layer")]
 pub use socket_listener::*;
 pub mod compression;
+pub mod distributed_lock;
 pub mod errors;
 pub mod scripts_container;
 pub use client::ConnectionRequest;
 pub mod cluster_scan_container;
+pub mod cluster_topology;
+pub mod command_latency;
+pub mod geo_search;
 pub mod iam;
+pub mod info;
+pub mod json_path;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
 pub mod pubsub;
+pub mod rate_limiter;
 pub mod request_type;
+#[cfg(feature = "proto")]
+pub mod response_encoding;
+pub mod response_filter;
+pub mod response_schema;
+pub mod routed_multi_client;
+#[cfg(feature = "search")]
+pub mod search;
 pub use telemetrylib::{
     DEFAULT_FLUSH_SIGNAL_INTERVAL_MS, DEFAULT_TRACE_SAMPLE_PERCENTAGE, GlideOpenTelemetry,
     GlideOpenTelemetryConfigBuilder, GlideOpenTelemetrySignalsExporter, GlideSpan, Telemetry,
@@ -0,0 +1,312 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! An in-process server that speaks RESP on a loopback port and answers commands from a table of canned responses,
+//! with scriptable error injection. Wrappers connect a regular client to it, so their unit tests run without a Valkey
+//! server.
+
+use crate::client::{NodeAddress, get_or_init_runtime};
+use redis::{ErrorKind, RedisError, RedisResult};
+use std::io;
+use std::sync::{Arc, Mutex, Weak};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+const LOCK_ERR: &str = "Failed to acquire the mock server lock";
+// The highest argument count and argument length accepted in a request, so a malformed request can't exhaust the memory.
+const MAX_REQUEST_LENGTH: usize = 512 * 1024 * 1024;
+
+struct CannedResponse {
+    prefix: Vec<Vec<u8>>,
+    response: Vec<u8>,
+}
+
+struct InjectedError {
+    prefix: Vec<Vec<u8>>,
+    message: String,
+    // `None` injects the error into every matching command.
+    remaining: Option<u32>,
+}
+
+#[derive(Default)]
+struct CommandTable {
+    responses: Vec<CannedResponse>,
+    errors: Vec<InjectedError>,
+}
+
+/// Whether the arguments of a command start with `prefix`. The command name is compared case-insensitively, and the
+/// other arguments exactly.
+fn matches_prefix(prefix: &[Vec<u8>], args: &[Vec<u8>]) -> bool {
+    prefix.len() <= args.len()
+        && prefix
+            .iter()
+            .zip(args)
+            .enumerate()
+            .all(|(index, (expected, arg))| match index {
+                0 => expected.eq_ignore_ascii_case(arg),
+                _ => expected == arg,
+            })
+}
+
+/// Returns the index of the entry with the longest prefix matching `args`.
+fn longest_match<'a>(
+    prefixes: impl Iterator<Item = &'a Vec<Vec<u8>>>,
+    args: &[Vec<u8>],
+) -> Option<usize> {
+    prefixes
+        .enumerate()
+        .filter(|(_, prefix)| matches_prefix(prefix, args))
+        .max_by_key(|(_, prefix)| prefix.len())
+        .map(|(index, _)| index)
+}
+
+fn bulk_string(value: &str) -> String {
+    format!("${}\r\n{value}\r\n", value.len())
+}
+
+/// The responses to the commands sent while connecting, and to `PING`, unless they are overridden.
+fn default_response(args: &[Vec<u8>]) -> Vec<u8> {
+    let Some(name) = args.first() else {
+        return b"-ERR empty command\r\n".to_vec();
+    };
+    let name = String::from_utf8_lossy(name).to_ascii_uppercase();
+    match name.as_str() {
+        "HELLO" => {
+            let protocol = match args.get(1).map(Vec::as_slice) {
+                Some(b"3") => 3,
+                _ => 2,
+            };
+            let fields = [
+                bulk_string("server"),
+                bulk_string("valkey"),
+                bulk_string("version"),
+                bulk_string("8.0.0"),
+                bulk_string("proto"),
+                format!(":{protocol}\r\n"),
+            ]
+            .concat();
+            match protocol {
+                3 => format!("%3\r\n{fields}").into_bytes(),
+                _ => format!("*6\r\n{fields}").into_bytes(),
+            }
+        }
+        "PING" => b"+PONG\r\n".to_vec(),
+        "INFO" => {
+            bulk_string("# Replication\r\nrole:master\r\nconnected_slaves:0\r\n").into_bytes()
+        }
+        "AUTH" | "CLIENT" | "READONLY" | "SELECT" => b"+OK\r\n".to_vec(),
+        _ => format!("-ERR unknown command '{name}', with no canned response\r\n").into_bytes(),
+    }
+}
+
+impl CommandTable {
+    fn respond(&mut self, args: &[Vec<u8>]) -> Vec<u8> {
+        if let Some(index) = longest_match(self.errors.iter().map(|error| &error.prefix), args) {
+            let error = &mut self.errors[index];
+            let response = format!("-{}\r\n", error.message).into_bytes();
+            if let Some(remaining) = error.remaining.as_mut() {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    self.errors.remove(index);
+                }
+            }
+            return response;
+        }
+        match longest_match(self.responses.iter().map(|response| &response.prefix), args) {
+            Some(index) => self.responses[index].response.clone(),
+            None => default_response(args),
+        }
+    }
+}
+
+fn invalid_request(line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid RESP request line: {line}"),
+    )
+}
+
+/// Reads a line without its CRLF. Returns `None` at the end of the stream.
+async fn read_line<R: AsyncRead + Unpin>(reader: &mut BufReader<R>) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches("\r\n").to_string()))
+}
+
+fn parse_length(line: &str, marker: char) -> io::Result<usize> {
+    line.strip_prefix(marker)
+        .and_then(|length| length.parse().ok())
+        .filter(|length| *length <= MAX_REQUEST_LENGTH)
+        .ok_or_else(|| invalid_request(line))
+}
+
+/// Reads a request, sent as a RESP array of bulk strings. Returns `None` once the client closed the connection.
+async fn read_request<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+) -> io::Result<Option<Vec<Vec<u8>>>> {
+    let Some(header) = read_line(reader).await? else {
+        return Ok(None);
+    };
+    let count = parse_length(&header, '*')?;
+    let mut args = Vec::with_capacity(count.min(64));
+    for _ in 0..count {
+        let line = read_line(reader)
+            .await?
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        let length = parse_length(&line, '$')?;
+        let mut arg = vec![0; length + 2];
+        reader.read_exact(&mut arg).await?;
+        arg.truncate(length);
+        args.push(arg);
+    }
+    Ok(Some(args))
+}
+
+async fn serve_connection(stream: TcpStream, table: Weak<Mutex<CommandTable>>) {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    while let Ok(Some(args)) = read_request(&mut reader).await {
+        // The connection is closed once the server is dropped.
+        let Some(table) = table.upgrade() else {
+            return;
+        };
+        let response = table.lock().expect(LOCK_ERR).respond(&args);
+        if writer.write_all(&response).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// A loopback server answering commands from a table of canned responses. Each command is answered by the entry
+/// whose prefix is the longest prefix of the command's arguments, and injected errors take precedence over responses.
+/// The server stops when it is dropped.
+pub struct MockServer {
+    address: NodeAddress,
+    table: Arc<Mutex<CommandTable>>,
+    accept_task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Starts a server on a free loopback port, running on the shared GLIDE runtime.
+    pub fn start() -> RedisResult<Self> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+        let runtime = get_or_init_runtime().map_err(|err| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "Failed to start the mock server",
+                err,
+            ))
+        })?;
+        let table = Arc::new(Mutex::new(CommandTable::default()));
+        let connections_table = Arc::downgrade(&table);
+        let accept_task = runtime.runtime.spawn(async move {
+            let Ok(listener) = TcpListener::from_std(listener) else {
+                return;
+            };
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(stream, connections_table.clone()));
+            }
+        });
+        Ok(Self {
+            address: NodeAddress {
+                host: "127.0.0.1".to_string(),
+                port,
+            },
+            table,
+            accept_task,
+        })
+    }
+
+    pub fn address(&self) -> &NodeAddress {
+        &self.address
+    }
+
+    /// Answers the commands starting with `prefix` with `response`, a raw RESP value, replacing the previous response
+    /// of the prefix.
+    pub fn set_response(&self, prefix: Vec<Vec<u8>>, response: Vec<u8>) -> RedisResult<()> {
+        // A malformed response would leave the client waiting for the rest of it.
+        redis::parse_redis_value(&response)?;
+        let mut table = self.table.lock().expect(LOCK_ERR);
+        table.responses.retain(|response| response.prefix != prefix);
+        table.responses.push(CannedResponse { prefix, response });
+        Ok(())
+    }
+
+    /// Answers the next `count` commands starting with `prefix` with the error `message`, such as
+    /// `"ERR something failed"`. A `count` of 0 answers every such command with the error.
+    pub fn inject_error(
+        &self,
+        prefix: Vec<Vec<u8>>,
+        message: String,
+        count: u32,
+    ) -> RedisResult<()> {
+        if message.contains(['\r', '\n']) {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Injected error messages can't contain line breaks",
+            )));
+        }
+        let mut table = self.table.lock().expect(LOCK_ERR);
+        table.errors.retain(|error| error.prefix != prefix);
+        table.errors.push(InjectedError {
+            prefix,
+            message,
+            remaining: (count > 0).then_some(count),
+        });
+        Ok(())
+    }
+
+    /// Removes the canned responses and the injected errors.
+    pub fn reset(&self) {
+        let mut table = self.table.lock().expect(LOCK_ERR);
+        table.responses.clear();
+        table.errors.clear();
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<Vec<u8>> {
+        args.iter().map(|arg| arg.as_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn test_longest_prefix_and_injected_errors() {
+        let mut table = CommandTable::default();
+        table.responses.push(CannedResponse {
+            prefix: args(&["GET"]),
+            response: b"$-1\r\n".to_vec(),
+        });
+        table.responses.push(CannedResponse {
+            prefix: args(&["GET", "key"]),
+            response: b"$5\r\nvalue\r\n".to_vec(),
+        });
+        table.errors.push(InjectedError {
+            prefix: args(&["GET", "key"]),
+            message: "ERR injected".to_string(),
+            remaining: Some(1),
+        });
+
+        assert_eq!(table.respond(&args(&["get", "key"])), b"-ERR injected\r\n");
+        assert_eq!(table.respond(&args(&["get", "key"])), b"$5\r\nvalue\r\n");
+        assert_eq!(table.respond(&args(&["GET", "other"])), b"$-1\r\n");
+        assert_eq!(table.respond(&args(&["PING"])), b"+PONG\r\n");
+        assert!(
+            table
+                .respond(&args(&["SET", "key", "value"]))
+                .starts_with(b"-ERR unknown command")
+        );
+    }
+}
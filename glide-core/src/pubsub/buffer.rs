@@ -0,0 +1,233 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! A bounded buffer of pubsub messages, which decouples receiving the messages from delivering them to a slow
+//! wrapper callback. Once the buffer is full, messages are dropped according to its [`PubSubOverflowPolicy`].
+
+use redis::PushInfo;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+use telemetrylib::Telemetry;
+
+const LOCK_ERR: &str = "Failed to acquire the pubsub buffer lock";
+
+/// The default number of messages a [`PubSubBuffer`] holds.
+pub const DEFAULT_PUBSUB_BUFFER_CAPACITY: usize = 10_000;
+
+/// Which message is dropped when a message arrives at a full [`PubSubBuffer`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PubSubOverflowPolicy {
+    /// The oldest buffered message is dropped, so the newest messages are delivered.
+    #[default]
+    DropOldest = 0,
+    /// The arriving message is dropped, so the buffered messages are delivered.
+    DropNewest = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PubSubBufferConfig {
+    /// The maximal number of buffered messages.
+    pub capacity: usize,
+    pub overflow_policy: PubSubOverflowPolicy,
+}
+
+impl Default for PubSubBufferConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_PUBSUB_BUFFER_CAPACITY,
+            overflow_policy: PubSubOverflowPolicy::default(),
+        }
+    }
+}
+
+/// Statistics of the messages handled by a [`PubSubBuffer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PubSubBufferStatistics {
+    /// Number of messages currently buffered.
+    pub buffered_count: u64,
+    /// The highest number of messages that were buffered at once.
+    pub max_buffered_count: u64,
+    /// Number of messages dropped because the buffer was full.
+    pub dropped_count: u64,
+}
+
+struct BufferState {
+    messages: VecDeque<PushInfo>,
+    closed: bool,
+}
+
+/// A bounded queue of pubsub messages, filled by the push handler of a client and drained by a delivery thread.
+pub struct PubSubBuffer {
+    config: PubSubBufferConfig,
+    state: Mutex<BufferState>,
+    message_available: Condvar,
+    // Notified once the delivery thread took the last buffered message.
+    emptied: Condvar,
+    max_buffered_count: AtomicU64,
+    dropped_count: AtomicU64,
+}
+
+impl PubSubBuffer {
+    pub fn new(config: PubSubBufferConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BufferState {
+                messages: VecDeque::new(),
+                closed: false,
+            }),
+            message_available: Condvar::new(),
+            emptied: Condvar::new(),
+            max_buffered_count: AtomicU64::new(0),
+            dropped_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds a message to the buffer, dropping a message if the buffer is full. Messages pushed after the buffer was
+    /// closed are discarded.
+    pub fn push(&self, message: PushInfo) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        if state.closed {
+            return;
+        }
+        if state.messages.len() >= self.config.capacity {
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+            Telemetry::incr_pubsub_messages_dropped();
+            match self.config.overflow_policy {
+                PubSubOverflowPolicy::DropOldest => {
+                    state.messages.pop_front();
+                }
+                PubSubOverflowPolicy::DropNewest => return,
+            }
+        }
+        state.messages.push_back(message);
+        self.max_buffered_count
+            .fetch_max(state.messages.len() as u64, Ordering::Relaxed);
+        drop(state);
+        self.message_available.notify_one();
+    }
+
+    /// Removes the oldest message from the buffer, waiting for one to arrive if the buffer is empty.
+    /// Returns `None` once the buffer is closed.
+    pub fn pop(&self) -> Option<PushInfo> {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        loop {
+            if state.closed {
+                return None;
+            }
+            if let Some(message) = state.messages.pop_front() {
+                if state.messages.is_empty() {
+                    self.emptied.notify_all();
+                }
+                return Some(message);
+            }
+            state = self.message_available.wait(state).expect(LOCK_ERR);
+        }
+    }
+
+    /// Closes the buffer and discards the buffered messages, which stops the delivery thread.
+    pub fn close(&self) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        state.closed = true;
+        state.messages.clear();
+        drop(state);
+        self.message_available.notify_all();
+        self.emptied.notify_all();
+    }
+
+    /// Waits up to `timeout` for the delivery thread to take every buffered message. Returns whether the buffer is
+    /// empty.
+    pub fn wait_until_empty(&self, timeout: Duration) -> bool {
+        let state = self.state.lock().expect(LOCK_ERR);
+        let (state, _) = self
+            .emptied
+            .wait_timeout_while(state, timeout, |state| {
+                !state.closed && !state.messages.is_empty()
+            })
+            .expect(LOCK_ERR);
+        state.messages.is_empty()
+    }
+
+    pub fn statistics(&self) -> PubSubBufferStatistics {
+        let buffered_count = self.state.lock().expect(LOCK_ERR).messages.len() as u64;
+        PubSubBufferStatistics {
+            buffered_count,
+            max_buffered_count: self.max_buffered_count.load(Ordering::Relaxed),
+            dropped_count: self.dropped_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::{PushKind, Value};
+
+    fn message(payload: &str) -> PushInfo {
+        PushInfo {
+            kind: PushKind::Message,
+            data: vec![
+                Value::BulkString(b"channel".to_vec()),
+                Value::BulkString(payload.as_bytes().to_vec()),
+            ],
+        }
+    }
+
+    fn pop_payload(buffer: &PubSubBuffer) -> Option<Value> {
+        buffer.pop().map(|message| message.data[1].clone())
+    }
+
+    fn payload(payload: &str) -> Option<Value> {
+        Some(Value::BulkString(payload.as_bytes().to_vec()))
+    }
+
+    fn buffer(overflow_policy: PubSubOverflowPolicy) -> PubSubBuffer {
+        let buffer = PubSubBuffer::new(PubSubBufferConfig {
+            capacity: 2,
+            overflow_policy,
+        });
+        for payload in ["1", "2", "3"] {
+            buffer.push(message(payload));
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_full_buffer_drops_oldest_message() {
+        let buffer = buffer(PubSubOverflowPolicy::DropOldest);
+        assert_eq!(
+            buffer.statistics(),
+            PubSubBufferStatistics {
+                buffered_count: 2,
+                max_buffered_count: 2,
+                dropped_count: 1,
+            }
+        );
+        assert_eq!(pop_payload(&buffer), payload("2"));
+        assert_eq!(pop_payload(&buffer), payload("3"));
+    }
+
+    #[test]
+    fn test_full_buffer_drops_newest_message() {
+        let buffer = buffer(PubSubOverflowPolicy::DropNewest);
+        assert_eq!(buffer.statistics().dropped_count, 1);
+        assert_eq!(pop_payload(&buffer), payload("1"));
+        assert_eq!(pop_payload(&buffer), payload("2"));
+
+        buffer.close();
+        buffer.push(message("4"));
+        assert!(buffer.pop().is_none());
+    }
+
+    #[test]
+    fn test_wait_until_empty() {
+        let buffer = buffer(PubSubOverflowPolicy::DropOldest);
+        assert!(!buffer.wait_until_empty(Duration::from_millis(10)));
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| while pop_payload(&buffer) != payload("3") {});
+            assert!(buffer.wait_until_empty(Duration::from_secs(5)));
+        });
+    }
+}
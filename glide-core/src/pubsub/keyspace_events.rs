@@ -0,0 +1,99 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Helpers for keyspace notifications, which the server publishes to the `__keyspace@<db>__:<key>`
+//! and `__keyevent@<db>__:<event>` channels when `notify-keyspace-events` is configured.
+
+const KEYSPACE_PREFIX: &[u8] = b"__keyspace@";
+const KEYEVENT_PREFIX: &[u8] = b"__keyevent@";
+const DB_SUFFIX: &[u8] = b"__:";
+
+/// A keyspace notification, parsed from a message published to a keyspace or keyevent channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyspaceEvent {
+    /// The event type, such as `set`, `del` or `expired`.
+    pub event: Vec<u8>,
+    pub key: Vec<u8>,
+    pub db: i64,
+}
+
+/// Returns the channel patterns to subscribe to, in order to receive the events of all databases.
+/// Each key pattern is subscribed through the keyspace channels, and if no key pattern is given,
+/// all events are subscribed through the keyevent channels.
+pub fn keyspace_channel_patterns(key_patterns: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    if key_patterns.is_empty() {
+        return vec![[KEYEVENT_PREFIX, b"*", DB_SUFFIX, b"*"].concat()];
+    }
+    key_patterns
+        .iter()
+        .map(|key_pattern| [KEYSPACE_PREFIX, b"*", DB_SUFFIX, key_pattern].concat())
+        .collect()
+}
+
+/// Returns true if the channel is a keyspace or keyevent channel.
+pub fn is_keyspace_channel(channel: &[u8]) -> bool {
+    channel.starts_with(KEYSPACE_PREFIX) || channel.starts_with(KEYEVENT_PREFIX)
+}
+
+/// Parses a message published to a keyspace or keyevent channel.
+/// Returns `None` if the channel isn't a well-formed keyspace or keyevent channel.
+pub fn parse_keyspace_event(channel: &[u8], message: &[u8]) -> Option<KeyspaceEvent> {
+    let (is_keyspace, rest) = if let Some(rest) = channel.strip_prefix(KEYSPACE_PREFIX) {
+        (true, rest)
+    } else {
+        (false, channel.strip_prefix(KEYEVENT_PREFIX)?)
+    };
+    let db_end = rest
+        .windows(DB_SUFFIX.len())
+        .position(|window| window == DB_SUFFIX)?;
+    let db = std::str::from_utf8(&rest[..db_end]).ok()?.parse().ok()?;
+    let suffix = rest[db_end + DB_SUFFIX.len()..].to_vec();
+
+    let (event, key) = if is_keyspace {
+        (message.to_vec(), suffix)
+    } else {
+        (suffix, message.to_vec())
+    };
+    Some(KeyspaceEvent { event, key, db })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyspace_channel_patterns() {
+        assert_eq!(
+            keyspace_channel_patterns(&[b"user:*".to_vec()]),
+            vec![b"__keyspace@*__:user:*".to_vec()]
+        );
+        assert_eq!(
+            keyspace_channel_patterns(&[]),
+            vec![b"__keyevent@*__:*".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_parse_keyspace_event() {
+        assert_eq!(
+            parse_keyspace_event(b"__keyspace@0__:user:1", b"set"),
+            Some(KeyspaceEvent {
+                event: b"set".to_vec(),
+                key: b"user:1".to_vec(),
+                db: 0,
+            })
+        );
+        // Keys may contain the `__:` separator
+        assert_eq!(
+            parse_keyspace_event(b"__keyevent@12__:expired", b"a__:b"),
+            Some(KeyspaceEvent {
+                event: b"expired".to_vec(),
+                key: b"a__:b".to_vec(),
+                db: 12,
+            })
+        );
+        assert_eq!(parse_keyspace_event(b"news", b"set"), None);
+        assert_eq!(parse_keyspace_event(b"__keyspace@x__:key", b"set"), None);
+        assert!(is_keyspace_channel(b"__keyevent@0__:del"));
+        assert!(!is_keyspace_channel(b"news"));
+    }
+}
@@ -1,11 +1,11 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use crate::client::ClientWrapper;
-use redis::PushInfo;
 pub use redis::{
     ErrorKind, PubSubChannelOrPattern, PubSubSubscriptionInfo, PubSubSubscriptionKind,
     PubSubSynchronizer, RedisError,
 };
+use redis::{PushInfo, Value};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 use tokio::sync::{RwLock, mpsc};
@@ -19,6 +19,9 @@ pub use mock::MockPubSubBroker;
 #[cfg(not(feature = "mock-pubsub"))]
 pub mod synchronizer;
 
+pub mod buffer;
+pub mod keyspace_events;
+
 /// Factory function to create a synchronizer with internal client reference
 pub async fn create_pubsub_synchronizer(
     _push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
@@ -50,6 +53,7 @@ pub async fn create_pubsub_synchronizer(
     #[cfg(not(feature = "mock-pubsub"))]
     {
         let sync = synchronizer::GlidePubSubSynchronizer::new(
+            _push_sender,
             initial_subscriptions,
             is_cluster,
             reconciliation_interval,
@@ -68,3 +72,106 @@ pub async fn create_pubsub_synchronizer(
         sync
     }
 }
+
+/// Summarizes the subscriptions of a client by kind, from the desired and actual subscriptions of its synchronizer.
+/// Returns:
+///
+/// ```text
+/// Exact | Pattern | Sharded => map of:
+///     count => (integer) the number of subscriptions requested by the user
+///     active => (array) the subscriptions the client is subscribed to
+///     pending => (array) the subscriptions that weren't established yet, or are waiting to be restored after a reconnection
+/// ```
+///
+/// The channels and patterns are sorted, so the summary doesn't depend on the order of the subscriptions.
+pub fn subscriptions_summary(
+    desired: &PubSubSubscriptionInfo,
+    actual: &PubSubSubscriptionInfo,
+) -> Value {
+    let kinds = [
+        (PubSubSubscriptionKind::Exact, "Exact"),
+        (PubSubSubscriptionKind::Pattern, "Pattern"),
+        (PubSubSubscriptionKind::Sharded, "Sharded"),
+    ];
+    let entry = |key: &str, value: Value| (Value::SimpleString(key.to_string()), value);
+    Value::Map(
+        kinds
+            .into_iter()
+            .map(|(kind, name)| {
+                let mut desired: Vec<_> = desired.get(&kind).into_iter().flatten().collect();
+                desired.sort();
+                let count = desired.len() as i64;
+                let (active, pending): (Vec<_>, Vec<_>) =
+                    desired.into_iter().partition(|channel| {
+                        actual
+                            .get(&kind)
+                            .is_some_and(|channels| channels.contains(*channel))
+                    });
+                let to_values = |channels: Vec<&PubSubChannelOrPattern>| {
+                    Value::Array(
+                        channels
+                            .into_iter()
+                            .map(|channel| Value::BulkString(channel.clone()))
+                            .collect(),
+                    )
+                };
+                entry(
+                    name,
+                    Value::Map(vec![
+                        entry("count", Value::Int(count)),
+                        entry("active", to_values(active)),
+                        entry("pending", to_values(pending)),
+                    ]),
+                )
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+
+    fn subscriptions(kind: PubSubSubscriptionKind, channels: &[&str]) -> PubSubSubscriptionInfo {
+        HashMap::from([(
+            kind,
+            channels
+                .iter()
+                .map(|channel| channel.as_bytes().to_vec())
+                .collect::<HashSet<_>>(),
+        )])
+    }
+
+    #[test]
+    fn test_subscriptions_summary_splits_active_and_pending() {
+        let desired = subscriptions(PubSubSubscriptionKind::Pattern, &["b*", "a*", "c*"]);
+        let actual = subscriptions(PubSubSubscriptionKind::Pattern, &["c*", "a*", "stale*"]);
+
+        let Value::Map(kinds) = subscriptions_summary(&desired, &actual) else {
+            panic!("Expected a map");
+        };
+        assert_eq!(kinds.len(), 3);
+        let (_, Value::Map(exact)) = &kinds[0] else {
+            panic!("Expected a map");
+        };
+        assert_eq!(exact[0].1, Value::Int(0));
+
+        let (name, Value::Map(pattern)) = &kinds[1] else {
+            panic!("Expected a map");
+        };
+        assert_eq!(name, &Value::SimpleString("Pattern".to_string()));
+        assert_eq!(pattern[0].1, Value::Int(3));
+        assert_eq!(
+            pattern[1].1,
+            Value::Array(vec![
+                Value::BulkString(b"a*".to_vec()),
+                Value::BulkString(b"c*".to_vec()),
+            ])
+        );
+        assert_eq!(
+            pattern[2].1,
+            Value::Array(vec![Value::BulkString(b"b*".to_vec())])
+        );
+    }
+}
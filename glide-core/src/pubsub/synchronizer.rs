@@ -1,19 +1,20 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use crate::client::{ClientWrapper, PubSubCommandApplier};
+use crate::pubsub::keyspace_events::is_keyspace_channel;
 use async_trait::async_trait;
 use logger_core::{log_debug, log_error, log_warn};
 use once_cell::sync::OnceCell;
 use redis::{
     Cmd, ErrorKind, PubSubChannelOrPattern, PubSubSubscriptionInfo, PubSubSubscriptionKind,
-    PubSubSynchronizer, RedisError, RedisResult, SlotMap, Value, cluster_routing::Routable,
-    cluster_routing::SingleNodeRoutingInfo,
+    PubSubSynchronizer, PushInfo, PushKind, RedisError, RedisResult, SlotMap, Value,
+    cluster_routing::Routable, cluster_routing::SingleNodeRoutingInfo,
 };
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant};
 use telemetrylib::GlideOpenTelemetry;
-use tokio::sync::{Notify, RwLock as TokioRwLock};
+use tokio::sync::{Notify, RwLock as TokioRwLock, mpsc};
 
 const LOCK_ERR: &str = "Lock poisoned";
 const DEFAULT_RECONCILIATION_INTERVAL: Duration = Duration::from_secs(3);
@@ -34,9 +35,16 @@ const STANDALONE_SUBSCRIPTION_KINDS: &[PubSubSubscriptionKind] = &[
 struct SyncDiff {
     is_synchronized: bool,
     to_subscribe: PubSubSubscriptionInfo,
+    to_subscribe_by_address: HashMap<String, PubSubSubscriptionInfo>,
     to_unsubscribe_by_address: HashMap<String, PubSubSubscriptionInfo>,
 }
 
+/// Subscriptions dropped by a disconnection, waiting to be restored by the reconciliation task
+struct LostSubscriptions {
+    disconnected_at: Instant,
+    subscriptions: PubSubSubscriptionInfo,
+}
+
 /// Glide PubSub Synchronizer
 ///
 /// Implements the observer pattern for managing PubSub subscriptions:
@@ -74,10 +82,20 @@ pub struct GlidePubSubSynchronizer {
 
     /// Request timeout for non-blocking operations
     request_timeout: Duration,
+
+    /// Sender used to notify the wrapper once the subscriptions lost on disconnection are restored
+    push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+
+    /// Subscriptions lost on disconnection that weren't restored yet
+    lost_subscriptions: Mutex<Option<LostSubscriptions>>,
+
+    /// Addresses of the primaries in the last topology, on which the keyspace patterns are subscribed in cluster mode
+    primary_addresses: RwLock<HashSet<String>>,
 }
 
 impl GlidePubSubSynchronizer {
     pub fn new(
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
         initial_subscriptions: Option<PubSubSubscriptionInfo>,
         is_cluster: bool,
         reconciliation_interval: Option<Duration>,
@@ -96,6 +114,9 @@ impl GlidePubSubSynchronizer {
             pending_unsubscribes: RwLock::new(HashMap::new()),
             reconciliation_interval: interval,
             request_timeout,
+            push_sender,
+            lost_subscriptions: Mutex::new(None),
+            primary_addresses: RwLock::new(HashSet::new()),
         });
 
         sync.start_reconciliation_task();
@@ -116,6 +137,17 @@ impl GlidePubSubSynchronizer {
         }
     }
 
+    /// Returns true if the subscription must be made on every primary rather than on a single node.
+    /// In cluster mode, each node only publishes the keyspace notifications of its own keys,
+    /// so the keyspace and keyevent patterns are subscribed on all primaries.
+    fn is_per_primary_subscription(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channel: &PubSubChannelOrPattern,
+    ) -> bool {
+        self.is_cluster && kind == PubSubSubscriptionKind::Pattern && is_keyspace_channel(channel)
+    }
+
     // get the actual subscriptions not by address
     fn compute_actual_subscriptions(&self) -> PubSubSubscriptionInfo {
         let current_by_addr = self
@@ -155,11 +187,15 @@ impl GlidePubSubSynchronizer {
             .map(|k| (*k, HashSet::new()))
             .collect();
 
+        let primary_addresses = self.primary_addresses.read().expect(LOCK_ERR);
+
         let mut to_unsubscribe_by_address: HashMap<String, PubSubSubscriptionInfo> = HashMap::new();
 
         // Pass 1: O(current_subscriptions)
-        // Iterate over current subscriptions and add to to_unsub each subscription not in desired
+        // Iterate over current subscriptions and add to to_unsub each subscription not in desired,
+        // or subscribed per primary on a node that isn't a primary anymore
         for (addr, subs) in current_by_addr.iter() {
+            let is_primary = primary_addresses.contains(addr);
             for (kind, channels) in subs.iter() {
                 actual
                     .get_mut(kind)
@@ -170,7 +206,10 @@ impl GlidePubSubSynchronizer {
 
                 let to_unsub: HashSet<_> = channels
                     .iter()
-                    .filter(|ch| desired_for_kind.is_none_or(|d| !d.contains(*ch)))
+                    .filter(|ch| {
+                        desired_for_kind.is_none_or(|d| !d.contains(*ch))
+                            || (!is_primary && self.is_per_primary_subscription(*kind, ch))
+                    })
                     .cloned()
                     .collect();
 
@@ -186,30 +225,53 @@ impl GlidePubSubSynchronizer {
         }
 
         let mut to_subscribe = PubSubSubscriptionInfo::new();
+        let mut to_subscribe_by_address: HashMap<String, PubSubSubscriptionInfo> = HashMap::new();
 
         // Pass 2: O(desired_subscriptions)
-        // Iterate over desired subscriptions and add to to_sub each subscription not in actual
+        // Iterate over desired subscriptions and add to to_sub each subscription not in actual,
+        // and each subscription made per primary to the primaries it's missing from
         for kind in self.subscription_kinds() {
             if let Some(desired_channels) = desired.get(kind) {
                 let actual_channels = actual.get(kind);
 
-                let to_sub: HashSet<_> = desired_channels
+                let (per_primary, to_sub): (HashSet<_>, HashSet<_>) = desired_channels
                     .iter()
-                    .filter(|ch| actual_channels.is_none_or(|a| !a.contains(*ch)))
                     .cloned()
+                    .partition(|ch| self.is_per_primary_subscription(*kind, ch));
+                let to_sub: HashSet<_> = to_sub
+                    .into_iter()
+                    .filter(|ch| actual_channels.is_none_or(|a| !a.contains(ch)))
                     .collect();
 
                 if !to_sub.is_empty() {
                     to_subscribe.insert(*kind, to_sub);
                 }
+
+                for addr in primary_addresses.iter() {
+                    let subscribed = current_by_addr.get(addr).and_then(|subs| subs.get(kind));
+                    let missing: HashSet<_> = per_primary
+                        .iter()
+                        .filter(|ch| subscribed.is_none_or(|s| !s.contains(*ch)))
+                        .cloned()
+                        .collect();
+                    if !missing.is_empty() {
+                        to_subscribe_by_address
+                            .entry(addr.clone())
+                            .or_default()
+                            .insert(*kind, missing);
+                    }
+                }
             }
         }
 
-        let is_synchronized = to_subscribe.is_empty() && to_unsubscribe_by_address.is_empty();
+        let is_synchronized = to_subscribe.is_empty()
+            && to_subscribe_by_address.is_empty()
+            && to_unsubscribe_by_address.is_empty();
 
         SyncDiff {
             is_synchronized,
             to_subscribe,
+            to_subscribe_by_address,
             to_unsubscribe_by_address,
         }
     }
@@ -220,12 +282,55 @@ impl GlidePubSubSynchronizer {
 
         if state.is_synchronized {
             let _ = GlideOpenTelemetry::update_subscription_last_sync_timestamp();
+            self.notify_restored_subscriptions();
             return;
         }
 
         let _ = GlideOpenTelemetry::record_subscription_out_of_sync();
     }
 
+    /// Notify the wrapper that the subscriptions lost on disconnection were restored.
+    /// Should only be called once the subscriptions are synchronized.
+    fn notify_restored_subscriptions(&self) {
+        let Some(lost) = self.lost_subscriptions.lock().expect(LOCK_ERR).take() else {
+            return;
+        };
+        let Some(push_sender) = &self.push_sender else {
+            return;
+        };
+
+        // Subscriptions removed by the user since the disconnection weren't restored
+        let desired = self.desired_subscriptions.read().expect(LOCK_ERR);
+        let restored = lost.subscriptions.into_iter().flat_map(|(kind, channels)| {
+            let desired_for_kind = desired.get(&kind);
+            channels
+                .into_iter()
+                .filter(move |ch| desired_for_kind.is_some_and(|d| d.contains(ch)))
+                .map(move |ch| Value::Array(vec![Value::Int(kind as i64), Value::BulkString(ch)]))
+        });
+
+        let mut data = vec![Value::BulkString(
+            lost.disconnected_at
+                .elapsed()
+                .as_millis()
+                .to_string()
+                .into_bytes(),
+        )];
+        data.extend(restored);
+
+        log_debug(
+            "pubsub_synchronizer",
+            format!(
+                "Restored {} subscriptions lost on disconnection",
+                data.len() - 1
+            ),
+        );
+        let _ = push_sender.send(PushInfo {
+            kind: PushKind::Resubscribed,
+            data,
+        });
+    }
+
     async fn apply_pubsub(
         &self,
         cmd: &mut Cmd,
@@ -324,6 +429,15 @@ impl GlidePubSubSynchronizer {
                 .await;
         }
 
+        for (addr, subs_by_kind) in diff.to_subscribe_by_address {
+            let routing = Self::parse_address_to_routing(&addr).ok();
+
+            for (kind, channels) in subs_by_kind {
+                self.execute_subscription_change(channels, kind, true, routing.clone())
+                    .await;
+            }
+        }
+
         for (addr, subs_by_kind) in diff.to_unsubscribe_by_address {
             let routing = Self::parse_address_to_routing(&addr).ok();
 
@@ -724,10 +838,15 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
         } else {
             // For regular subscriptions (Exact/Pattern), remove from ALL addresses.
             // These are not slot-bound, and the server's unsubscribe is authoritative.
-            for (_, addr_subs) in current_by_addr.iter_mut() {
+            // The subscriptions made per primary are only removed from the address that unsubscribed them.
+            for (addr, addr_subs) in current_by_addr.iter_mut() {
                 if let Some(existing) = addr_subs.get_mut(&subscription_type) {
                     for channel in &channels {
-                        existing.remove(channel);
+                        if *addr == address
+                            || !self.is_per_primary_subscription(subscription_type, channel)
+                        {
+                            existing.remove(channel);
+                        }
                     }
                 }
             }
@@ -758,9 +877,21 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
             .write()
             .expect(LOCK_ERR);
 
+        let mut lost_subscriptions = self.lost_subscriptions.lock().expect(LOCK_ERR);
         for address in addresses {
-            current_by_addr.remove(address);
+            let Some(removed) = current_by_addr.remove(address) else {
+                continue;
+            };
+            // Keep the time of the first disconnection, so the reported gap covers all of them
+            let lost = lost_subscriptions.get_or_insert_with(|| LostSubscriptions {
+                disconnected_at: Instant::now(),
+                subscriptions: PubSubSubscriptionInfo::new(),
+            });
+            for (kind, channels) in removed {
+                lost.subscriptions.entry(kind).or_default().extend(channels);
+            }
         }
+        drop(lost_subscriptions);
         self.trigger_reconciliation();
     }
 
@@ -770,8 +901,19 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
             .iter()
             .map(|arc| arc.to_string())
             .collect();
+        let new_primaries: HashSet<String> = new_slot_map
+            .addresses_for_all_primaries()
+            .iter()
+            .map(|arc| arc.to_string())
+            .collect();
 
-        let mut modified = false;
+        // The keyspace patterns are subscribed on the new primaries, and unsubscribed from the demoted ones
+        let mut modified = {
+            let mut primary_addresses = self.primary_addresses.write().expect(LOCK_ERR);
+            let primaries_changed = *primary_addresses != new_primaries;
+            *primary_addresses = new_primaries.clone();
+            primaries_changed
+        };
 
         {
             let mut current_by_addr = self
@@ -830,6 +972,15 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
                     let mut migrated_channels: HashSet<PubSubChannelOrPattern> = HashSet::new();
 
                     channels.retain(|channel| {
+                        if self.is_per_primary_subscription(*kind, channel) {
+                            // Not slot-bound, kept as long as the node is a primary
+                            if new_primaries.contains(addr.as_str()) {
+                                return true;
+                            }
+                            migrated_channels.insert(channel.clone());
+                            modified = true;
+                            return false;
+                        }
                         let slot = redis::cluster_topology::get_slot(channel);
 
                         match new_slot_map.shard_addrs_for_slot(slot) {
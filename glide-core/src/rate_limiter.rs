@@ -0,0 +1,295 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! A rate limiter built on Lua scripts, so every wrapper limits requests the same way.
+//!
+//! Each bucket is a single key, updated atomically by the script of its algorithm with the server's time, so the
+//! clients sharing a bucket don't depend on their clocks. Denied acquisitions may be cached locally for a short time,
+//! so a client that keeps retrying a denied bucket doesn't send a script for each attempt.
+
+use redis::{ErrorKind, RedisError, RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The algorithm of a rate limiter.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+#[repr(C)]
+pub enum RateLimitAlgorithm {
+    /// A bucket of `limit` tokens, refilled continuously at `limit` tokens per `window`. Allows bursts of up to
+    /// `limit` tokens.
+    #[default]
+    TokenBucket,
+    /// At most `limit` tokens in any `window`, tracked with a log of the acquired tokens. Memory grows with the limit.
+    SlidingWindow,
+}
+
+/// The configuration of a rate limiter.
+#[derive(Clone, Debug)]
+pub struct RateLimitConfig {
+    pub algorithm: RateLimitAlgorithm,
+    /// The number of tokens that can be acquired in a window.
+    pub limit: u64,
+    /// The window of the limit. Must be at least a millisecond.
+    pub window: Duration,
+    /// How long a denied acquisition is cached locally, bounded by the time until the tokens may be available.
+    /// `None` sends every acquisition to the server.
+    pub deny_cache_duration: Option<Duration>,
+}
+
+/// Keeps the tokens in a hash of `tokens` and `ts`, the time in milliseconds the tokens were last refilled.
+/// Returns the decision, the remaining tokens and the milliseconds until the requested tokens are available.
+pub(crate) const TOKEN_BUCKET_SCRIPT: &str = r#"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local requested = tonumber(ARGV[3])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local rate = limit / window
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(bucket[1]) or limit
+local ts = tonumber(bucket[2]) or now
+tokens = math.min(limit, tokens + math.max(0, now - ts) * rate)
+local allowed = 0
+local retry_after = 0
+if tokens >= requested then
+    tokens = tokens - requested
+    allowed = 1
+else
+    retry_after = math.ceil((requested - tokens) / rate)
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], window)
+return {allowed, math.floor(tokens), retry_after}
+"#;
+
+/// Keeps a sorted set of the acquired tokens, scored by the time in milliseconds they were acquired.
+/// Returns the decision, the remaining tokens and the milliseconds until the requested tokens are available.
+pub(crate) const SLIDING_WINDOW_SCRIPT: &str = r#"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local requested = tonumber(ARGV[3])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+local count = redis.call('ZCARD', KEYS[1])
+if count + requested <= limit then
+    for i = 1, requested do
+        redis.call('ZADD', KEYS[1], now, now .. '-' .. count .. '-' .. i)
+    end
+    redis.call('PEXPIRE', KEYS[1], window)
+    return {1, limit - count - requested, 0}
+end
+local expiring = count + requested - limit - 1
+local oldest = redis.call('ZRANGE', KEYS[1], expiring, expiring, 'WITHSCORES')
+return {0, limit - count, math.max(1, tonumber(oldest[2]) + window - now)}
+"#;
+
+impl RateLimitConfig {
+    pub(crate) fn script(&self) -> &'static str {
+        match self.algorithm {
+            RateLimitAlgorithm::TokenBucket => TOKEN_BUCKET_SCRIPT,
+            RateLimitAlgorithm::SlidingWindow => SLIDING_WINDOW_SCRIPT,
+        }
+    }
+
+    /// Validates the configuration, and that `tokens` can ever be acquired.
+    pub(crate) fn validate(&self, tokens: u64) -> RedisResult<()> {
+        let error = if self.limit == 0 {
+            "the limit must be positive"
+        } else if self.window < Duration::from_millis(1) {
+            "the window must be at least a millisecond"
+        } else if tokens == 0 || tokens > self.limit {
+            "the tokens must be positive and no more than the limit"
+        } else {
+            return Ok(());
+        };
+        Err(RedisError::from((
+            ErrorKind::ClientError,
+            "Invalid rate limit",
+            error.to_string(),
+        )))
+    }
+
+    /// The arguments of the script: the limit, the window in milliseconds and the requested tokens.
+    pub(crate) fn script_args(&self, tokens: u64) -> [Vec<u8>; 3] {
+        [
+            self.limit.to_string().into_bytes(),
+            self.window.as_millis().to_string().into_bytes(),
+            tokens.to_string().into_bytes(),
+        ]
+    }
+}
+
+/// The decision of an acquisition.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    /// The tokens left in the bucket after the acquisition.
+    pub remaining: u64,
+    /// How long until the requested tokens may be available. Zero if the acquisition was allowed.
+    pub retry_after: Duration,
+    /// Whether the decision was taken from the local cache of denied acquisitions, without reaching the server.
+    pub cached: bool,
+}
+
+impl RateLimitDecision {
+    /// Converts the reply of a rate limiter script.
+    pub(crate) fn from_script_response(value: Value) -> RedisResult<Self> {
+        let integers = match &value {
+            Value::Array(values) if values.len() == 3 => values
+                .iter()
+                .map(|value| match value {
+                    Value::Int(integer) => u64::try_from(*integer).ok(),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        };
+        let Some([allowed, remaining, retry_after]) = integers.as_deref() else {
+            return Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Unexpected response for the rate limiter script",
+                format!("(response was {value:?})"),
+            )));
+        };
+        Ok(Self {
+            allowed: *allowed == 1,
+            remaining: *remaining,
+            retry_after: Duration::from_millis(*retry_after),
+            cached: false,
+        })
+    }
+
+    /// Converts the decision to a map of `allowed`, `remaining`, `retry_after_ms` and `cached`.
+    pub fn into_value(self) -> Value {
+        let entry = |name: &str, value: Value| (Value::SimpleString(name.to_string()), value);
+        Value::Map(vec![
+            entry("allowed", Value::Boolean(self.allowed)),
+            entry("remaining", Value::Int(self.remaining as i64)),
+            entry(
+                "retry_after_ms",
+                Value::Int(self.retry_after.as_millis() as i64),
+            ),
+            entry("cached", Value::Boolean(self.cached)),
+        ])
+    }
+}
+
+struct DeniedAcquisition {
+    tokens: u64,
+    until: Instant,
+}
+
+/// The local cache of denied acquisitions of a client, by bucket key.
+#[derive(Default)]
+pub(crate) struct DenyCache {
+    entries: Mutex<HashMap<Vec<u8>, DeniedAcquisition>>,
+}
+
+impl DenyCache {
+    /// Returns a cached denial of `tokens` from `bucket_key`. A cached denial also denies acquiring more tokens.
+    pub(crate) fn get(
+        &self,
+        bucket_key: &[u8],
+        tokens: u64,
+        now: Instant,
+    ) -> Option<RateLimitDecision> {
+        let entries = self.entries.lock().unwrap();
+        let denied = entries.get(bucket_key)?;
+        if denied.until <= now || tokens < denied.tokens {
+            return None;
+        }
+        Some(RateLimitDecision {
+            allowed: false,
+            remaining: 0,
+            retry_after: denied.until - now,
+            cached: true,
+        })
+    }
+
+    /// Caches a denial of `tokens` from `bucket_key` for `duration`, bounded by the retry time of the decision.
+    pub(crate) fn insert(
+        &self,
+        bucket_key: &[u8],
+        tokens: u64,
+        decision: &RateLimitDecision,
+        duration: Duration,
+        now: Instant,
+    ) {
+        let until = now + duration.min(decision.retry_after);
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, denied| denied.until > now);
+        entries.insert(bucket_key.to_vec(), DeniedAcquisition { tokens, until });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(limit: u64) -> RateLimitConfig {
+        RateLimitConfig {
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            limit,
+            window: Duration::from_secs(1),
+            deny_cache_duration: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_rate_limit_config() {
+        assert!(config(10).validate(10).is_ok());
+        assert!(config(10).validate(11).is_err());
+        assert!(config(10).validate(0).is_err());
+        assert!(config(0).validate(1).is_err());
+        let mut short_window = config(10);
+        short_window.window = Duration::from_micros(10);
+        assert!(short_window.validate(1).is_err());
+    }
+
+    #[test]
+    fn test_decision_from_script_response() {
+        let decision = RateLimitDecision::from_script_response(Value::Array(vec![
+            Value::Int(0),
+            Value::Int(2),
+            Value::Int(150),
+        ]))
+        .unwrap();
+        assert_eq!(
+            decision,
+            RateLimitDecision {
+                allowed: false,
+                remaining: 2,
+                retry_after: Duration::from_millis(150),
+                cached: false,
+            }
+        );
+        assert!(RateLimitDecision::from_script_response(Value::Okay).is_err());
+    }
+
+    #[test]
+    fn test_deny_cache() {
+        let cache = DenyCache::default();
+        let now = Instant::now();
+        let denied = RateLimitDecision {
+            allowed: false,
+            remaining: 0,
+            retry_after: Duration::from_millis(50),
+            cached: false,
+        };
+        cache.insert(b"bucket", 3, &denied, Duration::from_millis(100), now);
+
+        let cached = cache.get(b"bucket", 5, now).unwrap();
+        assert!(cached.cached);
+        assert_eq!(cached.retry_after, Duration::from_millis(50));
+        // Fewer tokens may still be available.
+        assert!(cache.get(b"bucket", 2, now).is_none());
+        assert!(cache.get(b"other", 3, now).is_none());
+        // The denial is cached only until the tokens may be available.
+        assert!(
+            cache
+                .get(b"bucket", 3, now + Duration::from_millis(50))
+                .is_none()
+        );
+    }
+}
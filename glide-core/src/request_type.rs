@@ -6,7 +6,7 @@ use redis::{Cmd, cmd};
 use crate::command_request::RequestType as ProtobufRequestType;
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RequestType {
     /// Invalid request type
     InvalidRequest = 0,
@@ -426,6 +426,8 @@ pub enum RequestType {
     JsonStrLen = 2020,
     JsonToggle = 2021,
     JsonType = 2022,
+    JsonMSet = 2023,
+    JsonMerge = 2024,
 
     //// Vector Search commands
     FtList = 2101,
@@ -726,6 +728,8 @@ impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
             ProtobufRequestType::JsonStrLen => RequestType::JsonStrLen,
             ProtobufRequestType::JsonToggle => RequestType::JsonToggle,
             ProtobufRequestType::JsonType => RequestType::JsonType,
+            ProtobufRequestType::JsonMSet => RequestType::JsonMSet,
+            ProtobufRequestType::JsonMerge => RequestType::JsonMerge,
             ProtobufRequestType::FtList => RequestType::FtList,
             ProtobufRequestType::FtAggregate => RequestType::FtAggregate,
             ProtobufRequestType::FtAliasAdd => RequestType::FtAliasAdd,
@@ -835,7 +839,8 @@ impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
             ProtobufRequestType::SSubscribeBlocking => RequestType::SSubscribeBlocking,
             ProtobufRequestType::SUnsubscribeBlocking => RequestType::SUnsubscribeBlocking,
             ProtobufRequestType::GetSubscriptions => RequestType::GetSubscriptions,
-            _ => todo!(),
+            // The request types that aren't supported are rejected like unknown ones.
+            _ => RequestType::InvalidRequest,
         }
     }
 }
@@ -1148,6 +1153,8 @@ impl RequestType {
             RequestType::JsonStrLen => Some(cmd("JSON.STRLEN")),
             RequestType::JsonToggle => Some(cmd("JSON.TOGGLE")),
             RequestType::JsonType => Some(cmd("JSON.TYPE")),
+            RequestType::JsonMSet => Some(cmd("JSON.MSET")),
+            RequestType::JsonMerge => Some(cmd("JSON.MERGE")),
             RequestType::FtList => Some(cmd("FT._LIST")),
             RequestType::FtAggregate => Some(cmd("FT.AGGREGATE")),
             RequestType::FtAliasAdd => Some(cmd("FT.ALIASADD")),
@@ -1252,7 +1259,221 @@ impl RequestType {
             RequestType::SSubscribeBlocking => Some(cmd("SSUBSCRIBE_BLOCKING")),
             RequestType::SUnsubscribeBlocking => Some(cmd("SUNSUBSCRIBE_BLOCKING")),
             RequestType::GetSubscriptions => Some(cmd("GET_SUBSCRIPTIONS")),
-            _ => todo!(),
+            _ => None,
         }
     }
+
+    /// Validates the number of arguments (excluding the command name) passed to a command,
+    /// so malformed requests are rejected before being routed. Commands without known arity
+    /// constraints are always accepted.
+    pub fn validate_arg_count(&self, arg_count: usize) -> Result<(), String> {
+        let (min, max) = match self {
+            RequestType::JsonGet | RequestType::JsonDebug => (1, None),
+            RequestType::JsonArrLen
+            | RequestType::JsonClear
+            | RequestType::JsonDel
+            | RequestType::JsonForget
+            | RequestType::JsonObjKeys
+            | RequestType::JsonObjLen
+            | RequestType::JsonResp
+            | RequestType::JsonStrLen
+            | RequestType::JsonToggle
+            | RequestType::JsonType => (1, Some(2)),
+            RequestType::JsonArrPop => (1, Some(3)),
+            RequestType::JsonMGet => (2, None),
+            RequestType::JsonStrAppend => (2, Some(3)),
+            RequestType::JsonSet => (3, Some(4)),
+            RequestType::JsonNumIncrBy | RequestType::JsonNumMultBy | RequestType::JsonMerge => {
+                (3, Some(3))
+            }
+            RequestType::JsonArrAppend => (3, None),
+            RequestType::JsonArrIndex => (3, Some(5)),
+            RequestType::JsonArrInsert => (4, None),
+            RequestType::JsonArrTrim => (4, Some(4)),
+            RequestType::FunctionStats => (0, Some(0)),
+            RequestType::ClientNoEvict
+            | RequestType::ClientNoTouch
+            | RequestType::ExpireTime
+            | RequestType::PExpireTime
+            | RequestType::ObjectEncoding
+            | RequestType::ObjectFreq
+            | RequestType::ObjectIdleTime
+            | RequestType::ObjectRefCount => (1, Some(1)),
+            // LPOS key element [RANK rank] [COUNT num-matches] [MAXLEN len]
+            RequestType::LPos => (2, Some(8)),
+            RequestType::WaitAof => (3, Some(3)),
+            // SINTERCARD/ZINTERCARD numkeys key [key ...] [LIMIT limit]
+            RequestType::SInterCard | RequestType::ZInterCard => (2, None),
+            // LMPOP/ZMPOP numkeys key [key ...] direction [COUNT count]
+            RequestType::LMPop | RequestType::ZMPop => (3, None),
+            // BLMPOP/BZMPOP timeout numkeys key [key ...] direction [COUNT count]
+            RequestType::BLMPop | RequestType::BZMPop => (4, None),
+            RequestType::JsonMSet => {
+                // JSON.MSET key path value [key path value ...]
+                if arg_count == 0 || arg_count % 3 != 0 {
+                    return Err(format!(
+                        "{self:?} expects key, path and value triplets, got {arg_count} arguments"
+                    ));
+                }
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+        if arg_count < min || max.is_some_and(|max| arg_count > max) {
+            return Err(format!(
+                "Wrong number of arguments for {self:?}: got {arg_count}, expected {}",
+                match max {
+                    Some(max) if max == min => format!("{min}"),
+                    Some(max) => format!("between {min} and {max}"),
+                    None => format!("at least {min}"),
+                }
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates the arguments (excluding the command name) passed to a command, so invalid calls fail fast with a
+    /// client error instead of a server round trip. Besides the number of arguments checked by
+    /// [`Self::validate_arg_count`], checks the key count of the commands taking `numkeys` and the flags of the
+    /// commands taking a fixed set of values.
+    pub fn validate_args(&self, args: &[&[u8]]) -> Result<(), String> {
+        self.validate_arg_count(args.len())?;
+        match self {
+            RequestType::SInterCard | RequestType::ZInterCard => {
+                self.validate_key_count(args, 0, 0)
+            }
+            RequestType::LMPop | RequestType::ZMPop => self.validate_key_count(args, 0, 1),
+            RequestType::BLMPop | RequestType::BZMPop => self.validate_key_count(args, 1, 1),
+            RequestType::ClientNoEvict | RequestType::ClientNoTouch => {
+                if args[0].eq_ignore_ascii_case(b"ON") || args[0].eq_ignore_ascii_case(b"OFF") {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "{self:?} expects ON or OFF, got {}",
+                        String::from_utf8_lossy(args[0])
+                    ))
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the `numkeys` argument at `index` is a positive integer, followed by as many keys and at least
+    /// `required_after_keys` arguments.
+    fn validate_key_count(
+        &self,
+        args: &[&[u8]],
+        index: usize,
+        required_after_keys: usize,
+    ) -> Result<(), String> {
+        let key_count = std::str::from_utf8(args[index])
+            .ok()
+            .and_then(|key_count| key_count.parse::<usize>().ok())
+            .filter(|key_count| *key_count > 0)
+            .ok_or_else(|| {
+                format!(
+                    "{self:?} expects a positive number of keys, got {}",
+                    String::from_utf8_lossy(args[index])
+                )
+            })?;
+        let available_keys = args.len() - index - 1;
+        if key_count + required_after_keys > available_keys {
+            return Err(format!(
+                "{self:?} expects {key_count} keys, got {available_keys} arguments after the number of keys"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestType;
+
+    #[test]
+    fn test_validate_arg_count_for_json_commands() {
+        assert!(RequestType::JsonSet.validate_arg_count(3).is_ok());
+        assert!(RequestType::JsonSet.validate_arg_count(4).is_ok());
+        assert!(RequestType::JsonSet.validate_arg_count(2).is_err());
+        assert!(RequestType::JsonSet.validate_arg_count(5).is_err());
+        assert!(RequestType::JsonGet.validate_arg_count(10).is_ok());
+        assert!(RequestType::JsonGet.validate_arg_count(0).is_err());
+        assert!(RequestType::JsonArrTrim.validate_arg_count(4).is_ok());
+        assert!(RequestType::JsonArrTrim.validate_arg_count(3).is_err());
+        assert!(RequestType::JsonMSet.validate_arg_count(6).is_ok());
+        assert!(RequestType::JsonMSet.validate_arg_count(4).is_err());
+        assert!(RequestType::JsonMSet.validate_arg_count(0).is_err());
+    }
+
+    #[test]
+    fn test_validate_args_for_key_count_commands() {
+        let args = |args: &[&'static str]| -> Vec<&'static [u8]> {
+            args.iter().map(|arg| arg.as_bytes()).collect()
+        };
+        assert!(
+            RequestType::SInterCard
+                .validate_args(&args(&["2", "a", "b", "LIMIT", "1"]))
+                .is_ok()
+        );
+        assert!(
+            RequestType::SInterCard
+                .validate_args(&args(&["3", "a", "b"]))
+                .is_err()
+        );
+        assert!(
+            RequestType::LMPop
+                .validate_args(&args(&["1", "a", "LEFT"]))
+                .is_ok()
+        );
+        assert!(
+            RequestType::LMPop
+                .validate_args(&args(&["2", "a", "LEFT"]))
+                .is_err()
+        );
+        assert!(
+            RequestType::BZMPop
+                .validate_args(&args(&["0.5", "1", "a", "MIN", "COUNT", "2"]))
+                .is_ok()
+        );
+        assert!(
+            RequestType::ZMPop
+                .validate_args(&args(&["0", "a", "MIN"]))
+                .is_err()
+        );
+        assert!(
+            RequestType::ClientNoEvict
+                .validate_args(&args(&["on"]))
+                .is_ok()
+        );
+        assert!(
+            RequestType::ClientNoEvict
+                .validate_args(&args(&["yes"]))
+                .is_err()
+        );
+        assert!(
+            RequestType::FunctionStats
+                .validate_args(&args(&["x"]))
+                .is_err()
+        );
+        assert!(RequestType::LPos.validate_args(&args(&["key"])).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "proto")]
+    fn test_unsupported_request_types_are_rejected() {
+        use super::ProtobufRequestType;
+
+        let request_type: RequestType =
+            protobuf::EnumOrUnknown::new(ProtobufRequestType::SetEx).into();
+        assert!(matches!(request_type, RequestType::InvalidRequest));
+        let request_type: RequestType = protobuf::EnumOrUnknown::from_i32(i32::MAX).into();
+        assert!(matches!(request_type, RequestType::InvalidRequest));
+        assert!(RequestType::SetEx.get_command().is_none());
+    }
+
+    #[test]
+    fn test_validate_arg_count_accepts_commands_without_constraints() {
+        assert!(RequestType::Get.validate_arg_count(0).is_ok());
+        assert!(RequestType::CustomCommand.validate_arg_count(7).is_ok());
+    }
 }
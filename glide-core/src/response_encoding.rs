@@ -0,0 +1,150 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Serializes command results as protobuf [`Response`] messages, for the wrappers that parse the responses with
+//! protobuf instead of walking the values returned through FFI.
+//!
+//! Unlike the socket layer, which passes a pointer to the value in `resp_pointer`, the value itself is serialized
+//! in `response_value`.
+
+use crate::errors::{RequestErrorType, error_message, error_type};
+use crate::response::{self, Response, ResponseValue, response_value};
+use protobuf::{Message, MessageField};
+use redis::{RedisResult, Value};
+
+/// Serializes the result of a command as a protobuf [`Response`].
+///
+/// `OK` is encoded as the `OK` constant response and `nil` as a response without a value, the same as in the socket
+/// layer. Errors are encoded as a [`response::RequestError`].
+pub fn encode_response(result: RedisResult<Value>) -> Vec<u8> {
+    let mut response = Response::new();
+    response.value = match result {
+        Ok(Value::Okay) => Some(response::response::Value::ConstantResponse(
+            response::ConstantResponse::OK.into(),
+        )),
+        Ok(Value::Nil) => None,
+        Ok(value) => Some(response::response::Value::ResponseValue(to_response_value(
+            value,
+        ))),
+        Err(err) => Some(response::response::Value::RequestError(
+            response::RequestError {
+                type_: to_protobuf_error_type(error_type(&err)).into(),
+                message: error_message(&err).into(),
+                ..Default::default()
+            },
+        )),
+    };
+    response
+        .write_to_bytes()
+        .expect("Serializing a protobuf response to a vector can't fail")
+}
+
+/// Maps the error type of a request to its protobuf counterpart.
+pub(crate) fn to_protobuf_error_type(error_type: RequestErrorType) -> response::RequestErrorType {
+    match error_type {
+        RequestErrorType::Unspecified => response::RequestErrorType::Unspecified,
+        RequestErrorType::ExecAbort => response::RequestErrorType::ExecAbort,
+        RequestErrorType::Timeout => response::RequestErrorType::Timeout,
+        RequestErrorType::Disconnect => response::RequestErrorType::Disconnect,
+        // The protobuf protocol has no dedicated type, and the message tells the two apart.
+        RequestErrorType::WatchedKeyModified => response::RequestErrorType::ExecAbort,
+        RequestErrorType::CircuitOpen => response::RequestErrorType::Disconnect,
+        RequestErrorType::ResponseSchemaMismatch => response::RequestErrorType::Unspecified,
+        RequestErrorType::DeadlineExceededBeforeSend => response::RequestErrorType::Timeout,
+    }
+}
+
+fn to_response_value(value: Value) -> ResponseValue {
+    let value = match value {
+        Value::Nil => response_value::Value::Nil(true),
+        Value::Okay => response_value::Value::Okay(true),
+        Value::SimpleString(text) => response_value::Value::SimpleString(text.into_bytes().into()),
+        Value::BulkString(bytes) => response_value::Value::BulkString(bytes.into()),
+        Value::Int(number) => response_value::Value::Int(number),
+        Value::Double(number) => response_value::Value::Double(number),
+        Value::Boolean(boolean) => response_value::Value::Boolean(boolean),
+        Value::Array(values) => response_value::Value::Array(to_response_values(values)),
+        Value::Set(values) => response_value::Value::Set(to_response_values(values)),
+        Value::Push { data, .. } => response_value::Value::Array(to_response_values(data)),
+        Value::Map(entries) => response_value::Value::Map(response::ResponseMap {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| response::ResponseMapEntry {
+                    key: MessageField::some(to_response_value(key)),
+                    value: MessageField::some(to_response_value(value)),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }),
+        Value::Attribute { data, .. } => return to_response_value(*data),
+        Value::BigNumber(number) => response_value::Value::BigNumber(number.to_string().into()),
+        Value::VerbatimString { format, text } => {
+            response_value::Value::VerbatimString(response::VerbatimString {
+                format: format.to_string().into(),
+                text: text.into_bytes().into(),
+                ..Default::default()
+            })
+        }
+        Value::ServerError(err) => {
+            response_value::Value::ServerError(error_message(&err.into()).into())
+        }
+    };
+    ResponseValue {
+        value: Some(value),
+        ..Default::default()
+    }
+}
+
+fn to_response_values(values: Vec<Value>) -> response::ResponseValues {
+    response::ResponseValues {
+        values: values.into_iter().map(to_response_value).collect(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::ErrorKind;
+
+    fn decode(bytes: &[u8]) -> Response {
+        Response::parse_from_bytes(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_encode_constant_and_nil_responses() {
+        let response = decode(&encode_response(Ok(Value::Okay)));
+        assert_eq!(response.constant_response(), response::ConstantResponse::OK);
+
+        let response = decode(&encode_response(Ok(Value::Nil)));
+        assert!(response.value.is_none());
+    }
+
+    #[test]
+    fn test_encode_nested_values() {
+        let value = Value::Map(vec![(
+            Value::BulkString(b"key".to_vec()),
+            Value::Array(vec![Value::Int(1), Value::Nil, Value::Double(1.5)]),
+        )]);
+        let response = decode(&encode_response(Ok(value)));
+
+        let map = response.response_value().map();
+        assert_eq!(map.entries.len(), 1);
+        assert_eq!(map.entries[0].key.bulk_string(), b"key");
+        let values = &map.entries[0].value.array().values;
+        assert_eq!(values[0].int(), 1);
+        assert!(values[1].nil());
+        assert_eq!(values[2].double(), 1.5);
+    }
+
+    #[test]
+    fn test_encode_error() {
+        let err = redis::RedisError::from((ErrorKind::ResponseError, "WRONGTYPE", "bad".into()));
+        let response = decode(&encode_response(Err(err)));
+        assert_eq!(
+            response.request_error().type_.enum_value(),
+            Ok(response::RequestErrorType::Unspecified)
+        );
+        assert!(!response.request_error().message.is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Projection of large responses, such as the ones of `HGETALL` or `XRANGE`, applied before the responses are
+//! converted for the wrapper, so callers that need only a few fields don't pay for converting and allocating the rest.
+
+use redis::Value;
+
+/// The projection applied to a response.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResponseFilter {
+    /// Keeps only the entries of these fields. Applies to the response if it's a map of fields to values, such as the
+    /// response of `HGETALL`, and otherwise to each of the records it holds, such as the entries of `XRANGE`, given as
+    /// maps or as arrays of field and value pairs. `None` keeps all the fields.
+    pub fields: Option<Vec<Vec<u8>>>,
+    /// Keeps at most this many entries of the response, in the order they were returned. `None` keeps all entries.
+    pub max_entries: Option<usize>,
+}
+
+impl ResponseFilter {
+    /// Applies the projection to a response. Responses that aren't maps or arrays are returned as is.
+    pub fn apply(&self, value: Value) -> Value {
+        let value = match (value, self.max_entries) {
+            (Value::Map(mut entries), Some(max_entries)) => {
+                entries.truncate(max_entries);
+                Value::Map(entries)
+            }
+            (Value::Array(mut items), Some(max_entries)) => {
+                items.truncate(max_entries);
+                Value::Array(items)
+            }
+            (value, _) => value,
+        };
+        match &self.fields {
+            Some(fields) => project_fields(value, fields),
+            None => value,
+        }
+    }
+}
+
+fn project_fields(value: Value, fields: &[Vec<u8>]) -> Value {
+    match value {
+        Value::Map(entries) if entries.iter().any(|(_, value)| is_record(value)) => Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key, project_fields(value, fields)))
+                .collect(),
+        ),
+        Value::Map(entries) => Value::Map(
+            entries
+                .into_iter()
+                .filter(|(field, _)| is_projected(field, fields))
+                .collect(),
+        ),
+        Value::Array(items) if items.iter().all(|item| field_of_pair(item).is_some()) => {
+            Value::Array(
+                items
+                    .into_iter()
+                    .filter(|item| {
+                        field_of_pair(item).is_some_and(|field| is_projected(field, fields))
+                    })
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| project_fields(item, fields))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn is_record(value: &Value) -> bool {
+    matches!(value, Value::Map(_) | Value::Array(_))
+}
+
+/// Returns the field of a field and value pair, given as an array of two elements that aren't records.
+fn field_of_pair(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(pair) if pair.len() == 2 && !pair.iter().any(is_record) => Some(&pair[0]),
+        _ => None,
+    }
+}
+
+fn is_projected(field: &Value, fields: &[Vec<u8>]) -> bool {
+    let field = match field {
+        Value::BulkString(bytes) => bytes.as_slice(),
+        Value::SimpleString(text) => text.as_bytes(),
+        Value::VerbatimString { text, .. } => text.as_bytes(),
+        _ => return false,
+    };
+    fields.iter().any(|projected| projected == field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    fn pair(field: &str, value: &str) -> Value {
+        Value::Array(vec![bulk(field), bulk(value)])
+    }
+
+    #[test]
+    fn test_filter_map_of_fields() {
+        let filter = ResponseFilter {
+            fields: Some(vec![b"name".to_vec(), b"age".to_vec()]),
+            max_entries: None,
+        };
+        let hgetall = Value::Map(vec![
+            (bulk("name"), bulk("alice")),
+            (bulk("email"), bulk("alice@example.com")),
+            (bulk("age"), bulk("30")),
+        ]);
+        assert_eq!(
+            filter.apply(hgetall),
+            Value::Map(vec![
+                (bulk("name"), bulk("alice")),
+                (bulk("age"), bulk("30")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_records() {
+        let filter = ResponseFilter {
+            fields: Some(vec![b"temperature".to_vec()]),
+            max_entries: Some(2),
+        };
+        let xrange = Value::Map(vec![
+            (
+                bulk("1-0"),
+                Value::Array(vec![pair("temperature", "20"), pair("humidity", "40")]),
+            ),
+            (
+                bulk("2-0"),
+                Value::Array(vec![pair("humidity", "45"), pair("temperature", "21")]),
+            ),
+            (bulk("3-0"), Value::Array(vec![pair("temperature", "22")])),
+        ]);
+        assert_eq!(
+            filter.apply(xrange),
+            Value::Map(vec![
+                (bulk("1-0"), Value::Array(vec![pair("temperature", "20")])),
+                (bulk("2-0"), Value::Array(vec![pair("temperature", "21")])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_keeps_scalars() {
+        let filter = ResponseFilter {
+            fields: Some(vec![b"name".to_vec()]),
+            max_entries: Some(1),
+        };
+        assert_eq!(filter.apply(Value::Int(3)), Value::Int(3));
+        assert_eq!(filter.apply(Value::Nil), Value::Nil);
+        assert_eq!(
+            ResponseFilter::default().apply(Value::Array(vec![bulk("a"), bulk("b")])),
+            Value::Array(vec![bulk("a"), bulk("b")])
+        );
+    }
+}
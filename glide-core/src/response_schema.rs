@@ -0,0 +1,149 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Validation of batch responses against the response types the wrapper expects for each command, so a response
+//! whose shape changed, for example after a server upgrade, fails the batch with a
+//! [`crate::errors::RequestErrorType::ResponseSchemaMismatch`] error naming the command, instead of failing the
+//! wrapper's deserializer.
+
+use crate::client::get_value_type;
+use crate::errors::response_schema_mismatch_error;
+use redis::{ErrorKind, RedisError, RedisResult, Value};
+
+/// The response type expected for a command of a batch.
+///
+/// `nil` responses, and the server errors returned when the batch doesn't raise on errors, match every type.
+/// Wrappers pass the types by their discriminants, which are converted with [`ExpectedResponseType::try_from`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExpectedResponseType {
+    /// The response isn't validated.
+    #[default]
+    Any = 0,
+    Int = 1,
+    Double = 2,
+    Boolean = 3,
+    /// A bulk, simple or verbatim string, or `OK`.
+    String = 4,
+    Array = 5,
+    /// An array whose elements are all strings or `nil`.
+    ArrayOfStrings = 6,
+    Map = 7,
+    Set = 8,
+}
+
+fn is_string(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::BulkString(_) | Value::SimpleString(_) | Value::VerbatimString { .. } | Value::Okay
+    )
+}
+
+impl TryFrom<u32> for ExpectedResponseType {
+    type Error = RedisError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => ExpectedResponseType::Any,
+            1 => ExpectedResponseType::Int,
+            2 => ExpectedResponseType::Double,
+            3 => ExpectedResponseType::Boolean,
+            4 => ExpectedResponseType::String,
+            5 => ExpectedResponseType::Array,
+            6 => ExpectedResponseType::ArrayOfStrings,
+            7 => ExpectedResponseType::Map,
+            8 => ExpectedResponseType::Set,
+            value => {
+                return Err(RedisError::from((
+                    ErrorKind::ClientError,
+                    "Invalid expected response type",
+                    value.to_string(),
+                )));
+            }
+        })
+    }
+}
+
+impl ExpectedResponseType {
+    fn matches(self, value: &Value) -> bool {
+        if matches!(value, Value::Nil | Value::ServerError(_)) {
+            return true;
+        }
+        match self {
+            ExpectedResponseType::Any => true,
+            ExpectedResponseType::Int => matches!(value, Value::Int(_)),
+            ExpectedResponseType::Double => matches!(value, Value::Double(_)),
+            ExpectedResponseType::Boolean => matches!(value, Value::Boolean(_)),
+            ExpectedResponseType::String => is_string(value),
+            ExpectedResponseType::Array => matches!(value, Value::Array(_)),
+            ExpectedResponseType::ArrayOfStrings => match value {
+                Value::Array(values) => values
+                    .iter()
+                    .all(|value| matches!(value, Value::Nil) || is_string(value)),
+                _ => false,
+            },
+            ExpectedResponseType::Map => matches!(value, Value::Map(_)),
+            ExpectedResponseType::Set => matches!(value, Value::Set(_)),
+        }
+    }
+}
+
+/// Validates the responses of a batch against the expected type of each command, in order.
+/// The `nil` response of an aborted transaction isn't validated.
+pub fn validate_batch_response(
+    expected_types: &[ExpectedResponseType],
+    response: &Value,
+) -> RedisResult<()> {
+    let Value::Array(values) = response else {
+        return Ok(());
+    };
+    for (index, (expected_type, value)) in expected_types.iter().zip(values).enumerate() {
+        if !expected_type.matches(value) {
+            return Err(response_schema_mismatch_error(
+                index,
+                &format!("{expected_type:?}"),
+                get_value_type(value),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{RequestErrorType, error_type};
+
+    #[test]
+    fn test_validate_batch_response() {
+        let expected_types = [
+            ExpectedResponseType::Int,
+            ExpectedResponseType::ArrayOfStrings,
+            ExpectedResponseType::Any,
+            ExpectedResponseType::Map,
+        ];
+        let response = Value::Array(vec![
+            Value::Int(1),
+            Value::Array(vec![Value::BulkString(b"a".to_vec()), Value::Nil]),
+            Value::Double(1.5),
+            Value::Nil,
+        ]);
+        assert!(validate_batch_response(&expected_types, &response).is_ok());
+        // Aborted transactions aren't validated.
+        assert!(validate_batch_response(&expected_types, &Value::Nil).is_ok());
+
+        let response = Value::Array(vec![
+            Value::Int(1),
+            Value::Array(vec![Value::Int(2)]),
+            Value::Nil,
+            Value::Nil,
+        ]);
+        let err = validate_batch_response(&expected_types, &response).unwrap_err();
+        assert_eq!(error_type(&err), RequestErrorType::ResponseSchemaMismatch);
+        assert!(err.to_string().contains("command 1"), "{err}");
+
+        assert_eq!(
+            ExpectedResponseType::try_from(6).unwrap(),
+            ExpectedResponseType::ArrayOfStrings
+        );
+        assert!(ExpectedResponseType::try_from(9).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! A client wrapping the clients of several clusters, which dispatches each command to the cluster owning its keys,
+//! according to a table of key prefixes. It is used to shard tenants across clusters behind a single client.
+
+use crate::client::Client;
+use redis::cluster_routing::{RoutingInfo, command_keys};
+use redis::{Cmd, ErrorKind, RedisError, RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+const LOCK_ERR: &str = "Failed to acquire the routes lock";
+
+/// Routes the keys starting with `prefix` to the cluster named `cluster`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPrefixRoute {
+    pub prefix: Vec<u8>,
+    pub cluster: String,
+}
+
+/// Returns the cluster of the route with the longest prefix of `key`.
+fn longest_prefix_route<'a>(routes: &'a [KeyPrefixRoute], key: &[u8]) -> Option<&'a str> {
+    routes
+        .iter()
+        .filter(|route| key.starts_with(&route.prefix))
+        .max_by_key(|route| route.prefix.len())
+        .map(|route| route.cluster.as_str())
+}
+
+/// A client dispatching each command to one of several clusters. A key is routed to the cluster of the route with the
+/// longest prefix of the key, or to the default cluster if no route matches it. Commands without keys are sent to the
+/// default cluster.
+pub struct RoutedMultiClient {
+    clients: HashMap<String, Client>,
+    routes: RwLock<Vec<KeyPrefixRoute>>,
+    default_cluster: Option<String>,
+}
+
+impl RoutedMultiClient {
+    /// Creates a client dispatching the commands to `clients`, keyed by the cluster name, according to `routes`.
+    /// Fails if a route or `default_cluster` names a cluster without a client.
+    pub fn new(
+        clients: HashMap<String, Client>,
+        routes: Vec<KeyPrefixRoute>,
+        default_cluster: Option<String>,
+    ) -> RedisResult<Self> {
+        if let Some(cluster) = default_cluster
+            .as_ref()
+            .filter(|cluster| !clients.contains_key(*cluster))
+        {
+            return Err(unknown_cluster(cluster));
+        }
+        let client = Self {
+            clients,
+            routes: RwLock::new(Vec::new()),
+            default_cluster,
+        };
+        client.set_routes(routes)?;
+        Ok(client)
+    }
+
+    /// Replaces the routes. The commands that were already dispatched aren't affected.
+    pub fn set_routes(&self, routes: Vec<KeyPrefixRoute>) -> RedisResult<()> {
+        if let Some(route) = routes
+            .iter()
+            .find(|route| !self.clients.contains_key(&route.cluster))
+        {
+            return Err(unknown_cluster(&route.cluster));
+        }
+        *self.routes.write().expect(LOCK_ERR) = routes;
+        Ok(())
+    }
+
+    /// Returns the routes.
+    pub fn routes(&self) -> Vec<KeyPrefixRoute> {
+        self.routes.read().expect(LOCK_ERR).clone()
+    }
+
+    /// Returns the name of the cluster that `key` is routed to.
+    pub fn cluster_for_key(&self, key: &[u8]) -> Option<String> {
+        let routes = self.routes.read().expect(LOCK_ERR);
+        longest_prefix_route(&routes, key)
+            .or(self.default_cluster.as_deref())
+            .map(str::to_string)
+    }
+
+    /// Returns the name of the cluster that `cmd` is dispatched to. Fails if the keys of the command are routed to
+    /// different clusters, or if no cluster is found.
+    pub fn cluster_for_command(&self, cmd: &Cmd) -> RedisResult<String> {
+        let keys = command_keys(cmd);
+        let Some((first_key, other_keys)) = keys.split_first() else {
+            return self.default_cluster.clone().ok_or_else(|| {
+                RedisError::from((
+                    ErrorKind::ClientError,
+                    "The command has no keys, and no default cluster was configured",
+                ))
+            });
+        };
+        let cluster = self.cluster_for_key(first_key).ok_or_else(|| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "No route matches the key, and no default cluster was configured",
+                String::from_utf8_lossy(first_key).into_owned(),
+            ))
+        })?;
+        if other_keys
+            .iter()
+            .any(|key| self.cluster_for_key(key).as_ref() != Some(&cluster))
+        {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "The keys of the command are routed to different clusters",
+            )));
+        }
+        Ok(cluster)
+    }
+
+    /// Returns the client of the cluster named `cluster`.
+    pub fn client(&self, cluster: &str) -> Option<Client> {
+        self.clients.get(cluster).cloned()
+    }
+
+    /// Sends `cmd` to the cluster its keys are routed to. `routing` routes the command within that cluster.
+    pub async fn send_command(
+        &self,
+        cmd: &mut Cmd,
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Value> {
+        let cluster = self.cluster_for_command(cmd)?;
+        let mut client = self
+            .client(&cluster)
+            .ok_or_else(|| unknown_cluster(&cluster))?;
+        client.send_command(cmd, routing).await
+    }
+}
+
+fn unknown_cluster(cluster: &str) -> RedisError {
+    RedisError::from((
+        ErrorKind::ClientError,
+        "No client was given for the cluster",
+        cluster.to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(prefix: &str, cluster: &str) -> KeyPrefixRoute {
+        KeyPrefixRoute {
+            prefix: prefix.as_bytes().to_vec(),
+            cluster: cluster.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_longest_prefix_route() {
+        let routes = vec![
+            route("tenant:", "shared"),
+            route("tenant:acme:", "acme"),
+            route("", "fallback"),
+        ];
+
+        assert_eq!(
+            longest_prefix_route(&routes, b"tenant:acme:orders"),
+            Some("acme")
+        );
+        assert_eq!(
+            longest_prefix_route(&routes, b"tenant:other:orders"),
+            Some("shared")
+        );
+        assert_eq!(longest_prefix_route(&routes, b"session"), Some("fallback"));
+        assert_eq!(longest_prefix_route(&routes[..2], b"session"), None);
+    }
+}
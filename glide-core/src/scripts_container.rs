@@ -7,6 +7,7 @@ use sha1_smol::Sha1;
 use std::cell::Cell;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 const LOCK_ERR: &str = "Failed to acquire the scripts container lock";
 
@@ -22,10 +23,42 @@ struct ScriptEntry {
 static CONTAINER: Lazy<Mutex<HashMap<String, ScriptEntry>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
-pub fn add_script(script: &[u8]) -> String {
+/// The number of scripts whose invocation statistics are kept. Once reached, the statistics of the script invoked
+/// least recently are evicted to make room for a new script.
+const MAX_SCRIPT_STATISTICS: usize = 1024;
+
+/// Invocation statistics of the scripts, keyed by their hash.
+#[derive(Default)]
+struct StatisticsTable {
+    /// The statistics of each script, with the sequence number of its latest invocation.
+    scripts: HashMap<String, (ScriptStatistics, u64)>,
+    next_sequence: u64,
+}
+
+static STATISTICS: Lazy<Mutex<StatisticsTable>> =
+    Lazy::new(|| Mutex::new(StatisticsTable::default()));
+
+/// The invocations of a script that was run from its source, recorded since the statistics were last reset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptStatistics {
+    pub invocations: u64,
+    /// The invocations in which the script wasn't cached on the server, so its source was sent with `EVAL`.
+    pub eval_fallbacks: u64,
+    /// The invocations that returned an error.
+    pub errors: u64,
+    pub total_latency: Duration,
+    pub max_latency: Duration,
+}
+
+/// Returns the SHA1 hash of the script, which identifies it in `EVALSHA` and in this container.
+pub fn script_hash(script: &[u8]) -> String {
     let mut hash = Sha1::new();
     hash.update(script);
-    let hash = hash.digest().to_string();
+    hash.digest().to_string()
+}
+
+pub fn add_script(script: &[u8]) -> String {
+    let hash = script_hash(script);
     log_info(
         "script lifetime",
         format!("Added script with hash: `{hash}`"),
@@ -81,6 +114,53 @@ pub fn remove_script(hash: &str) {
     }
 }
 
+impl StatisticsTable {
+    fn record(&mut self, hash: &str, latency: Duration, eval_fallback: bool, failed: bool) {
+        if !self.scripts.contains_key(hash)
+            && self.scripts.len() >= MAX_SCRIPT_STATISTICS
+            && let Some(least_recent) = self
+                .scripts
+                .iter()
+                .min_by_key(|(_, (_, sequence))| *sequence)
+                .map(|(hash, _)| hash.clone())
+        {
+            self.scripts.remove(&least_recent);
+        }
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        let (entry, latest_invocation) = self.scripts.entry(hash.to_string()).or_default();
+        *latest_invocation = sequence;
+        entry.invocations += 1;
+        entry.eval_fallbacks += u64::from(eval_fallback);
+        entry.errors += u64::from(failed);
+        entry.total_latency += latency;
+        entry.max_latency = entry.max_latency.max(latency);
+    }
+}
+
+/// Records an invocation of the script with the given hash.
+pub fn record_invocation(hash: &str, latency: Duration, eval_fallback: bool, failed: bool) {
+    STATISTICS
+        .lock()
+        .expect(LOCK_ERR)
+        .record(hash, latency, eval_fallback, failed);
+}
+
+/// Returns the invocation statistics of the script with the given hash, if it was invoked.
+pub fn script_statistics(hash: &str) -> Option<ScriptStatistics> {
+    STATISTICS
+        .lock()
+        .expect(LOCK_ERR)
+        .scripts
+        .get(hash)
+        .map(|(statistics, _)| *statistics)
+}
+
+/// Removes the invocation statistics of all scripts.
+pub fn reset_script_statistics() {
+    STATISTICS.lock().expect(LOCK_ERR).scripts.clear();
+}
+
 #[cfg(test)]
 mod script_tests {
     use super::*;
@@ -112,6 +192,45 @@ mod script_tests {
         assert!(get_script(&hash).is_none());
     }
 
+    #[test]
+    fn test_record_invocations() {
+        let hash = script_hash(b"return 'statistics test'");
+        assert_eq!(script_statistics(&hash), None);
+
+        record_invocation(&hash, Duration::from_millis(3), true, false);
+        record_invocation(&hash, Duration::from_millis(1), false, true);
+
+        assert_eq!(
+            script_statistics(&hash),
+            Some(ScriptStatistics {
+                invocations: 2,
+                eval_fallbacks: 1,
+                errors: 1,
+                total_latency: Duration::from_millis(4),
+                max_latency: Duration::from_millis(3),
+            })
+        );
+    }
+
+    #[test]
+    fn test_statistics_of_the_least_recently_invoked_script_are_evicted() {
+        let mut table = StatisticsTable::default();
+        let hashes: Vec<String> = (0..=MAX_SCRIPT_STATISTICS)
+            .map(|index| index.to_string())
+            .collect();
+        for hash in &hashes[..MAX_SCRIPT_STATISTICS] {
+            table.record(hash, Duration::ZERO, false, false);
+        }
+        // The first script is invoked again, so the second one is the least recently invoked.
+        table.record(&hashes[0], Duration::ZERO, false, false);
+        table.record(&hashes[MAX_SCRIPT_STATISTICS], Duration::ZERO, false, false);
+
+        assert_eq!(table.scripts.len(), MAX_SCRIPT_STATISTICS);
+        assert_eq!(table.scripts[&hashes[0]].0.invocations, 2);
+        assert!(!table.scripts.contains_key(&hashes[1]));
+        assert!(table.scripts.contains_key(&hashes[MAX_SCRIPT_STATISTICS]));
+    }
+
     #[test]
     fn test_remove_non_existent_script() {
         let fake_hash = "nonexistenthash";
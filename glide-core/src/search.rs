@@ -0,0 +1,177 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Helpers for the vector search module (`FT.*` commands).
+
+use redis::{ErrorKind, RedisResult, Value};
+
+const TOTAL_RESULTS_KEY: &str = "total_results";
+const DOCUMENTS_KEY: &str = "documents";
+const DOCUMENT_KEY_KEY: &str = "key";
+const DOCUMENT_SCORE_KEY: &str = "score";
+const DOCUMENT_FIELDS_KEY: &str = "fields";
+
+/// Converts an `FT.SEARCH` reply, as returned by [`crate::client::Client::send_command`], into a structured map:
+///
+/// ```text
+/// total_results => (integer)
+/// documents =>
+///    1) key => "json:2"
+///       score => (double) 11.11
+///       fields => 1# "$" => "{\"vec\":[1.1,1.2]}"
+/// ```
+///
+/// The score of a document is taken from the `__<field>_score` field added by the server for vector queries,
+/// and is omitted if the server didn't return one.
+pub fn convert_ft_search_response(value: Value) -> RedisResult<Value> {
+    let Value::Array(mut items) = value else {
+        return Err(unexpected_response(&value));
+    };
+    if items.is_empty() {
+        return Err(unexpected_response(&Value::Array(items)));
+    }
+    let total_results = items.remove(0);
+    let documents = match items.pop() {
+        None => Vec::new(),
+        Some(Value::Map(documents)) => documents
+            .into_iter()
+            .map(|(key, fields)| convert_document(key, fields))
+            .collect::<RedisResult<_>>()?,
+        Some(other) => return Err(unexpected_response(&other)),
+    };
+
+    Ok(Value::Map(vec![
+        (Value::SimpleString(TOTAL_RESULTS_KEY.into()), total_results),
+        (
+            Value::SimpleString(DOCUMENTS_KEY.into()),
+            Value::Array(documents),
+        ),
+    ]))
+}
+
+fn convert_document(key: Value, fields: Value) -> RedisResult<Value> {
+    let fields = match fields {
+        Value::Map(fields) => fields,
+        Value::Nil => Vec::new(),
+        other => return Err(unexpected_response(&other)),
+    };
+
+    let mut score = None;
+    let mut remaining_fields = Vec::with_capacity(fields.len());
+    for (name, value) in fields {
+        if score.is_none() && is_score_field(&name) {
+            score = parse_score(&value);
+            if score.is_some() {
+                continue;
+            }
+        }
+        remaining_fields.push((name, value));
+    }
+
+    let mut document = vec![(Value::SimpleString(DOCUMENT_KEY_KEY.into()), key)];
+    if let Some(score) = score {
+        document.push((
+            Value::SimpleString(DOCUMENT_SCORE_KEY.into()),
+            Value::Double(score),
+        ));
+    }
+    document.push((
+        Value::SimpleString(DOCUMENT_FIELDS_KEY.into()),
+        Value::Map(remaining_fields),
+    ));
+    Ok(Value::Map(document))
+}
+
+fn is_score_field(name: &Value) -> bool {
+    match name {
+        Value::BulkString(bytes) => bytes.starts_with(b"__") && bytes.ends_with(b"_score"),
+        Value::SimpleString(text) => text.starts_with("__") && text.ends_with("_score"),
+        _ => false,
+    }
+}
+
+fn parse_score(value: &Value) -> Option<f64> {
+    match value {
+        Value::Double(score) => Some(*score),
+        Value::Int(score) => Some(*score as f64),
+        Value::BulkString(bytes) => std::str::from_utf8(bytes).ok()?.parse().ok(),
+        Value::SimpleString(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+fn unexpected_response(value: &Value) -> redis::RedisError {
+    (
+        ErrorKind::TypeError,
+        "Response couldn't be converted for FT.SEARCH",
+        format!("(response was {value:?})"),
+    )
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(text: &str) -> Value {
+        Value::BulkString(text.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_convert_ft_search_response_with_scores() {
+        let response = Value::Array(vec![
+            Value::Int(1),
+            Value::Map(vec![(
+                bulk("json:2"),
+                Value::Map(vec![
+                    (bulk("__VEC_score"), bulk("11.5")),
+                    (bulk("$"), bulk("{\"vec\":[1.1,1.2]}")),
+                ]),
+            )]),
+        ]);
+
+        let converted = convert_ft_search_response(response).unwrap();
+
+        assert_eq!(
+            converted,
+            Value::Map(vec![
+                (Value::SimpleString("total_results".into()), Value::Int(1)),
+                (
+                    Value::SimpleString("documents".into()),
+                    Value::Array(vec![Value::Map(vec![
+                        (Value::SimpleString("key".into()), bulk("json:2")),
+                        (Value::SimpleString("score".into()), Value::Double(11.5)),
+                        (
+                            Value::SimpleString("fields".into()),
+                            Value::Map(vec![(bulk("$"), bulk("{\"vec\":[1.1,1.2]}"))]),
+                        ),
+                    ])]),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_convert_ft_search_response_without_documents() {
+        let converted = convert_ft_search_response(Value::Array(vec![Value::Int(0)])).unwrap();
+
+        assert_eq!(
+            converted,
+            Value::Map(vec![
+                (Value::SimpleString("total_results".into()), Value::Int(0)),
+                (
+                    Value::SimpleString("documents".into()),
+                    Value::Array(vec![]),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_convert_ft_search_response_rejects_unexpected_values() {
+        assert!(convert_ft_search_response(Value::Nil).is_err());
+        assert!(convert_ft_search_response(Value::Array(vec![])).is_err());
+        assert!(
+            convert_ft_search_response(Value::Array(vec![Value::Int(1), Value::Int(2)])).is_err()
+        );
+    }
+}
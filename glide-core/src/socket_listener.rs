@@ -1,8 +1,8 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use super::rotating_buffer::RotatingBuffer;
-use crate::client::Client;
 use crate::client::get_or_init_runtime;
+use crate::client::{Client, deadline_from_unix_millis};
 use crate::compression::process_command_args_for_compression;
 
 use crate::cluster_scan_container::get_cluster_scan_cursor;
@@ -10,12 +10,13 @@ use crate::command_request::{
     Batch, ClusterScan, Command, CommandRequest, Routes, SlotTypes, command, command_request,
 };
 use crate::connection_request::ConnectionRequest;
-use crate::errors::{RequestErrorType, error_message, error_type};
+use crate::errors::{error_message, error_type};
 use crate::otel_db_semantics::{
     set_db_attributes, set_db_batch_attributes, set_db_script_attributes,
 };
 use crate::response;
 use crate::response::Response;
+use crate::response_encoding::to_protobuf_error_type;
 use ClosingReason::*;
 use PipeListeningResult::*;
 use bytes::Bytes;
@@ -39,6 +40,7 @@ use std::ptr::from_mut;
 use std::rc::Rc;
 use std::str;
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 use telemetrylib::{GlideSpan, GlideSpanStatus};
 use thiserror::Error;
 
@@ -248,13 +250,7 @@ async fn write_result(
                 span.set_status(GlideSpanStatus::Error((&error_message).into()));
             }
             let request_error = response::RequestError {
-                type_: match error_type(&err) {
-                    RequestErrorType::Unspecified => response::RequestErrorType::Unspecified,
-                    RequestErrorType::ExecAbort => response::RequestErrorType::ExecAbort,
-                    RequestErrorType::Timeout => response::RequestErrorType::Timeout,
-                    RequestErrorType::Disconnect => response::RequestErrorType::Disconnect,
-                }
-                .into(),
+                type_: to_protobuf_error_type(error_type(&err)).into(),
                 message: error_message.into(),
                 ..Default::default()
             };
@@ -331,6 +327,7 @@ async fn send_command(
     mut cmd: Cmd,
     mut client: Client,
     routing: Option<RoutingInfo>,
+    database_id: Option<i64>,
 ) -> ClientUsageResult<Value> {
     if let Some(ref span) = cmd.span() {
         set_db_attributes(span, &cmd, &client);
@@ -347,10 +344,15 @@ async fn send_command(
         );
     }
 
-    client
-        .send_command(&mut cmd, routing)
-        .await
-        .map_err(|err| err.into())
+    match database_id {
+        Some(database_id) => {
+            client
+                .send_command_to_database(&mut cmd, routing, database_id)
+                .await
+        }
+        None => client.send_command(&mut cmd, routing).await,
+    }
+    .map_err(|err| err.into())
 }
 
 /// Process a command for compression by extracting arguments and applying compression
@@ -574,6 +576,7 @@ async fn send_batch(
                 PipelineRetryStrategy {
                     retry_server_error: request.retry_server_error.unwrap_or_default(),
                     retry_connection_error: request.retry_connection_error.unwrap_or_default(),
+                    follow_ask_redirects: request.follow_ask_redirects.unwrap_or(true),
                 },
             )
             .await
@@ -667,6 +670,15 @@ fn get_route(
                 Ok(None)
             }
         },
+        Value::AzAffinityRoute(az_affinity_route) if az_affinity_route.az.is_empty() => Err(
+            ClientUsageError::User("AZ affinity routes require an availability zone".to_string()),
+        ),
+        Value::AzAffinityRoute(az_affinity_route) => Ok(Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::ReplicaInAZ {
+                slot: redis::cluster_topology::get_slot(az_affinity_route.slot_key.as_bytes()),
+                az: az_affinity_route.az.to_string(),
+            },
+        ))),
     }
 }
 
@@ -675,13 +687,24 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
         let mut updated_inflight_counter = true;
         let client_clone = client.clone();
 
-        let result = match client.reserve_inflight_request() {
+        let priority = request.priority.enum_value_or_default().into();
+        let result = match client.reserve_inflight_request_with_priority(priority) {
             false => {
                 updated_inflight_counter = false;
                 Err(ClientUsageError::User(
                     "Reached maximum inflight requests".to_string(),
                 ))
             }
+            true if request.database_id.is_some()
+                && !matches!(
+                    request.command,
+                    Some(command_request::Command::SingleCommand(_))
+                ) =>
+            {
+                Err(ClientUsageError::User(
+                    "Database override is only supported for single commands".to_string(),
+                ))
+            }
             true => match request.command {
                 Some(action) => match action {
                     command_request::Command::ClusterScan(cluster_scan_command) => {
@@ -693,7 +716,42 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                             Ok(mut cmd) => match get_route(request.route.0, Some(&cmd)) {
                                 Ok(routes) => {
                                     cmd.set_span(get_unsafe_span_from_ptr(request.root_span_ptr));
-                                    send_command(cmd, client, routes).await
+                                    cmd.set_deadline(
+                                        request.deadline_unix_ms.map(deadline_from_unix_millis),
+                                    );
+                                    let start = Instant::now();
+                                    let normalize_topology = command.normalize_cluster_topology
+                                        && crate::cluster_topology::is_cluster_topology_command(
+                                            &cmd,
+                                        );
+                                    let convert_geo = command.structured_geo_response
+                                        && crate::geo_search::is_geo_search_command(&cmd);
+                                    let response_cmd =
+                                        (normalize_topology || convert_geo).then(|| cmd.clone());
+                                    let result =
+                                        send_command(cmd, client, routes, request.database_id)
+                                            .await;
+                                    crate::command_latency::record(
+                                        command.request_type.into(),
+                                        start.elapsed(),
+                                    );
+                                    match response_cmd {
+                                        Some(cmd) if normalize_topology => {
+                                            result.and_then(|value| {
+                                                crate::cluster_topology::normalize_cluster_topology(
+                                                    &cmd, value,
+                                                )
+                                                .map_err(|err| err.into())
+                                            })
+                                        }
+                                        Some(cmd) => result.and_then(|value| {
+                                            crate::geo_search::convert_geo_search_response(
+                                                &cmd, value,
+                                            )
+                                            .map_err(|err| err.into())
+                                        }),
+                                        None => result,
+                                    }
                                 }
                                 Err(e) => Err(e),
                             },
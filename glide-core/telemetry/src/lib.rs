@@ -32,6 +32,20 @@ pub struct Telemetry {
     subscription_out_of_sync_count: usize,
     /// Unix timestamp (in milliseconds) of the last time subscriptions were in sync
     subscription_last_sync_timestamp: u64,
+    /// Number of replica reads that failed on a stale replica and were retried on the primary
+    primary_fallback_reads_count: usize,
+    /// Number of times a node circuit breaker was opened
+    circuit_breaker_opened_count: usize,
+    /// Number of requests that failed fast because the circuit breaker of their node was open
+    circuit_breaker_rejected_count: usize,
+    /// Number of pubsub messages dropped because the pubsub buffer of their client was full
+    pubsub_messages_dropped_count: usize,
+    /// Number of cluster scan cursors reaped because they weren't used within their TTL
+    cluster_scan_cursors_reaped_count: usize,
+    /// Number of connections recycled because they reached the maximum connection age
+    connections_recycled_max_age_count: usize,
+    /// Number of connections recycled because they weren't used within the idle timeout
+    connections_recycled_idle_count: usize,
 }
 
 lazy_static! {
@@ -202,6 +216,120 @@ impl Telemetry {
             .subscription_last_sync_timestamp
     }
 
+    /// Increment the number of replica reads retried on the primary
+    /// Return the new count after increment
+    pub fn incr_primary_fallback_reads() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.primary_fallback_reads_count = t.primary_fallback_reads_count.saturating_add(1);
+        t.primary_fallback_reads_count
+    }
+
+    /// Get the number of replica reads retried on the primary
+    pub fn primary_fallback_reads_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .primary_fallback_reads_count
+    }
+
+    /// Increment the number of times a node circuit breaker was opened
+    /// Return the new count after increment
+    pub fn incr_circuit_breaker_opened() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.circuit_breaker_opened_count = t.circuit_breaker_opened_count.saturating_add(1);
+        t.circuit_breaker_opened_count
+    }
+
+    /// Get the number of times a node circuit breaker was opened
+    pub fn circuit_breaker_opened_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .circuit_breaker_opened_count
+    }
+
+    /// Increment the number of requests rejected by an open circuit breaker
+    /// Return the new count after increment
+    pub fn incr_circuit_breaker_rejected() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.circuit_breaker_rejected_count = t.circuit_breaker_rejected_count.saturating_add(1);
+        t.circuit_breaker_rejected_count
+    }
+
+    /// Get the number of requests rejected by an open circuit breaker
+    pub fn circuit_breaker_rejected_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .circuit_breaker_rejected_count
+    }
+
+    /// Increment the number of pubsub messages dropped by a full pubsub buffer
+    /// Return the new count after increment
+    pub fn incr_pubsub_messages_dropped() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.pubsub_messages_dropped_count = t.pubsub_messages_dropped_count.saturating_add(1);
+        t.pubsub_messages_dropped_count
+    }
+
+    /// Get the number of pubsub messages dropped by a full pubsub buffer
+    pub fn pubsub_messages_dropped_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .pubsub_messages_dropped_count
+    }
+
+    /// Increase the number of cluster scan cursors reaped because they weren't used within their TTL
+    /// Return the new count after increment
+    pub fn incr_cluster_scan_cursors_reaped(count: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.cluster_scan_cursors_reaped_count =
+            t.cluster_scan_cursors_reaped_count.saturating_add(count);
+        t.cluster_scan_cursors_reaped_count
+    }
+
+    /// Get the number of cluster scan cursors reaped because they weren't used within their TTL
+    pub fn cluster_scan_cursors_reaped_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .cluster_scan_cursors_reaped_count
+    }
+
+    /// Increment the number of connections recycled because they reached the maximum connection age
+    /// Return the new count after increment
+    pub fn incr_connections_recycled_max_age() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.connections_recycled_max_age_count =
+            t.connections_recycled_max_age_count.saturating_add(1);
+        t.connections_recycled_max_age_count
+    }
+
+    /// Get the number of connections recycled because they reached the maximum connection age
+    pub fn connections_recycled_max_age_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .connections_recycled_max_age_count
+    }
+
+    /// Increment the number of connections recycled because they weren't used within the idle timeout
+    /// Return the new count after increment
+    pub fn incr_connections_recycled_idle() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.connections_recycled_idle_count = t.connections_recycled_idle_count.saturating_add(1);
+        t.connections_recycled_idle_count
+    }
+
+    /// Get the number of connections recycled because they weren't used within the idle timeout
+    pub fn connections_recycled_idle_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .connections_recycled_idle_count
+    }
+
     /// Reset the telemetry collected thus far
     pub fn reset() {
         *TELEMETRY.write().expect(MUTEX_WRITE_ERR) = Telemetry::default();
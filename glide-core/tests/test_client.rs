@@ -1660,6 +1660,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -1724,6 +1725,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await;
@@ -1798,6 +1800,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -1858,6 +1861,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await;
@@ -1905,6 +1909,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2003,6 +2008,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2062,6 +2068,7 @@ pub(crate) mod shared_client_tests {
                         PipelineRetryStrategy {
                             retry_server_error: true,
                             retry_connection_error: false,
+                            follow_ask_redirects: true,
                         },
                     )
                     .await
@@ -2155,6 +2162,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2197,6 +2205,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await;
@@ -2245,6 +2254,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2289,6 +2299,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2339,6 +2350,7 @@ pub(crate) mod shared_client_tests {
                     PipelineRetryStrategy {
                         retry_server_error: true,
                         retry_connection_error: false,
+                        follow_ask_redirects: true,
                     },
                 )
                 .await
@@ -2396,6 +2408,7 @@ pub(crate) mod shared_client_tests {
                         PipelineRetryStrategy {
                             retry_server_error: false,
                             retry_connection_error: false,
+                            follow_ask_redirects: true,
                         },
                     )
                     .await
@@ -3131,4 +3144,209 @@ pub(crate) mod shared_client_tests {
             }
         });
     }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_blocking_command_runs_against_the_selected_database(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            if use_cluster
+                && !utilities::version_greater_or_equal(&mut test_basics.client, "9.0.0").await
+            {
+                return;
+            }
+
+            let list = generate_random_string(10);
+            let mut blpop = redis::cmd("BLPOP");
+            blpop.arg(&list).arg(0.1);
+            // Leaves an idle blocking connection to the database the client started with.
+            assert_eq!(
+                test_basics
+                    .client
+                    .send_blocking_command(&mut blpop, None)
+                    .await
+                    .unwrap(),
+                Value::Nil
+            );
+
+            let mut select_cmd = redis::cmd("SELECT");
+            select_cmd.arg(5);
+            assert_eq!(
+                test_basics
+                    .client
+                    .send_command(&mut select_cmd, None)
+                    .await
+                    .unwrap(),
+                Value::Okay
+            );
+            let mut rpush = redis::cmd("RPUSH");
+            rpush.arg(&list).arg("element");
+            test_basics
+                .client
+                .send_command(&mut rpush, None)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                test_basics
+                    .client
+                    .send_blocking_command(&mut blpop, None)
+                    .await
+                    .unwrap(),
+                Value::Array(vec![
+                    Value::BulkString(list.into_bytes()),
+                    Value::BulkString(b"element".to_vec()),
+                ])
+            );
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_blocking_command_after_password_update(#[values(false, true)] use_cluster: bool) {
+        const NEW_PASSWORD: &str = "AnotherSecurePassword";
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    connection_info: Some(redis::RedisConnectionInfo {
+                        password: Some("ReallySecurePassword".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+            let mut blpop = redis::cmd("BLPOP");
+            blpop.arg(generate_random_string(10)).arg(0.1);
+            assert_eq!(
+                test_basics
+                    .client
+                    .send_blocking_command(&mut blpop, None)
+                    .await
+                    .unwrap(),
+                Value::Nil
+            );
+
+            // Replace the password on the server. The existing connections stay authenticated.
+            let mut acl_cmd = redis::cmd("ACL");
+            acl_cmd
+                .arg("SETUSER")
+                .arg("default")
+                .arg("resetpass")
+                .arg(format!(">{NEW_PASSWORD}"));
+            test_basics
+                .client
+                .send_command(
+                    &mut acl_cmd,
+                    Some(RoutingInfo::MultiNode((
+                        MultipleNodeRoutingInfo::AllNodes,
+                        Some(redis::cluster_routing::ResponsePolicy::AllSucceeded),
+                    ))),
+                )
+                .await
+                .unwrap();
+            test_basics
+                .client
+                .update_connection_password(Some(NEW_PASSWORD.to_string()), false)
+                .await
+                .unwrap();
+
+            // The blocking connections opened from now on authenticate with the new password.
+            let mut blocking_commands = Vec::new();
+            for _ in 0..2 {
+                let mut client = test_basics.client.clone();
+                let mut blpop = blpop.clone();
+                blocking_commands.push(tokio::spawn(async move {
+                    client.send_blocking_command(&mut blpop, None).await
+                }));
+            }
+            for blocking_command in blocking_commands {
+                assert_eq!(blocking_command.await.unwrap().unwrap(), Value::Nil);
+            }
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_watched_transaction_runs_against_the_selected_database(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            if use_cluster
+                && !utilities::version_greater_or_equal(&mut test_basics.client, "9.0.0").await
+            {
+                return;
+            }
+
+            let key = generate_random_string(10);
+            // Connects the watch connection to the database the client started with.
+            let token = test_basics
+                .client
+                .watch(&[key.clone().into_bytes()])
+                .await
+                .unwrap();
+            let Value::Int(token) = token else {
+                panic!("Unexpected watch token: {token:?}");
+            };
+            test_basics.client.unwatch(token as u64).await.unwrap();
+
+            let mut select_cmd = redis::cmd("SELECT");
+            select_cmd.arg(5);
+            assert_eq!(
+                test_basics
+                    .client
+                    .send_command(&mut select_cmd, None)
+                    .await
+                    .unwrap(),
+                Value::Okay
+            );
+            let mut set_cmd = redis::cmd("SET");
+            set_cmd.arg(&key).arg("value");
+            test_basics
+                .client
+                .send_command(&mut set_cmd, None)
+                .await
+                .unwrap();
+
+            let mut pipeline = redis::pipe();
+            pipeline.atomic().cmd("GET").arg(&key);
+            assert_eq!(
+                test_basics
+                    .client
+                    .execute_transaction_with_watch(
+                        None,
+                        &[key.clone().into_bytes()],
+                        &pipeline,
+                        None,
+                        None,
+                        true,
+                    )
+                    .await
+                    .unwrap(),
+                Value::Array(vec![Value::BulkString(b"value".to_vec())])
+            );
+        });
+    }
 }
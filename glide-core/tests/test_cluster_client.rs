@@ -16,14 +16,14 @@ mod cluster_client_tests {
         *,
     };
     use glide_core::{
-        client::Client,
+        client::{Client, FlushMode},
         connection_request::{
             self, ProtocolVersion as GlideProtocolVersion, PubSubChannelsOrPatterns,
             PubSubSubscriptions, ReadFrom,
         },
     };
     use redis::{
-        InfoDict, RedisConnectionInfo, Value,
+        InfoDict, Pipeline, PipelineRetryStrategy, RedisConnectionInfo, Value,
         cluster_routing::{
             MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
         },
@@ -824,4 +824,162 @@ mod cluster_client_tests {
             }
         });
     }
+
+    #[rstest]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_ping_all_nodes_reports_the_latency_of_every_node() {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics_internal(TestConfiguration {
+                cluster_mode: ClusterMode::Enabled,
+                shared_server: true,
+                ..Default::default()
+            })
+            .await;
+
+            let Value::Map(latencies) = test_basics.client.ping_all_nodes().await.unwrap() else {
+                panic!("Expected a map of the nodes to their latency");
+            };
+            // The shared cluster has 3 primaries with a replica each.
+            assert_eq!(latencies.len(), 6);
+            for (address, latency) in latencies {
+                assert!(matches!(address, Value::BulkString(_)));
+                assert!(
+                    matches!(latency, Value::Int(micros) if micros >= 0),
+                    "Expected a latency, got {latency:?}"
+                );
+            }
+        });
+    }
+
+    #[rstest]
+    #[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+    fn test_per_node_helpers_report_an_unresponsive_node_with_the_others() {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics_internal(TestConfiguration {
+                cluster_mode: ClusterMode::Enabled,
+                shared_server: false,
+                request_timeout: Some(500),
+                ..Default::default()
+            })
+            .await;
+
+            // Pause the clients of the primary of slot 0, so it doesn't respond within the request timeout.
+            let mut pause_cmd = redis::cmd("CLIENT");
+            pause_cmd.arg("PAUSE").arg(3000);
+            test_basics
+                .client
+                .send_command(
+                    &mut pause_cmd,
+                    Some(RoutingInfo::SingleNode(
+                        SingleNodeRoutingInfo::SpecificNode(Route::new(0, SlotAddr::Master)),
+                    )),
+                )
+                .await
+                .unwrap();
+
+            let Value::Map(latencies) = test_basics.client.ping_all_nodes().await.unwrap() else {
+                panic!("Expected a map of the nodes to their latency");
+            };
+            assert_eq!(latencies.len(), 3);
+            let unresponsive = latencies
+                .iter()
+                .filter(|(_, latency)| matches!(latency, Value::SimpleString(_)))
+                .count();
+            assert_eq!(unresponsive, 1);
+
+            let report = test_basics
+                .client
+                .flush_cluster(true, FlushMode::Default)
+                .await
+                .unwrap();
+            let Value::Map(report) = report else {
+                panic!("Expected a flush report, got {report:?}");
+            };
+            let section = |name: &str| {
+                report
+                    .iter()
+                    .find(|(key, _)| *key == Value::SimpleString(name.to_string()))
+                    .map(|(_, value)| value.clone())
+                    .unwrap()
+            };
+            assert!(matches!(section("succeeded"), Value::Array(nodes) if nodes.len() == 2));
+            assert!(matches!(section("failed"), Value::Map(nodes) if nodes.len() == 1));
+        });
+    }
+
+    #[rstest]
+    #[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+    fn test_read_your_writes_reads_every_written_slot_from_its_primary() {
+        block_on_all(async {
+            let test_basics = setup_cluster_with_replicas(
+                TestConfiguration {
+                    cluster_mode: ClusterMode::Enabled,
+                    shared_server: false,
+                    ..Default::default()
+                },
+                1,
+                3,
+            )
+            .await;
+            let cluster = test_basics.cluster.unwrap();
+            let mut connection_request = create_connection_request(
+                &cluster.get_server_addresses(),
+                &TestConfiguration {
+                    cluster_mode: ClusterMode::Enabled,
+                    read_from: Some(ReadFrom::PreferReplica),
+                    ..Default::default()
+                },
+            );
+            // Each primary has a single replica, so no write is acknowledged by enough replicas.
+            let mut read_your_writes = connection_request::ReadYourWritesConfig::new();
+            read_your_writes.num_replicas = 2;
+            read_your_writes.timeout_ms = 50;
+            connection_request.read_your_writes = protobuf::MessageField::some(read_your_writes);
+            let mut client = Client::new(connection_request.into(), None).await.unwrap();
+
+            // The hash tags put the keys in different slots.
+            let keys: Vec<String> = (0..6).map(|index| format!("{{rw-{index}}}key")).collect();
+            let get = |key: &str| {
+                let mut cmd = redis::cmd("GET");
+                cmd.arg(key);
+                cmd
+            };
+
+            client.send_command(&mut get(&keys[0]), None).await.unwrap();
+            assert_eq!(client.served_read_nodes(usize::MAX).len(), 1);
+
+            let mut mset = redis::cmd("MSET");
+            mset.arg(&keys[1]).arg("value").arg(&keys[2]).arg("value");
+            client.send_command(&mut mset, None).await.unwrap();
+
+            let retry_strategy = PipelineRetryStrategy {
+                retry_server_error: false,
+                retry_connection_error: false,
+                follow_ask_redirects: true,
+            };
+            let mut pipeline = Pipeline::new();
+            pipeline.set(&keys[3], "value").del(&keys[4]);
+            client
+                .send_pipeline(&pipeline, None, true, None, retry_strategy)
+                .await
+                .unwrap();
+
+            let mut transaction = Pipeline::new();
+            transaction.atomic().set(&keys[5], "value");
+            client
+                .send_transaction(&transaction, None, None, true)
+                .await
+                .unwrap();
+
+            // The reads of the written slots are sent to the primaries, which aren't recorded as served reads.
+            for key in &keys[1..] {
+                client.send_command(&mut get(key), None).await.unwrap();
+            }
+            assert_eq!(client.served_read_nodes(usize::MAX).len(), 1);
+
+            // The slots that weren't written are still read from the replicas.
+            client.send_command(&mut get(&keys[0]), None).await.unwrap();
+            assert_eq!(client.served_read_nodes(usize::MAX).len(), 2);
+        });
+    }
 }
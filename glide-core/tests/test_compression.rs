@@ -652,6 +652,29 @@ mod compression_tests {
         assert!(manager.is_enabled());
     }
 
+    #[test]
+    fn test_compression_manager_statistics() {
+        use glide_core::compression::zstd_backend::ZstdBackend;
+
+        let backend = Box::new(ZstdBackend::new());
+        let config = CompressionConfig::new(CompressionBackendType::Zstd);
+        let manager = CompressionManager::new(backend, config).unwrap();
+        assert_eq!(manager.statistics(), CompressionStatistics::default());
+
+        let large_data = "A".repeat(1024);
+        let compressed = manager.compress_value(large_data.as_bytes()).into_owned();
+        // Values below the threshold are skipped and not counted
+        manager.compress_value(b"small");
+        manager.decompress_value(&compressed).unwrap();
+
+        let statistics = manager.statistics();
+        assert_eq!(statistics.values_compressed, 1);
+        assert_eq!(statistics.values_decompressed, 1);
+        assert_eq!(statistics.original_bytes, 1024);
+        assert_eq!(statistics.compressed_bytes, compressed.len() as u64);
+        assert_eq!(statistics.bytes_saved(), 1024 - compressed.len() as u64);
+    }
+
     #[test]
     fn test_compression_manager_decompress_scenarios() {
         use glide_core::compression::zstd_backend::ZstdBackend;
@@ -760,4 +783,22 @@ mod compression_tests {
         let result = manager.try_decompress_value(&unsupported_data);
         assert_eq!(result, unsupported_data);
     }
+
+    #[test]
+    fn test_decompress_payload() {
+        use glide_core::compression::lz4_backend::Lz4Backend;
+        use glide_core::compression::zstd_backend::ZstdBackend;
+
+        // A serialized protobuf message is returned as is.
+        let payload = b"\x0a\x09localhost".repeat(100);
+        assert_eq!(decompress_payload(&payload).unwrap(), payload.as_slice());
+
+        let compressed = Lz4Backend::new().compress(&payload, None).unwrap();
+        assert!(compressed.len() < payload.len());
+        assert_eq!(decompress_payload(&compressed).unwrap(), payload.as_slice());
+
+        // Payloads are only compressed with LZ4.
+        let zstd_compressed = ZstdBackend::new().compress(&payload, None).unwrap();
+        assert!(decompress_payload(&zstd_compressed).is_err());
+    }
 }
@@ -675,3 +675,232 @@ fn test_all_subscription_types_survive_failover() {
         );
     });
 }
+
+#[rstest]
+#[serial_test::serial]
+#[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+fn test_binary_subscriptions_survive_slot_migration() {
+    block_on_all(async {
+        let cluster = RedisCluster::new(false, &None, Some(3), Some(0));
+        let addresses = cluster.get_server_addresses();
+        let mut setup = PubSubTestSetup::new(&addresses).await;
+
+        skip_if_version_below!(setup, "7.0.0");
+
+        let topology = ClusterTopology::from_connection(&mut setup.connection).await;
+        // Names that aren't valid UTF-8, which must be kept byte for byte when resubscribing.
+        let exact_channel = b"{binary-test}\xff\xfe\x00exact".to_vec();
+        let sharded_channel = b"{binary-test}\xc3\x28sharded".to_vec();
+        assert!(String::from_utf8(exact_channel.clone()).is_err());
+        assert!(String::from_utf8(sharded_channel.clone()).is_err());
+
+        let slot = redis::cluster_topology::get_slot(&exact_channel);
+
+        let exact_sub = subscribe_and_wait(
+            &setup.synchronizer,
+            std::slice::from_ref(&exact_channel),
+            PubSubSubscriptionKind::Exact,
+            SUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        let sharded_sub = subscribe_and_wait(
+            &setup.synchronizer,
+            std::slice::from_ref(&sharded_channel),
+            PubSubSubscriptionKind::Sharded,
+            SUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        assert!(exact_sub, "Exact subscription should be established");
+        assert!(sharded_sub, "Sharded subscription should be established");
+
+        let subs_before = setup.get_subscriptions_by_address();
+
+        let migrated =
+            migrate_channel_to_different_node(&mut setup.connection, &topology, slot).await;
+        assert!(
+            migrated.is_some(),
+            "Should have migrated to a different node"
+        );
+
+        // small sleep to allow for the synchronizer handle_topology to start and unsubscribe
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let exact_resub = wait_for_pubsub_state(
+            &setup.synchronizer,
+            PubSubSubscriptionKind::Exact,
+            &HashSet::from([exact_channel.clone()]),
+            true,
+            RESUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        let sharded_resub = wait_for_pubsub_state(
+            &setup.synchronizer,
+            PubSubSubscriptionKind::Sharded,
+            &HashSet::from([sharded_channel.clone()]),
+            true,
+            RESUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        assert!(exact_resub, "Exact subscription should be re-established");
+        assert!(
+            sharded_resub,
+            "Sharded subscription should be re-established"
+        );
+
+        let subs_after = setup.get_subscriptions_by_address();
+        let (exact_changed, _, exact_not_found) = verify_subscription_addresses_changed(
+            &subs_before,
+            &subs_after,
+            std::slice::from_ref(&exact_channel),
+            PubSubSubscriptionKind::Exact,
+        );
+        let (sharded_changed, _, sharded_not_found) = verify_subscription_addresses_changed(
+            &subs_before,
+            &subs_after,
+            std::slice::from_ref(&sharded_channel),
+            PubSubSubscriptionKind::Sharded,
+        );
+        assert_eq!(exact_not_found, 0, "Exact subscription should be found");
+        assert_eq!(sharded_not_found, 0, "Sharded subscription should be found");
+        assert_eq!(exact_changed, 1, "Exact subscription should have moved");
+        assert_eq!(sharded_changed, 1, "Sharded subscription should have moved");
+    });
+}
+
+/// Waits until the pattern is subscribed on exactly the primaries of the topology.
+async fn wait_for_pattern_on_primaries(
+    setup: &PubSubTestSetup,
+    pattern: &[u8],
+    topology: &ClusterTopology,
+    timeout: Duration,
+) -> bool {
+    let primaries: HashSet<String> = topology
+        .primary_nodes
+        .iter()
+        .map(|node| format!("{}:{}", node.host, node.port))
+        .collect();
+    let start = std::time::Instant::now();
+    while start.elapsed() < timeout {
+        let subscribed_on: HashSet<String> = setup
+            .get_subscriptions_by_address()
+            .into_iter()
+            .filter(|(_, subs)| {
+                subs.get(&PubSubSubscriptionKind::Pattern)
+                    .is_some_and(|patterns| patterns.contains(pattern))
+            })
+            .map(|(address, _)| address)
+            .collect();
+        if subscribed_on == primaries {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    false
+}
+
+#[rstest]
+#[serial_test::serial]
+#[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+fn test_keyspace_patterns_are_subscribed_on_every_primary() {
+    block_on_all(async {
+        let cluster = RedisCluster::new(false, &None, Some(3), Some(0));
+        let addresses = cluster.get_server_addresses();
+        let mut setup = PubSubTestSetup::new(&addresses).await;
+
+        let topology = ClusterTopology::from_connection(&mut setup.connection).await;
+        let keyspace_pattern = b"__keyspace@*__:user:*".to_vec();
+        let regular_pattern = b"news.*".to_vec();
+
+        let subscribed = subscribe_and_wait(
+            &setup.synchronizer,
+            &[keyspace_pattern.clone(), regular_pattern.clone()],
+            PubSubSubscriptionKind::Pattern,
+            SUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        assert!(subscribed, "Pattern subscriptions should be established");
+        assert!(
+            wait_for_pattern_on_primaries(
+                &setup,
+                &keyspace_pattern,
+                &topology,
+                SUBSCRIPTION_TIMEOUT
+            )
+            .await,
+            "Keyspace pattern should be subscribed on every primary"
+        );
+
+        // Regular patterns are still subscribed on a single node
+        let regular_addresses = setup
+            .get_subscriptions_by_address()
+            .values()
+            .filter(|subs| {
+                subs.get(&PubSubSubscriptionKind::Pattern)
+                    .is_some_and(|patterns| patterns.contains(&regular_pattern))
+            })
+            .count();
+        assert_eq!(regular_addresses, 1);
+    });
+}
+
+#[rstest]
+#[serial_test::serial]
+#[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+fn test_keyspace_patterns_follow_the_primaries_through_failover() {
+    block_on_all(async {
+        let cluster = RedisCluster::new(false, &None, Some(3), Some(1));
+        let addresses = cluster.get_server_addresses();
+        let mut setup = PubSubTestSetup::new(&addresses).await;
+
+        let topology = ClusterTopology::from_connection(&mut setup.connection).await;
+        let keyspace_pattern = b"__keyevent@*__:*".to_vec();
+
+        subscribe_and_wait(
+            &setup.synchronizer,
+            std::slice::from_ref(&keyspace_pattern),
+            PubSubSubscriptionKind::Pattern,
+            SUBSCRIPTION_TIMEOUT,
+        )
+        .await;
+        assert!(
+            wait_for_pattern_on_primaries(
+                &setup,
+                &keyspace_pattern,
+                &topology,
+                SUBSCRIPTION_TIMEOUT
+            )
+            .await,
+            "Keyspace pattern should be subscribed on every primary"
+        );
+
+        let primary = topology
+            .find_primary_with_replica()
+            .expect("Should find a primary with a replica");
+        let replica = topology.find_replicas_of(&primary.node_id)[0];
+        let failover_initiated = trigger_failover(&mut setup.connection, replica).await;
+        assert!(failover_initiated, "Failover should be initiated");
+        let became_primary = wait_for_node_to_become_primary(
+            &mut setup.connection,
+            &replica.node_id,
+            Duration::from_secs(30),
+        )
+        .await;
+        assert!(
+            became_primary,
+            "Replica should become primary after failover"
+        );
+
+        // The pattern moves from the demoted primary to the promoted replica
+        let new_topology = ClusterTopology::from_connection(&mut setup.connection).await;
+        assert!(
+            wait_for_pattern_on_primaries(
+                &setup,
+                &keyspace_pattern,
+                &new_topology,
+                RESUBSCRIPTION_TIMEOUT
+            )
+            .await,
+            "Keyspace pattern should follow the primaries after failover"
+        );
+    });
+}
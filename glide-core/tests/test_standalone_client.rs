@@ -389,6 +389,52 @@ mod standalone_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_send_command_to_address_keeps_the_reads_on_one_replica() {
+        let servers = create_primary_mock_with_replicas(2);
+        let mut cmd = redis::cmd("SCAN");
+        cmd.arg("0");
+        for server in servers.iter().skip(1) {
+            for _ in 0..3 {
+                server.add_response(&cmd, "*2\r\n$1\r\n0\r\n*0\r\n".to_string());
+            }
+        }
+        let mut connection_request =
+            create_connection_request(get_mock_addresses(&servers).as_slice(), &Default::default());
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+            let address = client.read_node_address().await;
+            for _ in 0..3 {
+                client
+                    .send_command_to_address(&cmd, &address)
+                    .await
+                    .unwrap();
+            }
+            assert!(
+                client
+                    .send_command_to_address(&cmd, "192.0.2.1:6379")
+                    .await
+                    .is_err()
+            );
+        });
+
+        assert_eq!(servers[0].get_number_of_received_commands(), 0);
+        let mut replica_reads: Vec<_> = servers
+            .iter()
+            .skip(1)
+            .map(|mock| mock.get_number_of_received_commands())
+            .collect();
+        replica_reads.sort();
+        assert_eq!(replica_reads, vec![0, 3]);
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
@@ -1321,4 +1367,197 @@ mod standalone_client_tests {
             );
         });
     }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(LONG_STANDALONE_TEST_TIMEOUT)]
+    fn test_send_command_to_database_keeps_the_database_of_the_client() {
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                shared_server: true,
+                ..Default::default()
+            };
+            let connection_request =
+                create_connection_request(&[get_shared_server_address(false)], &configuration);
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .unwrap();
+            let key = generate_random_string(10);
+
+            let mut set_cmd = redis::cmd("SET");
+            set_cmd.arg(&key).arg("value");
+            assert_eq!(
+                client
+                    .send_command_to_database(&mut set_cmd, None, 1)
+                    .await
+                    .unwrap(),
+                Value::Okay
+            );
+            let mut get_cmd = redis::cmd("GET");
+            get_cmd.arg(&key);
+            assert_eq!(
+                client
+                    .send_command_to_database(&mut get_cmd, None, 1)
+                    .await
+                    .unwrap(),
+                Value::BulkString(b"value".to_vec())
+            );
+            assert_eq!(
+                client.send_command(&mut get_cmd, None).await.unwrap(),
+                Value::Nil
+            );
+
+            // A blocking command of the database doesn't stall the other commands of the database.
+            let list = generate_random_string(10);
+            let blocking = tokio::spawn({
+                let mut client = client.clone();
+                let list = list.clone();
+                async move {
+                    let mut blpop = redis::cmd("BLPOP");
+                    blpop.arg(&list).arg(5);
+                    client.send_command_to_database(&mut blpop, None, 1).await
+                }
+            });
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            let mut rpush = redis::cmd("RPUSH");
+            rpush.arg(&list).arg("element");
+            client
+                .send_command_to_database(&mut rpush, None, 1)
+                .await
+                .unwrap();
+            assert_eq!(
+                blocking.await.unwrap().unwrap(),
+                Value::Array(vec![
+                    Value::BulkString(list.into_bytes()),
+                    Value::BulkString(b"element".to_vec()),
+                ])
+            );
+
+            let mut del_cmd = redis::cmd("DEL");
+            del_cmd.arg(&key);
+            client
+                .send_command_to_database(&mut del_cmd, None, 1)
+                .await
+                .unwrap();
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(LONG_STANDALONE_TEST_TIMEOUT)]
+    fn test_send_command_to_database_follows_the_state_of_the_client() {
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                shared_server: true,
+                ..Default::default()
+            };
+            let connection_request =
+                create_connection_request(&[get_shared_server_address(false)], &configuration);
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .unwrap();
+            let key = generate_random_string(10);
+            let mut set_cmd = redis::cmd("SET");
+            set_cmd.arg(&key).arg("value");
+            client
+                .send_command_to_database(&mut set_cmd, None, 1)
+                .await
+                .unwrap();
+
+            let name = generate_random_string(10);
+            let mut setname_cmd = redis::cmd("CLIENT");
+            setname_cmd.arg("SETNAME").arg(&name);
+            client.send_command(&mut setname_cmd, None).await.unwrap();
+            let mut getname_cmd = redis::cmd("CLIENT");
+            getname_cmd.arg("GETNAME");
+            assert_eq!(
+                client
+                    .send_command_to_database(&mut getname_cmd, None, 1)
+                    .await
+                    .unwrap(),
+                Value::BulkString(name.into_bytes())
+            );
+
+            // The connection of the database keeps its database when the client selects another one.
+            let mut select_cmd = redis::cmd("SELECT");
+            select_cmd.arg(2);
+            client.send_command(&mut select_cmd, None).await.unwrap();
+            let mut get_cmd = redis::cmd("GET");
+            get_cmd.arg(&key);
+            assert_eq!(
+                client
+                    .send_command_to_database(&mut get_cmd, None, 1)
+                    .await
+                    .unwrap(),
+                Value::BulkString(b"value".to_vec())
+            );
+
+            let mut del_cmd = redis::cmd("DEL");
+            del_cmd.arg(&key);
+            client
+                .send_command_to_database(&mut del_cmd, None, 1)
+                .await
+                .unwrap();
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(LONG_STANDALONE_TEST_TIMEOUT)]
+    fn test_send_command_to_database_after_password_update() {
+        const NEW_PASSWORD: &str = "AnotherSecurePassword";
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                connection_info: Some(redis::RedisConnectionInfo {
+                    password: Some("ReallySecurePassword".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let test_basics = setup_test_basics_internal(&configuration).await;
+            let address = test_basics
+                .server
+                .as_ref()
+                .expect("Expected a dedicated standalone server")
+                .get_client_addr();
+            let connection_request =
+                create_connection_request(std::slice::from_ref(&address), &configuration);
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .unwrap();
+            let mut ping_cmd = redis::cmd("PING");
+            client
+                .send_command_to_database(&mut ping_cmd, None, 1)
+                .await
+                .unwrap();
+
+            let mut acl_cmd = redis::cmd("ACL");
+            acl_cmd
+                .arg("SETUSER")
+                .arg("default")
+                .arg("resetpass")
+                .arg(format!(">{NEW_PASSWORD}"));
+            client.send_command(&mut acl_cmd, None).await.unwrap();
+            client
+                .update_connection_password(Some(NEW_PASSWORD.to_string()), false)
+                .await
+                .unwrap();
+
+            // The connection of the database reconnects with the new password.
+            kill_connection(&mut client).await;
+            let mut result = client
+                .send_command_to_database(&mut ping_cmd, None, 1)
+                .await;
+            for _ in 0..10 {
+                if result.is_ok() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                result = client
+                    .send_command_to_database(&mut ping_cmd, None, 1)
+                    .await;
+            }
+            assert_eq!(result.unwrap(), Value::SimpleString("PONG".to_string()));
+        });
+    }
 }
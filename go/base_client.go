@@ -20,6 +20,7 @@ package glide
 //                     const uint8_t *message, int64_t message_len,
 //                     const uint8_t *channel, int64_t channel_len,
 //                     const uint8_t *pattern, int64_t pattern_len);
+// void clientCreationCallback(void *channelPtr, struct ConnectionResponse *response);
 import "C"
 
 import (
@@ -129,8 +130,10 @@ func buildAsyncClientType(successCb C.SuccessCallback, failureCb C.FailureCallba
 	return clientType, nil
 }
 
-// Creates a connection by invoking the `create_client` function from Rust library via FFI.
+// Creates a connection by invoking the `create_client_async` function from Rust library via FFI.
 // Passes the pointers to callback functions which will be invoked when the command succeeds or fails.
+// The connection response is delivered to `clientCreationCallback`, so no OS thread is blocked in the FFI call while the
+// client connects.
 // Once the connection is established, this function invokes `free_connection_response` exposed by rust library to free the
 // connection_response to avoid any memory leaks.
 func createClient(config clientConfiguration) (*baseClient, error) {
@@ -156,14 +159,22 @@ func createClient(config clientConfiguration) (*baseClient, error) {
 	}
 	client := &baseClient{pending: make(map[unsafe.Pointer]struct{}), mu: &sync.Mutex{}}
 
-	cResponse := (*C.struct_ConnectionResponse)(
-		C.create_client(
-			(*C.uchar)(requestBytes),
-			C.uintptr_t(byteCount),
-			&clientType,
-			(C.PubSubCallback)(unsafe.Pointer(C.pubSubCallback)),
-		),
+	// make the channel buffered, so that clientCreationCallback doesn't wait for this function to receive the response.
+	responseChannel := make(chan *C.struct_ConnectionResponse, 1)
+	pinner := pinner{}
+	pinnedChannelPtr := uintptr(pinner.Pin(unsafe.Pointer(&responseChannel)))
+	defer pinner.Unpin()
+
+	C.create_client_async(
+		(*C.uchar)(requestBytes),
+		C.uintptr_t(byteCount),
+		&clientType,
+		(C.PubSubCallback)(unsafe.Pointer(C.pubSubCallback)),
+		nil,
+		C.uintptr_t(pinnedChannelPtr),
+		(C.ClientCreationCallback)(unsafe.Pointer(C.clientCreationCallback)),
 	)
+	cResponse := <-responseChannel
 	defer C.free_connection_response(cResponse)
 	cErr := cResponse.connection_error_message
 	if cErr != nil {
@@ -512,12 +523,16 @@ func createBatchOptionsInfo(pinner pinner, options internal.BatchOptions) C.Batc
 	info := C.BatchOptionsInfo{}
 	info.retry_server_error = C._Bool(false)
 	info.retry_connection_error = C._Bool(false)
+	info.follow_ask_redirects = C._Bool(true)
 	if options.RetryServerError != nil {
 		info.retry_server_error = C._Bool(*options.RetryServerError)
 	}
 	if options.RetryConnectionError != nil {
 		info.retry_connection_error = C._Bool(*options.RetryConnectionError)
 	}
+	if options.FollowAskRedirects != nil {
+		info.follow_ask_redirects = C._Bool(*options.FollowAskRedirects)
+	}
 	if options.Timeout != nil {
 		info.has_timeout = C._Bool(true)
 		info.timeout = C.uint(*options.Timeout)
@@ -9754,19 +9769,31 @@ func (client *baseClient) Watch(ctx context.Context, keys []string) (string, err
 //	  - compression_skipped_count: Number of times compression was skipped
 //	  - subscription_out_of_sync_count: Number of times subscriptions were out of sync during reconciliation
 //	  - subscription_last_sync_timestamp: Timestamp of last successful subscription sync (milliseconds since epoch)
+//	  - primary_fallback_reads_count: Number of replica reads that failed on a stale replica and were retried on the primary
+//	  - circuit_breaker_opened_count: Number of times a node circuit breaker was opened
+//	  - circuit_breaker_rejected_count: Number of requests that failed fast because the circuit breaker of their node was open
+//	  - pubsub_messages_dropped_count: Number of pubsub messages dropped because the pubsub buffer of their client was full
+//	  - connections_recycled_max_age_count: Number of connections recycled because they reached the maximum connection age
+//	  - connections_recycled_idle_count: Number of connections recycled because they weren't used within the idle timeout
 func (client *baseClient) GetStatistics() map[string]uint64 {
 	stats := C.get_statistics()
 	return map[string]uint64{
-		"total_connections":                uint64(stats.total_connections),
-		"total_clients":                    uint64(stats.total_clients),
-		"total_values_compressed":          uint64(stats.total_values_compressed),
-		"total_values_decompressed":        uint64(stats.total_values_decompressed),
-		"total_original_bytes":             uint64(stats.total_original_bytes),
-		"total_bytes_compressed":           uint64(stats.total_bytes_compressed),
-		"total_bytes_decompressed":         uint64(stats.total_bytes_decompressed),
-		"compression_skipped_count":        uint64(stats.compression_skipped_count),
-		"subscription_out_of_sync_count":   uint64(stats.subscription_out_of_sync_count),
-		"subscription_last_sync_timestamp": uint64(stats.subscription_last_sync_timestamp),
+		"total_connections":                  uint64(stats.total_connections),
+		"total_clients":                      uint64(stats.total_clients),
+		"total_values_compressed":            uint64(stats.total_values_compressed),
+		"total_values_decompressed":          uint64(stats.total_values_decompressed),
+		"total_original_bytes":               uint64(stats.total_original_bytes),
+		"total_bytes_compressed":             uint64(stats.total_bytes_compressed),
+		"total_bytes_decompressed":           uint64(stats.total_bytes_decompressed),
+		"compression_skipped_count":          uint64(stats.compression_skipped_count),
+		"subscription_out_of_sync_count":     uint64(stats.subscription_out_of_sync_count),
+		"subscription_last_sync_timestamp":   uint64(stats.subscription_last_sync_timestamp),
+		"primary_fallback_reads_count":       uint64(stats.primary_fallback_reads_count),
+		"circuit_breaker_opened_count":       uint64(stats.circuit_breaker_opened_count),
+		"circuit_breaker_rejected_count":     uint64(stats.circuit_breaker_rejected_count),
+		"pubsub_messages_dropped_count":      uint64(stats.pubsub_messages_dropped_count),
+		"connections_recycled_max_age_count": uint64(stats.connections_recycled_max_age_count),
+		"connections_recycled_idle_count":    uint64(stats.connections_recycled_idle_count),
 	}
 }
 
@@ -54,6 +54,12 @@ func failureCallback(channelPtr unsafe.Pointer, cErrorMessage *C.char, cErrorTyp
 	resultChannel <- payload{value: nil, error: GoError(uint32(cErrorType), msg)}
 }
 
+//export clientCreationCallback
+func clientCreationCallback(channelPtr unsafe.Pointer, cResponse *C.struct_ConnectionResponse) {
+	responseChannel := *(*chan *C.struct_ConnectionResponse)(getPinnedPtr(channelPtr))
+	responseChannel <- cResponse
+}
+
 //
 //export pubSubCallback
 func pubSubCallback(
@@ -77,10 +83,10 @@ func pubSubCallback(
 		pat = models.CreateStringResult(string(C.GoBytes(pattern, pattern_len)))
 	}
 
-	go func() {
-		// Process different types of push messages
-		message := models.NewPubSubMessageWithPattern(msg, cha, pat)
+	// The restored subscriptions are reported with their own kind, and aren't messages.
+	resubscribed := pushKind == C.PushResubscribed
 
+	go func() {
 		if clientPtr != nil {
 			// Look up the client in our registry using the pointer address
 			ptrValue := uintptr(clientPtr)
@@ -89,7 +95,11 @@ func pubSubCallback(
 			if client != nil {
 				// If the client has a message handler, use it
 				if handler := client.getMessageHandler(); handler != nil {
-					handler.handleMessage(message)
+					if resubscribed {
+						handler.handleResubscribed(cha, msg)
+					} else {
+						handler.handleMessage(models.NewPubSubMessageWithPattern(msg, cha, pat))
+					}
 				}
 			} else {
 				log.Printf("Client not found for pointer: %v\n", ptrValue)
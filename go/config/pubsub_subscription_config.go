@@ -3,6 +3,8 @@
 package config
 
 import (
+	"time"
+
 	"github.com/valkey-io/valkey-glide/go/v2/internal/protobuf"
 	"github.com/valkey-io/valkey-glide/go/v2/models"
 )
@@ -10,10 +12,20 @@ import (
 // *** BaseSubscriptionConfig ***
 type MessageCallback func(message *models.PubSubMessage, ctx any)
 
+// ResubscribedCallback is called when the subscriptions lost on a disconnection were restored, with the restored
+// channels and patterns by their mode, and the time since the disconnection. Messages published in that time may have
+// been lost. The modes of standalone clients are given by the [PubSubClusterChannelMode] of the same value.
+type ResubscribedCallback func(
+	restored map[PubSubClusterChannelMode][]string,
+	disconnectedFor time.Duration,
+	ctx any,
+)
+
 type BaseSubscriptionConfig struct {
-	callback      MessageCallback
-	context       any
-	subscriptions map[uint32][]string
+	callback             MessageCallback
+	resubscribedCallback ResubscribedCallback
+	context              any
+	subscriptions        map[uint32][]string
 }
 
 func NewBaseSubscriptionConfig() *BaseSubscriptionConfig {
@@ -45,6 +57,10 @@ func (config *BaseSubscriptionConfig) GetCallback() MessageCallback {
 	return config.callback
 }
 
+func (config *BaseSubscriptionConfig) GetResubscribedCallback() ResubscribedCallback {
+	return config.resubscribedCallback
+}
+
 func (config *BaseSubscriptionConfig) GetContext() any {
 	return config.context
 }
@@ -78,6 +94,13 @@ func (config *StandaloneSubscriptionConfig) WithCallback(callback MessageCallbac
 	return config
 }
 
+// WithResubscribedCallback sets the callback that is notified when the subscriptions lost on a disconnection were
+// restored. It's given the context of [StandaloneSubscriptionConfig.WithCallback].
+func (config *StandaloneSubscriptionConfig) WithResubscribedCallback(callback ResubscribedCallback) *StandaloneSubscriptionConfig {
+	config.resubscribedCallback = callback
+	return config
+}
+
 func (config *StandaloneSubscriptionConfig) WithSubscription(
 	mode PubSubChannelMode,
 	channelOrPattern string,
@@ -124,6 +147,13 @@ func (config *ClusterSubscriptionConfig) WithCallback(callback MessageCallback,
 	return config
 }
 
+// WithResubscribedCallback sets the callback that is notified when the subscriptions lost on a disconnection were
+// restored. It's given the context of [ClusterSubscriptionConfig.WithCallback].
+func (config *ClusterSubscriptionConfig) WithResubscribedCallback(callback ResubscribedCallback) *ClusterSubscriptionConfig {
+	config.resubscribedCallback = callback
+	return config
+}
+
 func (config *ClusterSubscriptionConfig) WithSubscription(
 	mode PubSubClusterChannelMode,
 	channelOrPattern string,
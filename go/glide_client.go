@@ -61,7 +61,9 @@ func NewClient(config *config.ClientConfiguration) (*Client, error) {
 	}
 	if config.HasSubscription() {
 		subConfig := config.GetSubscription()
-		client.setMessageHandler(NewMessageHandler(subConfig.GetCallback(), subConfig.GetContext()))
+		handler := NewMessageHandler(subConfig.GetCallback(), subConfig.GetContext())
+		handler.resubscribedCallback = subConfig.GetResubscribedCallback()
+		client.setMessageHandler(handler)
 	} else {
 		client.setMessageHandler(NewMessageHandler(nil, nil))
 	}
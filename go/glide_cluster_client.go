@@ -68,7 +68,9 @@ func NewClusterClient(config *config.ClusterClientConfiguration) (*ClusterClient
 	}
 	if config.HasSubscription() {
 		subConfig := config.GetSubscription()
-		client.setMessageHandler(NewMessageHandler(subConfig.GetCallback(), subConfig.GetContext()))
+		handler := NewMessageHandler(subConfig.GetCallback(), subConfig.GetContext())
+		handler.resubscribedCallback = subConfig.GetResubscribedCallback()
+		client.setMessageHandler(handler)
 	} else {
 		client.setMessageHandler(NewMessageHandler(nil, nil))
 	}
@@ -46,6 +46,7 @@ type BatchOptions struct {
 	Route                config.Route
 	RetryServerError     *bool
 	RetryConnectionError *bool
+	FollowAskRedirects   *bool
 }
 
 type LCSResponseType int
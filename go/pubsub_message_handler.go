@@ -6,10 +6,13 @@ package glide
 import "C"
 
 import (
+	"encoding/binary"
 	"errors"
 	"fmt"
 	"log"
+	"strconv"
 	"sync"
+	"time"
 
 	"github.com/valkey-io/valkey-glide/go/v2/config"
 	"github.com/valkey-io/valkey-glide/go/v2/models"
@@ -36,9 +39,10 @@ func (e *MessageCallbackError) Cause() error {
 // *** Message Handler ***
 
 type MessageHandler struct {
-	callback config.MessageCallback
-	context  any
-	queue    *PubSubMessageQueue
+	callback             config.MessageCallback
+	resubscribedCallback config.ResubscribedCallback
+	context              any
+	queue                *PubSubMessageQueue
 }
 
 func NewMessageHandler(callback config.MessageCallback, context any) *MessageHandler {
@@ -69,6 +73,38 @@ func (handler *MessageHandler) handleMessage(message *models.PubSubMessage) erro
 	}
 }
 
+// handleResubscribed notifies the resubscribed callback, if one is set, that the subscriptions lost on a disconnection
+// were restored. Each subscription is given in subscriptions by its mode in a single byte, the length of its name as a
+// 4 bytes big-endian integer, and its name. The time since the disconnection is given in milliseconds in
+// disconnectedForMs. Unlike messages, these notifications are never queued.
+func (handler *MessageHandler) handleResubscribed(subscriptions string, disconnectedForMs string) {
+	if handler.resubscribedCallback == nil {
+		return
+	}
+	defer func() {
+		if r := recover(); r != nil {
+			log.Println("panic in resubscribed callback", r)
+		}
+	}()
+
+	restored := map[config.PubSubClusterChannelMode][]string{}
+	for len(subscriptions) >= 5 {
+		mode := config.PubSubClusterChannelMode(subscriptions[0])
+		length := int(binary.BigEndian.Uint32([]byte(subscriptions[1:5])))
+		if len(subscriptions) < 5+length {
+			log.Println("truncated resubscription notification")
+			break
+		}
+		restored[mode] = append(restored[mode], subscriptions[5:5+length])
+		subscriptions = subscriptions[5+length:]
+	}
+	millis, err := strconv.ParseInt(disconnectedForMs, 10, 64)
+	if err != nil {
+		millis = 0
+	}
+	handler.resubscribedCallback(restored, time.Duration(millis)*time.Millisecond, handler.context)
+}
+
 func (handler *MessageHandler) GetQueue() *PubSubMessageQueue {
 	return handler.queue
 }
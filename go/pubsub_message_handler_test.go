@@ -0,0 +1,48 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+package glide
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/valkey-io/valkey-glide/go/v2/config"
+)
+
+func TestMessageHandler_HandleResubscribed(t *testing.T) {
+	var restored map[config.PubSubClusterChannelMode][]string
+	var disconnectedFor time.Duration
+	var context any
+	handler := NewMessageHandler(nil, "context")
+	handler.resubscribedCallback = func(
+		subscriptions map[config.PubSubClusterChannelMode][]string,
+		gap time.Duration,
+		ctx any,
+	) {
+		restored = subscriptions
+		disconnectedFor = gap
+		context = ctx
+	}
+
+	subscriptions := "\x00\x00\x00\x00\x0bnews\nsports" + "\x01\x00\x00\x00\x08sports.*" + "\x02\x00\x00\x00\x05shard"
+	handler.handleResubscribed(subscriptions, "1500")
+
+	assert.Equal(t, map[config.PubSubClusterChannelMode][]string{
+		config.ExactClusterChannelMode:   {"news\nsports"},
+		config.PatternClusterChannelMode: {"sports.*"},
+		config.ShardedClusterChannelMode: {"shard"},
+	}, restored)
+	assert.Equal(t, 1500*time.Millisecond, disconnectedFor)
+	assert.Equal(t, "context", context)
+	// The notification isn't queued as a message.
+	assert.Nil(t, handler.GetQueue().Pop())
+}
+
+func TestMessageHandler_HandleResubscribedWithoutCallback(t *testing.T) {
+	handler := NewMessageHandler(nil, nil)
+
+	handler.handleResubscribed("\x00\x00\x00\x00\x04news", "10")
+
+	assert.Nil(t, handler.GetQueue().Pop())
+}
@@ -314,7 +314,11 @@ import glide.api.models.commands.stream.StreamReadOptions;
 import glide.api.models.commands.stream.StreamTrimOptions;
 import glide.api.models.configuration.BaseClientConfiguration;
 import glide.api.models.configuration.BaseSubscriptionConfiguration;
+import glide.api.models.configuration.BaseSubscriptionConfiguration.ChannelMode;
+import glide.api.models.configuration.ClusterSubscriptionConfiguration;
+import glide.api.models.configuration.ClusterSubscriptionConfiguration.PubSubClusterChannelMode;
 import glide.api.models.configuration.ServerCredentials;
+import glide.api.models.configuration.StandaloneSubscriptionConfiguration.PubSubChannelMode;
 import glide.api.models.exceptions.ConfigurationError;
 import glide.api.models.exceptions.GlideException;
 import glide.connectors.handlers.MessageHandler;
@@ -322,6 +326,7 @@ import glide.ffi.resolvers.GlideValueResolver;
 import glide.ffi.resolvers.NativeUtils;
 import glide.ffi.resolvers.StatisticsResolver;
 import glide.internal.GlideCoreClient;
+import glide.internal.GlideNativeBridge;
 import glide.managers.BaseResponseResolver;
 import glide.managers.CommandManager;
 import glide.managers.ConnectionManager;
@@ -331,6 +336,7 @@ import java.util.Arrays;
 import java.util.Collections;
 import java.util.EnumSet;
 import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
 import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
@@ -555,6 +561,17 @@ public abstract class BaseClient
         return StatisticsResolver.getStatistics();
     }
 
+    /**
+     * Return the statistics collected for this client.
+     *
+     * @return Return a {@link Map} that contains the number of commands, batches and errors sent
+     *     through this client and their average and maximum latency in microseconds, along with the
+     *     connection and client totals collected by GLIDE core
+     */
+    public Map<String, String> getClientStatistics() {
+        return GlideNativeBridge.getClientStatistics(connectionManager.getNativeClientHandle());
+    }
+
     /**
      * Return a next pubsub message if it is present.
      *
@@ -604,6 +621,26 @@ public abstract class BaseClient
         connectionManager.closeConnectionSync();
     }
 
+    /**
+     * Replaces the configuration of a client created with {@code lazyConnect}, before it sends its
+     * first command. The client then connects with the new configuration.
+     *
+     * @param configuration The new configuration. It must be a configuration of the same kind of
+     *     client, standalone or cluster, with at least one address.
+     * @return A future that completes once the configuration is replaced.
+     * @throws ConfigurationError if the client already sent a command, or if the configuration is
+     *     invalid.
+     * @example
+     *     <pre>{@code
+     * GlideClient client = GlideClient.createClient(config.lazyConnect(true).build()).get();
+     * client.updateLazyConfiguration(config.lazyConnect(true).clientName("worker").build()).get();
+     * }</pre>
+     */
+    public CompletableFuture<Void> updateLazyConfiguration(
+            @NonNull BaseClientConfiguration configuration) {
+        return connectionManager.updateLazyConfiguration(configuration);
+    }
+
     /** Check if the client is connected and ready for commands. */
     public boolean isConnected() {
         return connectionManager.isConnected();
@@ -6354,6 +6391,67 @@ public abstract class BaseClient
                 PUnsubscribeBlocking, new String[] {String.valueOf(timeoutMs)}, response -> null);
     }
 
+    @Override
+    public CompletableFuture<Void> subscribeLazyBinary(Set<GlideString> channels) {
+        return commandManager.submitNewCommand(
+                Subscribe, channels.toArray(EMPTY_GLIDE_STRING_ARRAY), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> subscribeBinary(Set<GlideString> channels, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                SubscribeBlocking, withTimeout(channels, timeoutMs), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> psubscribeLazyBinary(Set<GlideString> patterns) {
+        return commandManager.submitNewCommand(
+                PSubscribe, patterns.toArray(EMPTY_GLIDE_STRING_ARRAY), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> psubscribeBinary(Set<GlideString> patterns, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                PSubscribeBlocking, withTimeout(patterns, timeoutMs), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> unsubscribeLazyBinary(Set<GlideString> channels) {
+        return commandManager.submitNewCommand(
+                Unsubscribe, channels.toArray(EMPTY_GLIDE_STRING_ARRAY), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> unsubscribeBinary(Set<GlideString> channels, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                UnsubscribeBlocking, withTimeout(channels, timeoutMs), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> punsubscribeLazyBinary(Set<GlideString> patterns) {
+        return commandManager.submitNewCommand(
+                PUnsubscribe, patterns.toArray(EMPTY_GLIDE_STRING_ARRAY), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> punsubscribeBinary(Set<GlideString> patterns, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                PUnsubscribeBlocking, withTimeout(patterns, timeoutMs), response -> null);
+    }
+
+    /**
+     * Returns the arguments of a blocking subscription command, which are the channels or patterns
+     * followed by the timeout.
+     */
+    protected static GlideString[] withTimeout(Set<GlideString> channels, int timeoutMs) {
+        if (timeoutMs < 0) {
+            throw new IllegalArgumentException("Timeout must be non-negative, got: " + timeoutMs);
+        }
+        GlideString[] args = channels.toArray(new GlideString[channels.size() + 1]);
+        args[channels.size()] = gs(String.valueOf(timeoutMs));
+        return args;
+    }
+
     protected Object parseSubscriptionState(Object response) {
         if (!(response instanceof Object[])) {
             throw new RuntimeException(
@@ -6396,4 +6494,37 @@ public abstract class BaseClient
         }
         messageHandler.getQueue().push(message);
     }
+
+    /**
+     * Internal method for reporting restored PubSub subscriptions from native callback. This is
+     * called by the native layer after the subscriptions were restored on a new connection.
+     */
+    public void __notifyResubscribed(long disconnectedForMillis, int[] modes, byte[][] channels) {
+        if (!subscriptionConfiguration.isPresent()
+                || !subscriptionConfiguration.get().getResubscribedCallback().isPresent()) {
+            return;
+        }
+        BaseSubscriptionConfiguration config = subscriptionConfiguration.get();
+        ChannelMode[] knownModes =
+                config instanceof ClusterSubscriptionConfiguration
+                        ? PubSubClusterChannelMode.values()
+                        : PubSubChannelMode.values();
+        Map<ChannelMode, Set<GlideString>> restored = new LinkedHashMap<>();
+        for (int i = 0; i < modes.length && i < channels.length; i++) {
+            if (modes[i] < 0 || modes[i] >= knownModes.length) {
+                continue;
+            }
+            restored
+                    .computeIfAbsent(knownModes[modes[i]], mode -> new LinkedHashSet<>())
+                    .add(GlideString.of(channels[i]));
+        }
+        try {
+            config
+                    .getResubscribedCallback()
+                    .get()
+                    .accept(restored, disconnectedForMillis, config.getContext().orElse(null));
+        } catch (Throwable ignored) {
+            // Ensure user callback exceptions do not break push delivery loop
+        }
+    }
 }
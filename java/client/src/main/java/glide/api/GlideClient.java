@@ -60,6 +60,7 @@ import glide.api.models.configuration.ServerCredentials;
 import glide.api.models.configuration.StandaloneSubscriptionConfiguration;
 import glide.utils.ArgsBuilder;
 import java.util.Arrays;
+import java.util.List;
 import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
@@ -136,6 +137,31 @@ public class GlideClient extends BaseClient
         return BaseClient.createClient(config, GlideClient::new);
     }
 
+    /**
+     * Returns a client that sends its commands to the logical database {@code databaseId}, without
+     * changing the database of this client or of its other commands.
+     *
+     * @remarks The returned client shares the connection of this client, so closing either of them
+     *     closes both. Only single commands are supported: batches, and commands that change the
+     *     connection state such as <code>SELECT</code> or <code>WATCH</code>, fail.
+     * @param databaseId The index of the database.
+     * @return A client bound to the database.
+     * @example
+     *     <pre>{@code
+     * GlideClient sessions = client.withDatabase(2);
+     * sessions.set("session", "value").get();
+     * assert client.get("session").get() == null;
+     * }</pre>
+     */
+    public GlideClient withDatabase(long databaseId) {
+        return new GlideClient(
+                new ClientBuilder(
+                        connectionManager,
+                        commandManager.withDatabase(databaseId),
+                        messageHandler,
+                        subscriptionConfiguration));
+    }
+
     @Override
     public CompletableFuture<Object> customCommand(@NonNull String[] args) {
         return commandManager.submitCustomCommand(args, this::handleObjectOrNullResponse);
@@ -146,6 +172,17 @@ public class GlideClient extends BaseClient
         return commandManager.submitCustomCommand(args, this::handleBinaryObjectOrNullResponse);
     }
 
+    @Override
+    public List<CompletableFuture<Object>> customCommands(@NonNull String[][] commands) {
+        return commandManager.submitCustomCommands(commands, this::handleObjectOrNullResponse);
+    }
+
+    @Override
+    public List<CompletableFuture<Object>> customCommands(@NonNull GlideString[][] commands) {
+        return commandManager.submitCustomCommands(
+                commands, this::handleBinaryObjectOrNullResponse);
+    }
+
     @Deprecated
     @Override
     public CompletableFuture<Object[]> exec(@NonNull Transaction transaction) {
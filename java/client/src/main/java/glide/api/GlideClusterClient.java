@@ -96,6 +96,7 @@ import java.util.Arrays;
 import java.util.Collections;
 import java.util.EnumSet;
 import java.util.LinkedHashMap;
+import java.util.List;
 import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
@@ -193,6 +194,20 @@ public class GlideClusterClient extends BaseClient
                 args, response -> ClusterValue.of(handleBinaryObjectOrNullResponse(response)));
     }
 
+    @Override
+    public List<CompletableFuture<ClusterValue<Object>>> customCommands(
+            @NonNull String[][] commands) {
+        return commandManager.submitCustomCommands(
+                commands, response -> ClusterValue.of(handleObjectOrNullResponse(response)));
+    }
+
+    @Override
+    public List<CompletableFuture<ClusterValue<Object>>> customCommands(
+            @NonNull GlideString[][] commands) {
+        return commandManager.submitCustomCommands(
+                commands, response -> ClusterValue.of(handleBinaryObjectOrNullResponse(response)));
+    }
+
     @Override
     public CompletableFuture<ClusterValue<Object>> customCommand(
             @NonNull String[] args, @NonNull Route route) {
@@ -1621,6 +1636,34 @@ public class GlideClusterClient extends BaseClient
                 SUnsubscribeBlocking, new String[] {String.valueOf(timeoutMs)}, response -> null);
     }
 
+    @Override
+    public CompletableFuture<Void> ssubscribeLazyBinary(Set<GlideString> channels) {
+        return commandManager.submitNewCommand(
+                SSubscribe,
+                channels.toArray(BaseClient.EMPTY_GLIDE_STRING_ARRAY),
+                response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> ssubscribeBinary(Set<GlideString> channels, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                SSubscribeBlocking, withTimeout(channels, timeoutMs), response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> sunsubscribeLazyBinary(Set<GlideString> channels) {
+        return commandManager.submitNewCommand(
+                SUnsubscribe,
+                channels.toArray(BaseClient.EMPTY_GLIDE_STRING_ARRAY),
+                response -> null);
+    }
+
+    @Override
+    public CompletableFuture<Void> sunsubscribeBinary(Set<GlideString> channels, int timeoutMs) {
+        return commandManager.submitNewCommand(
+                SUnsubscribeBlocking, withTimeout(channels, timeoutMs), response -> null);
+    }
+
     /**
      * Gets the current subscription state for this cluster client.
      *
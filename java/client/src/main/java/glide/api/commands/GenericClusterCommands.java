@@ -6,6 +6,7 @@ import glide.api.models.GlideString;
 import glide.api.models.commands.scan.ClusterScanCursor;
 import glide.api.models.commands.scan.ScanOptions;
 import glide.api.models.configuration.RequestRoutingConfiguration.Route;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
 
 /**
@@ -49,6 +50,46 @@ public interface GenericClusterCommands {
      */
     CompletableFuture<ClusterValue<Object>> customCommand(GlideString[] args);
 
+    /**
+     * Executes many independent commands, without checking inputs, with a single call into the
+     * native layer, to amortize its cost for workloads issuing many small commands. The commands
+     * aren't pipelined or atomic: each is executed concurrently and completes its own future.
+     * Every part of a command, including subcommands, should be added as a separate value in its
+     * arguments.<br>
+     * Each command will be routed automatically based on its default request policy.
+     *
+     * @see #customCommand(String[])
+     * @param commands Arguments of each custom command.
+     * @return The returned value of each custom command, in the order of <code>commands</code>.
+     * @example
+     *     <pre>{@code
+     * List<CompletableFuture<ClusterValue<Object>>> responses =
+     *         client.customCommands(new String[][] {{"GET", "key1"}, {"GET", "key2"}});
+     * Object value1 = responses.get(0).get().getSingleValue();
+     * }</pre>
+     */
+    List<CompletableFuture<ClusterValue<Object>>> customCommands(String[][] commands);
+
+    /**
+     * Executes many independent commands, without checking inputs, with a single call into the
+     * native layer, to amortize its cost for workloads issuing many small commands. The commands
+     * aren't pipelined or atomic: each is executed concurrently and completes its own future.
+     * Every part of a command, including subcommands, should be added as a separate value in its
+     * arguments.<br>
+     * Each command will be routed automatically based on its default request policy.
+     *
+     * @see #customCommand(GlideString[])
+     * @param commands Arguments of each custom command.
+     * @return The returned value of each custom command, in the order of <code>commands</code>.
+     * @example
+     *     <pre>{@code
+     * List<CompletableFuture<ClusterValue<Object>>> responses =
+     *         client.customCommands(new GlideString[][] {{gs("GET"), gs("key1")}});
+     * Object value1 = responses.get(0).get().getSingleValue();
+     * }</pre>
+     */
+    List<CompletableFuture<ClusterValue<Object>>> customCommands(GlideString[][] commands);
+
     /**
      * Executes a single command, without checking inputs. Every part of the command, including
      * subcommands, should be added as a separate value in <code>args</code>.
@@ -3,6 +3,7 @@ package glide.api.commands;
 
 import glide.api.models.GlideString;
 import glide.api.models.commands.scan.ScanOptions;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
 
 /**
@@ -48,6 +49,44 @@ public interface GenericCommands {
      */
     CompletableFuture<Object> customCommand(GlideString[] args);
 
+    /**
+     * Executes many independent commands, without checking inputs, with a single call into the
+     * native layer, to amortize its cost for workloads issuing many small commands. The commands
+     * aren't pipelined or atomic: each is executed concurrently and completes its own future.
+     * Every part of a command, including subcommands, should be added as a separate value in its
+     * arguments.
+     *
+     * @see #customCommand(String[])
+     * @param commands Arguments of each custom command.
+     * @return The returned value of each custom command, in the order of <code>commands</code>.
+     * @example
+     *     <pre>{@code
+     * List<CompletableFuture<Object>> responses =
+     *         client.customCommands(new String[][] {{"GET", "key1"}, {"GET", "key2"}});
+     * Object value1 = responses.get(0).get();
+     * }</pre>
+     */
+    List<CompletableFuture<Object>> customCommands(String[][] commands);
+
+    /**
+     * Executes many independent commands, without checking inputs, with a single call into the
+     * native layer, to amortize its cost for workloads issuing many small commands. The commands
+     * aren't pipelined or atomic: each is executed concurrently and completes its own future.
+     * Every part of a command, including subcommands, should be added as a separate value in its
+     * arguments.
+     *
+     * @see #customCommand(GlideString[])
+     * @param commands Arguments of each custom command.
+     * @return The returned value of each custom command, in the order of <code>commands</code>.
+     * @example
+     *     <pre>{@code
+     * List<CompletableFuture<Object>> responses =
+     *         client.customCommands(new GlideString[][] {{gs("GET"), gs("key1")}});
+     * Object value1 = responses.get(0).get();
+     * }</pre>
+     */
+    List<CompletableFuture<Object>> customCommands(GlideString[][] commands);
+
     /**
      * Returns a random key from currently selected database.
      *
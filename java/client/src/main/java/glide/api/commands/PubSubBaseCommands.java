@@ -423,4 +423,140 @@ public interface PubSubBaseCommands {
      * @see <a href="https://valkey.io/commands/punsubscribe/">valkey.io</a> for details
      */
     CompletableFuture<Void> punsubscribe(int timeoutMs);
+
+    /**
+     * Subscribes the client to the specified channels, given as binary-safe names. Unlike {@link
+     * #subscribeLazy(Set)}, the names are sent as is, so they may hold any bytes, including invalid
+     * UTF-8.
+     *
+     * @param channels A set of channel names to subscribe to
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.subscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/subscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> subscribeLazyBinary(Set<GlideString> channels);
+
+    /**
+     * Subscribes the client to the specified channels with a timeout, given as binary-safe names.
+     * Unlike {@link #subscribe(Set, int)}, the names are sent as is, so they may hold any bytes,
+     * including invalid UTF-8.
+     *
+     * @param channels A set of channel names to subscribe to
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.subscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/subscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> subscribeBinary(Set<GlideString> channels, int timeoutMs);
+
+    /**
+     * Subscribes the client to channels matching the specified patterns, given as binary-safe
+     * names. Unlike {@link #psubscribeLazy(Set)}, the names are sent as is, so they may hold any
+     * bytes, including invalid UTF-8.
+     *
+     * @param patterns A set of glob patterns to subscribe to
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.psubscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/psubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> psubscribeLazyBinary(Set<GlideString> patterns);
+
+    /**
+     * Subscribes the client to channels matching the specified patterns with a timeout, given as
+     * binary-safe names. Unlike {@link #psubscribe(Set, int)}, the names are sent as is, so they
+     * may hold any bytes, including invalid UTF-8.
+     *
+     * @param patterns A set of glob patterns to subscribe to
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.psubscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/psubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> psubscribeBinary(Set<GlideString> patterns, int timeoutMs);
+
+    /**
+     * Unsubscribes the client from the specified channels, given as binary-safe names. Unlike
+     * {@link #unsubscribeLazy(Set)}, the names are sent as is, so they may hold any bytes,
+     * including invalid UTF-8.
+     *
+     * @param channels A set of channel names to unsubscribe from
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.unsubscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/unsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> unsubscribeLazyBinary(Set<GlideString> channels);
+
+    /**
+     * Unsubscribes the client from the specified channels with a timeout, given as binary-safe
+     * names. Unlike {@link #unsubscribe(Set, int)}, the names are sent as is, so they may hold any
+     * bytes, including invalid UTF-8.
+     *
+     * @param channels A set of channel names to unsubscribe from
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.unsubscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/unsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> unsubscribeBinary(Set<GlideString> channels, int timeoutMs);
+
+    /**
+     * Unsubscribes the client from the specified patterns, given as binary-safe names. Unlike
+     * {@link #punsubscribeLazy(Set)}, the names are sent as is, so they may hold any bytes,
+     * including invalid UTF-8.
+     *
+     * @param patterns A set of glob patterns to unsubscribe from
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.punsubscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/punsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> punsubscribeLazyBinary(Set<GlideString> patterns);
+
+    /**
+     * Unsubscribes the client from the specified patterns with a timeout, given as binary-safe
+     * names. Unlike {@link #punsubscribe(Set, int)}, the names are sent as is, so they may hold any
+     * bytes, including invalid UTF-8.
+     *
+     * @param patterns A set of glob patterns to unsubscribe from
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.punsubscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/punsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> punsubscribeBinary(Set<GlideString> patterns, int timeoutMs);
 }
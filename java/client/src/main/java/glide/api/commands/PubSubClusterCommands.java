@@ -267,4 +267,72 @@ public interface PubSubClusterCommands {
      * @see <a href="https://valkey.io/commands/sunsubscribe/">valkey.io</a> for details
      */
     CompletableFuture<Void> sunsubscribe(int timeoutMs);
+
+    /**
+     * Subscribes the client to the specified sharded channels, given as binary-safe names. Unlike
+     * {@link #ssubscribeLazy(Set)}, the names are sent as is, so they may hold any bytes, including
+     * invalid UTF-8.
+     *
+     * @param channels A set of sharded channel names to subscribe to
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.ssubscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/ssubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> ssubscribeLazyBinary(Set<GlideString> channels);
+
+    /**
+     * Subscribes the client to the specified sharded channels with a timeout, given as binary-safe
+     * names. Unlike {@link #ssubscribe(Set, int)}, the names are sent as is, so they may hold any
+     * bytes, including invalid UTF-8.
+     *
+     * @param channels A set of sharded channel names to subscribe to
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.ssubscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/ssubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> ssubscribeBinary(Set<GlideString> channels, int timeoutMs);
+
+    /**
+     * Unsubscribes the client from the specified sharded channels, given as binary-safe names.
+     * Unlike {@link #sunsubscribeLazy(Set)}, the names are sent as is, so they may hold any bytes,
+     * including invalid UTF-8.
+     *
+     * @param channels A set of sharded channel names to unsubscribe from
+     * @return A {@link CompletableFuture} that completes when the request is processed
+     * @example
+     *     <pre>{@code
+     * client.sunsubscribeLazyBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe}))).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/sunsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> sunsubscribeLazyBinary(Set<GlideString> channels);
+
+    /**
+     * Unsubscribes the client from the specified sharded channels with a timeout, given as binary-
+     * safe names. Unlike {@link #sunsubscribe(Set, int)}, the names are sent as is, so they may
+     * hold any bytes, including invalid UTF-8.
+     *
+     * @param channels A set of sharded channel names to unsubscribe from
+     * @param timeoutMs Maximum time in milliseconds to wait for confirmation. A value of 0 blocks
+     *     indefinitely until confirmation.
+     * @return A {@link CompletableFuture} that completes when the change is confirmed or times out
+     * @example
+     *     <pre>{@code
+     * client.sunsubscribeBinary(Set.of(gs(new byte[] {(byte) 0xff, (byte) 0xfe})), 5000).get();
+     * }</pre>
+     *
+     * @see <a href="https://valkey.io/commands/sunsubscribe/">valkey.io</a> for details
+     */
+    CompletableFuture<Void> sunsubscribeBinary(Set<GlideString> channels, int timeoutMs);
 }
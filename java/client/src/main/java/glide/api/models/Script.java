@@ -19,6 +19,13 @@ public class Script implements AutoCloseable {
     /** Indication if script invocation output can return binary data. */
     @Getter private final Boolean binaryOutput;
 
+    /**
+     * Indication if the script doesn't modify data. Read-only scripts are invoked with <code>
+     * EVALSHA_RO</code>, and may be served by replicas according to the client's <code>ReadFrom
+     * </code> strategy.
+     */
+    @Getter private final boolean readOnly;
+
     private boolean dropped = false;
 
     /**
@@ -28,8 +35,21 @@ public class Script implements AutoCloseable {
      * @param binaryOutput Indicates if the output can return binary data.
      */
     public <T> Script(T code, Boolean binaryOutput) {
+        this(code, binaryOutput, false);
+    }
+
+    /**
+     * Wraps around creating a Script object from <code>code</code>.
+     *
+     * @param code To execute with a ScriptInvoke call.
+     * @param binaryOutput Indicates if the output can return binary data.
+     * @param readOnly Indicates if the script doesn't modify data, so it can be invoked with <code>
+     *     EVALSHA_RO</code> and served by replicas.
+     */
+    public <T> Script(T code, Boolean binaryOutput, boolean readOnly) {
         this.hash = ScriptResolver.storeScript(GlideString.of(code).getBytes());
         this.binaryOutput = binaryOutput;
+        this.readOnly = readOnly;
     }
 
     /** Drop the linked script from glide-rs <code>code</code>. */
@@ -57,7 +57,7 @@ import lombok.Getter;
  * <b>Note:</b> Currently, retry strategies are supported only for non-atomic batches.
  *
  * <p><b>Default:</b> Both {@code retryServerError} and {@code retryConnectionError} are set to
- * {@code false}.
+ * {@code false}, and {@code followAskRedirects} is set to {@code true}.
  */
 @Getter
 @Builder
@@ -83,4 +83,13 @@ public class ClusterBatchRetryStrategy {
      * <p>By default, this is set to {@code false}.
      */
     private final boolean retryConnectionError;
+
+    /**
+     * If {@code true}, commands redirected with an <code>ASK</code> error, because their slot is being
+     * migrated, are retried on the importing node. If {@code false}, they fail with the <code>ASK
+     * </code> error. A redirected command fails with the last error once the retries are exhausted.
+     *
+     * <p>By default, this is set to {@code true}.
+     */
+    @Builder.Default private final boolean followAskRedirects = true;
 }
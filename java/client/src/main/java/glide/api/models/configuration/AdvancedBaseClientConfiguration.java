@@ -60,6 +60,86 @@ public abstract class AdvancedBaseClientConfiguration {
      */
     private final Integer pubsubReconciliationIntervalMs;
 
+    /**
+     * Whether each connection sends <code>CLIENT NO-EVICT ON</code> when it is established, including
+     * reconnections, so the server doesn't evict it when the memory for client connections is
+     * exhausted.
+     *
+     * <p>If not explicitly set, connections may be evicted.
+     */
+    private final Boolean clientNoEvict;
+
+    /**
+     * Whether each connection sends <code>CLIENT NO-TOUCH ON</code> when it is established, including
+     * reconnections, so the client's commands don't alter the LRU/LFU of the keys they access. This
+     * keeps monitoring clients from skewing the eviction statistics. Requires Valkey 7.2 or later.
+     *
+     * <p>If not explicitly set, the commands touch the keys they access.
+     */
+    private final Boolean clientNoTouch;
+
+    /**
+     * Whether a connection falls back to RESP2 when a node rejects <code>HELLO 3</code>, instead of
+     * failing. RESP3-only features, such as PubSub push notifications, aren't available on the
+     * connections that fell back. The downgraded nodes are reported in the server capabilities.
+     * Ignored when PubSub subscriptions are configured, and subscribing on a connection that fell
+     * back fails.
+     *
+     * <p>If not explicitly set, connecting to a node that rejects RESP3 fails.
+     */
+    private final Boolean resp3Fallback;
+
+    /**
+     * Whether <code>JSON.GET</code> is evaluated on the client when the server doesn't have the
+     * JSON module. The document is then read as the string value of the key with <code>GET</code>,
+     * and the paths are evaluated by the client, returning the same response as the module would.
+     * Filter expressions aren't supported.
+     *
+     * <p>If not explicitly set, <code>JSON.GET</code> fails on servers without the JSON module.
+     */
+    private final Boolean jsonPathFallback;
+
+    /**
+     * TCP keepalive settings of the client's connections, which keep NAT and load balancer mappings
+     * of idle connections alive.
+     *
+     * <p>If not explicitly set, keepalive probes are sent with the system defaults.
+     */
+    private final TcpKeepaliveConfiguration tcpKeepalive;
+
+    /**
+     * The interval in milliseconds of the <code>PING</code> sent to every node, keeping idle
+     * connections alive when TCP keepalive isn't enough. Nodes that fail to respond are reconnected.
+     *
+     * <p>If not explicitly set, no heartbeat is sent.
+     */
+    private final Integer heartbeatIntervalMs;
+
+    /**
+     * The maximum age in milliseconds of a connection, after which it is replaced, such as behind
+     * load balancers that silently drop long-lived connections. Replacements are counted in the
+     * client statistics.
+     *
+     * <p>If not explicitly set, connections are kept regardless of their age.
+     */
+    private final Integer maxConnectionAgeMs;
+
+    /**
+     * The time in milliseconds a connection may go without sending a request, after which it is
+     * replaced. Replacements are counted in the client statistics.
+     *
+     * <p>If not explicitly set, idle connections are kept.
+     */
+    private final Integer connectionIdleTimeoutMs;
+
+    /**
+     * The SOCKS5 or HTTP CONNECT proxy that the connections are tunneled through, for servers that
+     * are only reachable through a bastion host. The proxy resolves the host names of the nodes.
+     *
+     * <p>If not explicitly set, the client connects to the nodes directly.
+     */
+    private final ProxyConfiguration proxy;
+
     /** Abstract builder class for {@link AdvancedBaseClientConfiguration}. */
     public abstract static class AdvancedBaseClientConfigurationBuilder<
             C extends AdvancedBaseClientConfiguration,
@@ -2,6 +2,7 @@
 package glide.api.models.configuration;
 
 import glide.api.GlideClusterClient;
+import java.util.Map;
 import lombok.Builder;
 import lombok.Getter;
 import lombok.ToString;
@@ -50,4 +51,13 @@ public class AdvancedGlideClusterClientConfiguration extends AdvancedBaseClientC
      */
     @Builder.Default
     private final PeriodicChecksConfig periodicChecks = PeriodicChecksStatus.ENABLED_DEFAULT_CONFIGS;
+
+    /**
+     * The {@code host:port} addresses to connect to instead of the {@code host:port} addresses the
+     * nodes announce, for nodes that aren't reachable at their announced addresses, such as behind
+     * NAT or inside containers. For example, {@code Map.of("10.0.0.1:6379", "localhost:7001")}.
+     *
+     * <p>If not set, nodes are reached at the addresses they announce.
+     */
+    private final Map<String, String> addressTranslation;
 }
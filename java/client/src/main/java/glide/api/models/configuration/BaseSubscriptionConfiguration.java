@@ -43,6 +43,25 @@ public abstract class BaseSubscriptionConfiguration {
      */
     public interface MessageCallback extends BiConsumer<PubSubMessage, Object> {}
 
+    /**
+     * Callback called after the client restored its subscriptions on a new connection, once the
+     * connection to the server was lost. Messages published while the client was disconnected are
+     * not delivered.<br>
+     * The callback arguments are:
+     *
+     * <ol>
+     *   <li>The restored channels and patterns, by their {@link ChannelMode}.
+     *   <li>For how long the client was disconnected, in milliseconds.
+     *   <li>A user-defined {@link #context} or <code>null</code> if not configured.
+     * </ol>
+     */
+    public interface ResubscribedCallback {
+        void accept(
+                Map<ChannelMode, Set<GlideString>> restored,
+                long disconnectedForMillis,
+                Object context);
+    }
+
     /**
      * Optional callback to accept the incoming messages. See {@link MessageCallback}.<br>
      * If not set, messages will be available via {@link BaseClient#tryGetPubSubMessage()} or {@link
@@ -57,6 +76,12 @@ public abstract class BaseSubscriptionConfiguration {
      */
     protected final Optional<Object> context;
 
+    /**
+     * Optional callback to learn about the restored subscriptions after a reconnection. See {@link
+     * ResubscribedCallback}.
+     */
+    protected final Optional<ResubscribedCallback> resubscribedCallback;
+
     // All code below is a custom implementation of `SuperBuilder`, because we provide
     // custom user-friendly API `callback` and `subscription`.
     /**
@@ -69,6 +94,7 @@ public abstract class BaseSubscriptionConfiguration {
 
         protected Optional<MessageCallback> callback = Optional.empty();
         protected Optional<Object> context = Optional.empty();
+        protected Optional<ResubscribedCallback> resubscribedCallback = Optional.empty();
 
         protected <M extends ChannelMode> void addSubscription(
                 Map<M, Set<GlideString>> subscriptions, M mode, GlideString channelOrPattern) {
@@ -113,5 +139,16 @@ public abstract class BaseSubscriptionConfiguration {
             this.callback = Optional.ofNullable(callback);
             return self();
         }
+
+        /**
+         * Set a callback to be called when the subscriptions are restored after a reconnection.
+         *
+         * @param resubscribedCallback The {@link #resubscribedCallback}. This can be null to unset
+         *     the callback.
+         */
+        public B resubscribedCallback(ResubscribedCallback resubscribedCallback) {
+            this.resubscribedCallback = Optional.ofNullable(resubscribedCallback);
+            return self();
+        }
     }
 }
@@ -73,8 +73,9 @@ public final class ClusterSubscriptionConfiguration extends BaseSubscriptionConf
     private ClusterSubscriptionConfiguration(
             Optional<MessageCallback> callback,
             Optional<Object> context,
-            Map<PubSubClusterChannelMode, Set<GlideString>> subscriptions) {
-        super(callback, context);
+            Map<PubSubClusterChannelMode, Set<GlideString>> subscriptions,
+            Optional<ResubscribedCallback> resubscribedCallback) {
+        super(callback, context, resubscribedCallback);
         Map<PubSubClusterChannelMode, Set<GlideString>> unmodifiableMap = new HashMap<>();
         for (Map.Entry<PubSubClusterChannelMode, Set<GlideString>> entry : subscriptions.entrySet()) {
             unmodifiableMap.put(
@@ -146,7 +147,8 @@ public final class ClusterSubscriptionConfiguration extends BaseSubscriptionConf
 
         @Override
         public ClusterSubscriptionConfiguration build() {
-            return new ClusterSubscriptionConfiguration(callback, context, subscriptions);
+            return new ClusterSubscriptionConfiguration(
+                    callback, context, subscriptions, resubscribedCallback);
         }
     }
 }
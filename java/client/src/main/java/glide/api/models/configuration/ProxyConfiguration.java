@@ -0,0 +1,50 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.models.configuration;
+
+import lombok.Builder;
+import lombok.Getter;
+import lombok.NonNull;
+import lombok.ToString;
+
+/**
+ * A proxy that the client's connections are tunneled through, for servers that are only reachable
+ * through a bastion host. TLS, when enabled, is negotiated with the server inside the tunnel.
+ *
+ * @example
+ *     <pre>{@code
+ * ProxyConfiguration proxy = ProxyConfiguration.builder()
+ *     .type(ProxyConfiguration.ProxyType.SOCKS5)
+ *     .host("bastion.example.com")
+ *     .port(1080)
+ *     .username("user")
+ *     .password("secret")
+ *     .build();
+ * }</pre>
+ */
+@Getter
+@Builder
+@ToString(exclude = "password")
+public class ProxyConfiguration {
+    /** The protocols of the supported proxies. */
+    public enum ProxyType {
+        /** A SOCKS5 proxy, authenticated with a username and password if they're set. */
+        SOCKS5,
+        /** An HTTP proxy accepting <code>CONNECT</code>, with basic authentication if it's set. */
+        HTTP_CONNECT
+    }
+
+    /** The protocol of the proxy. Defaults to {@link ProxyType#SOCKS5}. */
+    @Builder.Default private final ProxyType type = ProxyType.SOCKS5;
+
+    /** The host name or IP address of the proxy. */
+    @NonNull private final String host;
+
+    /** The port of the proxy. */
+    private final int port;
+
+    /** The username of the proxy, if it requires authentication. */
+    private final String username;
+
+    /** The password of the proxy, if it requires authentication. */
+    private final String password;
+}
@@ -65,7 +65,15 @@ public final class StandaloneSubscriptionConfiguration extends BaseSubscriptionC
             Optional<MessageCallback> callback,
             Optional<Object> context,
             Map<PubSubChannelMode, Set<GlideString>> subscriptions) {
-        super(callback, context);
+        this(callback, context, subscriptions, Optional.empty());
+    }
+
+    public StandaloneSubscriptionConfiguration(
+            Optional<MessageCallback> callback,
+            Optional<Object> context,
+            Map<PubSubChannelMode, Set<GlideString>> subscriptions,
+            Optional<ResubscribedCallback> resubscribedCallback) {
+        super(callback, context, resubscribedCallback);
         Map<PubSubChannelMode, Set<GlideString>> unmodifiableMap = new LinkedHashMap<>();
         for (Map.Entry<PubSubChannelMode, Set<GlideString>> entry : subscriptions.entrySet()) {
             unmodifiableMap.put(
@@ -127,7 +135,8 @@ public final class StandaloneSubscriptionConfiguration extends BaseSubscriptionC
 
         @Override
         public StandaloneSubscriptionConfiguration build() {
-            return new StandaloneSubscriptionConfiguration(callback, context, subscriptions);
+            return new StandaloneSubscriptionConfiguration(
+                    callback, context, subscriptions, resubscribedCallback);
         }
     }
 }
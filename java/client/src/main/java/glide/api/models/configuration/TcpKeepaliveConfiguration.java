@@ -0,0 +1,33 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.models.configuration;
+
+import lombok.Builder;
+import lombok.Getter;
+import lombok.ToString;
+
+/**
+ * TCP keepalive settings of the client's connections. Settings that aren't set use the system
+ * defaults.
+ *
+ * @example
+ *     <pre>{@code
+ * TcpKeepaliveConfiguration keepalive = TcpKeepaliveConfiguration.builder()
+ *     .idleMs(30000)
+ *     .intervalMs(5000)
+ *     .count(3)
+ *     .build();
+ * }</pre>
+ */
+@Getter
+@Builder
+@ToString
+public class TcpKeepaliveConfiguration {
+    /** How long a connection stays idle, in milliseconds, before the first keepalive probe. */
+    private final Integer idleMs;
+
+    /** The time between unacknowledged keepalive probes, in milliseconds. */
+    private final Integer intervalMs;
+
+    /** The number of unacknowledged keepalive probes after which the connection is dropped. */
+    private final Integer count;
+}
@@ -52,6 +52,21 @@ public class TlsAdvancedConfiguration {
      */
     @Builder.Default private final byte[] rootCertificates = null;
 
+    /**
+     * Client certificate data for mutual TLS (mTLS) connections.
+     *
+     * <p>Must be provided together with {@link #clientKey}. The certificate data should be in PEM
+     * format as a byte array.
+     */
+    @Builder.Default private final byte[] clientCertificate = null;
+
+    /**
+     * Private key data matching {@link #clientCertificate}, used for mutual TLS (mTLS) connections.
+     *
+     * <p>The key data should be in PEM format as a byte array.
+     */
+    @Builder.Default private final byte[] clientKey = null;
+
     /**
      * Create TlsAdvancedConfiguration from a Java KeyStore file.
      *
@@ -0,0 +1,12 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.models.exceptions;
+
+/**
+ * Deadline exceeded before send error: Errors that are thrown when the deadline of a request passed
+ * while it was queued, so it was dropped without being sent to the server.
+ */
+public class DeadlineExceededBeforeSendException extends TimeoutException {
+    public DeadlineExceededBeforeSendException(String message) {
+        super(message);
+    }
+}
@@ -3,7 +3,53 @@ package glide.api.models.exceptions;
 
 /** Glide client error: Base class for errors. */
 public class GlideException extends RuntimeException {
+    /** The error code, such as <code>MOVED</code>, <code>CLUSTERDOWN</code> or <code>OOM</code>. */
+    private String errorCode;
+
+    /** Whether the request may succeed when it's retried. */
+    private boolean retryable;
+
+    /** The address of the node the error originated from. */
+    private String nodeAddress;
+
     public GlideException(String message) {
         super(message);
     }
+
+    /**
+     * Returns the error code, such as <code>MOVED</code>, <code>CLUSTERDOWN</code> or <code>OOM
+     * </code>, or <code>null</code> if the error has none.
+     */
+    public String getErrorCode() {
+        return errorCode;
+    }
+
+    /** Returns whether the request may succeed when it's retried. */
+    public boolean isRetryable() {
+        return retryable;
+    }
+
+    /**
+     * Returns the address of the node the error originated from, or <code>null</code> if it isn't
+     * known.
+     */
+    public String getNodeAddress() {
+        return nodeAddress;
+    }
+
+    /**
+     * Attaches the details of the error reported by the native layer.
+     *
+     * @param errorCode the error code, or <code>null</code> if the error has none
+     * @param retryable whether the request may succeed when it's retried
+     * @param nodeAddress the address of the node the error originated from, or <code>null</code>
+     * @return this exception
+     */
+    public GlideException withErrorDetails(
+            String errorCode, boolean retryable, String nodeAddress) {
+        this.errorCode = errorCode;
+        this.retryable = retryable;
+        this.nodeAddress = nodeAddress;
+        return this;
+    }
 }
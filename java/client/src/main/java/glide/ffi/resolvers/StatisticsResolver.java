@@ -11,4 +11,16 @@ public class StatisticsResolver {
 
     /** Return the internal statistics Map object */
     public static native LinkedHashMap getStatistics();
+
+    /** Enable or disable the collection of per-command latency histograms */
+    public static native void setCommandLatencyHistogramEnabled(boolean enabled);
+
+    /**
+     * Return the count and the p50, p95 and p99 latencies in microseconds of the given command
+     * type. Throws an exception if the command type is unknown or unsupported.
+     */
+    public static native LinkedHashMap getCommandLatencyPercentiles(int requestType);
+
+    /** Clear the latency histograms of all command types */
+    public static native void resetCommandLatencyHistograms();
 }
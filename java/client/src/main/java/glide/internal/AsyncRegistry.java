@@ -2,7 +2,9 @@
 package glide.internal;
 
 import glide.api.models.exceptions.ClosingException;
+import glide.api.models.exceptions.DeadlineExceededBeforeSendException;
 import glide.api.models.exceptions.ExecAbortException;
+import glide.api.models.exceptions.GlideException;
 import glide.api.models.exceptions.RequestException;
 import glide.api.models.exceptions.TimeoutException;
 import java.util.concurrent.CompletableFuture;
@@ -14,6 +16,7 @@ import java.util.concurrent.TimeUnit;
 import java.util.concurrent.atomic.AtomicBoolean;
 import java.util.concurrent.atomic.AtomicInteger;
 import java.util.concurrent.atomic.AtomicLong;
+import java.util.function.Consumer;
 
 /**
  * Async registry for correlating native callbacks with Java {@link CompletableFuture}s.
@@ -41,6 +44,10 @@ public final class AsyncRegistry {
     private static final ConcurrentHashMap<Long, ScheduledFuture<?>> timeoutTasks =
             new ConcurrentHashMap<>();
 
+    /** Consumers of the chunks of streamed responses, mapped by correlation ID. */
+    private static final ConcurrentHashMap<Long, Consumer<Object[]>> partialResultConsumers =
+            new ConcurrentHashMap<>();
+
     /**
      * Per-client inflight request counters. Maps client handle to the number of active requests for
      * that client.
@@ -168,6 +175,36 @@ public final class AsyncRegistry {
         return correlationId;
     }
 
+    /**
+     * Register the future of a streamed response, whose chunks are passed to {@code
+     * onPartialResult} before the future is completed. See {@link #register} for the other
+     * parameters.
+     *
+     * @param onPartialResult the consumer of the chunks of the response, called on a native callback
+     *     thread. If it throws, the future is completed with the exception and the remaining chunks
+     *     are dropped.
+     * @return correlation ID for native callback, or 0 if shutdown is in progress
+     */
+    public static <T> long registerStreaming(
+            CompletableFuture<T> future,
+            Consumer<Object[]> onPartialResult,
+            int maxInflightRequests,
+            long clientHandle,
+            long timeoutMillis) {
+        if (onPartialResult == null) {
+            throw new IllegalArgumentException("Partial result consumer cannot be null");
+        }
+        long correlationId = register(future, maxInflightRequests, clientHandle, timeoutMillis);
+        if (correlationId != 0L) {
+            partialResultConsumers.put(correlationId, onPartialResult);
+            // The future may have completed before the consumer was stored.
+            if (future.isDone()) {
+                partialResultConsumers.remove(correlationId);
+            }
+        }
+        return correlationId;
+    }
+
     /** Enforce per-client inflight limit, throwing RequestException if exceeded. */
     private static void enforceInflightLimit(long clientHandle, int maxInflightRequests) {
         clientInflightCounts.compute(
@@ -214,6 +251,7 @@ public final class AsyncRegistry {
                 (result, error) -> {
                     // Atomic cleanup - no race conditions
                     activeFutures.remove(correlationId);
+                    partialResultConsumers.remove(correlationId);
 
                     // Cancel the timeout task if it hasn't fired yet
                     // Using cancel(false) to avoid interrupting the scheduler thread
@@ -257,9 +295,34 @@ public final class AsyncRegistry {
         return future != null && future.complete(result);
     }
 
+    /**
+     * Deliver a chunk of a streamed response to the consumer registered with {@link
+     * #registerStreaming}. Returns false if the future is already completed, or if the consumer
+     * threw, in which case the future is completed with the exception.
+     *
+     * @param correlationId the correlation ID from registerStreaming()
+     * @param chunk the next elements of the response
+     * @return true if more chunks are accepted
+     */
+    public static boolean onPartialResult(long correlationId, Object[] chunk) {
+        CompletableFuture<Object> future = activeFutures.get(correlationId);
+        Consumer<Object[]> consumer = partialResultConsumers.get(correlationId);
+        if (future == null || consumer == null || future.isDone()) {
+            return false;
+        }
+        try {
+            consumer.accept(chunk);
+            return true;
+        } catch (RuntimeException e) {
+            future.completeExceptionally(e);
+            return false;
+        }
+    }
+
     /**
      * Complete with error using a structured error code from native layer. Codes map to glide-core
-     * RequestErrorType: 0=Unspecified, 1=ExecAbort, 2=Timeout, 3=Disconnect.
+     * RequestErrorType: 0=Unspecified, 1=ExecAbort, 2=Timeout, 3=Disconnect,
+     * 7=DeadlineExceededBeforeSend.
      *
      * @param correlationId the correlation ID from register()
      * @param errorTypeCode error type code from native layer
@@ -272,13 +335,45 @@ public final class AsyncRegistry {
         if (future == null) {
             return false;
         }
+        return future.completeExceptionally(createException(errorTypeCode, errorMessage));
+    }
 
+    /**
+     * Complete with error using a structured error code and the details of a server error from the
+     * native layer, which are attached to the exception.
+     *
+     * @param correlationId the correlation ID from register()
+     * @param errorTypeCode error type code from native layer
+     * @param errorMessage error message from native layer
+     * @param errorCode the error code, such as MOVED or OOM, or null if the error has none
+     * @param retryable whether the request may succeed when it's retried
+     * @param nodeAddress the address of the node the error originated from, or null if unknown
+     * @return true if completed, false if already done
+     */
+    public static boolean completeCallbackWithErrorDetails(
+            long correlationId,
+            int errorTypeCode,
+            String errorMessage,
+            String errorCode,
+            boolean retryable,
+            String nodeAddress) {
+        CompletableFuture<Object> future = activeFutures.get(correlationId);
+        if (future == null) {
+            return false;
+        }
+        GlideException ex =
+                createException(errorTypeCode, errorMessage)
+                        .withErrorDetails(errorCode, retryable, nodeAddress);
+        return future.completeExceptionally(ex);
+    }
+
+    private static GlideException createException(int errorTypeCode, String errorMessage) {
         String msg =
                 (errorMessage == null || errorMessage.trim().isEmpty())
                         ? "Unknown error from native code"
                         : errorMessage;
 
-        RuntimeException ex;
+        GlideException ex;
         switch (errorTypeCode) {
             case 2:
                 ex = new TimeoutException(msg);
@@ -286,6 +381,9 @@ public final class AsyncRegistry {
             case 3:
                 ex = new ClosingException(msg);
                 break;
+            case 7:
+                ex = new DeadlineExceededBeforeSendException(msg);
+                break;
             case 1:
                 ex = new ExecAbortException(msg);
                 break;
@@ -293,8 +391,7 @@ public final class AsyncRegistry {
                 ex = new RequestException(msg);
                 break;
         }
-
-        return future.completeExceptionally(ex);
+        return ex;
     }
 
     /** Get current pending operation count. */
@@ -315,6 +412,7 @@ public final class AsyncRegistry {
         // Cancel user futures with interrupt (may be blocked waiting)
         activeFutures.values().forEach(future -> future.cancel(true));
         activeFutures.clear();
+        partialResultConsumers.clear();
         clientInflightCounts.clear();
 
         // Shutdown the timeout scheduler
@@ -339,6 +437,7 @@ public final class AsyncRegistry {
                         : errorMessage;
         activeFutures.forEach((id, future) -> future.completeExceptionally(new ClosingException(msg)));
         activeFutures.clear();
+        partialResultConsumers.clear();
 
         timeoutTasks.values().forEach(task -> task.cancel(false));
         timeoutTasks.clear();
@@ -359,6 +458,7 @@ public final class AsyncRegistry {
         timeoutTasks.values().forEach(task -> task.cancel(false));
         timeoutTasks.clear();
         activeFutures.clear();
+        partialResultConsumers.clear();
         clientInflightCounts.clear();
         nextId.set(1);
     }
@@ -8,10 +8,16 @@ import glide.ffi.resolvers.NativeUtils;
 import java.lang.ref.PhantomReference;
 import java.lang.ref.ReferenceQueue;
 import java.lang.ref.WeakReference;
+import java.nio.ByteBuffer;
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
 import java.util.concurrent.ConcurrentHashMap;
 import java.util.concurrent.atomic.AtomicBoolean;
 import java.util.concurrent.atomic.AtomicLong;
+import java.util.function.Consumer;
 
 /**
  * GLIDE core client transport. Provides direct native access to glide-core with all routing and
@@ -73,6 +79,71 @@ public class GlideCoreClient implements AutoCloseable {
 
     private static native void freeNativeBuffer(long id);
 
+    private static native void setDirectBufferThreshold(long thresholdBytes);
+
+    private static native long nativeBufferHandle(java.nio.ByteBuffer buffer);
+
+    /**
+     * Frees the native memory backing a direct buffer response right away, instead of when the
+     * buffer is garbage collected. The buffer, and any view of it, must not be accessed afterwards.
+     *
+     * @param handle The handle of the buffer, as returned by {@link
+     *     #getNativeBufferHandle(java.nio.ByteBuffer)}.
+     * @return <code>false</code> if the buffer was already released.
+     */
+    public static native boolean releaseNativeBuffer(long handle);
+
+    /** The size in bytes above which responses are delivered in native memory by default. */
+    public static final long DEFAULT_DIRECT_BUFFER_THRESHOLD = 16 * 1024;
+
+    private static volatile boolean directBufferResponses = false;
+
+    /**
+     * Configures the delivery of large bulk string responses as {@link java.nio.ByteBuffer}s backed
+     * by native memory.
+     *
+     * <p>Responses larger than <code>thresholdBytes</code> are always handed to Java in native
+     * memory. When direct buffer responses are enabled, binary bulk string responses, such as the
+     * response of a binary <code>customCommand</code>, are returned as a read-only direct buffer
+     * instead of being copied to a <code>byte[]</code>, which saves a full copy of multi-megabyte
+     * values. The native memory is freed when the buffer is garbage collected, or earlier with {@link
+     * #releaseNativeBuffer(long)}.
+     *
+     * <p>The configuration applies to all the clients of the process.
+     *
+     * @param enabled Whether binary bulk string responses are returned as direct buffers.
+     * @param thresholdBytes The size in bytes above which responses are delivered in native memory.
+     */
+    public static void configureDirectBufferResponses(boolean enabled, long thresholdBytes) {
+        if (thresholdBytes < 0) {
+            throw new IllegalArgumentException("The direct buffer threshold must not be negative");
+        }
+        setDirectBufferThreshold(thresholdBytes);
+        directBufferResponses = enabled;
+    }
+
+    /**
+     * @return Whether binary bulk string responses are returned as direct buffers.
+     * @see #configureDirectBufferResponses(boolean, long)
+     */
+    public static boolean isDirectBufferResponsesEnabled() {
+        return directBufferResponses;
+    }
+
+    /**
+     * Returns the handle of the native memory backing a direct buffer response, to release it with
+     * {@link #releaseNativeBuffer(long)}.
+     *
+     * @param buffer A direct buffer returned as a response, or a view of it.
+     * @return The handle, or <code>0</code> if the buffer isn't backed by live native memory.
+     */
+    public static long getNativeBufferHandle(java.nio.ByteBuffer buffer) {
+        if (buffer == null || !buffer.isDirect()) {
+            return 0;
+        }
+        return nativeBufferHandle(buffer);
+    }
+
     private static final ConcurrentHashMap<Long, WeakReference<BaseClient>> clients =
             new ConcurrentHashMap<>();
 
@@ -104,6 +175,16 @@ public class GlideCoreClient implements AutoCloseable {
         }
     }
 
+    // Called by native after the PubSub subscriptions were restored on a new connection
+    private static void onNativeResubscribed(
+            long handle, long disconnectedForMillis, int[] modes, byte[][] channels) {
+        WeakReference<BaseClient> ref = clients.get(handle);
+        if (ref != null) {
+            BaseClient c = ref.get();
+            if (c != null) c.__notifyResubscribed(disconnectedForMillis, modes, channels);
+        }
+    }
+
     // Register cleanup action to free native memory when the given ByteBuffer is GC'd
     static void registerNativeBufferCleaner(java.nio.ByteBuffer buffer, long id) {
         if (buffer == null || id == 0) return;
@@ -187,19 +268,6 @@ public class GlideCoreClient implements AutoCloseable {
      * CommandManager)
      */
     public CompletableFuture<Object> executeBinaryCommandAsync(byte[] requestBytes) {
-        return executeBinaryCommandAsyncInternal(requestBytes, this.requestTimeoutMillis);
-    }
-
-    /**
-     * Execute binary command asynchronously without Java-side timeout. Used for blocking commands
-     * (BLPOP, BRPOP, etc.) where the command has its own timeout that Rust handles.
-     */
-    public CompletableFuture<Object> executeBinaryCommandAsyncNoTimeout(byte[] requestBytes) {
-        return executeBinaryCommandAsyncInternal(requestBytes, 0);
-    }
-
-    private CompletableFuture<Object> executeBinaryCommandAsyncInternal(
-            byte[] requestBytes, long timeoutMs) {
         try {
             long handle = nativeClientHandle.get();
             if (handle == 0) {
@@ -213,7 +281,9 @@ public class GlideCoreClient implements AutoCloseable {
             CompletableFuture<Object> future = new CompletableFuture<>();
             long correlationId;
             try {
-                correlationId = AsyncRegistry.register(future, this.maxInflightRequests, handle, timeoutMs);
+                correlationId =
+                        AsyncRegistry.register(
+                                future, this.maxInflightRequests, handle, this.requestTimeoutMillis);
             } catch (glide.api.models.exceptions.RequestException e) {
                 future.completeExceptionally(e);
                 return future;
@@ -239,11 +309,102 @@ public class GlideCoreClient implements AutoCloseable {
     }
 
     /**
-     * Execute command asynchronously without Java-side timeout. Used for blocking commands (BLPOP,
-     * BRPOP, etc.) where the command has its own timeout that Rust handles.
+     * Execute many independent commands with a single native call, to amortize the cost of crossing
+     * into the native layer for small commands. The commands aren't pipelined or atomic: each is
+     * executed concurrently and completes its own future, in the order of {@code requestsBytes}.
+     * The response of each request is decoded as UTF-8 strings if its flag in {@code
+     * expectUtf8Responses} is set, and as binary otherwise.
      */
-    public CompletableFuture<Object> executeCommandAsyncNoTimeout(byte[] requestBytes) {
-        return executeCommandAsyncInternal(requestBytes, 0);
+    public List<CompletableFuture<Object>> executeCommandsBulkAsync(
+            byte[][] requestsBytes, boolean[] expectUtf8Responses) {
+        List<CompletableFuture<Object>> futures = new ArrayList<>(requestsBytes.length);
+        long handle = nativeClientHandle.get();
+        if (handle == 0) {
+            for (int i = 0; i < requestsBytes.length; i++) {
+                CompletableFuture<Object> future = new CompletableFuture<>();
+                future.completeExceptionally(
+                        new glide.api.models.exceptions.ClosingException("Client is closed"));
+                futures.add(future);
+            }
+            return futures;
+        }
+
+        long[] correlationIds = new long[requestsBytes.length];
+        byte[][] submittedRequests = new byte[requestsBytes.length][];
+        boolean[] submittedFlags = new boolean[requestsBytes.length];
+        int submitted = 0;
+        int framedLength = 0;
+        for (int i = 0; i < requestsBytes.length; i++) {
+            byte[] requestBytes = requestsBytes[i];
+            CompletableFuture<Object> future = new CompletableFuture<>();
+            futures.add(future);
+            try {
+                correlationIds[submitted] =
+                        AsyncRegistry.register(
+                                future,
+                                this.maxInflightRequests,
+                                handle,
+                                this.requestTimeoutMillis);
+            } catch (glide.api.models.exceptions.RequestException e) {
+                // Only this request is rejected, the others are still submitted.
+                future.completeExceptionally(e);
+                continue;
+            }
+            submittedFlags[submitted] = expectUtf8Responses[i];
+            submittedRequests[submitted++] = requestBytes;
+            framedLength += Integer.BYTES + requestBytes.length;
+        }
+        if (submitted == 0) {
+            return futures;
+        }
+
+        ByteBuffer framed = ByteBuffer.allocate(framedLength);
+        for (int i = 0; i < submitted; i++) {
+            framed.putInt(submittedRequests[i].length).put(submittedRequests[i]);
+        }
+        GlideNativeBridge.executeCommandsBulkAsync(
+                handle,
+                framed.array(),
+                Arrays.copyOf(correlationIds, submitted),
+                Arrays.copyOf(submittedFlags, submitted));
+        return futures;
+    }
+
+    /**
+     * Execute a blocking command (BLPOP, BRPOP, etc.) asynchronously through a connection of its own,
+     * so it doesn't delay the other commands of the client. There's no Java-side timeout, since the
+     * command has its own timeout that Rust handles.
+     */
+    public CompletableFuture<Object> executeBlockingCommandAsync(
+            byte[] requestBytes, boolean expectUtf8Response) {
+        try {
+            long handle = nativeClientHandle.get();
+            if (handle == 0) {
+                CompletableFuture<Object> future = new CompletableFuture<>();
+                future.completeExceptionally(
+                        new glide.api.models.exceptions.ClosingException("Client is closed"));
+                return future;
+            }
+
+            CompletableFuture<Object> future = new CompletableFuture<>();
+            long correlationId;
+            try {
+                correlationId = AsyncRegistry.register(future, this.maxInflightRequests, handle, 0);
+            } catch (glide.api.models.exceptions.RequestException e) {
+                future.completeExceptionally(e);
+                return future;
+            }
+
+            GlideNativeBridge.executeBlockingCommandAsync(
+                    handle, requestBytes, expectUtf8Response, correlationId);
+
+            return future;
+
+        } catch (Exception e) {
+            CompletableFuture<Object> future = new CompletableFuture<>();
+            future.completeExceptionally(e);
+            return future;
+        }
     }
 
     private CompletableFuture<Object> executeCommandAsyncInternal(
@@ -279,6 +440,54 @@ public class GlideCoreClient implements AutoCloseable {
         }
     }
 
+    /**
+     * Execute command asynchronously, passing the elements of a large array, set or map reply to
+     * {@code onPartialResult} in chunks of up to {@code chunkSize} elements, instead of allocating
+     * the whole reply as a single array. Map entries are passed as consecutive keys and values. The
+     * returned future completes with the total number of elements once all the chunks were passed,
+     * or with the reply itself if it isn't an array, set or map.
+     */
+    public CompletableFuture<Object> executeStreamingCommandAsync(
+            byte[] requestBytes,
+            boolean expectUtf8Response,
+            int chunkSize,
+            java.util.function.Consumer<Object[]> onPartialResult) {
+        try {
+            long handle = nativeClientHandle.get();
+            if (handle == 0) {
+                CompletableFuture<Object> future = new CompletableFuture<>();
+                future.completeExceptionally(
+                        new glide.api.models.exceptions.ClosingException("Client is closed"));
+                return future;
+            }
+
+            CompletableFuture<Object> future = new CompletableFuture<>();
+            long correlationId;
+            try {
+                correlationId =
+                        AsyncRegistry.registerStreaming(
+                                future,
+                                onPartialResult,
+                                this.maxInflightRequests,
+                                handle,
+                                this.requestTimeoutMillis);
+            } catch (glide.api.models.exceptions.RequestException e) {
+                future.completeExceptionally(e);
+                return future;
+            }
+
+            GlideNativeBridge.executeStreamingCommandAsync(
+                    handle, requestBytes, expectUtf8Response, chunkSize, correlationId);
+
+            return future;
+
+        } catch (Exception e) {
+            CompletableFuture<Object> future = new CompletableFuture<>();
+            future.completeExceptionally(e);
+            return future;
+        }
+    }
+
     /** Execute batch asynchronously using raw protobuf bytes. */
     public CompletableFuture<Object> executeBatchAsync(
             byte[] batchRequestBytes, boolean expectUtf8Response, Integer timeoutOverrideMs) {
@@ -409,6 +618,113 @@ public class GlideCoreClient implements AutoCloseable {
         return future;
     }
 
+    /**
+     * Acquire a distributed lock on {@code key} for {@code ttlMillis}. The returned future
+     * completes with the token of the lock, needed to release or extend it, or null if the lock is
+     * held by another owner. With {@code autoExtend}, the lock is extended until it's released, and
+     * {@code onLockLost} is called with the reason if the lock is lost before.
+     */
+    public CompletableFuture<String> acquireLock(
+            byte[] key, long ttlMillis, boolean autoExtend, Consumer<Throwable> onLockLost) {
+        CompletableFuture<String> future = new CompletableFuture<>();
+
+        long handle = nativeClientHandle.get();
+        if (handle == 0) {
+            future.completeExceptionally(
+                    new glide.api.models.exceptions.ClosingException("Client is closed"));
+            return future;
+        }
+
+        long correlationId;
+        long lockLostCorrelationId = 0;
+        CompletableFuture<Object> lockLost = null;
+        try {
+            if (autoExtend && onLockLost != null) {
+                // Completed only when the lock is released or lost, so it isn't subject to the
+                // request timeout or the inflight limit.
+                lockLost = new CompletableFuture<>();
+                lockLost.whenComplete(
+                        (ignored, error) -> {
+                            if (error != null) {
+                                onLockLost.accept(error);
+                            }
+                        });
+                lockLostCorrelationId = AsyncRegistry.register(lockLost, 0, handle, 0);
+            }
+            correlationId =
+                    AsyncRegistry.register(
+                            future, this.maxInflightRequests, handle, this.requestTimeoutMillis);
+        } catch (glide.api.models.exceptions.RequestException e) {
+            if (lockLost != null) {
+                lockLost.complete(null);
+            }
+            future.completeExceptionally(e);
+            return future;
+        }
+
+        GlideNativeBridge.acquireLockAsync(
+                handle, key, ttlMillis, autoExtend, lockLostCorrelationId, correlationId);
+        return future;
+    }
+
+    /**
+     * Release the distributed lock on {@code key} if it's held with {@code token}. The returned
+     * future completes with whether the lock was released.
+     */
+    public CompletableFuture<Boolean> releaseLock(byte[] key, String token) {
+        CompletableFuture<Boolean> future = new CompletableFuture<>();
+
+        long handle = nativeClientHandle.get();
+        if (handle == 0) {
+            future.completeExceptionally(
+                    new glide.api.models.exceptions.ClosingException("Client is closed"));
+            return future;
+        }
+
+        long correlationId;
+        try {
+            correlationId =
+                    AsyncRegistry.register(
+                            future, this.maxInflightRequests, handle, this.requestTimeoutMillis);
+        } catch (glide.api.models.exceptions.RequestException e) {
+            future.completeExceptionally(e);
+            return future;
+        }
+
+        GlideNativeBridge.releaseLockAsync(
+                handle, key, token.getBytes(StandardCharsets.UTF_8), correlationId);
+        return future;
+    }
+
+    /**
+     * Set the expiration of the distributed lock on {@code key} to {@code ttlMillis} if it's held
+     * with {@code token}. The returned future completes with whether the lock was extended.
+     */
+    public CompletableFuture<Boolean> extendLock(byte[] key, String token, long ttlMillis) {
+        CompletableFuture<Boolean> future = new CompletableFuture<>();
+
+        long handle = nativeClientHandle.get();
+        if (handle == 0) {
+            future.completeExceptionally(
+                    new glide.api.models.exceptions.ClosingException("Client is closed"));
+            return future;
+        }
+
+        long correlationId;
+        try {
+            correlationId =
+                    AsyncRegistry.register(
+                            future, this.maxInflightRequests, handle, this.requestTimeoutMillis);
+        } catch (glide.api.models.exceptions.RequestException e) {
+            future.completeExceptionally(e);
+            return future;
+        }
+
+        GlideNativeBridge.extendLockAsync(
+                handle, key, token.getBytes(StandardCharsets.UTF_8), ttlMillis, correlationId);
+        return future;
+    }
+
     /** Execute script via native invoke_script path */
     public CompletableFuture<Object> executeScriptAsync(
             String hash,
@@ -417,7 +733,8 @@ public class GlideCoreClient implements AutoCloseable {
             boolean hasRoute,
             int routeType,
             String routeParam,
-            boolean expectUtf8Response) {
+            boolean expectUtf8Response,
+            boolean readOnly) {
         try {
             long handle = nativeClientHandle.get();
             if (handle == 0) {
@@ -447,7 +764,8 @@ public class GlideCoreClient implements AutoCloseable {
                     hasRoute,
                     routeType,
                     routeParam,
-                    expectUtf8Response);
+                    expectUtf8Response,
+                    readOnly);
 
             return future;
 
@@ -3,6 +3,7 @@ package glide.internal;
 
 import glide.api.logging.Logger;
 import glide.ffi.resolvers.NativeUtils;
+import java.util.Map;
 
 /**
  * Native bridge for the Valkey client. Methods expose a handle-based API for safe cross-language
@@ -26,14 +27,64 @@ public class GlideNativeBridge {
     /** Create a new native client instance */
     public static native long createClient(byte[] connectionRequestBytes);
 
+    /**
+     * Create a new native client instance without blocking the calling thread. The callback is
+     * completed with the handle of the client once it's connected, or with the connection error.
+     */
+    public static native void createClientAsync(byte[] connectionRequestBytes, long callbackId);
+
+    /**
+     * Register a native client that's created on its first command, so its configuration may still
+     * be changed with {@link #updatePendingConfig}. Returns the handle of the client, or 0 if the
+     * connection request is invalid.
+     */
+    public static native long registerLazyClient(byte[] connectionRequestBytes);
+
+    /**
+     * Replace the configuration of a client registered with {@link #registerLazyClient} before its
+     * first command. Throws a {@link RuntimeException} if the client was already created, or if the
+     * configuration is invalid or changes whether the client is a cluster client.
+     */
+    public static native void updatePendingConfig(long handle, byte[] configBytes);
+
     /** Execute command asynchronously */
     public static native void executeCommandAsync(
             long clientPtr, byte[] requestBytes, long callbackId);
 
+    /**
+     * Execute many independent commands in a single call. {@code framedRequests} holds each request
+     * prefixed with its length as a big-endian int, {@code callbackIds} the callback of each
+     * request, and {@code expectUtf8Responses} whether the response of each request is decoded as
+     * UTF-8 strings, in the same order. The commands aren't pipelined, and each completes its own
+     * callback.
+     */
+    public static native void executeCommandsBulkAsync(
+            long clientPtr,
+            byte[] framedRequests,
+            long[] callbackIds,
+            boolean[] expectUtf8Responses);
+
     /** Execute binary command with mixed String/byte[] arguments asynchronously */
     public static native void executeBinaryCommandAsync(
             long clientPtr, byte[] requestBytes, long callbackId);
 
+    /**
+     * Execute command asynchronously, delivering the elements of an array, set or map reply to {@link
+     * AsyncRegistry#onPartialResult} in chunks of up to {@code chunkSize} elements. Map entries are
+     * delivered as consecutive keys and values. The callback is then completed with the total number
+     * of elements as a {@code Long}, or with the reply itself if it isn't an array, set or map.
+     */
+    public static native void executeStreamingCommandAsync(
+            long clientPtr,
+            byte[] requestBytes,
+            boolean expectUtf8Response,
+            int chunkSize,
+            long callbackId);
+
+    /** Execute a blocking command asynchronously through a connection of its own */
+    public static native void executeBlockingCommandAsync(
+            long clientPtr, byte[] requestBytes, boolean expectUtf8Response, long callbackId);
+
     /** Execute batch (pipeline/transaction) asynchronously */
     public static native void executeBatchAsync(
             long clientPtr, byte[] batchRequestBytes, boolean expectUtf8Response, long callbackId);
@@ -45,15 +96,48 @@ public class GlideNativeBridge {
     /** Refresh the IAM authentication token. */
     public static native void refreshIamToken(long clientPtr, long callbackId);
 
+    /**
+     * Acquire a distributed lock on a key. The callback is completed with the token of the lock, or
+     * null if the lock is held by another owner. When {@code lockLostCallbackId} isn't 0, it's
+     * completed with an error if the automatically extended lock is lost, and with null once the
+     * lock is released, or right away if the lock isn't extended automatically.
+     */
+    public static native void acquireLockAsync(
+            long clientPtr,
+            byte[] key,
+            long ttlMillis,
+            boolean autoExtend,
+            long lockLostCallbackId,
+            long callbackId);
+
+    /** Release a distributed lock if it's held with the token, stopping its automatic extension. */
+    public static native void releaseLockAsync(
+            long clientPtr, byte[] key, byte[] token, long callbackId);
+
+    /** Set the expiration of a distributed lock if it's held with the token. */
+    public static native void extendLockAsync(
+            long clientPtr, byte[] key, byte[] token, long ttlMillis, long callbackId);
+
     /** Check if the native client is connected */
     public static native boolean isConnected(long clientPtr);
 
     /** Get client information from native layer */
     public static native String getClientInfo(long clientPtr);
 
+    /** Get the command, batch, error and latency statistics collected for a native client */
+    public static native Map<String, String> getClientStatistics(long clientPtr);
+
     /** Close and release a native client */
     public static native void closeClient(long clientPtr);
 
+    /**
+     * Shut the native layer down before unloading the native library: close every native client,
+     * shut the runtime down, stop the callback workers and fail the pending futures. Calling it again
+     * is a no-op. When called from a future's completion, the shutdown finishes in the background,
+     * and a later call waits for it.
+     */
+    public static native void shutdownNative();
+
     /** Execute script asynchronously using glide-core's invoke_script */
     public static native void executeScriptAsync(
             long clientPtr,
@@ -64,7 +148,8 @@ public class GlideNativeBridge {
             boolean hasRoute,
             int routeType,
             String routeParam,
-            boolean expectUtf8Response);
+            boolean expectUtf8Response,
+            boolean readOnly);
 
     /** Get glide-core default connection timeout in milliseconds */
     public static native long getGlideCoreDefaultConnectionTimeoutMs();
@@ -36,11 +36,15 @@ import glide.utils.Java8Utils;
 import java.math.BigInteger;
 import java.nio.ByteBuffer;
 import java.nio.ByteOrder;
+import java.util.ArrayList;
+import java.util.Arrays;
 import java.util.Collections;
 import java.util.List;
 import java.util.Optional;
 import java.util.Set;
 import java.util.concurrent.CompletableFuture;
+import java.util.function.IntFunction;
+import java.util.function.IntPredicate;
 import lombok.NonNull;
 import lombok.RequiredArgsConstructor;
 import response.ResponseOuterClass.ConstantResponse;
@@ -72,6 +76,19 @@ public class CommandManager {
     /** Core client connection. */
     private final GlideCoreClient coreClient;
 
+    /** Logical database the single commands are sent to, or null to use the client's database. */
+    private Long databaseId;
+
+    /**
+     * Returns a command manager sharing the connection of this one, that sends the single commands
+     * to the logical database {@code databaseId} instead of the client's database.
+     */
+    public CommandManager withDatabase(long databaseId) {
+        CommandManager commandManager = new CommandManager(coreClient);
+        commandManager.databaseId = databaseId;
+        return commandManager;
+    }
+
     /**
      * Apply a response handler with cleanup on exception. If the handler throws, the stored object in
      * JniResponseRegistry is removed to prevent memory leaks.
@@ -304,6 +321,65 @@ public class CommandManager {
         return submitCommandToJni(command, responseHandler, true, false);
     }
 
+    /**
+     * Submit many independent custom commands with a single native call. Each command completes its
+     * own future, in the order of {@code commands}. Blocking commands are submitted separately,
+     * since each is sent through a connection of its own.
+     */
+    public <T> List<CompletableFuture<T>> submitCustomCommands(
+            String[][] commands, GlideExceptionCheckedFunction<Response, T> responseHandler) {
+        return submitCustomCommands(
+                commands.length,
+                i -> isBlockingCustomCommand(commands[i]),
+                i -> submitCustomCommand(commands[i], responseHandler),
+                i -> prepareCommandRequest(RequestType.CustomCommand, commands[i]),
+                true,
+                responseHandler);
+    }
+
+    /**
+     * Submit many independent custom commands with GlideString args with a single native call. Each
+     * command completes its own future, in the order of {@code commands}.
+     */
+    public <T> List<CompletableFuture<T>> submitCustomCommands(
+            GlideString[][] commands, GlideExceptionCheckedFunction<Response, T> responseHandler) {
+        return submitCustomCommands(
+                commands.length,
+                i -> isBlockingCustomCommand(commands[i]),
+                i -> submitCustomCommand(commands[i], responseHandler),
+                i -> prepareCommandRequest(RequestType.CustomCommand, commands[i]),
+                false,
+                responseHandler);
+    }
+
+    private <T> List<CompletableFuture<T>> submitCustomCommands(
+            int count,
+            IntPredicate isBlocking,
+            IntFunction<CompletableFuture<T>> submitBlocking,
+            IntFunction<CommandRequest.Builder> prepare,
+            boolean expectUtf8Response,
+            GlideExceptionCheckedFunction<Response, T> responseHandler) {
+        List<CompletableFuture<T>> futures = new ArrayList<>(Collections.nCopies(count, null));
+        List<CommandRequest.Builder> bulkCommands = new ArrayList<>(count);
+        List<Integer> bulkIndices = new ArrayList<>(count);
+        for (int i = 0; i < count; i++) {
+            if (isBlocking.test(i)) {
+                futures.set(i, submitBlocking.apply(i));
+            } else {
+                bulkCommands.add(prepare.apply(i));
+                bulkIndices.add(i);
+            }
+        }
+        boolean[] expectUtf8Responses = new boolean[bulkCommands.size()];
+        Arrays.fill(expectUtf8Responses, expectUtf8Response);
+        List<CompletableFuture<T>> bulkFutures =
+                submitCommandsToJni(bulkCommands, responseHandler, expectUtf8Responses);
+        for (int i = 0; i < bulkFutures.size(); i++) {
+            futures.set(bulkIndices.get(i), bulkFutures.get(i));
+        }
+        return futures;
+    }
+
     /** Check if a custom command is a blocking command by inspecting the first argument. */
     private boolean isBlockingCustomCommand(String[] arguments) {
         return arguments != null
@@ -377,7 +453,8 @@ public class CommandManager {
                             false, /* routeType */
                             0, /* routeParam */
                             null,
-                            expectUtf8Response);
+                            expectUtf8Response,
+                            script.isReadOnly());
 
             return jniFuture
                     .thenApply(result -> createDirectResponse(result, expectUtf8Response))
@@ -420,7 +497,8 @@ public class CommandManager {
                             routeArgs.hasRoute,
                             routeArgs.routeType,
                             routeArgs.routeParam,
-                            expectUtf8Response);
+                            expectUtf8Response,
+                            script.isReadOnly());
 
             return jniFuture
                     .thenApply(result -> createDirectResponse(result, expectUtf8Response))
@@ -695,6 +773,13 @@ public class CommandManager {
         }
 
         try {
+            // The Java-side timeout fails the future once the request timeout elapsed, so a command
+            // still queued by then is dropped instead of being sent for nothing.
+            long requestTimeoutMillis = coreClient.getRequestTimeoutMillis();
+            if (requestTimeoutMillis > 0 && !command.hasDeadlineUnixMs()) {
+                command.setDeadlineUnixMs(System.currentTimeMillis() + requestTimeoutMillis);
+            }
+
             // Serialize the protobuf command request
             byte[] requestBytes = command.build().toByteArray();
 
@@ -717,6 +802,53 @@ public class CommandManager {
         }
     }
 
+    /**
+     * Take many independent command requests and submit them with a single native call. The
+     * response of each command is decoded as UTF-8 strings if its flag in {@code
+     * expectUtf8Responses} is set, and as binary otherwise.
+     */
+    protected <T> List<CompletableFuture<T>> submitCommandsToJni(
+            List<CommandRequest.Builder> commands,
+            GlideExceptionCheckedFunction<Response, T> responseHandler,
+            boolean[] expectUtf8Responses) {
+        List<CompletableFuture<T>> futures = new ArrayList<>(commands.size());
+        if (commands.isEmpty()) {
+            return futures;
+        }
+        if (!coreClient.isConnected()) {
+            for (int i = 0; i < commands.size(); i++) {
+                CompletableFuture<T> errorFuture = new CompletableFuture<T>();
+                errorFuture.completeExceptionally(
+                        new ClosingException("Client closed: Unable to submit command."));
+                futures.add(errorFuture);
+            }
+            return futures;
+        }
+
+        long requestTimeoutMillis = coreClient.getRequestTimeoutMillis();
+        byte[][] requestsBytes = new byte[commands.size()][];
+        for (int i = 0; i < commands.size(); i++) {
+            CommandRequest.Builder command = commands.get(i);
+            if (requestTimeoutMillis > 0 && !command.hasDeadlineUnixMs()) {
+                command.setDeadlineUnixMs(System.currentTimeMillis() + requestTimeoutMillis);
+            }
+            requestsBytes[i] = command.build().toByteArray();
+        }
+
+        List<CompletableFuture<Object>> jniFutures =
+                coreClient.executeCommandsBulkAsync(requestsBytes, expectUtf8Responses);
+        for (int i = 0; i < jniFutures.size(); i++) {
+            boolean expectUtf8Response = expectUtf8Responses[i];
+            CompletableFuture<Object> jniFuture = jniFutures.get(i);
+            futures.add(
+                    jniFuture
+                            .thenApply(r -> buildResponseFromJniResult(r, expectUtf8Response))
+                            .thenApply(r -> applyHandlerWithCleanup(r, responseHandler))
+                            .exceptionally(this::exceptionHandler));
+        }
+        return futures;
+    }
+
     /**
      * Submit a blocking command to JNI without Java-side timeout. Blocking commands (BLPOP, BRPOP,
      * etc.) have their own timeout in the command arguments, which Rust handles correctly.
@@ -740,9 +872,7 @@ public class CommandManager {
 
             // Execute via JNI WITHOUT Java-side timeout - Rust handles blocking command timeout
             CompletableFuture<Object> jniFuture =
-                    expectUtf8Response
-                            ? coreClient.executeCommandAsyncNoTimeout(requestBytes)
-                            : coreClient.executeBinaryCommandAsyncNoTimeout(requestBytes);
+                    coreClient.executeBlockingCommandAsync(requestBytes, expectUtf8Response);
 
             return jniFuture
                     .thenApply(result -> buildResponseFromJniResult(result, expectUtf8Response))
@@ -771,7 +901,11 @@ public class CommandManager {
             builder.setConstantResponse(ConstantResponse.OK);
         } else {
             if (result instanceof ByteBuffer) {
-                toStore = normalizeDirectBuffer((ByteBuffer) result, expectUtf8Response);
+                toStore =
+                        normalizeDirectBuffer(
+                                (ByteBuffer) result,
+                                expectUtf8Response,
+                                GlideCoreClient.isDirectBufferResponsesEnabled());
             }
             long objectId = JniResponseRegistry.storeObject(toStore);
             builder.setRespPointer(objectId);
@@ -779,7 +913,17 @@ public class CommandManager {
         return builder.build();
     }
 
-    private Object normalizeDirectBuffer(ByteBuffer buffer, boolean expectUtf8Response) {
+    /**
+     * Convert a DirectByteBuffer response to the Java value it holds.
+     *
+     * @param buffer the buffer received from JNI
+     * @param expectUtf8Response whether to expect UTF-8 encoded response
+     * @param keepBinaryBuffer whether a binary bulk string is returned as a read-only view of the
+     *     buffer instead of being copied
+     * @return the decoded value
+     */
+    private Object normalizeDirectBuffer(
+            ByteBuffer buffer, boolean expectUtf8Response, boolean keepBinaryBuffer) {
         ByteBuffer dup = buffer.duplicate();
         dup.order(ByteOrder.BIG_ENDIAN);
         dup.rewind();
@@ -798,6 +942,9 @@ public class CommandManager {
         if (expectUtf8Response) {
             // Decode UTF-8 directly from buffer
             return BufferUtils.decodeUtf8(dup);
+        } else if (keepBinaryBuffer) {
+            // The view keeps the buffer reachable, so its native memory isn't freed while it's used.
+            return dup.asReadOnlyBuffer();
         } else {
             byte[] bytes = new byte[dup.remaining()];
             dup.get(bytes);
@@ -854,7 +1001,9 @@ public class CommandManager {
 
     /**
      * Deserialize a ByteBuffer containing a serialized map back to Map<?,?>. Format: '%' + count(u32
-     * BE) + repeated [keyLen(u32) + keyBytes + valLen(u32) + valBytes]
+     * BE) + repeated [key + valLen(u32) + valBytes], where the key is a type marker followed by its
+     * data, like the array elements: ':' + i64 or ',' + f64 for integer and double keys, '(' +
+     * len(u32) + digits for big number keys, and '$' + len(u32) + bytes for the other keys.
      *
      * <p>This method includes defense-in-depth validation to protect against malformed buffers from
      * the native layer (due to bugs or memory corruption).
@@ -888,17 +1037,44 @@ public class CommandManager {
                 new java.util.LinkedHashMap<>(Math.min(count, 1024));
 
         for (int i = 0; i < count; i++) {
-            requireBufferBytes(buffer, 4, "key length at entry " + i);
-            int klen = buffer.getInt();
-            validateLength(klen, buffer, "Key", i);
+            requireBufferBytes(buffer, 1, "key type marker at entry " + i);
+            byte keyMarker = buffer.get();
 
             Object key;
-            if (expectUtf8) {
-                key = BufferUtils.decodeUtf8(buffer, klen);
-            } else {
-                byte[] kbytes = new byte[klen];
-                buffer.get(kbytes);
-                key = glide.api.models.GlideString.gs(kbytes);
+            switch (keyMarker) {
+                case ':': // Integer
+                    requireBufferBytes(buffer, 8, "integer key at entry " + i);
+                    key = buffer.getLong();
+                    break;
+
+                case ',': // Double
+                    requireBufferBytes(buffer, 8, "double key at entry " + i);
+                    key = buffer.getDouble();
+                    break;
+
+                case '(': // BigNumber
+                    requireBufferBytes(buffer, 4, "big number key length at entry " + i);
+                    int bigNumberLen = buffer.getInt();
+                    validateLength(bigNumberLen, buffer, "Key", i);
+                    key = new BigInteger(BufferUtils.decodeUtf8(buffer, bigNumberLen));
+                    break;
+
+                case '$': // Bulk string
+                    requireBufferBytes(buffer, 4, "key length at entry " + i);
+                    int klen = buffer.getInt();
+                    validateLength(klen, buffer, "Key", i);
+                    if (expectUtf8) {
+                        key = BufferUtils.decodeUtf8(buffer, klen);
+                    } else {
+                        byte[] kbytes = new byte[klen];
+                        buffer.get(kbytes);
+                        key = glide.api.models.GlideString.gs(kbytes);
+                    }
+                    break;
+
+                default:
+                    throw new IllegalArgumentException(
+                            "Unknown key type marker at entry " + i + ": " + (char) keyMarker);
             }
 
             requireBufferBytes(buffer, 4, "value length at entry " + i);
@@ -983,31 +1159,11 @@ public class CommandManager {
             builder.setConstantResponse(ConstantResponse.OK);
         } else if (jniResult instanceof ByteBuffer) {
             // DirectByteBuffer from JNI. Could be a serialized array/map or a large bulk string.
-            ByteBuffer buffer = (ByteBuffer) jniResult;
-            ByteBuffer dup = buffer.duplicate();
-            dup.rewind();
-            Object toStore;
-            if (dup.remaining() > 0) {
-                byte marker = dup.get();
-                if (marker == '*') {
-                    dup.rewind();
-                    toStore = deserializeByteBufferArray(dup, expectUtf8Response);
-                } else if (marker == '%') {
-                    dup.rewind();
-                    toStore = deserializeByteBufferMap(dup, expectUtf8Response);
-                } else {
-                    dup.rewind();
-                    if (expectUtf8Response) {
-                        toStore = BufferUtils.decodeUtf8(dup);
-                    } else {
-                        byte[] bytes = new byte[dup.remaining()];
-                        dup.get(bytes);
-                        toStore = glide.api.models.GlideString.gs(bytes);
-                    }
-                }
-            } else {
-                toStore = expectUtf8Response ? "" : glide.api.models.GlideString.gs(new byte[0]);
-            }
+            Object toStore =
+                    normalizeDirectBuffer(
+                            (ByteBuffer) jniResult,
+                            expectUtf8Response,
+                            GlideCoreClient.isDirectBufferResponsesEnabled());
             long objectId = JniResponseRegistry.storeObject(toStore);
             builder.setRespPointer(objectId);
         } else {
@@ -1035,31 +1191,11 @@ public class CommandManager {
             builder.setConstantResponse(ConstantResponse.OK);
         } else if (jniResult instanceof ByteBuffer) {
             // DirectByteBuffer from JNI. Could be serialized array/map or large bulk string.
-            ByteBuffer buffer = (ByteBuffer) jniResult;
-            ByteBuffer dup = buffer.duplicate();
-            dup.rewind();
-            Object toStore;
-            if (dup.remaining() > 0) {
-                byte marker = dup.get();
-                if (marker == '*') {
-                    dup.rewind();
-                    toStore = deserializeByteBufferArray(dup, expectUtf8Response);
-                } else if (marker == '%') {
-                    dup.rewind();
-                    toStore = deserializeByteBufferMap(dup, expectUtf8Response);
-                } else {
-                    dup.rewind();
-                    if (expectUtf8Response) {
-                        toStore = BufferUtils.decodeUtf8(dup);
-                    } else {
-                        byte[] bytes = new byte[dup.remaining()];
-                        dup.get(bytes);
-                        toStore = glide.api.models.GlideString.gs(bytes);
-                    }
-                }
-            } else {
-                toStore = expectUtf8Response ? "" : glide.api.models.GlideString.gs(new byte[0]);
-            }
+            Object toStore =
+                    normalizeDirectBuffer(
+                            (ByteBuffer) jniResult,
+                            expectUtf8Response,
+                            GlideCoreClient.isDirectBufferResponsesEnabled());
             long objectId = JniResponseRegistry.storeObject(toStore);
             builder.setRespPointer(objectId);
         } else {
@@ -1211,6 +1347,9 @@ public class CommandManager {
         CommandRequest.Builder builder =
                 CommandRequest.newBuilder()
                         .setSingleCommand(commandBuilder.setRequestType(requestType).build());
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         if (spanPtr != 0) {
             builder.setRootSpanPtr(spanPtr);
@@ -1233,6 +1372,9 @@ public class CommandManager {
         CommandRequest.Builder builder =
                 CommandRequest.newBuilder()
                         .setSingleCommand(commandBuilder.setRequestType(requestType).build());
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         if (spanPtr != 0) {
             builder.setRootSpanPtr(spanPtr);
@@ -1245,6 +1387,10 @@ public class CommandManager {
     protected CommandRequest.Builder prepareCommandRequest(
             Batch batch, boolean raiseOnError, Optional<BatchOptions> options) {
         CommandRequest.Builder builder = CommandRequest.newBuilder();
+        // Batches can't override the database, the request is rejected
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         long spanPtr = 0;
         if (OpenTelemetry.isInitialized() && OpenTelemetry.shouldSample()) {
@@ -1272,6 +1418,10 @@ public class CommandManager {
             ClusterBatch batch, boolean raiseOnError, Optional<ClusterBatchOptions> options) {
 
         CommandRequest.Builder builder = CommandRequest.newBuilder();
+        // Batches can't override the database, the request is rejected
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         long spanPtr = 0;
         if (OpenTelemetry.isInitialized() && OpenTelemetry.shouldSample()) {
@@ -1293,6 +1443,7 @@ public class CommandManager {
                 }
                 batchBuilder.setRetryServerError(opts.getRetryStrategy().isRetryServerError());
                 batchBuilder.setRetryConnectionError(opts.getRetryStrategy().isRetryConnectionError());
+                batchBuilder.setFollowAskRedirects(opts.getRetryStrategy().isFollowAskRedirects());
             }
 
             builder.setBatch(batchBuilder.setRaiseOnError(raiseOnError).build());
@@ -1367,6 +1518,9 @@ public class CommandManager {
         CommandRequest.Builder builder =
                 CommandRequest.newBuilder()
                         .setSingleCommand(commandBuilder.setRequestType(requestType).build());
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         if (spanPtr != 0) {
             builder.setRootSpanPtr(spanPtr);
@@ -1388,6 +1542,9 @@ public class CommandManager {
         CommandRequest.Builder builder =
                 CommandRequest.newBuilder()
                         .setSingleCommand(commandBuilder.setRequestType(requestType).build());
+        if (databaseId != null) {
+            builder.setDatabaseId(databaseId);
+        }
 
         if (spanPtr != 0) {
             builder.setRootSpanPtr(spanPtr);
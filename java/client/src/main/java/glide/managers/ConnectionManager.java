@@ -16,8 +16,10 @@ import glide.api.models.configuration.IamAuthConfig;
 import glide.api.models.configuration.PeriodicChecksConfig;
 import glide.api.models.configuration.PeriodicChecksManualInterval;
 import glide.api.models.configuration.PeriodicChecksStatus;
+import glide.api.models.configuration.ProxyConfiguration;
 import glide.api.models.configuration.ServerCredentials;
 import glide.api.models.configuration.StandaloneSubscriptionConfiguration;
+import glide.api.models.configuration.TcpKeepaliveConfiguration;
 import glide.api.models.configuration.TlsAdvancedConfiguration;
 import glide.api.models.exceptions.ClosingException;
 import glide.api.models.exceptions.ConfigurationError;
@@ -27,6 +29,7 @@ import glide.internal.GlideNativeBridge;
 import java.util.Map;
 import java.util.Set;
 import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.CompletionException;
 import java.util.concurrent.Future;
 import lombok.RequiredArgsConstructor;
 
@@ -49,12 +52,62 @@ public class ConnectionManager {
     private volatile boolean isClosed = false;
 
     /**
-     * Connect to Valkey using the native bridge.
+     * Connect to Valkey using the native bridge. A client configured with {@code lazyConnect} is
+     * only registered, and is created by its first command.
      *
      * @param configuration Connection Configuration
      * @return CompletableFuture that completes when connection is established
      */
     public CompletableFuture<Void> connectToValkey(BaseClientConfiguration configuration) {
+        return buildConnectionRequest(configuration)
+                .thenCompose(
+                        requestBytes ->
+                                configuration.isLazyConnect()
+                                        ? registerLazyNativeClient(requestBytes)
+                                        : createNativeClient(requestBytes));
+    }
+
+    /**
+     * Replace the configuration of a client configured with {@code lazyConnect} before its first
+     * command.
+     *
+     * @param configuration The new configuration
+     * @return CompletableFuture that completes when the configuration is replaced
+     */
+    public CompletableFuture<Void> updateLazyConfiguration(BaseClientConfiguration configuration) {
+        ServerCredentials previousCredentials = credentials;
+        int previousMaxInflightRequests = maxInflightRequests;
+        int previousRequestTimeoutMs = requestTimeoutMs;
+        return buildConnectionRequest(configuration)
+                .thenAccept(
+                        requestBytes -> {
+                            if (isClosed || nativeClientHandle == 0) {
+                                throw new ClosingException("Client is closed");
+                            }
+                            try {
+                                GlideNativeBridge.updatePendingConfig(
+                                        nativeClientHandle, requestBytes);
+                            } catch (RuntimeException e) {
+                                throw new ConfigurationError(e.getMessage());
+                            }
+                        })
+                .whenComplete(
+                        (ignored, error) -> {
+                            if (error != null) {
+                                // Keep the settings of the configuration the client still uses
+                                this.credentials = previousCredentials;
+                                this.maxInflightRequests = previousMaxInflightRequests;
+                                this.requestTimeoutMs = previousRequestTimeoutMs;
+                            }
+                        });
+    }
+
+    /**
+     * Serialize the configuration into a connection request, and store the settings the client
+     * uses on the Java side.
+     */
+    private CompletableFuture<byte[]> buildConnectionRequest(
+            BaseClientConfiguration configuration) {
         return CompletableFuture.supplyAsync(
                 () -> {
                     try {
@@ -263,6 +316,11 @@ public class ConnectionManager {
                                                     .setDurationInSec(manualInterval.getDurationInSec())
                                                     .build());
                                 }
+
+                                if (advancedConfig.getAddressTranslation() != null) {
+                                    requestBuilder.putAllAddressTranslation(
+                                            advancedConfig.getAddressTranslation());
+                                }
                             }
                         }
 
@@ -329,6 +387,17 @@ public class ConnectionManager {
                             requestBuilder.addRootCerts(com.google.protobuf.ByteString.copyFrom(rootCerts));
                         }
 
+                        // Set the client certificate and key for mutual TLS if provided
+                        TlsAdvancedConfiguration tlsConfig = extractTlsAdvancedConfiguration(configuration);
+                        if (tlsConfig != null && tlsConfig.getClientCertificate() != null) {
+                            requestBuilder.setClientCert(
+                                    com.google.protobuf.ByteString.copyFrom(tlsConfig.getClientCertificate()));
+                        }
+                        if (tlsConfig != null && tlsConfig.getClientKey() != null) {
+                            requestBuilder.setClientKey(
+                                    com.google.protobuf.ByteString.copyFrom(tlsConfig.getClientKey()));
+                        }
+
                         // Set pubsub subscriptions
                         if (subExact.length > 0 || subPattern.length > 0 || subSharded.length > 0) {
                             PubSubSubscriptions.Builder subBuilder = PubSubSubscriptions.newBuilder();
@@ -381,6 +450,49 @@ public class ConnectionManager {
                                     advanced.getPubsubReconciliationIntervalMs());
                         }
 
+                        // Set the client eviction and touch modes (only if explicitly configured)
+                        if (advanced != null && advanced.getClientNoEvict() != null) {
+                            requestBuilder.setClientNoEvict(advanced.getClientNoEvict());
+                        }
+                        if (advanced != null && advanced.getClientNoTouch() != null) {
+                            requestBuilder.setClientNoTouch(advanced.getClientNoTouch());
+                        }
+                        if (advanced != null && advanced.getResp3Fallback() != null) {
+                            requestBuilder.setResp3Fallback(advanced.getResp3Fallback());
+                        }
+                        if (advanced != null && advanced.getJsonPathFallback() != null) {
+                            requestBuilder.setJsonPathFallback(advanced.getJsonPathFallback());
+                        }
+
+                        // Set the TCP keepalive and heartbeat (only if explicitly configured)
+                        if (advanced != null && advanced.getTcpKeepalive() != null) {
+                            TcpKeepalive.Builder keepaliveBuilder = TcpKeepalive.newBuilder();
+                            TcpKeepaliveConfiguration keepalive = advanced.getTcpKeepalive();
+                            if (keepalive.getIdleMs() != null) {
+                                keepaliveBuilder.setIdleMs(keepalive.getIdleMs());
+                            }
+                            if (keepalive.getIntervalMs() != null) {
+                                keepaliveBuilder.setIntervalMs(keepalive.getIntervalMs());
+                            }
+                            if (keepalive.getCount() != null) {
+                                keepaliveBuilder.setCount(keepalive.getCount());
+                            }
+                            requestBuilder.setTcpKeepalive(keepaliveBuilder.build());
+                        }
+                        if (advanced != null && advanced.getHeartbeatIntervalMs() != null) {
+                            requestBuilder.setHeartbeatIntervalMs(advanced.getHeartbeatIntervalMs());
+                        }
+                        if (advanced != null && advanced.getMaxConnectionAgeMs() != null) {
+                            requestBuilder.setMaxConnectionAgeMs(advanced.getMaxConnectionAgeMs());
+                        }
+                        if (advanced != null && advanced.getConnectionIdleTimeoutMs() != null) {
+                            requestBuilder.setConnectionIdleTimeoutMs(
+                                    advanced.getConnectionIdleTimeoutMs());
+                        }
+                        if (advanced != null && advanced.getProxy() != null) {
+                            requestBuilder.setProxy(toProtobufProxy(advanced.getProxy()));
+                        }
+
                         // Set read-only mode for standalone clients
                         if (configuration instanceof GlideClientConfiguration) {
                             GlideClientConfiguration standaloneConfig = (GlideClientConfiguration) configuration;
@@ -393,14 +505,7 @@ public class ConnectionManager {
                         ConnectionRequest request = requestBuilder.build();
                         byte[] requestBytes = request.toByteArray();
 
-                        // Create native client with protobuf bytes
-                        this.nativeClientHandle = GlideNativeBridge.createClient(requestBytes);
-
-                        if (nativeClientHandle == 0) {
-                            throw new ClosingException("Failed to create client - Connection refused");
-                        }
-
-                        return null; // Success
+                        return requestBytes;
                     } catch (Exception e) {
                         if (e instanceof GlideException) {
                             throw (GlideException) e;
@@ -410,6 +515,44 @@ public class ConnectionManager {
                 });
     }
 
+    /**
+     * Register the native client with the serialized connection request, without connecting it. The
+     * native layer creates the client on its first command.
+     */
+    private CompletableFuture<Void> registerLazyNativeClient(byte[] requestBytes) {
+        long handle = GlideNativeBridge.registerLazyClient(requestBytes);
+        if (handle == 0) {
+            throw new ClosingException("Failed to create client: invalid connection request");
+        }
+        this.nativeClientHandle = handle;
+        return CompletableFuture.completedFuture(null);
+    }
+
+    /**
+     * Create the native client with the serialized connection request. The native layer connects
+     * without blocking the calling thread, and completes the returned future once the client is
+     * ready.
+     */
+    private CompletableFuture<Void> createNativeClient(byte[] requestBytes) {
+        CompletableFuture<Object> handleFuture = new CompletableFuture<>();
+        long callbackId = AsyncRegistry.register(handleFuture, 0, 0L, 0L);
+        if (callbackId != 0L) {
+            GlideNativeBridge.createClientAsync(requestBytes, callbackId);
+        }
+        return handleFuture.handle(
+                (handle, error) -> {
+                    if (error != null) {
+                        Throwable cause = error instanceof CompletionException ? error.getCause() : error;
+                        if (cause instanceof ClosingException) {
+                            throw (ClosingException) cause;
+                        }
+                        throw new ClosingException("Failed to create client: " + cause.getMessage());
+                    }
+                    this.nativeClientHandle = (Long) handle;
+                    return null;
+                });
+    }
+
     /**
      * Close the connection.
      *
@@ -540,14 +683,37 @@ public class ConnectionManager {
     }
 
     private static byte[] extractRootCertificates(BaseClientConfiguration configuration) {
+        TlsAdvancedConfiguration tlsConfig = extractTlsAdvancedConfiguration(configuration);
+        if (tlsConfig == null) {
+            return null;
+        }
+        return tlsConfig.getRootCertificates();
+    }
+
+    private static TlsAdvancedConfiguration extractTlsAdvancedConfiguration(
+            BaseClientConfiguration configuration) {
         AdvancedBaseClientConfiguration advanced = configuration.getAdvancedConfiguration();
         if (advanced == null) {
             return null;
         }
-        TlsAdvancedConfiguration tlsConfig = advanced.getTlsAdvancedConfiguration();
-        if (tlsConfig == null) {
-            return null;
+        return advanced.getTlsAdvancedConfiguration();
+    }
+
+    private static ProxyConfig toProtobufProxy(ProxyConfiguration proxy) {
+        ProxyConfig.Builder proxyBuilder =
+                ProxyConfig.newBuilder()
+                        .setProxyType(
+                                proxy.getType() == ProxyConfiguration.ProxyType.HTTP_CONNECT
+                                        ? ProxyType.HttpConnect
+                                        : ProxyType.Socks5)
+                        .setHost(proxy.getHost())
+                        .setPort(proxy.getPort());
+        if (proxy.getUsername() != null) {
+            proxyBuilder.setUsername(proxy.getUsername());
         }
-        return tlsConfig.getRootCertificates();
+        if (proxy.getPassword() != null) {
+            proxyBuilder.setPassword(proxy.getPassword());
+        }
+        return proxyBuilder.build();
     }
 }
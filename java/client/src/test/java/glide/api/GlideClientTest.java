@@ -16272,4 +16272,26 @@ public class GlideClientTest {
         // verify
         assertNull(response.get());
     }
+
+    @SneakyThrows
+    @Test
+    public void subscribe_binary_sends_names_as_is() {
+        // setup
+        CompletableFuture<Void> testResponse = new CompletableFuture<>();
+        testResponse.complete(null);
+        GlideString channel = gs(new byte[] {(byte) 0xff, (byte) 0xfe, 0});
+        GlideString[] arguments = new GlideString[] {channel, gs("5000")};
+
+        // match on protobuf request
+        when(commandManager.<Void>submitNewCommand(eq(SubscribeBlocking), eq(arguments), any()))
+                .thenReturn(testResponse);
+
+        // exercise
+        CompletableFuture<Void> response = service.subscribeBinary(Set.of(channel), 5000);
+
+        // verify
+        assertNull(response.get());
+        assertThrows(
+                IllegalArgumentException.class, () -> service.subscribeBinary(Set.of(channel), -1));
+    }
 }
@@ -94,6 +94,7 @@ import java.util.EnumSet;
 import java.util.HashMap;
 import java.util.Map;
 import java.util.Optional;
+import java.util.Set;
 import java.util.UUID;
 import java.util.concurrent.CompletableFuture;
 import lombok.SneakyThrows;
@@ -3870,4 +3871,24 @@ public class GlideClusterClientTest {
         // verify
         assertNull(response.get());
     }
+
+    @SneakyThrows
+    @Test
+    public void ssubscribe_binary_sends_names_as_is() {
+        // setup
+        CompletableFuture<Void> testResponse = new CompletableFuture<>();
+        testResponse.complete(null);
+        GlideString channel = gs(new byte[] {(byte) 0xc3, (byte) 0x28});
+        GlideString[] arguments = new GlideString[] {channel, gs("5000")};
+
+        // match on protobuf request
+        when(commandManager.<Void>submitNewCommand(eq(SSubscribeBlocking), eq(arguments), any()))
+                .thenReturn(testResponse);
+
+        // exercise
+        CompletableFuture<Void> response = service.ssubscribeBinary(Set.of(channel), 5000);
+
+        // verify
+        assertNull(response.get());
+    }
 }
@@ -3,8 +3,11 @@ package glide.api.models.configuration;
 
 import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertSame;
 import static org.junit.jupiter.api.Assertions.assertThrows;
 
+import glide.api.models.configuration.BaseSubscriptionConfiguration.ResubscribedCallback;
+import java.util.Optional;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.params.ParameterizedTest;
 import org.junit.jupiter.params.provider.ValueSource;
@@ -123,4 +126,26 @@ public class BaseClientConfigurationTest {
         assertEquals(
                 "pubsubReconciliationIntervalMs must be positive, got: -1", exception.getMessage());
     }
+
+    @Test
+    public void testResubscribedCallback() {
+        ResubscribedCallback callback = (restored, disconnectedForMillis, context) -> {};
+        assertEquals(
+                Optional.empty(),
+                StandaloneSubscriptionConfiguration.builder().build().getResubscribedCallback());
+        assertSame(
+                callback,
+                StandaloneSubscriptionConfiguration.builder()
+                        .resubscribedCallback(callback)
+                        .build()
+                        .getResubscribedCallback()
+                        .get());
+        assertSame(
+                callback,
+                ClusterSubscriptionConfiguration.builder()
+                        .resubscribedCallback(callback)
+                        .build()
+                        .getResubscribedCallback()
+                        .get());
+    }
 }
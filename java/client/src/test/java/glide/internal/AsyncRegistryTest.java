@@ -8,6 +8,8 @@ import static org.junit.jupiter.api.Assertions.assertInstanceOf;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import glide.api.models.exceptions.ClosingException;
+import java.util.ArrayList;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
 import java.util.concurrent.ExecutionException;
 import org.junit.jupiter.api.BeforeEach;
@@ -177,6 +179,41 @@ public class AsyncRegistryTest {
         }
     }
 
+    @Test
+    void onPartialResult_deliversChunksUntilCompleted() {
+        CompletableFuture<Object> f = new CompletableFuture<>();
+        List<Object> received = new ArrayList<>();
+        long id =
+                AsyncRegistry.registerStreaming(f, chunk -> received.addAll(List.of(chunk)), 0, 1L, 0);
+
+        assertTrue(AsyncRegistry.onPartialResult(id, new Object[] {"a", "b"}));
+        assertTrue(AsyncRegistry.onPartialResult(id, new Object[] {"c"}));
+        assertTrue(AsyncRegistry.completeCallback(id, 3L));
+
+        assertEquals(List.of("a", "b", "c"), received);
+        assertEquals(3L, f.join());
+        assertFalse(AsyncRegistry.onPartialResult(id, new Object[] {"d"}));
+    }
+
+    @Test
+    void onPartialResult_consumerFailure_failsFuture() {
+        CompletableFuture<Object> f = new CompletableFuture<>();
+        long id =
+                AsyncRegistry.registerStreaming(
+                        f,
+                        chunk -> {
+                            throw new IllegalStateException("consumer failed");
+                        },
+                        0,
+                        1L,
+                        0);
+
+        assertFalse(AsyncRegistry.onPartialResult(id, new Object[] {"a"}));
+        assertTrue(f.isCompletedExceptionally());
+        assertFalse(AsyncRegistry.completeCallback(id, 1L));
+        assertEquals(0, AsyncRegistry.getActiveFutureCount());
+    }
+
     @Test
     void isShutdown_initiallyFalse() {
         assertFalse(AsyncRegistry.isShutdown());
@@ -7,6 +7,7 @@ import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 import static org.mockito.Mockito.mock;
 
+import glide.api.models.GlideString;
 import glide.internal.GlideCoreClient;
 import java.lang.reflect.InvocationTargetException;
 import java.lang.reflect.Method;
@@ -172,9 +173,10 @@ public class CommandManagerDirectBufferTest {
 
     @Test
     void deserializeByteBufferMap_rejectsKeyLengthExceedingBuffer() {
-        ByteBuffer buffer = ByteBuffer.allocate(9).order(ByteOrder.BIG_ENDIAN);
+        ByteBuffer buffer = ByteBuffer.allocate(10).order(ByteOrder.BIG_ENDIAN);
         buffer.put((byte) '%');
         buffer.putInt(1); // 1 entry
+        buffer.put((byte) '$'); // bulk string key
         buffer.putInt(1000); // key length claims 1000 bytes
         buffer.flip();
 
@@ -188,9 +190,10 @@ public class CommandManagerDirectBufferTest {
 
     @Test
     void deserializeByteBufferMap_rejectsNegativeKeyLength() {
-        ByteBuffer buffer = ByteBuffer.allocate(9).order(ByteOrder.BIG_ENDIAN);
+        ByteBuffer buffer = ByteBuffer.allocate(10).order(ByteOrder.BIG_ENDIAN);
         buffer.put((byte) '%');
         buffer.putInt(1); // 1 entry
+        buffer.put((byte) '$'); // bulk string key
         buffer.putInt(-5); // negative key length
         buffer.flip();
 
@@ -203,9 +206,10 @@ public class CommandManagerDirectBufferTest {
 
     @Test
     void deserializeByteBufferMap_rejectsValueLengthExceedingBuffer() {
-        ByteBuffer buffer = ByteBuffer.allocate(16).order(ByteOrder.BIG_ENDIAN);
+        ByteBuffer buffer = ByteBuffer.allocate(17).order(ByteOrder.BIG_ENDIAN);
         buffer.put((byte) '%');
         buffer.putInt(1); // 1 entry
+        buffer.put((byte) '$'); // bulk string key
         buffer.putInt(3); // key length = 3
         buffer.put("key".getBytes(StandardCharsets.UTF_8));
         buffer.putInt(1000); // value length claims 1000 bytes
@@ -221,9 +225,10 @@ public class CommandManagerDirectBufferTest {
 
     @Test
     void deserializeByteBufferMap_rejectsNegativeValueLength() {
-        ByteBuffer buffer = ByteBuffer.allocate(16).order(ByteOrder.BIG_ENDIAN);
+        ByteBuffer buffer = ByteBuffer.allocate(17).order(ByteOrder.BIG_ENDIAN);
         buffer.put((byte) '%');
         buffer.putInt(1); // 1 entry
+        buffer.put((byte) '$'); // bulk string key
         buffer.putInt(3); // key length = 3
         buffer.put("key".getBytes(StandardCharsets.UTF_8));
         buffer.putInt(-5); // negative value length
@@ -243,12 +248,14 @@ public class CommandManagerDirectBufferTest {
         buffer.putInt(2); // 2 entries
 
         // Entry 1: "key1" -> "val1"
+        buffer.put((byte) '$');
         buffer.putInt(4);
         buffer.put("key1".getBytes(StandardCharsets.UTF_8));
         buffer.putInt(4);
         buffer.put("val1".getBytes(StandardCharsets.UTF_8));
 
         // Entry 2: "key2" -> "val2"
+        buffer.put((byte) '$');
         buffer.putInt(4);
         buffer.put("key2".getBytes(StandardCharsets.UTF_8));
         buffer.putInt(4);
@@ -263,6 +270,72 @@ public class CommandManagerDirectBufferTest {
         assertEquals("val2", map.get("key2"));
     }
 
+    @Test
+    void deserializeByteBufferMap_keepsTheTypesOfTheKeys() throws Exception {
+        // The bytes written by `serialize_map_vec_to_bytes` in the native layer for the same map.
+        byte[] bigNumberBytes = "12345678901234567890".getBytes(StandardCharsets.UTF_8);
+        ByteBuffer buffer = ByteBuffer.allocate(100).order(ByteOrder.BIG_ENDIAN);
+        buffer.put((byte) '%');
+        buffer.putInt(4);
+
+        buffer.put((byte) ':').putLong(12);
+        buffer.putInt(3).put("int".getBytes(StandardCharsets.UTF_8));
+
+        buffer.put((byte) ',').putDouble(1.5);
+        buffer.putInt(6).put("double".getBytes(StandardCharsets.UTF_8));
+
+        buffer.put((byte) '(').putInt(bigNumberBytes.length).put(bigNumberBytes);
+        buffer.putInt(3).put("big".getBytes(StandardCharsets.UTF_8));
+
+        buffer.put((byte) '$').putInt(2).put("ok".getBytes(StandardCharsets.UTF_8));
+        buffer.putInt(6).put("simple".getBytes(StandardCharsets.UTF_8));
+
+        buffer.flip();
+
+        LinkedHashMap<Object, Object> map = deserializeByteBufferMap(buffer, true);
+
+        assertEquals(4, map.size());
+        assertEquals("int", map.get(12L));
+        assertEquals("double", map.get(1.5d));
+        assertEquals("big", map.get(new BigInteger("12345678901234567890")));
+        assertEquals("simple", map.get("ok"));
+        assertNull(map.get("12"));
+    }
+
+    @Test
+    void deserializeByteBufferMap_rejectsUnknownKeyTypeMarker() {
+        ByteBuffer buffer = ByteBuffer.allocate(6).order(ByteOrder.BIG_ENDIAN);
+        buffer.put((byte) '%');
+        buffer.putInt(1); // 1 entry
+        buffer.put((byte) '!');
+        buffer.flip();
+
+        InvocationTargetException ex =
+                assertThrows(
+                        InvocationTargetException.class, () -> deserializeByteBufferMap(buffer, false));
+        assertTrue(ex.getCause() instanceof IllegalArgumentException);
+        assertTrue(ex.getCause().getMessage().contains("Unknown key type marker"));
+    }
+
+    @Test
+    void normalizeDirectBuffer_keepsBinaryBulkStringBufferWhenEnabled() throws Exception {
+        ByteBuffer buffer = ByteBuffer.allocateDirect(5);
+        buffer.put("value".getBytes(StandardCharsets.UTF_8));
+        buffer.flip();
+
+        Object kept = normalizeDirectBuffer(buffer, false, true);
+        assertTrue(kept instanceof ByteBuffer);
+        ByteBuffer view = (ByteBuffer) kept;
+        assertTrue(view.isReadOnly());
+        assertTrue(view.isDirect());
+        byte[] bytes = new byte[view.remaining()];
+        view.get(bytes);
+        assertEquals("value", new String(bytes, StandardCharsets.UTF_8));
+
+        assertEquals(GlideString.gs("value"), normalizeDirectBuffer(buffer, false, false));
+        assertEquals("value", normalizeDirectBuffer(buffer, true, true));
+    }
+
     // ==================== Helper Methods ====================
 
     private Object[] deserializeByteBufferArray(ByteBuffer buffer, boolean expectUtf8Response)
@@ -274,6 +347,15 @@ public class CommandManagerDirectBufferTest {
         return (Object[]) method.invoke(commandManager, buffer, expectUtf8Response);
     }
 
+    private Object normalizeDirectBuffer(
+            ByteBuffer buffer, boolean expectUtf8Response, boolean keepBinaryBuffer) throws Exception {
+        Method method =
+                CommandManager.class.getDeclaredMethod(
+                        "normalizeDirectBuffer", ByteBuffer.class, boolean.class, boolean.class);
+        method.setAccessible(true);
+        return method.invoke(commandManager, buffer, expectUtf8Response, keepBinaryBuffer);
+    }
+
     @SuppressWarnings("unchecked")
     private LinkedHashMap<Object, Object> deserializeByteBufferMap(
             ByteBuffer buffer, boolean expectUtf8Response) throws Exception {
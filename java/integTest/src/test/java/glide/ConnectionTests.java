@@ -21,6 +21,7 @@ import glide.api.models.ClusterValue;
 import glide.api.models.commands.InfoOptions;
 import glide.api.models.configuration.*;
 import glide.api.models.exceptions.ClosingException;
+import glide.api.models.exceptions.ConfigurationError;
 import glide.cluster.ValkeyCluster;
 import java.util.Arrays;
 import java.util.Collections;
@@ -837,4 +838,39 @@ public class ConnectionTests {
         assertEquals("value3", clientFalse.get("key3").get());
         clientFalse.close();
     }
+
+    @ParameterizedTest
+    @ValueSource(booleans = {true, false})
+    @SneakyThrows
+    public void update_lazy_configuration_before_first_command(boolean clusterMode) {
+        BaseClient client;
+        BaseClientConfiguration updated;
+        if (clusterMode) {
+            GlideClusterClientConfiguration initial =
+                    commonClusterClientConfig().lazyConnect(true).clientName("before").build();
+            client = GlideClusterClient.createClient(initial).get();
+            updated = commonClusterClientConfig().lazyConnect(true).clientName("after").build();
+        } else {
+            GlideClientConfiguration initial =
+                    commonClientConfig().lazyConnect(true).clientName("before").build();
+            client = GlideClient.createClient(initial).get();
+            updated = commonClientConfig().lazyConnect(true).clientName("after").build();
+        }
+        client.updateLazyConfiguration(updated).get();
+
+        // The client connects with the updated configuration on its first command.
+        String name =
+                clusterMode
+                        ? ((GlideClusterClient) client).clientGetName().get()
+                        : ((GlideClient) client).clientGetName().get();
+        assertEquals("after", name);
+
+        // The configuration can't be changed once the client was created.
+        ExecutionException executionException =
+                assertThrows(
+                        ExecutionException.class,
+                        () -> client.updateLazyConfiguration(updated).get());
+        assertInstanceOf(ConfigurationError.class, executionException.getCause());
+        client.close();
+    }
 }
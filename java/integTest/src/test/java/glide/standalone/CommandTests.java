@@ -48,6 +48,7 @@ import static org.junit.jupiter.api.Assertions.assertTrue;
 import static org.junit.jupiter.api.Assumptions.assumeTrue;
 
 import glide.api.GlideClient;
+import glide.api.models.Batch;
 import glide.api.models.GlideString;
 import glide.api.models.Script;
 import glide.api.models.commands.FlushMode;
@@ -165,6 +166,42 @@ public class CommandTests {
         assertNull(data);
     }
 
+    @ParameterizedTest(autoCloseArguments = false)
+    @MethodSource("getClients")
+    @SneakyThrows
+    public void custom_commands_complete_each_command(GlideClient regularClient) {
+        String key1 = UUID.randomUUID().toString();
+        String key2 = UUID.randomUUID().toString();
+        String key3 = UUID.randomUUID().toString();
+        String list = UUID.randomUUID().toString();
+        regularClient.set(key2, INITIAL_VALUE).get();
+        regularClient.set(key3, INITIAL_VALUE).get();
+
+        // The commands are executed concurrently, so each uses a key of its own
+        List<CompletableFuture<Object>> responses =
+                regularClient.customCommands(
+                        new String[][] {
+                            {"SET", key1, INITIAL_VALUE},
+                            {"BLPOP", list, "0.1"},
+                            {"GET", key2},
+                            {"NOTACOMMAND"},
+                            {"DEL", key3}
+                        });
+        assertEquals(5, responses.size());
+        assertEquals(OK, responses.get(0).get());
+        assertNull(responses.get(1).get());
+        assertEquals(INITIAL_VALUE, responses.get(2).get());
+        ExecutionException e = assertThrows(ExecutionException.class, () -> responses.get(3).get());
+        assertInstanceOf(RequestException.class, e.getCause());
+        assertEquals(1L, responses.get(4).get());
+
+        // The responses of binary commands aren't decoded
+        List<CompletableFuture<Object>> binaryResponses =
+                regularClient.customCommands(new GlideString[][] {{gs("GET"), gs(key1)}});
+        assertEquals(gs(INITIAL_VALUE), binaryResponses.get(0).get());
+        assertTrue(regularClient.customCommands(new String[0][]).isEmpty());
+    }
+
     @ParameterizedTest(autoCloseArguments = false)
     @MethodSource("getClients")
     @SneakyThrows
@@ -251,6 +288,35 @@ public class CommandTests {
         assertInstanceOf(RequestException.class, e.getCause());
     }
 
+    @ParameterizedTest(autoCloseArguments = false)
+    @MethodSource("getClients")
+    @SneakyThrows
+    public void withDatabase_sends_commands_to_the_database(GlideClient regularClient) {
+        String key = UUID.randomUUID().toString();
+        String list = UUID.randomUUID().toString();
+        assertEquals(OK, regularClient.select(0).get());
+        GlideClient databaseClient = regularClient.withDatabase(1);
+
+        assertEquals(OK, databaseClient.set(key, "value").get());
+        assertEquals("value", databaseClient.get(key).get());
+        assertNull(regularClient.get(key).get());
+
+        // A blocking command of the database doesn't stall the other commands of the database
+        CompletableFuture<String[]> blpop = databaseClient.blpop(new String[] {list}, 5);
+        Thread.sleep(100);
+        assertEquals(1L, databaseClient.rpush(list, new String[] {"element"}).get());
+        assertArrayEquals(new String[] {list, "element"}, blpop.get());
+
+        // Batches can't override the database
+        ExecutionException e =
+                assertThrows(
+                        ExecutionException.class,
+                        () -> databaseClient.exec(new Batch(false).get(key), false).get());
+        assertInstanceOf(RequestException.class, e.getCause());
+
+        assertEquals(1L, databaseClient.del(new String[] {key}).get());
+    }
+
     @ParameterizedTest(autoCloseArguments = false)
     @MethodSource("getClients")
     @SneakyThrows
@@ -22,6 +22,7 @@ import glide.api.models.configuration.IamAuthConfig;
 import glide.api.models.configuration.ServerCredentials;
 import glide.api.models.exceptions.ClosingException;
 import glide.api.models.exceptions.RequestException;
+import glide.internal.GlideNativeBridge;
 import java.util.Collections;
 import java.util.UUID;
 import java.util.concurrent.ExecutionException;
@@ -51,6 +52,30 @@ public class StandaloneClientTests {
         client.close();
     }
 
+    @Test
+    @SneakyThrows
+    public void shutdown_native_from_a_callback() {
+        GlideClient client = GlideClient.createClient(commonClientConfig().build()).get();
+
+        // The blocking command completes after the action is attached, so the action runs on a
+        // callback worker, which the shutdown can't wait for.
+        client
+                .blpop(new String[] {getRandomString(10)}, 0.5)
+                .thenRun(
+                        () -> {
+                            client.close();
+                            GlideNativeBridge.shutdownNative();
+                        })
+                .get();
+        // Waits for the shutdown that was requested from the callback.
+        GlideNativeBridge.shutdownNative();
+
+        // The native layer starts again on its next use.
+        GlideClient newClient = GlideClient.createClient(commonClientConfig().build()).get();
+        assertEquals("PONG", newClient.ping().get());
+        newClient.close();
+    }
+
     @Test
     @SneakyThrows
     public void can_connect_with_auth_require_pass() {
@@ -5,19 +5,19 @@ use anyhow::Result;
 use dashmap::DashMap;
 use glide_core::client::Client as GlideClient;
 use glide_core::client::ConnectionRequest;
-use glide_core::errors::{error_message, error_type};
+use glide_core::errors::{error_details, error_message, error_type};
 use jni::JNIEnv;
 use jni::JavaVM;
-use jni::objects::{GlobalRef, JClass, JObject, JStaticMethodID, JValue};
+use jni::objects::{GlobalRef, JByteBuffer, JClass, JObject, JStaticMethodID, JValue};
 use jni::signature;
-use jni::sys::{JNI_VERSION_1_8, jint, jlong, jstring};
-use parking_lot::Mutex;
+use jni::sys::{JNI_VERSION_1_8, jboolean, jint, jlong, jsize, jstring};
+use parking_lot::{Mutex, RwLock};
 use redis::{RedisError as ServerError, Value as ServerValue};
 use std::ffi::c_void;
 use std::sync::Arc;
 use std::sync::mpsc::{Sender, channel};
 use std::thread;
-use tokio::runtime::Runtime;
+use tokio::runtime::{Handle, Runtime};
 
 #[unsafe(no_mangle)]
 pub extern "system" fn JNI_OnLoad(vm: JavaVM, _reserved: *mut c_void) -> jint {
@@ -61,11 +61,13 @@ type CallbackResult = Result<ServerValue, ServerError>;
 
 // Runtime and JVM statics
 pub static JVM: std::sync::OnceLock<Arc<JavaVM>> = std::sync::OnceLock::new();
-static RUNTIME: std::sync::OnceLock<Runtime> = std::sync::OnceLock::new();
+static RUNTIME: std::sync::OnceLock<RwLock<Option<Runtime>>> = std::sync::OnceLock::new();
 
 // Defaults for runtime and callback workers
 const DEFAULT_RUNTIME_WORKER_THREADS: usize = 1;
 const DEFAULT_CALLBACK_WORKER_THREADS: usize = 2;
+// How long `shutdown_native` waits for the runtime's tasks to stop
+const RUNTIME_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 // =========================
 // Native buffer registry
@@ -73,6 +75,13 @@ const DEFAULT_CALLBACK_WORKER_THREADS: usize = 2;
 static NATIVE_BUFFER_REGISTRY: std::sync::OnceLock<dashmap::DashMap<u64, Vec<u8>>> =
     std::sync::OnceLock::new();
 static NEXT_NATIVE_BUFFER_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+// Maps the address of each registered buffer to its id, so Java can find the handle of a `ByteBuffer`.
+static NATIVE_BUFFER_HANDLES: std::sync::OnceLock<dashmap::DashMap<usize, u64>> =
+    std::sync::OnceLock::new();
+// Responses larger than this many bytes are delivered as a DirectByteBuffer backed by a native buffer.
+const DEFAULT_DIRECT_BUFFER_THRESHOLD: usize = 16 * 1024;
+static DIRECT_BUFFER_THRESHOLD: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(DEFAULT_DIRECT_BUFFER_THRESHOLD);
 static TIMED_OUT_CALLBACKS: std::sync::OnceLock<dashmap::DashMap<jlong, ()>> =
     std::sync::OnceLock::new();
 
@@ -88,12 +97,32 @@ pub fn register_native_buffer(bytes: Vec<u8>) -> (u64, *mut u8, usize) {
     let guard = registry.get(&id).expect("buffer just inserted");
     let ptr = guard.as_ptr() as *mut u8;
     let len = guard.len();
+    NATIVE_BUFFER_HANDLES
+        .get_or_init(dashmap::DashMap::new)
+        .insert(ptr as usize, id);
     (id, ptr, len)
 }
 
 pub fn free_native_buffer(id: u64) -> bool {
     let registry = get_native_buffer_registry();
-    registry.remove(&id).is_some()
+    match registry.remove(&id) {
+        Some((_, bytes)) => {
+            // Remove the address before the buffer is dropped, so it can't be reused by a newer buffer first.
+            NATIVE_BUFFER_HANDLES
+                .get_or_init(dashmap::DashMap::new)
+                .remove(&(bytes.as_ptr() as usize));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns the id of the registered buffer that starts at `address`, if there is one.
+fn native_buffer_handle(address: usize) -> Option<u64> {
+    NATIVE_BUFFER_HANDLES
+        .get_or_init(dashmap::DashMap::new)
+        .get(&address)
+        .map(|id| *id)
 }
 
 fn get_timed_out_callbacks() -> &'static dashmap::DashMap<jlong, ()> {
@@ -110,27 +139,38 @@ fn take_timed_out_callback(callback_id: jlong) -> bool {
     registry.remove(&callback_id).is_some()
 }
 
-/// Initialize or return the shared Tokio runtime.
-pub(crate) fn get_runtime() -> &'static Runtime {
-    RUNTIME.get_or_init(|| {
-        let worker_threads = if let Ok(threads_str) = std::env::var("GLIDE_TOKIO_WORKER_THREADS") {
-            threads_str
-                .parse::<usize>()
-                .unwrap_or(DEFAULT_RUNTIME_WORKER_THREADS)
-        } else {
-            DEFAULT_RUNTIME_WORKER_THREADS
-        };
+/// Initialize the shared Tokio runtime if needed, and return a handle to it.
+/// The runtime is created again on first use after `shutdown_native`.
+pub(crate) fn get_runtime() -> Handle {
+    let runtime = RUNTIME.get_or_init(|| RwLock::new(None));
+    if let Some(runtime) = runtime.read().as_ref() {
+        return runtime.handle().clone();
+    }
+    runtime
+        .write()
+        .get_or_insert_with(build_runtime)
+        .handle()
+        .clone()
+}
 
-        tokio::runtime::Builder::new_multi_thread()
-            .worker_threads(worker_threads)
-            .max_blocking_threads(worker_threads * 2)
-            .enable_all()
-            .thread_name("glide-worker")
-            .thread_stack_size(2 * 1024 * 1024)
-            .thread_keep_alive(std::time::Duration::from_secs(60))
-            .build()
-            .expect("Failed to create Tokio runtime")
-    })
+fn build_runtime() -> Runtime {
+    let worker_threads = if let Ok(threads_str) = std::env::var("GLIDE_TOKIO_WORKER_THREADS") {
+        threads_str
+            .parse::<usize>()
+            .unwrap_or(DEFAULT_RUNTIME_WORKER_THREADS)
+    } else {
+        DEFAULT_RUNTIME_WORKER_THREADS
+    };
+
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(worker_threads)
+        .max_blocking_threads(worker_threads * 2)
+        .enable_all()
+        .thread_name("glide-worker")
+        .thread_stack_size(2 * 1024 * 1024)
+        .thread_keep_alive(std::time::Duration::from_secs(60))
+        .build()
+        .expect("Failed to create Tokio runtime")
 }
 
 /// Handle table for native clients.
@@ -148,6 +188,39 @@ pub(crate) fn get_pending_map() -> &'static PendingMap {
     PENDING_CONFIGS.get_or_init(|| Arc::new(DashMap::new()))
 }
 
+/// Registers a client that's created on its first command, and returns its handle.
+pub(crate) fn register_pending_client(connection_request: ConnectionRequest) -> u64 {
+    let handle_id = generate_safe_handle();
+    get_pending_map().insert(handle_id, connection_request);
+    handle_id
+}
+
+/// Replaces the configuration of a client that wasn't created yet. Fails if the client was already created, or if
+/// the configuration can't be used by the client, such as one that changes whether it's a cluster client.
+pub(crate) fn update_pending_config(
+    handle_id: u64,
+    connection_request: ConnectionRequest,
+) -> Result<(), String> {
+    if connection_request.addresses.is_empty() {
+        return Err("The configuration must have at least one address".to_string());
+    }
+    // The entry is locked while it's replaced, so the client is created with either configuration, never a mix.
+    let Some(mut pending) = get_pending_map().get_mut(&handle_id) else {
+        return Err(if get_handle_table().contains_key(&handle_id) {
+            "The configuration can't be changed after the client was created".to_string()
+        } else {
+            "Client not found".to_string()
+        });
+    };
+    if pending.cluster_mode_enabled != connection_request.cluster_mode_enabled {
+        return Err(
+            "The configuration can't change whether the client is a cluster client".to_string(),
+        );
+    }
+    *pending = connection_request;
+    Ok(())
+}
+
 /// Generate unique safe handle for JNI resource management
 static NEXT_HANDLE_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
 
@@ -222,6 +295,11 @@ pub(crate) fn handle_push_notification(env: &mut JNIEnv, handle_id: jlong, push:
         }
     };
 
+    if push.kind == PushKind::Resubscribed {
+        notify_resubscribed(env, handle_id, &push.data);
+        return;
+    }
+
     let mapped: Option<PushMessageTuple> = match push.kind {
         PushKind::Message | PushKind::SMessage => {
             if push.data.len() >= 2 {
@@ -277,12 +355,72 @@ pub(crate) fn handle_push_notification(env: &mut JNIEnv, handle_id: jlong, push:
     }
 }
 
+/// Report the subscriptions restored after a reconnection to the Java client.
+///
+/// The push data holds the disconnection time in milliseconds, followed by a `[kind, name]` array
+/// per restored subscription.
+fn notify_resubscribed(env: &mut JNIEnv, handle_id: jlong, data: &[redis::Value]) {
+    use redis::Value;
+
+    let Some(Value::BulkString(gap)) = data.first() else {
+        return;
+    };
+    let disconnected_for_ms = std::str::from_utf8(gap)
+        .ok()
+        .and_then(|gap| gap.parse::<i64>().ok())
+        .unwrap_or_default();
+    let (kinds, names): (Vec<jint>, Vec<&[u8]>) = data[1..]
+        .iter()
+        .filter_map(|entry| match entry {
+            Value::Array(pair) => match pair.as_slice() {
+                [Value::Int(kind), Value::BulkString(name)] => {
+                    Some((*kind as jint, name.as_slice()))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .unzip();
+
+    let _ = env.push_local_frame(16);
+    let _: Result<()> = (|| {
+        let cache = get_glide_core_client_cache_safe(env)?;
+        let jkinds = env.new_int_array(kinds.len() as jsize)?;
+        env.set_int_array_region(&jkinds, 0, &kinds)?;
+        let jnames = env.new_object_array(names.len() as jsize, "[B", JObject::null())?;
+        for (index, name) in names.iter().enumerate() {
+            let jname = env.byte_array_from_slice(name)?;
+            env.set_object_array_element(&jnames, index as jsize, &jname)?;
+            env.delete_local_ref(jname)?;
+        }
+        let jkinds_obj: JObject = jkinds.into();
+        let jnames_obj: JObject = jnames.into();
+        unsafe {
+            env.call_static_method_unchecked(
+                &cache.class,
+                cache.on_native_resubscribed,
+                signature::ReturnType::Primitive(signature::Primitive::Void),
+                &[
+                    JValue::Long(handle_id).as_jni(),
+                    JValue::Long(disconnected_for_ms).as_jni(),
+                    JValue::Object(&jkinds_obj).as_jni(),
+                    JValue::Object(&jnames_obj).as_jni(),
+                ],
+            )?;
+        }
+        Ok(())
+    })();
+    let _ = unsafe { env.pop_local_frame(&JObject::null()) };
+}
+
 /// Cache of required Java method IDs.
 #[derive(Clone)]
 pub(crate) struct MethodCache {
     async_handle_table_class: GlobalRef,
     complete_callback_method: JStaticMethodID,
     complete_error_with_code_method: JStaticMethodID,
+    complete_error_with_details_method: JStaticMethodID,
+    partial_result_method: JStaticMethodID,
     fail_all_method: JStaticMethodID,
 }
 
@@ -321,6 +459,20 @@ pub(crate) fn get_method_cache(env: &mut JNIEnv) -> Result<MethodCache> {
             anyhow::anyhow!("Failed to get completeCallbackWithErrorCode method ID: {e}")
         })?;
 
+    let complete_error_with_details_method = env
+        .get_static_method_id(
+            &class,
+            "completeCallbackWithErrorDetails",
+            "(JILjava/lang/String;Ljava/lang/String;ZLjava/lang/String;)Z",
+        )
+        .map_err(|e| {
+            anyhow::anyhow!("Failed to get completeCallbackWithErrorDetails method ID: {e}")
+        })?;
+
+    let partial_result_method = env
+        .get_static_method_id(&class, "onPartialResult", "(J[Ljava/lang/Object;)Z")
+        .map_err(|e| anyhow::anyhow!("Failed to get onPartialResult method ID: {e}"))?;
+
     let fail_all_method = env
         .get_static_method_id(&class, "failAllWithError", "(Ljava/lang/String;)V")
         .map_err(|e| anyhow::anyhow!("Failed to get failAllWithError method ID: {e}"))?;
@@ -329,6 +481,8 @@ pub(crate) fn get_method_cache(env: &mut JNIEnv) -> Result<MethodCache> {
         async_handle_table_class: global_class,
         complete_callback_method,
         complete_error_with_code_method,
+        complete_error_with_details_method,
+        partial_result_method,
         fail_all_method,
     };
 
@@ -341,11 +495,51 @@ pub(crate) fn get_method_cache(env: &mut JNIEnv) -> Result<MethodCache> {
     Ok(method_cache)
 }
 
+/// How a successful response is decoded into Java objects
+pub enum ResponseEncoding {
+    /// Strings are returned as `String`
+    Utf8,
+    /// Strings are returned as `byte[]`
+    Binary,
+    /// The response is a batch result, where the value of each command is decoded as `byte[]` if its flag is set, or as `String` otherwise
+    PerCommand(Vec<bool>),
+    /// The elements of an array, set or map response are delivered to `onPartialResult` in chunks of up to
+    /// `chunk_size` elements, and strings are returned as `byte[]` if `binary` is set
+    Streamed { binary: bool, chunk_size: usize },
+}
+
+impl ResponseEncoding {
+    pub(crate) fn from_binary_mode(binary_mode: bool) -> Self {
+        if binary_mode {
+            Self::Binary
+        } else {
+            Self::Utf8
+        }
+    }
+
+    fn is_binary(&self) -> bool {
+        matches!(self, Self::Binary | Self::Streamed { binary: true, .. })
+    }
+}
+
 /// Callback job type handled by dedicated callback workers
-type CallbackJob = (Arc<JavaVM>, jlong, CallbackResult, bool);
+type CallbackJob = (Arc<JavaVM>, jlong, CallbackResult, ResponseEncoding);
+
+/// The sender of the global unbounded callback queue, and the threads of the workers draining it
+struct CallbackWorkers {
+    sender: Sender<CallbackJob>,
+    threads: Vec<thread::JoinHandle<()>>,
+}
 
-/// Global unbounded callback queue sender
-static CALLBACK_SENDER: std::sync::OnceLock<Sender<CallbackJob>> = std::sync::OnceLock::new();
+static CALLBACK_WORKERS: std::sync::OnceLock<Mutex<Option<CallbackWorkers>>> =
+    std::sync::OnceLock::new();
+// The shutdown that was handed to a separate thread, because it was requested from a callback worker or the runtime.
+static BACKGROUND_SHUTDOWN: std::sync::OnceLock<Mutex<Option<thread::JoinHandle<()>>>> =
+    std::sync::OnceLock::new();
+
+thread_local! {
+    static IS_CALLBACK_WORKER: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
 
 fn get_callback_worker_threads() -> usize {
     if let Ok(val) = std::env::var("GLIDE_CALLBACK_WORKER_THREADS") {
@@ -357,17 +551,23 @@ fn get_callback_worker_threads() -> usize {
     }
 }
 
-pub fn init_callback_workers() -> &'static Sender<CallbackJob> {
-    CALLBACK_SENDER.get_or_init(|| {
+/// Start the callback workers if needed, and return the sender of their queue.
+/// The workers are started again on first use after `shutdown_native`.
+pub fn init_callback_workers() -> Sender<CallbackJob> {
+    let workers = CALLBACK_WORKERS.get_or_init(|| Mutex::new(None));
+    let mut workers = workers.lock();
+    let workers = workers.get_or_insert_with(|| {
         let (tx, rx) = channel::<CallbackJob>();
         let rx = Arc::new(std::sync::Mutex::new(rx));
         let worker_threads = get_callback_worker_threads();
 
+        let mut threads = Vec::with_capacity(worker_threads);
         for i in 0..worker_threads {
             let rx_clone = Arc::clone(&rx);
-            thread::Builder::new()
+            let worker = thread::Builder::new()
                 .name(format!("glide-jni-callback-{i}"))
                 .spawn(move || {
+                    IS_CALLBACK_WORKER.set(true);
                     // Pre-attach to JVM once at thread start. attach_current_thread_as_daemon
                     // keeps the thread attached for its entire lifetime (no detach on drop).
                     // This eliminates per-callback attach overhead and the attach failure window.
@@ -385,19 +585,24 @@ pub fn init_callback_workers() -> &'static Sender<CallbackJob> {
                             let guard = rx_clone.lock().unwrap();
                             guard.recv().ok()
                         };
-                        let Some((_, callback_id, result, binary_mode)) = job_opt else {
+                        let Some((_, callback_id, result, encoding)) = job_opt else {
                             break;
                         };
 
                         // Process callback with pre-attached env
-                        process_callback_job_with_env(&mut env, callback_id, result, binary_mode);
+                        process_callback_job_with_env(&mut env, callback_id, result, encoding);
                     }
                 })
                 .expect("Failed to spawn callback worker thread");
+            threads.push(worker);
         }
 
-        tx
-    })
+        CallbackWorkers {
+            sender: tx,
+            threads,
+        }
+    });
+    workers.sender.clone()
 }
 
 /// Process a callback with an already-attached JNIEnv.
@@ -406,7 +611,7 @@ fn process_callback_job_with_env(
     env: &mut JNIEnv,
     callback_id: jlong,
     result: CallbackResult,
-    binary_mode: bool,
+    encoding: ResponseEncoding,
 ) {
     if take_timed_out_callback(callback_id) {
         return;
@@ -416,10 +621,21 @@ fn process_callback_job_with_env(
         Ok(server_value) => {
             let _ = env.push_local_frame(16);
 
-            let java_result = if should_use_direct_buffer(&server_value) {
-                create_direct_byte_buffer(env, server_value, !binary_mode)
-            } else {
-                crate::resp_value_to_java(env, server_value, !binary_mode)
+            let java_result = match (server_value, encoding) {
+                (ServerValue::Array(values), ResponseEncoding::PerCommand(binary_flags)) => {
+                    crate::batch_values_to_java_array(env, values, &binary_flags)
+                }
+                (server_value, ResponseEncoding::Streamed { binary, chunk_size }) => {
+                    stream_response_to_java(env, callback_id, server_value, !binary, chunk_size)
+                }
+                (server_value, encoding) => {
+                    let binary_mode = encoding.is_binary();
+                    if should_use_direct_buffer(&server_value) {
+                        create_direct_byte_buffer(env, server_value, !binary_mode)
+                    } else {
+                        crate::resp_value_to_java(env, server_value, !binary_mode)
+                    }
+                }
             };
 
             if take_timed_out_callback(callback_id) {
@@ -455,10 +671,7 @@ fn process_callback_job_with_env(
                 return;
             }
 
-            let error_code = error_type(&server_err) as i32;
-            let error_msg = error_message(&server_err);
-            if let Err(e) =
-                complete_java_callback_with_error_code(env, callback_id, error_code, &error_msg)
+            if let Err(e) = complete_java_callback_with_server_error(env, callback_id, &server_err)
             {
                 log::error!("JNI error completion failed for callback {callback_id}: {e}");
                 let _ = env.exception_clear();
@@ -474,9 +687,24 @@ pub fn complete_callback(
     callback_id: jlong,
     result: CallbackResult,
     binary_mode: bool,
+) {
+    complete_callback_with_encoding(
+        jvm,
+        callback_id,
+        result,
+        ResponseEncoding::from_binary_mode(binary_mode),
+    );
+}
+
+/// Enqueue callback job to dedicated workers, decoding a successful response with the given encoding.
+pub fn complete_callback_with_encoding(
+    jvm: Arc<JavaVM>,
+    callback_id: jlong,
+    result: CallbackResult,
+    encoding: ResponseEncoding,
 ) {
     let sender = init_callback_workers();
-    if let Err(e) = sender.send((jvm.clone(), callback_id, result, binary_mode)) {
+    if let Err(e) = sender.send((jvm.clone(), callback_id, result, encoding)) {
         log::error!("Callback channel dead, sweeping all pending futures: {e}");
         // Workers are dead — sweep the entire AsyncRegistry table
         if let Ok(mut env) = jvm.attach_current_thread_as_daemon() {
@@ -492,6 +720,84 @@ pub fn complete_callback(
     }
 }
 
+/// Shut the native layer down, so the library can be unloaded: close every client, shut the runtime down, stop the
+/// callback workers once they delivered the queued callbacks, and fail the futures that are still pending.
+/// Calling it again is a no-op, and the runtime and the workers are started again on their next use.
+///
+/// When it's called from a callback worker or from the runtime, such as from a future's completion, the shutdown
+/// can't wait for the current thread to stop, so it runs on a separate thread instead. A later call waits for it.
+pub fn shutdown_native(env: &mut JNIEnv) {
+    let background_shutdown = BACKGROUND_SHUTDOWN.get_or_init(|| Mutex::new(None));
+    if IS_CALLBACK_WORKER.get() || Handle::try_current().is_ok() {
+        let spawned = thread::Builder::new()
+            .name("glide-shutdown".to_string())
+            .spawn(|| {
+                let Some(jvm) = JVM.get() else {
+                    log::error!("Shutdown: JVM not cached, cannot shut down");
+                    return;
+                };
+                match jvm.attach_current_thread() {
+                    Ok(mut env) => shutdown_now(&mut env),
+                    Err(e) => log::error!("Shutdown: failed to attach to JVM: {e}"),
+                }
+            });
+        match spawned {
+            Ok(thread) => *background_shutdown.lock() = Some(thread),
+            Err(e) => log::error!("Failed to spawn the shutdown thread: {e}"),
+        }
+        return;
+    }
+
+    let pending = background_shutdown.lock().take();
+    if let Some(thread) = pending
+        && thread.join().is_err()
+    {
+        log::error!("The background shutdown panicked");
+    }
+    shutdown_now(env);
+}
+
+fn shutdown_now(env: &mut JNIEnv) {
+    get_pending_map().clear();
+    let handle_table = get_handle_table();
+    let handle_ids: Vec<u64> = handle_table.iter().map(|entry| *entry.key()).collect();
+    let clients: Vec<GlideClient> = handle_ids
+        .into_iter()
+        .filter_map(|handle_id| {
+            crate::stats::remove_client(handle_id);
+            handle_table.remove(&handle_id).map(|(_, client)| client)
+        })
+        .collect();
+    for client in &clients {
+        client.stop_lock_extensions();
+    }
+
+    let runtime = RUNTIME.get().and_then(|runtime| runtime.write().take());
+    if let Some(runtime) = runtime {
+        {
+            // The clients close their connections on drop, which needs the runtime.
+            let _guard = runtime.enter();
+            drop(clients);
+        }
+        runtime.shutdown_timeout(RUNTIME_SHUTDOWN_TIMEOUT);
+    }
+
+    let workers = CALLBACK_WORKERS
+        .get()
+        .and_then(|workers| workers.lock().take());
+    if let Some(workers) = workers {
+        // The workers exit once the queue is drained and its sender is dropped.
+        drop(workers.sender);
+        for thread in workers.threads {
+            if thread.join().is_err() {
+                log::error!("A callback worker panicked during shutdown");
+            }
+        }
+        get_timed_out_callbacks().clear();
+        fail_all_pending_futures(env, "The native library was shut down");
+    }
+}
+
 /// Fail all pending futures in AsyncRegistry by calling failAllWithError from Java.
 /// Used when fatal infrastructure failures are detected (channel dead, native panic).
 pub fn fail_all_pending_futures(env: &mut JNIEnv, error_msg: &str) {
@@ -548,6 +854,69 @@ pub fn complete_java_callback(
     Ok(())
 }
 
+/// Deliver the elements of an array, set or map response to `AsyncRegistry.onPartialResult` in chunks of up to
+/// `chunk_size` elements. Each chunk is converted in its own local frame, so the references of a delivered chunk are
+/// released before the next one is converted. Map entries are delivered as consecutive key and value elements.
+/// Returns the total number of elements as a `Long`, or the converted response if it isn't an array, set or map.
+/// The remaining chunks are dropped once `onPartialResult` returns false, such as after the request timed out.
+fn stream_response_to_java<'local>(
+    env: &mut JNIEnv<'local>,
+    callback_id: jlong,
+    value: ServerValue,
+    encoding_utf8: bool,
+    chunk_size: usize,
+) -> Result<JObject<'local>, crate::errors::FFIError> {
+    let elements = match value {
+        ServerValue::Array(values) | ServerValue::Set(values) => values,
+        ServerValue::Map(entries) => entries
+            .into_iter()
+            .flat_map(|(key, value)| [key, value])
+            .collect(),
+        value => return crate::resp_value_to_java(env, value, encoding_utf8),
+    };
+    let total = elements.len();
+    let mut elements = elements.into_iter();
+    loop {
+        let chunk: Vec<ServerValue> = elements.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        env.push_local_frame(16)?;
+        let delivered = crate::array_to_java_array(env, chunk, encoding_utf8)
+            .map(|chunk| deliver_partial_result(env, callback_id, &chunk));
+        let _ = unsafe { env.pop_local_frame(&JObject::null()) };
+        if !delivered? {
+            break;
+        }
+    }
+    Ok(env.new_object("java/lang/Long", "(J)V", &[JValue::Long(total as jlong)])?)
+}
+
+/// Deliver a chunk of a streamed response to `AsyncRegistry.onPartialResult`, returning whether more chunks are
+/// accepted. A failed delivery is logged, and no more chunks are accepted.
+fn deliver_partial_result(env: &mut JNIEnv, callback_id: jlong, chunk: &JObject) -> bool {
+    let delivered = get_method_cache(env).and_then(|method_cache| {
+        let accepted = unsafe {
+            env.call_static_method_unchecked(
+                &method_cache.async_handle_table_class,
+                method_cache.partial_result_method,
+                jni::signature::ReturnType::Primitive(jni::signature::Primitive::Boolean),
+                &[
+                    JValue::Long(callback_id).as_jni(),
+                    JValue::Object(chunk).as_jni(),
+                ],
+            )
+        }?
+        .z()?;
+        Ok(accepted)
+    });
+    delivered.unwrap_or_else(|e| {
+        log::error!("Partial result delivery failed for callback {callback_id}: {e}");
+        let _ = env.exception_clear();
+        false
+    })
+}
+
 /// Complete Java CompletableFuture with error code and message using cached method IDs.
 pub fn complete_java_callback_with_error_code(
     env: &mut JNIEnv,
@@ -574,12 +943,50 @@ pub fn complete_java_callback_with_error_code(
     Ok(())
 }
 
-/// Check if response should use DirectByteBuffer based on size threshold (16KB)
+/// Complete Java CompletableFuture with a server error, including its error code, whether it's retryable and the
+/// address of the node it originated from.
+pub fn complete_java_callback_with_server_error(
+    env: &mut JNIEnv,
+    callback_id: jlong,
+    err: &ServerError,
+) -> Result<()> {
+    let method_cache = get_method_cache(env)?;
+    let details = error_details(err);
+    let _ = env.push_local_frame(8);
+    let error_string = env.new_string(error_message(err))?;
+    let code_string = match details.code {
+        Some(code) => JObject::from(env.new_string(code)?),
+        None => JObject::null(),
+    };
+    let node_address_string = match details.node_address {
+        Some(address) => JObject::from(env.new_string(address)?),
+        None => JObject::null(),
+    };
+    unsafe {
+        env.call_static_method_unchecked(
+            &method_cache.async_handle_table_class,
+            method_cache.complete_error_with_details_method,
+            jni::signature::ReturnType::Primitive(jni::signature::Primitive::Boolean),
+            &[
+                JValue::Long(callback_id).as_jni(),
+                JValue::Int(error_type(err) as i32).as_jni(),
+                JValue::Object(&error_string).as_jni(),
+                JValue::Object(&code_string).as_jni(),
+                JValue::Bool(details.retryable.into()).as_jni(),
+                JValue::Object(&node_address_string).as_jni(),
+            ],
+        )
+    }?;
+    let _ = unsafe { env.pop_local_frame(&JObject::null()) };
+    Ok(())
+}
+
+/// Check if response should use DirectByteBuffer based on the size threshold (16KB by default)
 fn should_use_direct_buffer(value: &ServerValue) -> bool {
-    const THRESHOLD: usize = 16 * 1024; // 16KB threshold
+    let threshold = DIRECT_BUFFER_THRESHOLD.load(std::sync::atomic::Ordering::Relaxed);
 
     match value {
-        redis::Value::BulkString(data) => data.len() > THRESHOLD,
+        redis::Value::BulkString(data) => data.len() > threshold,
         redis::Value::Array(arr) => {
             // Only offload arrays composed of simple scalar types. Nested arrays/maps lose fidelity
             if arr.iter().any(|elem| !is_simple_scalar(elem)) {
@@ -588,7 +995,7 @@ fn should_use_direct_buffer(value: &ServerValue) -> bool {
 
             // Calculate total estimated size of array elements
             let total_size: usize = arr.iter().map(estimate_value_size).sum();
-            total_size > THRESHOLD
+            total_size > threshold
         }
         redis::Value::Map(map) => {
             // Direct buffers are only safe when both keys and values are bulk strings; complex
@@ -605,7 +1012,7 @@ fn should_use_direct_buffer(value: &ServerValue) -> bool {
                 .iter()
                 .map(|(k, v)| estimate_value_size(k) + estimate_value_size(v))
                 .sum();
-            total_size > THRESHOLD
+            total_size > threshold
         }
         redis::Value::Set(set) => {
             // Sets must also contain only scalar elements to be safely serialized.
@@ -615,7 +1022,7 @@ fn should_use_direct_buffer(value: &ServerValue) -> bool {
 
             // Calculate total size of set elements
             let total_size: usize = set.iter().map(estimate_value_size).sum();
-            total_size > THRESHOLD
+            total_size > threshold
         }
         _ => false, // Other types (Int, Double, Boolean, etc.) are typically small
     }
@@ -665,7 +1072,7 @@ fn estimate_value_size(value: &ServerValue) -> usize {
     }
 }
 
-/// Create DirectByteBuffer for large responses (>16KB) with zero-copy optimization
+/// Create DirectByteBuffer for large responses with zero-copy optimization
 fn create_direct_byte_buffer<'local>(
     env: &mut JNIEnv<'local>,
     value: ServerValue,
@@ -825,14 +1232,7 @@ fn serialize_map_vec_to_bytes(
 
     for (key, value) in map {
         // Serialize key
-        if let redis::Value::BulkString(key_data) = key {
-            bytes.extend_from_slice(&(key_data.len() as u32).to_be_bytes());
-            bytes.extend_from_slice(&key_data);
-        } else {
-            let key_repr = format!("{:?}", key).into_bytes();
-            bytes.extend_from_slice(&(key_repr.len() as u32).to_be_bytes());
-            bytes.extend_from_slice(&key_repr);
-        }
+        write_map_key(&mut bytes, key);
 
         // Serialize value
         if let redis::Value::BulkString(value_data) = value {
@@ -848,6 +1248,38 @@ fn serialize_map_vec_to_bytes(
     Ok(bytes)
 }
 
+/// Writes a map key, prefixed by a type marker like the array elements, so Java reads integer, double and big number
+/// keys back as `Long`, `Double` and `BigInteger` instead of strings holding their digits. Other keys are written as
+/// bulk strings.
+fn write_map_key(bytes: &mut Vec<u8>, key: ServerValue) {
+    let data = match key {
+        redis::Value::Int(n) => {
+            bytes.push(b':'); // Integer marker
+            bytes.extend_from_slice(&n.to_be_bytes());
+            return;
+        }
+        redis::Value::Double(n) => {
+            bytes.push(b','); // Double marker
+            bytes.extend_from_slice(&n.to_be_bytes());
+            return;
+        }
+        redis::Value::BigNumber(n) => {
+            let data = n.to_string().into_bytes();
+            bytes.push(b'('); // BigNumber marker
+            bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(&data);
+            return;
+        }
+        redis::Value::BulkString(data) => data,
+        redis::Value::SimpleString(text) => text.into_bytes(),
+        redis::Value::VerbatimString { text, .. } => text.into_bytes(),
+        key => format!("{:?}", key).into_bytes(),
+    };
+    bytes.push(b'$'); // Bulk string marker
+    bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&data);
+}
+
 /// Extract optional string parameter from JNI.
 pub fn get_optional_string_param_raw(env: &mut JNIEnv, param: jstring) -> Option<String> {
     if param.is_null() {
@@ -885,8 +1317,9 @@ pub extern "system" fn Java_glide_internal_GlideCoreClient_onNativeInit(
     // Cache GlideCoreClient class and method IDs with correct classloader context.
     // The 'class' parameter is GlideCoreClient, already loaded by the application classloader.
     if let Ok(global) = env.new_global_ref(&class)
-        && let (Ok(on_native_push), Ok(register_cleaner)) = (
+        && let (Ok(on_native_push), Ok(on_native_resubscribed), Ok(register_cleaner)) = (
             env.get_static_method_id(&class, "onNativePush", "(J[B[B[B)V"),
+            env.get_static_method_id(&class, "onNativeResubscribed", "(JJ[I[[B)V"),
             env.get_static_method_id(
                 &class,
                 "registerNativeBufferCleaner",
@@ -897,6 +1330,7 @@ pub extern "system" fn Java_glide_internal_GlideCoreClient_onNativeInit(
         let cache = GlideCoreClientCache {
             class: global,
             on_native_push,
+            on_native_resubscribed,
             register_native_buffer_cleaner: register_cleaner,
         };
         let cache_mutex = GLIDE_CORE_CLIENT_CACHE.get_or_init(|| Mutex::new(None));
@@ -915,10 +1349,51 @@ pub extern "system" fn Java_glide_internal_GlideCoreClient_freeNativeBuffer(
     let _ = free_native_buffer(id);
 }
 
+/// Sets the size, in bytes, above which responses are delivered as a DirectByteBuffer backed by native memory.
+/// A negative threshold restores the default of 16KB.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideCoreClient_setDirectBufferThreshold(
+    _env: JNIEnv,
+    _class: JClass,
+    threshold: jlong,
+) {
+    let threshold = usize::try_from(threshold).unwrap_or(DEFAULT_DIRECT_BUFFER_THRESHOLD);
+    DIRECT_BUFFER_THRESHOLD.store(threshold, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns the handle of the native memory backing a DirectByteBuffer response, or 0 if the buffer isn't backed by
+/// a live native buffer, such as after it was released.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideCoreClient_nativeBufferHandle(
+    env: JNIEnv,
+    _class: JClass,
+    buffer: JByteBuffer,
+) -> jlong {
+    if buffer.is_null() {
+        return 0;
+    }
+    env.get_direct_buffer_address(&buffer)
+        .ok()
+        .and_then(|address| native_buffer_handle(address as usize))
+        .map_or(0, |id| id as jlong)
+}
+
+/// Frees the native memory backing a DirectByteBuffer response right away, instead of when the buffer is
+/// garbage collected. Returns false if the buffer was already released.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideCoreClient_releaseNativeBuffer(
+    _env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+) -> jboolean {
+    jboolean::from(free_native_buffer(handle as u64))
+}
+
 #[derive(Clone)]
 struct GlideCoreClientCache {
     class: GlobalRef,
     on_native_push: JStaticMethodID,
+    on_native_resubscribed: JStaticMethodID,
     register_native_buffer_cleaner: JStaticMethodID,
 }
 
@@ -944,6 +1419,8 @@ fn get_glide_core_client_cache_safe(env: &mut JNIEnv) -> Result<GlideCoreClientC
     let class = env.find_class("glide/internal/GlideCoreClient")?;
     let global = env.new_global_ref(&class)?;
     let on_native_push = env.get_static_method_id(&class, "onNativePush", "(J[B[B[B)V")?;
+    let on_native_resubscribed =
+        env.get_static_method_id(&class, "onNativeResubscribed", "(JJ[I[[B)V")?;
     let register_cleaner = env.get_static_method_id(
         &class,
         "registerNativeBufferCleaner",
@@ -953,6 +1430,7 @@ fn get_glide_core_client_cache_safe(env: &mut JNIEnv) -> Result<GlideCoreClientC
     let cache = GlideCoreClientCache {
         class: global,
         on_native_push,
+        on_native_resubscribed,
         register_native_buffer_cleaner: register_cleaner,
     };
 
@@ -966,9 +1444,54 @@ fn get_glide_core_client_cache_safe(env: &mut JNIEnv) -> Result<GlideCoreClientC
 
 #[cfg(test)]
 mod tests {
-    use super::serialize_array_to_bytes;
+    use super::{
+        get_pending_map, register_pending_client, serialize_array_to_bytes,
+        serialize_map_vec_to_bytes, update_pending_config,
+    };
+    use glide_core::client::{ConnectionRequest, NodeAddress};
     use redis::{Value, parse_redis_value};
 
+    fn connection_request(port: u16, cluster_mode_enabled: bool) -> ConnectionRequest {
+        ConnectionRequest {
+            addresses: vec![NodeAddress {
+                host: "localhost".to_string(),
+                port,
+            }],
+            cluster_mode_enabled,
+            ..Default::default()
+        }
+    }
+
+    fn pending_port(handle_id: u64) -> u16 {
+        get_pending_map().get(&handle_id).unwrap().addresses[0].port
+    }
+
+    #[test]
+    fn update_pending_config_replaces_the_configuration_of_a_pending_client() {
+        let handle_id = register_pending_client(connection_request(6379, false));
+
+        update_pending_config(handle_id, connection_request(6380, false)).unwrap();
+        assert_eq!(pending_port(handle_id), 6380);
+
+        get_pending_map().remove(&handle_id);
+    }
+
+    #[test]
+    fn update_pending_config_rejects_invalid_configurations() {
+        let handle_id = register_pending_client(connection_request(6379, false));
+
+        let err = update_pending_config(handle_id, connection_request(6380, true)).unwrap_err();
+        assert!(err.contains("cluster client"), "{err}");
+        let err = update_pending_config(handle_id, ConnectionRequest::default()).unwrap_err();
+        assert!(err.contains("at least one address"), "{err}");
+        // The rejected configurations don't replace the pending one.
+        assert_eq!(pending_port(handle_id), 6379);
+
+        get_pending_map().remove(&handle_id);
+        let err = update_pending_config(handle_id, connection_request(6380, false)).unwrap_err();
+        assert_eq!(err, "Client not found");
+    }
+
     #[test]
     fn serialize_array_to_bytes_encodes_bool_double_bignumber_and_nil() {
         let big_number_value = parse_redis_value(b"(123456789012345678901234567890\r\n").unwrap();
@@ -1015,4 +1538,46 @@ mod tests {
             -1
         );
     }
+
+    #[test]
+    fn serialize_map_vec_to_bytes_marks_the_types_of_the_keys() {
+        let Value::BigNumber(big_number) = parse_redis_value(b"(12345678901234567890\r\n").unwrap()
+        else {
+            panic!("expected big number from parser");
+        };
+        let map = vec![
+            (Value::Int(12), Value::BulkString(b"int".to_vec())),
+            (Value::Double(1.5), Value::BulkString(b"double".to_vec())),
+            (
+                Value::BigNumber(big_number),
+                Value::BulkString(b"big".to_vec()),
+            ),
+            (
+                Value::SimpleString("ok".to_string()),
+                Value::BulkString(b"simple".to_vec()),
+            ),
+        ];
+
+        let bytes = serialize_map_vec_to_bytes(map, false).unwrap();
+
+        // The same bytes are decoded by `CommandManagerDirectBufferTest` on the Java side.
+        let mut expected = vec![b'%'];
+        expected.extend_from_slice(&4u32.to_be_bytes());
+        let mut entry = |key: &[u8], value: &[u8]| {
+            expected.extend_from_slice(key);
+            expected.extend_from_slice(&(value.len() as u32).to_be_bytes());
+            expected.extend_from_slice(value);
+        };
+        entry(&[&[b':'][..], &12i64.to_be_bytes()].concat(), b"int");
+        entry(&[&[b','][..], &1.5f64.to_be_bytes()].concat(), b"double");
+        entry(
+            &[&[b'('][..], &20u32.to_be_bytes(), b"12345678901234567890"].concat(),
+            b"big",
+        );
+        entry(
+            &[&[b'$'][..], &2u32.to_be_bytes(), b"ok"].concat(),
+            b"simple",
+        );
+        assert_eq!(bytes, expected);
+    }
 }
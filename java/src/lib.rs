@@ -19,7 +19,8 @@ use protobuf::Message;
 use jni::JNIEnv;
 use jni::errors::Error as JniError;
 use jni::objects::{
-    GlobalRef, JByteArray, JClass, JMethodID, JObject, JObjectArray, JStaticMethodID, JString,
+    GlobalRef, JBooleanArray, JByteArray, JClass, JLongArray, JMethodID, JObject, JObjectArray,
+    JStaticMethodID, JString,
 };
 use jni::sys::{jint, jlong};
 use parking_lot::Mutex;
@@ -31,8 +32,9 @@ mod errors;
 mod jni_client;
 mod linked_hashmap;
 mod protobuf_bridge;
+mod stats;
 
-use errors::{FFIError, handle_errors, run_ffi};
+use errors::{ExceptionType, FFIError, handle_errors, run_ffi, throw_java_exception};
 use jni_client::*;
 use protobuf_bridge::*;
 
@@ -155,6 +157,13 @@ fn get_jvm_or_complete_error(
     }
 }
 
+fn database_override_unsupported() -> redis::RedisError {
+    redis::RedisError::from((
+        redis::ErrorKind::ClientError,
+        "Database override is only supported for single commands",
+    ))
+}
+
 // Internal helper: execute a parsed CommandRequest and complete Java callback
 async fn execute_command_request_and_complete(
     handle_id: u64,
@@ -162,7 +171,24 @@ async fn execute_command_request_and_complete(
     callback_id: jlong,
     jvm: std::sync::Arc<jni::JavaVM>,
     expect_utf8: bool,
+    blocking: bool,
 ) {
+    let result = execute_command_request(handle_id, command_request, blocking).await;
+    let binary_mode = !expect_utf8;
+    jni_client::complete_callback(jvm, callback_id, result, binary_mode);
+}
+
+// Internal helper: execute a parsed CommandRequest and record it in the statistics of the client
+async fn execute_command_request(
+    handle_id: u64,
+    command_request: protobuf_bridge::CommandRequest,
+    blocking: bool,
+) -> redis::RedisResult<redis::Value> {
+    let is_batch = matches!(
+        command_request.command,
+        Some(protobuf_bridge::command_request::Command::Batch(_))
+    );
+    let start = std::time::Instant::now();
     let result: Result<redis::Value, redis::RedisError> = async {
         let mut client = jni_client::ensure_client_for_handle(handle_id)
             .await
@@ -174,164 +200,228 @@ async fn execute_command_request_and_complete(
                 ))
             })?;
 
-        let root_span_ptr_opt = command_request.root_span_ptr;
-        match &command_request.command {
-            Some(protobuf_bridge::command_request::Command::SingleCommand(command)) => {
-                let mut cmd = protobuf_bridge::create_valkey_command(command).map_err(|e| {
-                    redis::RedisError::from((
-                        redis::ErrorKind::ClientError,
-                        "Failed to create command",
-                        e.to_string(),
-                    ))
-                })?;
-
-                // Compute routing
-                let route_box = command_request.route.0;
-                let routing = if let Some(route_box) = route_box {
-                    protobuf_bridge::get_route(*route_box, Some(&cmd)).map_err(|e| {
+        // The Java registry enforces the inflight requests limit, so only the high watermark sheds requests here.
+        let priority = command_request.priority.enum_value_or_default().into();
+        if !client.reserve_inflight_request_with_priority(priority) {
+            return Err(redis::RedisError::from((
+                redis::ErrorKind::ClientError,
+                "Reached maximum inflight requests",
+            )));
+        }
+        let client_for_release = client.clone();
+        let result = async {
+            let root_span_ptr_opt = command_request.root_span_ptr;
+            let command_span = root_span_ptr_opt.filter(|ptr| *ptr != 0).and_then(|ptr| {
+                unsafe { glide_core::GlideOpenTelemetry::span_from_pointer(ptr) }.ok()
+            });
+            match &command_request.command {
+                Some(protobuf_bridge::command_request::Command::SingleCommand(command)) => {
+                    let mut cmd = protobuf_bridge::create_valkey_command(command).map_err(|e| {
                         redis::RedisError::from((
                             redis::ErrorKind::ClientError,
-                            "Routing error",
+                            "Failed to create command",
                             e.to_string(),
                         ))
-                    })?
-                } else {
-                    None
-                };
+                    })?;
+
+                    // Compute routing
+                    let route_box = command_request.route.0;
+                    let routing = if let Some(route_box) = route_box {
+                        protobuf_bridge::get_route(*route_box, Some(&cmd)).map_err(|e| {
+                            redis::RedisError::from((
+                                redis::ErrorKind::ClientError,
+                                "Routing error",
+                                e.to_string(),
+                            ))
+                        })?
+                    } else {
+                        None
+                    };
 
-                let exec = client.send_command(&mut cmd, routing).await;
+                    let send_command_span = command_span
+                        .as_ref()
+                        .and_then(|span| span.add_span("send_command").ok());
+                    cmd.set_span(command_span);
+                    cmd.set_deadline(
+                        command_request
+                            .deadline_unix_ms
+                            .map(glide_core::client::deadline_from_unix_millis),
+                    );
 
-                if let Some(root_span_ptr) = root_span_ptr_opt
-                    && root_span_ptr != 0
-                {
-                    match unsafe {
-                        glide_core::GlideOpenTelemetry::span_from_pointer(root_span_ptr)
-                    } {
-                        Ok(span) => {
-                            span.end();
-                            unsafe {
-                                std::sync::Arc::from_raw(
-                                    root_span_ptr as *const glide_core::GlideSpan,
+                    let start = std::time::Instant::now();
+                    // A command overriding the database is sent through the side connection of its database,
+                    // which also serves its blocking commands.
+                    let exec = match command_request.database_id {
+                        Some(database_id) => {
+                            client
+                                .send_command_to_database(&mut cmd, routing, database_id)
+                                .await
+                        }
+                        None if blocking => client.send_blocking_command(&mut cmd, routing).await,
+                        None => client.send_command(&mut cmd, routing).await,
+                    };
+                    let exec = if command.normalize_cluster_topology {
+                        exec.and_then(|value| {
+                            glide_core::cluster_topology::normalize_cluster_topology(&cmd, value)
+                        })
+                    } else if command.structured_geo_response {
+                        exec.and_then(|value| {
+                            glide_core::geo_search::convert_geo_search_response(&cmd, value)
+                        })
+                    } else {
+                        exec
+                    };
+                    glide_core::command_latency::record(
+                        command.request_type.into(),
+                        start.elapsed(),
+                    );
+                    if let Some(span) = send_command_span {
+                        span.end();
+                    }
+
+                    if let Some(root_span_ptr) = root_span_ptr_opt
+                        && root_span_ptr != 0
+                    {
+                        match unsafe {
+                            glide_core::GlideOpenTelemetry::span_from_pointer(root_span_ptr)
+                        } {
+                            Ok(span) => {
+                                span.end();
+                                unsafe {
+                                    std::sync::Arc::from_raw(
+                                        root_span_ptr as *const glide_core::GlideSpan,
+                                    );
+                                }
+                            }
+                            Err(err) => {
+                                log::warn!(
+                                    "Failed to finalize OpenTelemetry span: pointer={}, error={}",
+                                    root_span_ptr,
+                                    err
                                 );
                             }
                         }
-                        Err(err) => {
-                            log::warn!(
-                                "Failed to finalize OpenTelemetry span: pointer={}, error={}",
-                                root_span_ptr,
-                                err
-                            );
-                        }
                     }
+                    exec
                 }
-                exec
-            }
-            Some(protobuf_bridge::command_request::Command::Batch(batch)) => {
-                // Build pipeline
-                let mut pipeline = redis::Pipeline::with_capacity(batch.commands.len());
-                if batch.is_atomic {
-                    pipeline.atomic();
-                }
-                for c in &batch.commands {
-                    let valkey_cmd = protobuf_bridge::create_valkey_command(c).map_err(|e| {
-                        redis::RedisError::from((
-                            redis::ErrorKind::ClientError,
-                            "Failed to create batch command",
-                            e.to_string(),
-                        ))
-                    })?;
-                    pipeline.add_command(valkey_cmd);
-                }
-
-                // Routing for batch
-                let route_box = command_request.route.0;
-                let routing = if let Some(route_box) = route_box {
-                    protobuf_bridge::get_route(*route_box, None).map_err(|e| {
-                        redis::RedisError::from((
-                            redis::ErrorKind::ClientError,
-                            "Routing error",
-                            e.to_string(),
-                        ))
-                    })?
-                } else {
-                    None
-                };
-
-                // Child span as per previous behavior
-                let mut send_batch_span: Option<glide_core::GlideSpan> = None;
-                if let Some(root_span_ptr) = root_span_ptr_opt
-                    && root_span_ptr != 0
-                    && let Ok(root_span) =
-                        unsafe { glide_core::GlideOpenTelemetry::span_from_pointer(root_span_ptr) }
-                    && let Ok(child) = root_span.add_span("send_batch")
+                Some(protobuf_bridge::command_request::Command::Batch(_))
+                    if command_request.database_id.is_some() =>
                 {
-                    send_batch_span = Some(child);
+                    Err(database_override_unsupported())
                 }
+                Some(protobuf_bridge::command_request::Command::Batch(batch)) => {
+                    // Build pipeline
+                    let mut pipeline = redis::Pipeline::with_capacity(batch.commands.len());
+                    if batch.is_atomic {
+                        pipeline.atomic();
+                    }
+                    for c in &batch.commands {
+                        let valkey_cmd =
+                            protobuf_bridge::create_valkey_command(c).map_err(|e| {
+                                redis::RedisError::from((
+                                    redis::ErrorKind::ClientError,
+                                    "Failed to create batch command",
+                                    e.to_string(),
+                                ))
+                            })?;
+                        pipeline.add_command(valkey_cmd);
+                    }
 
-                let exec_res = if batch.is_atomic {
-                    client
-                        .send_transaction(
-                            &pipeline,
-                            routing,
-                            batch.timeout,
-                            batch.raise_on_error.unwrap_or(true),
-                        )
-                        .await
-                } else {
-                    client
-                        .send_pipeline(
-                            &pipeline,
-                            routing,
-                            batch.raise_on_error.unwrap_or(true),
-                            batch.timeout,
-                            redis::PipelineRetryStrategy {
-                                retry_server_error: batch.retry_server_error.unwrap_or(false),
-                                retry_connection_error: batch
-                                    .retry_connection_error
-                                    .unwrap_or(false),
-                            },
-                        )
-                        .await
-                };
+                    // Routing for batch
+                    let route_box = command_request.route.0;
+                    let routing = if let Some(route_box) = route_box {
+                        protobuf_bridge::get_route(*route_box, None).map_err(|e| {
+                            redis::RedisError::from((
+                                redis::ErrorKind::ClientError,
+                                "Routing error",
+                                e.to_string(),
+                            ))
+                        })?
+                    } else {
+                        None
+                    };
 
-                if let Some(child) = send_batch_span.as_ref() {
-                    child.end();
-                }
-                if let Some(root_span_ptr) = root_span_ptr_opt
-                    && root_span_ptr != 0
-                {
-                    match unsafe {
-                        glide_core::GlideOpenTelemetry::span_from_pointer(root_span_ptr)
-                    } {
-                        Ok(root_span) => {
-                            root_span.end();
-                            unsafe {
-                                std::sync::Arc::from_raw(
-                                    root_span_ptr as *const glide_core::GlideSpan,
+                    // Child span as per previous behavior
+                    let send_batch_span = command_span
+                        .as_ref()
+                        .and_then(|span| span.add_span("send_batch").ok());
+                    pipeline.set_pipeline_span(command_span);
+
+                    let exec_res = if batch.is_atomic {
+                        client
+                            .send_transaction(
+                                &pipeline,
+                                routing,
+                                batch.timeout,
+                                batch.raise_on_error.unwrap_or(true),
+                            )
+                            .await
+                    } else {
+                        client
+                            .send_pipeline(
+                                &pipeline,
+                                routing,
+                                batch.raise_on_error.unwrap_or(true),
+                                batch.timeout,
+                                redis::PipelineRetryStrategy {
+                                    retry_server_error: batch.retry_server_error.unwrap_or(false),
+                                    retry_connection_error: batch
+                                        .retry_connection_error
+                                        .unwrap_or(false),
+                                    follow_ask_redirects: batch
+                                        .follow_ask_redirects
+                                        .unwrap_or(true),
+                                },
+                            )
+                            .await
+                    };
+
+                    if let Some(child) = send_batch_span.as_ref() {
+                        child.end();
+                    }
+                    if let Some(root_span_ptr) = root_span_ptr_opt
+                        && root_span_ptr != 0
+                    {
+                        match unsafe {
+                            glide_core::GlideOpenTelemetry::span_from_pointer(root_span_ptr)
+                        } {
+                            Ok(root_span) => {
+                                root_span.end();
+                                unsafe {
+                                    std::sync::Arc::from_raw(
+                                        root_span_ptr as *const glide_core::GlideSpan,
+                                    );
+                                }
+                            }
+                            Err(err) => {
+                                log::warn!(
+                                    "Failed to finalize OpenTelemetry span: pointer={}, error={}",
+                                    root_span_ptr,
+                                    err
                                 );
                             }
                         }
-                        Err(err) => {
-                            log::warn!(
-                                "Failed to finalize OpenTelemetry span: pointer={}, error={}",
-                                root_span_ptr,
-                                err
-                            );
-                        }
                     }
+                    exec_res
                 }
-                exec_res
+                _ => Err(redis::RedisError::from((
+                    redis::ErrorKind::ClientError,
+                    "Unsupported command type",
+                ))),
             }
-            _ => Err(redis::RedisError::from((
-                redis::ErrorKind::ClientError,
-                "Unsupported command type",
-            ))),
         }
+        .await;
+        client_for_release.release_inflight_request();
+        result
     }
     .await;
 
-    let binary_mode = !expect_utf8;
-    jni_client::complete_callback(jvm, callback_id, result, binary_mode);
+    if is_batch {
+        stats::record_batch(handle_id, start.elapsed(), result.is_err());
+    } else {
+        stats::record_command(handle_id, start.elapsed(), result.is_err());
+    }
+    result
 }
 
 /// Configuration for OpenTelemetry integration in the Java client.
@@ -435,6 +525,7 @@ fn resp_value_to_java<'local>(
             let linked_hash_map =
                 unsafe { env.new_object_unchecked(cls, cache.linked_hash_map_ctor, &[])? };
 
+            // The keys are converted the same as values, so integer, double and big number keys keep their types.
             for (key, value) in map {
                 let java_key = resp_value_to_java(env, key, encoding_utf8)?;
                 let java_value = resp_value_to_java(env, value, encoding_utf8)?;
@@ -661,6 +752,24 @@ fn array_to_java_array<'local>(
     Ok(items.into())
 }
 
+/// Convert the values of a batch to a Java array, decoding each value as `byte[]` if the binary flag of its command is set.
+fn batch_values_to_java_array<'local>(
+    env: &mut JNIEnv<'local>,
+    values: Vec<Value>,
+    binary_flags: &[bool],
+) -> Result<JObject<'local>, FFIError> {
+    let items: JObjectArray =
+        env.new_object_array(values.len() as i32, "java/lang/Object", JObject::null())?;
+
+    for (i, item) in values.into_iter().enumerate() {
+        let binary = binary_flags.get(i).copied().unwrap_or(false);
+        let java_value = resp_value_to_java(env, item, !binary)?;
+        env.set_object_array_element(&items, i as i32, java_value)?;
+    }
+
+    Ok(items.into())
+}
+
 /// Returns the maximum total length in bytes of request arguments.
 ///
 /// This function is meant to be invoked by Java using JNI. This is used to ensure
@@ -1122,9 +1231,123 @@ pub extern "system" fn Java_glide_ffi_resolvers_StatisticsResolver_getStatistics
         &format!("{}", Telemetry::subscription_last_sync_timestamp()),
     );
 
+    linked_hashmap::put_strings(
+        &mut env,
+        &mut map,
+        "primary_fallback_reads_count",
+        &format!("{}", Telemetry::primary_fallback_reads_count()),
+    );
+
+    linked_hashmap::put_strings(
+        &mut env,
+        &mut map,
+        "circuit_breaker_opened_count",
+        &format!("{}", Telemetry::circuit_breaker_opened_count()),
+    );
+
+    linked_hashmap::put_strings(
+        &mut env,
+        &mut map,
+        "circuit_breaker_rejected_count",
+        &format!("{}", Telemetry::circuit_breaker_rejected_count()),
+    );
+
+    linked_hashmap::put_strings(
+        &mut env,
+        &mut map,
+        "connections_recycled_max_age_count",
+        &format!("{}", Telemetry::connections_recycled_max_age_count()),
+    );
+
+    linked_hashmap::put_strings(
+        &mut env,
+        &mut map,
+        "connections_recycled_idle_count",
+        &format!("{}", Telemetry::connections_recycled_idle_count()),
+    );
+
     map
 }
 
+/// Enables or disables the collection of per-command latency histograms.
+///
+/// This function is meant to be invoked by Java using JNI.
+///
+/// * `_env`    - The JNI environment. Not used.
+/// * `_class`  - The class object. Not used.
+/// * `enabled` - Whether latencies should be collected.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_StatisticsResolver_setCommandLatencyHistogramEnabled<
+    'local,
+>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    enabled: jni::sys::jboolean,
+) {
+    glide_core::command_latency::set_enabled(enabled != 0);
+}
+
+/// Returns a Java's `LinkedHashMap` with the `count`, `p50_us`, `p95_us` and `p99_us` latency percentiles
+/// of a command type, in microseconds.
+///
+/// This function is meant to be invoked by Java using JNI.
+///
+/// * `env`          - The JNI environment.
+/// * `_class`       - The class object. Not used.
+/// * `request_type` - The number of the command's `RequestType`. An unknown or unsupported request type throws an
+///   exception.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_StatisticsResolver_getCommandLatencyPercentiles<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    request_type: jint,
+) -> JObject<'local> {
+    let request_type: glide_core::request_type::RequestType =
+        protobuf::EnumOrUnknown::<glide_core::command_request::RequestType>::from_i32(request_type)
+            .into();
+    if matches!(
+        request_type,
+        glide_core::request_type::RequestType::InvalidRequest
+    ) {
+        throw_java_exception(
+            &mut env,
+            ExceptionType::Exception,
+            "Unknown or unsupported request type",
+        );
+        return JObject::null();
+    }
+    let Some(mut map) = linked_hashmap::new_linked_hashmap(&mut env) else {
+        return JObject::null();
+    };
+
+    let percentiles = glide_core::command_latency::percentiles(request_type);
+    for (name, value) in [
+        ("count", percentiles.count),
+        ("p50_us", percentiles.p50_us),
+        ("p95_us", percentiles.p95_us),
+        ("p99_us", percentiles.p99_us),
+    ] {
+        linked_hashmap::put_strings(&mut env, &mut map, name, &format!("{value}"));
+    }
+
+    map
+}
+
+/// Clears the latency histograms of all command types.
+///
+/// This function is meant to be invoked by Java using JNI.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_StatisticsResolver_resetCommandLatencyHistograms<
+    'local,
+>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+) {
+    glide_core::command_latency::reset();
+}
+
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_ffi_resolvers_OpenTelemetryResolver_initOpenTelemetry<'local>(
     mut env: JNIEnv<'local>,
@@ -1312,6 +1535,56 @@ fn safe_create_jstring<'local>(mut env: JNIEnv<'local>, input: &str) -> JString<
 
 // ==================== JNI CLIENT MANAGEMENT FUNCTIONS ====================
 
+/// Parse a protobuf `ConnectionRequest` into the glide-core connection request.
+fn parse_connection_request(
+    env: &JNIEnv,
+    connection_request_bytes: &JByteArray,
+) -> Result<glide_core::client::ConnectionRequest, String> {
+    // Convert Java byte array to Rust bytes
+    let request_bytes = env
+        .convert_byte_array(connection_request_bytes)
+        .map_err(|e| format!("Failed to convert byte array: {e}"))?;
+
+    // Decompress and parse ConnectionRequest protobuf
+    let request_bytes = glide_core::compression::decompress_payload(&request_bytes)
+        .map_err(|e| format!("Failed to decompress ConnectionRequest: {e}"))?;
+    let request =
+        glide_core::connection_request::ConnectionRequest::parse_from_bytes(&request_bytes)
+            .map_err(|e| format!("Failed to parse ConnectionRequest protobuf: {e}"))?;
+
+    // Convert protobuf to glide_core ConnectionRequest
+    Ok(glide_core::client::ConnectionRequest::from(request))
+}
+
+/// Store a created client in the handle table, and start forwarding its push notifications to Java.
+/// Returns the handle of the client.
+fn register_created_client(
+    client: glide_core::client::Client,
+    mut push_rx: tokio::sync::mpsc::UnboundedReceiver<redis::PushInfo>,
+) -> jlong {
+    let safe_handle = jni_client::generate_safe_handle();
+    let handle_table = get_handle_table();
+
+    // Store in handle table
+    handle_table.insert(safe_handle, client);
+    stats::register_client(safe_handle);
+
+    // Always spawn push forwarder to deliver pushes to Java
+    let jvm_arc = jni_client::JVM.get().cloned();
+    let handle_for_java = safe_handle as jlong;
+    get_runtime().spawn(async move {
+        while let Some(push) = push_rx.recv().await {
+            if let Some(jvm) = jvm_arc.as_ref()
+                && let Ok(mut env) = jvm.attach_current_thread_as_daemon()
+            {
+                handle_push_notification(&mut env, handle_for_java, push);
+            }
+        }
+    });
+
+    handle_for_java
+}
+
 /// Create Valkey client and store handle.
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
@@ -1320,29 +1593,14 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
     connection_request_bytes: JByteArray,
 ) -> jlong {
     run_ffi(|| {
-        // Convert Java byte array to Rust bytes
-        let request_bytes = match env.convert_byte_array(&connection_request_bytes) {
-            Ok(bytes) => bytes,
-            Err(e) => {
-                log::error!("Failed to convert byte array: {e}");
-                return Some(0);
-            }
-        };
-
-        // Parse ConnectionRequest protobuf
-        let request = match glide_core::connection_request::ConnectionRequest::parse_from_bytes(
-            &request_bytes,
-        ) {
-            Ok(req) => req,
+        let connection_request = match parse_connection_request(&env, &connection_request_bytes) {
+            Ok(request) => request,
             Err(e) => {
-                log::error!("Failed to parse ConnectionRequest protobuf: {e}");
+                log::error!("{e}");
                 return Some(0);
             }
         };
 
-        // Convert protobuf to glide_core ConnectionRequest
-        let connection_request = glide_core::client::ConnectionRequest::from(request);
-
         // Cache JVM for push callbacks
         if let Ok(jvm) = env.get_java_vm() {
             let _ = jni_client::JVM.set(Arc::new(jvm));
@@ -1356,29 +1614,7 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<redis::PushInfo>();
 
         match runtime.block_on(async { create_glide_client(connection_request, Some(tx)).await }) {
-            Ok(client) => {
-                let safe_handle = jni_client::generate_safe_handle();
-                let handle_table = get_handle_table();
-
-                // Store in handle table
-                handle_table.insert(safe_handle, client);
-
-                // Always spawn push forwarder to deliver pushes to Java
-                let jvm_arc = jni_client::JVM.get().cloned();
-                let handle_for_java = safe_handle as jlong;
-                get_runtime().spawn(async move {
-                    let mut rx = rx;
-                    while let Some(push) = rx.recv().await {
-                        if let Some(jvm) = jvm_arc.as_ref()
-                            && let Ok(mut env) = jvm.attach_current_thread_as_daemon()
-                        {
-                            handle_push_notification(&mut env, handle_for_java, push);
-                        }
-                    }
-                });
-
-                Some(safe_handle as jlong)
-            }
+            Ok(client) => Some(register_created_client(client, rx)),
             Err(e) => {
                 log::error!("Failed to create client: {e}");
                 Some(0)
@@ -1388,6 +1624,106 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
     .unwrap_or(0)
 }
 
+/// Create Valkey client without blocking the calling thread.
+///
+/// Returns immediately, and completes `callback_id` with the handle of the client once it's connected, or with
+/// the connection error.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClientAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    connection_request_bytes: JByteArray,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let connection_request = match parse_connection_request(&env, &connection_request_bytes) {
+            Ok(request) => request,
+            Err(e) => {
+                complete_callback_with_error_on_caller(&mut env, callback_id, &e);
+                return Some(());
+            }
+        };
+
+        let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "createClientAsync")
+        else {
+            return Some(());
+        };
+        // Cache JVM for push callbacks
+        let _ = jni_client::JVM.set(jvm.clone());
+
+        // Always create push channel to support dynamic subscriptions via customCommand
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<redis::PushInfo>();
+
+        get_runtime().spawn(async move {
+            let result = create_glide_client(connection_request, Some(tx))
+                .await
+                .map(|client| Value::Int(register_created_client(client, rx)))
+                .map_err(|e| {
+                    redis::RedisError::from((
+                        redis::ErrorKind::IoError,
+                        "Failed to create client",
+                        e.to_string(),
+                    ))
+                });
+            complete_callback(jvm, callback_id, result, false);
+        });
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
+/// Register a Valkey client that's created on the first command sent with its handle, so its configuration may still
+/// be changed with `updatePendingConfig`. Returns the handle of the client, or 0 if the connection request is invalid.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_registerLazyClient(
+    env: JNIEnv,
+    _class: JClass,
+    connection_request_bytes: JByteArray,
+) -> jlong {
+    run_ffi(|| {
+        let connection_request = match parse_connection_request(&env, &connection_request_bytes) {
+            Ok(request) => request,
+            Err(e) => {
+                log::error!("{e}");
+                return Some(0);
+            }
+        };
+
+        // Cache JVM for push callbacks
+        if let Ok(jvm) = env.get_java_vm() {
+            let _ = jni_client::JVM.set(Arc::new(jvm));
+        }
+
+        let handle_id = jni_client::register_pending_client(connection_request);
+        stats::register_client(handle_id);
+        Some(handle_id as jlong)
+    })
+    .unwrap_or(0)
+}
+
+/// Replace the configuration of a client registered with `registerLazyClient` that wasn't created yet.
+/// Throws a `RuntimeException` if the client was already created, or if the configuration is invalid or changes
+/// whether the client is a cluster client.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_updatePendingConfig(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+    config_bytes: JByteArray,
+) {
+    run_ffi(|| {
+        let result = parse_connection_request(&env, &config_bytes).and_then(|connection_request| {
+            jni_client::update_pending_config(handle as u64, connection_request)
+        });
+        if let Err(e) = result {
+            throw_java_exception(&mut env, ExceptionType::RuntimeException, &e);
+        }
+        Some(())
+    })
+    .unwrap_or(())
+}
+
 /// Execute Valkey command asynchronously using protobuf with FFI-imported routing.
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeCommandAsync(
@@ -1414,6 +1750,7 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeCommandAsync
             callback_id,
             jvm,
             true, // executeCommandAsync expects UTF-8 decoding
+            false,
         ));
 
         Some(())
@@ -1421,6 +1758,124 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeCommandAsync
     .unwrap_or(())
 }
 
+/// Execute many independent commands in a single JNI call, to amortize the cost of crossing JNI for small commands.
+/// `framed_requests` holds the serialized `CommandRequest` of each command prefixed with its length as a big-endian
+/// 32-bit integer, `callback_ids` the callback of each command, and `expect_utf8_responses` whether the response of
+/// each command is decoded as UTF-8 strings, in the same order. The commands aren't pipelined: each is executed
+/// concurrently and completes its own callback, as if submitted with `executeCommandAsync` or
+/// `executeBinaryCommandAsync`.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeCommandsBulkAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    framed_requests: JByteArray,
+    callback_ids: JLongArray,
+    expect_utf8_responses: JBooleanArray,
+) {
+    run_ffi(|| {
+        let callback_ids = match env.get_array_length(&callback_ids).and_then(|length| {
+            let mut ids = vec![0; length as usize];
+            env.get_long_array_region(&callback_ids, 0, &mut ids)?;
+            Ok(ids)
+        }) {
+            Ok(ids) => ids,
+            Err(e) => {
+                log::error!("Failed to read callback ids of bulk commands: {e}");
+                return Some(());
+            }
+        };
+        let fail_all = |env: &mut JNIEnv, msg: &str| {
+            for callback_id in &callback_ids {
+                complete_callback_with_error_on_caller(env, *callback_id, msg);
+            }
+        };
+        let expect_utf8_responses =
+            match env
+                .get_array_length(&expect_utf8_responses)
+                .and_then(|length| {
+                    let mut flags = vec![0; length as usize];
+                    env.get_boolean_array_region(&expect_utf8_responses, 0, &mut flags)?;
+                    Ok(flags)
+                }) {
+                Ok(flags) if flags.len() == callback_ids.len() => flags,
+                Ok(flags) => {
+                    let msg = format!(
+                        "Received {} response flags for {} callbacks",
+                        flags.len(),
+                        callback_ids.len()
+                    );
+                    fail_all(&mut env, &msg);
+                    return Some(());
+                }
+                Err(e) => {
+                    fail_all(&mut env, &format!("Failed to read response flags: {e}"));
+                    return Some(());
+                }
+            };
+        let raw_bytes = match env.convert_byte_array(&framed_requests) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                fail_all(&mut env, &format!("Failed to read request bytes: {e}"));
+                return Some(());
+            }
+        };
+        let requests = match protobuf_bridge::split_framed_requests(&raw_bytes) {
+            Ok(requests) if requests.len() == callback_ids.len() => requests,
+            Ok(requests) => {
+                let msg = format!(
+                    "Received {} framed requests for {} callbacks",
+                    requests.len(),
+                    callback_ids.len()
+                );
+                fail_all(&mut env, &msg);
+                return Some(());
+            }
+            Err(e) => {
+                fail_all(&mut env, &format!("Failed to split framed requests: {e}"));
+                return Some(());
+            }
+        };
+        let Some(jvm) = callback_ids.first().and_then(|callback_id| {
+            get_jvm_or_complete_error(&mut env, *callback_id, "executeCommandsBulkAsync")
+        }) else {
+            // The first callback was already completed with the error, if any.
+            for callback_id in callback_ids.iter().skip(1) {
+                complete_callback_with_error_on_caller(&mut env, *callback_id, "Failed to get JVM");
+            }
+            return Some(());
+        };
+
+        let handle_id = client_ptr as u64;
+        let runtime = get_runtime();
+        for ((request, callback_id), expect_utf8) in requests
+            .into_iter()
+            .zip(callback_ids.iter().copied())
+            .zip(expect_utf8_responses)
+        {
+            let command_request = match protobuf_bridge::parse_command_request(request) {
+                Ok(command_request) => command_request,
+                Err(e) => {
+                    let msg = format!("Failed to parse command request: {e}");
+                    complete_callback_with_error_on_caller(&mut env, callback_id, &msg);
+                    continue;
+                }
+            };
+            runtime.spawn(execute_command_request_and_complete(
+                handle_id,
+                command_request,
+                callback_id,
+                jvm.clone(),
+                expect_utf8 != 0,
+                false,
+            ));
+        }
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
 /// Close client and release resources.
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_internal_GlideNativeBridge_closeClient(
@@ -1432,8 +1887,13 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_closeClient(
         let handle_table = get_handle_table();
         let handle_id = client_ptr as u64;
 
+        stats::remove_client(handle_id);
+        // A client that wasn't created yet is only registered with its configuration
+        jni_client::get_pending_map().remove(&handle_id);
         // DashMap operations are sync and lock-free
         if let Some((_, client)) = handle_table.remove(&handle_id) {
+            // The lock extension tasks hold a clone of the client, which would keep its connections open.
+            client.stop_lock_extensions();
             // Schedule async cleanup
             let runtime = get_runtime();
             runtime.spawn(async move {
@@ -1446,6 +1906,20 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_closeClient(
     .unwrap_or(())
 }
 
+/// Shut the native layer down, so the native library can be unloaded: close every client handle, shut the runtime
+/// down, stop the callback workers and fail the futures that are still pending. Idempotent.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_shutdownNative(
+    mut env: JNIEnv,
+    _class: JClass,
+) {
+    run_ffi(|| {
+        jni_client::shutdown_native(&mut env);
+        Some(())
+    })
+    .unwrap_or(())
+}
+
 /// Check if client handle exists.
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_internal_GlideNativeBridge_isConnected(
@@ -1456,7 +1930,10 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_isConnected(
     run_ffi(|| {
         let handle_table = get_handle_table();
         let handle_id = client_ptr as u64;
-        if handle_table.contains_key(&handle_id) {
+        // A client registered with `registerLazyClient` is created by its first command
+        if handle_table.contains_key(&handle_id)
+            || jni_client::get_pending_map().contains_key(&handle_id)
+        {
             Some(1)
         } else {
             Some(0)
@@ -1484,6 +1961,9 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_getClientInfo<'loca
                 // Return basic client information
                 let info = format!("Client handle: {}, Status: Connected", handle_id);
                 Ok(env.new_string(info)?)
+            } else if jni_client::get_pending_map().contains_key(&handle_id) {
+                let info = format!("Client handle: {}, Status: Pending", handle_id);
+                Ok(env.new_string(info)?)
             } else {
                 let info = format!("Client handle: {}, Status: Not found", handle_id);
                 Ok(env.new_string(info)?)
@@ -1495,6 +1975,42 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_getClientInfo<'loca
     .unwrap_or(JString::default())
 }
 
+/// Returns a Java's `LinkedHashMap` with the statistics collected for a client handle: the number of commands,
+/// batches and errors, and the average and maximum request latency in microseconds, along with the
+/// `total_connections` and `total_clients` of glide-core. Returns `null` if the handle doesn't exist.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_getClientStatistics<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    client_ptr: jlong,
+) -> JObject<'local> {
+    run_ffi(|| {
+        let Some(client_stats) = stats::get_client_statistics(client_ptr as u64) else {
+            return Some(JObject::null());
+        };
+        let mut map = linked_hashmap::new_linked_hashmap(&mut env)?;
+
+        linked_hashmap::put_strings(
+            &mut env,
+            &mut map,
+            "total_connections",
+            &format!("{}", Telemetry::total_connections()),
+        );
+        linked_hashmap::put_strings(
+            &mut env,
+            &mut map,
+            "total_clients",
+            &format!("{}", Telemetry::total_clients()),
+        );
+        for (name, value) in client_stats.to_pairs() {
+            linked_hashmap::put_strings(&mut env, &mut map, name, &format!("{value}"));
+        }
+
+        Some(map)
+    })
+    .unwrap_or(JObject::null())
+}
+
 /// Get glide-core default connection timeout in milliseconds
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_glide_internal_GlideNativeBridge_getGlideCoreDefaultConnectionTimeoutMs(
@@ -1556,6 +2072,15 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
             let root_span_ptr_opt = command_request.root_span_ptr;
             let route = command_request.route.0.map(|r| *r);
 
+            if command_request.database_id.is_some() {
+                complete_callback_with_error_on_caller(
+                    &mut env,
+                    callback_id,
+                    &database_override_unsupported().to_string(),
+                );
+                return Some(());
+            }
+
             // Extract the batch from the command request (take ownership to avoid clone)
             let batch = match command_request.command {
                 Some(command_request::Command::Batch(batch)) => batch,
@@ -1569,6 +2094,19 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
                 }
             };
 
+            // Commands may override the batch-level encoding of their own result
+            let binary_flags = batch
+                .commands
+                .iter()
+                .any(|cmd| cmd.binary_output.is_some())
+                .then(|| {
+                    batch
+                        .commands
+                        .iter()
+                        .map(|cmd| cmd.binary_output.unwrap_or(expect_utf8 == 0))
+                        .collect::<Vec<_>>()
+                });
+
             let handle_id = client_ptr as u64;
             let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "executeBatchAsync")
             else {
@@ -1579,6 +2117,7 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
                 let client_result = ensure_client_for_handle(handle_id).await;
                 match client_result {
                     Ok(mut client) => {
+                        let start = std::time::Instant::now();
                         // Execute batch using existing FFI methodology
                         let result: Result<redis::Value, redis::RedisError> = async {
                             // If we have a root span, create a child span named "send_batch" to match expectations
@@ -1647,6 +2186,9 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
                                             retry_connection_error: batch
                                                 .retry_connection_error
                                                 .unwrap_or(false),
+                                            follow_ask_redirects: batch
+                                                .follow_ask_redirects
+                                                .unwrap_or(true),
                                         },
                                     )
                                     .await
@@ -1683,9 +2225,13 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
                             exec_res
                         }
                         .await;
+                        stats::record_batch(handle_id, start.elapsed(), result.is_err());
 
-                        let binary_mode = expect_utf8 == 0;
-                        complete_callback(jvm, callback_id, result, binary_mode);
+                        let encoding = match binary_flags {
+                            Some(binary_flags) => ResponseEncoding::PerCommand(binary_flags),
+                            None => ResponseEncoding::from_binary_mode(expect_utf8 == 0),
+                        };
+                        complete_callback_with_encoding(jvm, callback_id, result, encoding);
                     }
                     Err(err) => {
                         let error = Err(redis::RedisError::from((
@@ -1731,6 +2277,83 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBinaryComman
             callback_id,
             jvm,
             false, // binary entrypoint expects binary decoding
+            false,
+        ));
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
+/// Execute a command asynchronously, delivering the elements of an array, set or map reply to
+/// `AsyncRegistry.onPartialResult` in chunks of up to `chunk_size` elements, instead of as a single `Object[]`.
+/// Map entries are delivered as consecutive key and value elements. Once all the chunks are delivered, the callback is
+/// completed with the total number of elements as a `Long`. Other replies complete the callback as is.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeStreamingCommandAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    request_bytes: JByteArray,
+    expect_utf8_response: jni::sys::jboolean,
+    chunk_size: jint,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let Some(command_request) = parse_request_bytes(&mut env, &request_bytes, callback_id)
+        else {
+            return Some(());
+        };
+        let Some(jvm) =
+            get_jvm_or_complete_error(&mut env, callback_id, "executeStreamingCommandAsync")
+        else {
+            return Some(());
+        };
+
+        let handle_id = client_ptr as u64;
+        let encoding = jni_client::ResponseEncoding::Streamed {
+            binary: expect_utf8_response == 0,
+            chunk_size: chunk_size.max(1) as usize,
+        };
+        get_runtime().spawn(async move {
+            let result = execute_command_request(handle_id, command_request, false).await;
+            jni_client::complete_callback_with_encoding(jvm, callback_id, result, encoding);
+        });
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
+/// Execute a blocking command asynchronously through a connection of its own, so it doesn't delay the other commands.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBlockingCommandAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    request_bytes: JByteArray,
+    expect_utf8_response: jni::sys::jboolean,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let Some(command_request) = parse_request_bytes(&mut env, &request_bytes, callback_id)
+        else {
+            return Some(());
+        };
+        let Some(jvm) =
+            get_jvm_or_complete_error(&mut env, callback_id, "executeBlockingCommandAsync")
+        else {
+            return Some(());
+        };
+
+        let handle_id = client_ptr as u64;
+        get_runtime().spawn(execute_command_request_and_complete(
+            handle_id,
+            command_request,
+            callback_id,
+            jvm,
+            expect_utf8_response != 0,
+            true,
         ));
 
         Some(())
@@ -1752,6 +2375,7 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeScriptAsync(
     route_type: jint,
     route_param: JString,
     expect_utf8: jni::sys::jboolean,
+    read_only: jni::sys::jboolean,
 ) {
     run_ffi(|| {
         let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "executeScriptAsync")
@@ -1950,8 +2574,12 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeScriptAsync(
                             }
                         }
                     } else {
-                        // Auto route by constructing EVALSHA-shaped command
-                        let mut route_cmd = redis::cmd("EVALSHA");
+                        // Auto route by constructing EVALSHA-shaped command, so read-only scripts may be routed to replicas
+                        let mut route_cmd = redis::cmd(if read_only != 0 {
+                            "EVALSHA_RO"
+                        } else {
+                            "EVALSHA"
+                        });
                         route_cmd.arg(hash_str.as_bytes());
                         route_cmd.arg(keys_data.len());
                         for k in &keys_data {
@@ -1978,21 +2606,24 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeScriptAsync(
                         }
                     };
 
-                    let result = client
-                        .invoke_script(
-                            &hash_str,
-                            &keys_data.iter().map(|k| k.as_slice()).collect::<Vec<_>>(),
-                            &args_data.iter().map(|a| a.as_slice()).collect::<Vec<_>>(),
-                            routing_info,
-                        )
-                        .await
-                        .map_err(|e| {
-                            redis::RedisError::from((
-                                redis::ErrorKind::ClientError,
-                                "Script execution failed",
-                                e.to_string(),
-                            ))
-                        });
+                    let keys = keys_data.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+                    let args = args_data.iter().map(|a| a.as_slice()).collect::<Vec<_>>();
+                    let result = if read_only != 0 {
+                        client
+                            .invoke_script_ro(&hash_str, &keys, &args, routing_info)
+                            .await
+                    } else {
+                        client
+                            .invoke_script(&hash_str, &keys, &args, routing_info)
+                            .await
+                    }
+                    .map_err(|e| {
+                        redis::RedisError::from((
+                            redis::ErrorKind::ClientError,
+                            "Script execution failed",
+                            e.to_string(),
+                        ))
+                    });
 
                     let binary_mode = expect_utf8 == 0;
                     complete_callback(jvm, callback_id, result, binary_mode);
@@ -2117,6 +2748,184 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_refreshIamToken(
     .unwrap_or(())
 }
 
+/// Read a byte array parameter, completing the callback with an error on failure.
+fn read_byte_array_param(
+    env: &mut JNIEnv,
+    array: &JByteArray,
+    callback_id: jlong,
+    name: &str,
+) -> Option<Vec<u8>> {
+    match env.convert_byte_array(array) {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            let msg = format!("Failed to read {name}: {e}");
+            complete_callback_with_error_on_caller(env, callback_id, &msg);
+            None
+        }
+    }
+}
+
+/// Acquire a distributed lock on a key, optionally extending it automatically until it's released.
+/// The callback is completed with the token of the lock, or null if the lock is held by another owner.
+/// When `lock_lost_callback_id` isn't 0, it's completed with an error if the automatically extended lock is lost,
+/// and with null once the lock is released, or right away if the lock isn't extended automatically.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_acquireLockAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    key: JByteArray,
+    ttl_ms: jlong,
+    auto_extend: jni::sys::jboolean,
+    lock_lost_callback_id: jlong,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let handle_id = client_ptr as u64;
+        let auto_extend = auto_extend != 0;
+        let Some(key) = read_byte_array_param(&mut env, &key, callback_id, "lock key") else {
+            return Some(());
+        };
+        let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "acquireLockAsync") else {
+            return Some(());
+        };
+
+        get_runtime().spawn(async move {
+            let complete_lock_lost = |jvm: Arc<jni::JavaVM>, result| {
+                if lock_lost_callback_id != 0 {
+                    complete_callback(jvm, lock_lost_callback_id, result, false);
+                }
+            };
+            let mut client = match ensure_client_for_handle(handle_id).await {
+                Ok(client) => client,
+                Err(err) => {
+                    let error = redis::RedisError::from((
+                        redis::ErrorKind::ClientError,
+                        "Client not found",
+                        err.to_string(),
+                    ));
+                    complete_lock_lost(jvm.clone(), Ok(Value::Nil));
+                    complete_callback(jvm, callback_id, Err(error), false);
+                    return;
+                }
+            };
+            let listener: Option<glide_core::distributed_lock::LockExtensionListener> =
+                (lock_lost_callback_id != 0).then(|| {
+                    let jvm = jvm.clone();
+                    Arc::new(move |_key: &[u8], reason: Option<&str>| {
+                        let result = match reason {
+                            Some(reason) => Err(redis::RedisError::from((
+                                redis::ErrorKind::ClientError,
+                                "Lock lost",
+                                reason.to_string(),
+                            ))),
+                            None => Ok(Value::Nil),
+                        };
+                        complete_callback(jvm.clone(), lock_lost_callback_id, result, false);
+                    }) as glide_core::distributed_lock::LockExtensionListener
+                });
+            let result = client
+                .acquire_lock(
+                    &key,
+                    std::time::Duration::from_millis(ttl_ms.max(0) as u64),
+                    auto_extend,
+                    listener,
+                )
+                .await;
+            // The listener is only called for a lock that is extended automatically.
+            if !auto_extend || !matches!(result, Ok(Value::BulkString(_))) {
+                complete_lock_lost(jvm.clone(), Ok(Value::Nil));
+            }
+            complete_callback(jvm, callback_id, result, false);
+        });
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
+/// Release a distributed lock if it's held with the token, stopping its automatic extension.
+/// The callback is completed with whether the lock was released.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_releaseLockAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    key: JByteArray,
+    token: JByteArray,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let handle_id = client_ptr as u64;
+        let Some(key) = read_byte_array_param(&mut env, &key, callback_id, "lock key") else {
+            return Some(());
+        };
+        let Some(token) = read_byte_array_param(&mut env, &token, callback_id, "lock token") else {
+            return Some(());
+        };
+        let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "releaseLockAsync") else {
+            return Some(());
+        };
+
+        get_runtime().spawn(async move {
+            let result = match ensure_client_for_handle(handle_id).await {
+                Ok(mut client) => client.release_lock(&key, &token).await,
+                Err(err) => Err(redis::RedisError::from((
+                    redis::ErrorKind::ClientError,
+                    "Client not found",
+                    err.to_string(),
+                ))),
+            };
+            complete_callback(jvm, callback_id, result, false);
+        });
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
+/// Set the expiration of a distributed lock if it's held with the token.
+/// The callback is completed with whether the lock was extended.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_internal_GlideNativeBridge_extendLockAsync(
+    mut env: JNIEnv,
+    _class: JClass,
+    client_ptr: jlong,
+    key: JByteArray,
+    token: JByteArray,
+    ttl_ms: jlong,
+    callback_id: jlong,
+) {
+    run_ffi(|| {
+        let handle_id = client_ptr as u64;
+        let Some(key) = read_byte_array_param(&mut env, &key, callback_id, "lock key") else {
+            return Some(());
+        };
+        let Some(token) = read_byte_array_param(&mut env, &token, callback_id, "lock token") else {
+            return Some(());
+        };
+        let Some(jvm) = get_jvm_or_complete_error(&mut env, callback_id, "extendLockAsync") else {
+            return Some(());
+        };
+
+        get_runtime().spawn(async move {
+            let ttl = std::time::Duration::from_millis(ttl_ms.max(0) as u64);
+            let result = match ensure_client_for_handle(handle_id).await {
+                Ok(mut client) => client.extend_lock(&key, &token, ttl).await,
+                Err(err) => Err(redis::RedisError::from((
+                    redis::ErrorKind::ClientError,
+                    "Client not found",
+                    err.to_string(),
+                ))),
+            };
+            complete_callback(jvm, callback_id, result, false);
+        });
+
+        Some(())
+    })
+    .unwrap_or(())
+}
+
 /// JNI bridge for cluster scan that properly manages cursor lifecycle
 /// This reuses the existing cluster scan logic from glide-core
 #[unsafe(no_mangle)]
@@ -13,12 +13,37 @@ use glide_core::command_request::SimpleRoutes;
 use glide_core::command_request::SlotTypes;
 pub use glide_core::command_request::{Command, CommandRequest, Routes, command_request};
 
-/// Parse CommandRequest from protobuf bytes (using existing protobuf parsing)
+/// Parse CommandRequest from protobuf bytes (using existing protobuf parsing).
+/// Large requests, such as batches, may be compressed with LZ4 by the caller.
 pub fn parse_command_request(bytes: &[u8]) -> Result<CommandRequest> {
-    CommandRequest::parse_from_bytes(bytes)
+    let bytes = glide_core::compression::decompress_payload(bytes)
+        .map_err(|e| anyhow!("Failed to decompress CommandRequest: {}", e))?;
+    CommandRequest::parse_from_bytes(&bytes)
         .map_err(|e| anyhow!("Failed to parse CommandRequest protobuf: {}", e))
 }
 
+/// Split requests framed by `executeCommandsBulkAsync`, each prefixed with its length as a big-endian 32-bit integer
+pub fn split_framed_requests(bytes: &[u8]) -> Result<Vec<&[u8]>> {
+    let mut requests = Vec::new();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        let Some((length, rest)) = remaining.split_first_chunk::<4>() else {
+            return Err(anyhow!("Truncated length prefix of framed request"));
+        };
+        let length = u32::from_be_bytes(*length) as usize;
+        if rest.len() < length {
+            return Err(anyhow!(
+                "Framed request of {length} bytes exceeds the remaining {} bytes",
+                rest.len()
+            ));
+        }
+        let (request, rest) = rest.split_at(length);
+        requests.push(request);
+        remaining = rest;
+    }
+    Ok(requests)
+}
+
 /// Since socket_listener functions are private, we'll need to access the core request_type logic
 /// This reuses the same pattern as socket_listener but makes it accessible for JNI
 pub fn create_valkey_command(command: &Command) -> Result<redis::Cmd> {
@@ -159,6 +184,18 @@ pub(crate) fn get_route(route: Routes, cmd: Option<&Cmd>) -> RedisResult<Option<
                 format!("Value: {}", by_address_route.port),
             ))),
         },
+        Value::AzAffinityRoute(az_affinity_route) if az_affinity_route.az.is_empty() => {
+            Err(RedisError::from((
+                redis::ErrorKind::ClientError,
+                "AZ affinity routes require an availability zone",
+            )))
+        }
+        Value::AzAffinityRoute(az_affinity_route) => Ok(Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::ReplicaInAZ {
+                slot: redis::cluster_topology::get_slot(az_affinity_route.slot_key.as_bytes()),
+                az: az_affinity_route.az.to_string(),
+            },
+        ))),
         _ => Err(RedisError::from((
             redis::ErrorKind::ClientError,
             "Unknown route type.",
@@ -166,3 +203,49 @@ pub(crate) fn get_route(route: Routes, cmd: Option<&Cmd>) -> RedisResult<Option<
         ))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::cluster_routing::LogicalAggregateOp;
+
+    #[test]
+    fn script_exists_routes_aggregate_the_replies_of_all_nodes() {
+        let mut cmd = redis::cmd("SCRIPT");
+        cmd.arg("EXISTS").arg("sha1").arg("sha2");
+        for (simple_route, nodes) in [
+            (SimpleRoutes::AllNodes, MultipleNodeRoutingInfo::AllNodes),
+            (
+                SimpleRoutes::AllPrimaries,
+                MultipleNodeRoutingInfo::AllMasters,
+            ),
+        ] {
+            let mut route = Routes::new();
+            route.set_simple_routes(simple_route);
+            assert_eq!(
+                get_route(route, Some(&cmd)).unwrap(),
+                Some(RoutingInfo::MultiNode((
+                    nodes,
+                    Some(ResponsePolicy::AggregateLogical(LogicalAggregateOp::And)),
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn framed_requests_are_split_by_their_length_prefix() {
+        let mut framed = Vec::new();
+        for request in [&b"first"[..], b"", b"third request"] {
+            framed.extend_from_slice(&(request.len() as u32).to_be_bytes());
+            framed.extend_from_slice(request);
+        }
+        assert_eq!(
+            split_framed_requests(&framed).unwrap(),
+            vec![&b"first"[..], b"", b"third request"]
+        );
+        assert!(split_framed_requests(&[]).unwrap().is_empty());
+
+        assert!(split_framed_requests(&framed[..framed.len() - 1]).is_err());
+        assert!(split_framed_requests(&[0, 0, 1]).is_err());
+    }
+}
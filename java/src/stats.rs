@@ -0,0 +1,122 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Per-handle statistics of the clients created through `GlideNativeBridge`.
+
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// Counters of a single client handle. Commands and batches are counted separately,
+/// while errors and latencies include both.
+#[derive(Default)]
+pub(crate) struct ClientStatistics {
+    commands_count: AtomicU64,
+    batches_count: AtomicU64,
+    errors_count: AtomicU64,
+    total_latency_us: AtomicU64,
+    max_latency_us: AtomicU64,
+}
+
+impl ClientStatistics {
+    fn record(&self, latency: Duration, is_error: bool) {
+        let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        if is_error {
+            self.errors_count.fetch_add(1, Ordering::Relaxed);
+        }
+        self.total_latency_us
+            .fetch_add(latency_us, Ordering::Relaxed);
+        self.max_latency_us.fetch_max(latency_us, Ordering::Relaxed);
+    }
+
+    /// Returns the statistics as name / value pairs, in the order they are reported to Java.
+    pub(crate) fn to_pairs(&self) -> Vec<(&'static str, u64)> {
+        let commands_count = self.commands_count.load(Ordering::Relaxed);
+        let batches_count = self.batches_count.load(Ordering::Relaxed);
+        let requests_count = commands_count + batches_count;
+        let average_latency_us = match requests_count {
+            0 => 0,
+            count => self.total_latency_us.load(Ordering::Relaxed) / count,
+        };
+        vec![
+            ("commands_count", commands_count),
+            ("batches_count", batches_count),
+            ("errors_count", self.errors_count.load(Ordering::Relaxed)),
+            ("average_latency_us", average_latency_us),
+            (
+                "max_latency_us",
+                self.max_latency_us.load(Ordering::Relaxed),
+            ),
+        ]
+    }
+}
+
+static CLIENT_STATISTICS: OnceLock<DashMap<u64, Arc<ClientStatistics>>> = OnceLock::new();
+
+fn client_statistics() -> &'static DashMap<u64, Arc<ClientStatistics>> {
+    CLIENT_STATISTICS.get_or_init(DashMap::new)
+}
+
+/// Starts collecting statistics for a newly created client handle.
+pub(crate) fn register_client(handle_id: u64) {
+    client_statistics().insert(handle_id, Arc::default());
+}
+
+/// Stops collecting statistics for a closed client handle.
+pub(crate) fn remove_client(handle_id: u64) {
+    client_statistics().remove(&handle_id);
+}
+
+/// Returns the statistics of the client handle, or `None` if the handle isn't registered.
+pub(crate) fn get_client_statistics(handle_id: u64) -> Option<Arc<ClientStatistics>> {
+    client_statistics()
+        .get(&handle_id)
+        .map(|stats| stats.value().clone())
+}
+
+/// Records a single command sent through the client handle.
+pub(crate) fn record_command(handle_id: u64, latency: Duration, is_error: bool) {
+    if let Some(stats) = get_client_statistics(handle_id) {
+        stats.commands_count.fetch_add(1, Ordering::Relaxed);
+        stats.record(latency, is_error);
+    }
+}
+
+/// Records a batch (pipeline or transaction) sent through the client handle.
+pub(crate) fn record_batch(handle_id: u64, latency: Duration, is_error: bool) {
+    if let Some(stats) = get_client_statistics(handle_id) {
+        stats.batches_count.fetch_add(1, Ordering::Relaxed);
+        stats.record(latency, is_error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_commands_and_batches_per_handle() {
+        register_client(u64::MAX);
+        record_command(u64::MAX, Duration::from_micros(100), false);
+        record_command(u64::MAX, Duration::from_micros(300), true);
+        record_batch(u64::MAX, Duration::from_micros(200), false);
+        // Unregistered handles are ignored.
+        record_command(u64::MAX - 1, Duration::from_micros(100), false);
+
+        let pairs = get_client_statistics(u64::MAX).unwrap().to_pairs();
+        assert_eq!(
+            pairs,
+            vec![
+                ("commands_count", 2),
+                ("batches_count", 1),
+                ("errors_count", 1),
+                ("average_latency_us", 200),
+                ("max_latency_us", 300),
+            ]
+        );
+        assert!(get_client_statistics(u64::MAX - 1).is_none());
+
+        remove_client(u64::MAX);
+        assert!(get_client_statistics(u64::MAX).is_none());
+    }
+}
@@ -798,6 +798,11 @@ class BaseClientConfiguration:
     ) -> Tuple[Optional[Callable[[PubSubMsg, Any], None]], Any]:
         return None, None
 
+    def _get_pubsub_resubscribed_callback(
+        self,
+    ) -> Optional[Callable[[Dict[Any, Set[bytes]], int, Any], None]]:
+        return None
+
 
 class AdvancedGlideClientConfiguration(AdvancedBaseClientConfiguration):
     """
@@ -902,6 +907,11 @@ class GlideClientConfiguration(BaseClientConfiguration):
                 Optional callback to accept the incomming messages.
             context (Any):
                 Arbitrary context to pass to the callback.
+            resubscribed_callback (Optional[Callable[[Dict[Any, Set[bytes]], int, Any], None]]):
+                Optional callback notified when the subscriptions lost on a disconnection were restored, with the
+                restored channels and patterns by their `GlideClientConfiguration.PubSubChannelModes`, the
+                milliseconds since the disconnection, and the context. Messages published in that time may have been
+                lost.
         """
 
         channels_and_patterns: Dict[
@@ -909,6 +919,9 @@ class GlideClientConfiguration(BaseClientConfiguration):
         ]
         callback: Optional[Callable[[PubSubMsg, Any], None]]
         context: Any
+        resubscribed_callback: Optional[
+            Callable[[Dict[Any, Set[bytes]], int, Any], None]
+        ] = None
 
     @dataclass
     class PubSubState:
@@ -997,6 +1010,13 @@ class GlideClientConfiguration(BaseClientConfiguration):
             return self.pubsub_subscriptions.callback, self.pubsub_subscriptions.context
         return None, None
 
+    def _get_pubsub_resubscribed_callback(
+        self,
+    ) -> Optional[Callable[[Dict[Any, Set[bytes]], int, Any], None]]:
+        if self.pubsub_subscriptions:
+            return self.pubsub_subscriptions.resubscribed_callback
+        return None
+
 
 class AdvancedGlideClusterClientConfiguration(AdvancedBaseClientConfiguration):
     """
@@ -1126,6 +1146,11 @@ class GlideClusterClientConfiguration(BaseClientConfiguration):
                 Optional callback to accept the incoming messages.
             context (Any):
                 Arbitrary context to pass to the callback.
+            resubscribed_callback (Optional[Callable[[Dict[Any, Set[bytes]], int, Any], None]]):
+                Optional callback notified when the subscriptions lost on a disconnection were restored, with the
+                restored channels and patterns by their `GlideClusterClientConfiguration.PubSubChannelModes`, the
+                milliseconds since the disconnection, and the context. Messages published in that time may have been
+                lost.
         """
 
         channels_and_patterns: Dict[
@@ -1133,6 +1158,9 @@ class GlideClusterClientConfiguration(BaseClientConfiguration):
         ]
         callback: Optional[Callable[[PubSubMsg, Any], None]]
         context: Any
+        resubscribed_callback: Optional[
+            Callable[[Dict[Any, Set[bytes]], int, Any], None]
+        ] = None
 
     @dataclass
     class PubSubState:
@@ -1228,6 +1256,13 @@ class GlideClusterClientConfiguration(BaseClientConfiguration):
             return self.pubsub_subscriptions.callback, self.pubsub_subscriptions.context
         return None, None
 
+    def _get_pubsub_resubscribed_callback(
+        self,
+    ) -> Optional[Callable[[Dict[Any, Set[bytes]], int, Any], None]]:
+        if self.pubsub_subscriptions:
+            return self.pubsub_subscriptions.resubscribed_callback
+        return None
+
 
 def load_root_certificates_from_file(path: str) -> bytes:
     """
@@ -94,6 +94,9 @@ class _GlideFFI:
             typedef struct {
                 const char* command_error_message;
                 int command_error_type;
+                const char* command_error_code;
+                bool command_error_retryable;
+                const char* command_error_node_address;
             } CommandError;
 
             typedef struct {
@@ -193,6 +196,19 @@ class _GlideFFI:
                 const ClientType* client_type,
                 PubSubCallback pubsub_callback
             );
+            typedef void (*ClientCreationCallback)(
+                uintptr_t request_id,
+                const ConnectionResponse* response
+            );
+            void create_client_async(
+                const uint8_t* connection_request_bytes,
+                size_t connection_request_len,
+                const ClientType* client_type,
+                PubSubCallback pubsub_callback,
+                const void* connection_event_callback,
+                uintptr_t request_id,
+                ClientCreationCallback creation_callback
+            );
             void close_client(const void* client_adapter_ptr);
             void free_connection_response(ConnectionResponse* connection_response_ptr);
 
@@ -203,7 +219,8 @@ class _GlideFFI:
                 Random,
                 SlotId,
                 SlotKey,
-                ByAddress
+                ByAddress,
+                AzAffinity
             } RouteType;
 
             typedef enum {
@@ -218,6 +235,7 @@ class _GlideFFI:
                 int slot_type;
                 const char* hostname;
                 int port;
+                const char* az;
             } RouteInfo;
 
             typedef struct {
@@ -231,11 +249,13 @@ class _GlideFFI:
                 size_t cmd_count;
                 const CmdInfo** cmds;
                 bool is_atomic;
+                const uint32_t* expected_types;
             } BatchInfo;
 
             typedef struct {
                 bool retry_server_error;
                 bool retry_connection_error;
+                bool follow_ask_redirects;
                 bool has_timeout;
                 uint32_t timeout;
                 const RouteInfo* route_info;
@@ -318,6 +338,12 @@ class _GlideFFI:
                 unsigned long compression_skipped_count;
                 unsigned long subscription_out_of_sync_count;
                 unsigned long subscription_last_sync_timestamp;
+                unsigned long primary_fallback_reads_count;
+                unsigned long circuit_breaker_opened_count;
+                unsigned long circuit_breaker_rejected_count;
+                unsigned long pubsub_messages_dropped_count;
+                unsigned long connections_recycled_max_age_count;
+                unsigned long connections_recycled_idle_count;
             } Statistics;
 
             Statistics get_statistics();
@@ -1,9 +1,10 @@
 # Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 import os
+import struct
 import sys
 import threading
-from typing import Any, List, Optional, Tuple, Union
+from typing import Any, Dict, List, Optional, Set, Tuple, Union
 
 from glide_shared.commands.command_args import ObjectType
 from glide_shared.commands.core_options import PubSubMsg
@@ -113,12 +114,32 @@ class BaseClient(CoreCommands):
         # Store reference to prevent garbage collection
         self._pubsub_callback_ref = pubsub_callback
 
-        client_response_ptr = self._lib.create_client(
+        # Wait for the client to connect in Python rather than inside the FFI call,
+        # so signal handlers, such as the one of KeyboardInterrupt, can run meanwhile.
+        created = threading.Event()
+        responses = []
+
+        def _creation_callback(request_id, response_ptr):
+            responses.append(response_ptr)
+            created.set()
+
+        creation_callback = self._ffi.callback(
+            "ClientCreationCallback", _creation_callback
+        )
+        # Store reference to prevent garbage collection, even if the wait is interrupted
+        self._creation_callback_ref = creation_callback
+
+        self._lib.create_client_async(
             conn_req_bytes,
             len(conn_req_bytes),
             client_type,
             pubsub_callback,
+            self._ffi.NULL,
+            0,
+            creation_callback,
         )
+        created.wait()
+        client_response_ptr = responses[0]
 
         Logger.log(Level.INFO, "connection info", "new connection established")
 
@@ -180,6 +201,7 @@ class BaseClient(CoreCommands):
                     9: "Subscribe",
                     10: "PSubscribe",
                     11: "SSubscribe",
+                    12: "Resubscribed",
                 }
 
                 message_kind = push_kind_map.get(kind)
@@ -207,6 +229,24 @@ class BaseClient(CoreCommands):
                         else:
                             self._pubsub_queue.append(pubsub_msg)
                             self._pubsub_condition.notify()
+                elif message_kind == "Resubscribed":
+                    # The channel holds the restored subscriptions, each as its mode in a single byte, the length of
+                    # its name as a 4 bytes big-endian integer, and its name. The message is the milliseconds since
+                    # the disconnection. It isn't a message, so it's never queued.
+                    resubscribed_callback = (
+                        self._config._get_pubsub_resubscribed_callback()
+                    )
+                    if resubscribed_callback:
+                        modes = self._config.PubSubChannelModes  # type: ignore[attr-defined]
+                        restored: Dict[Any, Set[bytes]] = {}
+                        offset = 0
+                        while offset + 5 <= len(channel):
+                            (length,) = struct.unpack_from(">I", channel, offset + 1)
+                            name = channel[offset + 5 : offset + 5 + length]
+                            restored.setdefault(modes(channel[offset]), set()).add(name)
+                            offset += 5 + length
+                        _, context = self._config._get_pubsub_callback_and_context()
+                        resubscribed_callback(restored, int(message), context)
                 elif message_kind in [
                     "PSubscribe",
                     "Subscribe",
@@ -652,6 +692,7 @@ class BaseClient(CoreCommands):
             {
                 "retry_server_error": retry_server_error,
                 "retry_connection_error": retry_connection_error,
+                "follow_ask_redirects": True,
                 "has_timeout": timeout is not None,
                 "timeout": timeout or 0,
                 "route_info": route_info,
@@ -717,6 +758,7 @@ class BaseClient(CoreCommands):
                 "slot_type": slot_type,
                 "hostname": hostname_ptr,
                 "port": port,
+                "az": self._ffi.NULL,
             },
         )
 
@@ -829,6 +871,12 @@ class BaseClient(CoreCommands):
                 - compression_skipped_count: Number of times compression was skipped
                 - subscription_out_of_sync_count: Failed reconciliation attempts
                 - subscription_last_sync_timestamp: Last successful sync (milliseconds since epoch)
+                - primary_fallback_reads_count: Stale replica reads retried on the primary
+                - circuit_breaker_opened_count: Node circuit breakers that were opened
+                - circuit_breaker_rejected_count: Requests that failed fast on an open circuit breaker
+                - pubsub_messages_dropped_count: Pubsub messages dropped because the pubsub buffer was full
+                - connections_recycled_max_age_count: Connections recycled after the maximum connection age
+                - connections_recycled_idle_count: Connections recycled after the idle timeout
         """
         # Call the C FFI get_statistics function (returns by value, no manual free needed)
         stats = self._lib.get_statistics()
@@ -845,6 +893,12 @@ class BaseClient(CoreCommands):
             "compression_skipped_count": stats.compression_skipped_count,
             "subscription_out_of_sync_count": stats.subscription_out_of_sync_count,
             "subscription_last_sync_timestamp": stats.subscription_last_sync_timestamp,
+            "primary_fallback_reads_count": stats.primary_fallback_reads_count,
+            "circuit_breaker_opened_count": stats.circuit_breaker_opened_count,
+            "circuit_breaker_rejected_count": stats.circuit_breaker_rejected_count,
+            "pubsub_messages_dropped_count": stats.pubsub_messages_dropped_count,
+            "connections_recycled_max_age_count": stats.connections_recycled_max_age_count,
+            "connections_recycled_idle_count": stats.connections_recycled_idle_count,
         }
 
     def get_subscriptions(self):
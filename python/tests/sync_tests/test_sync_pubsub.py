@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 import time
-from typing import List, Optional, cast
+from typing import Any, Dict, List, Optional, Set, cast
 
 import pytest
 from glide_shared.commands.core_options import PubSubMsg
@@ -3591,6 +3591,64 @@ class TestSyncPubSub:
 
             sync_check_no_messages_left(method, listening_client, callback_messages, 2)
 
+    @pytest.mark.parametrize("cluster_mode", [True, False])
+    def test_sync_resubscribed_callback_after_connection_kill(
+        self,
+        request,
+        cluster_mode: bool,
+    ):
+        """
+        Test that restoring the subscriptions after a connection kill notifies the resubscribed callback,
+        and isn't delivered as a pubsub message.
+        """
+        channel = "test_channel_resubscribed_" + get_random_string(5)
+        resubscriptions: List[Dict[Any, Set[bytes]]] = []
+
+        def on_resubscribed(
+            restored: Dict[Any, Set[bytes]], disconnected_for_ms: int, context
+        ):
+            assert disconnected_for_ms >= 0
+            resubscriptions.append(restored)
+
+        pubsub_subscription = create_pubsub_subscription(
+            cluster_mode, channels={channel}
+        )
+        pubsub_subscription.resubscribed_callback = on_resubscribed
+        listening_client, publishing_client = None, None
+        try:
+            if cluster_mode:
+                listening_client = create_sync_client(
+                    request,
+                    cluster_mode,
+                    cluster_mode_pubsub=pubsub_subscription,  # type: ignore[arg-type]
+                )
+            else:
+                listening_client = create_sync_client(
+                    request,
+                    cluster_mode,
+                    standalone_mode_pubsub=pubsub_subscription,  # type: ignore[arg-type]
+                )
+            publishing_client = create_sync_client(request, cluster_mode)
+
+            kill_connections(publishing_client, None)
+            sync_wait_for_subscription_state(
+                listening_client, expected_channels={channel}, timeout_sec=5.0
+            )
+
+            deadline = time.time() + 5
+            while not resubscriptions and time.time() < deadline:
+                time.sleep(0.1)
+            # Exact channels have the mode 0 in both standalone and cluster mode.
+            assert any(
+                channel.encode() in restored.get(0, set())
+                for restored in resubscriptions
+            )
+            # The notification isn't queued as a message.
+            assert listening_client.try_get_pubsub_message() is None
+        finally:
+            sync_client_cleanup(listening_client)
+            sync_client_cleanup(publishing_client)
+
     @pytest.mark.parametrize("cluster_mode", [True, False])
     @pytest.mark.parametrize(
         "method",